@@ -0,0 +1,143 @@
+//! Renders schema errors as rustc-style diagnostics with source
+//! snippets, in the same format as
+//! [`GraphQLParseError::format_detailed()`](libgraphql_parser::GraphQLParseError::format_detailed).
+//!
+//! Unlike parse errors, whose spans are pre-resolved against a
+//! single source, schema error spans may point into any of the
+//! sources loaded into a
+//! [`SchemaBuilder`](crate::schema::SchemaBuilder). Every span
+//! (primary and note) is therefore resolved through the
+//! [`SchemaSourceMap`] it was recorded against, and note snippets
+//! carry their own `-->` location line.
+
+use crate::error_note::ErrorNote;
+use crate::error_note::ErrorNoteKind;
+use crate::schema_source_map::resolve_span;
+use crate::schema_source_map::ResolvedSpan;
+use crate::schema_source_map::SchemaSourceMap;
+use crate::span::Span;
+use crate::span::BUILTIN_SOURCE_MAP_ID;
+
+/// Formats an error as a multi-line diagnostic:
+///
+/// ```text
+/// error: duplicate type definition `User`
+///   --> types/user.graphql:3:1
+///    |
+///  3 | type User {
+///    | ^^^^^^^^^^^
+///    = note: first defined here
+///      --> schema.graphql:5:1
+///       |
+///     5 | type User {
+///       | -----------
+/// ```
+///
+/// Spans on the built-in source map (errors with no user-authored
+/// location) render without a location or snippet. Spans whose
+/// source map no longer holds its source text render with a
+/// location but no snippet.
+pub(crate) fn format_detailed(
+    message: &str,
+    span: Span,
+    notes: &[ErrorNote],
+    source_maps: &[SchemaSourceMap],
+) -> String {
+    let mut output = format!("error: {message}\n");
+    if let Some(resolved) = resolve_user_span(source_maps, span) {
+        output.push_str(&format!("  --> {}\n", location(&resolved)));
+        push_snippet(&mut output, source_maps, span, &resolved, "", '^');
+    }
+
+    for note in notes {
+        let prefix = match note.kind {
+            ErrorNoteKind::General => "note",
+            ErrorNoteKind::Help => "help",
+            ErrorNoteKind::Spec => "spec",
+        };
+        output.push_str(&format!("   = {prefix}: {}\n", note.message));
+
+        let Some(note_span) = note.span else { continue };
+        if let Some(resolved) = resolve_user_span(source_maps, note_span) {
+            output.push_str(&format!("     --> {}\n", location(&resolved)));
+            push_snippet(
+                &mut output,
+                source_maps,
+                note_span,
+                &resolved,
+                "   ",
+                '-',
+            );
+        }
+    }
+
+    output
+}
+
+/// Resolves `span` unless it is a built-in span (which has no
+/// user-facing location).
+fn resolve_user_span(
+    source_maps: &[SchemaSourceMap],
+    span: Span,
+) -> Option<ResolvedSpan<'_>> {
+    if span.source_map_id == BUILTIN_SOURCE_MAP_ID {
+        return None;
+    }
+    resolve_span(source_maps, span)
+}
+
+/// Formats a resolved span's start as `file:line:column` (1-based),
+/// using `<input>` for sources loaded from a string.
+fn location(resolved: &ResolvedSpan<'_>) -> String {
+    let file_name = resolved.file_path
+        .map(|p| p.display().to_string())
+        .unwrap_or_else(|| "<input>".to_string());
+    format!(
+        "{file_name}:{}:{}",
+        resolved.start.line + 1,
+        resolved.start.col_utf8 + 1,
+    )
+}
+
+/// Appends the first source line of `span` with an underline made
+/// of `marker` characters. Multi-line spans are underlined to the
+/// end of their first line.
+fn push_snippet(
+    output: &mut String,
+    source_maps: &[SchemaSourceMap],
+    span: Span,
+    resolved: &ResolvedSpan<'_>,
+    indent: &str,
+    marker: char,
+) {
+    let Some(line_content) = source_maps
+        .get(span.source_map_id.0 as usize)
+        .and_then(|sm| sm.line_content(resolved.start.line))
+    else {
+        return;
+    };
+
+    let display_line_num = resolved.start.line + 1;
+    let line_num_width = display_line_num.to_string().len().max(2);
+    let col_start = resolved.start.col_utf8 as usize;
+    let col_end = if resolved.end.line == resolved.start.line {
+        resolved.end.col_utf8 as usize
+    } else {
+        line_content.chars().count()
+    };
+    let underline_len = col_end.saturating_sub(col_start).max(1);
+
+    output.push_str(&format!(
+        "{indent}{:>line_num_width$} |\n",
+        "",
+    ));
+    output.push_str(&format!(
+        "{indent}{display_line_num:>line_num_width$} | {line_content}\n",
+    ));
+    output.push_str(&format!(
+        "{indent}{:>line_num_width$} | {:>col_start$}{}\n",
+        "",
+        "",
+        marker.to_string().repeat(underline_len),
+    ));
+}
@@ -23,6 +23,21 @@ pub struct DirectiveAnnotation {
 }
 
 impl DirectiveAnnotation {
+    /// Creates an annotation applying the directive `name` with the
+    /// given `arguments`, e.g. for use with the type builders'
+    /// `add_directive()` methods.
+    pub fn new(
+        name: impl Into<DirectiveName>,
+        arguments: impl IntoIterator<Item = (FieldName, Value)>,
+        span: Span,
+    ) -> Self {
+        Self {
+            arguments: arguments.into_iter().collect(),
+            name: name.into(),
+            span,
+        }
+    }
+
     pub fn arguments(&self) -> &IndexMap<FieldName, Value> {
         &self.arguments
     }
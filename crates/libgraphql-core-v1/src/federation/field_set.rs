@@ -0,0 +1,150 @@
+use crate::names::TypeName;
+use crate::types::GraphQLType;
+use indexmap::IndexMap;
+use libgraphql_parser::ast;
+
+/// Checks that `field_set` (the `fields:` argument of a `@key`
+/// directive) is a valid selection set on `type_name`.
+///
+/// A `FieldSet` is written without its enclosing braces, so it is
+/// parsed as the shorthand query `{ <field_set> }`. Every selected
+/// field must exist on the type it is selected from; fields of
+/// object type need a sub-selection and leaf fields must not have
+/// one. Aliases, arguments, directives, fragments, and fields
+/// returning interfaces or unions are not allowed in a key.
+///
+/// Returns a human-readable reason on failure.
+///
+/// See [`@key`](https://www.apollographql.com/docs/graphos/reference/federation/directives#key).
+pub(crate) fn validate_key_field_set(
+    field_set: &str,
+    type_name: &TypeName,
+    types: &IndexMap<TypeName, GraphQLType>,
+) -> Result<(), String> {
+    let source = format!("{{ {field_set} }}");
+    let parse_result = libgraphql_parser::parse_executable(&source);
+    if parse_result.has_errors() {
+        let message = parse_result.errors()
+            .first()
+            .map(|error| error.message().to_string())
+            .unwrap_or_default();
+        return Err(format!("`{field_set}` is not a selection set: {message}"));
+    }
+
+    let document = parse_result.ast();
+    let selection_set = match document.definitions.as_slice() {
+        [ast::Definition::OperationDefinition(operation)]
+            if operation.shorthand => &operation.selection_set,
+        _ => return Err(format!("`{field_set}` is not a selection set")),
+    };
+    let Some(type_) = types.get(type_name) else {
+        return Ok(());
+    };
+    validate_selection_set(selection_set, type_, types)
+}
+
+/// The names of the top-level fields selected by `field_set`, or
+/// none if it does not parse.
+pub(crate) fn key_field_names(field_set: &str) -> Vec<String> {
+    let source = format!("{{ {field_set} }}");
+    let parse_result = libgraphql_parser::parse_executable(&source);
+    if parse_result.has_errors() {
+        return vec![];
+    }
+    let document = parse_result.ast();
+    let [ast::Definition::OperationDefinition(operation)] =
+        document.definitions.as_slice()
+    else {
+        return vec![];
+    };
+    operation.selection_set.selections
+        .iter()
+        .filter_map(|selection| match selection {
+            ast::Selection::Field(field) => {
+                Some(field.name_value().to_string())
+            },
+            _ => None,
+        })
+        .collect()
+}
+
+fn validate_selection_set(
+    selection_set: &ast::SelectionSet<'_>,
+    parent_type: &GraphQLType,
+    types: &IndexMap<TypeName, GraphQLType>,
+) -> Result<(), String> {
+    let parent_fields = match parent_type {
+        GraphQLType::Interface(interface) => interface.fields(),
+        GraphQLType::Object(object) => object.fields(),
+        _ => return Err(format!(
+            "`{}` is not an object or interface type",
+            parent_type.name(),
+        )),
+    };
+
+    for selection in &selection_set.selections {
+        let ast::Selection::Field(selection) = selection else {
+            return Err("fragments are not allowed".to_string());
+        };
+        let field_name = selection.name_value();
+        if let Some(alias) = &selection.alias {
+            return Err(format!(
+                "alias `{}` on `{field_name}` is not allowed",
+                alias.value,
+            ));
+        }
+        if !selection.arguments.is_empty() {
+            return Err(format!(
+                "arguments on `{field_name}` are not allowed",
+            ));
+        }
+        if !selection.directives.is_empty() {
+            return Err(format!(
+                "directives on `{field_name}` are not allowed",
+            ));
+        }
+
+        let Some(field) = parent_fields.get(field_name) else {
+            return Err(format!(
+                "`{}` has no field `{field_name}`",
+                parent_type.name(),
+            ));
+        };
+        // Undefined return types are reported by `build()`.
+        let Some(field_type) = types.get(field.return_type_name()) else {
+            continue;
+        };
+        match (field_type, &selection.selection_set) {
+            (GraphQLType::Interface(_) | GraphQLType::Union(_), _) => {
+                return Err(format!(
+                    "`{}.{field_name}` returns {} `{}`, which is not \
+                    allowed in a key",
+                    parent_type.name(),
+                    field_type.type_kind(),
+                    field_type.name(),
+                ));
+            },
+            (GraphQLType::Object(_), Some(sub_selection)) => {
+                validate_selection_set(sub_selection, field_type, types)?;
+            },
+            (GraphQLType::Object(_), None) => {
+                return Err(format!(
+                    "`{}.{field_name}` returns object type `{}` and \
+                    must have a selection of subfields",
+                    parent_type.name(),
+                    field_type.name(),
+                ));
+            },
+            (_, Some(_)) => {
+                return Err(format!(
+                    "`{}.{field_name}` returns leaf type `{}` and must \
+                    not have a selection of subfields",
+                    parent_type.name(),
+                    field_type.name(),
+                ));
+            },
+            (_, None) => {},
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,19 @@
+//! [Apollo Federation](https://www.apollographql.com/docs/graphos/reference/federation/subgraph-spec)
+//! support: building a subgraph schema with the Federation
+//! definitions injected, printing it in its `_service { sdl }`
+//! form, and composing subgraphs into a supergraph.
+
+mod field_set;
+mod subgraph;
+mod supergraph;
+
+pub use crate::federation::subgraph::build_subgraph;
+pub use crate::federation::subgraph::build_subgraph_from_str;
+pub use crate::federation::subgraph::service_sdl;
+pub use crate::federation::subgraph::FEDERATION_SPEC_URL;
+pub use crate::federation::supergraph::EntityKey;
+pub use crate::federation::supergraph::Supergraph;
+pub use crate::federation::supergraph::SupergraphComposer;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,375 @@
+use crate::federation::field_set::validate_key_field_set;
+use crate::located::Located;
+use crate::names::FieldName;
+use crate::names::TypeName;
+use crate::schema::Schema;
+use crate::schema::SchemaBuildError;
+use crate::schema::SchemaBuildErrorKind;
+use crate::schema::SchemaBuilder;
+use crate::schema::SchemaErrors;
+use crate::schema::TypeValidationError;
+use crate::schema::TypeValidationErrorKind;
+use crate::span::Span;
+use crate::types::FieldDefinition;
+use crate::types::FieldedTypeData;
+use crate::types::GraphQLType;
+use crate::types::ObjectType;
+use crate::types::ParameterDefinition;
+use crate::types::ParameterOwner;
+use crate::types::TypeAnnotation;
+use crate::types::UnionType;
+use crate::value::Value;
+use indexmap::IndexMap;
+
+/// The `@link` URL of the Federation version whose definitions
+/// [`build_subgraph()`] injects.
+pub const FEDERATION_SPEC_URL: &str = "https://specs.apollo.dev/federation/v2.7";
+
+/// The Federation directive definitions, keyed by name.
+const FEDERATION_DIRECTIVES: [(&str, &str); 9] = [
+    (
+        "key",
+        "directive @key(fields: FieldSet!, resolvable: Boolean = true) \
+        repeatable on OBJECT | INTERFACE",
+    ),
+    ("shareable", "directive @shareable repeatable on OBJECT | FIELD_DEFINITION"),
+    ("external", "directive @external on OBJECT | FIELD_DEFINITION"),
+    ("requires", "directive @requires(fields: FieldSet!) on FIELD_DEFINITION"),
+    ("provides", "directive @provides(fields: FieldSet!) on FIELD_DEFINITION"),
+    (
+        "override",
+        "directive @override(from: String!, label: String) on FIELD_DEFINITION",
+    ),
+    (
+        "inaccessible",
+        "directive @inaccessible on FIELD_DEFINITION | OBJECT | INTERFACE \
+        | UNION | ARGUMENT_DEFINITION | SCALAR | ENUM | ENUM_VALUE \
+        | INPUT_OBJECT | INPUT_FIELD_DEFINITION",
+    ),
+    (
+        "tag",
+        "directive @tag(name: String!) repeatable on FIELD_DEFINITION \
+        | OBJECT | INTERFACE | UNION | ARGUMENT_DEFINITION | SCALAR | ENUM \
+        | ENUM_VALUE | INPUT_OBJECT | INPUT_FIELD_DEFINITION | SCHEMA",
+    ),
+    (
+        "link",
+        "directive @link(url: String!, as: String, for: link__Purpose, \
+        import: [link__Import]) repeatable on SCHEMA",
+    ),
+];
+
+/// The supporting Federation type definitions, keyed by name.
+const FEDERATION_TYPES: [(&str, &str); 5] = [
+    ("FieldSet", "scalar FieldSet"),
+    ("link__Import", "scalar link__Import"),
+    ("link__Purpose", "enum link__Purpose { SECURITY EXECUTION }"),
+    ("_Any", "scalar _Any"),
+    ("_Service", "type _Service { sdl: String }"),
+];
+
+const ENTITIES_FIELD_NAME: &str = "_entities";
+const ENTITY_UNION_NAME: &str = "_Entity";
+const SERVICE_FIELD_NAME: &str = "_service";
+
+/// Builds `builder` as an
+/// [Apollo Federation](https://www.apollographql.com/docs/graphos/reference/federation/subgraph-spec)
+/// subgraph schema.
+///
+/// Before validating, injects every Federation definition the
+/// loaded schema does not already define:
+///
+/// - the directives `@key`, `@shareable`, `@external`,
+///   `@requires`, `@provides`, `@override`, `@inaccessible`,
+///   `@tag`, and `@link`
+/// - the scalars `FieldSet`, `_Any`, and `link__Import`, the enum
+///   `link__Purpose`, and the object type `_Service`
+/// - the union `_Entity` of every object type annotated with
+///   `@key`
+/// - the fields `_entities(representations: [_Any!]!): [_Entity]!`
+///   (only if there is at least one entity) and
+///   `_service: _Service!` on the query root type, which is
+///   created if the subgraph defines none
+///
+/// Each `@key(fields:)` argument is also checked to be a valid
+/// selection set on the type it is applied to, and reported as a
+/// [`TypeValidationErrorKind::InvalidFieldSet`] otherwise.
+///
+/// ```rust
+/// # use libgraphql_core_v1 as libgraphql_core;
+/// use libgraphql_core::federation;
+/// use libgraphql_core::schema::SchemaBuilder;
+///
+/// let mut builder = SchemaBuilder::new();
+/// builder.load_str(r#"type User @key(fields: "id") { id: ID! }"#).unwrap();
+/// let schema = federation::build_subgraph(builder).unwrap();
+///
+/// assert_eq!(schema.union_type("_Entity").unwrap().members().len(), 1);
+/// assert!(schema.query_type().field("_entities").is_some());
+/// assert!(schema.query_type().field("_service").is_some());
+/// ```
+// TODO: SchemaErrors wraps Vec<SchemaBuildError> which is
+// large. Consider boxing once error strategy is finalized.
+#[allow(clippy::result_large_err)]
+pub fn build_subgraph(mut builder: SchemaBuilder) -> Result<Schema, SchemaErrors> {
+    inject_federation_definitions(&mut builder);
+    let key_errors = validate_key_field_sets(&builder);
+    builder.push_errors(key_errors);
+    inject_entity_union(&mut builder);
+    inject_query_fields(&mut builder);
+    builder.build()
+}
+
+/// Convenience: parse a subgraph schema string and build it with
+/// [`build_subgraph()`] in one step.
+// TODO: SchemaErrors wraps Vec<SchemaBuildError> which is
+// large. Consider boxing once error strategy is finalized.
+#[allow(clippy::result_large_err)]
+pub fn build_subgraph_from_str(source: &str) -> Result<Schema, SchemaErrors> {
+    let mut builder = SchemaBuilder::new();
+    if let Err(errors) = builder.load_str(source) {
+        return Err(
+            SchemaErrors::new(errors)
+                .with_source_maps(builder.source_maps().to_vec()),
+        );
+    }
+    build_subgraph(builder)
+}
+
+/// Prints `schema` in the form a subgraph returns from
+/// `_service { sdl }`.
+///
+/// The Federation definitions [`build_subgraph()`] injects (the
+/// directives and supporting types, `_Entity`, and the
+/// `_entities` / `_service` query fields) are omitted; instead the
+/// output starts with an `extend schema @link(...)` importing the
+/// Federation directives from [`FEDERATION_SPEC_URL`]. A query
+/// root type left without fields is omitted as well.
+pub fn service_sdl(schema: &Schema) -> String {
+    let mut schema = schema.clone();
+    remove_entity_resolution(&mut schema);
+    for (name, _) in FEDERATION_DIRECTIVES {
+        schema.directive_defs.shift_remove(name);
+    }
+    for (name, _) in FEDERATION_TYPES {
+        schema.types.shift_remove(name);
+    }
+
+    let imports: Vec<String> = FEDERATION_DIRECTIVES.iter()
+        .filter(|(name, _)| *name != "link")
+        .map(|(name, _)| format!("\"@{name}\""))
+        .collect();
+    let link = format!(
+        "extend schema @link(url: \"{FEDERATION_SPEC_URL}\", import: [{}])\n",
+        imports.join(", "),
+    );
+    let sdl = schema.to_sdl();
+    if sdl.is_empty() {
+        link
+    } else {
+        format!("{link}\n{sdl}")
+    }
+}
+
+/// Removes the `_Entity`, `_Any`, and `_Service` types and the
+/// `_entities` / `_service` query fields, which only exist for a
+/// router to query a single subgraph. A query root type left
+/// without fields is removed as well.
+pub(crate) fn remove_entity_resolution(schema: &mut Schema) {
+    for name in [ENTITY_UNION_NAME, "_Any", "_Service"] {
+        schema.types.shift_remove(name);
+    }
+    let query_type_name = schema.query_type_name.clone();
+    if let Some(GraphQLType::Object(query)) =
+        schema.types.get_mut(&query_type_name)
+    {
+        query.0.fields.shift_remove(ENTITIES_FIELD_NAME);
+        query.0.fields.shift_remove(SERVICE_FIELD_NAME);
+        if query.0.fields.is_empty() {
+            schema.types.shift_remove(&query_type_name);
+        }
+    }
+}
+
+/// Whether `name` is one of the Federation directives.
+pub(crate) fn is_federation_directive(name: &str) -> bool {
+    FEDERATION_DIRECTIVES.iter().any(|(directive_name, _)| *directive_name == name)
+}
+
+/// Whether `name` is one of the Federation supporting types
+/// (including the generated `_Entity` union).
+pub(crate) fn is_federation_type(name: &str) -> bool {
+    name == ENTITY_UNION_NAME
+        || FEDERATION_TYPES.iter().any(|(type_name, _)| *type_name == name)
+}
+
+/// Loads the Federation directive and type definitions that
+/// `builder` does not already define.
+fn inject_federation_definitions(builder: &mut SchemaBuilder) {
+    let mut preamble = String::new();
+    for (name, sdl) in FEDERATION_DIRECTIVES {
+        if !builder.directive_defs().contains_key(name) {
+            preamble.push_str(sdl);
+            preamble.push('\n');
+        }
+    }
+    for (name, sdl) in FEDERATION_TYPES {
+        if !builder.types().contains_key(name) {
+            preamble.push_str(sdl);
+            preamble.push('\n');
+        }
+    }
+    if preamble.is_empty() {
+        return;
+    }
+    if let Err(errors) = builder.load_str(&preamble) {
+        builder.push_errors(errors);
+    }
+}
+
+/// Checks the `fields:` argument of every `@key` applied to an
+/// object or interface type.
+fn validate_key_field_sets(builder: &SchemaBuilder) -> Vec<SchemaBuildError> {
+    let types = builder.types();
+    let mut errors = vec![];
+    for type_ in types.values() {
+        if !matches!(type_, GraphQLType::Interface(_) | GraphQLType::Object(_)) {
+            continue;
+        }
+        for annotation in type_.directives() {
+            if annotation.name().as_str() != "key" {
+                continue;
+            }
+            let Some(Value::String(field_set)) =
+                annotation.arguments().get("fields")
+            else {
+                continue;
+            };
+            if let Err(reason) =
+                validate_key_field_set(field_set, type_.name(), types)
+            {
+                let error = TypeValidationError::new(
+                    TypeValidationErrorKind::InvalidFieldSet {
+                        directive_name: "key".to_string(),
+                        reason,
+                        type_name: type_.name().to_string(),
+                    },
+                    annotation.span(),
+                    vec![],
+                );
+                errors.push(SchemaBuildError::new(
+                    SchemaBuildErrorKind::TypeValidation(error),
+                    annotation.span(),
+                    vec![],
+                ));
+            }
+        }
+    }
+    errors
+}
+
+/// The object types annotated with `@key`, in definition order.
+fn entity_type_names(builder: &SchemaBuilder) -> Vec<TypeName> {
+    builder.types()
+        .values()
+        .filter(|type_| matches!(type_, GraphQLType::Object(_)))
+        .filter(|type_| {
+            type_.directives()
+                .iter()
+                .any(|annotation| annotation.name().as_str() == "key")
+        })
+        .map(|type_| type_.name().clone())
+        .collect()
+}
+
+/// Adds the `_Entity` union of every entity type, unless there are
+/// no entities or `_Entity` is already defined.
+fn inject_entity_union(builder: &mut SchemaBuilder) {
+    let entities = entity_type_names(builder);
+    if entities.is_empty() || builder.types().contains_key(ENTITY_UNION_NAME) {
+        return;
+    }
+    let name = TypeName::new(ENTITY_UNION_NAME);
+    let union_type = UnionType {
+        description: None,
+        directives: vec![],
+        members: entities.into_iter()
+            .map(|value| Located { value, span: Span::builtin() })
+            .collect(),
+        name: name.clone(),
+        span: Span::builtin(),
+    };
+    builder.types_mut().insert(name, GraphQLType::Union(Box::new(union_type)));
+}
+
+/// Adds `_entities` (if `_Entity` is defined) and `_service` to the
+/// query root type, creating the query root type if needed.
+fn inject_query_fields(builder: &mut SchemaBuilder) {
+    let has_entities = builder.types().contains_key(ENTITY_UNION_NAME);
+    let query_type_name = builder.query_type_name()
+        .map(|(name, _)| name.clone())
+        .unwrap_or_else(|| TypeName::new("Query"));
+    let query = builder.types_mut()
+        .entry(query_type_name.clone())
+        .or_insert_with(|| GraphQLType::Object(Box::new(ObjectType(
+            FieldedTypeData {
+                description: None,
+                directives: vec![],
+                fields: IndexMap::new(),
+                interfaces: vec![],
+                name: query_type_name.clone(),
+                span: Span::builtin(),
+            },
+        ))));
+    // A non-object query root type is reported by `build()`.
+    let GraphQLType::Object(query) = query else {
+        return;
+    };
+    let fields = &mut query.0.fields;
+
+    if has_entities && !fields.contains_key(ENTITIES_FIELD_NAME) {
+        let field_name = FieldName::new(ENTITIES_FIELD_NAME);
+        let param_name = FieldName::new("representations");
+        let representations = ParameterDefinition {
+            default_value: None,
+            default_value_spans: None,
+            description: None,
+            directives: vec![],
+            name: param_name.clone(),
+            owner: ParameterOwner::Field {
+                field_name: field_name.clone(),
+                type_name: query_type_name.clone(),
+            },
+            span: Span::builtin(),
+            type_annotation: TypeAnnotation::list(
+                TypeAnnotation::named("_Any", false),
+                false,
+            ),
+        };
+        fields.insert(field_name.clone(), FieldDefinition {
+            description: None,
+            directives: vec![],
+            name: field_name,
+            parameters: IndexMap::from([(param_name, representations)]),
+            parent_type_name: query_type_name.clone(),
+            span: Span::builtin(),
+            type_annotation: TypeAnnotation::list(
+                TypeAnnotation::named(ENTITY_UNION_NAME, true),
+                false,
+            ),
+        });
+    }
+
+    if !fields.contains_key(SERVICE_FIELD_NAME) {
+        let field_name = FieldName::new(SERVICE_FIELD_NAME);
+        fields.insert(field_name.clone(), FieldDefinition {
+            description: None,
+            directives: vec![],
+            name: field_name,
+            parameters: IndexMap::new(),
+            parent_type_name: query_type_name,
+            span: Span::builtin(),
+            type_annotation: TypeAnnotation::named("_Service", false),
+        });
+    }
+}
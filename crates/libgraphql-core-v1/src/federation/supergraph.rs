@@ -0,0 +1,353 @@
+use crate::directive_annotation::DirectiveAnnotation;
+use crate::error_note::ErrorNote;
+use crate::federation::field_set::key_field_names;
+use crate::federation::subgraph::is_federation_directive;
+use crate::federation::subgraph::is_federation_type;
+use crate::federation::subgraph::remove_entity_resolution;
+use crate::names::FieldName;
+use crate::names::TypeName;
+use crate::schema::Schema;
+use crate::schema::SchemaBuildError;
+use crate::schema::SchemaBuildErrorKind;
+use crate::schema::SchemaCoordinate;
+use crate::schema::SchemaErrors;
+use crate::schema::SchemaMerger;
+use crate::span::Span;
+use crate::types::GraphQLType;
+use crate::value::Value;
+use indexmap::IndexMap;
+
+/// Composes Federation v2 subgraph schemas (each built with
+/// [`build_subgraph()`](crate::federation::build_subgraph)) into a
+/// [`Supergraph`], without a network round-trip or an external
+/// composition tool.
+///
+/// Subgraphs are merged as by [`SchemaMerger`], with the
+/// Federation rules applied first:
+///
+/// * Entities are merged by name; the `@key`s each subgraph
+///   declares are recorded in [`Supergraph::keys_of()`].
+/// * A field marked `@override(from: "other")` takes over the
+///   field from subgraph `other`, whose definition is dropped.
+/// * An object type field resolved by more than one subgraph must
+///   be shareable in each of them: marked `@shareable` (on the
+///   field or its type) or part of one of the type's `@key`s.
+///   `@external` definitions do not resolve the field. Violations
+///   are reported as
+///   [`SchemaBuildErrorKind::FederationFieldNotShareable`].
+///
+/// The generated `_Entity`, `_Any`, and `_Service` types and the
+/// `_entities` / `_service` query fields are not carried over.
+/// Errors (and spans in the composed schemas) point into each
+/// subgraph's own sources.
+///
+/// ```rust
+/// # use libgraphql_core_v1 as libgraphql_core;
+/// use libgraphql_core::federation;
+/// use libgraphql_core::federation::SupergraphComposer;
+/// use libgraphql_core::schema::SchemaCoordinate;
+///
+/// let products = federation::build_subgraph_from_str(r#"
+///   type Query { topProducts: [Product] }
+///   type Product @key(fields: "upc") { upc: ID!, name: String }
+/// "#).unwrap();
+/// let reviews = federation::build_subgraph_from_str(r#"
+///   type Product @key(fields: "upc") { upc: ID!, reviews: [String] }
+/// "#).unwrap();
+///
+/// let supergraph = SupergraphComposer::new()
+///     .add_subgraph("products", products)
+///     .add_subgraph("reviews", reviews)
+///     .compose()
+///     .unwrap();
+/// let product = supergraph.api_schema().object_type("Product").unwrap();
+/// assert_eq!(product.fields().len(), 3);
+///
+/// let reviews = SchemaCoordinate::parse("Product.reviews").unwrap();
+/// assert_eq!(supergraph.resolvers_of(&reviews), ["reviews"]);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct SupergraphComposer {
+    subgraphs: Vec<(String, Schema)>,
+}
+
+/// A `@key` declared on an entity type by one subgraph.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EntityKey {
+    pub(crate) fields: String,
+    pub(crate) resolvable: bool,
+    pub(crate) subgraph_name: String,
+}
+
+impl EntityKey {
+    /// The key's `FieldSet`, as written in `@key(fields:)`.
+    pub fn fields(&self) -> &str { &self.fields }
+
+    /// Whether the subgraph can resolve the entity by this key
+    /// (`@key(resolvable:)`, `true` by default).
+    pub fn resolvable(&self) -> bool { self.resolvable }
+
+    pub fn subgraph_name(&self) -> &str { &self.subgraph_name }
+}
+
+/// The result of [`SupergraphComposer::compose()`].
+#[derive(Clone, Debug)]
+pub struct Supergraph {
+    api_schema: Schema,
+    keys: IndexMap<TypeName, Vec<EntityKey>>,
+    resolvers: IndexMap<SchemaCoordinate, Vec<String>>,
+    schema: Schema,
+}
+
+impl Supergraph {
+    /// The schema exposed to clients: the composed schema without
+    /// any `@inaccessible` element (or the types only they
+    /// reference), and without the Federation directives and their
+    /// supporting types.
+    ///
+    /// No other types are pruned: an entity that no field refers to
+    /// (one subgraphs only resolve through `_entities`) is kept, as
+    /// it is in [`schema()`](Self::schema).
+    pub fn api_schema(&self) -> &Schema { &self.api_schema }
+
+    /// The `@key`s declared on the entity type `type_name`, in
+    /// subgraph order, or an empty slice if it is not an entity.
+    pub fn keys_of(&self, type_name: &str) -> &[EntityKey] {
+        self.keys.get(type_name).map_or(&[], Vec::as_slice)
+    }
+
+    /// The subgraphs that resolve the object type field at
+    /// `coordinate`, in the order they were added, or an empty
+    /// slice if none does.
+    pub fn resolvers_of(&self, coordinate: &SchemaCoordinate) -> &[String] {
+        self.resolvers.get(coordinate).map_or(&[], Vec::as_slice)
+    }
+
+    /// The composed schema, including `@inaccessible` elements and
+    /// Federation directive applications.
+    pub fn schema(&self) -> &Schema { &self.schema }
+}
+
+impl SupergraphComposer {
+    pub fn new() -> Self { Self::default() }
+
+    /// Adds a subgraph schema to compose, built with
+    /// [`build_subgraph()`](crate::federation::build_subgraph).
+    /// `subgraph_name` is the name `@override(from:)` refers to it
+    /// by.
+    pub fn add_subgraph(
+        &mut self,
+        subgraph_name: impl Into<String>,
+        schema: Schema,
+    ) -> &mut Self {
+        self.subgraphs.push((subgraph_name.into(), schema));
+        self
+    }
+
+    /// Composes the added subgraphs, in the order they were added.
+    ///
+    /// Federation rule violations, merge conflicts, and validation
+    /// errors in the composed schema are reported together.
+    // TODO: SchemaErrors wraps Vec<SchemaBuildError> which is
+    // large. Consider boxing once error strategy is finalized.
+    #[allow(clippy::result_large_err)]
+    pub fn compose(&self) -> Result<Supergraph, SchemaErrors> {
+        let mut merger = SchemaMerger::new();
+        for (name, schema) in &self.subgraphs {
+            merger.add_schema(name.clone(), schema.clone());
+        }
+
+        let mut keys = IndexMap::new();
+        let mut resolvers = IndexMap::new();
+        let merged = merger.merge_with(|subgraphs, errors| {
+            for (_, schema) in subgraphs.iter_mut() {
+                remove_entity_resolution(schema);
+            }
+            keys = collect_keys(subgraphs);
+            resolvers = resolve_fields(subgraphs, errors);
+        })?;
+
+        let schema = merged.into_schema();
+        let api_schema = schema
+            .remove_elements_with_directive_and_orphans("inaccessible")?
+            .filter(|coordinate| !is_federation_element(coordinate))?;
+        Ok(Supergraph { api_schema, keys, resolvers, schema })
+    }
+}
+
+/// Collects the `@key`s each subgraph declares, by entity type.
+fn collect_keys(
+    subgraphs: &[(String, Schema)],
+) -> IndexMap<TypeName, Vec<EntityKey>> {
+    let mut keys: IndexMap<TypeName, Vec<EntityKey>> = IndexMap::new();
+    for (subgraph_name, schema) in subgraphs {
+        for type_ in schema.types.values() {
+            for annotation in annotations_named(type_.directives(), "key") {
+                let Some(Value::String(fields)) =
+                    annotation.arguments().get("fields")
+                else {
+                    continue;
+                };
+                let resolvable = !matches!(
+                    annotation.arguments().get("resolvable"),
+                    Some(Value::Boolean(false)),
+                );
+                keys.entry(type_.name().clone()).or_default().push(EntityKey {
+                    fields: fields.clone(),
+                    resolvable,
+                    subgraph_name: subgraph_name.clone(),
+                });
+            }
+        }
+    }
+    keys
+}
+
+/// One subgraph's definition of an object type field.
+struct FieldCopy {
+    external: bool,
+    overridden: bool,
+    override_from: Option<String>,
+    shareable: bool,
+    span: Span,
+    subgraph_index: usize,
+}
+
+/// Applies `@override` (dropping each overridden field from the
+/// subgraph it is taken from) and checks that every field resolved
+/// by several subgraphs is shareable in each. Returns the subgraphs
+/// that resolve each object type field.
+fn resolve_fields(
+    subgraphs: &mut [(String, Schema)],
+    errors: &mut Vec<SchemaBuildError>,
+) -> IndexMap<SchemaCoordinate, Vec<String>> {
+    let mut copies: IndexMap<(TypeName, FieldName), Vec<FieldCopy>> =
+        IndexMap::new();
+    for (subgraph_index, (_, schema)) in subgraphs.iter().enumerate() {
+        for type_ in schema.types.values() {
+            let GraphQLType::Object(object) = type_ else { continue };
+            let type_shareable =
+                annotations_named(object.directives(), "shareable").next().is_some();
+            let type_external =
+                annotations_named(object.directives(), "external").next().is_some();
+            let key_fields: Vec<String> = annotations_named(object.directives(), "key")
+                .filter_map(|annotation| match annotation.arguments().get("fields") {
+                    Some(Value::String(fields)) => Some(key_field_names(fields)),
+                    _ => None,
+                })
+                .flatten()
+                .collect();
+
+            for field in object.fields().values() {
+                let has = |name| {
+                    annotations_named(field.directives(), name).next().is_some()
+                };
+                let override_from = annotations_named(field.directives(), "override")
+                    .find_map(|annotation| match annotation.arguments().get("from") {
+                        Some(Value::String(from)) => Some(from.clone()),
+                        _ => None,
+                    });
+                copies
+                    .entry((object.name().clone(), field.name().clone()))
+                    .or_default()
+                    .push(FieldCopy {
+                        external: type_external || has("external"),
+                        overridden: false,
+                        override_from,
+                        shareable: type_shareable
+                            || has("shareable")
+                            || key_fields.iter().any(|key| key == field.name().as_str()),
+                        span: field.span(),
+                        subgraph_index,
+                    });
+            }
+        }
+    }
+
+    let mut resolvers = IndexMap::new();
+    for ((type_name, field_name), mut copies) in copies {
+        // https://www.apollographql.com/docs/graphos/reference/federation/directives#override
+        let overrides: Vec<(usize, String)> = copies.iter()
+            .filter_map(|copy| {
+                let from = copy.override_from.clone()?;
+                Some((copy.subgraph_index, from))
+            })
+            .collect();
+        for (overriding_index, from) in overrides {
+            for copy in &mut copies {
+                if copy.subgraph_index != overriding_index
+                    && subgraphs[copy.subgraph_index].0 == from
+                {
+                    copy.overridden = true;
+                }
+            }
+        }
+        for copy in copies.iter().filter(|copy| copy.overridden) {
+            let schema = &mut subgraphs[copy.subgraph_index].1;
+            if let Some(GraphQLType::Object(object)) =
+                schema.types.get_mut(&type_name)
+            {
+                object.0.fields.shift_remove(&field_name);
+            }
+        }
+
+        let resolving: Vec<&FieldCopy> = copies.iter()
+            .filter(|copy| !copy.external && !copy.overridden)
+            .collect();
+        let subgraph_names: Vec<String> = resolving.iter()
+            .map(|copy| subgraphs[copy.subgraph_index].0.clone())
+            .collect();
+        let coordinate = SchemaCoordinate::Member {
+            member_name: field_name.to_string(),
+            type_name: type_name.clone(),
+        };
+
+        // https://www.apollographql.com/docs/graphos/reference/federation/directives#shareable
+        let not_shareable = resolving.iter().find(|copy| !copy.shareable);
+        if let (true, Some(not_shareable)) = (resolving.len() > 1, not_shareable) {
+            let mut notes: Vec<ErrorNote> = resolving.iter()
+                .filter(|copy| copy.subgraph_index != not_shareable.subgraph_index)
+                .map(|copy| ErrorNote::general_with_span(
+                    format!(
+                        "also resolved by `{}` here",
+                        subgraphs[copy.subgraph_index].0,
+                    ),
+                    copy.span,
+                ))
+                .collect();
+            notes.push(ErrorNote::help(
+                "mark the field `@shareable` in every subgraph that \
+                resolves it, or use `@override` to move it",
+            ));
+            errors.push(SchemaBuildError::new(
+                SchemaBuildErrorKind::FederationFieldNotShareable {
+                    coordinate: coordinate.to_string(),
+                    subgraph_names: subgraph_names.clone(),
+                },
+                not_shareable.span,
+                notes,
+            ));
+        }
+        resolvers.insert(coordinate, subgraph_names);
+    }
+    resolvers
+}
+
+fn annotations_named<'a>(
+    annotations: &'a [DirectiveAnnotation],
+    name: &'a str,
+) -> impl Iterator<Item = &'a DirectiveAnnotation> {
+    annotations.iter().filter(move |annotation| annotation.name().as_str() == name)
+}
+
+/// Whether `coordinate` names (or is within) a Federation directive
+/// or supporting type, which the API schema omits.
+fn is_federation_element(coordinate: &SchemaCoordinate) -> bool {
+    match coordinate {
+        SchemaCoordinate::Directive { directive_name }
+        | SchemaCoordinate::DirectiveArgument { directive_name, .. } => {
+            is_federation_directive(directive_name.as_str())
+        },
+        _ => is_federation_type(coordinate.root_name()),
+    }
+}
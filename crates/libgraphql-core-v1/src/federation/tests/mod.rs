@@ -0,0 +1,2 @@
+mod subgraph_tests;
+mod supergraph_tests;
@@ -0,0 +1,188 @@
+use crate::federation::build_subgraph;
+use crate::federation::build_subgraph_from_str;
+use crate::federation::service_sdl;
+use crate::schema::Schema;
+use crate::schema::SchemaBuildErrorKind;
+use crate::schema::SchemaBuilder;
+use crate::schema::TypeValidationErrorKind;
+
+fn build(source: &str) -> Schema {
+    build_subgraph_from_str(source)
+        .unwrap_or_else(|errs| panic!("subgraph failed to build:\n{errs}"))
+}
+
+/// The reasons of every `InvalidFieldSet` error reported for
+/// `source`.
+fn field_set_errors(source: &str) -> Vec<String> {
+    let errors = build_subgraph_from_str(source).unwrap_err();
+    errors.errors()
+        .iter()
+        .filter_map(|error| match error.kind() {
+            SchemaBuildErrorKind::TypeValidation(error) => {
+                match error.kind() {
+                    TypeValidationErrorKind::InvalidFieldSet {
+                        reason, ..
+                    } => Some(reason.clone()),
+                    _ => None,
+                }
+            },
+            _ => None,
+        })
+        .collect()
+}
+
+// Verifies that building a subgraph injects the Federation
+// directives and supporting types, the `_Entity` union of every
+// `@key` object type, and the `_entities` / `_service` query fields.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn injects_federation_definitions() {
+    let schema = build(r#"
+type Query { me: User }
+type User @key(fields: "id") @key(fields: "org { id } login") {
+  id: ID!
+  login: String!
+  org: Org @shareable
+}
+type Org @key(fields: "id", resolvable: false) { id: ID! }
+type Review { body: String @tag(name: "public") }
+"#);
+
+    for name in [
+        "key", "shareable", "external", "requires", "provides",
+        "override", "inaccessible", "tag", "link",
+    ] {
+        assert!(schema.directive(name).is_some(), "missing @{name}");
+    }
+    for name in ["FieldSet", "_Any", "link__Import", "link__Purpose", "_Service"] {
+        assert!(schema.get_type(name).is_some(), "missing {name}");
+    }
+
+    let entity = schema.union_type("_Entity").unwrap();
+    let members: Vec<&str> = entity.members()
+        .iter()
+        .map(|member| member.value.as_str())
+        .collect();
+    assert_eq!(members, ["User", "Org"]);
+
+    let query = schema.query_type();
+    let fields: Vec<&str> = query.fields()
+        .keys()
+        .map(|name| name.as_str())
+        .collect();
+    assert_eq!(fields, ["me", "_entities", "_service"]);
+    let entities = query.field("_entities").unwrap();
+    assert_eq!(entities.type_annotation().to_string(), "[_Entity]!");
+    assert_eq!(
+        entities.parameters()["representations"].type_annotation().to_string(),
+        "[_Any!]!",
+    );
+    assert_eq!(
+        query.field("_service").unwrap().type_annotation().to_string(),
+        "_Service!",
+    );
+}
+
+// Verifies that a subgraph without entities or a query type gets a
+// query type with only `_service`, and that definitions the subgraph
+// already declares are kept rather than injected again.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn creates_query_type_and_keeps_declared_definitions() {
+    let mut builder = SchemaBuilder::new();
+    builder.load_str("\
+\"Tags the element.\"
+directive @tag(name: String!) repeatable on FIELD_DEFINITION | OBJECT
+type Mutation { ping: Boolean @tag(name: \"ops\") }
+").unwrap();
+    let schema = build_subgraph(builder).unwrap();
+
+    assert!(schema.union_type("_Entity").is_none());
+    let fields: Vec<&str> = schema.query_type()
+        .fields()
+        .keys()
+        .map(|name| name.as_str())
+        .collect();
+    assert_eq!(fields, ["_service"]);
+    assert_eq!(
+        schema.directive("tag").unwrap().description(),
+        Some("Tags the element."),
+    );
+}
+
+// Verifies that invalid `@key(fields:)` selections are reported:
+// syntax errors, unknown fields, missing and unexpected
+// sub-selections, fields returning unions, aliases, arguments, and
+// fragments.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn reports_invalid_key_field_sets() {
+    let reasons = field_set_errors(r#"
+type Query { a: Int }
+type Org { id: ID!, node: Node }
+union Node = Org
+type A @key(fields: "id {") { id: ID! }
+type B @key(fields: "uuid") { id: ID! }
+type C @key(fields: "org") { org: Org }
+type D @key(fields: "id { value }") { id: ID! }
+type E @key(fields: "org { node { ... on Org { id } } }") { org: Org }
+type F @key(fields: "key: id") { id: ID! }
+type G @key(fields: "id(format: 1)") { id: ID! }
+type H @key(fields: "... on H { id }") { id: ID! }
+"#);
+    assert_eq!(reasons.len(), 8, "{reasons:#?}");
+    assert!(reasons[0].starts_with("`id {` is not a selection set"), "{}", reasons[0]);
+    assert_eq!(reasons[1], "`B` has no field `uuid`");
+    assert_eq!(
+        reasons[2],
+        "`C.org` returns object type `Org` and must have a selection of \
+        subfields",
+    );
+    assert_eq!(
+        reasons[3],
+        "`D.id` returns leaf type `ID` and must not have a selection of \
+        subfields",
+    );
+    assert_eq!(
+        reasons[4],
+        "`Org.node` returns union `Node`, which is not allowed in a key",
+    );
+    assert_eq!(reasons[5], "alias `key` on `id` is not allowed");
+    assert_eq!(reasons[6], "arguments on `id` are not allowed");
+    assert_eq!(reasons[7], "fragments are not allowed");
+}
+
+// Verifies that the `_service { sdl }` form omits every generated
+// definition and links the Federation directives instead.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn prints_service_sdl() {
+    let schema = build(r#"
+type Query { me: User }
+type User @key(fields: "id") { id: ID!, name: String @shareable }
+"#);
+    assert_eq!(service_sdl(&schema), "\
+extend schema @link(url: \"https://specs.apollo.dev/federation/v2.7\", \
+import: [\"@key\", \"@shareable\", \"@external\", \"@requires\", \
+\"@provides\", \"@override\", \"@inaccessible\", \"@tag\"])
+
+type Query {
+  me: User
+}
+
+type User @key(fields: \"id\") {
+  id: ID!
+  name: String @shareable
+}
+");
+
+    let entity_only = build(r#"type User @key(fields: "id") { id: ID! }"#);
+    let sdl = service_sdl(&entity_only);
+    assert!(sdl.ends_with("\n\ntype User @key(fields: \"id\") {\n  id: ID!\n}\n"), "{sdl}");
+    assert!(!sdl.contains("schema {"), "{sdl}");
+    assert!(!sdl.contains("Query"), "{sdl}");
+}
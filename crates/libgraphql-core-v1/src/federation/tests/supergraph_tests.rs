@@ -0,0 +1,186 @@
+use crate::federation::build_subgraph_from_str;
+use crate::federation::Supergraph;
+use crate::federation::SupergraphComposer;
+use crate::schema::Schema;
+use crate::schema::SchemaBuildErrorKind;
+use crate::schema::SchemaCoordinate;
+use crate::schema::SchemaErrors;
+use crate::schema_source_map::resolve_span;
+
+fn subgraph(source: &str) -> Schema {
+    build_subgraph_from_str(source)
+        .unwrap_or_else(|errs| panic!("subgraph failed to build:\n{errs}"))
+}
+
+fn compose(subgraphs: &[(&str, &str)]) -> Result<Supergraph, SchemaErrors> {
+    let mut composer = SupergraphComposer::new();
+    for (name, source) in subgraphs {
+        composer.add_subgraph(*name, subgraph(source));
+    }
+    composer.compose()
+}
+
+fn coordinate(source: &str) -> SchemaCoordinate {
+    SchemaCoordinate::parse(source).unwrap()
+}
+
+const PRODUCTS: &str = r#"
+type Query { topProducts: [Product] }
+type Product @key(fields: "upc") {
+  upc: ID!
+  name: String @shareable
+  price: Int
+  cost: Int @inaccessible
+}
+"#;
+
+const INVENTORY: &str = r#"
+type Product @key(fields: "upc") {
+  upc: ID!
+  name: String @shareable
+  price: Int @override(from: "products")
+  inStock: Boolean
+  weight: Int @external
+}
+"#;
+
+const REVIEWS: &str = r#"
+type Query { reviews: [Review] }
+type Review { body: String, product: Product }
+type Product @key(fields: "upc", resolvable: false) {
+  upc: ID!
+  weight: Int
+}
+"#;
+
+// Verifies that entities are merged by name with every subgraph's
+// keys recorded, that `@override` moves a field to the overriding
+// subgraph, that `@external` definitions do not resolve a field,
+// and that the API schema hides `@inaccessible` elements and the
+// Federation definitions.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn composes_entities() {
+    let supergraph = compose(&[
+        ("products", PRODUCTS),
+        ("inventory", INVENTORY),
+        ("reviews", REVIEWS),
+    ]).unwrap();
+
+    let keys: Vec<(&str, &str, bool)> = supergraph.keys_of("Product")
+        .iter()
+        .map(|key| (key.subgraph_name(), key.fields(), key.resolvable()))
+        .collect();
+    assert_eq!(keys, [
+        ("products", "upc", true),
+        ("inventory", "upc", true),
+        ("reviews", "upc", false),
+    ]);
+    assert!(supergraph.keys_of("Review").is_empty());
+
+    assert_eq!(
+        supergraph.resolvers_of(&coordinate("Product.upc")),
+        ["products", "inventory", "reviews"],
+    );
+    assert_eq!(
+        supergraph.resolvers_of(&coordinate("Product.name")),
+        ["products", "inventory"],
+    );
+    assert_eq!(supergraph.resolvers_of(&coordinate("Product.price")), ["inventory"]);
+    assert_eq!(supergraph.resolvers_of(&coordinate("Product.weight")), ["reviews"]);
+
+    assert!(supergraph.schema().object_type("Product").unwrap().field("cost").is_some());
+    assert_eq!(supergraph.api_schema().to_sdl(), "\
+type Query {
+  topProducts: [Product]
+  reviews: [Review]
+}
+
+type Product {
+  upc: ID!
+  name: String
+  price: Int
+  inStock: Boolean
+  weight: Int
+}
+
+type Review {
+  body: String
+  product: Product
+}
+");
+}
+
+// Verifies that the API schema drops the types only `@inaccessible`
+// elements refer to, but keeps an entity no field refers to (one
+// the router only reaches through `_entities`).
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn api_schema_keeps_entities_only_reachable_through_entities() {
+    let supergraph = compose(&[
+        ("products", r#"
+type Query { topProducts: [Product] }
+type Product @key(fields: "upc") {
+  upc: ID!
+  audit: AuditLog @inaccessible
+}
+type AuditLog { entries: [String] }
+"#),
+        ("shipping", r#"
+type Shipment @key(fields: "id") { id: ID!, carrier: String }
+"#),
+    ]).unwrap();
+
+    assert!(supergraph.schema().object_type("AuditLog").is_some());
+    assert!(supergraph.api_schema().object_type("AuditLog").is_none());
+    assert_eq!(supergraph.api_schema().to_sdl(), "\
+type Query {
+  topProducts: [Product]
+}
+
+type Product {
+  upc: ID!
+}
+
+type Shipment {
+  id: ID!
+  carrier: String
+}
+");
+}
+
+// Verifies that a field resolved by several subgraphs without being
+// shareable in all of them is reported at the non-shareable
+// definition, with a note pointing into each other subgraph's source.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn reports_non_shareable_fields() {
+    let errors = compose(&[
+        ("products", PRODUCTS),
+        ("shipping", "\n\ntype Product @key(fields: \"upc\") { upc: ID!, name: String }"),
+    ]).unwrap_err();
+
+    assert_eq!(errors.errors().len(), 1, "{errors}");
+    let error = &errors.errors()[0];
+    assert_eq!(
+        error.kind(),
+        &SchemaBuildErrorKind::FederationFieldNotShareable {
+            coordinate: "Product.name".to_string(),
+            subgraph_names: vec!["products".to_string(), "shipping".to_string()],
+        },
+    );
+
+    let source_maps = errors.source_maps();
+    let span = resolve_span(source_maps, error.span()).unwrap();
+    assert_eq!(span.start.line, 2);
+    let note_span = error.notes()[0].span.unwrap();
+    let note_span = resolve_span(source_maps, note_span).unwrap();
+    assert_eq!(note_span.start.line, 4);
+
+    let detailed = error.format_detailed(source_maps);
+    assert!(detailed.contains("name: String @shareable"), "{detailed}");
+    assert!(detailed.contains("also resolved by `products` here"), "{detailed}");
+}
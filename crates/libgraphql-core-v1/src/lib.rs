@@ -12,7 +12,8 @@
 //! ## Architecture
 //!
 //! - **Name newtypes** (`TypeName`, `FieldName`, etc.) prevent
-//!   cross-domain string confusion
+//!   cross-domain string confusion and wrap interned
+//!   [`Symbol`](libgraphql_parser::Symbol)s for cheap comparison
 //! - **Builder pattern** for type-safe, incremental construction of
 //!   schemas and operations
 //! - **Owned types** (no lifetime parameters) enable caching,
@@ -22,18 +23,25 @@
 //!
 //! ## Usage
 //!
-//! ```ignore
+//! ```rust
+//! # use libgraphql_core_v1 as libgraphql_core;
 //! use libgraphql_core::schema::SchemaBuilder;
 //!
 //! let schema = SchemaBuilder::build_from_str(
 //!     "type Query { hello: String }",
 //! ).unwrap();
+//! assert!(schema.query_type().field("hello").is_some());
 //! ```
 
+pub(crate) mod diagnostic_renderer;
 pub mod directive_annotation;
 pub mod error_note;
+pub mod federation;
+pub mod lint;
 pub mod located;
 pub mod names;
+pub mod relay;
+pub mod scalars;
 pub mod schema;
 pub mod schema_source_map;
 pub mod span;
@@ -44,6 +52,7 @@ pub mod value;
 
 pub use crate::located::Located;
 pub use crate::schema_source_map::LineCol;
+pub use crate::schema_source_map::ResolvedSpan;
 pub use crate::schema_source_map::SchemaSourceMap;
 pub use crate::span::Span;
 
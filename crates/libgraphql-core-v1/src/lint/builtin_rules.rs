@@ -0,0 +1,252 @@
+use crate::lint::lint_config::LintConfig;
+use crate::lint::lint_diagnostic::LintSeverity;
+use crate::lint::lint_rule::LintContext;
+use crate::lint::lint_rule::LintRule;
+use crate::relay;
+use crate::schema::Schema;
+use crate::schema::SchemaElement;
+use crate::types::DeprecationState;
+use crate::types::GraphQLType;
+use crate::types::TypeAnnotation;
+
+/// The built-in rules `config` enables, with their configured
+/// severities, in [`LintConfig`] field order.
+pub(crate) fn enabled(
+    config: &LintConfig,
+) -> Vec<(&'static dyn LintRule, LintSeverity)> {
+    let rules: [(&'static dyn LintRule, Option<LintSeverity>); 8] = [
+        (&DeprecationReasons, config.deprecation_reasons),
+        (&Descriptions, config.descriptions),
+        (&EnumValueCase, config.enum_value_case),
+        (&FieldNameCase, config.field_name_case),
+        (&IdFieldNames, config.id_field_names),
+        (&InputTypeSuffix, config.input_type_suffix),
+        (&RelayConnections, config.relay_connections),
+        (&TypeNameCase, config.type_name_case),
+    ];
+    rules.into_iter()
+        .filter_map(|(rule, severity)| Some((rule, severity?)))
+        .collect()
+}
+
+/// Calls `f` on every non-built-in directive definition, type,
+/// field, argument, input field, and enum value in `schema`.
+fn for_each_element<'a>(schema: &'a Schema, mut f: impl FnMut(SchemaElement<'a>)) {
+    for def in schema.directive_defs().values().filter(|def| !def.is_builtin()) {
+        f(SchemaElement::Directive(def));
+        def.parameters().values().for_each(|param| {
+            f(SchemaElement::DirectiveArgument(param));
+        });
+    }
+    for type_ in schema.types().values().filter(|type_| !type_.is_builtin()) {
+        f(SchemaElement::Type(type_));
+        let fields = match type_ {
+            GraphQLType::Enum(enum_type) => {
+                enum_type.values().values().for_each(|value| {
+                    f(SchemaElement::EnumValue(value));
+                });
+                continue;
+            },
+            GraphQLType::InputObject(input_obj) => {
+                input_obj.fields().values().for_each(|field| {
+                    f(SchemaElement::InputField(field));
+                });
+                continue;
+            },
+            GraphQLType::Interface(iface) => iface.fields(),
+            GraphQLType::Object(obj) => obj.fields(),
+            GraphQLType::Scalar(_) | GraphQLType::Union(_) => continue,
+        };
+        for field in fields.values() {
+            f(SchemaElement::Field(field));
+            field.parameters().values().for_each(|param| {
+                f(SchemaElement::FieldArgument(param));
+            });
+        }
+    }
+}
+
+fn is_pascal_case(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_uppercase())
+        && name.chars().all(|c| c.is_ascii_alphanumeric())
+}
+
+fn is_camel_case(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_lowercase())
+        && name.chars().all(|c| c.is_ascii_alphanumeric())
+}
+
+fn is_screaming_snake_case(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_uppercase())
+        && name.chars().all(|c| {
+            c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_'
+        })
+}
+
+// ---------------------------------------------------------
+// Rules
+// ---------------------------------------------------------
+
+struct DeprecationReasons;
+
+impl LintRule for DeprecationReasons {
+    fn name(&self) -> &str { "deprecation-reasons" }
+
+    fn check(&self, schema: &Schema, cx: &mut LintContext<'_>) {
+        for_each_element(schema, |element| {
+            let state = DeprecationState::from_directives(element.directives());
+            let DeprecationState::Deprecated { reason } = state else {
+                return;
+            };
+            if reason.is_none_or(|reason| reason.trim().is_empty()) {
+                cx.report(element, format!(
+                    "`{}` is deprecated without a reason",
+                    element.coordinate(),
+                ));
+            }
+        });
+    }
+}
+
+struct Descriptions;
+
+impl LintRule for Descriptions {
+    fn name(&self) -> &str { "descriptions" }
+
+    fn check(&self, schema: &Schema, cx: &mut LintContext<'_>) {
+        for_each_element(schema, |element| {
+            let kind = match element {
+                SchemaElement::Type(_) => "type",
+                SchemaElement::Field(_) => "field",
+                SchemaElement::InputField(_) => "input field",
+                _ => return,
+            };
+            if element.description().is_none_or(|d| d.trim().is_empty()) {
+                cx.report(element, format!(
+                    "{kind} `{}` has no description",
+                    element.coordinate(),
+                ));
+            }
+        });
+    }
+}
+
+struct EnumValueCase;
+
+impl LintRule for EnumValueCase {
+    fn name(&self) -> &str { "enum-value-case" }
+
+    fn check(&self, schema: &Schema, cx: &mut LintContext<'_>) {
+        for_each_element(schema, |element| {
+            let SchemaElement::EnumValue(value) = element else { return };
+            if !is_screaming_snake_case(value.name().as_str()) {
+                cx.report(element, format!(
+                    "enum value `{}` should be SCREAMING_SNAKE_CASE",
+                    element.coordinate(),
+                ));
+            }
+        });
+    }
+}
+
+struct FieldNameCase;
+
+impl LintRule for FieldNameCase {
+    fn name(&self) -> &str { "field-name-case" }
+
+    fn check(&self, schema: &Schema, cx: &mut LintContext<'_>) {
+        for_each_element(schema, |element| {
+            let (kind, name) = match element {
+                SchemaElement::Field(field) => ("field", field.name()),
+                SchemaElement::InputField(field) => ("input field", field.name()),
+                _ => return,
+            };
+            if !is_camel_case(name.as_str()) {
+                cx.report(element, format!(
+                    "{kind} `{}` should be camelCase",
+                    element.coordinate(),
+                ));
+            }
+        });
+    }
+}
+
+struct IdFieldNames;
+
+impl LintRule for IdFieldNames {
+    fn name(&self) -> &str { "id-field-names" }
+
+    fn check(&self, schema: &Schema, cx: &mut LintContext<'_>) {
+        for_each_element(schema, |element| {
+            let SchemaElement::Field(field) = element else { return };
+            let TypeAnnotation::Named(named) = field.type_annotation() else {
+                return;
+            };
+            if named.type_name().as_str() == "ID" && field.name().as_str() != "id" {
+                cx.report(element, format!(
+                    "field `{}` has type `ID` but is not named `id`",
+                    element.coordinate(),
+                ));
+            }
+        });
+    }
+}
+
+struct InputTypeSuffix;
+
+impl LintRule for InputTypeSuffix {
+    fn name(&self) -> &str { "input-type-suffix" }
+
+    fn check(&self, schema: &Schema, cx: &mut LintContext<'_>) {
+        for_each_element(schema, |element| {
+            let SchemaElement::Type(GraphQLType::InputObject(input_obj)) = element
+            else {
+                return;
+            };
+            let name = input_obj.name();
+            if !name.as_str().ends_with("Input") {
+                cx.report(element, format!(
+                    "input type `{name}` should be named `{name}Input`",
+                ));
+            }
+        });
+    }
+}
+
+/// Reports the cursor connection violations found by
+/// [`validate_relay()`](crate::relay::validate_relay) — the shape of
+/// `*Connection`, edge, and `PageInfo` types, and the pagination
+/// arguments of fields returning connections.
+struct RelayConnections;
+
+impl LintRule for RelayConnections {
+    fn name(&self) -> &str { "relay-connections" }
+
+    fn check(&self, schema: &Schema, cx: &mut LintContext<'_>) {
+        for violation in relay::validate_relay_connections(schema) {
+            cx.report_at(
+                violation.coordinate().clone(),
+                violation.span(),
+                violation.to_string(),
+            );
+        }
+    }
+}
+
+struct TypeNameCase;
+
+impl LintRule for TypeNameCase {
+    fn name(&self) -> &str { "type-name-case" }
+
+    fn check(&self, schema: &Schema, cx: &mut LintContext<'_>) {
+        for_each_element(schema, |element| {
+            let SchemaElement::Type(type_) = element else { return };
+            if !is_pascal_case(type_.name().as_str()) {
+                cx.report(element, format!(
+                    "type `{}` should be PascalCase",
+                    type_.name(),
+                ));
+            }
+        });
+    }
+}
@@ -0,0 +1,88 @@
+use crate::lint::lint_diagnostic::LintSeverity;
+
+/// Enables the built-in lint rules run by a
+/// [`Linter`](crate::lint::Linter), and sets the severity of each.
+///
+/// Each field configures one rule (named in its documentation):
+/// `None` disables it, `Some(severity)` enables it. The default
+/// enables every rule as a [`LintSeverity::Warning`].
+///
+/// # Example
+///
+/// ```rust
+/// # use libgraphql_core_v1 as libgraphql_core;
+/// use libgraphql_core::lint::LintConfig;
+/// use libgraphql_core::lint::LintSeverity;
+///
+/// // Only enforce naming conventions, failing on type names
+/// let naming = LintConfig {
+///     type_name_case: Some(LintSeverity::Error),
+///     field_name_case: Some(LintSeverity::Warning),
+///     enum_value_case: Some(LintSeverity::Warning),
+///     ..LintConfig::none()
+/// };
+/// # assert!(naming.descriptions.is_none());
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct LintConfig {
+    /// `deprecation-reasons`: every `@deprecated` has a non-empty
+    /// `reason`.
+    pub deprecation_reasons: Option<LintSeverity>,
+
+    /// `descriptions`: every type, field, and input field has a
+    /// description.
+    pub descriptions: Option<LintSeverity>,
+
+    /// `enum-value-case`: enum values are `SCREAMING_SNAKE_CASE`.
+    pub enum_value_case: Option<LintSeverity>,
+
+    /// `field-name-case`: fields and input fields are `camelCase`.
+    pub field_name_case: Option<LintSeverity>,
+
+    /// `id-field-names`: fields of type `ID` are named `id`.
+    pub id_field_names: Option<LintSeverity>,
+
+    /// `input-type-suffix`: input object type names end in `Input`.
+    pub input_type_suffix: Option<LintSeverity>,
+
+    /// `relay-connections`: `*Connection` types follow the
+    /// [Relay connection spec](https://relay.dev/graphql/connections.htm),
+    /// as checked by [`validate_relay()`](crate::relay::validate_relay).
+    pub relay_connections: Option<LintSeverity>,
+
+    /// `type-name-case`: type names are `PascalCase`.
+    pub type_name_case: Option<LintSeverity>,
+}
+
+impl LintConfig {
+    /// Returns a config with every built-in rule disabled, e.g. to
+    /// run only user-defined rules or to enable rules one by one.
+    pub fn none() -> Self {
+        Self {
+            deprecation_reasons: None,
+            descriptions: None,
+            enum_value_case: None,
+            field_name_case: None,
+            id_field_names: None,
+            input_type_suffix: None,
+            relay_connections: None,
+            type_name_case: None,
+        }
+    }
+}
+
+impl Default for LintConfig {
+    fn default() -> Self {
+        let warning = Some(LintSeverity::Warning);
+        Self {
+            deprecation_reasons: warning,
+            descriptions: warning,
+            enum_value_case: warning,
+            field_name_case: warning,
+            id_field_names: warning,
+            input_type_suffix: warning,
+            relay_connections: warning,
+            type_name_case: warning,
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use crate::schema::SchemaCoordinate;
+use crate::span::Span;
+
+/// How seriously a [`LintDiagnostic`] should be treated (e.g.
+/// whether it fails a CI check).
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum LintSeverity {
+    /// The schema must be fixed.
+    Error,
+
+    /// The schema should be fixed, or the violation acknowledged.
+    Warning,
+}
+
+impl std::fmt::Display for LintSeverity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Error => "error",
+            Self::Warning => "warning",
+        })
+    }
+}
+
+/// A violation of a [`LintRule`](crate::lint::LintRule), as
+/// reported by [`Linter::lint()`](crate::lint::Linter::lint).
+///
+/// Every diagnostic carries:
+/// - The [`rule_name`](Self::rule_name) of the rule that reported it
+/// - Its [`severity`](Self::severity), as configured for that rule
+/// - The [`coordinate`](Self::coordinate) and [`span`](Self::span)
+///   of the offending schema element
+#[derive(Clone, Debug, PartialEq)]
+pub struct LintDiagnostic {
+    coordinate: SchemaCoordinate,
+    message: String,
+    rule_name: String,
+    severity: LintSeverity,
+    span: Span,
+}
+
+impl LintDiagnostic {
+    pub(crate) fn new(
+        rule_name: String,
+        severity: LintSeverity,
+        coordinate: SchemaCoordinate,
+        span: Span,
+        message: String,
+    ) -> Self {
+        Self { coordinate, message, rule_name, severity, span }
+    }
+
+    pub fn coordinate(&self) -> &SchemaCoordinate { &self.coordinate }
+    pub fn message(&self) -> &str { &self.message }
+    pub fn rule_name(&self) -> &str { &self.rule_name }
+    pub fn severity(&self) -> LintSeverity { self.severity }
+    /// Resolve with the linted schema's
+    /// [`resolve_span()`](crate::schema::Schema::resolve_span).
+    pub fn span(&self) -> Span { self.span }
+}
+
+impl std::fmt::Display for LintDiagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}[{}]: {}", self.severity, self.rule_name, self.message)
+    }
+}
@@ -0,0 +1,107 @@
+use crate::lint::lint_diagnostic::LintDiagnostic;
+use crate::lint::lint_diagnostic::LintSeverity;
+use crate::schema::Schema;
+use crate::schema::SchemaCoordinate;
+use crate::schema::SchemaElement;
+use crate::span::Span;
+
+/// A check run over a validated [`Schema`] by a
+/// [`Linter`](crate::lint::Linter).
+///
+/// The built-in rules are enabled through
+/// [`LintConfig`](crate::lint::LintConfig); user-defined rules are
+/// added with [`Linter::add_rule()`](crate::lint::Linter::add_rule).
+///
+/// ```rust
+/// # use libgraphql_core_v1 as libgraphql_core;
+/// use libgraphql_core::lint::LintConfig;
+/// use libgraphql_core::lint::LintContext;
+/// use libgraphql_core::lint::LintRule;
+/// use libgraphql_core::lint::Linter;
+/// use libgraphql_core::schema::Schema;
+/// use libgraphql_core::schema::SchemaBuilder;
+/// use libgraphql_core::schema::SchemaElement;
+///
+/// /// Forbids the `JSON` escape hatch.
+/// struct NoJsonFields;
+///
+/// impl LintRule for NoJsonFields {
+///     fn name(&self) -> &str { "no-json-fields" }
+///
+///     fn check(&self, schema: &Schema, cx: &mut LintContext<'_>) {
+///         for object in schema.object_types() {
+///             for field in object.fields().values() {
+///                 if field.return_type_name().as_str() == "JSON" {
+///                     cx.report(SchemaElement::Field(field), "avoid `JSON` fields");
+///                 }
+///             }
+///         }
+///     }
+/// }
+///
+/// let schema = SchemaBuilder::build_from_str(
+///     "scalar JSON type Query { config: JSON }",
+/// ).unwrap();
+/// let mut linter = Linter::new(LintConfig::none());
+/// linter.add_rule(NoJsonFields);
+/// let diagnostics = linter.lint(&schema);
+/// assert_eq!(
+///     diagnostics[0].to_string(),
+///     "warning[no-json-fields]: avoid `JSON` fields",
+/// );
+/// ```
+pub trait LintRule: Send + Sync {
+    /// A unique, kebab-case name identifying the rule in
+    /// diagnostics (e.g. `"type-name-case"`).
+    fn name(&self) -> &str;
+
+    /// The severity of this rule's diagnostics.
+    fn severity(&self) -> LintSeverity { LintSeverity::Warning }
+
+    /// Checks `schema`, reporting each violation to `cx`.
+    fn check(&self, schema: &Schema, cx: &mut LintContext<'_>);
+}
+
+/// Collects the diagnostics reported by one [`LintRule`], tagging
+/// each with the rule's name and severity.
+pub struct LintContext<'a> {
+    diagnostics: &'a mut Vec<LintDiagnostic>,
+    rule_name: &'a str,
+    severity: LintSeverity,
+}
+
+impl<'a> LintContext<'a> {
+    pub(crate) fn new(
+        diagnostics: &'a mut Vec<LintDiagnostic>,
+        rule_name: &'a str,
+        severity: LintSeverity,
+    ) -> Self {
+        Self { diagnostics, rule_name, severity }
+    }
+
+    /// Reports a violation at `element`'s coordinate and span.
+    pub fn report(
+        &mut self,
+        element: SchemaElement<'_>,
+        message: impl Into<String>,
+    ) {
+        self.report_at(element.coordinate(), element.span(), message);
+    }
+
+    /// Reports a violation at an explicit coordinate and span (e.g.
+    /// the span of a field's type annotation).
+    pub fn report_at(
+        &mut self,
+        coordinate: SchemaCoordinate,
+        span: Span,
+        message: impl Into<String>,
+    ) {
+        self.diagnostics.push(LintDiagnostic::new(
+            self.rule_name.to_string(),
+            self.severity,
+            coordinate,
+            span,
+            message.into(),
+        ));
+    }
+}
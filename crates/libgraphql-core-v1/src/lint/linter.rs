@@ -0,0 +1,72 @@
+use crate::lint::builtin_rules;
+use crate::lint::lint_config::LintConfig;
+use crate::lint::lint_diagnostic::LintDiagnostic;
+use crate::lint::lint_rule::LintContext;
+use crate::lint::lint_rule::LintRule;
+use crate::schema::Schema;
+
+/// Runs the built-in lint rules enabled by a [`LintConfig`], plus
+/// any user-defined [`LintRule`]s, over a validated [`Schema`].
+///
+/// ```rust
+/// # use libgraphql_core_v1 as libgraphql_core;
+/// use libgraphql_core::lint::LintConfig;
+/// use libgraphql_core::lint::Linter;
+/// use libgraphql_core::schema::SchemaBuilder;
+///
+/// let schema = SchemaBuilder::build_from_str(
+///     "type Query { user_name: String }",
+/// ).unwrap();
+/// let linter = Linter::new(LintConfig {
+///     descriptions: None,
+///     ..LintConfig::default()
+/// });
+///
+/// let diagnostics = linter.lint(&schema);
+/// assert_eq!(diagnostics.len(), 1);
+/// assert_eq!(diagnostics[0].coordinate().to_string(), "Query.user_name");
+/// assert_eq!(
+///     diagnostics[0].to_string(),
+///     "warning[field-name-case]: field `Query.user_name` should be camelCase",
+/// );
+/// ```
+#[derive(Default)]
+pub struct Linter {
+    config: LintConfig,
+    rules: Vec<Box<dyn LintRule>>,
+}
+
+impl Linter {
+    pub fn new(config: LintConfig) -> Self {
+        Self { config, rules: vec![] }
+    }
+
+    /// Adds a user-defined rule, run after the built-in ones with
+    /// its own [`severity()`](LintRule::severity).
+    pub fn add_rule(&mut self, rule: impl LintRule + 'static) -> &mut Self {
+        self.rules.push(Box::new(rule));
+        self
+    }
+
+    pub fn config(&self) -> &LintConfig { &self.config }
+
+    /// Runs every enabled rule over `schema`, returning their
+    /// diagnostics grouped by rule (built-in rules first, in
+    /// [`LintConfig`] field order).
+    pub fn lint(&self, schema: &Schema) -> Vec<LintDiagnostic> {
+        let mut diagnostics = vec![];
+        for (rule, severity) in builtin_rules::enabled(&self.config) {
+            let mut cx = LintContext::new(&mut diagnostics, rule.name(), severity);
+            rule.check(schema, &mut cx);
+        }
+        for rule in &self.rules {
+            let mut cx = LintContext::new(
+                &mut diagnostics,
+                rule.name(),
+                rule.severity(),
+            );
+            rule.check(schema, &mut cx);
+        }
+        diagnostics
+    }
+}
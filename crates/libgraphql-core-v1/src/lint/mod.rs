@@ -0,0 +1,24 @@
+//! Configurable lint rules over a validated
+//! [`Schema`](crate::schema::Schema), for conventions beyond what
+//! the GraphQL spec requires (naming, documentation, deprecation
+//! reasons, Relay connections, ...).
+//!
+//! Built-in rules are enabled individually through a
+//! [`LintConfig`]; user-defined rules implement [`LintRule`] and
+//! are added to the [`Linter`].
+
+mod builtin_rules;
+mod lint_config;
+mod lint_diagnostic;
+mod lint_rule;
+mod linter;
+
+pub use crate::lint::lint_config::LintConfig;
+pub use crate::lint::lint_diagnostic::LintDiagnostic;
+pub use crate::lint::lint_diagnostic::LintSeverity;
+pub use crate::lint::lint_rule::LintContext;
+pub use crate::lint::lint_rule::LintRule;
+pub use crate::lint::linter::Linter;
+
+#[cfg(test)]
+mod tests;
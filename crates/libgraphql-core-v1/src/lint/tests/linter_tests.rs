@@ -0,0 +1,197 @@
+use crate::lint::LintConfig;
+use crate::lint::LintContext;
+use crate::lint::LintRule;
+use crate::lint::LintSeverity;
+use crate::lint::Linter;
+use crate::schema::Schema;
+use crate::schema::SchemaBuilder;
+use crate::schema::SchemaElement;
+
+fn build(source: &str) -> Schema {
+    SchemaBuilder::build_from_str(source)
+        .unwrap_or_else(|errs| panic!("schema failed to build:\n{errs}"))
+}
+
+/// Lints `schema` with every built-in rule except `descriptions`
+/// (which would flag nearly every element of a terse test schema),
+/// returning each diagnostic as `coordinate: message`.
+fn lint_without_descriptions(schema: &Schema) -> Vec<String> {
+    let linter = Linter::new(LintConfig {
+        descriptions: None,
+        ..LintConfig::default()
+    });
+    linter.lint(schema)
+        .iter()
+        .map(|diagnostic| {
+            format!("{}: {}", diagnostic.coordinate(), diagnostic)
+        })
+        .collect()
+}
+
+// Verifies that each built-in naming and deprecation rule reports
+// the offending element's coordinate, in config field order.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn reports_builtin_rule_violations() {
+    let schema = build("\
+type Query {
+  user(user_id: ID): user_profile
+  legacy: String @deprecated
+  old: String @deprecated(reason: \"use `user`\")
+}
+type user_profile { id: ID!, ownerId: ID, friendIds: [ID!], Status: Status }
+enum Status { ACTIVE, pendingReview @deprecated(reason: \"\") }
+input Filter { name_prefix: String }
+input PageInput { size: Int }
+");
+    assert_eq!(lint_without_descriptions(&schema), [
+        "Query.legacy: warning[deprecation-reasons]: `Query.legacy` is \
+        deprecated without a reason",
+        "Status.pendingReview: warning[deprecation-reasons]: \
+        `Status.pendingReview` is deprecated without a reason",
+        "Status.pendingReview: warning[enum-value-case]: enum value \
+        `Status.pendingReview` should be SCREAMING_SNAKE_CASE",
+        "user_profile.Status: warning[field-name-case]: field \
+        `user_profile.Status` should be camelCase",
+        "Filter.name_prefix: warning[field-name-case]: input field \
+        `Filter.name_prefix` should be camelCase",
+        "user_profile.ownerId: warning[id-field-names]: field \
+        `user_profile.ownerId` has type `ID` but is not named `id`",
+        "Filter: warning[input-type-suffix]: input type `Filter` should \
+        be named `FilterInput`",
+        "user_profile: warning[type-name-case]: type `user_profile` \
+        should be PascalCase",
+    ]);
+}
+
+// Verifies that the descriptions rule flags undescribed types,
+// fields, and input fields (but not enum values or arguments), and
+// that a rule's configured severity and a diagnostic's span are
+// reported.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn reports_missing_descriptions_with_configured_severity() {
+    let schema = build("\
+\"The root.\"
+type Query {
+  \"The greeting.\"
+  hello(name: String): String
+  bye: String
+}
+input GreetingInput { to: String }
+enum Mood { HAPPY }
+");
+    let linter = Linter::new(LintConfig {
+        descriptions: Some(LintSeverity::Error),
+        ..LintConfig::none()
+    });
+    let diagnostics = linter.lint(&schema);
+    let messages: Vec<String> = diagnostics.iter()
+        .map(|diagnostic| diagnostic.to_string())
+        .collect();
+    assert_eq!(messages, [
+        "error[descriptions]: field `Query.bye` has no description",
+        "error[descriptions]: type `GreetingInput` has no description",
+        "error[descriptions]: input field `GreetingInput.to` has no description",
+        "error[descriptions]: type `Mood` has no description",
+    ]);
+
+    let span = schema.resolve_span(diagnostics[0].span()).unwrap();
+    assert_eq!(span.start.line, 4);
+    assert_eq!(diagnostics[0].rule_name(), "descriptions");
+    assert_eq!(diagnostics[0].severity(), LintSeverity::Error);
+}
+
+// Verifies that the relay-connections rule reports the cursor
+// connection violations found by `validate_relay()` at their
+// coordinates, without the object identification ones (this schema
+// has no `Node` interface).
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn reports_relay_connection_violations() {
+    let schema = build("\
+type Query {
+  users(first: Int, after: String): UserConnection
+  posts: PostConnection
+}
+type PageInfo {
+  hasNextPage: Boolean!
+  hasPreviousPage: Boolean!
+  startCursor: String
+  endCursor: String
+}
+type UserConnection { edges: [UserEdge], pageInfo: PageInfo! }
+type UserEdge { node: User, cursor: String! }
+type User { name: String }
+type PostConnection { edges: PostEdge, pageInfo: PageInfo }
+type PostEdge { node: String }
+type CommentConnection { pageInfo: PageInfo! }
+");
+    let linter = Linter::new(LintConfig {
+        relay_connections: Some(LintSeverity::Warning),
+        ..LintConfig::none()
+    });
+    let diagnostics: Vec<String> = linter.lint(&schema)
+        .iter()
+        .map(|diagnostic| {
+            format!("{}: {}", diagnostic.coordinate(), diagnostic.message())
+        })
+        .collect();
+    assert_eq!(diagnostics, [
+        "PostConnection.edges: `PostConnection.edges` has type `PostEdge`, \
+        but should have a list of edge types",
+        "PostConnection.pageInfo: `PostConnection.pageInfo` has type \
+        `PageInfo`, but should have type `PageInfo!`",
+        "CommentConnection: connection type `CommentConnection` has no \
+        `edges` field",
+        "PostEdge: edge type `PostEdge` has no `cursor` field",
+        "Query.posts: `Query.posts` returns a connection but has neither \
+        `first`/`after` nor `last`/`before` arguments",
+    ]);
+}
+
+/// Flags object types with more than `max_fields` fields.
+struct MaxFields {
+    max_fields: usize,
+}
+
+impl LintRule for MaxFields {
+    fn name(&self) -> &str { "max-fields" }
+
+    fn severity(&self) -> LintSeverity { LintSeverity::Error }
+
+    fn check(&self, schema: &Schema, cx: &mut LintContext<'_>) {
+        for type_ in schema.types().values() {
+            let Some(object) = type_.as_object() else { continue };
+            if object.fields().len() > self.max_fields {
+                cx.report(SchemaElement::Type(type_), format!(
+                    "`{}` has more than {} fields",
+                    object.name(),
+                    self.max_fields,
+                ));
+            }
+        }
+    }
+}
+
+// Verifies that user-defined rules run after the built-in ones with
+// their own name and severity.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn runs_user_defined_rules() {
+    let schema = build("type Query { a: Int, b: Int, c: Int } type Small { a: Int }");
+    let mut linter = Linter::new(LintConfig {
+        type_name_case: Some(LintSeverity::Warning),
+        ..LintConfig::none()
+    });
+    linter.add_rule(MaxFields { max_fields: 2 });
+    let messages: Vec<String> = linter.lint(&schema)
+        .iter()
+        .map(|diagnostic| diagnostic.to_string())
+        .collect();
+    assert_eq!(messages, ["error[max-fields]: `Query` has more than 2 fields"]);
+}
@@ -0,0 +1 @@
+mod linter_tests;
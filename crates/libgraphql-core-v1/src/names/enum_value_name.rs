@@ -1,5 +1,6 @@
 use crate::names::graphql_name::GraphQLName;
 use inherent::inherent;
+use libgraphql_parser::Symbol;
 use std::borrow::Borrow;
 
 /// A GraphQL [enum value name](https://spec.graphql.org/September2025/#EnumValuesDefinition)
@@ -23,32 +24,37 @@ use std::borrow::Borrow;
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 #[derive(serde::Deserialize, serde::Serialize)]
 #[repr(transparent)]
-pub struct EnumValueName(String);
+pub struct EnumValueName(Symbol);
 
 #[inherent]
 impl GraphQLName for EnumValueName {
-    pub fn new(s: impl Into<String>) -> Self { Self(s.into()) }
-    pub fn as_str(&self) -> &str { &self.0 }
+    pub fn new(s: impl AsRef<str>) -> Self { Self(Symbol::intern(s.as_ref())) }
+    pub fn as_str(&self) -> &str { self.0.as_str() }
+    pub fn symbol(&self) -> Symbol { self.0.clone() }
 }
 
 impl AsRef<str> for EnumValueName {
-    fn as_ref(&self) -> &str { &self.0 }
+    fn as_ref(&self) -> &str { self.0.as_str() }
 }
 
 impl Borrow<str> for EnumValueName {
-    fn borrow(&self) -> &str { &self.0 }
+    fn borrow(&self) -> &str { self.0.as_str() }
 }
 
 impl std::fmt::Display for EnumValueName {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str(&self.0)
+        f.write_str(self.0.as_str())
     }
 }
 
 impl From<&str> for EnumValueName {
-    fn from(s: &str) -> Self { Self(s.to_owned()) }
+    fn from(s: &str) -> Self { Self::new(s) }
 }
 
 impl From<String> for EnumValueName {
-    fn from(s: String) -> Self { Self(s) }
+    fn from(s: String) -> Self { Self::new(s) }
+}
+
+impl From<Symbol> for EnumValueName {
+    fn from(s: Symbol) -> Self { Self(s) }
 }
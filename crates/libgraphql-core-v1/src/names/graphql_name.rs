@@ -1,3 +1,4 @@
+use libgraphql_parser::Symbol;
 use std::borrow::Borrow;
 use std::fmt::Debug;
 use std::fmt::Display;
@@ -6,8 +7,10 @@ use std::hash::Hash;
 /// Constrains all GraphQL name newtypes to a consistent set of
 /// capabilities. Every name type must be cloneable, hashable,
 /// orderable, serializable, displayable, convertible from
-/// `String`, and constructible via `new` from any input that
-/// can be converted into a `String`.
+/// `String` and [`Symbol`], and constructible via `new` from any
+/// string-like input. Every name type wraps an interned
+/// [`Symbol`], so equality is a pointer comparison and repeated
+/// names share one allocation.
 ///
 /// This trait is `pub(crate)` — it enforces consistency at
 /// definition time but is not part of the public API. Public
@@ -23,9 +26,11 @@ pub(crate) trait GraphQLName:
     + AsRef<str>
     + Borrow<str>
     + From<String>
+    + From<Symbol>
     + serde::Serialize
     + for<'de> serde::Deserialize<'de>
 {
-    fn new(s: impl Into<String>) -> Self;
+    fn new(s: impl AsRef<str>) -> Self;
     fn as_str(&self) -> &str;
+    fn symbol(&self) -> Symbol;
 }
@@ -270,3 +270,35 @@ fn name_types_are_independent() {
     takes_type_name(&type_name);
     takes_field_name(&field_name);
 }
+
+// ── Interning ─────────────────────────────────────────
+
+// Verifies that names with equal contents share one interned
+// Symbol, including across different name domains.
+#[test]
+fn names_share_interned_symbol() {
+    let a = TypeName::new("User");
+    let b: TypeName = String::from("User").into();
+    assert_eq!(a.symbol(), b.symbol());
+    assert!(std::ptr::eq(a.as_str(), b.as_str()));
+
+    let field = FieldName::new("User");
+    assert_eq!(a.symbol(), field.symbol());
+}
+
+// Verifies From<Symbol> wraps the given Symbol as-is.
+#[test]
+fn name_from_symbol() {
+    let symbol = libgraphql_parser::Symbol::intern("deprecated");
+    let name = DirectiveName::from(symbol.clone());
+    assert_eq!(name.symbol(), symbol);
+    assert_eq!(name, DirectiveName::new("deprecated"));
+}
+
+// Verifies ordering stays lexicographic (not interning order).
+#[test]
+fn name_ordering_is_lexicographic() {
+    let z = TypeName::new("names_tests_Zebra");
+    let a = TypeName::new("names_tests_Aardvark");
+    assert!(a < z);
+}
@@ -0,0 +1,15 @@
+//! Conformance checks for the Relay
+//! [Global Object Identification](https://relay.dev/graphql/objectidentification.htm)
+//! and [Cursor Connections](https://relay.dev/graphql/connections.htm)
+//! server specifications.
+
+mod relay_validator;
+mod relay_violation;
+
+pub use crate::relay::relay_validator::validate_relay;
+pub(crate) use crate::relay::relay_validator::validate_relay_connections;
+pub use crate::relay::relay_violation::RelayViolation;
+pub use crate::relay::relay_violation::RelayViolationKind;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,449 @@
+use crate::error_note::ErrorNote;
+use crate::names::TypeName;
+use crate::relay::relay_violation::RelayViolation;
+use crate::relay::relay_violation::RelayViolationKind;
+use crate::schema::Schema;
+use crate::types::FieldDefinition;
+use crate::types::GraphQLType;
+use crate::types::HasFieldsAndInterfaces;
+use crate::types::ParameterDefinition;
+use crate::types::TypeAnnotation;
+use std::collections::HashSet;
+
+const CONNECTION_TYPES_SPEC: &str =
+    "https://relay.dev/graphql/connections.htm#sec-Connection-Types";
+const CONNECTION_ARGUMENTS_SPEC: &str =
+    "https://relay.dev/graphql/connections.htm#sec-Arguments";
+const EDGE_TYPES_SPEC: &str =
+    "https://relay.dev/graphql/connections.htm#sec-Edge-Types";
+const NODE_INTERFACE_SPEC: &str =
+    "https://relay.dev/graphql/objectidentification.htm#sec-Node-Interface";
+const NODE_ROOT_FIELD_SPEC: &str =
+    "https://relay.dev/graphql/objectidentification.htm#sec-Node-root-field";
+const PAGE_INFO_SPEC: &str =
+    "https://relay.dev/graphql/connections.htm#sec-undefined.PageInfo";
+const PLURAL_ROOT_FIELDS_SPEC: &str =
+    "https://relay.dev/graphql/objectidentification.htm#sec-Plural-identifying-root-fields";
+
+/// Checks `schema` against the Relay
+/// [Global Object Identification](https://relay.dev/graphql/objectidentification.htm)
+/// and [Cursor Connections](https://relay.dev/graphql/connections.htm)
+/// specifications, returning every violation found.
+///
+/// Object identification requires:
+///
+/// - a `Node` interface with an `id: ID!` field,
+/// - a `node(id: ID!): Node` field on the query root type, and
+/// - a `nodes(ids: [ID!]!)` field on the query root type returning
+///   a list of `Node`.
+///
+/// Cursor connections require, for every object or interface type
+/// whose name ends in `Connection`:
+///
+/// - an `edges` field returning a list of edge types, and a
+///   `pageInfo: PageInfo!` field,
+/// - each edge type (and every object or interface type whose name
+///   ends in `Edge`) to have a non-list `node` field and a `cursor`
+///   field that serializes as a string,
+/// - `PageInfo` (if defined) to have `hasNextPage: Boolean!`,
+///   `hasPreviousPage: Boolean!`, and string-typed `startCursor`
+///   and `endCursor` fields, and
+/// - every field returning a connection to take `first: Int` and
+///   `after` (a cursor), or `last: Int` and `before` (a cursor).
+///
+/// Unlike the checks run by
+/// [`SchemaBuilder::build()`](crate::schema::SchemaBuilder::build),
+/// these conventions are opt-in: a schema that violates them is
+/// still a valid GraphQL schema.
+///
+/// ```rust
+/// # use libgraphql_core_v1 as libgraphql_core;
+/// use libgraphql_core::relay::validate_relay;
+/// use libgraphql_core::schema::SchemaBuilder;
+///
+/// let schema = SchemaBuilder::build_from_str("
+///     interface Node { id: ID! }
+///     type Query {
+///       node(id: ID!): Node
+///       nodes(ids: [ID!]!): [Node]!
+///       users(first: Int, after: String): UserConnection
+///     }
+///     type User implements Node { id: ID! }
+///     type UserConnection { edges: [UserEdge], pageInfo: PageInfo }
+///     type UserEdge { node: User, cursor: String! }
+///     type PageInfo {
+///       hasNextPage: Boolean!
+///       hasPreviousPage: Boolean!
+///       startCursor: String
+///       endCursor: String
+///     }
+/// ").unwrap();
+///
+/// let violations = validate_relay(&schema);
+/// assert_eq!(violations.len(), 1);
+/// assert_eq!(
+///     violations[0].to_string(),
+///     "`UserConnection.pageInfo` has type `PageInfo`, but should have \
+///     type `PageInfo!`",
+/// );
+/// ```
+pub fn validate_relay(schema: &Schema) -> Vec<RelayViolation> {
+    let mut validator = RelayValidator::new(schema);
+    validator.check_object_identification();
+    validator.check_connections();
+    validator.violations
+}
+
+/// Checks only the cursor connection half of
+/// [`validate_relay()`], for the `relay-connections` lint rule.
+pub(crate) fn validate_relay_connections(schema: &Schema) -> Vec<RelayViolation> {
+    let mut validator = RelayValidator::new(schema);
+    validator.check_connections();
+    validator.violations
+}
+
+struct RelayValidator<'a> {
+    checked_edge_types: HashSet<&'a TypeName>,
+    schema: &'a Schema,
+    violations: Vec<RelayViolation>,
+}
+
+impl<'a> RelayValidator<'a> {
+    fn new(schema: &'a Schema) -> Self {
+        Self {
+            checked_edge_types: HashSet::new(),
+            schema,
+            violations: vec![],
+        }
+    }
+
+    /// Yields every non-built-in object and interface type, as
+    /// [`HasFieldsAndInterfaces`] trait objects.
+    fn fielded_types(&self) -> impl Iterator<Item = &'a dyn HasFieldsAndInterfaces> + use<'a> {
+        self.schema.types().values().filter_map(|type_| match type_ {
+            GraphQLType::Interface(iface) if !type_.is_builtin() => {
+                Some(&**iface as &dyn HasFieldsAndInterfaces)
+            },
+            GraphQLType::Object(obj) if !type_.is_builtin() => {
+                Some(&**obj as &dyn HasFieldsAndInterfaces)
+            },
+            _ => None,
+        })
+    }
+
+    fn fielded_type(&self, name: &str) -> Option<&'a dyn HasFieldsAndInterfaces> {
+        match self.schema.get_type(name)? {
+            GraphQLType::Interface(iface) => Some(&**iface),
+            GraphQLType::Object(obj) => Some(&**obj),
+            _ => None,
+        }
+    }
+
+    fn is_connection_type(&self, name: &str) -> bool {
+        name.ends_with("Connection") && self.fielded_type(name).is_some()
+    }
+
+    /// Whether `annotation` is a non-list scalar that serializes as
+    /// a string: `String`, `ID`, or a custom scalar.
+    fn is_string_scalar(&self, annotation: &TypeAnnotation) -> bool {
+        let TypeAnnotation::Named(named) = annotation else {
+            return false;
+        };
+        let name = named.type_name().as_str();
+        self.schema.scalar_type(name).is_some()
+            && !matches!(name, "Boolean" | "Float" | "Int")
+    }
+
+    // ---------------------------------------------------------
+    // Global Object Identification
+    // ---------------------------------------------------------
+
+    fn check_object_identification(&mut self) {
+        let query = self.schema.query_type();
+        let node = match self.schema.get_type("Node") {
+            Some(GraphQLType::Interface(node)) => node,
+            Some(other) => {
+                self.violations.push(RelayViolation::new(
+                    RelayViolationKind::InvalidNodeType {
+                        type_name: other.name().to_string(),
+                    },
+                    other.coordinate(),
+                    other.span(),
+                    vec![ErrorNote::spec(NODE_INTERFACE_SPEC)],
+                ));
+                return;
+            },
+            None => {
+                self.violations.push(RelayViolation::new(
+                    RelayViolationKind::MissingNodeInterface,
+                    query.coordinate(),
+                    query.span(),
+                    vec![ErrorNote::spec(NODE_INTERFACE_SPEC)],
+                ));
+                return;
+            },
+        };
+
+        if let Some(id) = self.require_field(&**node, "id", "interface", NODE_INTERFACE_SPEC) {
+            let is_valid = id.type_annotation().to_string() == "ID!";
+            self.expect_field_type(id, is_valid, "type `ID!`", NODE_INTERFACE_SPEC);
+        }
+
+        if let Some(node_field) = self.require_field(query, "node", "query type", NODE_ROOT_FIELD_SPEC) {
+            let is_valid = matches!(
+                node_field.type_annotation(),
+                TypeAnnotation::Named(named) if named.type_name().as_str() == "Node",
+            );
+            self.expect_field_type(node_field, is_valid, "type `Node`", NODE_ROOT_FIELD_SPEC);
+            self.require_argument(node_field, "id", "ID!", NODE_ROOT_FIELD_SPEC);
+        }
+
+        if let Some(nodes_field) = self.require_field(query, "nodes", "query type", PLURAL_ROOT_FIELDS_SPEC) {
+            let is_valid = matches!(
+                nodes_field.type_annotation(),
+                TypeAnnotation::List(list) if matches!(
+                    list.inner(),
+                    TypeAnnotation::Named(named) if named.type_name().as_str() == "Node",
+                ),
+            );
+            self.expect_field_type(nodes_field, is_valid, "a list of `Node`", PLURAL_ROOT_FIELDS_SPEC);
+            self.require_argument(nodes_field, "ids", "[ID!]!", PLURAL_ROOT_FIELDS_SPEC);
+        }
+    }
+
+    // ---------------------------------------------------------
+    // Cursor Connections
+    // ---------------------------------------------------------
+
+    fn check_connections(&mut self) {
+        for type_ in self.fielded_types() {
+            if self.is_connection_type(type_.name().as_str()) {
+                self.check_connection_type(type_);
+            }
+        }
+        for type_ in self.fielded_types() {
+            if type_.name().as_str().ends_with("Edge") {
+                self.check_edge_type(type_);
+            }
+        }
+        if let Some(page_info) = self.schema.object_type("PageInfo") {
+            self.check_page_info_type(page_info);
+        }
+        for type_ in self.fielded_types() {
+            for field in type_.fields().values() {
+                let returns_connection = matches!(
+                    field.type_annotation(),
+                    TypeAnnotation::Named(named)
+                        if self.is_connection_type(named.type_name().as_str()),
+                );
+                if returns_connection {
+                    self.check_connection_arguments(field);
+                }
+            }
+        }
+    }
+
+    fn check_connection_type(&mut self, connection: &'a dyn HasFieldsAndInterfaces) {
+        if let Some(edges) = self.require_field(connection, "edges", "connection type", CONNECTION_TYPES_SPEC) {
+            let edge = match edges.type_annotation() {
+                TypeAnnotation::List(list) => match list.inner() {
+                    TypeAnnotation::Named(named) => {
+                        self.fielded_type(named.type_name().as_str())
+                    },
+                    TypeAnnotation::List(_) => None,
+                },
+                TypeAnnotation::Named(_) => None,
+            };
+            match edge {
+                Some(edge) => self.check_edge_type(edge),
+                None => self.expect_field_type(
+                    edges,
+                    false,
+                    "a list of edge types",
+                    CONNECTION_TYPES_SPEC,
+                ),
+            }
+        }
+
+        if let Some(page_info) = self.require_field(connection, "pageInfo", "connection type", CONNECTION_TYPES_SPEC) {
+            let is_valid = page_info.type_annotation().to_string() == "PageInfo!";
+            self.expect_field_type(page_info, is_valid, "type `PageInfo!`", CONNECTION_TYPES_SPEC);
+        }
+    }
+
+    fn check_edge_type(&mut self, edge: &'a dyn HasFieldsAndInterfaces) {
+        if !self.checked_edge_types.insert(edge.name()) {
+            return;
+        }
+        if let Some(node) = self.require_field(edge, "node", "edge type", EDGE_TYPES_SPEC) {
+            let is_valid = matches!(node.type_annotation(), TypeAnnotation::Named(_));
+            self.expect_field_type(node, is_valid, "a non-list type", EDGE_TYPES_SPEC);
+        }
+        if let Some(cursor) = self.require_field(edge, "cursor", "edge type", EDGE_TYPES_SPEC) {
+            let is_valid = self.is_string_scalar(cursor.type_annotation());
+            self.expect_field_type(
+                cursor,
+                is_valid,
+                "a type that serializes as a string",
+                EDGE_TYPES_SPEC,
+            );
+        }
+    }
+
+    fn check_page_info_type(&mut self, page_info: &'a dyn HasFieldsAndInterfaces) {
+        for field_name in ["hasNextPage", "hasPreviousPage"] {
+            if let Some(field) = self.require_field(page_info, field_name, "type", PAGE_INFO_SPEC) {
+                let is_valid = field.type_annotation().to_string() == "Boolean!";
+                self.expect_field_type(field, is_valid, "type `Boolean!`", PAGE_INFO_SPEC);
+            }
+        }
+        for field_name in ["startCursor", "endCursor"] {
+            if let Some(field) = self.require_field(page_info, field_name, "type", PAGE_INFO_SPEC) {
+                let is_valid = self.is_string_scalar(field.type_annotation());
+                self.expect_field_type(
+                    field,
+                    is_valid,
+                    "a type that serializes as a string",
+                    PAGE_INFO_SPEC,
+                );
+            }
+        }
+    }
+
+    fn check_connection_arguments(&mut self, field: &FieldDefinition) {
+        let params = field.parameters();
+        let has_forward = params.contains_key("first") && params.contains_key("after");
+        let has_backward = params.contains_key("last") && params.contains_key("before");
+        if !has_forward && !has_backward {
+            self.violations.push(RelayViolation::new(
+                RelayViolationKind::MissingPaginationArguments {
+                    coordinate: field.coordinate().to_string(),
+                },
+                field.coordinate(),
+                field.span(),
+                vec![ErrorNote::spec(CONNECTION_ARGUMENTS_SPEC)],
+            ));
+        }
+
+        for (name, param) in params {
+            let (is_valid, expected_type) = match name.as_str() {
+                "first" | "last" => (
+                    matches!(
+                        param.type_annotation(),
+                        TypeAnnotation::Named(named) if named.type_name().as_str() == "Int",
+                    ),
+                    "type `Int`",
+                ),
+                "after" | "before" => (
+                    self.is_string_scalar(param.type_annotation()),
+                    "a cursor type that serializes as a string",
+                ),
+                _ => continue,
+            };
+            if !is_valid {
+                self.push_invalid_argument_type(param, expected_type, CONNECTION_ARGUMENTS_SPEC);
+            }
+        }
+    }
+
+    // ---------------------------------------------------------
+    // Reporting helpers
+    // ---------------------------------------------------------
+
+    /// Reports a violation at `field`'s type annotation unless
+    /// `is_valid`.
+    fn expect_field_type(
+        &mut self,
+        field: &FieldDefinition,
+        is_valid: bool,
+        expected_type: &str,
+        spec_url: &str,
+    ) {
+        if is_valid {
+            return;
+        }
+        self.violations.push(RelayViolation::new(
+            RelayViolationKind::InvalidFieldType {
+                actual_type: field.type_annotation().to_string(),
+                coordinate: field.coordinate().to_string(),
+                expected_type: expected_type.to_string(),
+            },
+            field.coordinate(),
+            field.type_annotation().span(),
+            vec![ErrorNote::spec(spec_url)],
+        ));
+    }
+
+    fn push_invalid_argument_type(
+        &mut self,
+        param: &ParameterDefinition,
+        expected_type: &str,
+        spec_url: &str,
+    ) {
+        self.violations.push(RelayViolation::new(
+            RelayViolationKind::InvalidArgumentType {
+                actual_type: param.type_annotation().to_string(),
+                coordinate: param.coordinate().to_string(),
+                expected_type: expected_type.to_string(),
+            },
+            param.coordinate(),
+            param.type_annotation().span(),
+            vec![ErrorNote::spec(spec_url)],
+        ));
+    }
+
+    /// Reports a violation unless `field` has an `argument_name`
+    /// argument of type `expected_type` (e.g. `"ID!"`).
+    fn require_argument(
+        &mut self,
+        field: &FieldDefinition,
+        argument_name: &str,
+        expected_type: &str,
+        spec_url: &str,
+    ) {
+        let Some(param) = field.parameters().get(argument_name) else {
+            self.violations.push(RelayViolation::new(
+                RelayViolationKind::MissingArgument {
+                    argument_name: argument_name.to_string(),
+                    field_coordinate: field.coordinate().to_string(),
+                },
+                field.coordinate(),
+                field.span(),
+                vec![ErrorNote::spec(spec_url)],
+            ));
+            return;
+        };
+        if param.type_annotation().to_string() != expected_type {
+            self.push_invalid_argument_type(
+                param,
+                &format!("type `{expected_type}`"),
+                spec_url,
+            );
+        }
+    }
+
+    /// Returns `type_`'s `field_name` field, reporting a violation
+    /// at `type_` if it has none.
+    fn require_field<'t>(
+        &mut self,
+        type_: &'t (impl HasFieldsAndInterfaces + ?Sized),
+        field_name: &str,
+        type_description: &'static str,
+        spec_url: &str,
+    ) -> Option<&'t FieldDefinition> {
+        let field = type_.field(field_name);
+        if field.is_none() {
+            self.violations.push(RelayViolation::new(
+                RelayViolationKind::MissingField {
+                    field_name: field_name.to_string(),
+                    type_description,
+                    type_name: type_.name().to_string(),
+                },
+                type_.coordinate(),
+                type_.span(),
+                vec![ErrorNote::spec(spec_url)],
+            ));
+        }
+        field
+    }
+}
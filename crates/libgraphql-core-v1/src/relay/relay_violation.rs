@@ -0,0 +1,133 @@
+use crate::error_note::ErrorNote;
+use crate::schema::SchemaCoordinate;
+use crate::schema_source_map::SchemaSourceMap;
+use crate::span::Span;
+
+/// A violation of the Relay
+/// [Global Object Identification](https://relay.dev/graphql/objectidentification.htm)
+/// or [Cursor Connections](https://relay.dev/graphql/connections.htm)
+/// specifications, found by
+/// [`validate_relay()`](crate::relay::validate_relay).
+///
+/// Like [`SchemaBuildError`](crate::schema::SchemaBuildError), each
+/// violation carries a primary [`span`](Self::span) (the offending
+/// type, field, argument, or type annotation), a
+/// [`kind`](Self::kind) for programmatic matching, and
+/// [`notes`](Self::notes) linking the relevant spec section. It also
+/// carries the [`coordinate`](Self::coordinate) of the offending
+/// schema element.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RelayViolation {
+    coordinate: SchemaCoordinate,
+    kind: RelayViolationKind,
+    notes: Vec<ErrorNote>,
+    span: Span,
+}
+
+impl RelayViolation {
+    pub(crate) fn new(
+        kind: RelayViolationKind,
+        coordinate: SchemaCoordinate,
+        span: Span,
+        notes: Vec<ErrorNote>,
+    ) -> Self {
+        Self { coordinate, kind, notes, span }
+    }
+
+    pub fn coordinate(&self) -> &SchemaCoordinate { &self.coordinate }
+    pub fn kind(&self) -> &RelayViolationKind { &self.kind }
+    pub fn notes(&self) -> &[ErrorNote] { &self.notes }
+    pub fn span(&self) -> Span { self.span }
+
+    /// Formats this violation as a multi-line diagnostic with source
+    /// snippets, in the same style as
+    /// [`SchemaBuildError::format_detailed()`](crate::schema::SchemaBuildError::format_detailed).
+    ///
+    /// Spans are resolved through `source_maps` — pass
+    /// [`Schema::source_maps()`](crate::schema::Schema::source_maps)
+    /// for the schema that was validated.
+    pub fn format_detailed(
+        &self,
+        source_maps: &[SchemaSourceMap],
+    ) -> String {
+        crate::diagnostic_renderer::format_detailed(
+            &self.kind.to_string(),
+            self.span,
+            &self.notes,
+            source_maps,
+        )
+    }
+}
+
+impl std::fmt::Display for RelayViolation {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        write!(f, "{}", self.kind)
+    }
+}
+
+impl std::error::Error for RelayViolation {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.kind)
+    }
+}
+
+/// Categorized Relay violation kind for programmatic matching.
+///
+/// `#[non_exhaustive]` — new variants may be added in minor
+/// releases. Always include a wildcard arm in `match`
+/// expressions.
+#[derive(Clone, Debug, PartialEq, thiserror::Error)]
+#[non_exhaustive]
+pub enum RelayViolationKind {
+    #[error(
+        "`{coordinate}` has type `{actual_type}`, but should have \
+        {expected_type}"
+    )]
+    InvalidArgumentType {
+        actual_type: String,
+        coordinate: String,
+        expected_type: String,
+    },
+
+    #[error(
+        "`{coordinate}` has type `{actual_type}`, but should have \
+        {expected_type}"
+    )]
+    InvalidFieldType {
+        actual_type: String,
+        coordinate: String,
+        expected_type: String,
+    },
+
+    #[error("`{type_name}` must be an interface type")]
+    InvalidNodeType {
+        type_name: String,
+    },
+
+    #[error("`{field_coordinate}` has no `{argument_name}` argument")]
+    MissingArgument {
+        argument_name: String,
+        field_coordinate: String,
+    },
+
+    #[error("{type_description} `{type_name}` has no `{field_name}` field")]
+    MissingField {
+        field_name: String,
+        type_description: &'static str,
+        type_name: String,
+    },
+
+    #[error("schema does not define a `Node` interface")]
+    MissingNodeInterface,
+
+    #[error(
+        "`{coordinate}` returns a connection but has neither \
+        `first`/`after` nor `last`/`before` arguments"
+    )]
+    MissingPaginationArguments {
+        coordinate: String,
+    },
+}
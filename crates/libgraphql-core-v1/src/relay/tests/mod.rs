@@ -0,0 +1 @@
+mod relay_validator_tests;
@@ -0,0 +1,156 @@
+use crate::relay::validate_relay;
+use crate::relay::RelayViolationKind;
+use crate::schema::Schema;
+use crate::schema::SchemaBuilder;
+
+fn build(source: &str) -> Schema {
+    SchemaBuilder::build_from_str(source)
+        .unwrap_or_else(|errs| panic!("schema failed to build:\n{errs}"))
+}
+
+fn violation_messages(schema: &Schema) -> Vec<String> {
+    validate_relay(schema)
+        .iter()
+        .map(|violation| violation.to_string())
+        .collect()
+}
+
+const NODE_INTERFACE: &str = "interface Node { id: ID! }";
+
+const PAGE_INFO: &str = "
+type PageInfo {
+  hasNextPage: Boolean!
+  hasPreviousPage: Boolean!
+  startCursor: String
+  endCursor: String
+}
+";
+
+// Verifies that a schema following both Relay specifications,
+// including interface connections, custom cursor scalars, and
+// backward-only pagination, has no violations.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn conforming_schema_has_no_violations() {
+    let schema = build(&format!("{NODE_INTERFACE}{PAGE_INFO}
+scalar Cursor
+type Query {{
+  node(id: ID!): Node
+  nodes(ids: [ID!]!): [Node]!
+  users(first: Int, after: Cursor, last: Int, before: Cursor): UserConnection!
+}}
+type User implements Node {{
+  id: ID!
+  friends(last: Int!, before: Cursor): FriendConnection
+}}
+interface FriendConnection {{ edges: [FriendEdge!]!, pageInfo: PageInfo! }}
+type FriendEdge {{ node: User!, cursor: Cursor! }}
+type UserConnection {{ edges: [FriendEdge], pageInfo: PageInfo! }}
+"));
+    assert_eq!(violation_messages(&schema), Vec::<String>::new());
+}
+
+// Verifies that a missing `Node` interface is reported at the query
+// type, and that nothing else about object identification is checked
+// without it.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn reports_missing_node_interface() {
+    let schema = build("type Query { hello: String }");
+    let violations = validate_relay(&schema);
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].kind(), &RelayViolationKind::MissingNodeInterface);
+    assert_eq!(violations[0].coordinate().to_string(), "Query");
+    assert_eq!(violations[0].span(), schema.query_type().span());
+
+    let schema = build("type Node { id: ID! } type Query { node: Node }");
+    assert_eq!(violation_messages(&schema), [
+        "`Node` must be an interface type",
+    ]);
+}
+
+// Verifies the checks of the `Node` interface and the `node` and
+// `nodes` root fields, with type violations reported at the
+// offending type annotation.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn reports_object_identification_violations() {
+    let schema = build("\
+interface Node { id: ID }
+type Query {
+  node(id: String!): Node!
+  nodes: Node
+}
+");
+    let violations = validate_relay(&schema);
+    let messages: Vec<String> = violations.iter()
+        .map(|violation| violation.to_string())
+        .collect();
+    assert_eq!(messages, [
+        "`Node.id` has type `ID`, but should have type `ID!`",
+        "`Query.node(id:)` has type `String!`, but should have type `ID!`",
+        "`Query.nodes` has type `Node`, but should have a list of `Node`",
+        "`Query.nodes` has no `ids` argument",
+    ]);
+
+    let node_id = schema.resolve_span(violations[0].span()).unwrap();
+    assert_eq!((node_id.start.line, node_id.start.col_utf8), (0, 21));
+    let node_arg = schema.resolve_span(violations[1].span()).unwrap();
+    assert_eq!((node_arg.start.line, node_arg.start.col_utf8), (2, 11));
+    assert_eq!(violations[3].coordinate().to_string(), "Query.nodes");
+
+    let schema = build("interface Node { key: ID! } type Query { id: ID }");
+    assert_eq!(violation_messages(&schema), [
+        "interface `Node` has no `id` field",
+        "query type `Query` has no `node` field",
+        "query type `Query` has no `nodes` field",
+    ]);
+}
+
+// Verifies the checks of connection, edge, and `PageInfo` types and
+// of pagination arguments, including `*Edge` types not reached from
+// any connection.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn reports_connection_violations() {
+    let schema = build(&format!("{NODE_INTERFACE}
+type Query {{
+  node(id: ID!): Node
+  nodes(ids: [ID!]!): [Node]!
+  posts(first: Int): PostConnection
+  comments(last: String, before: Int): CommentConnection
+}}
+type PostConnection {{ edges: PostEdge, pageInfo: PageInfo! }}
+type PostEdge {{ node: [String], cursor: Int }}
+type CommentConnection {{ edges: [[CommentEdge]] }}
+type CommentEdge {{ node: String }}
+type PageInfo {{ hasNextPage: Boolean, startCursor: [String], endCursor: ID }}
+"));
+    assert_eq!(violation_messages(&schema), [
+        "`PostConnection.edges` has type `PostEdge`, but should have a \
+        list of edge types",
+        "`CommentConnection.edges` has type `[[CommentEdge]]`, but should \
+        have a list of edge types",
+        "connection type `CommentConnection` has no `pageInfo` field",
+        "`PostEdge.node` has type `[String]`, but should have a non-list \
+        type",
+        "`PostEdge.cursor` has type `Int`, but should have a type that \
+        serializes as a string",
+        "edge type `CommentEdge` has no `cursor` field",
+        "`PageInfo.hasNextPage` has type `Boolean`, but should have type \
+        `Boolean!`",
+        "type `PageInfo` has no `hasPreviousPage` field",
+        "`PageInfo.startCursor` has type `[String]`, but should have a \
+        type that serializes as a string",
+        "`Query.posts` returns a connection but has neither \
+        `first`/`after` nor `last`/`before` arguments",
+        "`Query.comments(last:)` has type `String`, but should have type \
+        `Int`",
+        "`Query.comments(before:)` has type `Int`, but should have a \
+        cursor type that serializes as a string",
+    ]);
+}
@@ -0,0 +1,42 @@
+use crate::value::Value;
+
+/// A Rust implementation of a custom scalar type (e.g. `DateTime`,
+/// `URL`, `JSON`, `BigInt`).
+///
+/// Custom scalars are opaque to the GraphQL type system: the spec
+/// leaves their input and result coercion rules to the service.
+/// Registering an implementation in a
+/// [`ScalarRegistry`](crate::scalars::ScalarRegistry) lets schema
+/// validation and variable coercion enforce those rules, so that a
+/// default value like `createdAt: DateTime = "not-a-date"` is
+/// rejected when the schema is built.
+///
+/// Errors are returned as short human-readable reasons (e.g.
+/// `"expected an RFC 3339 timestamp"`); callers attach the
+/// location of the offending value.
+///
+/// See [Custom Scalars](https://spec.graphql.org/September2025/#sec-Scalars.Custom-Scalars).
+pub trait CustomScalar: Send + Sync {
+    /// Validates a literal written in a GraphQL document — a
+    /// default value, a directive argument, or an operation
+    /// argument.
+    ///
+    /// Only const values are passed: variable references are
+    /// rejected before this is called.
+    fn validate_literal(&self, value: &Value) -> Result<(), String>;
+
+    /// Coerces a JSON input value (e.g. a variable value provided
+    /// with a request) to this scalar's canonical JSON
+    /// representation.
+    fn coerce_input(
+        &self,
+        value: &serde_json::Value,
+    ) -> Result<serde_json::Value, String>;
+
+    /// Serializes a resolved result value to the JSON
+    /// representation sent to clients.
+    fn serialize(
+        &self,
+        value: &serde_json::Value,
+    ) -> Result<serde_json::Value, String>;
+}
@@ -0,0 +1,8 @@
+mod custom_scalar;
+mod scalar_registry;
+
+pub use crate::scalars::custom_scalar::CustomScalar;
+pub use crate::scalars::scalar_registry::ScalarRegistry;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,119 @@
+use crate::names::TypeName;
+use crate::scalars::custom_scalar::CustomScalar;
+use crate::types::ScalarType;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// A set of [`CustomScalar`] implementations, keyed by scalar name
+/// or by the URL passed to a scalar's
+/// [`@specifiedBy`](https://spec.graphql.org/September2025/#sec--specifiedBy)
+/// directive.
+///
+/// Keying by URL lets one implementation serve every schema that
+/// declares a scalar with the same specification, whatever the
+/// scalar is called (e.g. `scalar DateTime @specifiedBy(url:
+/// "https://scalars.graphql.org/andimarek/date-time")`). A
+/// registration by name takes precedence over one by URL.
+///
+/// Only custom scalars consult the registry; the built-in scalars
+/// always follow the spec's coercion rules.
+///
+/// Pass a registry to
+/// [`SchemaBuilder::set_scalar_registry()`](crate::schema::SchemaBuilder::set_scalar_registry)
+/// to validate literals while building a schema. The built
+/// [`Schema`](crate::schema::Schema) keeps the registry (including
+/// through transforms and merges) and uses it in
+/// [`Schema::coerce_variable_value()`](crate::schema::Schema::coerce_variable_value)
+/// to coerce variable values. Cloning a registry is cheap.
+#[derive(Clone, Default)]
+pub struct ScalarRegistry {
+    by_name: HashMap<TypeName, Arc<dyn CustomScalar>>,
+    by_specified_by_url: HashMap<String, Arc<dyn CustomScalar>>,
+}
+
+impl ScalarRegistry {
+    pub fn new() -> Self { Self::default() }
+
+    /// Looks up the implementation for `scalar`: first by name,
+    /// then by its `@specifiedBy` URL.
+    pub fn get(&self, scalar: &ScalarType) -> Option<&dyn CustomScalar> {
+        self.by_name
+            .get(scalar.name())
+            .or_else(|| {
+                scalar.specified_by_url()
+                    .and_then(|url| self.by_specified_by_url.get(url))
+            })
+            .map(|scalar| scalar.as_ref())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.by_name.is_empty() && self.by_specified_by_url.is_empty()
+    }
+
+    /// Registers the implementation for the scalar named
+    /// `scalar_name`, replacing any previous registration for that
+    /// name.
+    pub fn register(
+        &mut self,
+        scalar_name: impl Into<TypeName>,
+        scalar: impl CustomScalar + 'static,
+    ) -> &mut Self {
+        self.by_name.insert(scalar_name.into(), Arc::new(scalar));
+        self
+    }
+
+    /// Registers the implementation for every scalar annotated with
+    /// `@specifiedBy(url: <url>)`, replacing any previous
+    /// registration for that URL.
+    pub fn register_specified_by(
+        &mut self,
+        url: impl Into<String>,
+        scalar: impl CustomScalar + 'static,
+    ) -> &mut Self {
+        self.by_specified_by_url.insert(url.into(), Arc::new(scalar));
+        self
+    }
+
+    /// Adds `other`'s registrations, keeping this registry's
+    /// registration wherever both have one.
+    pub(crate) fn absorb(&mut self, other: ScalarRegistry) {
+        for (name, scalar) in other.by_name {
+            self.by_name.entry(name).or_insert(scalar);
+        }
+        for (url, scalar) in other.by_specified_by_url {
+            self.by_specified_by_url.entry(url).or_insert(scalar);
+        }
+    }
+}
+
+impl std::fmt::Debug for ScalarRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut names: Vec<&str> =
+            self.by_name.keys().map(|name| name.as_str()).collect();
+        names.sort_unstable();
+        let mut urls: Vec<&str> =
+            self.by_specified_by_url.keys().map(|url| url.as_str()).collect();
+        urls.sort_unstable();
+        f.debug_struct("ScalarRegistry")
+            .field("by_name", &names)
+            .field("by_specified_by_url", &urls)
+            .finish()
+    }
+}
+
+/// Two registries are equal if they hold the same implementations (by
+/// identity) under the same keys.
+impl PartialEq for ScalarRegistry {
+    fn eq(&self, other: &Self) -> bool {
+        fn same<K: Eq + std::hash::Hash>(
+            a: &HashMap<K, Arc<dyn CustomScalar>>,
+            b: &HashMap<K, Arc<dyn CustomScalar>>,
+        ) -> bool {
+            a.len() == b.len() && a.iter().all(|(key, scalar)| {
+                b.get(key).is_some_and(|other| Arc::ptr_eq(scalar, other))
+            })
+        }
+        same(&self.by_name, &other.by_name)
+            && same(&self.by_specified_by_url, &other.by_specified_by_url)
+    }
+}
@@ -0,0 +1 @@
+mod scalar_registry_tests;
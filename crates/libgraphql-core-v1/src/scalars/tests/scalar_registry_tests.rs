@@ -0,0 +1,280 @@
+use crate::scalars::CustomScalar;
+use crate::scalars::ScalarRegistry;
+use crate::schema::Schema;
+use crate::schema::SchemaBuildErrorKind;
+use crate::schema::SchemaBuilder;
+use crate::schema::SchemaMerger;
+use crate::types::TypeAnnotation;
+use crate::value::Value;
+use serde_json::json;
+use serde_json::Value as JsonValue;
+
+/// A minimal `DateTime` scalar accepting `YYYY-MM-DDTHH:MM:SSZ`
+/// strings. Input and output are normalized to upper case.
+struct DateTime;
+
+impl DateTime {
+    fn check(s: &str) -> Result<(), String> {
+        let is_date_time = s.len() == 20 && s.char_indices().all(|(i, c)| {
+            match i {
+                4 | 7 => c == '-',
+                10 => c.eq_ignore_ascii_case(&'t'),
+                13 | 16 => c == ':',
+                19 => c.eq_ignore_ascii_case(&'z'),
+                _ => c.is_ascii_digit(),
+            }
+        });
+        if is_date_time {
+            Ok(())
+        } else {
+            Err("expected a `YYYY-MM-DDTHH:MM:SSZ` timestamp".to_string())
+        }
+    }
+
+    fn coerce(value: &JsonValue) -> Result<JsonValue, String> {
+        let s = value.as_str().ok_or("expected a string")?;
+        Self::check(s)?;
+        Ok(JsonValue::String(s.to_ascii_uppercase()))
+    }
+}
+
+impl CustomScalar for DateTime {
+    fn validate_literal(&self, value: &Value) -> Result<(), String> {
+        match value {
+            Value::String(s) => Self::check(s),
+            _ => Err("expected a string".to_string()),
+        }
+    }
+
+    fn coerce_input(&self, value: &JsonValue) -> Result<JsonValue, String> {
+        Self::coerce(value)
+    }
+
+    fn serialize(&self, value: &JsonValue) -> Result<JsonValue, String> {
+        Self::coerce(value)
+    }
+}
+
+const DATE_TIME_URL: &str = "https://scalars.graphql.org/andimarek/date-time";
+
+fn date_time_registry() -> ScalarRegistry {
+    let mut registry = ScalarRegistry::new();
+    registry.register("DateTime", DateTime);
+    registry
+}
+
+/// Builds `source` with `registry`, returning the messages of any
+/// type validation errors.
+fn build_with(
+    source: &str,
+    registry: ScalarRegistry,
+) -> Result<Schema, Vec<String>> {
+    let mut builder = SchemaBuilder::new();
+    builder.set_scalar_registry(registry);
+    builder.load_str(source).unwrap();
+    builder.build().map_err(|errors| {
+        errors
+            .errors()
+            .iter()
+            .map(|e| match e.kind() {
+                SchemaBuildErrorKind::TypeValidation(tv) => tv.to_string(),
+                other => panic!("unexpected error kind: {other:?}"),
+            })
+            .collect()
+    })
+}
+
+// Verifies that implementations are found by scalar name or by
+// `@specifiedBy(url:)`, that a name registration takes precedence,
+// and that unregistered and built-in scalars have none.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn lookup_by_name_and_specified_by_url() {
+    let schema = SchemaBuilder::build_from_str(&format!("\
+type Query {{ a: Int }}
+scalar DateTime
+scalar Timestamp @specifiedBy(url: \"{DATE_TIME_URL}\")
+scalar Json
+"))
+    .unwrap();
+    let scalar = |name: &str| schema.scalar_type(name).unwrap();
+
+    let mut registry = ScalarRegistry::new();
+    assert!(registry.is_empty());
+    registry.register_specified_by(DATE_TIME_URL, DateTime);
+    assert!(!registry.is_empty());
+    assert!(registry.get(scalar("Timestamp")).is_some());
+    assert!(registry.get(scalar("DateTime")).is_none());
+    assert!(registry.get(scalar("Json")).is_none());
+    assert!(registry.get(scalar("String")).is_none());
+
+    registry.register("DateTime", DateTime);
+    let date_time = registry.get(scalar("DateTime")).unwrap();
+    assert_eq!(
+        date_time.serialize(&json!("2024-01-02t03:04:05z")),
+        Ok(json!("2024-01-02T03:04:05Z")),
+    );
+    assert_eq!(
+        format!("{registry:?}"),
+        "ScalarRegistry { by_name: [\"DateTime\"], by_specified_by_url: \
+         [\"https://scalars.graphql.org/andimarek/date-time\"] }",
+    );
+}
+
+// Verifies that building a schema validates custom scalar literals
+// in default values and directive arguments with the registered
+// implementation, and accepts any literal without one.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn build_validates_custom_scalar_literals() {
+    let source = "\
+scalar DateTime
+directive @since(at: DateTime!) on FIELD_DEFINITION
+input Filter { createdAt: DateTime = \"not-a-date\" }
+type Query {
+  a(after: [DateTime!] = [\"2024-01-02T03:04:05Z\", 5]): Int
+  b: Int @since(at: \"2024-01-02T03:04:05Z\")
+  c: Int @since(at: \"yesterday\")
+}
+";
+    assert!(build_with(source, ScalarRegistry::new()).is_ok());
+
+    assert_eq!(build_with(source, date_time_registry()).unwrap_err(), [
+        "invalid value for argument `at` of `@since`: invalid `DateTime` \
+         value `\"yesterday\"`: expected a `YYYY-MM-DDTHH:MM:SSZ` timestamp",
+        "invalid default value `\"not-a-date\"` for `Filter.createdAt`: \
+         invalid `DateTime` value `\"not-a-date\"`: expected a \
+         `YYYY-MM-DDTHH:MM:SSZ` timestamp",
+        "invalid default value `[\"2024-01-02T03:04:05Z\", 5]` for \
+         `Query.a(after:)`: invalid `DateTime` value `5`: expected a string \
+         at `[1]`",
+    ]);
+}
+
+// Verifies that variable coercion applies the built-in input
+// coercion rules and consults the registry for custom scalars,
+// reporting the path to the offending value.
+// https://spec.graphql.org/September2025/#sec-Coercing-Variable-Values
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn coerce_variable_values() {
+    let schema = build_with("\
+scalar DateTime
+enum Order { ASC DESC }
+input Filter {
+  createdAfter: DateTime
+  ids: [ID!]
+  order: Order = DESC
+  limit: Int!
+}
+input By @oneOf { id: ID, name: String }
+type Query { a: Int }
+", date_time_registry()).unwrap();
+    let coerce = |type_name: &str, value: JsonValue| {
+        schema
+            .coerce_variable_value(
+                &TypeAnnotation::named(type_name, /* nullable = */ true),
+                &value,
+            )
+            .map_err(|err| err.to_string())
+    };
+
+    assert_eq!(
+        coerce("Filter", json!({
+            "createdAfter": "2024-01-02t03:04:05z",
+            "ids": 7,
+            "limit": 10,
+        })),
+        Ok(json!({
+            "createdAfter": "2024-01-02T03:04:05Z",
+            "ids": ["7"],
+            "order": "DESC",
+            "limit": 10,
+        })),
+    );
+    assert_eq!(coerce("Filter", JsonValue::Null), Ok(JsonValue::Null));
+    assert_eq!(coerce("By", json!({"name": "x"})), Ok(json!({"name": "x"})));
+
+    assert_eq!(
+        coerce("Filter", json!({"createdAfter": "not-a-date", "limit": 1})),
+        Err("invalid `DateTime` value: expected a `YYYY-MM-DDTHH:MM:SSZ` \
+             timestamp at `createdAfter`"
+            .to_string()),
+    );
+    assert_eq!(
+        coerce("Filter", json!({"ids": [1, null], "limit": 1})),
+        Err("expected non-null `ID!`, found `null` at `ids[1]`".to_string()),
+    );
+    assert_eq!(
+        coerce("Filter", json!({"order": "UP", "limit": 1})),
+        Err("enum `Order` has no value `UP` at `order`".to_string()),
+    );
+    assert_eq!(
+        coerce("Filter", json!({"limit": 1.5})),
+        Err("expected `Int`, found `1.5` at `limit`".to_string()),
+    );
+    assert_eq!(
+        coerce("Filter", json!({})),
+        Err("missing required field `Filter.limit`".to_string()),
+    );
+    assert_eq!(
+        coerce("Filter", json!({"limit": 1, "extra": true})),
+        Err("input object `Filter` has no field `extra`".to_string()),
+    );
+    assert_eq!(
+        coerce("By", json!({"id": 1, "name": "x"})),
+        Err("OneOf input object `By` requires exactly one field, found 2"
+            .to_string()),
+    );
+}
+
+// Verifies that a schema keeps its registry through transforms and
+// merges, so derived schemas still coerce variables with it and
+// still validate custom scalar literals from sources that were
+// built without one.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn registry_survives_transforms_and_merges() {
+    let schema = build_with("\
+scalar DateTime
+type Query { a(at: DateTime): Int }
+type Unused { b: Int }
+", date_time_registry()).unwrap();
+    let date_time = TypeAnnotation::named("DateTime", /* nullable = */ true);
+
+    let pruned = schema.prune_unreachable_types().unwrap();
+    assert!(pruned.object_type("Unused").is_none());
+    assert_eq!(pruned.scalar_registry(), schema.scalar_registry());
+    assert_eq!(
+        pruned.coerce_variable_value(&date_time, &json!("2024-01-02t03:04:05z")),
+        Ok(json!("2024-01-02T03:04:05Z")),
+    );
+
+    let other = SchemaBuilder::build_from_str("\
+scalar DateTime
+type Query { b(at: DateTime = \"yesterday\"): Int }
+").unwrap();
+    assert!(other.scalar_registry().is_empty());
+    let errors = SchemaMerger::new()
+        .add_schema("a", schema)
+        .add_schema("b", other)
+        .merge()
+        .unwrap_err();
+    let messages: Vec<String> = errors
+        .errors()
+        .iter()
+        .map(|e| match e.kind() {
+            SchemaBuildErrorKind::TypeValidation(tv) => tv.to_string(),
+            other => panic!("unexpected error kind: {other:?}"),
+        })
+        .collect();
+    assert_eq!(messages, [
+        "invalid default value `\"yesterday\"` for `Query.b(at:)`: invalid \
+         `DateTime` value `\"yesterday\"`: expected a `YYYY-MM-DDTHH:MM:SSZ` \
+         timestamp",
+    ]);
+}
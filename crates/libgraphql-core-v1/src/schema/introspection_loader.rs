@@ -0,0 +1,1019 @@
+//! Converts a JSON-serialized
+//! [introspection](https://spec.graphql.org/September2025/#sec-Introspection)
+//! result into type and directive builders.
+//!
+//! Introspection JSON carries no source text, so everything loaded
+//! here uses [`Span::dummy()`]. Instead of spans, malformed input
+//! is reported with a JSON path to the offending value (e.g.
+//! `$.data.__schema.types[3].fields[0].type`), and errors raised
+//! by the builders themselves get a note naming the JSON path of
+//! the record they were built from.
+
+use crate::directive_annotation::DirectiveAnnotation;
+use crate::error_note::ErrorNote;
+use crate::names::DirectiveName;
+use crate::names::FieldName;
+use crate::names::TypeName;
+use crate::schema::schema_build_error::SchemaBuildError;
+use crate::schema::schema_build_error::SchemaBuildErrorKind;
+use crate::schema::schema_builder::parse_schema_source;
+use crate::schema::schema_builder::SchemaBuilder;
+use crate::span::Span;
+use crate::type_builders::ast_helpers;
+use crate::type_builders::DirectiveBuilder;
+use crate::type_builders::EnumTypeBuilder;
+use crate::type_builders::EnumValueDefBuilder;
+use crate::type_builders::FieldDefBuilder;
+use crate::type_builders::InputFieldDefBuilder;
+use crate::type_builders::InputObjectTypeBuilder;
+use crate::type_builders::InterfaceTypeBuilder;
+use crate::type_builders::IntoGraphQLType;
+use crate::type_builders::ObjectTypeBuilder;
+use crate::type_builders::ParameterDefBuilder;
+use crate::type_builders::ScalarTypeBuilder;
+use crate::type_builders::UnionTypeBuilder;
+use crate::types::DirectiveLocationKind;
+use crate::types::TypeAnnotation;
+use crate::value::Value;
+use indexmap::IndexMap;
+use libgraphql_parser::ast;
+use serde_json::Map;
+use serde_json::Value as JsonValue;
+
+/// Directives the [`SchemaBuilder`] seeds itself. Introspection
+/// results list these alongside custom directives, but they must
+/// not be redefined.
+const BUILTIN_DIRECTIVE_NAMES: [&str; 5] = [
+    "deprecated",
+    "include",
+    "oneOf",
+    "skip",
+    "specifiedBy",
+];
+
+/// Scalars the [`SchemaBuilder`] seeds itself.
+const BUILTIN_SCALAR_NAMES: [&str; 5] = [
+    "Boolean",
+    "Float",
+    "ID",
+    "Int",
+    "String",
+];
+
+type JsonObject = Map<String, JsonValue>;
+
+/// The root operation type names declared by an introspection
+/// result's `queryType`, `mutationType`, and `subscriptionType`.
+pub(crate) struct IntrospectionRootTypes {
+    pub(crate) mutation: Option<TypeName>,
+    pub(crate) query: Option<TypeName>,
+    pub(crate) subscription: Option<TypeName>,
+}
+
+/// The parts of an introspection `__InputValue` shared by field
+/// parameters and input object fields.
+struct InputValueRecord {
+    default_value: Option<Value>,
+    deprecation: Option<DirectiveAnnotation>,
+    description: Option<String>,
+    name: String,
+    type_annotation: TypeAnnotation,
+}
+
+/// Walks an introspection result, absorbing every non-built-in
+/// type and directive it describes into a [`SchemaBuilder`].
+///
+/// Malformed records are skipped and reported; loading continues
+/// so that all problems are reported at once.
+pub(crate) struct IntrospectionLoader<'a> {
+    builder: &'a mut SchemaBuilder,
+    errors: Vec<SchemaBuildError>,
+}
+
+impl<'a> IntrospectionLoader<'a> {
+    pub(crate) fn new(builder: &'a mut SchemaBuilder) -> Self {
+        Self {
+            builder,
+            errors: vec![],
+        }
+    }
+
+    /// Loads `json`, which may be either a full GraphQL response
+    /// (`{"data": {"__schema": ...}}`) or just its `data`
+    /// (`{"__schema": ...}`).
+    pub(crate) fn load(
+        mut self,
+        json: &str,
+    ) -> Result<IntrospectionRootTypes, Vec<SchemaBuildError>> {
+        let root_types = self.load_json(json);
+        match root_types {
+            Some(root_types) if self.errors.is_empty() => Ok(root_types),
+            _ => Err(self.errors),
+        }
+    }
+
+    fn load_json(&mut self, json: &str) -> Option<IntrospectionRootTypes> {
+        let root: JsonValue = match serde_json::from_str(json) {
+            Ok(root) => root,
+            Err(err) => {
+                self.error("$", format!("invalid JSON: {err}"));
+                return None;
+            },
+        };
+        let root_obj = self.as_object(&root, "$")?;
+
+        let (data, data_path) = if root_obj.contains_key("data") {
+            (self.required_object(root_obj, "$", "data")?, "$.data")
+        } else if root_obj.contains_key("__schema") {
+            (root_obj, "$")
+        } else {
+            self.error(
+                "$",
+                "expected a `data` or `__schema` field".to_string(),
+            );
+            return None;
+        };
+        let schema_path = format!("{data_path}.__schema");
+        let schema = self.required_object(data, data_path, "__schema")?;
+        Some(self.load_schema(schema, &schema_path))
+    }
+
+    fn load_schema(
+        &mut self,
+        schema: &JsonObject,
+        path: &str,
+    ) -> IntrospectionRootTypes {
+        let query = self.root_type_name(schema, path, "queryType", true);
+        let mutation =
+            self.root_type_name(schema, path, "mutationType", false);
+        let subscription =
+            self.root_type_name(schema, path, "subscriptionType", false);
+
+        if let Some(types) = self.required_array(schema, path, "types") {
+            for (i, type_) in types.iter().enumerate() {
+                self.load_type(type_, &format!("{path}.types[{i}]"));
+            }
+        }
+        if let Some(directives) =
+            self.required_array(schema, path, "directives")
+        {
+            for (i, directive) in directives.iter().enumerate() {
+                self.load_directive(
+                    directive,
+                    &format!("{path}.directives[{i}]"),
+                );
+            }
+        }
+
+        IntrospectionRootTypes { mutation, query, subscription }
+    }
+
+    /// Reads a root operation type reference (`{"name": "Query"}`).
+    fn root_type_name(
+        &mut self,
+        schema: &JsonObject,
+        path: &str,
+        key: &str,
+        required: bool,
+    ) -> Option<TypeName> {
+        let type_ref = if required {
+            self.required_object(schema, path, key)?
+        } else {
+            self.optional_object(schema, path, key)?
+        };
+        let type_ref_path = format!("{path}.{key}");
+        self.required_str(type_ref, &type_ref_path, "name")
+            .map(TypeName::new)
+    }
+
+    // ---------------------------------------------------------
+    // __Type
+    // ---------------------------------------------------------
+
+    fn load_type(&mut self, value: &JsonValue, path: &str) {
+        let Some(obj) = self.as_object(value, path) else { return };
+        let kind = self.required_str(obj, path, "kind");
+        let name = self.required_str(obj, path, "name");
+        let description = self.optional_str(obj, path, "description");
+        let (Some(kind), Some(name)) = (kind, name) else { return };
+
+        // The introspection types themselves (`__Schema`,
+        // `__Type`, ...) are implicit in every schema.
+        if name.starts_with("__") {
+            return;
+        }
+
+        match kind {
+            "ENUM" => self.load_enum(obj, path, name, description),
+            "INPUT_OBJECT" => {
+                self.load_input_object(obj, path, name, description)
+            },
+            "INTERFACE" => {
+                self.load_interface(obj, path, name, description)
+            },
+            "OBJECT" => self.load_object(obj, path, name, description),
+            "SCALAR" => self.load_scalar(obj, path, name, description),
+            "UNION" => self.load_union(obj, path, name, description),
+            "LIST" | "NON_NULL" => self.error(
+                &format!("{path}.kind"),
+                format!(
+                    "wrapping type kind `{kind}` cannot be a named type",
+                ),
+            ),
+            _ => self.error(
+                &format!("{path}.kind"),
+                format!("unknown type kind `{kind}`"),
+            ),
+        }
+    }
+
+    fn load_enum(
+        &mut self,
+        obj: &JsonObject,
+        path: &str,
+        name: &str,
+        description: Option<&str>,
+    ) {
+        let Some(mut builder) = self.check(
+            EnumTypeBuilder::new(name, Span::dummy()),
+            path,
+        ) else { return };
+        if let Some(description) = description {
+            builder.set_description(description);
+        }
+
+        let values_path = format!("{path}.enumValues");
+        let values = self.required_array(obj, path, "enumValues");
+        for (i, value) in values.into_iter().flatten().enumerate() {
+            let value_path = format!("{values_path}[{i}]");
+            let Some(value_obj) = self.as_object(value, &value_path)
+            else { continue };
+            let value_name =
+                self.required_str(value_obj, &value_path, "name");
+            let value_description =
+                self.optional_str(value_obj, &value_path, "description");
+            let deprecation = self.deprecation(value_obj, &value_path);
+            let Some(value_name) = value_name else { continue };
+
+            let mut value_builder =
+                EnumValueDefBuilder::new(value_name, Span::dummy());
+            if let Some(value_description) = value_description {
+                value_builder.set_description(value_description);
+            }
+            if let Some(deprecation) = deprecation {
+                value_builder.add_directive(deprecation);
+            }
+            let result = builder.add_value(value_builder).map(|_| ());
+            self.check(result, &value_path);
+        }
+
+        self.absorb_type(builder, path);
+    }
+
+    fn load_input_object(
+        &mut self,
+        obj: &JsonObject,
+        path: &str,
+        name: &str,
+        description: Option<&str>,
+    ) {
+        let Some(mut builder) = self.check(
+            InputObjectTypeBuilder::new(name, Span::dummy()),
+            path,
+        ) else { return };
+        if let Some(description) = description {
+            builder.set_description(description);
+        }
+        if self.optional_bool(obj, path, "isOneOf") == Some(true) {
+            builder.add_directive(directive_annotation("oneOf", []));
+        }
+
+        let fields_path = format!("{path}.inputFields");
+        let fields = self.required_array(obj, path, "inputFields");
+        for (i, field) in fields.into_iter().flatten().enumerate() {
+            let field_path = format!("{fields_path}[{i}]");
+            let Some(record) = self.input_value(field, &field_path)
+            else { continue };
+
+            let mut field_builder = InputFieldDefBuilder::new(
+                record.name,
+                record.type_annotation,
+                Span::dummy(),
+            );
+            if let Some(description) = record.description {
+                field_builder.set_description(description);
+            }
+            if let Some(default_value) = record.default_value {
+                field_builder.set_default_value(default_value);
+            }
+            if let Some(deprecation) = record.deprecation {
+                field_builder.add_directive(deprecation);
+            }
+            let result = builder.add_field(field_builder).map(|_| ());
+            self.check(result, &field_path);
+        }
+
+        self.absorb_type(builder, path);
+    }
+
+    fn load_interface(
+        &mut self,
+        obj: &JsonObject,
+        path: &str,
+        name: &str,
+        description: Option<&str>,
+    ) {
+        let Some(mut builder) = self.check(
+            InterfaceTypeBuilder::new(name, Span::dummy()),
+            path,
+        ) else { return };
+        if let Some(description) = description {
+            builder.set_description(description);
+        }
+
+        for (field, field_path) in self.fields(obj, path) {
+            let result = builder.add_field(field).map(|_| ());
+            self.check(result, &field_path);
+        }
+        // `interfaces` on interface types was only added in the
+        // October 2021 spec, so older servers may omit it.
+        let interfaces_path = format!("{path}.interfaces");
+        let interfaces = self.optional_array(obj, path, "interfaces");
+        for (i, iface) in interfaces.into_iter().flatten().enumerate() {
+            let iface_path = format!("{interfaces_path}[{i}]");
+            let Some(iface_name) = self.named_type_ref(iface, &iface_path)
+            else { continue };
+            let result = builder
+                .add_implements(iface_name, Span::dummy())
+                .map(|_| ());
+            self.check(result, &iface_path);
+        }
+
+        self.absorb_type(builder, path);
+    }
+
+    fn load_object(
+        &mut self,
+        obj: &JsonObject,
+        path: &str,
+        name: &str,
+        description: Option<&str>,
+    ) {
+        let Some(mut builder) = self.check(
+            ObjectTypeBuilder::new(name, Span::dummy()),
+            path,
+        ) else { return };
+        if let Some(description) = description {
+            builder.set_description(description);
+        }
+
+        for (field, field_path) in self.fields(obj, path) {
+            let result = builder.add_field(field).map(|_| ());
+            self.check(result, &field_path);
+        }
+        let interfaces_path = format!("{path}.interfaces");
+        let interfaces = self.required_array(obj, path, "interfaces");
+        for (i, iface) in interfaces.into_iter().flatten().enumerate() {
+            let iface_path = format!("{interfaces_path}[{i}]");
+            let Some(iface_name) = self.named_type_ref(iface, &iface_path)
+            else { continue };
+            let result = builder
+                .add_implements(iface_name, Span::dummy())
+                .map(|_| ());
+            self.check(result, &iface_path);
+        }
+
+        self.absorb_type(builder, path);
+    }
+
+    fn load_scalar(
+        &mut self,
+        obj: &JsonObject,
+        path: &str,
+        name: &str,
+        description: Option<&str>,
+    ) {
+        if BUILTIN_SCALAR_NAMES.contains(&name) {
+            return;
+        }
+        let Some(mut builder) = self.check(
+            ScalarTypeBuilder::new(name, Span::dummy()),
+            path,
+        ) else { return };
+        if let Some(description) = description {
+            builder.set_description(description);
+        }
+        if let Some(url) = self.optional_str(obj, path, "specifiedByURL") {
+            builder.add_directive(directive_annotation(
+                "specifiedBy",
+                [("url", Value::String(url.to_string()))],
+            ));
+        }
+
+        self.absorb_type(builder, path);
+    }
+
+    fn load_union(
+        &mut self,
+        obj: &JsonObject,
+        path: &str,
+        name: &str,
+        description: Option<&str>,
+    ) {
+        let Some(mut builder) = self.check(
+            UnionTypeBuilder::new(name, Span::dummy()),
+            path,
+        ) else { return };
+        if let Some(description) = description {
+            builder.set_description(description);
+        }
+
+        let members_path = format!("{path}.possibleTypes");
+        let members = self.required_array(obj, path, "possibleTypes");
+        for (i, member) in members.into_iter().flatten().enumerate() {
+            let member_path = format!("{members_path}[{i}]");
+            let Some(member_name) =
+                self.named_type_ref(member, &member_path)
+            else { continue };
+            let result = builder
+                .add_member(member_name, Span::dummy())
+                .map(|_| ());
+            self.check(result, &member_path);
+        }
+
+        self.absorb_type(builder, path);
+    }
+
+    fn absorb_type(&mut self, builder: impl IntoGraphQLType, path: &str) {
+        let result = self.builder.absorb_type(builder).map(|_| ());
+        self.check(result, path);
+    }
+
+    // ---------------------------------------------------------
+    // __Field and __InputValue
+    // ---------------------------------------------------------
+
+    /// Reads the `fields` of an object or interface type,
+    /// returning each field builder with its JSON path.
+    fn fields(
+        &mut self,
+        obj: &JsonObject,
+        path: &str,
+    ) -> Vec<(FieldDefBuilder, String)> {
+        let fields_path = format!("{path}.fields");
+        let Some(fields) = self.required_array(obj, path, "fields") else {
+            return vec![];
+        };
+
+        let mut field_builders = vec![];
+        for (i, field) in fields.iter().enumerate() {
+            let field_path = format!("{fields_path}[{i}]");
+            if let Some(field_builder) = self.field(field, &field_path) {
+                field_builders.push((field_builder, field_path));
+            }
+        }
+        field_builders
+    }
+
+    fn field(
+        &mut self,
+        value: &JsonValue,
+        path: &str,
+    ) -> Option<FieldDefBuilder> {
+        let obj = self.as_object(value, path)?;
+        let name = self.required_str(obj, path, "name");
+        let description = self.optional_str(obj, path, "description");
+        let type_annotation = self.required_type_ref(obj, path, "type");
+        let deprecation = self.deprecation(obj, path);
+
+        let mut parameters = vec![];
+        let args_path = format!("{path}.args");
+        let args = self.required_array(obj, path, "args");
+        for (i, arg) in args.into_iter().flatten().enumerate() {
+            let arg_path = format!("{args_path}[{i}]");
+            if let Some(parameter) = self.parameter(arg, &arg_path) {
+                parameters.push((parameter, arg_path));
+            }
+        }
+
+        let mut builder = FieldDefBuilder::new(
+            name?,
+            type_annotation?,
+            Span::dummy(),
+        );
+        if let Some(description) = description {
+            builder.set_description(description);
+        }
+        if let Some(deprecation) = deprecation {
+            builder.add_directive(deprecation);
+        }
+        for (parameter, arg_path) in parameters {
+            let result = builder.add_parameter(parameter).map(|_| ());
+            self.check(result, &arg_path);
+        }
+        Some(builder)
+    }
+
+    fn parameter(
+        &mut self,
+        value: &JsonValue,
+        path: &str,
+    ) -> Option<ParameterDefBuilder> {
+        let record = self.input_value(value, path)?;
+        let mut builder = ParameterDefBuilder::new(
+            record.name,
+            record.type_annotation,
+            Span::dummy(),
+        );
+        if let Some(description) = record.description {
+            builder.set_description(description);
+        }
+        if let Some(default_value) = record.default_value {
+            builder.set_default_value(default_value);
+        }
+        if let Some(deprecation) = record.deprecation {
+            builder.add_directive(deprecation);
+        }
+        Some(builder)
+    }
+
+    fn input_value(
+        &mut self,
+        value: &JsonValue,
+        path: &str,
+    ) -> Option<InputValueRecord> {
+        let obj = self.as_object(value, path)?;
+        let name = self.required_str(obj, path, "name");
+        let description = self.optional_str(obj, path, "description");
+        let type_annotation = self.required_type_ref(obj, path, "type");
+        let deprecation = self.deprecation(obj, path);
+
+        let default_value_path = format!("{path}.defaultValue");
+        let default_value = match self.optional_str(
+            obj,
+            path,
+            "defaultValue",
+        ) {
+            Some(literal) => match parse_default_value(literal) {
+                Some(value) => Some(value),
+                None => {
+                    self.error(
+                        &default_value_path,
+                        format!(
+                            "`{literal}` is not a valid GraphQL input \
+                            value literal",
+                        ),
+                    );
+                    return None;
+                },
+            },
+            None => None,
+        };
+
+        Some(InputValueRecord {
+            default_value,
+            deprecation,
+            description: description.map(str::to_string),
+            name: name?.to_string(),
+            type_annotation: type_annotation?,
+        })
+    }
+
+    /// Converts `isDeprecated` / `deprecationReason` into a
+    /// `@deprecated` annotation.
+    fn deprecation(
+        &mut self,
+        obj: &JsonObject,
+        path: &str,
+    ) -> Option<DirectiveAnnotation> {
+        if self.optional_bool(obj, path, "isDeprecated") != Some(true) {
+            return None;
+        }
+        let reason = self.optional_str(obj, path, "deprecationReason");
+        Some(directive_annotation(
+            "deprecated",
+            reason.map(|reason| {
+                ("reason", Value::String(reason.to_string()))
+            }),
+        ))
+    }
+
+    // ---------------------------------------------------------
+    // __Type references
+    // ---------------------------------------------------------
+
+    fn required_type_ref(
+        &mut self,
+        obj: &JsonObject,
+        path: &str,
+        key: &str,
+    ) -> Option<TypeAnnotation> {
+        let value = self.required(obj, path, key)?;
+        self.type_ref(value, &format!("{path}.{key}"))
+    }
+
+    /// Converts a (possibly wrapped) type reference such as
+    /// `{"kind": "NON_NULL", "ofType": {"kind": "SCALAR", ...}}`
+    /// into a [`TypeAnnotation`].
+    fn type_ref(
+        &mut self,
+        value: &JsonValue,
+        path: &str,
+    ) -> Option<TypeAnnotation> {
+        let obj = self.as_object(value, path)?;
+        let kind = self.required_str(obj, path, "kind")?;
+        match kind {
+            "LIST" => {
+                let inner = self.required_type_ref(obj, path, "ofType")?;
+                Some(TypeAnnotation::list(inner, /* nullable = */ true))
+            },
+            "NON_NULL" => {
+                let mut inner =
+                    self.required_type_ref(obj, path, "ofType")?;
+                if !inner.nullable() {
+                    self.error(
+                        &format!("{path}.ofType"),
+                        "`NON_NULL` must not wrap another `NON_NULL`"
+                            .to_string(),
+                    );
+                    return None;
+                }
+                match &mut inner {
+                    TypeAnnotation::List(list) => list.nullable = false,
+                    TypeAnnotation::Named(named) => named.nullable = false,
+                }
+                Some(inner)
+            },
+            "ENUM" | "INPUT_OBJECT" | "INTERFACE" | "OBJECT" | "SCALAR"
+            | "UNION" => {
+                let name = self.required_str(obj, path, "name")?;
+                Some(TypeAnnotation::named(name, /* nullable = */ true))
+            },
+            _ => {
+                self.error(
+                    &format!("{path}.kind"),
+                    format!("unknown type kind `{kind}`"),
+                );
+                None
+            },
+        }
+    }
+
+    /// Reads the name of an unwrapped type reference, as listed in
+    /// `interfaces` and `possibleTypes`.
+    fn named_type_ref<'j>(
+        &mut self,
+        value: &'j JsonValue,
+        path: &str,
+    ) -> Option<&'j str> {
+        let obj = self.as_object(value, path)?;
+        self.required_str(obj, path, "name")
+    }
+
+    // ---------------------------------------------------------
+    // __Directive
+    // ---------------------------------------------------------
+
+    fn load_directive(&mut self, value: &JsonValue, path: &str) {
+        let Some(obj) = self.as_object(value, path) else { return };
+        let Some(name) = self.required_str(obj, path, "name") else {
+            return;
+        };
+        if BUILTIN_DIRECTIVE_NAMES.contains(&name) {
+            return;
+        }
+        let description = self.optional_str(obj, path, "description");
+        // `isRepeatable` was only added in the October 2021 spec.
+        let is_repeatable = self.optional_bool(obj, path, "isRepeatable");
+
+        let mut locations = vec![];
+        let locations_path = format!("{path}.locations");
+        let location_values = self.required_array(obj, path, "locations");
+        for (i, location) in location_values.into_iter().flatten().enumerate()
+        {
+            let location_path = format!("{locations_path}[{i}]");
+            let Some(location) = location.as_str() else {
+                self.error(
+                    &location_path,
+                    format!("expected a string, found {}", json_kind(location)),
+                );
+                continue;
+            };
+            match parse_directive_location(location) {
+                Some(location) => locations.push(location),
+                None => self.error(
+                    &location_path,
+                    format!("unknown directive location `{location}`"),
+                ),
+            }
+        }
+
+        let mut parameters = vec![];
+        let args_path = format!("{path}.args");
+        let args = self.required_array(obj, path, "args");
+        for (i, arg) in args.into_iter().flatten().enumerate() {
+            let arg_path = format!("{args_path}[{i}]");
+            if let Some(parameter) = self.parameter(arg, &arg_path) {
+                parameters.push((parameter, arg_path));
+            }
+        }
+
+        let Some(mut builder) = self.check(
+            DirectiveBuilder::new(name, Span::dummy()),
+            path,
+        ) else { return };
+        if let Some(description) = description {
+            builder.set_description(description);
+        }
+        builder.set_repeatable(is_repeatable.unwrap_or(false));
+        for location in locations {
+            builder.add_location(location);
+        }
+        for (parameter, arg_path) in parameters {
+            let result = builder.add_parameter(parameter).map(|_| ());
+            self.check(result, &arg_path);
+        }
+
+        let result = self.builder.absorb_directive(builder).map(|_| ());
+        self.check(result, path);
+    }
+
+    // ---------------------------------------------------------
+    // JSON accessors
+    // ---------------------------------------------------------
+
+    fn as_object<'j>(
+        &mut self,
+        value: &'j JsonValue,
+        path: &str,
+    ) -> Option<&'j JsonObject> {
+        match value {
+            JsonValue::Object(obj) => Some(obj),
+            _ => {
+                self.error(
+                    path,
+                    format!("expected an object, found {}", json_kind(value)),
+                );
+                None
+            },
+        }
+    }
+
+    /// Returns `obj[key]`, reporting an error if it is missing or
+    /// `null`.
+    fn required<'j>(
+        &mut self,
+        obj: &'j JsonObject,
+        path: &str,
+        key: &str,
+    ) -> Option<&'j JsonValue> {
+        match obj.get(key) {
+            None | Some(JsonValue::Null) => {
+                self.error(
+                    &format!("{path}.{key}"),
+                    "missing required field".to_string(),
+                );
+                None
+            },
+            Some(value) => Some(value),
+        }
+    }
+
+    fn required_array<'j>(
+        &mut self,
+        obj: &'j JsonObject,
+        path: &str,
+        key: &str,
+    ) -> Option<&'j Vec<JsonValue>> {
+        let value = self.required(obj, path, key)?;
+        self.expect_array(value, path, key)
+    }
+
+    fn optional_array<'j>(
+        &mut self,
+        obj: &'j JsonObject,
+        path: &str,
+        key: &str,
+    ) -> Option<&'j Vec<JsonValue>> {
+        match obj.get(key) {
+            None | Some(JsonValue::Null) => None,
+            Some(value) => self.expect_array(value, path, key),
+        }
+    }
+
+    fn expect_array<'j>(
+        &mut self,
+        value: &'j JsonValue,
+        path: &str,
+        key: &str,
+    ) -> Option<&'j Vec<JsonValue>> {
+        match value {
+            JsonValue::Array(items) => Some(items),
+            _ => {
+                self.error(
+                    &format!("{path}.{key}"),
+                    format!("expected an array, found {}", json_kind(value)),
+                );
+                None
+            },
+        }
+    }
+
+    fn required_object<'j>(
+        &mut self,
+        obj: &'j JsonObject,
+        path: &str,
+        key: &str,
+    ) -> Option<&'j JsonObject> {
+        let value = self.required(obj, path, key)?;
+        self.as_object(value, &format!("{path}.{key}"))
+    }
+
+    fn optional_object<'j>(
+        &mut self,
+        obj: &'j JsonObject,
+        path: &str,
+        key: &str,
+    ) -> Option<&'j JsonObject> {
+        match obj.get(key) {
+            None | Some(JsonValue::Null) => None,
+            Some(value) => self.as_object(value, &format!("{path}.{key}")),
+        }
+    }
+
+    fn required_str<'j>(
+        &mut self,
+        obj: &'j JsonObject,
+        path: &str,
+        key: &str,
+    ) -> Option<&'j str> {
+        let value = self.required(obj, path, key)?;
+        self.expect_str(value, path, key)
+    }
+
+    fn optional_str<'j>(
+        &mut self,
+        obj: &'j JsonObject,
+        path: &str,
+        key: &str,
+    ) -> Option<&'j str> {
+        match obj.get(key) {
+            None | Some(JsonValue::Null) => None,
+            Some(value) => self.expect_str(value, path, key),
+        }
+    }
+
+    fn expect_str<'j>(
+        &mut self,
+        value: &'j JsonValue,
+        path: &str,
+        key: &str,
+    ) -> Option<&'j str> {
+        match value {
+            JsonValue::String(s) => Some(s),
+            _ => {
+                self.error(
+                    &format!("{path}.{key}"),
+                    format!("expected a string, found {}", json_kind(value)),
+                );
+                None
+            },
+        }
+    }
+
+    fn optional_bool(
+        &mut self,
+        obj: &JsonObject,
+        path: &str,
+        key: &str,
+    ) -> Option<bool> {
+        match obj.get(key) {
+            None | Some(JsonValue::Null) => None,
+            Some(JsonValue::Bool(b)) => Some(*b),
+            Some(value) => {
+                self.error(
+                    &format!("{path}.{key}"),
+                    format!("expected a boolean, found {}", json_kind(value)),
+                );
+                None
+            },
+        }
+    }
+
+    // ---------------------------------------------------------
+    // Error reporting
+    // ---------------------------------------------------------
+
+    fn error(&mut self, json_path: &str, message: String) {
+        self.errors.push(SchemaBuildError::new(
+            SchemaBuildErrorKind::MalformedIntrospection {
+                json_path: json_path.to_string(),
+                message,
+            },
+            Span::dummy(),
+            vec![],
+        ));
+    }
+
+    /// Unwraps a builder result, recording its error (annotated
+    /// with the JSON path of the record being loaded) on failure.
+    fn check<T>(
+        &mut self,
+        result: Result<T, SchemaBuildError>,
+        path: &str,
+    ) -> Option<T> {
+        match result {
+            Ok(value) => Some(value),
+            Err(err) => {
+                let mut notes = err.notes().to_vec();
+                notes.push(ErrorNote::general(format!(
+                    "defined by the introspection record at `{path}`",
+                )));
+                self.errors.push(SchemaBuildError::new(
+                    err.kind().clone(),
+                    err.span(),
+                    notes,
+                ));
+                None
+            },
+        }
+    }
+}
+
+fn directive_annotation(
+    name: &str,
+    arguments: impl IntoIterator<Item = (&'static str, Value)>,
+) -> DirectiveAnnotation {
+    DirectiveAnnotation {
+        arguments: arguments
+            .into_iter()
+            .map(|(arg_name, value)| (FieldName::new(arg_name), value))
+            .collect::<IndexMap<_, _>>(),
+        name: DirectiveName::new(name),
+        span: Span::dummy(),
+    }
+}
+
+fn json_kind(value: &JsonValue) -> &'static str {
+    match value {
+        JsonValue::Array(_) => "an array",
+        JsonValue::Bool(_) => "a boolean",
+        JsonValue::Null => "null",
+        JsonValue::Number(_) => "a number",
+        JsonValue::Object(_) => "an object",
+        JsonValue::String(_) => "a string",
+    }
+}
+
+/// Parses an introspection `defaultValue` (a GraphQL literal
+/// printed as a string, e.g. `"{limit: 10}"`).
+///
+/// The literal is parsed as the default value of a one-field input
+/// object so that the parser enforces const-ness exactly as it
+/// would for SDL. Anything other than a single clean field (e.g. a
+/// literal that closes the braces itself) is rejected.
+fn parse_default_value(literal: &str) -> Option<Value> {
+    let source = format!(
+        "input IntrospectionDefaultValue {{ value: Int = {literal} }}",
+    );
+    let parse_result = parse_schema_source(&source);
+    let (doc, _) = parse_result.valid()?;
+    let [ast::Definition::TypeDefinition(
+        ast::TypeDefinition::InputObject(input_obj),
+    )] = doc.definitions.as_slice() else {
+        return None;
+    };
+    let [field] = input_obj.fields.as_slice() else {
+        return None;
+    };
+    field.default_value.as_ref().map(ast_helpers::value_from_ast)
+}
+
+fn parse_directive_location(
+    location: &str,
+) -> Option<DirectiveLocationKind> {
+    Some(match location {
+        "ARGUMENT_DEFINITION" => DirectiveLocationKind::ArgumentDefinition,
+        "ENUM" => DirectiveLocationKind::Enum,
+        "ENUM_VALUE" => DirectiveLocationKind::EnumValue,
+        "FIELD" => DirectiveLocationKind::Field,
+        "FIELD_DEFINITION" => DirectiveLocationKind::FieldDefinition,
+        "FRAGMENT_DEFINITION" => DirectiveLocationKind::FragmentDefinition,
+        "FRAGMENT_SPREAD" => DirectiveLocationKind::FragmentSpread,
+        "INLINE_FRAGMENT" => DirectiveLocationKind::InlineFragment,
+        "INPUT_FIELD_DEFINITION" => {
+            DirectiveLocationKind::InputFieldDefinition
+        },
+        "INPUT_OBJECT" => DirectiveLocationKind::InputObject,
+        "INTERFACE" => DirectiveLocationKind::Interface,
+        "MUTATION" => DirectiveLocationKind::Mutation,
+        "OBJECT" => DirectiveLocationKind::Object,
+        "QUERY" => DirectiveLocationKind::Query,
+        "SCALAR" => DirectiveLocationKind::Scalar,
+        "SCHEMA" => DirectiveLocationKind::Schema,
+        "SUBSCRIPTION" => DirectiveLocationKind::Subscription,
+        "UNION" => DirectiveLocationKind::Union,
+        "VARIABLE_DEFINITION" => DirectiveLocationKind::VariableDefinition,
+        _ => return None,
+    })
+}
@@ -0,0 +1,38 @@
+/// Options controlling how
+/// [`Schema::to_introspection_json_with_options()`](crate::schema::Schema::to_introspection_json_with_options)
+/// generates introspection data.
+///
+/// # Example
+///
+/// ```rust
+/// # use libgraphql_core_v1 as libgraphql_core;
+/// use libgraphql_core::schema::IntrospectionOptions;
+///
+/// // What a client sees when it omits `includeDeprecated`
+/// let active_only = IntrospectionOptions {
+///     include_deprecated: false,
+/// };
+/// # assert!(IntrospectionOptions::default().include_deprecated);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct IntrospectionOptions {
+    /// The value passed as the `includeDeprecated` argument of
+    /// `__Type.fields`, `__Type.enumValues`, `__Type.inputFields`,
+    /// `__Field.args`, and `__Directive.args`. When `false`,
+    /// deprecated elements are omitted from those lists.
+    ///
+    /// Defaults to `true`, matching the standard
+    /// `IntrospectionQuery` (the spec's own default for the
+    /// argument is `false`).
+    ///
+    /// See [Deprecation](https://spec.graphql.org/September2025/#sec-Deprecation).
+    pub include_deprecated: bool,
+}
+
+impl Default for IntrospectionOptions {
+    fn default() -> Self {
+        Self {
+            include_deprecated: true,
+        }
+    }
+}
@@ -0,0 +1,341 @@
+use crate::directive_annotation::DirectiveAnnotation;
+use crate::names::FieldName;
+use crate::names::TypeName;
+use crate::schema::introspection_options::IntrospectionOptions;
+use crate::schema::introspection_types::introspection_types;
+use crate::schema::schema_def::Schema;
+use crate::types::DeprecationState;
+use crate::types::DirectiveDefinition;
+use crate::types::EnumValue;
+use crate::types::FieldDefinition;
+use crate::types::GraphQLType;
+use crate::types::InputField;
+use crate::types::ParameterDefinition;
+use crate::types::TypeAnnotation;
+use crate::value::Value;
+use indexmap::IndexMap;
+use serde_json::json;
+use serde_json::Value as JsonValue;
+
+/// The `reason` a `@deprecated` annotation without an explicit
+/// reason resolves to.
+///
+/// See [@deprecated](https://spec.graphql.org/September2025/#sec--deprecated).
+const DEFAULT_DEPRECATION_REASON: &str = "No longer supported";
+
+/// Generates the result of the standard `IntrospectionQuery` for a
+/// validated [`Schema`].
+///
+/// Every record carries exactly the fields that query selects
+/// (including `isDeprecated`/`deprecationReason` on input values,
+/// `specifiedByURL`, and `isOneOf`), and fields that do not apply
+/// to a type's kind (e.g. `enumValues` on an object type) are
+/// `null`, as a server would resolve them.
+pub(crate) struct IntrospectionPrinter<'a> {
+    options: &'a IntrospectionOptions,
+    schema: &'a Schema,
+}
+
+impl<'a> IntrospectionPrinter<'a> {
+    pub fn new(
+        schema: &'a Schema,
+        options: &'a IntrospectionOptions,
+    ) -> Self {
+        Self { options, schema }
+    }
+
+    pub fn print(&self) -> JsonValue {
+        let root_type = |name: Option<&TypeName>| match name {
+            Some(name) => json!({ "name": name.as_str() }),
+            None => JsonValue::Null,
+        };
+        let types: Vec<JsonValue> = self.schema
+            .types()
+            .values()
+            .chain(introspection_types().values())
+            .map(|type_| self.full_type(type_))
+            .collect();
+        let directives: Vec<JsonValue> = self.schema
+            .directive_defs()
+            .values()
+            .map(|def| self.directive(def))
+            .collect();
+
+        json!({
+            "__schema": {
+                // Schema descriptions are not retained by the
+                // builder.
+                "description": null,
+                "queryType": root_type(Some(self.schema.query_type_name())),
+                "mutationType": root_type(self.schema.mutation_type_name()),
+                "subscriptionType":
+                    root_type(self.schema.subscription_type_name()),
+                "types": types,
+                "directives": directives,
+            },
+        })
+    }
+
+    // ---------------------------------------------------------
+    // __Type
+    // ---------------------------------------------------------
+
+    fn full_type(&self, type_: &GraphQLType) -> JsonValue {
+        let mut fields = JsonValue::Null;
+        let mut input_fields = JsonValue::Null;
+        let mut interfaces = JsonValue::Null;
+        let mut enum_values = JsonValue::Null;
+        let mut possible_types = JsonValue::Null;
+        let mut specified_by_url = JsonValue::Null;
+        let mut is_one_of = JsonValue::Null;
+
+        match type_ {
+            GraphQLType::Enum(enum_type) => {
+                enum_values = self.enum_values(enum_type.values().values());
+            },
+            GraphQLType::InputObject(input_obj) => {
+                input_fields = self.input_fields(input_obj.fields().values());
+                is_one_of = input_obj.is_one_of().into();
+            },
+            GraphQLType::Interface(iface) => {
+                fields = self.fields(iface.fields().values());
+                interfaces = self.named_type_refs(
+                    iface.interfaces().iter().map(|iface| &iface.value),
+                );
+                possible_types = self.named_type_refs(
+                    self.schema
+                        .possible_types(iface.name().as_str())
+                        .into_iter()
+                        .map(|obj| obj.name()),
+                );
+            },
+            GraphQLType::Object(obj) => {
+                fields = self.fields(obj.fields().values());
+                interfaces = self.named_type_refs(
+                    obj.interfaces().iter().map(|iface| &iface.value),
+                );
+            },
+            GraphQLType::Scalar(scalar) => {
+                specified_by_url = scalar.specified_by_url()
+                    .map_or(JsonValue::Null, JsonValue::from);
+            },
+            GraphQLType::Union(union_type) => {
+                possible_types = self.named_type_refs(
+                    union_type.members().iter().map(|member| &member.value),
+                );
+            },
+        }
+
+        json!({
+            "kind": type_kind_str(type_),
+            "name": type_.name().as_str(),
+            "description": type_.description(),
+            "specifiedByURL": specified_by_url,
+            "isOneOf": is_one_of,
+            "fields": fields,
+            "inputFields": input_fields,
+            "interfaces": interfaces,
+            "enumValues": enum_values,
+            "possibleTypes": possible_types,
+        })
+    }
+
+    fn fields<'f>(
+        &self,
+        fields: impl Iterator<Item = &'f FieldDefinition>,
+    ) -> JsonValue {
+        fields
+            .map(|field| {
+                (field, DeprecationState::from_directives(field.directives()))
+            })
+            .filter(|(_, state)| self.is_included(state))
+            .map(|(field, state)| {
+                let (is_deprecated, reason) = deprecation_fields(&state);
+                json!({
+                    "name": field.name().as_str(),
+                    "description": field.description(),
+                    "args": self.args(field.parameters()),
+                    "type": self.type_ref(field.type_annotation()),
+                    "isDeprecated": is_deprecated,
+                    "deprecationReason": reason,
+                })
+            })
+            .collect()
+    }
+
+    fn enum_values<'v>(
+        &self,
+        values: impl Iterator<Item = &'v EnumValue>,
+    ) -> JsonValue {
+        values
+            .map(|value| {
+                (value, DeprecationState::from_directives(value.directives()))
+            })
+            .filter(|(_, state)| self.is_included(state))
+            .map(|(value, state)| {
+                let (is_deprecated, reason) = deprecation_fields(&state);
+                json!({
+                    "name": value.name().as_str(),
+                    "description": value.description(),
+                    "isDeprecated": is_deprecated,
+                    "deprecationReason": reason,
+                })
+            })
+            .collect()
+    }
+
+    // ---------------------------------------------------------
+    // __InputValue
+    // ---------------------------------------------------------
+
+    fn args(
+        &self,
+        params: &IndexMap<FieldName, ParameterDefinition>,
+    ) -> JsonValue {
+        params
+            .values()
+            .filter_map(|param| self.input_value(
+                param.name(),
+                param.description(),
+                param.type_annotation(),
+                param.default_value(),
+                param.directives(),
+            ))
+            .collect()
+    }
+
+    fn input_fields<'f>(
+        &self,
+        fields: impl Iterator<Item = &'f InputField>,
+    ) -> JsonValue {
+        fields
+            .filter_map(|field| self.input_value(
+                field.name(),
+                field.description(),
+                field.type_annotation(),
+                field.default_value(),
+                field.directives(),
+            ))
+            .collect()
+    }
+
+    /// Returns `None` if the input value is deprecated and
+    /// deprecated elements are excluded.
+    fn input_value(
+        &self,
+        name: &FieldName,
+        description: Option<&str>,
+        type_annotation: &TypeAnnotation,
+        default_value: Option<&Value>,
+        directives: &[DirectiveAnnotation],
+    ) -> Option<JsonValue> {
+        let state = DeprecationState::from_directives(directives);
+        if !self.is_included(&state) {
+            return None;
+        }
+        let (is_deprecated, reason) = deprecation_fields(&state);
+        Some(json!({
+            "name": name.as_str(),
+            "description": description,
+            "type": self.type_ref(type_annotation),
+            "defaultValue": default_value.map(|value| value.to_string()),
+            "isDeprecated": is_deprecated,
+            "deprecationReason": reason,
+        }))
+    }
+
+    // ---------------------------------------------------------
+    // __Directive
+    // ---------------------------------------------------------
+
+    fn directive(&self, def: &DirectiveDefinition) -> JsonValue {
+        let locations: Vec<&str> = def.locations()
+            .iter()
+            .map(|loc| loc.as_str())
+            .collect();
+        json!({
+            "name": def.name().as_str(),
+            "description": def.description(),
+            "isRepeatable": def.is_repeatable(),
+            "locations": locations,
+            "args": self.args(def.parameters()),
+        })
+    }
+
+    // ---------------------------------------------------------
+    // Type references
+    // ---------------------------------------------------------
+
+    /// Converts a type annotation into a (possibly wrapped) type
+    /// reference such as
+    /// `{"kind": "NON_NULL", "name": null, "ofType": {...}}`.
+    fn type_ref(&self, annotation: &TypeAnnotation) -> JsonValue {
+        let unwrapped = match annotation {
+            TypeAnnotation::List(list) => json!({
+                "kind": "LIST",
+                "name": null,
+                "ofType": self.type_ref(list.inner()),
+            }),
+            TypeAnnotation::Named(named) => {
+                self.named_type_ref(named.type_name())
+            },
+        };
+        if annotation.nullable() {
+            unwrapped
+        } else {
+            json!({
+                "kind": "NON_NULL",
+                "name": null,
+                "ofType": unwrapped,
+            })
+        }
+    }
+
+    fn named_type_ref(&self, name: &TypeName) -> JsonValue {
+        let type_ = self.schema
+            .get_type(name.as_str())
+            .or_else(|| introspection_types().get(name.as_str()))
+            .expect("type references are validated at build time");
+        json!({
+            "kind": type_kind_str(type_),
+            "name": name.as_str(),
+            "ofType": null,
+        })
+    }
+
+    fn named_type_refs<'n>(
+        &self,
+        names: impl Iterator<Item = &'n TypeName>,
+    ) -> JsonValue {
+        names.map(|name| self.named_type_ref(name)).collect()
+    }
+
+    fn is_included(&self, state: &DeprecationState<'_>) -> bool {
+        self.options.include_deprecated || !state.is_deprecated()
+    }
+}
+
+/// The `isDeprecated` and `deprecationReason` values for an
+/// element.
+fn deprecation_fields<'s>(
+    state: &DeprecationState<'s>,
+) -> (bool, Option<&'s str>) {
+    match state {
+        DeprecationState::Active => (false, None),
+        DeprecationState::Deprecated { reason } => {
+            (true, Some(reason.unwrap_or(DEFAULT_DEPRECATION_REASON)))
+        },
+    }
+}
+
+/// The `__TypeKind` of a named type.
+fn type_kind_str(type_: &GraphQLType) -> &'static str {
+    match type_ {
+        GraphQLType::Enum(_) => "ENUM",
+        GraphQLType::InputObject(_) => "INPUT_OBJECT",
+        GraphQLType::Interface(_) => "INTERFACE",
+        GraphQLType::Object(_) => "OBJECT",
+        GraphQLType::Scalar(_) => "SCALAR",
+        GraphQLType::Union(_) => "UNION",
+    }
+}
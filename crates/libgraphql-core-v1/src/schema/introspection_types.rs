@@ -0,0 +1,230 @@
+//! The introspection meta-types (`__Schema`, `__Type`, ...) that
+//! every schema implicitly contains.
+//!
+//! [`SchemaBuilder`](crate::schema::SchemaBuilder) never stores
+//! these (the type builders reject `__`-prefixed names), but
+//! introspection results list them alongside the schema's own
+//! types, so they are constructed here directly.
+//!
+//! See [Schema Introspection](https://spec.graphql.org/September2025/#sec-Schema-Introspection).
+
+use crate::names::EnumValueName;
+use crate::names::FieldName;
+use crate::names::TypeName;
+use crate::span::Span;
+use crate::types::EnumType;
+use crate::types::EnumValue;
+use crate::types::FieldDefinition;
+use crate::types::FieldedTypeData;
+use crate::types::GraphQLType;
+use crate::types::ObjectType;
+use crate::types::ParameterDefinition;
+use crate::types::ParameterOwner;
+use crate::types::TypeAnnotation;
+use crate::value::Value;
+use indexmap::IndexMap;
+use std::sync::LazyLock;
+
+/// A meta-type field: `(name, type, takes_include_deprecated)`.
+type FieldSpec = (&'static str, &'static str, bool);
+
+const SCHEMA_FIELDS: &[FieldSpec] = &[
+    ("description", "String", false),
+    ("types", "[__Type!]!", false),
+    ("queryType", "__Type!", false),
+    ("mutationType", "__Type", false),
+    ("subscriptionType", "__Type", false),
+    ("directives", "[__Directive!]!", false),
+];
+
+const TYPE_FIELDS: &[FieldSpec] = &[
+    ("kind", "__TypeKind!", false),
+    ("name", "String", false),
+    ("description", "String", false),
+    ("fields", "[__Field!]", true),
+    ("interfaces", "[__Type!]", false),
+    ("possibleTypes", "[__Type!]", false),
+    ("enumValues", "[__EnumValue!]", true),
+    ("inputFields", "[__InputValue!]", true),
+    ("ofType", "__Type", false),
+    ("specifiedByURL", "String", false),
+    ("isOneOf", "Boolean", false),
+];
+
+const FIELD_FIELDS: &[FieldSpec] = &[
+    ("name", "String!", false),
+    ("description", "String", false),
+    ("args", "[__InputValue!]!", true),
+    ("type", "__Type!", false),
+    ("isDeprecated", "Boolean!", false),
+    ("deprecationReason", "String", false),
+];
+
+const INPUT_VALUE_FIELDS: &[FieldSpec] = &[
+    ("name", "String!", false),
+    ("description", "String", false),
+    ("type", "__Type!", false),
+    ("defaultValue", "String", false),
+    ("isDeprecated", "Boolean!", false),
+    ("deprecationReason", "String", false),
+];
+
+const ENUM_VALUE_FIELDS: &[FieldSpec] = &[
+    ("name", "String!", false),
+    ("description", "String", false),
+    ("isDeprecated", "Boolean!", false),
+    ("deprecationReason", "String", false),
+];
+
+const DIRECTIVE_FIELDS: &[FieldSpec] = &[
+    ("name", "String!", false),
+    ("description", "String", false),
+    ("isRepeatable", "Boolean!", false),
+    ("locations", "[__DirectiveLocation!]!", false),
+    ("args", "[__InputValue!]!", true),
+];
+
+const TYPE_KIND_VALUES: &[&str] = &[
+    "SCALAR",
+    "OBJECT",
+    "INTERFACE",
+    "UNION",
+    "ENUM",
+    "INPUT_OBJECT",
+    "LIST",
+    "NON_NULL",
+];
+
+const DIRECTIVE_LOCATION_VALUES: &[&str] = &[
+    "QUERY",
+    "MUTATION",
+    "SUBSCRIPTION",
+    "FIELD",
+    "FRAGMENT_DEFINITION",
+    "FRAGMENT_SPREAD",
+    "INLINE_FRAGMENT",
+    "VARIABLE_DEFINITION",
+    "SCHEMA",
+    "SCALAR",
+    "OBJECT",
+    "FIELD_DEFINITION",
+    "ARGUMENT_DEFINITION",
+    "INTERFACE",
+    "UNION",
+    "ENUM",
+    "ENUM_VALUE",
+    "INPUT_OBJECT",
+    "INPUT_FIELD_DEFINITION",
+];
+
+static INTROSPECTION_TYPES: LazyLock<IndexMap<TypeName, GraphQLType>> =
+    LazyLock::new(|| {
+        [
+            object_type("__Schema", SCHEMA_FIELDS),
+            object_type("__Type", TYPE_FIELDS),
+            enum_type("__TypeKind", TYPE_KIND_VALUES),
+            object_type("__Field", FIELD_FIELDS),
+            object_type("__InputValue", INPUT_VALUE_FIELDS),
+            object_type("__EnumValue", ENUM_VALUE_FIELDS),
+            object_type("__Directive", DIRECTIVE_FIELDS),
+            enum_type("__DirectiveLocation", DIRECTIVE_LOCATION_VALUES),
+        ]
+        .into_iter()
+        .map(|t| (t.name().clone(), t))
+        .collect()
+    });
+
+/// The introspection meta-types, in the order the spec defines
+/// them.
+pub(crate) fn introspection_types() -> &'static IndexMap<TypeName, GraphQLType> {
+    &INTROSPECTION_TYPES
+}
+
+fn object_type(name: &str, fields: &[FieldSpec]) -> GraphQLType {
+    let parent_type_name = TypeName::new(name);
+    let fields = fields
+        .iter()
+        .map(|&(field_name, type_str, takes_include_deprecated)| {
+            let mut parameters = IndexMap::new();
+            if takes_include_deprecated {
+                // includeDeprecated: Boolean! = false
+                parameters.insert(
+                    FieldName::new("includeDeprecated"),
+                    ParameterDefinition {
+                        default_value: Some(Value::Boolean(false)),
+                        default_value_spans: None,
+                        description: None,
+                        directives: vec![],
+                        name: FieldName::new("includeDeprecated"),
+                        owner: ParameterOwner::Field {
+                            field_name: FieldName::new(field_name),
+                            type_name: parent_type_name.clone(),
+                        },
+                        span: Span::builtin(),
+                        type_annotation: TypeAnnotation::named(
+                            "Boolean",
+                            /* nullable = */ false,
+                        ),
+                    },
+                );
+            }
+            let field = FieldDefinition {
+                description: None,
+                directives: vec![],
+                name: FieldName::new(field_name),
+                parameters,
+                parent_type_name: parent_type_name.clone(),
+                span: Span::builtin(),
+                type_annotation: parse_type_annotation(type_str),
+            };
+            (field.name.clone(), field)
+        })
+        .collect();
+
+    GraphQLType::Object(Box::new(ObjectType(FieldedTypeData {
+        description: None,
+        directives: vec![],
+        fields,
+        interfaces: vec![],
+        name: parent_type_name,
+        span: Span::builtin(),
+    })))
+}
+
+fn enum_type(name: &str, values: &[&str]) -> GraphQLType {
+    let parent_type_name = TypeName::new(name);
+    let values = values
+        .iter()
+        .map(|value_name| {
+            let value = EnumValue {
+                description: None,
+                directives: vec![],
+                name: EnumValueName::new(value_name),
+                parent_type_name: parent_type_name.clone(),
+                span: Span::builtin(),
+            };
+            (value.name.clone(), value)
+        })
+        .collect();
+
+    GraphQLType::Enum(Box::new(EnumType {
+        description: None,
+        directives: vec![],
+        name: parent_type_name,
+        span: Span::builtin(),
+        values,
+    }))
+}
+
+/// Parses the type references used in the tables above (e.g.
+/// `[__Type!]!`).
+fn parse_type_annotation(s: &str) -> TypeAnnotation {
+    let (inner, nullable) = match s.strip_suffix('!') {
+        Some(inner) => (inner, false),
+        None => (s, true),
+    };
+    match inner.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+        Some(item) => TypeAnnotation::list(parse_type_annotation(item), nullable),
+        None => TypeAnnotation::named(inner, nullable),
+    }
+}
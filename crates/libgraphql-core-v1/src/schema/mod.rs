@@ -1,16 +1,43 @@
+mod introspection_loader;
+mod introspection_options;
+mod introspection_printer;
+mod introspection_types;
 mod schema_build_error;
 mod schema_builder;
+mod schema_change;
+mod schema_coordinate;
 mod schema_def;
+mod schema_diff;
+mod schema_element;
 mod schema_errors;
+mod schema_merger;
+mod schema_transform;
+mod sdl_print_options;
+mod sdl_printer;
 mod type_validation_error;
+mod variable_coercion;
 
+pub use crate::schema::introspection_options::IntrospectionOptions;
 pub use crate::schema::schema_build_error::SchemaBuildError;
 pub use crate::schema::schema_build_error::SchemaBuildErrorKind;
 pub use crate::schema::schema_builder::SchemaBuilder;
+pub use crate::schema::schema_change::ChangeSeverity;
+pub use crate::schema::schema_change::SchemaChange;
+pub use crate::schema::schema_change::SchemaChangeKind;
+pub use crate::schema::schema_coordinate::SchemaCoordinate;
+pub use crate::schema::schema_coordinate::SchemaCoordinateParseError;
+pub use crate::schema::schema_coordinate::SchemaCoordinateParseErrorKind;
 pub use crate::schema::schema_def::Schema;
+pub use crate::schema::schema_diff::diff;
+pub use crate::schema::schema_element::SchemaElement;
 pub use crate::schema::schema_errors::SchemaErrors;
+pub use crate::schema::schema_merger::MergedSchema;
+pub use crate::schema::schema_merger::SchemaMerger;
+pub use crate::schema::sdl_print_options::SdlPrintOptions;
 pub use crate::schema::type_validation_error::TypeValidationError;
 pub use crate::schema::type_validation_error::TypeValidationErrorKind;
+pub use crate::schema::variable_coercion::VariableCoercionError;
+
 
 #[cfg(test)]
 mod tests;
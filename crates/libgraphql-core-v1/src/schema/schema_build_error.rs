@@ -1,5 +1,6 @@
 use crate::error_note::ErrorNote;
 use crate::schema::type_validation_error::TypeValidationError;
+use crate::schema_source_map::SchemaSourceMap;
 use crate::span::Span;
 
 /// An error encountered during schema construction.
@@ -39,6 +40,40 @@ impl SchemaBuildError {
     pub fn kind(&self) -> &SchemaBuildErrorKind { &self.kind }
     pub fn notes(&self) -> &[ErrorNote] { &self.notes }
     pub fn span(&self) -> Span { self.span }
+
+    /// Formats this error as a multi-line diagnostic with source
+    /// snippets, in the same style as
+    /// [`GraphQLParseError::format_detailed()`](libgraphql_parser::GraphQLParseError::format_detailed):
+    ///
+    /// ```text
+    /// error: duplicate type definition `User`
+    ///   --> types/user.graphql:3:1
+    ///    |
+    ///  3 | type User {
+    ///    | ^^^^^^^^^^^
+    ///    = note: first defined here
+    ///      --> schema.graphql:5:1
+    ///       |
+    ///     5 | type User {
+    ///       | -----------
+    /// ```
+    ///
+    /// Spans are resolved through `source_maps` — pass
+    /// [`SchemaBuilder::source_maps()`](crate::schema::SchemaBuilder::source_maps),
+    /// [`SchemaErrors::source_maps()`](crate::schema::SchemaErrors::source_maps),
+    /// or [`Schema::source_maps()`](crate::schema::Schema::source_maps),
+    /// whichever the error came from.
+    pub fn format_detailed(
+        &self,
+        source_maps: &[SchemaSourceMap],
+    ) -> String {
+        crate::diagnostic_renderer::format_detailed(
+            &self.kind.to_string(),
+            self.span,
+            &self.notes,
+            source_maps,
+        )
+    }
 }
 
 impl std::fmt::Display for SchemaBuildError {
@@ -145,6 +180,16 @@ pub enum SchemaBuildErrorKind {
         type_name: String,
     },
 
+    #[error(
+        "field `{coordinate}` is resolved by subgraphs {} but is not \
+        marked `@shareable` in all of them",
+        subgraph_names.iter().map(|n| format!("`{n}`")).collect::<Vec<_>>().join(", "),
+    )]
+    FederationFieldNotShareable {
+        coordinate: String,
+        subgraph_names: Vec<String>,
+    },
+
     #[error("directive name `@{name}` must not start with `__`")]
     InvalidDunderPrefixedDirectiveName {
         name: String,
@@ -199,6 +244,63 @@ pub enum SchemaBuildErrorKind {
         interface_name: String,
     },
 
+    #[error("malformed introspection result at `{json_path}`: {message}")]
+    MalformedIntrospection {
+        json_path: String,
+        message: String,
+    },
+
+    #[error(
+        "merged schemas define conflicting default values for \
+        `{coordinate}`"
+    )]
+    MergeConflictingDefaultValue {
+        coordinate: String,
+    },
+
+    #[error("merged schemas define directive `@{name}` differently")]
+    MergeConflictingDirectiveDefinition {
+        name: String,
+    },
+
+    #[error(
+        "merged schemas define conflicting arguments for field \
+        `{coordinate}`"
+    )]
+    MergeConflictingFieldArguments {
+        coordinate: String,
+    },
+
+    #[error(
+        "merged schemas define conflicting types for `{coordinate}`: \
+        `{first_type}` and `{second_type}`"
+    )]
+    MergeConflictingFieldType {
+        coordinate: String,
+        first_type: String,
+        second_type: String,
+    },
+
+    #[error(
+        "merged schemas bind the {operation} root operation type to \
+        both `{first_type_name}` and `{second_type_name}`"
+    )]
+    MergeConflictingRootOperationType {
+        first_type_name: String,
+        operation: String,
+        second_type_name: String,
+    },
+
+    #[error(
+        "merged schemas define `{type_name}` as both {first_kind} \
+        and {second_kind}"
+    )]
+    MergeConflictingTypeKind {
+        first_kind: crate::types::GraphQLTypeKind,
+        second_kind: crate::types::GraphQLTypeKind,
+        type_name: String,
+    },
+
     #[error("schema has no Query root operation type defined")]
     NoQueryOperationTypeDefined,
 
@@ -228,6 +330,15 @@ pub enum SchemaBuildErrorKind {
         type_name: String,
     },
 
+    #[error(
+        "failed to read schema file `{}`: {message}",
+        file_path.display(),
+    )]
+    SchemaFileReadError {
+        file_path: std::path::PathBuf,
+        message: String,
+    },
+
     #[error("too many source maps loaded (limit: 65535)")]
     SourceMapLimitExceeded,
 
@@ -3,15 +3,19 @@ use crate::error_note::ErrorNoteKind;
 use crate::names::DirectiveName;
 use crate::names::FieldName;
 use crate::names::TypeName;
+use crate::scalars::ScalarRegistry;
+use crate::schema::introspection_loader::IntrospectionLoader;
 use crate::schema::schema_def::Schema;
 use crate::schema::schema_build_error::SchemaBuildError;
 use crate::schema::schema_build_error::SchemaBuildErrorKind;
 use crate::schema::schema_errors::SchemaErrors;
+use crate::schema_source_map::ResolvedSpan;
 use crate::schema_source_map::SchemaSourceMap;
 use crate::span::SourceMapId;
 use crate::span::Span;
 use crate::type_builders::ast_helpers;
 use crate::type_builders::conversion_helpers::param_def_from_builder;
+use crate::type_builders::DefinesGraphQLType;
 use crate::type_builders::DirectiveBuilder;
 use crate::type_builders::EnumTypeBuilder;
 use crate::type_builders::InputObjectTypeBuilder;
@@ -25,14 +29,27 @@ use crate::types::DirectiveDefinitionKind;
 use crate::types::DirectiveLocationKind;
 use crate::types::GraphQLType;
 use crate::types::ParameterDefinition;
+use crate::types::ParameterOwner;
 use crate::types::ScalarKind;
 use crate::types::ScalarType;
 use crate::types::TypeAnnotation;
+use crate::validators::find_similar_names;
+use crate::validators::validate_default_values;
+use crate::validators::validate_directive_annotations;
+use crate::validators::validate_directive_definitions;
+use crate::validators::InputObjectTypeValidator;
+use crate::validators::ObjectOrInterfaceTypeValidator;
+use crate::validators::UnionTypeValidator;
 use crate::value::Value;
 use indexmap::IndexMap;
 use libgraphql_parser::ast;
 use libgraphql_parser::ByteSpan;
 use libgraphql_parser::GraphQLErrorNoteKind;
+use libgraphql_parser::GraphQLParser;
+use libgraphql_parser::GraphQLParserConfig;
+use libgraphql_parser::ParseResult;
+use std::path::Path;
+use std::path::PathBuf;
 
 /// Accumulates GraphQL type definitions, directive definitions,
 /// and schema metadata, then validates and produces an immutable
@@ -50,6 +67,7 @@ pub struct SchemaBuilder {
     errors: Vec<SchemaBuildError>,
     mutation_type_name: Option<(TypeName, Span)>,
     query_type_name: Option<(TypeName, Span)>,
+    scalar_registry: ScalarRegistry,
     source_maps: Vec<SchemaSourceMap>,
     subscription_type_name: Option<(TypeName, Span)>,
     types: IndexMap<TypeName, GraphQLType>,
@@ -76,6 +94,7 @@ impl SchemaBuilder {
             errors: vec![],
             mutation_type_name: None,
             query_type_name: None,
+            scalar_registry: ScalarRegistry::new(),
             source_maps: vec![SchemaSourceMap::builtin()],
             subscription_type_name: None,
             types: IndexMap::new(),
@@ -132,9 +151,13 @@ impl SchemaBuilder {
                     FieldName::new("if"),
                     ParameterDefinition {
                         default_value: None,
+                        default_value_spans: None,
                         description: None,
                         directives: vec![],
                         name: FieldName::new("if"),
+                        owner: ParameterOwner::Directive(
+                            DirectiveName::new("skip"),
+                        ),
                         span: Span::builtin(),
                         type_annotation: TypeAnnotation::named(
                             "Boolean",
@@ -163,9 +186,13 @@ impl SchemaBuilder {
                     FieldName::new("if"),
                     ParameterDefinition {
                         default_value: None,
+                        default_value_spans: None,
                         description: None,
                         directives: vec![],
                         name: FieldName::new("if"),
+                        owner: ParameterOwner::Directive(
+                            DirectiveName::new("include"),
+                        ),
                         span: Span::builtin(),
                         type_annotation: TypeAnnotation::named(
                             "Boolean",
@@ -199,9 +226,13 @@ impl SchemaBuilder {
                         default_value: Some(Value::String(
                             "No longer supported".to_string(),
                         )),
+                        default_value_spans: None,
                         description: None,
                         directives: vec![],
                         name: FieldName::new("reason"),
+                        owner: ParameterOwner::Directive(
+                            DirectiveName::new("deprecated"),
+                        ),
                         span: Span::builtin(),
                         type_annotation: TypeAnnotation::named(
                             "String",
@@ -226,9 +257,13 @@ impl SchemaBuilder {
                     FieldName::new("url"),
                     ParameterDefinition {
                         default_value: None,
+                        default_value_spans: None,
                         description: None,
                         directives: vec![],
                         name: FieldName::new("url"),
+                        owner: ParameterOwner::Directive(
+                            DirectiveName::new("specifiedBy"),
+                        ),
                         span: Span::builtin(),
                         type_annotation: TypeAnnotation::named(
                             "String",
@@ -289,6 +324,17 @@ impl SchemaBuilder {
         Ok(self)
     }
 
+    /// Registers the GraphQL type defined by the Rust type `T`
+    /// (typically via `#[derive(GraphQLObject)]`,
+    /// `#[derive(GraphQLEnum)]`, or `#[derive(GraphQLInputObject)]`).
+    /// Equivalent to
+    /// `absorb_type(T::graphql_type_builder()?)`.
+    pub fn absorb_rust_type<T: DefinesGraphQLType>(
+        &mut self,
+    ) -> Result<&mut Self, SchemaBuildError> {
+        self.absorb_type(T::graphql_type_builder()?)
+    }
+
     /// Registers a directive builder with the schema.
     ///
     /// Rejects redefinition of the five built-in directives
@@ -339,7 +385,10 @@ impl SchemaBuilder {
             locations: builder.locations,
             name: builder.name,
             parameters: builder.parameters.into_iter().map(|p| {
-                let param = param_def_from_builder(p);
+                let param = param_def_from_builder(
+                    p,
+                    ParameterOwner::Directive(name.clone()),
+                );
                 (param.name.clone(), param)
             }).collect(),
             span: builder.span,
@@ -361,9 +410,114 @@ impl SchemaBuilder {
         &mut self,
         source: &str,
     ) -> Result<&mut Self, Vec<SchemaBuildError>> {
-        let parse_result =
-            libgraphql_parser::parse_schema(source);
+        let parse_result = parse_schema_source(source);
+        self.load_parse_result(source, None, parse_result)?;
+        Ok(self)
+    }
+
+    /// Reads the file at `file_path` and loads it as with
+    /// [`load_str()`](Self::load_str).
+    ///
+    /// The file's [`SchemaSourceMap`] records `file_path`, so
+    /// every [`Span`] within it resolves back to this file.
+    pub fn load_file(
+        &mut self,
+        file_path: impl AsRef<Path>,
+    ) -> Result<&mut Self, Vec<SchemaBuildError>> {
+        self.load_files([file_path])
+    }
+
+    /// Reads and loads each file in `file_paths`.
+    ///
+    /// Files are read and parsed in parallel, then loaded in the
+    /// order given — so [`SourceMapId`]s and "first defined here"
+    /// notes are deterministic regardless of scheduling. Each
+    /// file gets its own [`SchemaSourceMap`].
+    ///
+    /// A file that cannot be read or parsed does not prevent the
+    /// remaining files from loading; errors from all files are
+    /// collected into the returned `Err` variant.
+    pub fn load_files(
+        &mut self,
+        file_paths: impl IntoIterator<Item = impl AsRef<Path>>,
+    ) -> Result<&mut Self, Vec<SchemaBuildError>> {
+        let file_paths: Vec<PathBuf> = file_paths
+            .into_iter()
+            .map(|path| path.as_ref().to_path_buf())
+            .collect();
+
+        let sources = parallel_map(&file_paths, |path| {
+            std::fs::read_to_string(path).map_err(|err| {
+                SchemaBuildError::new(
+                    SchemaBuildErrorKind::SchemaFileReadError {
+                        file_path: path.clone(),
+                        message: err.to_string(),
+                    },
+                    Span::builtin(),
+                    vec![],
+                )
+            })
+        });
+        let parse_results = parallel_map(&sources, |source| {
+            source.as_deref().ok().map(parse_schema_source)
+        });
+
+        let mut errors = vec![];
+        let loads = file_paths.into_iter()
+            .zip(&sources)
+            .zip(parse_results);
+        for ((file_path, source), parse_result) in loads {
+            let (Ok(source), Some(parse_result)) =
+                (source, parse_result)
+            else {
+                if let Err(err) = source {
+                    errors.push(err.clone());
+                }
+                continue;
+            };
+            if let Err(errs) = self.load_parse_result(
+                source,
+                Some(file_path),
+                parse_result,
+            ) {
+                errors.extend(errs);
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(self)
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Loads every GraphQL schema file (`*.graphql`, `*.graphqls`,
+    /// or `*.gql`) found under `dir_path`, recursively, as with
+    /// [`load_files()`](Self::load_files).
+    ///
+    /// Files are loaded in lexicographic path order so that a
+    /// schema split across many files builds identically on
+    /// every platform.
+    pub fn load_dir(
+        &mut self,
+        dir_path: impl AsRef<Path>,
+    ) -> Result<&mut Self, Vec<SchemaBuildError>> {
+        let mut file_paths = vec![];
+        collect_schema_files(dir_path.as_ref(), &mut file_paths)
+            .map_err(|err| vec![err])?;
+        file_paths.sort();
+        self.load_files(file_paths)
+    }
 
+    /// Registers a [`SchemaSourceMap`] for an already-parsed
+    /// source, then either translates its parse errors or loads
+    /// its definitions.
+    fn load_parse_result(
+        &mut self,
+        source: &str,
+        file_path: Option<PathBuf>,
+        parse_result: ParseResult<'_, ast::Document<'_>>,
+    ) -> Result<(), Vec<SchemaBuildError>> {
         // Register source map BEFORE checking parse errors
         // so we have a source_map_id for span translation.
         let source_map_id = match u16::try_from(
@@ -379,7 +533,7 @@ impl SchemaBuilder {
             },
         };
         self.source_maps.push(
-            SchemaSourceMap::from_source(source, None),
+            SchemaSourceMap::from_source(source, file_path),
         );
 
         // Report parse-level errors with proper spans
@@ -427,7 +581,7 @@ impl SchemaBuilder {
 
         let doc = parse_result.ast();
         self.load_document(doc, source_map_id);
-        Ok(self)
+        Ok(())
     }
 
     /// Iterates over all definitions in a parsed document and
@@ -525,9 +679,7 @@ impl SchemaBuilder {
         source_map_id: SourceMapId,
     ) {
         for root_op in &sd.root_operations {
-            let type_name = TypeName::new(
-                root_op.named_type.value.as_ref(),
-            );
+            let type_name = TypeName::from(root_op.named_type.symbol());
             let span = ast_helpers::span_from_ast(
                 root_op.span, source_map_id,
             );
@@ -568,13 +720,272 @@ impl SchemaBuilder {
         }
     }
 
-    /// Validates and finalizes the schema. Placeholder for
-    /// Task 16.
+    /// Validates and finalizes the schema.
+    ///
+    /// Runs all cross-type validation that cannot happen while
+    /// types are still being loaded:
+    ///
+    /// 1. Resolves the root operation types (falling back to the
+    ///    conventional `Query` / `Mutation` / `Subscription` names
+    ///    when no `schema { ... }` definition was loaded) and
+    ///    checks that each is a defined object type.
+    /// 2. Rejects object/interface types with no fields, unions
+    ///    with no members, and enums with no values.
+    /// 3. Validates interface contracts, field and parameter
+    ///    types, union members, and input object cycles.
+    /// 4. Validates custom directive definitions, and every
+    ///    directive applied to a type-system element (defined,
+    ///    allowed at that location, not repeated unless
+    ///    repeatable, and with valid arguments).
+    /// 5. Checks that every default value coerces to its input
+    ///    field's or parameter's type.
+    ///
+    /// Errors accumulated while loading (e.g. duplicate
+    /// definitions) are reported alongside validation errors.
     // TODO: SchemaErrors wraps Vec<SchemaBuildError> which is
     // large. Consider boxing once error strategy is finalized.
     #[allow(clippy::result_large_err)]
-    pub fn build(self) -> Result<Schema, SchemaErrors> {
-        todo!()
+    pub fn build(mut self) -> Result<Schema, SchemaErrors> {
+        self.resolve_default_root_type_names();
+        let query_type_name = self.validate_root_types();
+        self.validate_non_empty_types();
+        self.validate_types();
+
+        if !self.errors.is_empty() {
+            return Err(
+                SchemaErrors::new(self.errors)
+                    .with_source_maps(self.source_maps),
+            );
+        }
+
+        Ok(Schema {
+            directive_defs: self.directive_defs,
+            mutation_type_name: self.mutation_type_name
+                .map(|(name, _)| name),
+            query_type_name: query_type_name
+                .expect("a missing query type is reported as an error"),
+            scalar_registry: self.scalar_registry,
+            source_maps: self.source_maps,
+            subscription_type_name: self.subscription_type_name
+                .map(|(name, _)| name),
+            types: self.types,
+        })
+    }
+
+    /// When no `schema { ... }` definition was loaded, binds each
+    /// root operation to the type with its conventional name
+    /// (`Query`, `Mutation`, `Subscription`) if one is defined.
+    ///
+    /// See [Default Root Operation Type Names](https://spec.graphql.org/September2025/#sec-Root-Operation-Types.Default-Root-Operation-Type-Names).
+    fn resolve_default_root_type_names(&mut self) {
+        let has_schema_def = self.query_type_name.is_some()
+            || self.mutation_type_name.is_some()
+            || self.subscription_type_name.is_some();
+        if has_schema_def {
+            return;
+        }
+
+        for (name, slot) in [
+            ("Query", &mut self.query_type_name),
+            ("Mutation", &mut self.mutation_type_name),
+            ("Subscription", &mut self.subscription_type_name),
+        ] {
+            if let Some(type_) = self.types.get(name) {
+                *slot = Some((type_.name().clone(), type_.span()));
+            }
+        }
+    }
+
+    /// Checks that a Query root type is bound and that every bound
+    /// root operation type is a defined object type. Returns the
+    /// query root type name if one is bound.
+    ///
+    /// See [Root Operation Types](https://spec.graphql.org/September2025/#sec-Root-Operation-Types).
+    fn validate_root_types(&mut self) -> Option<TypeName> {
+        let Some((query_type_name, _)) = &self.query_type_name else {
+            let span = self.mutation_type_name.as_ref()
+                .or(self.subscription_type_name.as_ref())
+                .map(|(_, span)| *span)
+                .unwrap_or_else(Span::builtin);
+            self.errors.push(SchemaBuildError::new(
+                SchemaBuildErrorKind::NoQueryOperationTypeDefined,
+                span,
+                vec![
+                    ErrorNote::help(
+                        "define an object type named `Query`, or bind \
+                        one with `schema { query: ... }`",
+                    ),
+                    ErrorNote::spec(
+                        "https://spec.graphql.org/September2025/#sec-Root-Operation-Types",
+                    ),
+                ],
+            ));
+            return None;
+        };
+        let query_type_name = query_type_name.clone();
+
+        let root_bindings = [
+            ("query", self.query_type_name.clone()),
+            ("mutation", self.mutation_type_name.clone()),
+            ("subscription", self.subscription_type_name.clone()),
+        ];
+        for (operation, binding) in root_bindings {
+            let Some((type_name, span)) = binding else {
+                continue;
+            };
+            match self.types.get(&type_name) {
+                None => {
+                    let mut notes = Vec::new();
+                    let suggestions = find_similar_names(
+                        type_name.as_str(),
+                        self.types.keys(),
+                    );
+                    if let Some(best) = suggestions.first() {
+                        notes.push(ErrorNote::help(
+                            format!("did you mean `{best}`?"),
+                        ));
+                    }
+                    notes.push(ErrorNote::spec(
+                        "https://spec.graphql.org/September2025/#sec-Root-Operation-Types",
+                    ));
+                    self.errors.push(SchemaBuildError::new(
+                        SchemaBuildErrorKind::RootOperationTypeNotDefined {
+                            operation: operation.to_string(),
+                            type_name: type_name.to_string(),
+                        },
+                        span,
+                        notes,
+                    ));
+                },
+                Some(GraphQLType::Object(_)) => (),
+                Some(other) => {
+                    self.errors.push(SchemaBuildError::new(
+                        SchemaBuildErrorKind::RootOperationTypeNotObjectType {
+                            actual_kind: other.type_kind(),
+                            operation: operation.to_string(),
+                            type_name: type_name.to_string(),
+                        },
+                        span,
+                        vec![
+                            ErrorNote::general_with_span(
+                                format!("`{type_name}` is defined here"),
+                                other.span(),
+                            ),
+                            ErrorNote::spec(
+                                "https://spec.graphql.org/September2025/#sec-Root-Operation-Types",
+                            ),
+                        ],
+                    ));
+                },
+            }
+        }
+
+        Some(query_type_name)
+    }
+
+    /// Rejects object and interface types that define no fields,
+    /// unions that define no members, and enums that define no
+    /// values. These are syntactically valid (the field, member,
+    /// and value lists are optional in the grammar so that type
+    /// extensions can supply them) but semantically invalid.
+    fn validate_non_empty_types(&mut self) {
+        for type_ in self.types.values() {
+            let (kind, spec_url) = match type_ {
+                GraphQLType::Enum(enum_type)
+                    if enum_type.values().is_empty() => (
+                    SchemaBuildErrorKind::EnumWithNoValues {
+                        type_name: type_.name().to_string(),
+                    },
+                    "https://spec.graphql.org/September2025/#sec-Enums.Type-Validation",
+                ),
+                GraphQLType::Interface(iface)
+                    if iface.fields().is_empty() => (
+                    SchemaBuildErrorKind::EmptyObjectOrInterfaceType {
+                        type_kind: type_.type_kind(),
+                        type_name: type_.name().to_string(),
+                    },
+                    "https://spec.graphql.org/September2025/#sec-Interfaces.Type-Validation",
+                ),
+                GraphQLType::Object(obj)
+                    if obj.fields().is_empty() => (
+                    SchemaBuildErrorKind::EmptyObjectOrInterfaceType {
+                        type_kind: type_.type_kind(),
+                        type_name: type_.name().to_string(),
+                    },
+                    "https://spec.graphql.org/September2025/#sec-Objects.Type-Validation",
+                ),
+                GraphQLType::Union(union_type)
+                    if union_type.members().is_empty() => (
+                    SchemaBuildErrorKind::EmptyUnionType {
+                        type_name: type_.name().to_string(),
+                    },
+                    "https://spec.graphql.org/September2025/#sec-Unions.Type-Validation",
+                ),
+                _ => continue,
+            };
+            self.errors.push(SchemaBuildError::new(
+                kind,
+                type_.span(),
+                vec![ErrorNote::spec(spec_url)],
+            ));
+        }
+    }
+
+    /// Runs the per-type, directive-definition,
+    /// directive-application, and default-value validators over
+    /// every loaded definition, collecting their errors as
+    /// [`SchemaBuildErrorKind::TypeValidation`] errors.
+    fn validate_types(&mut self) {
+        let mut type_errors = Vec::new();
+        for type_ in self.types.values() {
+            type_errors.extend(match type_ {
+                GraphQLType::InputObject(input_obj) => {
+                    InputObjectTypeValidator::new(
+                        input_obj, &self.types,
+                    ).validate()
+                },
+                GraphQLType::Interface(iface) => {
+                    ObjectOrInterfaceTypeValidator::new(
+                        iface.as_ref(), &self.types,
+                    ).validate()
+                },
+                GraphQLType::Object(obj) => {
+                    ObjectOrInterfaceTypeValidator::new(
+                        obj.as_ref(), &self.types,
+                    ).validate()
+                },
+                GraphQLType::Union(union_type) => {
+                    UnionTypeValidator::new(
+                        union_type, &self.types,
+                    ).validate()
+                },
+                GraphQLType::Enum(_) | GraphQLType::Scalar(_) => vec![],
+            });
+        }
+        type_errors.extend(validate_directive_definitions(
+            &self.directive_defs,
+            &self.types,
+        ));
+        type_errors.extend(validate_directive_annotations(
+            &self.directive_defs,
+            &self.types,
+            &self.scalar_registry,
+        ));
+        type_errors.extend(validate_default_values(
+            &self.directive_defs,
+            &self.types,
+            &self.scalar_registry,
+        ));
+
+        self.errors.extend(type_errors.into_iter().map(|err| {
+            let span = err.span();
+            let notes = err.notes().to_vec();
+            SchemaBuildError::new(
+                SchemaBuildErrorKind::TypeValidation(err),
+                span,
+                notes,
+            )
+        }));
     }
 
     /// Convenience: parse a schema string and build in one step.
@@ -585,46 +996,281 @@ impl SchemaBuilder {
         source: &str,
     ) -> Result<Schema, SchemaErrors> {
         let mut sb = Self::new();
-        sb.load_str(source).map_err(SchemaErrors::new)?;
+        if let Err(errors) = sb.load_str(source) {
+            return Err(
+                SchemaErrors::new(errors)
+                    .with_source_maps(sb.source_maps),
+            );
+        }
+        sb.build()
+    }
+
+    /// Revalidates a schema produced by a transform of an already
+    /// built schema (see
+    /// [`Schema::prune_unreachable_types()`](Schema::prune_unreachable_types)
+    /// and friends), reporting `errors` found by the transform
+    /// alongside any validation errors. Source maps and spans are
+    /// carried over, so errors still point into the original
+    /// sources, and so is the [`ScalarRegistry`], so custom scalar
+    /// values are still validated.
+    // TODO: SchemaErrors wraps Vec<SchemaBuildError> which is
+    // large. Consider boxing once error strategy is finalized.
+    #[allow(clippy::result_large_err)]
+    pub(crate) fn rebuild(
+        schema: Schema,
+        errors: Vec<SchemaBuildError>,
+    ) -> Result<Schema, SchemaErrors> {
+        let Schema {
+            directive_defs,
+            mutation_type_name,
+            query_type_name,
+            scalar_registry,
+            source_maps,
+            subscription_type_name,
+            types,
+        } = schema;
+        let bind = |name: TypeName| {
+            let span = types.get(&name).map_or_else(Span::dummy, GraphQLType::span);
+            (name, span)
+        };
+        let mutation_type_name = mutation_type_name.map(bind);
+        let query_type_name = Some(bind(query_type_name));
+        let subscription_type_name = subscription_type_name.map(bind);
+        let sb = Self {
+            directive_defs,
+            errors,
+            mutation_type_name,
+            query_type_name,
+            scalar_registry,
+            source_maps,
+            subscription_type_name,
+            types,
+        };
         sb.build()
     }
 
+    /// Creates a builder pre-loaded with every type and directive
+    /// described by a JSON-serialized
+    /// [introspection](https://spec.graphql.org/September2025/#sec-Introspection)
+    /// result, such as the response to the canonical
+    /// `IntrospectionQuery`.
+    ///
+    /// `json` may be either the full response
+    /// (`{"data": {"__schema": ...}}`) or just its `data`
+    /// (`{"__schema": ...}`). The root operation types are bound
+    /// from `queryType`, `mutationType`, and `subscriptionType`;
+    /// deprecations become `@deprecated(reason:)` annotations,
+    /// `specifiedByURL` becomes `@specifiedBy(url:)`, and
+    /// `isOneOf` becomes `@oneOf`. Introspection types (`__Type`,
+    /// ...) and the built-in scalars and directives are skipped.
+    ///
+    /// Introspection carries no source text, so loaded
+    /// definitions have [`Span::dummy()`] spans. Malformed input
+    /// is instead reported as
+    /// [`SchemaBuildErrorKind::MalformedIntrospection`] errors
+    /// carrying the JSON path of the offending value (e.g.
+    /// `$.data.__schema.types[3].fields[0].type`).
+    ///
+    /// ```rust
+    /// # use libgraphql_core_v1 as libgraphql_core;
+    /// use libgraphql_core::schema::SchemaBuilder;
+    ///
+    /// let schema = SchemaBuilder::from_introspection_json(r#"{
+    ///   "data": { "__schema": {
+    ///     "queryType": { "name": "Query" },
+    ///     "types": [{
+    ///       "kind": "OBJECT",
+    ///       "name": "Query",
+    ///       "fields": [{
+    ///         "name": "hello",
+    ///         "args": [],
+    ///         "type": { "kind": "SCALAR", "name": "String" }
+    ///       }],
+    ///       "interfaces": []
+    ///     }],
+    ///     "directives": []
+    ///   }}
+    /// }"#).unwrap().build().unwrap();
+    ///
+    /// assert_eq!(schema.to_sdl(), "type Query {\n  hello: String\n}\n");
+    /// ```
+    // TODO: SchemaErrors wraps Vec<SchemaBuildError> which is
+    // large. Consider boxing once error strategy is finalized.
+    #[allow(clippy::result_large_err)]
+    pub fn from_introspection_json(
+        json: &str,
+    ) -> Result<Self, SchemaErrors> {
+        let mut sb = Self::new();
+        let root_types = IntrospectionLoader::new(&mut sb)
+            .load(json)
+            .map_err(SchemaErrors::new)?;
+        let bind = |name: Option<TypeName>| {
+            name.map(|name| (name, Span::dummy()))
+        };
+        sb.query_type_name = bind(root_types.query);
+        sb.mutation_type_name = bind(root_types.mutation);
+        sb.subscription_type_name = bind(root_types.subscription);
+        Ok(sb)
+    }
+
+    /// Resolves a [`Span`] from any source loaded so far to its
+    /// file path and line/column range.
+    pub fn resolve_span(&self, span: Span) -> Option<ResolvedSpan<'_>> {
+        crate::schema_source_map::resolve_span(&self.source_maps, span)
+    }
+
+    /// The source maps registered so far, indexed by
+    /// [`SourceMapId`]. Index 0 is always the built-in source map.
+    pub fn source_maps(&self) -> &[SchemaSourceMap] {
+        &self.source_maps
+    }
+
+    /// Sets the [`ScalarRegistry`] consulted by
+    /// [`build()`](Self::build) when validating custom scalar values
+    /// in default values and directive arguments. The built
+    /// [`Schema`] keeps it for
+    /// [`Schema::coerce_variable_value()`].
+    pub fn set_scalar_registry(
+        &mut self,
+        registry: ScalarRegistry,
+    ) -> &mut Self {
+        self.scalar_registry = registry;
+        self
+    }
+
     // ---------------------------------------------------------
-    // Test accessors
+    // Crate-internal accessors
     // ---------------------------------------------------------
 
-    /// Returns the registered types (for test inspection).
+    /// Returns the registered types.
     pub(crate) fn types(&self) -> &IndexMap<TypeName, GraphQLType> {
         &self.types
     }
 
-    /// Returns the registered directive definitions (for test
-    /// inspection).
+    /// Returns the registered types for in-place augmentation
+    /// (e.g. by [`federation`](crate::federation)).
+    pub(crate) fn types_mut(
+        &mut self,
+    ) -> &mut IndexMap<TypeName, GraphQLType> {
+        &mut self.types
+    }
+
+    /// Returns the registered directive definitions.
     pub(crate) fn directive_defs(
         &self,
     ) -> &IndexMap<DirectiveName, DirectiveDefinition> {
         &self.directive_defs
     }
 
-    /// Returns the query root type name binding (for test
-    /// inspection).
+    /// Returns the query root type name binding from a loaded
+    /// `schema { ... }` definition, if any.
     pub(crate) fn query_type_name(&self) -> Option<&(TypeName, Span)> {
         self.query_type_name.as_ref()
     }
 
     /// Returns accumulated errors (for test inspection).
+    #[cfg(test)]
     pub(crate) fn errors(&self) -> &[SchemaBuildError] {
         &self.errors
     }
 
-    /// Returns the mutation root type name binding (for test
-    /// inspection).
-    // TODO: Remove #[allow(dead_code)] once mutation root type
-    // tests are added or build() consumes this field.
-    #[allow(dead_code)]
-    pub(crate) fn mutation_type_name(&self) -> Option<&(TypeName, Span)> {
-        self.mutation_type_name.as_ref()
+    /// Records errors to be reported by [`build()`](Self::build).
+    pub(crate) fn push_errors(
+        &mut self,
+        errors: impl IntoIterator<Item = SchemaBuildError>,
+    ) {
+        self.errors.extend(errors);
+    }
+}
+
+// ---------------------------------------------------------
+// Source loading helpers
+// ---------------------------------------------------------
+
+/// File extensions recognized as GraphQL schema files by
+/// [`SchemaBuilder::load_dir()`].
+const SCHEMA_FILE_EXTENSIONS: [&str; 3] = ["gql", "graphql", "graphqls"];
+
+/// Parses a schema document with the configuration every
+/// `load_*` method uses.
+pub(crate) fn parse_schema_source(source: &str) -> ParseResult<'_, ast::Document<'_>> {
+    // Names are interned as they are parsed so that converting
+    // them into name newtypes is a clone of the recorded symbol.
+    GraphQLParser::with_config(
+        source,
+        GraphQLParserConfig {
+            intern_names: true,
+            ..GraphQLParserConfig::default()
+        },
+    ).parse_schema_document()
+}
+
+/// Recursively collects the paths of all schema files under
+/// `dir_path` into `file_paths`.
+// See the TODO on `impl SchemaBuilder` about SchemaBuildError's size.
+#[allow(clippy::result_large_err)]
+fn collect_schema_files(
+    dir_path: &Path,
+    file_paths: &mut Vec<PathBuf>,
+) -> Result<(), SchemaBuildError> {
+    let read_error = |path: &Path, err: std::io::Error| {
+        SchemaBuildError::new(
+            SchemaBuildErrorKind::SchemaFileReadError {
+                file_path: path.to_path_buf(),
+                message: err.to_string(),
+            },
+            Span::builtin(),
+            vec![],
+        )
+    };
+    let entries = std::fs::read_dir(dir_path)
+        .map_err(|err| read_error(dir_path, err))?;
+    for entry in entries {
+        let path = entry.map_err(|err| read_error(dir_path, err))?.path();
+        if path.is_dir() {
+            collect_schema_files(&path, file_paths)?;
+        } else if path.extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| SCHEMA_FILE_EXTENSIONS.contains(&ext))
+        {
+            file_paths.push(path);
+        }
     }
+    Ok(())
+}
+
+/// Maps `f` over `items` using scoped threads, one contiguous
+/// chunk per available core, preserving input order in the
+/// output.
+fn parallel_map<'a, T: Sync, R: Send>(
+    items: &'a [T],
+    f: impl Fn(&'a T) -> R + Sync,
+) -> Vec<R> {
+    let num_threads = std::thread::available_parallelism()
+        .map_or(1, |n| n.get())
+        .min(items.len());
+    if num_threads <= 1 {
+        return items.iter().map(f).collect();
+    }
+
+    let chunk_size = items.len().div_ceil(num_threads);
+    let f = &f;
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = items
+            .chunks(chunk_size)
+            .map(|chunk| scope.spawn(move || {
+                chunk.iter().map(f).collect::<Vec<R>>()
+            }))
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|handle| {
+                handle.join().unwrap_or_else(|panic| {
+                    std::panic::resume_unwind(panic)
+                })
+            })
+            .collect()
+    })
 }
 
 // ---------------------------------------------------------
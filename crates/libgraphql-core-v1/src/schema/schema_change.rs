@@ -0,0 +1,370 @@
+use crate::span::Span;
+use crate::types::GraphQLTypeKind;
+
+/// How a [`SchemaChange`] affects clients written against the old
+/// schema.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ChangeSeverity {
+    /// Operations that were valid against the old schema may fail
+    /// to validate (or may fail at runtime) against the new one.
+    Breaking,
+
+    /// Existing operations remain valid, but clients may observe
+    /// different behavior — e.g. a new enum value they don't
+    /// handle, or a changed argument default.
+    Dangerous,
+
+    /// Existing operations remain valid and behave the same.
+    Safe,
+}
+
+impl std::fmt::Display for ChangeSeverity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Breaking => "breaking",
+            Self::Dangerous => "dangerous",
+            Self::Safe => "safe",
+        })
+    }
+}
+
+/// A single difference between two schemas, as reported by
+/// [`diff()`](crate::schema::diff).
+///
+/// Every change carries:
+/// - A [`kind`](Self::kind) identifying what changed
+/// - A [`severity`](Self::severity) classifying its impact on
+///   existing clients
+/// - Spans locating the change in the [old](Self::old_span) and
+///   [new](Self::new_span) schemas
+///
+/// For additions, `old_span` points at the closest enclosing
+/// element that exists in the old schema (e.g. the field an
+/// argument was added to), and vice versa for removals. It is
+/// `None` only when there is no such element (e.g. an added
+/// type).
+#[derive(Clone, Debug, PartialEq)]
+pub struct SchemaChange {
+    kind: SchemaChangeKind,
+    new_span: Option<Span>,
+    old_span: Option<Span>,
+    severity: ChangeSeverity,
+}
+
+impl SchemaChange {
+    pub(crate) fn new(
+        kind: SchemaChangeKind,
+        severity: ChangeSeverity,
+        old_span: Option<Span>,
+        new_span: Option<Span>,
+    ) -> Self {
+        Self { kind, new_span, old_span, severity }
+    }
+
+    pub fn is_breaking(&self) -> bool {
+        self.severity == ChangeSeverity::Breaking
+    }
+    pub fn kind(&self) -> &SchemaChangeKind { &self.kind }
+    /// Resolve with the new schema's
+    /// [`resolve_span()`](crate::schema::Schema::resolve_span).
+    pub fn new_span(&self) -> Option<Span> { self.new_span }
+    /// Resolve with the old schema's
+    /// [`resolve_span()`](crate::schema::Schema::resolve_span).
+    pub fn old_span(&self) -> Option<Span> { self.old_span }
+    pub fn severity(&self) -> ChangeSeverity { self.severity }
+}
+
+impl std::fmt::Display for SchemaChange {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        write!(f, "{}: {}", self.severity, self.kind)
+    }
+}
+
+/// Categorized schema change for programmatic matching.
+///
+/// Type annotations and default values are carried as their
+/// GraphQL source text (e.g. `[String!]`, `{limit: 10}`).
+///
+/// `#[non_exhaustive]` — new variants may be added in minor
+/// releases. Always include a wildcard arm in `match`
+/// expressions.
+#[derive(Clone, Debug, PartialEq, thiserror::Error)]
+#[non_exhaustive]
+pub enum SchemaChangeKind {
+    #[error(
+        "argument `{argument_name}` was added to \
+        `{type_name}.{field_name}`{}",
+        if *required { " as a required argument" } else { "" },
+    )]
+    ArgumentAdded {
+        argument_name: String,
+        field_name: String,
+        required: bool,
+        type_name: String,
+    },
+
+    #[error(
+        "default value of argument `{argument_name}` on \
+        `{type_name}.{field_name}` changed from {} to {}",
+        display_default(old_default),
+        display_default(new_default),
+    )]
+    ArgumentDefaultValueChanged {
+        argument_name: String,
+        field_name: String,
+        new_default: Option<String>,
+        old_default: Option<String>,
+        type_name: String,
+    },
+
+    #[error(
+        "argument `{argument_name}` was removed from \
+        `{type_name}.{field_name}`"
+    )]
+    ArgumentRemoved {
+        argument_name: String,
+        field_name: String,
+        type_name: String,
+    },
+
+    #[error(
+        "type of argument `{argument_name}` on \
+        `{type_name}.{field_name}` changed from `{old_type}` to \
+        `{new_type}`"
+    )]
+    ArgumentTypeChanged {
+        argument_name: String,
+        field_name: String,
+        new_type: String,
+        old_type: String,
+        type_name: String,
+    },
+
+    #[error("directive `@{directive_name}` was added")]
+    DirectiveAdded {
+        directive_name: String,
+    },
+
+    #[error(
+        "argument `{argument_name}` was added to directive \
+        `@{directive_name}`{}",
+        if *required { " as a required argument" } else { "" },
+    )]
+    DirectiveArgumentAdded {
+        argument_name: String,
+        directive_name: String,
+        required: bool,
+    },
+
+    #[error(
+        "default value of argument `{argument_name}` on directive \
+        `@{directive_name}` changed from {} to {}",
+        display_default(old_default),
+        display_default(new_default),
+    )]
+    DirectiveArgumentDefaultValueChanged {
+        argument_name: String,
+        directive_name: String,
+        new_default: Option<String>,
+        old_default: Option<String>,
+    },
+
+    #[error(
+        "argument `{argument_name}` was removed from directive \
+        `@{directive_name}`"
+    )]
+    DirectiveArgumentRemoved {
+        argument_name: String,
+        directive_name: String,
+    },
+
+    #[error(
+        "type of argument `{argument_name}` on directive \
+        `@{directive_name}` changed from `{old_type}` to \
+        `{new_type}`"
+    )]
+    DirectiveArgumentTypeChanged {
+        argument_name: String,
+        directive_name: String,
+        new_type: String,
+        old_type: String,
+    },
+
+    #[error(
+        "location `{location}` was added to directive \
+        `@{directive_name}`"
+    )]
+    DirectiveLocationAdded {
+        directive_name: String,
+        location: String,
+    },
+
+    #[error(
+        "location `{location}` was removed from directive \
+        `@{directive_name}`"
+    )]
+    DirectiveLocationRemoved {
+        directive_name: String,
+        location: String,
+    },
+
+    #[error("directive `@{directive_name}` was removed")]
+    DirectiveRemoved {
+        directive_name: String,
+    },
+
+    #[error(
+        "directive `@{directive_name}` is {}repeatable",
+        if *is_repeatable { "now " } else { "no longer " },
+    )]
+    DirectiveRepeatableChanged {
+        directive_name: String,
+        is_repeatable: bool,
+    },
+
+    #[error("value `{value_name}` was added to enum `{type_name}`")]
+    EnumValueAdded {
+        type_name: String,
+        value_name: String,
+    },
+
+    #[error("value `{value_name}` was removed from enum `{type_name}`")]
+    EnumValueRemoved {
+        type_name: String,
+        value_name: String,
+    },
+
+    #[error("field `{type_name}.{field_name}` was added")]
+    FieldAdded {
+        field_name: String,
+        type_name: String,
+    },
+
+    #[error("field `{type_name}.{field_name}` was removed")]
+    FieldRemoved {
+        field_name: String,
+        type_name: String,
+    },
+
+    #[error(
+        "type of field `{type_name}.{field_name}` changed from \
+        `{old_type}` to `{new_type}`"
+    )]
+    FieldTypeChanged {
+        field_name: String,
+        new_type: String,
+        old_type: String,
+        type_name: String,
+    },
+
+    #[error(
+        "input field `{type_name}.{field_name}` was added{}",
+        if *required { " as a required field" } else { "" },
+    )]
+    InputFieldAdded {
+        field_name: String,
+        required: bool,
+        type_name: String,
+    },
+
+    #[error(
+        "default value of input field `{type_name}.{field_name}` \
+        changed from {} to {}",
+        display_default(old_default),
+        display_default(new_default),
+    )]
+    InputFieldDefaultValueChanged {
+        field_name: String,
+        new_default: Option<String>,
+        old_default: Option<String>,
+        type_name: String,
+    },
+
+    #[error("input field `{type_name}.{field_name}` was removed")]
+    InputFieldRemoved {
+        field_name: String,
+        type_name: String,
+    },
+
+    #[error(
+        "type of input field `{type_name}.{field_name}` changed \
+        from `{old_type}` to `{new_type}`"
+    )]
+    InputFieldTypeChanged {
+        field_name: String,
+        new_type: String,
+        old_type: String,
+        type_name: String,
+    },
+
+    #[error("`{type_name}` now implements `{interface_name}`")]
+    InterfaceImplementationAdded {
+        interface_name: String,
+        type_name: String,
+    },
+
+    #[error("`{type_name}` no longer implements `{interface_name}`")]
+    InterfaceImplementationRemoved {
+        interface_name: String,
+        type_name: String,
+    },
+
+    #[error(
+        "root {operation} type changed from {} to {}",
+        display_root(old_type_name),
+        display_root(new_type_name),
+    )]
+    RootOperationTypeChanged {
+        new_type_name: Option<String>,
+        old_type_name: Option<String>,
+        operation: String,
+    },
+
+    #[error("type `{type_name}` was added")]
+    TypeAdded {
+        type_name: String,
+    },
+
+    #[error(
+        "`{type_name}` changed from {old_kind} type to {new_kind} type"
+    )]
+    TypeKindChanged {
+        new_kind: GraphQLTypeKind,
+        old_kind: GraphQLTypeKind,
+        type_name: String,
+    },
+
+    #[error("type `{type_name}` was removed")]
+    TypeRemoved {
+        type_name: String,
+    },
+
+    #[error("member `{member_name}` was added to union `{type_name}`")]
+    UnionMemberAdded {
+        member_name: String,
+        type_name: String,
+    },
+
+    #[error("member `{member_name}` was removed from union `{type_name}`")]
+    UnionMemberRemoved {
+        member_name: String,
+        type_name: String,
+    },
+}
+
+fn display_default(default: &Option<String>) -> String {
+    match default {
+        Some(value) => format!("`{value}`"),
+        None => "none".to_string(),
+    }
+}
+
+fn display_root(type_name: &Option<String>) -> String {
+    match type_name {
+        Some(type_name) => format!("`{type_name}`"),
+        None => "none".to_string(),
+    }
+}
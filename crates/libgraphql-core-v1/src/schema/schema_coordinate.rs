@@ -0,0 +1,354 @@
+use crate::names::DirectiveName;
+use crate::names::FieldName;
+use crate::names::TypeName;
+use libgraphql_parser::ByteSpan;
+use libgraphql_parser::token::GraphQLToken;
+use libgraphql_parser::token::GraphQLTokenKind;
+use libgraphql_parser::token::StrGraphQLTokenSource;
+use std::str::FromStr;
+
+/// A [schema coordinate](https://spec.graphql.org/draft/#sec-Schema-Coordinates)
+/// — a human-readable string that uniquely identifies an element
+/// defined in a schema (e.g. `User`, `User.name`, `Query.user(id:)`,
+/// `@deprecated`, `@deprecated(reason:)`).
+///
+/// Parse a coordinate with [`SchemaCoordinate::parse()`] (or
+/// [`str::parse()`]), print it with [`Display`](std::fmt::Display),
+/// and look up the element it refers to with
+/// [`Schema::resolve_coordinate()`](crate::schema::Schema::resolve_coordinate).
+/// Every schema element also exposes its own coordinate (e.g.
+/// [`FieldDefinition::coordinate()`](crate::types::FieldDefinition::coordinate)).
+///
+/// ```rust
+/// # use libgraphql_core_v1 as libgraphql_core;
+/// use libgraphql_core::schema::SchemaCoordinate;
+///
+/// let coordinate = SchemaCoordinate::parse("Query.user(id:)").unwrap();
+/// assert!(matches!(coordinate, SchemaCoordinate::Argument { .. }));
+/// assert_eq!(coordinate.to_string(), "Query.user(id:)");
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum SchemaCoordinate {
+    /// `Type.field(argument:)` — an argument of a field on an object
+    /// or interface type.
+    Argument {
+        argument_name: FieldName,
+        field_name: FieldName,
+        type_name: TypeName,
+    },
+
+    /// `@directive` — a directive definition.
+    Directive {
+        directive_name: DirectiveName,
+    },
+
+    /// `@directive(argument:)` — an argument of a directive
+    /// definition.
+    DirectiveArgument {
+        argument_name: FieldName,
+        directive_name: DirectiveName,
+    },
+
+    /// `Type.member` — a field of an object or interface type, an
+    /// input field of an input object type, or a value of an enum
+    /// type.
+    ///
+    /// Which of these a member coordinate refers to depends on the
+    /// kind of `type_name` in the schema it is resolved against, so
+    /// the member name is not tied to a specific name type.
+    Member {
+        member_name: String,
+        type_name: TypeName,
+    },
+
+    /// `Type` — a named type.
+    Type {
+        type_name: TypeName,
+    },
+}
+
+impl SchemaCoordinate {
+    /// Parses a schema coordinate.
+    ///
+    /// Schema coordinates are a self-contained grammar: unlike GraphQL
+    /// documents, they may not contain whitespace, commas, or comments.
+    pub fn parse(source: &str) -> Result<Self, SchemaCoordinateParseError> {
+        CoordinateParser::new(source).parse()
+    }
+
+    /// The name of the type or directive this coordinate is rooted
+    /// at, without the `@` prefix for directives.
+    pub fn root_name(&self) -> &str {
+        match self {
+            Self::Argument { type_name, .. }
+                | Self::Member { type_name, .. }
+                | Self::Type { type_name } => type_name.as_str(),
+            Self::Directive { directive_name }
+                | Self::DirectiveArgument { directive_name, .. } => {
+                directive_name.as_str()
+            },
+        }
+    }
+}
+
+impl std::fmt::Display for SchemaCoordinate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Argument { argument_name, field_name, type_name } => {
+                write!(f, "{type_name}.{field_name}({argument_name}:)")
+            },
+            Self::Directive { directive_name } => {
+                write!(f, "@{directive_name}")
+            },
+            Self::DirectiveArgument { argument_name, directive_name } => {
+                write!(f, "@{directive_name}({argument_name}:)")
+            },
+            Self::Member { member_name, type_name } => {
+                write!(f, "{type_name}.{member_name}")
+            },
+            Self::Type { type_name } => write!(f, "{type_name}"),
+        }
+    }
+}
+
+impl FromStr for SchemaCoordinate {
+    type Err = SchemaCoordinateParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> { Self::parse(s) }
+}
+
+/// An error produced by [`SchemaCoordinate::parse()`].
+#[derive(Clone, Debug, PartialEq, thiserror::Error)]
+#[error("invalid schema coordinate `{source_text}`: {kind}")]
+pub struct SchemaCoordinateParseError {
+    byte_offset: u32,
+    kind: SchemaCoordinateParseErrorKind,
+    source_text: String,
+}
+
+impl SchemaCoordinateParseError {
+    /// The byte offset into the parsed string at which the error
+    /// was found.
+    pub fn byte_offset(&self) -> u32 { self.byte_offset }
+    pub fn kind(&self) -> &SchemaCoordinateParseErrorKind { &self.kind }
+    /// The full string that failed to parse.
+    pub fn source_text(&self) -> &str { &self.source_text }
+}
+
+/// Categorized schema coordinate parse error for programmatic
+/// matching.
+///
+/// `#[non_exhaustive]` — new variants may be added in minor
+/// releases. Always include a wildcard arm in `match`
+/// expressions.
+#[derive(Clone, Debug, PartialEq, thiserror::Error)]
+#[non_exhaustive]
+pub enum SchemaCoordinateParseErrorKind {
+    #[error(
+        "schema coordinates may not contain whitespace, commas, or \
+        comments"
+    )]
+    IgnoredTokens,
+
+    #[error("expected {expected}, found `{found}`")]
+    UnexpectedToken {
+        expected: &'static str,
+        found: String,
+    },
+
+    #[error("expected {expected}, found end of input")]
+    UnexpectedEnd {
+        expected: &'static str,
+    },
+}
+
+// ---------------------------------------------------------
+// Parser
+// ---------------------------------------------------------
+
+/// Parses a schema coordinate from the tokens produced by the
+/// `libgraphql-parser` lexer.
+///
+/// The lexer has no `.` punctuator (GraphQL documents never use a
+/// lone dot), so a single `.` arrives as a one-byte error token.
+struct CoordinateParser<'src> {
+    /// Byte offset immediately after the last consumed token.
+    offset: u32,
+    source: &'src str,
+    tokens: std::iter::Peekable<StrGraphQLTokenSource<'src>>,
+}
+
+impl<'src> CoordinateParser<'src> {
+    fn new(source: &'src str) -> Self {
+        Self {
+            offset: 0,
+            source,
+            tokens: StrGraphQLTokenSource::new(source).peekable(),
+        }
+    }
+
+    fn parse(mut self) -> Result<SchemaCoordinate, SchemaCoordinateParseError> {
+        let coordinate = if self.eat(|kind| matches!(kind, GraphQLTokenKind::At))? {
+            let directive_name = DirectiveName::new(self.expect_name()?);
+            match self.parse_argument_name()? {
+                Some(argument_name) => SchemaCoordinate::DirectiveArgument {
+                    argument_name,
+                    directive_name,
+                },
+                None => SchemaCoordinate::Directive { directive_name },
+            }
+        } else {
+            let type_name = TypeName::new(self.expect_name()?);
+            if self.eat_dot()? {
+                let member_name = self.expect_name()?;
+                match self.parse_argument_name()? {
+                    Some(argument_name) => SchemaCoordinate::Argument {
+                        argument_name,
+                        field_name: FieldName::new(member_name),
+                        type_name,
+                    },
+                    None => SchemaCoordinate::Member {
+                        member_name,
+                        type_name,
+                    },
+                }
+            } else {
+                SchemaCoordinate::Type { type_name }
+            }
+        };
+
+        let token = self.next_token()?;
+        match token.kind {
+            GraphQLTokenKind::Eof => Ok(coordinate),
+            _ => Err(self.unexpected(&token, "end of input")),
+        }
+    }
+
+    /// Parses an optional `(name:)` suffix.
+    fn parse_argument_name(
+        &mut self,
+    ) -> Result<Option<FieldName>, SchemaCoordinateParseError> {
+        if !self.eat(|kind| matches!(kind, GraphQLTokenKind::ParenOpen))? {
+            return Ok(None);
+        }
+        let argument_name = FieldName::new(self.expect_name()?);
+        self.expect(|kind| matches!(kind, GraphQLTokenKind::Colon), "`:`")?;
+        self.expect(|kind| matches!(kind, GraphQLTokenKind::ParenClose), "`)`")?;
+        Ok(Some(argument_name))
+    }
+
+    fn eat(
+        &mut self,
+        predicate: impl Fn(&GraphQLTokenKind<'_>) -> bool,
+    ) -> Result<bool, SchemaCoordinateParseError> {
+        let matches = self.peek_token()?.is_some_and(|token| predicate(&token.kind));
+        if matches {
+            self.next_token()?;
+        }
+        Ok(matches)
+    }
+
+    fn eat_dot(&mut self) -> Result<bool, SchemaCoordinateParseError> {
+        // `eat()` cannot see token spans, so dots are matched here.
+        let source = self.source;
+        let dot_len = self.peek_token()?.and_then(|token| {
+            let text = span_text(source, token.span);
+            let is_dot = matches!(token.kind, GraphQLTokenKind::Error(_))
+                && text.trim_end() == ".";
+            is_dot.then_some(text.len())
+        });
+        match dot_len {
+            None => Ok(false),
+            Some(1) => {
+                self.next_token()?;
+                Ok(true)
+            },
+            // The lexer folds whitespace following a lone `.` into
+            // its error token.
+            Some(_) => Err(SchemaCoordinateParseError {
+                byte_offset: self.offset + 1,
+                kind: SchemaCoordinateParseErrorKind::IgnoredTokens,
+                source_text: source.to_string(),
+            }),
+        }
+    }
+
+    fn expect(
+        &mut self,
+        predicate: impl Fn(&GraphQLTokenKind<'_>) -> bool,
+        expected: &'static str,
+    ) -> Result<(), SchemaCoordinateParseError> {
+        let token = self.next_token()?;
+        if predicate(&token.kind) {
+            Ok(())
+        } else {
+            Err(self.unexpected(&token, expected))
+        }
+    }
+
+    fn expect_name(&mut self) -> Result<String, SchemaCoordinateParseError> {
+        let token = self.next_token()?;
+        match &token.kind {
+            GraphQLTokenKind::Name(name) => Ok(name.to_string()),
+            GraphQLTokenKind::False => Ok("false".to_string()),
+            GraphQLTokenKind::Null => Ok("null".to_string()),
+            GraphQLTokenKind::True => Ok("true".to_string()),
+            _ => Err(self.unexpected(&token, "a name")),
+        }
+    }
+
+    /// Peeks at the next token, rejecting it if it is not directly
+    /// adjacent to the previous one.
+    fn peek_token(
+        &mut self,
+    ) -> Result<Option<&GraphQLToken<'src>>, SchemaCoordinateParseError> {
+        let offset = self.offset;
+        let source_text = self.source;
+        match self.tokens.peek() {
+            Some(token) if !token.preceding_trivia.is_empty()
+                || token.span.start != offset => {
+                Err(SchemaCoordinateParseError {
+                    byte_offset: offset,
+                    kind: SchemaCoordinateParseErrorKind::IgnoredTokens,
+                    source_text: source_text.to_string(),
+                })
+            },
+            Some(token) => Ok(Some(token)),
+            None => Ok(None),
+        }
+    }
+
+    fn next_token(&mut self) -> Result<GraphQLToken<'src>, SchemaCoordinateParseError> {
+        self.peek_token()?;
+        let token = self.tokens
+            .next()
+            .expect("the lexer always ends with an Eof token");
+        self.offset = token.span.end;
+        Ok(token)
+    }
+
+    fn unexpected(
+        &self,
+        token: &GraphQLToken<'_>,
+        expected: &'static str,
+    ) -> SchemaCoordinateParseError {
+        let kind = match token.kind {
+            GraphQLTokenKind::Eof => {
+                SchemaCoordinateParseErrorKind::UnexpectedEnd { expected }
+            },
+            _ => SchemaCoordinateParseErrorKind::UnexpectedToken {
+                expected,
+                found: span_text(self.source, token.span).to_string(),
+            },
+        };
+        SchemaCoordinateParseError {
+            byte_offset: token.span.start,
+            kind,
+            source_text: self.source.to_string(),
+        }
+    }
+}
+
+fn span_text(source: &str, span: ByteSpan) -> &str {
+    &source[span.start as usize..span.end as usize]
+}
@@ -1,9 +1,693 @@
+use crate::names::DirectiveName;
+use crate::names::TypeName;
+use crate::scalars::ScalarRegistry;
+use crate::schema::introspection_options::IntrospectionOptions;
+use crate::schema::introspection_printer::IntrospectionPrinter;
+use crate::schema::schema_coordinate::SchemaCoordinate;
+use crate::schema::schema_element::SchemaElement;
+use crate::schema::schema_errors::SchemaErrors;
+use crate::schema::schema_transform;
+use crate::schema::sdl_print_options::SdlPrintOptions;
+use crate::schema::sdl_printer::SdlPrinter;
+use crate::schema::variable_coercion;
+use crate::schema::variable_coercion::VariableCoercionError;
+use crate::schema_source_map::ResolvedSpan;
+use crate::schema_source_map::SchemaSourceMap;
+use crate::span::Span;
+use crate::types::DirectiveDefinition;
+use crate::types::EnumType;
+use crate::types::GraphQLType;
+use crate::types::InputObjectType;
+use crate::types::InterfaceType;
+use crate::types::ObjectType;
+use crate::types::ScalarType;
+use crate::types::TypeAnnotation;
+use crate::types::UnionType;
+use indexmap::IndexMap;
+
 /// A fully validated, immutable GraphQL schema.
 ///
 /// Produced by
 /// [`SchemaBuilder::build()`](crate::schema::SchemaBuilder::build).
-/// Task 16 will provide the full implementation.
+/// A `Schema` is guaranteed to satisfy all type-system validation
+/// rules from the
+/// [GraphQL specification](https://spec.graphql.org/September2025/)
+/// that `libgraphql-core` enforces: every type reference resolves,
+/// interface contracts are honored, union members are object
+/// types, and a Query root operation type exists.
+///
+/// All lookups take `&str` so callers can query by literal name
+/// (`schema.object_type("User")`) or by any name newtype via
+/// `as_str()`.
+///
+/// `Schema` implements `serde::Serialize` and
+/// `serde::Deserialize` for binary embedding by the
+/// `libgraphql-macros` crate.
+///
+/// ```rust
+/// # use libgraphql_core_v1 as libgraphql_core;
+/// use libgraphql_core::schema::SchemaBuilder;
+///
+/// let schema = SchemaBuilder::build_from_str(
+///     "type Query { me: User }
+///      interface Node { id: ID! }
+///      type User implements Node { id: ID! }",
+/// ).unwrap();
+///
+/// assert_eq!(schema.query_type().name().as_str(), "Query");
+/// assert!(schema.object_type("User").is_some());
+/// assert!(schema.directive("skip").is_some());
+/// assert_eq!(schema.possible_types("Node").len(), 1);
+/// ```
 #[derive(Clone, Debug, PartialEq)]
+#[derive(serde::Deserialize, serde::Serialize)]
 pub struct Schema {
-    _private: (),
+    pub(crate) directive_defs:
+        IndexMap<DirectiveName, DirectiveDefinition>,
+    pub(crate) mutation_type_name: Option<TypeName>,
+    pub(crate) query_type_name: TypeName,
+    #[serde(skip)]
+    pub(crate) scalar_registry: ScalarRegistry,
+    pub(crate) source_maps: Vec<SchemaSourceMap>,
+    pub(crate) subscription_type_name: Option<TypeName>,
+    pub(crate) types: IndexMap<TypeName, GraphQLType>,
+}
+
+impl Schema {
+    // ---------------------------------------------------------
+    // Generic lookups
+    // ---------------------------------------------------------
+
+    /// Looks up a directive definition (built-in or custom) by
+    /// name, without the leading `@`.
+    pub fn directive(&self, name: &str) -> Option<&DirectiveDefinition> {
+        self.directive_defs.get(name)
+    }
+
+    /// All directive definitions in the schema, including the
+    /// built-in directives, in definition order.
+    pub fn directive_defs(
+        &self,
+    ) -> &IndexMap<DirectiveName, DirectiveDefinition> {
+        &self.directive_defs
+    }
+
+    /// Looks up a type of any kind by name.
+    pub fn get_type(&self, name: &str) -> Option<&GraphQLType> {
+        self.types.get(name)
+    }
+
+    /// All types in the schema, including the built-in scalars,
+    /// in definition order.
+    pub fn types(&self) -> &IndexMap<TypeName, GraphQLType> {
+        &self.types
+    }
+
+    /// Looks up the element a [`SchemaCoordinate`] refers to, or
+    /// `None` if no such element is defined.
+    ///
+    /// A member coordinate (`Type.member`) resolves to a field of an
+    /// object or interface type, an input field of an input object
+    /// type, or a value of an enum type, depending on the kind of
+    /// `Type`.
+    ///
+    /// ```rust
+    /// # use libgraphql_core_v1 as libgraphql_core;
+    /// use libgraphql_core::schema::SchemaBuilder;
+    /// use libgraphql_core::schema::SchemaCoordinate;
+    /// use libgraphql_core::schema::SchemaElement;
+    ///
+    /// let schema = SchemaBuilder::build_from_str(
+    ///     "type Query { user(id: ID!): String }",
+    /// ).unwrap();
+    /// let coordinate = SchemaCoordinate::parse("Query.user(id:)").unwrap();
+    /// let Some(SchemaElement::FieldArgument(param)) =
+    ///     schema.resolve_coordinate(&coordinate)
+    /// else {
+    ///     panic!("expected a field argument");
+    /// };
+    /// assert_eq!(param.type_annotation().to_string(), "ID!");
+    /// ```
+    pub fn resolve_coordinate(
+        &self,
+        coordinate: &SchemaCoordinate,
+    ) -> Option<SchemaElement<'_>> {
+        match coordinate {
+            SchemaCoordinate::Argument {
+                argument_name,
+                field_name,
+                type_name,
+            } => {
+                let field = match self.get_type(type_name.as_str())? {
+                    GraphQLType::Interface(iface) => iface.field(field_name.as_str()),
+                    GraphQLType::Object(obj) => obj.field(field_name.as_str()),
+                    _ => None,
+                }?;
+                field.parameters()
+                    .get(argument_name.as_str())
+                    .map(SchemaElement::FieldArgument)
+            },
+            SchemaCoordinate::Directive { directive_name } => {
+                self.directive(directive_name.as_str())
+                    .map(SchemaElement::Directive)
+            },
+            SchemaCoordinate::DirectiveArgument {
+                argument_name,
+                directive_name,
+            } => {
+                self.directive(directive_name.as_str())?
+                    .parameters()
+                    .get(argument_name.as_str())
+                    .map(SchemaElement::DirectiveArgument)
+            },
+            SchemaCoordinate::Member { member_name, type_name } => {
+                let member_name = member_name.as_str();
+                match self.get_type(type_name.as_str())? {
+                    GraphQLType::Enum(enum_type) => {
+                        enum_type.value(member_name)
+                            .map(SchemaElement::EnumValue)
+                    },
+                    GraphQLType::InputObject(input_obj) => {
+                        input_obj.field(member_name)
+                            .map(SchemaElement::InputField)
+                    },
+                    GraphQLType::Interface(iface) => {
+                        iface.field(member_name).map(SchemaElement::Field)
+                    },
+                    GraphQLType::Object(obj) => {
+                        obj.field(member_name).map(SchemaElement::Field)
+                    },
+                    GraphQLType::Scalar(_) | GraphQLType::Union(_) => None,
+                }
+            },
+            SchemaCoordinate::Type { type_name } => {
+                self.get_type(type_name.as_str()).map(SchemaElement::Type)
+            },
+        }
+    }
+
+    // ---------------------------------------------------------
+    // Typed lookups
+    // ---------------------------------------------------------
+
+    pub fn enum_type(&self, name: &str) -> Option<&EnumType> {
+        self.types.get(name).and_then(|t| t.as_enum())
+    }
+
+    pub fn input_object_type(
+        &self,
+        name: &str,
+    ) -> Option<&InputObjectType> {
+        self.types.get(name).and_then(|t| t.as_input_object())
+    }
+
+    pub fn interface_type(&self, name: &str) -> Option<&InterfaceType> {
+        self.types.get(name).and_then(|t| t.as_interface())
+    }
+
+    pub fn object_type(&self, name: &str) -> Option<&ObjectType> {
+        self.types.get(name).and_then(|t| t.as_object())
+    }
+
+    pub fn scalar_type(&self, name: &str) -> Option<&ScalarType> {
+        self.types.get(name).and_then(|t| t.as_scalar())
+    }
+
+    pub fn union_type(&self, name: &str) -> Option<&UnionType> {
+        self.types.get(name).and_then(|t| t.as_union())
+    }
+
+    // ---------------------------------------------------------
+    // Typed iterators
+    // ---------------------------------------------------------
+
+    pub fn enum_types(&self) -> impl Iterator<Item = &EnumType> {
+        self.types.values().filter_map(|t| t.as_enum())
+    }
+
+    pub fn input_object_types(
+        &self,
+    ) -> impl Iterator<Item = &InputObjectType> {
+        self.types.values().filter_map(|t| t.as_input_object())
+    }
+
+    pub fn interface_types(
+        &self,
+    ) -> impl Iterator<Item = &InterfaceType> {
+        self.types.values().filter_map(|t| t.as_interface())
+    }
+
+    pub fn object_types(&self) -> impl Iterator<Item = &ObjectType> {
+        self.types.values().filter_map(|t| t.as_object())
+    }
+
+    pub fn scalar_types(&self) -> impl Iterator<Item = &ScalarType> {
+        self.types.values().filter_map(|t| t.as_scalar())
+    }
+
+    pub fn union_types(&self) -> impl Iterator<Item = &UnionType> {
+        self.types.values().filter_map(|t| t.as_union())
+    }
+
+    // ---------------------------------------------------------
+    // Abstract type relationships
+    // ---------------------------------------------------------
+
+    /// All object and interface types that directly declare they
+    /// implement the interface named `interface_name`.
+    ///
+    /// Because the spec requires implementations to also declare
+    /// every transitively-implemented interface, this includes
+    /// indirect implementors as well.
+    ///
+    /// See [Interfaces](https://spec.graphql.org/September2025/#sec-Interfaces).
+    pub fn types_implementing<'a>(
+        &'a self,
+        interface_name: &str,
+    ) -> impl Iterator<Item = &'a GraphQLType> + use<'a> {
+        let interface_name = TypeName::new(interface_name);
+        self.types.values().filter(move |t| {
+            let interfaces = match t {
+                GraphQLType::Interface(iface) => iface.interfaces(),
+                GraphQLType::Object(obj) => obj.interfaces(),
+                _ => return false,
+            };
+            interfaces
+                .iter()
+                .any(|iface| iface.value == interface_name)
+        })
+    }
+
+    /// The object types that may be the runtime type of a value
+    /// whose declared type is `type_name`:
+    ///
+    /// - For a union, its member types (in declaration order).
+    /// - For an interface, every object type implementing it.
+    /// - For an object type, the type itself.
+    ///
+    /// Returns an empty `Vec` for any other type kind or for an
+    /// undefined name.
+    ///
+    /// See [GetPossibleTypes()](https://spec.graphql.org/September2025/#GetPossibleTypes()).
+    pub fn possible_types(&self, type_name: &str) -> Vec<&ObjectType> {
+        match self.types.get(type_name) {
+            Some(GraphQLType::Interface(_)) => self
+                .types_implementing(type_name)
+                .filter_map(|t| t.as_object())
+                .collect(),
+            Some(GraphQLType::Object(obj)) => vec![obj.as_ref()],
+            Some(GraphQLType::Union(union_type)) => union_type
+                .members()
+                .iter()
+                .filter_map(|member| {
+                    self.object_type(member.value.as_str())
+                })
+                .collect(),
+            _ => vec![],
+        }
+    }
+
+    // ---------------------------------------------------------
+    // Root operation types
+    // ---------------------------------------------------------
+
+    /// The Query root operation type. Per the
+    /// [spec](https://spec.graphql.org/September2025/#sec-Root-Operation-Types),
+    /// all schemas must define a Query root type —
+    /// [`SchemaBuilder::build()`](crate::schema::SchemaBuilder::build)
+    /// validates this.
+    pub fn query_type(&self) -> &ObjectType {
+        self.object_type(self.query_type_name.as_str())
+            .expect("query root type is validated at build time")
+    }
+
+    pub fn query_type_name(&self) -> &TypeName {
+        &self.query_type_name
+    }
+
+    pub fn mutation_type(&self) -> Option<&ObjectType> {
+        self.mutation_type_name
+            .as_ref()
+            .and_then(|name| self.object_type(name.as_str()))
+    }
+
+    pub fn mutation_type_name(&self) -> Option<&TypeName> {
+        self.mutation_type_name.as_ref()
+    }
+
+    pub fn subscription_type(&self) -> Option<&ObjectType> {
+        self.subscription_type_name
+            .as_ref()
+            .and_then(|name| self.object_type(name.as_str()))
+    }
+
+    pub fn subscription_type_name(&self) -> Option<&TypeName> {
+        self.subscription_type_name.as_ref()
+    }
+
+    // ---------------------------------------------------------
+    // Input coercion
+    // ---------------------------------------------------------
+
+    /// Coerces a JSON variable value (as provided alongside a
+    /// request) to `type_annotation`, returning the coerced value.
+    ///
+    /// Built-in scalars, enums, lists, and input objects (including
+    /// OneOf input objects) follow the spec's input coercion rules;
+    /// omitted input object fields with a default value are filled
+    /// in. Custom scalars are coerced by their implementation in
+    /// the schema's [`scalar_registry()`](Self::scalar_registry),
+    /// and passed through unchanged if none is registered.
+    ///
+    /// See [Coercing Variable Values](https://spec.graphql.org/September2025/#sec-Coercing-Variable-Values).
+    ///
+    /// ```rust
+    /// # use libgraphql_core_v1 as libgraphql_core;
+    /// use libgraphql_core::schema::SchemaBuilder;
+    /// use libgraphql_core::types::TypeAnnotation;
+    /// use serde_json::json;
+    ///
+    /// let schema = SchemaBuilder::build_from_str(
+    ///     "type Query { a: Int } input Page { size: Int = 10, after: ID }",
+    /// ).unwrap();
+    /// let page = TypeAnnotation::named("Page", /* nullable = */ false);
+    ///
+    /// assert_eq!(
+    ///     schema.coerce_variable_value(&page, &json!({"after": 5})),
+    ///     Ok(json!({"size": 10, "after": "5"})),
+    /// );
+    /// let err = schema.coerce_variable_value(
+    ///     &page,
+    ///     &json!({"size": "big"}),
+    /// ).unwrap_err();
+    /// assert_eq!(err.to_string(), "expected `Int`, found `\"big\"` at `size`");
+    /// ```
+    pub fn coerce_variable_value(
+        &self,
+        type_annotation: &TypeAnnotation,
+        value: &serde_json::Value,
+    ) -> Result<serde_json::Value, VariableCoercionError> {
+        variable_coercion::coerce_variable_value(
+            value,
+            type_annotation,
+            &self.types,
+            &self.scalar_registry,
+            "",
+        )
+    }
+
+    /// The [`ScalarRegistry`] this schema was built with (see
+    /// [`SchemaBuilder::set_scalar_registry()`](crate::schema::SchemaBuilder::set_scalar_registry)).
+    /// Schemas derived from this one by a transform or a merge
+    /// keep it, so their custom scalar values are still validated.
+    ///
+    /// A registry holds trait objects, so it is not serialized; a
+    /// deserialized schema has an empty registry.
+    pub fn scalar_registry(&self) -> &ScalarRegistry {
+        &self.scalar_registry
+    }
+
+    // ---------------------------------------------------------
+    // Transforms
+    // ---------------------------------------------------------
+
+    /// Returns a copy of this schema without the types that cannot
+    /// be reached from a root operation type.
+    ///
+    /// A type is reachable if it is a root operation type, or is
+    /// the type of a field, argument, or input field of a reachable
+    /// type, an interface implemented by a reachable type, a member
+    /// of a reachable union, or an implementation of a reachable
+    /// interface. Types of directive definition arguments, and the
+    /// built-in scalars, are always kept.
+    ///
+    /// ```rust
+    /// # use libgraphql_core_v1 as libgraphql_core;
+    /// use libgraphql_core::schema::SchemaBuilder;
+    ///
+    /// let schema = SchemaBuilder::build_from_str(
+    ///     "type Query { me: User } type User { id: ID! } type Orphan { a: Int }",
+    /// ).unwrap();
+    /// let pruned = schema.prune_unreachable_types().unwrap();
+    /// assert!(pruned.object_type("User").is_some());
+    /// assert!(pruned.object_type("Orphan").is_none());
+    /// ```
+    // TODO: SchemaErrors wraps Vec<SchemaBuildError> which is
+    // large. Consider boxing once error strategy is finalized.
+    #[allow(clippy::result_large_err)]
+    pub fn prune_unreachable_types(&self) -> Result<Schema, SchemaErrors> {
+        schema_transform::prune_unreachable_types(self)
+    }
+
+    /// Returns a copy of this schema without the types, fields,
+    /// arguments, input fields, and enum values that carry the
+    /// directive `@<directive_name>` (e.g. `@internal`), and without
+    /// the directive's definition (unless it is a built-in
+    /// directive, such as `@deprecated`). Built-in scalars are
+    /// always kept.
+    ///
+    /// Removing an element also removes what it leaves dangling:
+    /// fields, arguments, and input fields of a removed type are
+    /// removed, and types left empty are removed in turn. Finally,
+    /// unreachable types are pruned as by
+    /// [`prune_unreachable_types()`](Self::prune_unreachable_types).
+    ///
+    /// The result is revalidated. Removals that leave it invalid
+    /// (e.g. removing a field an interface still requires) are
+    /// reported as errors.
+    ///
+    /// ```rust
+    /// # use libgraphql_core_v1 as libgraphql_core;
+    /// use libgraphql_core::schema::SchemaBuilder;
+    ///
+    /// let schema = SchemaBuilder::build_from_str("
+    ///     directive @internal on OBJECT | FIELD_DEFINITION
+    ///     type Query { me: User, audit: AuditLog @internal }
+    ///     type User { name: String, email: String @internal }
+    ///     type AuditLog @internal { entries: [String] }
+    /// ").unwrap();
+    /// let public = schema.remove_elements_with_directive("internal").unwrap();
+    /// assert_eq!(
+    ///     public.to_sdl(),
+    ///     "type Query {\n  me: User\n}\n\ntype User {\n  name: String\n}\n",
+    /// );
+    /// ```
+    #[allow(clippy::result_large_err)]
+    pub fn remove_elements_with_directive(
+        &self,
+        directive_name: &str,
+    ) -> Result<Schema, SchemaErrors> {
+        schema_transform::remove_elements_with_directive(
+            self,
+            directive_name,
+        )
+    }
+
+    /// Like
+    /// [`remove_elements_with_directive()`](Self::remove_elements_with_directive),
+    /// but only prunes the types the removal leaves unreachable.
+    #[allow(clippy::result_large_err)]
+    pub(crate) fn remove_elements_with_directive_and_orphans(
+        &self,
+        directive_name: &str,
+    ) -> Result<Schema, SchemaErrors> {
+        schema_transform::remove_elements_with_directive_and_orphans(
+            self,
+            directive_name,
+        )
+    }
+
+    /// Returns a copy of this schema keeping only the elements whose
+    /// [`SchemaCoordinate`] satisfies `keep`: types, fields,
+    /// arguments, input fields, enum values, directive definitions,
+    /// and directive arguments. Built-in scalars and directives are
+    /// always kept.
+    ///
+    /// Elements left dangling are removed as by
+    /// [`remove_elements_with_directive()`](Self::remove_elements_with_directive),
+    /// along with applications of removed directives. Unreachable
+    /// types are *not* pruned; chain
+    /// [`prune_unreachable_types()`](Self::prune_unreachable_types)
+    /// to do so.
+    ///
+    /// ```rust
+    /// # use libgraphql_core_v1 as libgraphql_core;
+    /// use libgraphql_core::schema::SchemaBuilder;
+    ///
+    /// let schema = SchemaBuilder::build_from_str(
+    ///     "type Query { user(id: ID!, debug: Boolean): String, stats: Int }",
+    /// ).unwrap();
+    /// let partner = schema.filter(|coordinate| {
+    ///     !["Query.stats", "Query.user(debug:)"]
+    ///         .contains(&coordinate.to_string().as_str())
+    /// }).unwrap();
+    /// assert_eq!(partner.to_sdl(), "type Query {\n  user(id: ID!): String\n}\n");
+    /// ```
+    #[allow(clippy::result_large_err)]
+    pub fn filter(
+        &self,
+        keep: impl Fn(&SchemaCoordinate) -> bool,
+    ) -> Result<Schema, SchemaErrors> {
+        schema_transform::filter(self, keep)
+    }
+
+    /// Returns a copy of this schema with `prefix` prepended to the
+    /// name of every type except the built-in scalars, and every
+    /// reference to those types (including the root operation type
+    /// bindings) updated to match.
+    ///
+    /// `prefix` must be a valid start of a GraphQL name. A prefixed
+    /// name that collides with a built-in scalar is reported as a
+    /// [`DuplicateTypeDefinition`](crate::schema::SchemaBuildErrorKind::DuplicateTypeDefinition)
+    /// error.
+    ///
+    /// ```rust
+    /// # use libgraphql_core_v1 as libgraphql_core;
+    /// use libgraphql_core::schema::SchemaBuilder;
+    ///
+    /// let schema = SchemaBuilder::build_from_str(
+    ///     "type Query { me: User } type User { id: ID! }",
+    /// ).unwrap();
+    /// let prefixed = schema.prefix_type_names("Partner").unwrap();
+    /// assert_eq!(prefixed.query_type_name().as_str(), "PartnerQuery");
+    /// assert_eq!(
+    ///     prefixed.query_type().field("me").unwrap().type_annotation().to_string(),
+    ///     "PartnerUser",
+    /// );
+    /// ```
+    #[allow(clippy::result_large_err)]
+    pub fn prefix_type_names(
+        &self,
+        prefix: &str,
+    ) -> Result<Schema, SchemaErrors> {
+        schema_transform::prefix_type_names(self, prefix)
+    }
+
+    // ---------------------------------------------------------
+    // SDL printing
+    // ---------------------------------------------------------
+
+    /// Prints this schema as GraphQL SDL using the default
+    /// [`SdlPrintOptions`]: built-ins omitted, definition order
+    /// preserved, and descriptions as block strings.
+    ///
+    /// The output can be loaded back into a
+    /// [`SchemaBuilder`](crate::schema::SchemaBuilder) to rebuild
+    /// an equivalent schema.
+    pub fn to_sdl(&self) -> String {
+        self.to_sdl_with_options(&SdlPrintOptions::default())
+    }
+
+    /// Prints this schema as GraphQL SDL.
+    ///
+    /// A `schema { ... }` definition is printed only when the root
+    /// operation types differ from the defaults (`Query`,
+    /// `Mutation`, `Subscription`), and applied directives are
+    /// printed with their arguments.
+    ///
+    /// ```rust
+    /// # use libgraphql_core_v1 as libgraphql_core;
+    /// use libgraphql_core::schema::SchemaBuilder;
+    /// use libgraphql_core::schema::SdlPrintOptions;
+    ///
+    /// let schema = SchemaBuilder::build_from_str(
+    ///     "type Query { b: Int, a: Url }
+    ///      scalar Url @specifiedBy(url: \"https://url.spec.whatwg.org\")
+    ///      enum Color { RED }",
+    /// ).unwrap();
+    ///
+    /// // Types are sorted by name; fields keep their definition order.
+    /// let sdl = schema.to_sdl_with_options(&SdlPrintOptions {
+    ///     sort_alphabetically: true,
+    ///     ..SdlPrintOptions::default()
+    /// });
+    /// assert_eq!(sdl, "\
+    /// enum Color {
+    ///   RED
+    /// }
+    ///
+    /// type Query {
+    ///   b: Int
+    ///   a: Url
+    /// }
+    ///
+    /// scalar Url @specifiedBy(url: \"https://url.spec.whatwg.org\")
+    /// ");
+    /// ```
+    pub fn to_sdl_with_options(&self, options: &SdlPrintOptions) -> String {
+        SdlPrinter::new(self, options).print()
+    }
+
+    // ---------------------------------------------------------
+    // Introspection
+    // ---------------------------------------------------------
+
+    /// Generates the `data` the standard `IntrospectionQuery`
+    /// would return for this schema, using the default
+    /// [`IntrospectionOptions`] (deprecated elements included).
+    ///
+    /// The result can be loaded back with
+    /// [`SchemaBuilder::from_introspection_json()`](crate::schema::SchemaBuilder::from_introspection_json)
+    /// to rebuild an equivalent schema.
+    pub fn to_introspection_json(&self) -> serde_json::Value {
+        self.to_introspection_json_with_options(
+            &IntrospectionOptions::default(),
+        )
+    }
+
+    /// Generates the `data` the standard `IntrospectionQuery`
+    /// would return for this schema: a `{"__schema": ...}` object
+    /// listing every type (including the built-in scalars and the
+    /// introspection types), every directive, and the root
+    /// operation types.
+    ///
+    /// `options.include_deprecated` is passed as the
+    /// `includeDeprecated` argument of every field that accepts
+    /// one.
+    ///
+    /// ```rust
+    /// # use libgraphql_core_v1 as libgraphql_core;
+    /// use libgraphql_core::schema::IntrospectionOptions;
+    /// use libgraphql_core::schema::SchemaBuilder;
+    ///
+    /// let schema = SchemaBuilder::build_from_str(
+    ///     "type Query { old: Int @deprecated, new: Int }",
+    /// ).unwrap();
+    ///
+    /// let json = schema.to_introspection_json_with_options(
+    ///     &IntrospectionOptions { include_deprecated: false },
+    /// );
+    /// let query = &json["__schema"]["types"]
+    ///     .as_array()
+    ///     .unwrap()
+    ///     .iter()
+    ///     .find(|t| t["name"] == "Query")
+    ///     .unwrap();
+    /// assert_eq!(query["fields"].as_array().unwrap().len(), 1);
+    /// assert_eq!(query["fields"][0]["name"], "new");
+    /// ```
+    pub fn to_introspection_json_with_options(
+        &self,
+        options: &IntrospectionOptions,
+    ) -> serde_json::Value {
+        IntrospectionPrinter::new(self, options).print()
+    }
+
+    // ---------------------------------------------------------
+    // Source map resolution
+    // ---------------------------------------------------------
+
+    /// The source maps for every source loaded into the builder,
+    /// indexed by [`SourceMapId`](crate::span::SourceMapId).
+    /// Index 0 is always the built-in source map.
+    pub fn source_maps(&self) -> &[SchemaSourceMap] {
+        &self.source_maps
+    }
+
+    /// Resolves a [`Span`] recorded on any type, field, or
+    /// directive in this schema to its file path and line/column
+    /// range.
+    pub fn resolve_span(&self, span: Span) -> Option<ResolvedSpan<'_>> {
+        crate::schema_source_map::resolve_span(&self.source_maps, span)
+    }
 }
@@ -0,0 +1,807 @@
+use crate::located::Located;
+use crate::names::EnumValueName;
+use crate::names::FieldName;
+use crate::names::TypeName;
+use crate::schema::schema_change::ChangeSeverity;
+use crate::schema::schema_change::SchemaChange;
+use crate::schema::schema_change::SchemaChangeKind;
+use crate::schema::schema_def::Schema;
+use crate::span::Span;
+use crate::types::DirectiveDefinition;
+use crate::types::EnumValue;
+use crate::types::FieldDefinition;
+use crate::types::GraphQLType;
+use crate::types::InputField;
+use crate::types::ParameterDefinition;
+use crate::types::TypeAnnotation;
+use crate::value::Value;
+use indexmap::IndexMap;
+
+/// Compares two schemas and returns every change needed to turn
+/// `old` into `new`, each classified as breaking, dangerous, or
+/// safe for clients written against `old`.
+///
+/// Changes are reported in a stable order: root operation types,
+/// then directives, then types, each in `old`'s definition order
+/// followed by additions in `new`'s definition order.
+///
+/// Type changes are classified with
+/// [`TypeAnnotation::is_subtype_of()`]: a field's type may change
+/// to any subtype of its old type (e.g. `String` → `String!`, or
+/// an interface → one of its implementations), while an argument's
+/// or input field's type may only change to a supertype (e.g.
+/// `Int!` → `Int`).
+///
+/// ```rust
+/// # use libgraphql_core_v1 as libgraphql_core;
+/// use libgraphql_core::schema;
+/// use libgraphql_core::schema::ChangeSeverity;
+/// use libgraphql_core::schema::SchemaBuilder;
+///
+/// let old = SchemaBuilder::build_from_str(
+///     "type Query { name: String, age: Int }",
+/// ).unwrap();
+/// let new = SchemaBuilder::build_from_str(
+///     "type Query { name: String! }",
+/// ).unwrap();
+///
+/// let changes = schema::diff(&old, &new);
+/// let summary: Vec<String> =
+///     changes.iter().map(|c| c.to_string()).collect();
+/// assert_eq!(summary, [
+///     "safe: type of field `Query.name` changed from `String` to `String!`",
+///     "breaking: field `Query.age` was removed",
+/// ]);
+/// assert_eq!(changes[1].severity(), ChangeSeverity::Breaking);
+/// ```
+pub fn diff(old: &Schema, new: &Schema) -> Vec<SchemaChange> {
+    let mut differ = SchemaDiffer {
+        changes: vec![],
+        new,
+        old,
+    };
+    differ.diff_root_types();
+    differ.diff_directives();
+    differ.diff_types();
+    differ.changes
+}
+
+/// The element that owns a set of arguments.
+enum ArgumentOwner<'a> {
+    Directive {
+        directive_name: &'a str,
+    },
+    Field {
+        field_name: &'a str,
+        type_name: &'a str,
+    },
+}
+
+struct SchemaDiffer<'a> {
+    changes: Vec<SchemaChange>,
+    new: &'a Schema,
+    old: &'a Schema,
+}
+
+impl SchemaDiffer<'_> {
+    fn push(
+        &mut self,
+        kind: SchemaChangeKind,
+        severity: ChangeSeverity,
+        old_span: Option<Span>,
+        new_span: Option<Span>,
+    ) {
+        self.changes.push(
+            SchemaChange::new(kind, severity, old_span, new_span),
+        );
+    }
+
+    // ---------------------------------------------------------
+    // Root operation types
+    // ---------------------------------------------------------
+
+    fn diff_root_types(&mut self) {
+        let (old, new) = (self.old, self.new);
+        let bindings = [
+            (
+                "query",
+                Some(old.query_type_name()),
+                Some(new.query_type_name()),
+            ),
+            ("mutation", old.mutation_type_name(), new.mutation_type_name()),
+            (
+                "subscription",
+                old.subscription_type_name(),
+                new.subscription_type_name(),
+            ),
+        ];
+        for (operation, old_name, new_name) in bindings {
+            if old_name == new_name {
+                continue;
+            }
+            // Adding a root operation type can't affect existing
+            // operations; removing or rebinding one can.
+            let severity = if old_name.is_none() {
+                ChangeSeverity::Safe
+            } else {
+                ChangeSeverity::Breaking
+            };
+            self.push(
+                SchemaChangeKind::RootOperationTypeChanged {
+                    new_type_name: new_name.map(|n| n.to_string()),
+                    old_type_name: old_name.map(|n| n.to_string()),
+                    operation: operation.to_string(),
+                },
+                severity,
+                old_name.and_then(|n| old.get_type(n.as_str())).map(|t| t.span()),
+                new_name.and_then(|n| new.get_type(n.as_str())).map(|t| t.span()),
+            );
+        }
+    }
+
+    // ---------------------------------------------------------
+    // Directives
+    // ---------------------------------------------------------
+
+    fn diff_directives(&mut self) {
+        let (old, new) = (self.old, self.new);
+        for (name, old_def) in old.directive_defs() {
+            match new.directive(name.as_str()) {
+                Some(new_def) => self.diff_directive(old_def, new_def),
+                None => self.push(
+                    SchemaChangeKind::DirectiveRemoved {
+                        directive_name: name.to_string(),
+                    },
+                    ChangeSeverity::Breaking,
+                    Some(old_def.span()),
+                    None,
+                ),
+            }
+        }
+        for (name, new_def) in new.directive_defs() {
+            if old.directive(name.as_str()).is_none() {
+                self.push(
+                    SchemaChangeKind::DirectiveAdded {
+                        directive_name: name.to_string(),
+                    },
+                    ChangeSeverity::Safe,
+                    None,
+                    Some(new_def.span()),
+                );
+            }
+        }
+    }
+
+    fn diff_directive(
+        &mut self,
+        old_def: &DirectiveDefinition,
+        new_def: &DirectiveDefinition,
+    ) {
+        let directive_name = old_def.name().as_str();
+        let spans = (Some(old_def.span()), Some(new_def.span()));
+
+        if old_def.is_repeatable() != new_def.is_repeatable() {
+            self.push(
+                SchemaChangeKind::DirectiveRepeatableChanged {
+                    directive_name: directive_name.to_string(),
+                    is_repeatable: new_def.is_repeatable(),
+                },
+                if new_def.is_repeatable() {
+                    ChangeSeverity::Safe
+                } else {
+                    ChangeSeverity::Breaking
+                },
+                spans.0,
+                spans.1,
+            );
+        }
+
+        for location in old_def.locations() {
+            if !new_def.locations().contains(location) {
+                self.push(
+                    SchemaChangeKind::DirectiveLocationRemoved {
+                        directive_name: directive_name.to_string(),
+                        location: location.as_str()
+                            .to_string(),
+                    },
+                    ChangeSeverity::Breaking,
+                    spans.0,
+                    spans.1,
+                );
+            }
+        }
+        for location in new_def.locations() {
+            if !old_def.locations().contains(location) {
+                self.push(
+                    SchemaChangeKind::DirectiveLocationAdded {
+                        directive_name: directive_name.to_string(),
+                        location: location.as_str()
+                            .to_string(),
+                    },
+                    ChangeSeverity::Safe,
+                    spans.0,
+                    spans.1,
+                );
+            }
+        }
+
+        self.diff_arguments(
+            &ArgumentOwner::Directive { directive_name },
+            old_def.parameters(),
+            new_def.parameters(),
+            spans,
+        );
+    }
+
+    // ---------------------------------------------------------
+    // Types
+    // ---------------------------------------------------------
+
+    fn diff_types(&mut self) {
+        let (old, new) = (self.old, self.new);
+        for (name, old_type) in old.types() {
+            let Some(new_type) = new.get_type(name.as_str()) else {
+                self.push(
+                    SchemaChangeKind::TypeRemoved {
+                        type_name: name.to_string(),
+                    },
+                    ChangeSeverity::Breaking,
+                    Some(old_type.span()),
+                    None,
+                );
+                continue;
+            };
+            self.diff_type(old_type, new_type);
+        }
+        for (name, new_type) in new.types() {
+            if old.get_type(name.as_str()).is_none() {
+                self.push(
+                    SchemaChangeKind::TypeAdded {
+                        type_name: name.to_string(),
+                    },
+                    ChangeSeverity::Safe,
+                    None,
+                    Some(new_type.span()),
+                );
+            }
+        }
+    }
+
+    fn diff_type(&mut self, old_type: &GraphQLType, new_type: &GraphQLType) {
+        let type_name = old_type.name();
+        let spans = (Some(old_type.span()), Some(new_type.span()));
+        match (old_type, new_type) {
+            (GraphQLType::Enum(old_enum), GraphQLType::Enum(new_enum)) => {
+                self.diff_enum_values(
+                    type_name,
+                    old_enum.values(),
+                    new_enum.values(),
+                    spans,
+                );
+            },
+            (
+                GraphQLType::InputObject(old_obj),
+                GraphQLType::InputObject(new_obj),
+            ) => {
+                self.diff_input_fields(
+                    type_name,
+                    old_obj.fields(),
+                    new_obj.fields(),
+                    spans,
+                );
+            },
+            (
+                GraphQLType::Interface(old_iface),
+                GraphQLType::Interface(new_iface),
+            ) => {
+                self.diff_interfaces(
+                    type_name,
+                    old_iface.interfaces(),
+                    new_iface.interfaces(),
+                    spans,
+                );
+                self.diff_fields(
+                    type_name,
+                    old_iface.fields(),
+                    new_iface.fields(),
+                    spans,
+                );
+            },
+            (GraphQLType::Object(old_obj), GraphQLType::Object(new_obj)) => {
+                self.diff_interfaces(
+                    type_name,
+                    old_obj.interfaces(),
+                    new_obj.interfaces(),
+                    spans,
+                );
+                self.diff_fields(
+                    type_name,
+                    old_obj.fields(),
+                    new_obj.fields(),
+                    spans,
+                );
+            },
+            (GraphQLType::Scalar(old_scalar), GraphQLType::Scalar(new_scalar))
+                if old_scalar.kind() == new_scalar.kind() => {},
+            (GraphQLType::Union(old_union), GraphQLType::Union(new_union)) => {
+                self.diff_union_members(
+                    type_name,
+                    old_union.members(),
+                    new_union.members(),
+                    spans,
+                );
+            },
+            _ => self.push(
+                SchemaChangeKind::TypeKindChanged {
+                    new_kind: new_type.type_kind(),
+                    old_kind: old_type.type_kind(),
+                    type_name: type_name.to_string(),
+                },
+                ChangeSeverity::Breaking,
+                spans.0,
+                spans.1,
+            ),
+        }
+    }
+
+    fn diff_enum_values(
+        &mut self,
+        type_name: &TypeName,
+        old_values: &IndexMap<EnumValueName, EnumValue>,
+        new_values: &IndexMap<EnumValueName, EnumValue>,
+        (old_type_span, new_type_span): (Option<Span>, Option<Span>),
+    ) {
+        for (name, old_value) in old_values {
+            if !new_values.contains_key(name) {
+                self.push(
+                    SchemaChangeKind::EnumValueRemoved {
+                        type_name: type_name.to_string(),
+                        value_name: name.to_string(),
+                    },
+                    ChangeSeverity::Breaking,
+                    Some(old_value.span()),
+                    new_type_span,
+                );
+            }
+        }
+        for (name, new_value) in new_values {
+            if !old_values.contains_key(name) {
+                // Clients that exhaustively handle the enum may
+                // not expect the new value in responses.
+                self.push(
+                    SchemaChangeKind::EnumValueAdded {
+                        type_name: type_name.to_string(),
+                        value_name: name.to_string(),
+                    },
+                    ChangeSeverity::Dangerous,
+                    old_type_span,
+                    Some(new_value.span()),
+                );
+            }
+        }
+    }
+
+    fn diff_union_members(
+        &mut self,
+        type_name: &TypeName,
+        old_members: &[Located<TypeName>],
+        new_members: &[Located<TypeName>],
+        (old_type_span, new_type_span): (Option<Span>, Option<Span>),
+    ) {
+        for old_member in old_members {
+            if !new_members.iter().any(|m| m.value == old_member.value) {
+                self.push(
+                    SchemaChangeKind::UnionMemberRemoved {
+                        member_name: old_member.value.to_string(),
+                        type_name: type_name.to_string(),
+                    },
+                    ChangeSeverity::Breaking,
+                    Some(old_member.span),
+                    new_type_span,
+                );
+            }
+        }
+        for new_member in new_members {
+            if !old_members.iter().any(|m| m.value == new_member.value) {
+                self.push(
+                    SchemaChangeKind::UnionMemberAdded {
+                        member_name: new_member.value.to_string(),
+                        type_name: type_name.to_string(),
+                    },
+                    ChangeSeverity::Dangerous,
+                    old_type_span,
+                    Some(new_member.span),
+                );
+            }
+        }
+    }
+
+    fn diff_interfaces(
+        &mut self,
+        type_name: &TypeName,
+        old_interfaces: &[Located<TypeName>],
+        new_interfaces: &[Located<TypeName>],
+        (old_type_span, new_type_span): (Option<Span>, Option<Span>),
+    ) {
+        for old_iface in old_interfaces {
+            if !new_interfaces.iter().any(|i| i.value == old_iface.value) {
+                self.push(
+                    SchemaChangeKind::InterfaceImplementationRemoved {
+                        interface_name: old_iface.value.to_string(),
+                        type_name: type_name.to_string(),
+                    },
+                    ChangeSeverity::Breaking,
+                    Some(old_iface.span),
+                    new_type_span,
+                );
+            }
+        }
+        for new_iface in new_interfaces {
+            if !old_interfaces.iter().any(|i| i.value == new_iface.value) {
+                // Fragments on the interface may now match values
+                // of this type that they previously didn't.
+                self.push(
+                    SchemaChangeKind::InterfaceImplementationAdded {
+                        interface_name: new_iface.value.to_string(),
+                        type_name: type_name.to_string(),
+                    },
+                    ChangeSeverity::Dangerous,
+                    old_type_span,
+                    Some(new_iface.span),
+                );
+            }
+        }
+    }
+
+    // ---------------------------------------------------------
+    // Fields
+    // ---------------------------------------------------------
+
+    fn diff_fields(
+        &mut self,
+        type_name: &TypeName,
+        old_fields: &IndexMap<FieldName, FieldDefinition>,
+        new_fields: &IndexMap<FieldName, FieldDefinition>,
+        (old_type_span, new_type_span): (Option<Span>, Option<Span>),
+    ) {
+        for (name, old_field) in old_fields {
+            let Some(new_field) = new_fields.get(name) else {
+                self.push(
+                    SchemaChangeKind::FieldRemoved {
+                        field_name: name.to_string(),
+                        type_name: type_name.to_string(),
+                    },
+                    ChangeSeverity::Breaking,
+                    Some(old_field.span()),
+                    new_type_span,
+                );
+                continue;
+            };
+
+            let spans = (Some(old_field.span()), Some(new_field.span()));
+            let old_type = old_field.type_annotation();
+            let new_type = new_field.type_annotation();
+            if !new_type.is_equivalent_to(old_type) {
+                // Output types are covariant.
+                let severity =
+                    if new_type.is_subtype_of(self.new.types(), old_type) {
+                        ChangeSeverity::Safe
+                    } else {
+                        ChangeSeverity::Breaking
+                    };
+                self.push(
+                    SchemaChangeKind::FieldTypeChanged {
+                        field_name: name.to_string(),
+                        new_type: new_type.to_string(),
+                        old_type: old_type.to_string(),
+                        type_name: type_name.to_string(),
+                    },
+                    severity,
+                    spans.0,
+                    spans.1,
+                );
+            }
+
+            self.diff_arguments(
+                &ArgumentOwner::Field {
+                    field_name: name.as_str(),
+                    type_name: type_name.as_str(),
+                },
+                old_field.parameters(),
+                new_field.parameters(),
+                spans,
+            );
+        }
+        for (name, new_field) in new_fields {
+            if !old_fields.contains_key(name) {
+                self.push(
+                    SchemaChangeKind::FieldAdded {
+                        field_name: name.to_string(),
+                        type_name: type_name.to_string(),
+                    },
+                    ChangeSeverity::Safe,
+                    old_type_span,
+                    Some(new_field.span()),
+                );
+            }
+        }
+    }
+
+    fn diff_arguments(
+        &mut self,
+        owner: &ArgumentOwner<'_>,
+        old_params: &IndexMap<FieldName, ParameterDefinition>,
+        new_params: &IndexMap<FieldName, ParameterDefinition>,
+        (old_owner_span, new_owner_span): (Option<Span>, Option<Span>),
+    ) {
+        for (name, old_param) in old_params {
+            let Some(new_param) = new_params.get(name) else {
+                self.push(
+                    owner.argument_removed(name),
+                    ChangeSeverity::Breaking,
+                    Some(old_param.span()),
+                    new_owner_span,
+                );
+                continue;
+            };
+
+            let spans = (Some(old_param.span()), Some(new_param.span()));
+            let old_type = old_param.type_annotation();
+            let new_type = new_param.type_annotation();
+            if !new_type.is_equivalent_to(old_type) {
+                self.push(
+                    owner.argument_type_changed(name, old_type, new_type),
+                    self.input_type_change_severity(old_type, new_type),
+                    spans.0,
+                    spans.1,
+                );
+            }
+            if old_param.default_value() != new_param.default_value() {
+                self.push(
+                    owner.argument_default_value_changed(
+                        name,
+                        old_param.default_value(),
+                        new_param.default_value(),
+                    ),
+                    ChangeSeverity::Dangerous,
+                    spans.0,
+                    spans.1,
+                );
+            }
+        }
+        for (name, new_param) in new_params {
+            if old_params.contains_key(name) {
+                continue;
+            }
+            let required = is_required(
+                new_param.type_annotation(),
+                new_param.default_value(),
+            );
+            let severity = match (required, owner) {
+                (true, _) => ChangeSeverity::Breaking,
+                // A new optional field argument may change what
+                // the field resolves to for existing operations.
+                (false, ArgumentOwner::Field { .. }) => {
+                    ChangeSeverity::Dangerous
+                },
+                (false, ArgumentOwner::Directive { .. }) => {
+                    ChangeSeverity::Safe
+                },
+            };
+            self.push(
+                owner.argument_added(name, required),
+                severity,
+                old_owner_span,
+                Some(new_param.span()),
+            );
+        }
+    }
+
+    fn diff_input_fields(
+        &mut self,
+        type_name: &TypeName,
+        old_fields: &IndexMap<FieldName, InputField>,
+        new_fields: &IndexMap<FieldName, InputField>,
+        (old_type_span, new_type_span): (Option<Span>, Option<Span>),
+    ) {
+        for (name, old_field) in old_fields {
+            let Some(new_field) = new_fields.get(name) else {
+                self.push(
+                    SchemaChangeKind::InputFieldRemoved {
+                        field_name: name.to_string(),
+                        type_name: type_name.to_string(),
+                    },
+                    ChangeSeverity::Breaking,
+                    Some(old_field.span()),
+                    new_type_span,
+                );
+                continue;
+            };
+
+            let spans = (Some(old_field.span()), Some(new_field.span()));
+            let old_type = old_field.type_annotation();
+            let new_type = new_field.type_annotation();
+            if !new_type.is_equivalent_to(old_type) {
+                self.push(
+                    SchemaChangeKind::InputFieldTypeChanged {
+                        field_name: name.to_string(),
+                        new_type: new_type.to_string(),
+                        old_type: old_type.to_string(),
+                        type_name: type_name.to_string(),
+                    },
+                    self.input_type_change_severity(old_type, new_type),
+                    spans.0,
+                    spans.1,
+                );
+            }
+            if old_field.default_value() != new_field.default_value() {
+                self.push(
+                    SchemaChangeKind::InputFieldDefaultValueChanged {
+                        field_name: name.to_string(),
+                        new_default: new_field
+                            .default_value()
+                            .map(|v| v.to_string()),
+                        old_default: old_field
+                            .default_value()
+                            .map(|v| v.to_string()),
+                        type_name: type_name.to_string(),
+                    },
+                    ChangeSeverity::Dangerous,
+                    spans.0,
+                    spans.1,
+                );
+            }
+        }
+        for (name, new_field) in new_fields {
+            if old_fields.contains_key(name) {
+                continue;
+            }
+            let required = is_required(
+                new_field.type_annotation(),
+                new_field.default_value(),
+            );
+            self.push(
+                SchemaChangeKind::InputFieldAdded {
+                    field_name: name.to_string(),
+                    required,
+                    type_name: type_name.to_string(),
+                },
+                if required {
+                    ChangeSeverity::Breaking
+                } else {
+                    ChangeSeverity::Dangerous
+                },
+                old_type_span,
+                Some(new_field.span()),
+            );
+        }
+    }
+
+    /// Input types are contravariant: every value accepted by the
+    /// old type must still be accepted by the new one, so the old
+    /// type must be a subtype of the new one (e.g. `Int!` →
+    /// `Int`).
+    fn input_type_change_severity(
+        &self,
+        old_type: &TypeAnnotation,
+        new_type: &TypeAnnotation,
+    ) -> ChangeSeverity {
+        if old_type.is_subtype_of(self.new.types(), new_type) {
+            ChangeSeverity::Safe
+        } else {
+            ChangeSeverity::Breaking
+        }
+    }
+}
+
+impl ArgumentOwner<'_> {
+    fn argument_added(
+        &self,
+        argument_name: &FieldName,
+        required: bool,
+    ) -> SchemaChangeKind {
+        let argument_name = argument_name.to_string();
+        match *self {
+            Self::Directive { directive_name } => {
+                SchemaChangeKind::DirectiveArgumentAdded {
+                    argument_name,
+                    directive_name: directive_name.to_string(),
+                    required,
+                }
+            },
+            Self::Field { field_name, type_name } => {
+                SchemaChangeKind::ArgumentAdded {
+                    argument_name,
+                    field_name: field_name.to_string(),
+                    required,
+                    type_name: type_name.to_string(),
+                }
+            },
+        }
+    }
+
+    fn argument_default_value_changed(
+        &self,
+        argument_name: &FieldName,
+        old_default: Option<&Value>,
+        new_default: Option<&Value>,
+    ) -> SchemaChangeKind {
+        let argument_name = argument_name.to_string();
+        let old_default = old_default.map(|v| v.to_string());
+        let new_default = new_default.map(|v| v.to_string());
+        match *self {
+            Self::Directive { directive_name } => {
+                SchemaChangeKind::DirectiveArgumentDefaultValueChanged {
+                    argument_name,
+                    directive_name: directive_name.to_string(),
+                    new_default,
+                    old_default,
+                }
+            },
+            Self::Field { field_name, type_name } => {
+                SchemaChangeKind::ArgumentDefaultValueChanged {
+                    argument_name,
+                    field_name: field_name.to_string(),
+                    new_default,
+                    old_default,
+                    type_name: type_name.to_string(),
+                }
+            },
+        }
+    }
+
+    fn argument_removed(&self, argument_name: &FieldName) -> SchemaChangeKind {
+        let argument_name = argument_name.to_string();
+        match *self {
+            Self::Directive { directive_name } => {
+                SchemaChangeKind::DirectiveArgumentRemoved {
+                    argument_name,
+                    directive_name: directive_name.to_string(),
+                }
+            },
+            Self::Field { field_name, type_name } => {
+                SchemaChangeKind::ArgumentRemoved {
+                    argument_name,
+                    field_name: field_name.to_string(),
+                    type_name: type_name.to_string(),
+                }
+            },
+        }
+    }
+
+    fn argument_type_changed(
+        &self,
+        argument_name: &FieldName,
+        old_type: &TypeAnnotation,
+        new_type: &TypeAnnotation,
+    ) -> SchemaChangeKind {
+        let argument_name = argument_name.to_string();
+        let old_type = old_type.to_string();
+        let new_type = new_type.to_string();
+        match *self {
+            Self::Directive { directive_name } => {
+                SchemaChangeKind::DirectiveArgumentTypeChanged {
+                    argument_name,
+                    directive_name: directive_name.to_string(),
+                    new_type,
+                    old_type,
+                }
+            },
+            Self::Field { field_name, type_name } => {
+                SchemaChangeKind::ArgumentTypeChanged {
+                    argument_name,
+                    field_name: field_name.to_string(),
+                    new_type,
+                    old_type,
+                    type_name: type_name.to_string(),
+                }
+            },
+        }
+    }
+}
+
+/// Whether an argument or input field must be provided: it is
+/// non-null and has no default value.
+fn is_required(type_annotation: &TypeAnnotation, default: Option<&Value>) -> bool {
+    !type_annotation.nullable() && default.is_none()
+}
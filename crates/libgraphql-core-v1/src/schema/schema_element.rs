@@ -0,0 +1,87 @@
+use crate::directive_annotation::DirectiveAnnotation;
+use crate::schema::SchemaCoordinate;
+use crate::span::Span;
+use crate::types::DirectiveDefinition;
+use crate::types::EnumValue;
+use crate::types::FieldDefinition;
+use crate::types::GraphQLType;
+use crate::types::InputField;
+use crate::types::ParameterDefinition;
+
+/// A schema element that a [`SchemaCoordinate`] can refer to, as
+/// returned by
+/// [`Schema::resolve_coordinate()`](crate::schema::Schema::resolve_coordinate).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SchemaElement<'schema> {
+    /// `@directive`
+    Directive(&'schema DirectiveDefinition),
+    /// `@directive(argument:)`
+    DirectiveArgument(&'schema ParameterDefinition),
+    /// `Enum.VALUE`
+    EnumValue(&'schema EnumValue),
+    /// `Type.field` on an object or interface type
+    Field(&'schema FieldDefinition),
+    /// `Type.field(argument:)`
+    FieldArgument(&'schema ParameterDefinition),
+    /// `Input.field`
+    InputField(&'schema InputField),
+    /// `Type`
+    Type(&'schema GraphQLType),
+}
+
+impl SchemaElement<'_> {
+    /// The element's own schema coordinate.
+    ///
+    /// This is the canonical form of the coordinate the element was
+    /// resolved from.
+    pub fn coordinate(&self) -> SchemaCoordinate {
+        match self {
+            Self::Directive(def) => def.coordinate(),
+            Self::DirectiveArgument(param) => param.coordinate(),
+            Self::EnumValue(value) => value.coordinate(),
+            Self::Field(field) => field.coordinate(),
+            Self::FieldArgument(param) => param.coordinate(),
+            Self::InputField(field) => field.coordinate(),
+            Self::Type(type_) => type_.coordinate(),
+        }
+    }
+
+    pub fn description(&self) -> Option<&str> {
+        match self {
+            Self::Directive(def) => def.description(),
+            Self::DirectiveArgument(param) => param.description(),
+            Self::EnumValue(value) => value.description(),
+            Self::Field(field) => field.description(),
+            Self::FieldArgument(param) => param.description(),
+            Self::InputField(field) => field.description(),
+            Self::Type(type_) => type_.description(),
+        }
+    }
+
+    /// The directives applied to the element. Directive
+    /// definitions cannot carry directives, so this is empty for
+    /// them.
+    pub fn directives(&self) -> &[DirectiveAnnotation] {
+        match self {
+            Self::Directive(_) => &[],
+            Self::DirectiveArgument(param) => param.directives(),
+            Self::EnumValue(value) => value.directives(),
+            Self::Field(field) => field.directives(),
+            Self::FieldArgument(param) => param.directives(),
+            Self::InputField(field) => field.directives(),
+            Self::Type(type_) => type_.directives(),
+        }
+    }
+
+    pub fn span(&self) -> Span {
+        match self {
+            Self::Directive(def) => def.span(),
+            Self::DirectiveArgument(param) => param.span(),
+            Self::EnumValue(value) => value.span(),
+            Self::Field(field) => field.span(),
+            Self::FieldArgument(param) => param.span(),
+            Self::InputField(field) => field.span(),
+            Self::Type(type_) => type_.span(),
+        }
+    }
+}
@@ -1,4 +1,7 @@
 use crate::schema::schema_build_error::SchemaBuildError;
+use crate::schema_source_map::ResolvedSpan;
+use crate::schema_source_map::SchemaSourceMap;
+use crate::span::Span;
 
 /// A collection of errors from
 /// [`SchemaBuilder::build()`](crate::schema::SchemaBuilder::build).
@@ -9,19 +12,54 @@ use crate::schema::schema_build_error::SchemaBuildError;
 ///
 /// This type is never empty — construction via `new()` requires
 /// at least one error (enforced by `debug_assert`).
+///
+/// Carries the [`SchemaSourceMap`]s of every source loaded into
+/// the builder, so each error's [`Span`] can be resolved to a
+/// file path, line, and column via
+/// [`resolve_span()`](Self::resolve_span) after the builder has
+/// been consumed.
 #[derive(Debug, PartialEq)]
 pub struct SchemaErrors {
     errors: Vec<SchemaBuildError>,
+    source_maps: Vec<SchemaSourceMap>,
 }
 
 impl SchemaErrors {
     pub(crate) fn new(errors: Vec<SchemaBuildError>) -> Self {
         debug_assert!(!errors.is_empty());
-        Self { errors }
+        Self { errors, source_maps: vec![] }
+    }
+
+    pub(crate) fn with_source_maps(
+        mut self,
+        source_maps: Vec<SchemaSourceMap>,
+    ) -> Self {
+        self.source_maps = source_maps;
+        self
     }
 
     pub fn errors(&self) -> &[SchemaBuildError] { &self.errors }
 
+    /// Formats every error as a multi-line diagnostic with source
+    /// snippets (see
+    /// [`SchemaBuildError::format_detailed()`]), separated by
+    /// blank lines.
+    pub fn format_detailed(&self) -> String {
+        self.errors
+            .iter()
+            .map(|err| err.format_detailed(&self.source_maps))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Resolves a [`Span`] from one of these errors (or their
+    /// notes) to its file path and line/column range.
+    pub fn resolve_span(&self, span: Span) -> Option<ResolvedSpan<'_>> {
+        crate::schema_source_map::resolve_span(&self.source_maps, span)
+    }
+
+    pub fn source_maps(&self) -> &[SchemaSourceMap] { &self.source_maps }
+
     // SchemaErrors is guaranteed non-empty (enforced by
     // debug_assert in new()), so is_empty() is not provided.
     #[allow(clippy::len_without_is_empty)]
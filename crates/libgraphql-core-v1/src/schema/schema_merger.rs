@@ -0,0 +1,655 @@
+use crate::directive_annotation::DirectiveAnnotation;
+use crate::error_note::ErrorNote;
+use crate::names::DirectiveName;
+use crate::names::FieldName;
+use crate::names::TypeName;
+use crate::scalars::ScalarRegistry;
+use crate::schema::schema_build_error::SchemaBuildError;
+use crate::schema::schema_build_error::SchemaBuildErrorKind;
+use crate::schema::schema_builder::SchemaBuilder;
+use crate::schema::schema_def::Schema;
+use crate::schema::schema_errors::SchemaErrors;
+use crate::schema::SchemaCoordinate;
+use crate::schema_source_map::SchemaSourceMap;
+use crate::span::SourceMapId;
+use crate::span::Span;
+use crate::span::BUILTIN_SOURCE_MAP_ID;
+use crate::types::DirectiveDefinition;
+use crate::types::FieldedTypeData;
+use crate::types::GraphQLType;
+use crate::types::ParameterDefinition;
+use crate::types::TypeAnnotation;
+use indexmap::IndexMap;
+
+/// Merges whole schemas (e.g. one per backing service) into a
+/// single schema, recording which source contributed each element.
+///
+/// Types and directives defined by only one source are copied
+/// as-is. Types defined by several sources are merged:
+///
+/// * Object, interface, and input object types get the union of
+///   their fields (and implemented interfaces). A field defined by
+///   several sources must have the same type and arguments in each,
+///   otherwise the conflict is reported with both spans.
+/// * Enum types get the union of their values, and union types the
+///   union of their members.
+/// * Identical scalar types and directive definitions are deduped.
+///   Directive definitions that differ are reported.
+/// * Descriptions come from the first source that has one, and
+///   applied directives are deduped.
+/// * Each source's [`ScalarRegistry`](crate::scalars::ScalarRegistry)
+///   is carried over. A scalar registered by several sources uses
+///   the first source's implementation.
+///
+/// Every source must bind its root operation types to the same
+/// names. The merged schema is revalidated, so spans in its errors
+/// (and in the schema itself) point into the original sources.
+///
+/// ```rust
+/// # use libgraphql_core_v1 as libgraphql_core;
+/// use libgraphql_core::schema::SchemaBuilder;
+/// use libgraphql_core::schema::SchemaCoordinate;
+/// use libgraphql_core::schema::SchemaMerger;
+///
+/// let users = SchemaBuilder::build_from_str(
+///     "type Query { me: User } type User { id: ID!, name: String }",
+/// ).unwrap();
+/// let reviews = SchemaBuilder::build_from_str(
+///     "type Query { reviews: [Review] } type Review { body: String }
+///      type User { id: ID!, reviews: [Review] }",
+/// ).unwrap();
+///
+/// let merged = SchemaMerger::new()
+///     .add_schema("users", users)
+///     .add_schema("reviews", reviews)
+///     .merge()
+///     .unwrap();
+/// let user = merged.schema().object_type("User").unwrap();
+/// assert_eq!(user.fields().len(), 3);
+///
+/// let id = SchemaCoordinate::parse("User.id").unwrap();
+/// assert_eq!(merged.sources_of(&id), ["users", "reviews"]);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct SchemaMerger {
+    sources: Vec<(String, Schema)>,
+}
+
+/// The result of [`SchemaMerger::merge()`]: the merged schema and
+/// the names of the sources that contributed each of its elements.
+#[derive(Clone, Debug)]
+pub struct MergedSchema {
+    provenance: IndexMap<SchemaCoordinate, Vec<String>>,
+    schema: Schema,
+}
+
+impl MergedSchema {
+    pub fn into_schema(self) -> Schema { self.schema }
+
+    /// For each type, field, input field, and enum value in the
+    /// merged schema, the names of the sources that define it, in
+    /// the order they were added. Built-in scalars are omitted.
+    pub fn provenance(&self) -> &IndexMap<SchemaCoordinate, Vec<String>> {
+        &self.provenance
+    }
+
+    pub fn schema(&self) -> &Schema { &self.schema }
+
+    /// The names of the sources that define the element at
+    /// `coordinate`, or an empty slice if none does.
+    pub fn sources_of(&self, coordinate: &SchemaCoordinate) -> &[String] {
+        self.provenance.get(coordinate).map_or(&[], Vec::as_slice)
+    }
+}
+
+impl SchemaMerger {
+    pub fn new() -> Self { Self::default() }
+
+    /// Adds a schema to merge. `source_name` identifies it in
+    /// [`MergedSchema::provenance()`].
+    pub fn add_schema(
+        &mut self,
+        source_name: impl Into<String>,
+        schema: Schema,
+    ) -> &mut Self {
+        self.sources.push((source_name.into(), schema));
+        self
+    }
+
+    /// Merges the added schemas, in the order they were added.
+    ///
+    /// Conflicts between sources and validation errors in the
+    /// merged schema are reported together.
+    // TODO: SchemaErrors wraps Vec<SchemaBuildError> which is
+    // large. Consider boxing once error strategy is finalized.
+    #[allow(clippy::result_large_err)]
+    pub fn merge(&self) -> Result<MergedSchema, SchemaErrors> {
+        self.merge_with(|_, _| ())
+    }
+
+    /// Like [`merge()`](Self::merge), but first passes the sources
+    /// to `prepare` (e.g. for
+    /// [`federation`](crate::federation) composition), after their
+    /// spans have been remapped into the merged source maps so that
+    /// any errors `prepare` reports resolve correctly.
+    #[allow(clippy::result_large_err)]
+    pub(crate) fn merge_with(
+        &self,
+        prepare: impl FnOnce(&mut [(String, Schema)], &mut Vec<SchemaBuildError>),
+    ) -> Result<MergedSchema, SchemaErrors> {
+        let mut state = MergeState {
+            directive_defs: IndexMap::new(),
+            errors: vec![],
+            mutation_type_name: None,
+            provenance: IndexMap::new(),
+            query_type_name: None,
+            scalar_registry: ScalarRegistry::new(),
+            source_maps: vec![SchemaSourceMap::builtin()],
+            subscription_type_name: None,
+            types: IndexMap::new(),
+        };
+        let mut sources = vec![];
+        for (source_name, schema) in &self.sources {
+            let mut schema = schema.clone();
+            if !state.absorb_source_maps(&mut schema) {
+                state.errors.push(SchemaBuildError::new(
+                    SchemaBuildErrorKind::SourceMapLimitExceeded,
+                    Span::builtin(),
+                    vec![],
+                ));
+                break;
+            }
+            sources.push((source_name.clone(), schema));
+        }
+        prepare(&mut sources, &mut state.errors);
+        for (source_name, schema) in sources {
+            state.merge_schema(&source_name, schema);
+        }
+
+        let provenance = state.provenance;
+        let schema = Schema {
+            directive_defs: state.directive_defs,
+            mutation_type_name: state.mutation_type_name,
+            // Only reachable with no sources, in which case the
+            // missing type is reported by validation.
+            query_type_name: state.query_type_name
+                .unwrap_or_else(|| TypeName::new("Query")),
+            scalar_registry: state.scalar_registry,
+            source_maps: state.source_maps,
+            subscription_type_name: state.subscription_type_name,
+            types: state.types,
+        };
+        let schema = SchemaBuilder::rebuild(schema, state.errors)?;
+        Ok(MergedSchema { provenance, schema })
+    }
+}
+
+struct MergeState {
+    directive_defs: IndexMap<DirectiveName, DirectiveDefinition>,
+    errors: Vec<SchemaBuildError>,
+    mutation_type_name: Option<TypeName>,
+    provenance: IndexMap<SchemaCoordinate, Vec<String>>,
+    query_type_name: Option<TypeName>,
+    scalar_registry: ScalarRegistry,
+    source_maps: Vec<SchemaSourceMap>,
+    subscription_type_name: Option<TypeName>,
+    types: IndexMap<TypeName, GraphQLType>,
+}
+
+impl MergeState {
+    /// Appends `schema`'s source maps to the merged ones and
+    /// rewrites its spans to match. Returns `false` if there are
+    /// too many source maps to address.
+    fn absorb_source_maps(&mut self, schema: &mut Schema) -> bool {
+        // Every schema's first source map is the shared built-in one.
+        let offset = self.source_maps.len() - 1;
+        if offset + schema.source_maps.len() > usize::from(u16::MAX) {
+            return false;
+        }
+        self.source_maps.extend(schema.source_maps.drain(1..));
+        remap_spans(schema, &|span: &mut Span| {
+            if span.source_map_id != BUILTIN_SOURCE_MAP_ID {
+                span.source_map_id =
+                    SourceMapId(span.source_map_id.0 + offset as u16);
+            }
+        });
+        true
+    }
+
+    fn merge_schema(&mut self, source_name: &str, schema: Schema) {
+        let Schema {
+            directive_defs,
+            mutation_type_name,
+            query_type_name,
+            scalar_registry,
+            subscription_type_name,
+            types,
+            ..
+        } = schema;
+        self.scalar_registry.absorb(scalar_registry);
+
+        for (operation, merged, incoming) in [
+            ("query", &mut self.query_type_name, Some(query_type_name)),
+            ("mutation", &mut self.mutation_type_name, mutation_type_name),
+            (
+                "subscription",
+                &mut self.subscription_type_name,
+                subscription_type_name,
+            ),
+        ] {
+            let Some(incoming) = incoming else { continue };
+            match merged {
+                None => *merged = Some(incoming),
+                Some(existing) if *existing == incoming => (),
+                Some(existing) => {
+                    let span = types.get(&incoming)
+                        .map_or_else(Span::builtin, GraphQLType::span);
+                    let existing_span = self.types.get(existing)
+                        .map_or_else(Span::builtin, GraphQLType::span);
+                    self.errors.push(SchemaBuildError::new(
+                        SchemaBuildErrorKind::MergeConflictingRootOperationType {
+                            first_type_name: existing.to_string(),
+                            operation: operation.to_string(),
+                            second_type_name: incoming.to_string(),
+                        },
+                        span,
+                        vec![ErrorNote::general_with_span(
+                            format!("`{existing}` is bound here"),
+                            existing_span,
+                        )],
+                    ));
+                },
+            }
+        }
+
+        for (name, def) in directive_defs {
+            let Some(existing) = self.directive_defs.get(&name) else {
+                self.directive_defs.insert(name, def);
+                continue;
+            };
+            if !existing.is_builtin() && !directives_match(existing, &def) {
+                self.errors.push(SchemaBuildError::new(
+                    SchemaBuildErrorKind::MergeConflictingDirectiveDefinition {
+                        name: name.to_string(),
+                    },
+                    def.span(),
+                    vec![ErrorNote::general_with_span(
+                        "first defined here",
+                        existing.span(),
+                    )],
+                ));
+            }
+        }
+
+        for (name, type_) in types {
+            if type_.is_builtin() {
+                self.types.entry(name).or_insert(type_);
+                continue;
+            }
+            self.record_type(source_name, &type_);
+            match self.types.get_mut(&name) {
+                None => {
+                    self.types.insert(name, type_);
+                },
+                Some(existing) => {
+                    merge_type(existing, type_, &mut self.errors);
+                },
+            }
+        }
+    }
+
+    /// Records `source_name` as a source of `type_` and of each of
+    /// its fields, input fields, or enum values.
+    fn record_type(&mut self, source_name: &str, type_: &GraphQLType) {
+        let mut coordinates = vec![type_.coordinate()];
+        match type_ {
+            GraphQLType::Enum(enum_type) => coordinates.extend(
+                enum_type.values.values().map(|value| value.coordinate()),
+            ),
+            GraphQLType::InputObject(input_obj) => coordinates.extend(
+                input_obj.fields.values().map(|field| field.coordinate()),
+            ),
+            GraphQLType::Interface(iface) => coordinates.extend(
+                iface.0.fields.values().map(|field| field.coordinate()),
+            ),
+            GraphQLType::Object(obj) => coordinates.extend(
+                obj.0.fields.values().map(|field| field.coordinate()),
+            ),
+            GraphQLType::Scalar(_) | GraphQLType::Union(_) => (),
+        }
+        for coordinate in coordinates {
+            self.provenance
+                .entry(coordinate)
+                .or_default()
+                .push(source_name.to_string());
+        }
+    }
+}
+
+/// Merges `incoming` into `existing`, a type of the same name
+/// defined by an earlier source.
+fn merge_type(
+    existing: &mut GraphQLType,
+    incoming: GraphQLType,
+    errors: &mut Vec<SchemaBuildError>,
+) {
+    match (existing, incoming) {
+        (GraphQLType::Enum(existing), GraphQLType::Enum(incoming)) => {
+            let incoming = *incoming;
+            merge_description(&mut existing.description, incoming.description);
+            merge_directives(&mut existing.directives, incoming.directives);
+            for (name, value) in incoming.values {
+                existing.values.entry(name).or_insert(value);
+            }
+        },
+
+        (
+            GraphQLType::InputObject(existing),
+            GraphQLType::InputObject(incoming),
+        ) => {
+            let incoming = *incoming;
+            merge_description(&mut existing.description, incoming.description);
+            merge_directives(&mut existing.directives, incoming.directives);
+            for (name, field) in incoming.fields {
+                let Some(existing_field) = existing.fields.get_mut(&name)
+                else {
+                    existing.fields.insert(name, field);
+                    continue;
+                };
+                let coordinate = field.coordinate().to_string();
+                if !check_same_type(
+                    &coordinate,
+                    (&existing_field.type_annotation, existing_field.span),
+                    (&field.type_annotation, field.span),
+                    errors,
+                ) {
+                    continue;
+                }
+                if existing_field.default_value != field.default_value {
+                    errors.push(SchemaBuildError::new(
+                        SchemaBuildErrorKind::MergeConflictingDefaultValue {
+                            coordinate,
+                        },
+                        field.span,
+                        vec![ErrorNote::general_with_span(
+                            "first defined here",
+                            existing_field.span,
+                        )],
+                    ));
+                    continue;
+                }
+                merge_description(
+                    &mut existing_field.description,
+                    field.description,
+                );
+                merge_directives(
+                    &mut existing_field.directives,
+                    field.directives,
+                );
+            }
+        },
+
+        (GraphQLType::Interface(existing), GraphQLType::Interface(incoming)) => {
+            merge_fielded(&mut existing.0, incoming.0, errors);
+        },
+
+        (GraphQLType::Object(existing), GraphQLType::Object(incoming)) => {
+            merge_fielded(&mut existing.0, incoming.0, errors);
+        },
+
+        (GraphQLType::Scalar(existing), GraphQLType::Scalar(incoming)) => {
+            let incoming = *incoming;
+            merge_description(&mut existing.description, incoming.description);
+            merge_directives(&mut existing.directives, incoming.directives);
+        },
+
+        (GraphQLType::Union(existing), GraphQLType::Union(incoming)) => {
+            let incoming = *incoming;
+            merge_description(&mut existing.description, incoming.description);
+            merge_directives(&mut existing.directives, incoming.directives);
+            for member in incoming.members {
+                let is_known = existing.members
+                    .iter()
+                    .any(|known| known.value == member.value);
+                if !is_known {
+                    existing.members.push(member);
+                }
+            }
+        },
+
+        (existing, incoming) => {
+            errors.push(SchemaBuildError::new(
+                SchemaBuildErrorKind::MergeConflictingTypeKind {
+                    first_kind: existing.type_kind(),
+                    second_kind: incoming.type_kind(),
+                    type_name: existing.name().to_string(),
+                },
+                incoming.span(),
+                vec![ErrorNote::general_with_span(
+                    "first defined here",
+                    existing.span(),
+                )],
+            ));
+        },
+    }
+}
+
+fn merge_fielded(
+    existing: &mut FieldedTypeData,
+    incoming: FieldedTypeData,
+    errors: &mut Vec<SchemaBuildError>,
+) {
+    merge_description(&mut existing.description, incoming.description);
+    merge_directives(&mut existing.directives, incoming.directives);
+    for iface in incoming.interfaces {
+        let is_known = existing.interfaces
+            .iter()
+            .any(|known| known.value == iface.value);
+        if !is_known {
+            existing.interfaces.push(iface);
+        }
+    }
+
+    for (name, field) in incoming.fields {
+        let Some(existing_field) = existing.fields.get_mut(&name) else {
+            existing.fields.insert(name, field);
+            continue;
+        };
+        let coordinate = field.coordinate().to_string();
+        if !check_same_type(
+            &coordinate,
+            (&existing_field.type_annotation, existing_field.span),
+            (&field.type_annotation, field.span),
+            errors,
+        ) {
+            continue;
+        }
+        if !parameters_match(&existing_field.parameters, &field.parameters) {
+            errors.push(SchemaBuildError::new(
+                SchemaBuildErrorKind::MergeConflictingFieldArguments {
+                    coordinate,
+                },
+                field.span,
+                vec![ErrorNote::general_with_span(
+                    "first defined here",
+                    existing_field.span,
+                )],
+            ));
+            continue;
+        }
+        merge_description(&mut existing_field.description, field.description);
+        merge_directives(&mut existing_field.directives, field.directives);
+    }
+}
+
+/// Reports a conflict if a field (or input field) defined by two
+/// sources has different types. Returns whether the types match.
+fn check_same_type(
+    coordinate: &str,
+    (existing_type, existing_span): (&TypeAnnotation, Span),
+    (incoming_type, incoming_span): (&TypeAnnotation, Span),
+    errors: &mut Vec<SchemaBuildError>,
+) -> bool {
+    if existing_type.is_equivalent_to(incoming_type) {
+        return true;
+    }
+    errors.push(SchemaBuildError::new(
+        SchemaBuildErrorKind::MergeConflictingFieldType {
+            coordinate: coordinate.to_string(),
+            first_type: existing_type.to_string(),
+            second_type: incoming_type.to_string(),
+        },
+        incoming_span,
+        vec![ErrorNote::general_with_span(
+            format!("first defined here with type `{existing_type}`"),
+            existing_span,
+        )],
+    ));
+    false
+}
+
+fn merge_description(existing: &mut Option<String>, incoming: Option<String>) {
+    if existing.is_none() {
+        *existing = incoming;
+    }
+}
+
+/// Appends the directives in `incoming` that are not already
+/// applied with the same arguments.
+fn merge_directives(
+    existing: &mut Vec<DirectiveAnnotation>,
+    incoming: Vec<DirectiveAnnotation>,
+) {
+    for annotation in incoming {
+        let is_known = existing.iter().any(|known| {
+            known.name == annotation.name
+                && known.arguments == annotation.arguments
+        });
+        if !is_known {
+            existing.push(annotation);
+        }
+    }
+}
+
+fn directives_match(a: &DirectiveDefinition, b: &DirectiveDefinition) -> bool {
+    a.is_repeatable == b.is_repeatable
+        && a.locations.len() == b.locations.len()
+        && a.locations.iter().all(|loc| b.locations.contains(loc))
+        && parameters_match(&a.parameters, &b.parameters)
+}
+
+/// Whether two argument lists declare the same arguments (in any
+/// order) with the same types and default values.
+fn parameters_match(
+    a: &IndexMap<FieldName, ParameterDefinition>,
+    b: &IndexMap<FieldName, ParameterDefinition>,
+) -> bool {
+    a.len() == b.len() && a.iter().all(|(name, param)| {
+        b.get(name).is_some_and(|other| {
+            param.type_annotation.is_equivalent_to(&other.type_annotation)
+                && param.default_value == other.default_value
+        })
+    })
+}
+
+// ---------------------------------------------------------
+// Span remapping
+// ---------------------------------------------------------
+
+/// Calls `remap` on every span in `schema`.
+fn remap_spans(schema: &mut Schema, remap: &impl Fn(&mut Span)) {
+    let annotations = |annotations: &mut Vec<DirectiveAnnotation>| {
+        remap_annotation_spans(annotations, remap);
+    };
+
+    for def in schema.directive_defs.values_mut() {
+        remap(&mut def.span);
+        remap_parameter_spans(&mut def.parameters, remap);
+    }
+    for type_ in schema.types.values_mut() {
+        match type_ {
+            GraphQLType::Enum(enum_type) => {
+                remap(&mut enum_type.span);
+                annotations(&mut enum_type.directives);
+                for value in enum_type.values.values_mut() {
+                    remap(&mut value.span);
+                    annotations(&mut value.directives);
+                }
+            },
+            GraphQLType::InputObject(input_obj) => {
+                remap(&mut input_obj.span);
+                annotations(&mut input_obj.directives);
+                for field in input_obj.fields.values_mut() {
+                    remap(&mut field.span);
+                    remap_type_annotation(&mut field.type_annotation, remap);
+                    annotations(&mut field.directives);
+                }
+            },
+            GraphQLType::Interface(iface) => {
+                remap_fielded_spans(&mut iface.0, remap);
+            },
+            GraphQLType::Object(obj) => remap_fielded_spans(&mut obj.0, remap),
+            GraphQLType::Scalar(scalar) => {
+                remap(&mut scalar.span);
+                annotations(&mut scalar.directives);
+            },
+            GraphQLType::Union(union_type) => {
+                remap(&mut union_type.span);
+                annotations(&mut union_type.directives);
+                for member in &mut union_type.members {
+                    remap(&mut member.span);
+                }
+            },
+        }
+    }
+}
+
+fn remap_fielded_spans(
+    type_data: &mut FieldedTypeData,
+    remap: &impl Fn(&mut Span),
+) {
+    remap(&mut type_data.span);
+    remap_annotation_spans(&mut type_data.directives, remap);
+    for iface in &mut type_data.interfaces {
+        remap(&mut iface.span);
+    }
+    for field in type_data.fields.values_mut() {
+        remap(&mut field.span);
+        remap_type_annotation(&mut field.type_annotation, remap);
+        remap_annotation_spans(&mut field.directives, remap);
+        remap_parameter_spans(&mut field.parameters, remap);
+    }
+}
+
+fn remap_parameter_spans(
+    params: &mut IndexMap<FieldName, ParameterDefinition>,
+    remap: &impl Fn(&mut Span),
+) {
+    for param in params.values_mut() {
+        remap(&mut param.span);
+        remap_type_annotation(&mut param.type_annotation, remap);
+        remap_annotation_spans(&mut param.directives, remap);
+    }
+}
+
+fn remap_annotation_spans(
+    annotations: &mut [DirectiveAnnotation],
+    remap: &impl Fn(&mut Span),
+) {
+    for annotation in annotations {
+        remap(&mut annotation.span);
+    }
+}
+
+fn remap_type_annotation(
+    annotation: &mut TypeAnnotation,
+    remap: &impl Fn(&mut Span),
+) {
+    match annotation {
+        TypeAnnotation::List(list) => {
+            remap(&mut list.span);
+            remap_type_annotation(&mut list.inner, remap);
+        },
+        TypeAnnotation::Named(named) => remap(&mut named.span),
+    }
+}
@@ -0,0 +1,494 @@
+use crate::directive_annotation::DirectiveAnnotation;
+use crate::error_note::ErrorNote;
+use crate::names::DirectiveName;
+use crate::names::FieldName;
+use crate::names::TypeName;
+use crate::schema::schema_build_error::SchemaBuildError;
+use crate::schema::schema_build_error::SchemaBuildErrorKind;
+use crate::schema::schema_builder::SchemaBuilder;
+use crate::schema::schema_def::Schema;
+use crate::schema::schema_element::SchemaElement;
+use crate::schema::schema_errors::SchemaErrors;
+use crate::schema::SchemaCoordinate;
+use crate::types::FieldedTypeData;
+use crate::types::GraphQLType;
+use crate::types::ParameterOwner;
+use crate::types::TypeAnnotation;
+use indexmap::IndexMap;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+/// See [`Schema::prune_unreachable_types()`].
+#[allow(clippy::result_large_err)]
+pub(crate) fn prune_unreachable_types(
+    schema: &Schema,
+) -> Result<Schema, SchemaErrors> {
+    let mut schema = schema.clone();
+    prune(&mut schema);
+    SchemaBuilder::rebuild(schema, vec![])
+}
+
+/// See [`Schema::remove_elements_with_directive()`].
+#[allow(clippy::result_large_err)]
+pub(crate) fn remove_elements_with_directive(
+    schema: &Schema,
+    directive_name: &str,
+) -> Result<Schema, SchemaErrors> {
+    let mut schema = schema.clone();
+    retain_elements(&mut schema, |element| match element {
+        SchemaElement::Directive(def) => def.name().as_str() != directive_name,
+        _ => !element.directives()
+            .iter()
+            .any(|annotation| annotation.name().as_str() == directive_name),
+    });
+    prune(&mut schema);
+    SchemaBuilder::rebuild(schema, vec![])
+}
+
+/// Like [`remove_elements_with_directive()`], but only prunes the
+/// types that the removal leaves unreachable. Types that were
+/// already unreachable (e.g. Federation entities only reachable
+/// through `_entities`) are kept.
+#[allow(clippy::result_large_err)]
+pub(crate) fn remove_elements_with_directive_and_orphans(
+    schema: &Schema,
+    directive_name: &str,
+) -> Result<Schema, SchemaErrors> {
+    let mut schema = schema.clone();
+    let reachable_before = reachable_types(&schema);
+    retain_elements(&mut schema, |element| match element {
+        SchemaElement::Directive(def) => def.name().as_str() != directive_name,
+        _ => !element.directives()
+            .iter()
+            .any(|annotation| annotation.name().as_str() == directive_name),
+    });
+    let reachable_after = reachable_types(&schema);
+    schema.types.retain(|name, type_| {
+        type_.is_builtin()
+            || reachable_after.contains(name)
+            || !reachable_before.contains(name)
+    });
+    SchemaBuilder::rebuild(schema, vec![])
+}
+
+/// See [`Schema::filter()`].
+#[allow(clippy::result_large_err)]
+pub(crate) fn filter(
+    schema: &Schema,
+    keep: impl Fn(&SchemaCoordinate) -> bool,
+) -> Result<Schema, SchemaErrors> {
+    let mut schema = schema.clone();
+    retain_elements(&mut schema, |element| keep(&element.coordinate()));
+    SchemaBuilder::rebuild(schema, vec![])
+}
+
+/// See [`Schema::prefix_type_names()`].
+#[allow(clippy::result_large_err)]
+pub(crate) fn prefix_type_names(
+    schema: &Schema,
+    prefix: &str,
+) -> Result<Schema, SchemaErrors> {
+    let mut schema = schema.clone();
+    let renames: HashMap<TypeName, TypeName> = schema.types
+        .values()
+        .filter(|type_| !type_.is_builtin())
+        .map(|type_| {
+            let name = type_.name();
+            (name.clone(), TypeName::new(format!("{prefix}{name}")))
+        })
+        .collect();
+    let rename = |name: &mut TypeName| {
+        if let Some(new_name) = renames.get(name) {
+            *name = new_name.clone();
+        }
+    };
+
+    // A prefixed name can only collide with a built-in scalar (e.g.
+    // `In` + `t`); every other name gains the same prefix.
+    let mut errors = vec![];
+    let mut types = IndexMap::with_capacity(schema.types.len());
+    for (_, mut type_) in schema.types.drain(..) {
+        match &mut type_ {
+            GraphQLType::Enum(enum_type) => {
+                rename(&mut enum_type.name);
+                for value in enum_type.values.values_mut() {
+                    rename(&mut value.parent_type_name);
+                }
+            },
+            GraphQLType::InputObject(input_obj) => {
+                rename(&mut input_obj.name);
+                for field in input_obj.fields.values_mut() {
+                    rename(&mut field.parent_type_name);
+                    rename_in_annotation(&mut field.type_annotation, &rename);
+                }
+            },
+            GraphQLType::Interface(iface) => {
+                rename_in_fielded(&mut iface.0, &rename);
+            },
+            GraphQLType::Object(obj) => rename_in_fielded(&mut obj.0, &rename),
+            GraphQLType::Scalar(scalar) => rename(&mut scalar.name),
+            GraphQLType::Union(union_type) => {
+                rename(&mut union_type.name);
+                for member in &mut union_type.members {
+                    rename(&mut member.value);
+                }
+            },
+        }
+        let name = type_.name().clone();
+        if let Some(existing) = types.get(&name).map(GraphQLType::span) {
+            errors.push(SchemaBuildError::new(
+                SchemaBuildErrorKind::DuplicateTypeDefinition {
+                    type_name: name.to_string(),
+                },
+                type_.span(),
+                vec![ErrorNote::general_with_span(
+                    "first defined here",
+                    existing,
+                )],
+            ));
+            continue;
+        }
+        types.insert(name, type_);
+    }
+    schema.types = types;
+
+    for directive_def in schema.directive_defs.values_mut() {
+        for param in directive_def.parameters.values_mut() {
+            rename_in_annotation(&mut param.type_annotation, &rename);
+        }
+    }
+    rename(&mut schema.query_type_name);
+    if let Some(name) = &mut schema.mutation_type_name {
+        rename(name);
+    }
+    if let Some(name) = &mut schema.subscription_type_name {
+        rename(name);
+    }
+
+    SchemaBuilder::rebuild(schema, errors)
+}
+
+fn rename_in_fielded(
+    type_data: &mut FieldedTypeData,
+    rename: &impl Fn(&mut TypeName),
+) {
+    rename(&mut type_data.name);
+    for iface in &mut type_data.interfaces {
+        rename(&mut iface.value);
+    }
+    for field in type_data.fields.values_mut() {
+        rename(&mut field.parent_type_name);
+        rename_in_annotation(&mut field.type_annotation, rename);
+        for param in field.parameters.values_mut() {
+            if let ParameterOwner::Field { type_name, .. } = &mut param.owner {
+                rename(type_name);
+            }
+            rename_in_annotation(&mut param.type_annotation, rename);
+        }
+    }
+}
+
+fn rename_in_annotation(
+    annotation: &mut TypeAnnotation,
+    rename: &impl Fn(&mut TypeName),
+) {
+    match annotation {
+        TypeAnnotation::List(list) => {
+            rename_in_annotation(&mut list.inner, rename);
+        },
+        TypeAnnotation::Named(named) => rename(&mut named.type_name),
+    }
+}
+
+// ---------------------------------------------------------
+// Element removal
+// ---------------------------------------------------------
+
+/// Removes every element for which `keep` returns `false`, then
+/// everything left dangling by the removal (see
+/// [`remove_dangling_references()`]).
+///
+/// Built-in scalars and directives are always kept.
+fn retain_elements(
+    schema: &mut Schema,
+    keep: impl Fn(SchemaElement<'_>) -> bool,
+) {
+    schema.directive_defs.retain(|_, def| {
+        def.is_builtin() || keep(SchemaElement::Directive(def))
+    });
+    for def in schema.directive_defs.values_mut() {
+        if !def.is_builtin() {
+            def.parameters.retain(|_, param| {
+                keep(SchemaElement::DirectiveArgument(param))
+            });
+        }
+    }
+
+    schema.types.retain(|_, type_| {
+        type_.is_builtin() || keep(SchemaElement::Type(type_))
+    });
+    let retain_fields = |type_data: &mut FieldedTypeData| {
+        type_data.fields.retain(|_, field| keep(SchemaElement::Field(field)));
+        for field in type_data.fields.values_mut() {
+            field.parameters.retain(|_, param| {
+                keep(SchemaElement::FieldArgument(param))
+            });
+        }
+    };
+    for type_ in schema.types.values_mut() {
+        match type_ {
+            GraphQLType::Enum(enum_type) => {
+                enum_type.values.retain(|_, value| {
+                    keep(SchemaElement::EnumValue(value))
+                });
+            },
+            GraphQLType::InputObject(input_obj) => {
+                input_obj.fields.retain(|_, field| {
+                    keep(SchemaElement::InputField(field))
+                });
+            },
+            GraphQLType::Interface(iface) => retain_fields(&mut iface.0),
+            GraphQLType::Object(obj) => retain_fields(&mut obj.0),
+            GraphQLType::Scalar(_) | GraphQLType::Union(_) => (),
+        }
+    }
+
+    remove_dangling_references(schema);
+}
+
+/// Cleans up after elements were removed from `schema`:
+///
+/// * Fields, arguments, and input fields whose type was removed are
+///   removed, as are references to removed interfaces and union
+///   members.
+/// * Types left with no fields, values, or members are removed in
+///   turn (except the query root type, which stays to be reported
+///   by validation). A removed mutation or subscription root type
+///   is unbound.
+/// * Applications of removed directives, and arguments for removed
+///   directive arguments, are dropped.
+fn remove_dangling_references(schema: &mut Schema) {
+    loop {
+        let defined: HashSet<TypeName> =
+            schema.types.keys().cloned().collect();
+        let is_defined = |annotation: &TypeAnnotation| {
+            defined.contains(annotation.innermost_type_name())
+        };
+
+        for def in schema.directive_defs.values_mut() {
+            def.parameters.retain(|_, param| {
+                is_defined(&param.type_annotation)
+            });
+        }
+        let retain_fields = |type_data: &mut FieldedTypeData| {
+            type_data.interfaces.retain(|iface| {
+                defined.contains(&iface.value)
+            });
+            type_data.fields.retain(|_, field| {
+                is_defined(&field.type_annotation)
+            });
+            for field in type_data.fields.values_mut() {
+                field.parameters.retain(|_, param| {
+                    is_defined(&param.type_annotation)
+                });
+            }
+        };
+        for type_ in schema.types.values_mut() {
+            match type_ {
+                GraphQLType::InputObject(input_obj) => {
+                    input_obj.fields.retain(|_, field| {
+                        is_defined(&field.type_annotation)
+                    });
+                },
+                GraphQLType::Interface(iface) => retain_fields(&mut iface.0),
+                GraphQLType::Object(obj) => retain_fields(&mut obj.0),
+                GraphQLType::Union(union_type) => {
+                    union_type.members.retain(|member| {
+                        defined.contains(&member.value)
+                    });
+                },
+                GraphQLType::Enum(_) | GraphQLType::Scalar(_) => (),
+            }
+        }
+
+        let query_type_name = &schema.query_type_name;
+        schema.types.retain(|name, type_| {
+            name == query_type_name || !match type_ {
+                GraphQLType::Enum(enum_type) => enum_type.values.is_empty(),
+                GraphQLType::InputObject(input_obj) => {
+                    input_obj.fields.is_empty()
+                },
+                GraphQLType::Interface(iface) => iface.0.fields.is_empty(),
+                GraphQLType::Object(obj) => obj.0.fields.is_empty(),
+                GraphQLType::Scalar(_) => false,
+                GraphQLType::Union(union_type) => {
+                    union_type.members.is_empty()
+                },
+            }
+        });
+        if schema.types.len() == defined.len() {
+            break;
+        }
+    }
+
+    for root in [
+        &mut schema.mutation_type_name,
+        &mut schema.subscription_type_name,
+    ] {
+        if root.as_ref().is_some_and(|name| !schema.types.contains_key(name)) {
+            *root = None;
+        }
+    }
+
+    let directive_params: HashMap<DirectiveName, HashSet<FieldName>> =
+        schema.directive_defs
+            .values()
+            .map(|def| {
+                (def.name.clone(), def.parameters.keys().cloned().collect())
+            })
+            .collect();
+    let retain_annotations = |annotations: &mut Vec<DirectiveAnnotation>| {
+        annotations.retain_mut(|annotation| {
+            let Some(params) = directive_params.get(&annotation.name) else {
+                return false;
+            };
+            annotation.arguments.retain(|arg_name, _| {
+                params.contains(arg_name)
+            });
+            true
+        });
+    };
+    for def in schema.directive_defs.values_mut() {
+        for param in def.parameters.values_mut() {
+            retain_annotations(&mut param.directives);
+        }
+    }
+    for_each_annotation_list(&mut schema.types, retain_annotations);
+}
+
+/// Calls `f` with the directives applied to every type, field,
+/// argument, enum value, and input field in `types`.
+fn for_each_annotation_list(
+    types: &mut IndexMap<TypeName, GraphQLType>,
+    f: impl Fn(&mut Vec<DirectiveAnnotation>),
+) {
+    let fielded = |type_data: &mut FieldedTypeData| {
+        f(&mut type_data.directives);
+        for field in type_data.fields.values_mut() {
+            f(&mut field.directives);
+            for param in field.parameters.values_mut() {
+                f(&mut param.directives);
+            }
+        }
+    };
+    for type_ in types.values_mut() {
+        match type_ {
+            GraphQLType::Enum(enum_type) => {
+                f(&mut enum_type.directives);
+                for value in enum_type.values.values_mut() {
+                    f(&mut value.directives);
+                }
+            },
+            GraphQLType::InputObject(input_obj) => {
+                f(&mut input_obj.directives);
+                for field in input_obj.fields.values_mut() {
+                    f(&mut field.directives);
+                }
+            },
+            GraphQLType::Interface(iface) => fielded(&mut iface.0),
+            GraphQLType::Object(obj) => fielded(&mut obj.0),
+            GraphQLType::Scalar(scalar) => f(&mut scalar.directives),
+            GraphQLType::Union(union_type) => f(&mut union_type.directives),
+        }
+    }
+}
+
+// ---------------------------------------------------------
+// Reachability
+// ---------------------------------------------------------
+
+/// Removes every type that cannot be reached from a root operation
+/// type or from a directive definition's arguments. Built-in scalars
+/// are always kept.
+fn prune(schema: &mut Schema) {
+    let reachable = reachable_types(schema);
+    schema.types.retain(|name, type_| {
+        type_.is_builtin() || reachable.contains(name)
+    });
+}
+
+/// The names of the types that can be reached from a root operation
+/// type or from a directive definition's arguments.
+///
+/// Reachability follows field, argument, and input field types,
+/// implemented interfaces, and union members. An object or
+/// interface type implementing a reachable interface is reachable
+/// too, since a field of the interface's type may resolve to it.
+fn reachable_types(schema: &Schema) -> HashSet<TypeName> {
+    let mut implementations: HashMap<&TypeName, Vec<&TypeName>> =
+        HashMap::new();
+    for type_ in schema.types.values() {
+        let type_data = match type_ {
+            GraphQLType::Interface(iface) => &iface.0,
+            GraphQLType::Object(obj) => &obj.0,
+            _ => continue,
+        };
+        for iface in &type_data.interfaces {
+            implementations.entry(&iface.value)
+                .or_default()
+                .push(&type_data.name);
+        }
+    }
+
+    let mut pending: Vec<&TypeName> = [
+        Some(&schema.query_type_name),
+        schema.mutation_type_name.as_ref(),
+        schema.subscription_type_name.as_ref(),
+    ].into_iter().flatten().collect();
+    for def in schema.directive_defs.values() {
+        pending.extend(def.parameters.values().map(|param| {
+            param.type_annotation.innermost_type_name()
+        }));
+    }
+
+    let mut reachable: HashSet<TypeName> = HashSet::new();
+    while let Some(type_name) = pending.pop() {
+        if !reachable.insert(type_name.clone()) {
+            continue;
+        }
+        let Some(type_) = schema.types.get(type_name) else {
+            continue;
+        };
+        let type_data = match type_ {
+            GraphQLType::InputObject(input_obj) => {
+                pending.extend(input_obj.fields.values().map(|field| {
+                    field.type_annotation.innermost_type_name()
+                }));
+                continue;
+            },
+            GraphQLType::Interface(iface) => {
+                pending.extend(
+                    implementations.get(type_name).into_iter().flatten(),
+                );
+                &iface.0
+            },
+            GraphQLType::Object(obj) => &obj.0,
+            GraphQLType::Union(union_type) => {
+                pending.extend(
+                    union_type.members.iter().map(|member| &member.value),
+                );
+                continue;
+            },
+            GraphQLType::Enum(_) | GraphQLType::Scalar(_) => continue,
+        };
+        pending.extend(type_data.interfaces.iter().map(|iface| &iface.value));
+        for field in type_data.fields.values() {
+            pending.push(field.type_annotation.innermost_type_name());
+            pending.extend(field.parameters.values().map(|param| {
+                param.type_annotation.innermost_type_name()
+            }));
+        }
+    }
+
+    reachable
+}
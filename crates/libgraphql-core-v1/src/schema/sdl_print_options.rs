@@ -0,0 +1,74 @@
+/// Options controlling how
+/// [`Schema::to_sdl_with_options()`](crate::schema::Schema::to_sdl_with_options)
+/// prints a schema.
+///
+/// The defaults produce SDL that can be loaded back into a
+/// [`SchemaBuilder`](crate::schema::SchemaBuilder) to rebuild an
+/// equivalent schema: built-ins are omitted (the builder seeds
+/// them, and rejects redefinitions of built-in directives),
+/// definitions keep their original order, and descriptions are
+/// printed as block strings.
+///
+/// # Example
+///
+/// ```rust
+/// # use libgraphql_core_v1 as libgraphql_core;
+/// use libgraphql_core::schema::SdlPrintOptions;
+///
+/// // A canonical, diff-friendly artifact
+/// let canonical = SdlPrintOptions {
+///     sort_alphabetically: true,
+///     ..SdlPrintOptions::default()
+/// };
+/// # assert!(canonical.descriptions_as_block_strings);
+///
+/// // Everything, including the spec-defined built-ins
+/// let full = SdlPrintOptions::with_builtins();
+/// assert!(full.include_builtin_directives);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct SdlPrintOptions {
+    /// Whether descriptions are printed as `"""block strings"""`.
+    /// When `false` (or when a description cannot be represented
+    /// exactly as a block string), descriptions are printed as
+    /// `"quoted strings"`.
+    pub descriptions_as_block_strings: bool,
+
+    /// Whether to print definitions for the built-in directives
+    /// (`@skip`, `@include`, `@deprecated`, `@specifiedBy`,
+    /// `@oneOf`).
+    pub include_builtin_directives: bool,
+
+    /// Whether to print definitions for the built-in scalars
+    /// (`Boolean`, `Float`, `ID`, `Int`, `String`).
+    pub include_builtin_scalars: bool,
+
+    /// Whether to print directive definitions and types sorted by
+    /// name. When `false`, definitions are printed in the order
+    /// they were loaded. Fields, arguments, enum values, and union
+    /// members always keep their definition order.
+    pub sort_alphabetically: bool,
+}
+
+impl SdlPrintOptions {
+    /// Returns options that also print every built-in scalar and
+    /// directive definition.
+    pub fn with_builtins() -> Self {
+        Self {
+            include_builtin_directives: true,
+            include_builtin_scalars: true,
+            ..Self::default()
+        }
+    }
+}
+
+impl Default for SdlPrintOptions {
+    fn default() -> Self {
+        Self {
+            descriptions_as_block_strings: true,
+            include_builtin_directives: false,
+            include_builtin_scalars: false,
+            sort_alphabetically: false,
+        }
+    }
+}
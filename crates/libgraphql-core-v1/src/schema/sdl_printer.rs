@@ -0,0 +1,328 @@
+use crate::directive_annotation::DirectiveAnnotation;
+use crate::located::Located;
+use crate::names::FieldName;
+use crate::names::TypeName;
+use crate::schema::schema_def::Schema;
+use crate::schema::sdl_print_options::SdlPrintOptions;
+use crate::types::DirectiveDefinition;
+use crate::types::EnumType;
+use crate::types::FieldDefinition;
+use crate::types::GraphQLType;
+use crate::types::InputObjectType;
+use crate::types::ParameterDefinition;
+use crate::types::ScalarType;
+use crate::types::UnionType;
+use indexmap::IndexMap;
+use libgraphql_parser::ast::StringValue;
+use std::fmt::Write;
+
+const INDENT: &str = "  ";
+
+/// Prints a validated [`Schema`] as GraphQL SDL.
+///
+/// Output follows the conventional layout used by most GraphQL
+/// tooling: a `schema { ... }` definition (only when needed), then
+/// directive definitions, then type definitions, separated by
+/// blank lines.
+pub(crate) struct SdlPrinter<'a> {
+    options: &'a SdlPrintOptions,
+    output: String,
+    schema: &'a Schema,
+}
+
+impl<'a> SdlPrinter<'a> {
+    pub fn new(schema: &'a Schema, options: &'a SdlPrintOptions) -> Self {
+        Self {
+            options,
+            output: String::new(),
+            schema,
+        }
+    }
+
+    pub fn print(mut self) -> String {
+        let mut definitions = vec![];
+
+        if let Some(schema_def) = self.schema_definition() {
+            definitions.push(schema_def);
+        }
+
+        let mut directive_defs: Vec<&DirectiveDefinition> = self.schema
+            .directive_defs()
+            .values()
+            .filter(|d| {
+                self.options.include_builtin_directives || !d.is_builtin()
+            })
+            .collect();
+        let mut types: Vec<&GraphQLType> = self.schema
+            .types()
+            .values()
+            .filter(|t| {
+                self.options.include_builtin_scalars || !t.is_builtin()
+            })
+            .collect();
+        if self.options.sort_alphabetically {
+            directive_defs.sort_by(|a, b| a.name().cmp(b.name()));
+            types.sort_by(|a, b| a.name().cmp(b.name()));
+        }
+
+        for directive_def in directive_defs {
+            self.print_directive_definition(directive_def);
+            definitions.push(std::mem::take(&mut self.output));
+        }
+        for type_ in types {
+            self.print_type(type_);
+            definitions.push(std::mem::take(&mut self.output));
+        }
+
+        definitions.join("\n")
+    }
+
+    /// Returns a `schema { ... }` definition if the root operation
+    /// bindings differ from the ones a reader would infer from the
+    /// default root type names (`Query`, `Mutation`,
+    /// `Subscription`) in the absence of a schema definition.
+    ///
+    /// See [Default Root Operation Type Names](https://spec.graphql.org/September2025/#sec-Root-Operation-Types.Default-Root-Operation-Type-Names).
+    fn schema_definition(&self) -> Option<String> {
+        // A query root can only be missing from a schema that
+        // `federation::service_sdl()` stripped of generated types.
+        let query_type_name = self.schema
+            .get_type(self.schema.query_type_name().as_str())
+            .map(|t| t.name());
+        let bindings = [
+            ("query", query_type_name, "Query"),
+            ("mutation", self.schema.mutation_type_name(), "Mutation"),
+            (
+                "subscription",
+                self.schema.subscription_type_name(),
+                "Subscription",
+            ),
+        ];
+        let is_default = bindings.iter().all(|(_, bound, default)| {
+            let default_binding = self.schema
+                .get_type(default)
+                .map(|t| t.name());
+            *bound == default_binding
+        });
+        if is_default {
+            return None;
+        }
+
+        let mut output = String::from("schema {\n");
+        for (operation, bound, _) in bindings {
+            if let Some(type_name) = bound {
+                let _ = writeln!(output, "{INDENT}{operation}: {type_name}");
+            }
+        }
+        output.push_str("}\n");
+        Some(output)
+    }
+
+    // ---------------------------------------------------------
+    // Definitions
+    // ---------------------------------------------------------
+
+    fn print_directive_definition(&mut self, def: &DirectiveDefinition) {
+        self.print_description(def.description(), "");
+        let _ = write!(self.output, "directive @{}", def.name());
+        self.print_parameters(def.parameters(), "");
+        if def.is_repeatable() {
+            self.output.push_str(" repeatable");
+        }
+        let locations: Vec<&str> = def.locations()
+            .iter()
+            .map(|loc| loc.as_str())
+            .collect();
+        let _ = writeln!(self.output, " on {}", locations.join(" | "));
+    }
+
+    fn print_type(&mut self, type_: &GraphQLType) {
+        self.print_description(type_.description(), "");
+        match type_ {
+            GraphQLType::Enum(enum_type) => self.print_enum(enum_type),
+            GraphQLType::InputObject(input_obj) => {
+                self.print_input_object(input_obj)
+            },
+            GraphQLType::Interface(iface) => self.print_fielded_type(
+                "interface",
+                iface.name(),
+                iface.interfaces(),
+                iface.directives(),
+                iface.fields(),
+            ),
+            GraphQLType::Object(obj) => self.print_fielded_type(
+                "type",
+                obj.name(),
+                obj.interfaces(),
+                obj.directives(),
+                obj.fields(),
+            ),
+            GraphQLType::Scalar(scalar) => self.print_scalar(scalar),
+            GraphQLType::Union(union_type) => self.print_union(union_type),
+        }
+    }
+
+    fn print_enum(&mut self, enum_type: &EnumType) {
+        let _ = write!(self.output, "enum {}", enum_type.name());
+        self.print_directives(enum_type.directives());
+        self.output.push_str(" {\n");
+        for value in enum_type.values().values() {
+            self.print_description(value.description(), INDENT);
+            let _ = write!(self.output, "{INDENT}{}", value.name());
+            self.print_directives(value.directives());
+            self.output.push('\n');
+        }
+        self.output.push_str("}\n");
+    }
+
+    fn print_fielded_type(
+        &mut self,
+        keyword: &str,
+        name: &TypeName,
+        interfaces: &[Located<TypeName>],
+        directives: &[DirectiveAnnotation],
+        fields: &IndexMap<FieldName, FieldDefinition>,
+    ) {
+        let _ = write!(self.output, "{keyword} {name}");
+        if !interfaces.is_empty() {
+            let names: Vec<&str> = interfaces
+                .iter()
+                .map(|iface| iface.value.as_str())
+                .collect();
+            let _ = write!(self.output, " implements {}", names.join(" & "));
+        }
+        self.print_directives(directives);
+        self.output.push_str(" {\n");
+        for field in fields.values() {
+            self.print_description(field.description(), INDENT);
+            let _ = write!(self.output, "{INDENT}{}", field.name());
+            self.print_parameters(field.parameters(), INDENT);
+            let _ = write!(self.output, ": {}", field.type_annotation());
+            self.print_directives(field.directives());
+            self.output.push('\n');
+        }
+        self.output.push_str("}\n");
+    }
+
+    fn print_input_object(&mut self, input_obj: &InputObjectType) {
+        let _ = write!(self.output, "input {}", input_obj.name());
+        self.print_directives(input_obj.directives());
+        self.output.push_str(" {\n");
+        for field in input_obj.fields().values() {
+            self.print_description(field.description(), INDENT);
+            let _ = write!(
+                self.output,
+                "{INDENT}{}: {}",
+                field.name(),
+                field.type_annotation(),
+            );
+            if let Some(default_value) = field.default_value() {
+                let _ = write!(self.output, " = {default_value}");
+            }
+            self.print_directives(field.directives());
+            self.output.push('\n');
+        }
+        self.output.push_str("}\n");
+    }
+
+    fn print_scalar(&mut self, scalar: &ScalarType) {
+        let _ = write!(self.output, "scalar {}", scalar.name());
+        self.print_directives(scalar.directives());
+        self.output.push('\n');
+    }
+
+    fn print_union(&mut self, union_type: &UnionType) {
+        let _ = write!(self.output, "union {}", union_type.name());
+        self.print_directives(union_type.directives());
+        let members: Vec<&str> = union_type.members()
+            .iter()
+            .map(|member| member.value.as_str())
+            .collect();
+        let _ = writeln!(self.output, " = {}", members.join(" | "));
+    }
+
+    // ---------------------------------------------------------
+    // Shared pieces
+    // ---------------------------------------------------------
+
+    /// Prints an argument definition list. Arguments are printed
+    /// inline unless any of them has a description, in which case
+    /// each goes on its own line (descriptions must precede the
+    /// definition they describe).
+    fn print_parameters(
+        &mut self,
+        params: &IndexMap<FieldName, ParameterDefinition>,
+        indent: &str,
+    ) {
+        if params.is_empty() {
+            return;
+        }
+
+        let multiline = params.values().any(|p| p.description().is_some());
+        let param_indent = format!("{indent}{INDENT}");
+        self.output.push('(');
+        for (i, param) in params.values().enumerate() {
+            if multiline {
+                self.output.push('\n');
+                self.print_description(param.description(), &param_indent);
+                self.output.push_str(&param_indent);
+            } else if i > 0 {
+                self.output.push_str(", ");
+            }
+            let _ = write!(
+                self.output,
+                "{}: {}",
+                param.name(),
+                param.type_annotation(),
+            );
+            if let Some(default_value) = param.default_value() {
+                let _ = write!(self.output, " = {default_value}");
+            }
+            self.print_directives(param.directives());
+        }
+        if multiline {
+            let _ = write!(self.output, "\n{indent}");
+        }
+        self.output.push(')');
+    }
+
+    /// Prints applied directives, each preceded by a space.
+    fn print_directives(&mut self, directives: &[DirectiveAnnotation]) {
+        for directive in directives {
+            let _ = write!(self.output, " @{}", directive.name());
+            let args = directive.arguments();
+            if args.is_empty() {
+                continue;
+            }
+            let args: Vec<String> = args
+                .iter()
+                .map(|(name, value)| format!("{name}: {value}"))
+                .collect();
+            let _ = write!(self.output, "({})", args.join(", "));
+        }
+    }
+
+    /// Prints a description on its own line(s) at `indent`.
+    fn print_description(&mut self, description: Option<&str>, indent: &str) {
+        let Some(description) = description else {
+            return;
+        };
+        let mut literal = String::new();
+        if self.options.descriptions_as_block_strings
+            && StringValue::is_printable_as_block_string(description)
+        {
+            StringValue::append_block_string(&mut literal, description);
+        } else {
+            StringValue::append_quoted_string(&mut literal, description);
+        }
+        // Block string values strip the indentation common to their
+        // lines, so indenting every line keeps the value unchanged.
+        for line in literal.split('\n') {
+            if !line.is_empty() {
+                self.output.push_str(indent);
+            }
+            self.output.push_str(line);
+            self.output.push('\n');
+        }
+    }
+}
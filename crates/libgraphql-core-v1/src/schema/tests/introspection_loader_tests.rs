@@ -0,0 +1,317 @@
+use crate::schema::SchemaBuildErrorKind;
+use crate::schema::SchemaBuilder;
+use crate::schema::SchemaErrors;
+
+const KITCHEN_SINK_JSON: &str =
+    include_str!("fixtures/introspection/kitchen_sink.json");
+
+/// Wraps `types` (a JSON array of `__Type` records) in a minimal
+/// `{"__schema": ...}` introspection result rooted at `Query`.
+fn introspection_json(types: &str) -> String {
+    format!(
+        r#"{{"__schema": {{
+            "queryType": {{"name": "Query"}},
+            "types": {types},
+            "directives": []
+        }}}}"#,
+    )
+}
+
+const QUERY_TYPE_JSON: &str = r#"{
+    "kind": "OBJECT",
+    "name": "Query",
+    "fields": [{
+        "name": "hello",
+        "args": [],
+        "type": {"kind": "SCALAR", "name": "String"}
+    }],
+    "interfaces": []
+}"#;
+
+/// Returns `(json_path, message)` for every error, panicking on
+/// any error that is not a `MalformedIntrospection`.
+fn malformed_paths(json: &str) -> Vec<(String, String)> {
+    let errors: SchemaErrors =
+        match SchemaBuilder::from_introspection_json(json) {
+            Err(errors) => errors,
+            Ok(_) => panic!("expected malformed introspection errors"),
+        };
+    errors.errors().iter().map(|err| match err.kind() {
+        SchemaBuildErrorKind::MalformedIntrospection {
+            json_path,
+            message,
+        } => (json_path.clone(), message.clone()),
+        other => panic!("unexpected error kind: {other:?}"),
+    }).collect()
+}
+
+// Verifies that a full introspection response converts every
+// type kind, field, argument, enum value, and custom directive,
+// preserving descriptions, default values, deprecation reasons,
+// `specifiedByURL`, and `isOneOf` — while skipping introspection
+// types and the built-in scalars and directives.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn kitchen_sink_round_trips_to_sdl() {
+    let schema = SchemaBuilder::from_introspection_json(KITCHEN_SINK_JSON)
+        .unwrap()
+        .build()
+        .unwrap();
+
+    assert_eq!(schema.to_sdl(), "\
+\"\"\"Restricts access.\"\"\"
+directive @auth(requires: Role! = ADMIN) repeatable on FIELD_DEFINITION | OBJECT
+
+\"\"\"The query root.\"\"\"
+type Query {
+  node(
+    \"\"\"The global ID.\"\"\"
+    id: ID!
+  ): Node
+  users(filter: UserFilter = {role: MEMBER, limit: 10}, first: Int = 20 @deprecated(reason: \"Use `filter.limit`.\")): [User!]!
+  search: SearchResult @deprecated
+}
+
+type Mutation {
+  ping: Boolean
+}
+
+interface Node {
+  id: ID!
+}
+
+type User implements Node {
+  id: ID!
+  homepage: Url
+  role: Role
+}
+
+union SearchResult = User | Query
+
+\"\"\"A user's role.\"\"\"
+enum Role {
+  ADMIN
+  \"\"\"A regular member.\"\"\"
+  MEMBER
+  GUEST @deprecated(reason: \"Guests were removed.\")
+}
+
+input UserFilter {
+  role: Role
+  limit: Int = 10
+}
+
+input UserKey @oneOf {
+  id: ID
+  email: String
+}
+
+scalar Url @specifiedBy(url: \"https://url.spec.whatwg.org\")
+");
+    assert!(schema.get_type("__Schema").is_none());
+}
+
+// Verifies that root operation types are bound from `queryType`,
+// `mutationType`, and `subscriptionType`, including when they use
+// non-default names.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn binds_root_operation_types() {
+    let schema = SchemaBuilder::from_introspection_json(KITCHEN_SINK_JSON)
+        .unwrap()
+        .build()
+        .unwrap();
+    assert_eq!(schema.query_type_name().as_str(), "Query");
+    assert_eq!(schema.mutation_type_name().unwrap().as_str(), "Mutation");
+    assert!(schema.subscription_type_name().is_none());
+
+    let json = introspection_json(&format!(
+        "[{}]",
+        QUERY_TYPE_JSON.replace("\"Query\"", "\"RootQuery\""),
+    )).replace(
+        r#""queryType": {"name": "Query"}"#,
+        r#""queryType": {"name": "RootQuery"}"#,
+    );
+    let schema = SchemaBuilder::from_introspection_json(&json)
+        .unwrap()
+        .build()
+        .unwrap();
+    assert_eq!(schema.query_type_name().as_str(), "RootQuery");
+}
+
+// Verifies that both a full GraphQL response (`{"data": ...}`) and
+// a bare `{"__schema": ...}` object are accepted, and that the
+// optional fields added in later spec editions (`isRepeatable`,
+// `specifiedByURL`, interface `interfaces`, ...) may be omitted.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn accepts_bare_schema_and_omitted_optional_fields() {
+    let bare = introspection_json(&format!("[{QUERY_TYPE_JSON}]"));
+    let wrapped = format!(r#"{{"data": {bare}}}"#);
+    for json in [&bare, &wrapped] {
+        let schema = SchemaBuilder::from_introspection_json(json)
+            .unwrap()
+            .build()
+            .unwrap();
+        assert!(schema.query_type().field("hello").is_some());
+    }
+}
+
+// Verifies that invalid JSON and results without a `__schema` are
+// reported at the root or `data` path.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn reports_invalid_json_and_missing_schema() {
+    let errors = malformed_paths("{\"data\": ");
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].0, "$");
+    assert!(errors[0].1.starts_with("invalid JSON: "), "{}", errors[0].1);
+
+    assert_eq!(
+        malformed_paths(r#"{"errors": [{"message": "denied"}]}"#),
+        vec![(
+            "$".to_string(),
+            "expected a `data` or `__schema` field".to_string(),
+        )],
+    );
+    assert_eq!(
+        malformed_paths(r#"{"data": {}}"#),
+        vec![(
+            "$.data.__schema".to_string(),
+            "missing required field".to_string(),
+        )],
+    );
+}
+
+// Verifies that malformed records deep inside the result are
+// reported with their precise JSON path, and that every malformed
+// record is reported rather than just the first.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn reports_precise_json_paths() {
+    let json = introspection_json(&format!(r#"[
+        {QUERY_TYPE_JSON},
+        {{
+            "kind": "OBJECT",
+            "name": "User",
+            "fields": [{{
+                "name": "tags",
+                "args": [],
+                "type": {{"kind": "LIST", "ofType": {{"kind": "ARRAY"}}}}
+            }}, {{
+                "name": "id",
+                "args": [{{"name": "format", "type": 7}}]
+            }}],
+            "interfaces": []
+        }},
+        {{"kind": "WIDGET", "name": "Gadget"}}
+    ]"#));
+
+    assert_eq!(malformed_paths(&json), vec![
+        (
+            "$.__schema.types[1].fields[0].type.ofType.kind".to_string(),
+            "unknown type kind `ARRAY`".to_string(),
+        ),
+        (
+            "$.__schema.types[1].fields[1].type".to_string(),
+            "missing required field".to_string(),
+        ),
+        (
+            "$.__schema.types[1].fields[1].args[0].type".to_string(),
+            "expected an object, found a number".to_string(),
+        ),
+        (
+            "$.__schema.types[2].kind".to_string(),
+            "unknown type kind `WIDGET`".to_string(),
+        ),
+    ]);
+}
+
+// Verifies that `defaultValue` strings are parsed as const GraphQL
+// literals, and that anything else (including a variable or text
+// that would escape the literal) is reported at its path.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn reports_invalid_default_values() {
+    for literal in ["$var", "{a: ", "1 } type Evil { x: Int"] {
+        let json = introspection_json(&format!(
+            r#"[{{
+                "kind": "INPUT_OBJECT",
+                "name": "Filter",
+                "inputFields": [{{
+                    "name": "limit",
+                    "type": {{"kind": "SCALAR", "name": "Int"}},
+                    "defaultValue": {}
+                }}]
+            }}]"#,
+            serde_json::to_string(literal).unwrap(),
+        ));
+        assert_eq!(malformed_paths(&json), vec![(
+            "$.__schema.types[0].inputFields[0].defaultValue".to_string(),
+            format!("`{literal}` is not a valid GraphQL input value literal"),
+        )]);
+    }
+}
+
+// Verifies that `NON_NULL` wrapping `NON_NULL` is rejected, since
+// it has no SDL equivalent.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn rejects_nested_non_null() {
+    let json = introspection_json(r#"[{
+        "kind": "OBJECT",
+        "name": "Query",
+        "fields": [{
+            "name": "id",
+            "args": [],
+            "type": {"kind": "NON_NULL", "ofType": {
+                "kind": "NON_NULL", "ofType": {"kind": "SCALAR", "name": "ID"}
+            }}
+        }],
+        "interfaces": []
+    }]"#);
+    assert_eq!(malformed_paths(&json), vec![(
+        "$.__schema.types[0].fields[0].type.ofType".to_string(),
+        "`NON_NULL` must not wrap another `NON_NULL`".to_string(),
+    )]);
+}
+
+// Verifies that errors raised by the type builders (e.g. duplicate
+// fields) keep their kind and gain a note naming the JSON path of
+// the offending record.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn builder_errors_note_json_path() {
+    let json = introspection_json(r#"[{
+        "kind": "OBJECT",
+        "name": "Query",
+        "fields": [
+            {"name": "a", "args": [], "type": {"kind": "SCALAR", "name": "Int"}},
+            {"name": "a", "args": [], "type": {"kind": "SCALAR", "name": "Int"}}
+        ],
+        "interfaces": []
+    }]"#);
+    let errors = match SchemaBuilder::from_introspection_json(&json) {
+        Err(errors) => errors,
+        Ok(_) => panic!("expected a duplicate field error"),
+    };
+    assert_eq!(errors.len(), 1);
+    let err = &errors.errors()[0];
+    assert!(matches!(
+        err.kind(),
+        SchemaBuildErrorKind::DuplicateFieldNameDefinition { .. },
+    ));
+    assert_eq!(
+        err.notes().last().unwrap().message,
+        "defined by the introspection record at \
+        `$.__schema.types[0].fields[1]`",
+    );
+}
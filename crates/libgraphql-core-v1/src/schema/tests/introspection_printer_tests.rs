@@ -0,0 +1,310 @@
+use crate::schema::IntrospectionOptions;
+use crate::schema::Schema;
+use crate::schema::SchemaBuilder;
+use serde_json::json;
+use serde_json::Value as JsonValue;
+
+fn build(source: &str) -> Schema {
+    SchemaBuilder::build_from_str(source)
+        .unwrap_or_else(|errs| panic!("schema failed to build:\n{errs}"))
+}
+
+/// Returns the `__Type` record named `name`.
+fn find_type<'a>(json: &'a JsonValue, name: &str) -> &'a JsonValue {
+    json["__schema"]["types"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|t| t["name"] == name)
+        .unwrap_or_else(|| panic!("no `{name}` type in introspection"))
+}
+
+/// Returns the `name` of every record in `list`.
+fn names(list: &JsonValue) -> Vec<&str> {
+    list.as_array()
+        .unwrap()
+        .iter()
+        .map(|record| record["name"].as_str().unwrap())
+        .collect()
+}
+
+const KITCHEN_SINK: &str = "\
+\"\"\"The root query.\"\"\"
+type Query implements Node @tag(name: \"root\") {
+  \"The ID.\"
+  id: ID!
+  search(
+    \"What to search for.\"
+    term: String! = \"*\"
+    limit: Int = 10 @deprecated
+  ): [SearchResult!]! @deprecated(reason: \"Use `find`.\")
+  find(filter: Filter = {tags: [\"a\", \"b\"]}): Post
+}
+
+interface Node {
+  id: ID!
+}
+
+type Post implements Node {
+  id: ID!
+  status: Status
+}
+
+union SearchResult = Post | Query
+
+enum Status {
+  \"\"\"Not yet live.\"\"\"
+  DRAFT
+  PUBLISHED @deprecated
+}
+
+input Filter @oneOf {
+  status: Status
+  tags: [String!] @deprecated(reason: \"Unused.\")
+}
+
+scalar Url @specifiedBy(url: \"https://url.spec.whatwg.org\")
+
+directive @tag(name: String!) repeatable on OBJECT | FIELD_DEFINITION | UNION
+";
+
+// Verifies that generated introspection loads back into a schema
+// whose own introspection is identical (a generate/load/generate
+// fixed point).
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn round_trips_through_introspection_loader() {
+    let json = build(KITCHEN_SINK).to_introspection_json();
+    let rebuilt = SchemaBuilder::from_introspection_json(&json.to_string())
+        .unwrap()
+        .build()
+        .unwrap();
+
+    assert_eq!(rebuilt.to_introspection_json(), json);
+}
+
+// Verifies the exact `__Field` and `__InputValue` records,
+// including wrapped type references, printed default values,
+// and deprecation on fields and arguments.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn field_and_argument_records() {
+    let json = build(KITCHEN_SINK).to_introspection_json();
+    let query = find_type(&json, "Query");
+
+    assert_eq!(query["fields"][1], json!({
+        "name": "search",
+        "description": null,
+        "args": [
+            {
+                "name": "term",
+                "description": "What to search for.",
+                "type": {
+                    "kind": "NON_NULL",
+                    "name": null,
+                    "ofType": {"kind": "SCALAR", "name": "String", "ofType": null},
+                },
+                "defaultValue": "\"*\"",
+                "isDeprecated": false,
+                "deprecationReason": null,
+            },
+            {
+                "name": "limit",
+                "description": null,
+                "type": {"kind": "SCALAR", "name": "Int", "ofType": null},
+                "defaultValue": "10",
+                "isDeprecated": true,
+                "deprecationReason": "No longer supported",
+            },
+        ],
+        "type": {
+            "kind": "NON_NULL",
+            "name": null,
+            "ofType": {
+                "kind": "LIST",
+                "name": null,
+                "ofType": {
+                    "kind": "NON_NULL",
+                    "name": null,
+                    "ofType": {
+                        "kind": "UNION",
+                        "name": "SearchResult",
+                        "ofType": null,
+                    },
+                },
+            },
+        },
+        "isDeprecated": true,
+        "deprecationReason": "Use `find`.",
+    }));
+    assert_eq!(
+        query["fields"][2]["args"][0]["defaultValue"],
+        "{tags: [\"a\", \"b\"]}",
+    );
+}
+
+// Verifies the per-kind `__Type` fields: fields that do not apply
+// to a kind are null, and `specifiedByURL`, `isOneOf`,
+// `possibleTypes`, and `enumValues` are populated.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn type_records_by_kind() {
+    let json = build(KITCHEN_SINK).to_introspection_json();
+
+    assert_eq!(find_type(&json, "Url"), &json!({
+        "kind": "SCALAR",
+        "name": "Url",
+        "description": null,
+        "specifiedByURL": "https://url.spec.whatwg.org",
+        "isOneOf": null,
+        "fields": null,
+        "inputFields": null,
+        "interfaces": null,
+        "enumValues": null,
+        "possibleTypes": null,
+    }));
+
+    let status = find_type(&json, "Status");
+    assert_eq!(status["enumValues"], json!([
+        {
+            "name": "DRAFT",
+            "description": "Not yet live.",
+            "isDeprecated": false,
+            "deprecationReason": null,
+        },
+        {
+            "name": "PUBLISHED",
+            "description": null,
+            "isDeprecated": true,
+            "deprecationReason": "No longer supported",
+        },
+    ]));
+
+    let filter = find_type(&json, "Filter");
+    assert_eq!(filter["isOneOf"], true);
+    assert_eq!(filter["fields"], JsonValue::Null);
+    assert_eq!(names(&filter["inputFields"]), ["status", "tags"]);
+    assert_eq!(
+        filter["inputFields"][1]["deprecationReason"],
+        "Unused.",
+    );
+
+    let node = find_type(&json, "Node");
+    assert_eq!(node["kind"], "INTERFACE");
+    assert_eq!(names(&node["interfaces"]), Vec::<&str>::new());
+    assert_eq!(names(&node["possibleTypes"]), ["Query", "Post"]);
+
+    let query = find_type(&json, "Query");
+    assert_eq!(query["description"], "The root query.");
+    assert_eq!(query["interfaces"], json!([
+        {"kind": "INTERFACE", "name": "Node", "ofType": null},
+    ]));
+    assert_eq!(query["possibleTypes"], JsonValue::Null);
+    assert_eq!(names(&find_type(&json, "SearchResult")["possibleTypes"]), [
+        "Post",
+        "Query",
+    ]);
+}
+
+// Verifies that `types` also lists the built-in scalars and the
+// introspection types, that `directives` lists the built-in
+// directives, and that the root operation types are bound.
+//
+// See https://spec.graphql.org/September2025/#sec-Schema-Introspection
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn includes_builtins_and_root_types() {
+    let schema = build(
+        "schema { query: Root, subscription: Events }\n\
+         type Root { a: Int }\n\
+         type Events { b: Int }",
+    );
+    let json = schema.to_introspection_json();
+    let schema_json = &json["__schema"];
+
+    assert_eq!(schema_json["queryType"], json!({"name": "Root"}));
+    assert_eq!(schema_json["mutationType"], JsonValue::Null);
+    assert_eq!(schema_json["subscriptionType"], json!({"name": "Events"}));
+
+    let type_names = names(&schema_json["types"]);
+    for name in [
+        "Boolean", "Float", "ID", "Int", "String", "Root", "Events",
+        "__Schema", "__Type", "__TypeKind", "__Field", "__InputValue",
+        "__EnumValue", "__Directive", "__DirectiveLocation",
+    ] {
+        assert!(type_names.contains(&name), "missing `{name}`");
+    }
+    assert_eq!(
+        names(&find_type(&json, "__Type")["fields"][3]["args"]),
+        ["includeDeprecated"],
+    );
+
+    assert_eq!(
+        names(&schema_json["directives"]),
+        ["skip", "include", "deprecated", "specifiedBy", "oneOf"],
+    );
+    assert_eq!(schema_json["directives"][2], json!({
+        "name": "deprecated",
+        "description": null,
+        "isRepeatable": false,
+        "locations": [
+            "ARGUMENT_DEFINITION",
+            "ENUM_VALUE",
+            "FIELD_DEFINITION",
+            "INPUT_FIELD_DEFINITION",
+        ],
+        "args": [{
+            "name": "reason",
+            "description": null,
+            "type": {
+                "kind": "NON_NULL",
+                "name": null,
+                "ofType": {"kind": "SCALAR", "name": "String", "ofType": null},
+            },
+            "defaultValue": "\"No longer supported\"",
+            "isDeprecated": false,
+            "deprecationReason": null,
+        }],
+    }));
+}
+
+// Verifies that `includeDeprecated: false` omits deprecated
+// fields, arguments, enum values, and input fields.
+//
+// See https://spec.graphql.org/September2025/#sec-Deprecation
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn excludes_deprecated_when_requested() {
+    let schema = build(KITCHEN_SINK);
+    let json = schema.to_introspection_json_with_options(
+        &IntrospectionOptions { include_deprecated: false },
+    );
+
+    let query = find_type(&json, "Query");
+    assert_eq!(names(&query["fields"]), ["id", "find"]);
+    let status = find_type(&json, "Status");
+    assert_eq!(names(&status["enumValues"]), ["DRAFT"]);
+    let filter = find_type(&json, "Filter");
+    assert_eq!(names(&filter["inputFields"]), ["status"]);
+
+    let schema = build(
+        "type Query { a(x: Int @deprecated, y: Int): Int }\n\
+         directive @d(x: Int @deprecated) on FIELD",
+    );
+    let json = schema.to_introspection_json_with_options(
+        &IntrospectionOptions { include_deprecated: false },
+    );
+    assert_eq!(names(&find_type(&json, "Query")["fields"][0]["args"]), ["y"]);
+    let directive_d = json["__schema"]["directives"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|d| d["name"] == "d")
+        .unwrap();
+    assert_eq!(names(&directive_d["args"]), Vec::<&str>::new());
+}
@@ -1,4 +1,13 @@
+mod introspection_loader_tests;
+mod introspection_printer_tests;
 mod schema_build_error_tests;
 mod schema_builder_tests;
+mod schema_coordinate_tests;
+mod schema_diff_tests;
 mod schema_errors_tests;
+mod schema_merger_tests;
+mod schema_file_loading_tests;
+mod schema_tests;
+mod schema_transform_tests;
+mod sdl_printer_tests;
 mod type_validation_error_tests;
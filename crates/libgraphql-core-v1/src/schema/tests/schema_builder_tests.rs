@@ -1,10 +1,17 @@
 use crate::error_note::ErrorNoteKind;
 use crate::names::TypeName;
+use crate::schema::SchemaBuildError;
 use crate::schema::SchemaBuildErrorKind;
 use crate::schema::SchemaBuilder;
+use crate::schema::TypeValidationErrorKind;
 use crate::span::Span;
+use crate::type_builders::DefinesGraphQLType;
+use crate::type_builders::FieldDefBuilder;
+use crate::type_builders::GraphQLTypeRef;
 use crate::type_builders::ObjectTypeBuilder;
+use crate::types::GraphQLTypeKind;
 use crate::types::ScalarKind;
+use crate::types::TypeAnnotation;
 
 // Verifies that SchemaBuilder::new() pre-seeds the five built-in
 // scalar types: Boolean, Float, ID, Int, String.
@@ -255,6 +262,51 @@ fn absorb_type_programmatic() {
     assert!(sb.types().contains_key("MyType"));
 }
 
+/// A hand-written equivalent of `#[derive(GraphQLObject)]` on
+/// `struct Query { tags: Vec<Option<String>>, nested: Box<Option<Query>> }`.
+struct RustQuery;
+
+impl GraphQLTypeRef for RustQuery {
+    fn type_annotation() -> TypeAnnotation {
+        TypeAnnotation::named("Query", false)
+    }
+}
+
+impl DefinesGraphQLType for RustQuery {
+    type Builder = ObjectTypeBuilder;
+
+    fn graphql_type_builder() -> Result<ObjectTypeBuilder, SchemaBuildError> {
+        let mut builder = ObjectTypeBuilder::new("Query", Span::dummy())?;
+        builder.add_field(FieldDefBuilder::new(
+            "tags",
+            <Vec<Option<String>>>::type_annotation(),
+            Span::dummy(),
+        ))?;
+        builder.add_field(FieldDefBuilder::new(
+            "nested",
+            <Box<Option<RustQuery>>>::type_annotation(),
+            Span::dummy(),
+        ))?;
+        Ok(builder)
+    }
+}
+
+// Verifies that absorb_rust_type() registers the builder of a
+// DefinesGraphQLType, with field types mapped from Rust types
+// through GraphQLTypeRef.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn absorb_rust_type_maps_field_types() {
+    let mut sb = SchemaBuilder::new();
+    sb.absorb_rust_type::<RustQuery>().unwrap();
+    let schema = sb.build().unwrap();
+
+    let query = schema.query_type();
+    assert_eq!(query.field("tags").unwrap().type_annotation().to_string(), "[String]!");
+    assert_eq!(query.field("nested").unwrap().type_annotation().to_string(), "Query");
+}
+
 // Verifies that attempting to redefine a built-in directive
 // (e.g. @skip) produces a RedefinitionOfBuiltinDirective
 // error.
@@ -445,3 +497,229 @@ fn load_str_parse_error_has_proper_span() {
         not Span::builtin()",
     );
 }
+
+// Verifies that build() on a valid schema string succeeds and
+// binds the conventional `Query` type as the query root when no
+// `schema { ... }` definition is present.
+//
+// See https://spec.graphql.org/September2025/#sec-Root-Operation-Types.Default-Root-Operation-Type-Names
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn build_defaults_root_types_by_name() {
+    let schema = SchemaBuilder::build_from_str(
+        "type Query { a: Int }\n\
+         type Mutation { b: Int }\n\
+         type Subscription { c: Int }",
+    ).unwrap();
+
+    assert_eq!(schema.query_type_name().as_str(), "Query");
+    assert_eq!(
+        schema.mutation_type_name().map(|n| n.as_str()),
+        Some("Mutation"),
+    );
+    assert_eq!(
+        schema.subscription_type_name().map(|n| n.as_str()),
+        Some("Subscription"),
+    );
+}
+
+// Verifies that an explicit `schema { ... }` definition disables
+// the default root type names: a type named `Mutation` is not
+// bound as the mutation root unless the schema definition says
+// so.
+//
+// See https://spec.graphql.org/September2025/#sec-Root-Operation-Types.Default-Root-Operation-Type-Names
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn build_schema_definition_overrides_default_root_names() {
+    let schema = SchemaBuilder::build_from_str(
+        "schema { query: RootQuery }\n\
+         type RootQuery { a: Int }\n\
+         type Mutation { b: Int }",
+    ).unwrap();
+
+    assert_eq!(schema.query_type_name().as_str(), "RootQuery");
+    assert!(schema.mutation_type_name().is_none());
+    assert!(schema.mutation_type().is_none());
+}
+
+// Verifies that build() reports NoQueryOperationTypeDefined when
+// there is neither a `schema { query: ... }` binding nor a type
+// named `Query`.
+//
+// See https://spec.graphql.org/September2025/#sec-Root-Operation-Types
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn build_without_query_type_rejected() {
+    let errors = SchemaBuilder::build_from_str(
+        "type Mutation { a: Int }",
+    ).unwrap_err();
+
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(
+        errors.errors()[0].kind(),
+        SchemaBuildErrorKind::NoQueryOperationTypeDefined,
+    ));
+}
+
+// Verifies that a `schema { ... }` binding to an undefined type
+// produces RootOperationTypeNotDefined with a suggestion for a
+// similarly-named type.
+//
+// See https://spec.graphql.org/September2025/#sec-Root-Operation-Types
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn build_root_type_not_defined_rejected() {
+    let errors = SchemaBuilder::build_from_str(
+        "schema { query: Query mutation: Mutaton }\n\
+         type Query { a: Int }\n\
+         type Mutation { b: Int }",
+    ).unwrap_err();
+
+    assert_eq!(errors.len(), 1);
+    let err = &errors.errors()[0];
+    assert_eq!(
+        err.kind(),
+        &SchemaBuildErrorKind::RootOperationTypeNotDefined {
+            operation: "mutation".to_string(),
+            type_name: "Mutaton".to_string(),
+        },
+    );
+    assert!(err.notes().iter().any(|n| {
+        n.kind == ErrorNoteKind::Help
+            && n.message.contains("`Mutation`")
+    }));
+}
+
+// Verifies that binding a root operation to a non-object type
+// produces RootOperationTypeNotObjectType.
+//
+// See https://spec.graphql.org/September2025/#sec-Root-Operation-Types
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn build_root_type_not_object_rejected() {
+    let errors = SchemaBuilder::build_from_str(
+        "schema { query: Q }\n\
+         interface Q { a: Int }",
+    ).unwrap_err();
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors.errors()[0].kind(),
+        &SchemaBuildErrorKind::RootOperationTypeNotObjectType {
+            actual_kind: GraphQLTypeKind::Interface,
+            operation: "query".to_string(),
+            type_name: "Q".to_string(),
+        },
+    );
+}
+
+// Verifies that build() rejects object types, interface types,
+// unions, and enums that define no fields/members/values.
+//
+// See https://spec.graphql.org/September2025/#sec-Objects.Type-Validation
+// See https://spec.graphql.org/September2025/#sec-Unions.Type-Validation
+// See https://spec.graphql.org/September2025/#sec-Enums.Type-Validation
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn build_empty_types_rejected() {
+    let errors = SchemaBuilder::build_from_str(
+        "type Query { a: Int }\n\
+         type EmptyObj\n\
+         interface EmptyIface\n\
+         union EmptyUnion\n\
+         enum EmptyEnum",
+    ).unwrap_err();
+
+    let kinds: Vec<_> = errors.errors()
+        .iter()
+        .map(|e| e.kind().clone())
+        .collect();
+    assert_eq!(kinds, vec![
+        SchemaBuildErrorKind::EmptyObjectOrInterfaceType {
+            type_kind: GraphQLTypeKind::Object,
+            type_name: "EmptyObj".to_string(),
+        },
+        SchemaBuildErrorKind::EmptyObjectOrInterfaceType {
+            type_kind: GraphQLTypeKind::Interface,
+            type_name: "EmptyIface".to_string(),
+        },
+        SchemaBuildErrorKind::EmptyUnionType {
+            type_name: "EmptyUnion".to_string(),
+        },
+        SchemaBuildErrorKind::EnumWithNoValues {
+            type_name: "EmptyEnum".to_string(),
+        },
+    ]);
+}
+
+// Verifies that build() runs the per-type validators and
+// surfaces their errors as TypeValidation errors carrying the
+// validator's span and notes.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn build_runs_type_validators() {
+    let errors = SchemaBuilder::build_from_str(
+        "type Query { node: Node, search: SearchResult }\n\
+         interface Node { id: ID! }\n\
+         type User implements Node { name: String }\n\
+         union SearchResult = User | Node\n\
+         input Filter { self: Filter! }\n\
+         directive @tag(by: Query) on FIELD_DEFINITION",
+    ).unwrap_err();
+
+    let validation_kinds: Vec<&TypeValidationErrorKind> = errors
+        .errors()
+        .iter()
+        .map(|e| match e.kind() {
+            SchemaBuildErrorKind::TypeValidation(tv) => {
+                assert_eq!(e.span(), tv.span());
+                assert_eq!(e.notes(), tv.notes());
+                tv.kind()
+            },
+            other => panic!("unexpected error kind: {other:?}"),
+        })
+        .collect();
+
+    assert!(validation_kinds.iter().any(|k| matches!(
+        k,
+        TypeValidationErrorKind::MissingInterfaceSpecifiedField { .. },
+    )));
+    assert!(validation_kinds.iter().any(|k| matches!(
+        k,
+        TypeValidationErrorKind::InvalidUnionMemberTypeKind { .. },
+    )));
+    assert!(validation_kinds.iter().any(|k| matches!(
+        k,
+        TypeValidationErrorKind::CircularInputFieldChain { .. },
+    )));
+    assert!(validation_kinds.iter().any(|k| matches!(
+        k,
+        TypeValidationErrorKind::InvalidDirectiveParameterType { .. },
+    )));
+}
+
+// Verifies that errors collected while loading (here, a duplicate
+// type definition) are reported by build() even though
+// load_str() itself succeeded.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn build_reports_load_errors() {
+    let errors = SchemaBuilder::build_from_str(
+        "type Query { a: Int }\n\
+         type Query { b: Int }",
+    ).unwrap_err();
+
+    assert!(errors.errors().iter().any(|e| matches!(
+        e.kind(),
+        SchemaBuildErrorKind::DuplicateTypeDefinition { .. },
+    )));
+}
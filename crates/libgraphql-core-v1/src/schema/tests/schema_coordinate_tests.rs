@@ -0,0 +1,279 @@
+use crate::names::DirectiveName;
+use crate::names::FieldName;
+use crate::names::TypeName;
+use crate::schema::Schema;
+use crate::schema::SchemaBuilder;
+use crate::schema::SchemaCoordinate;
+use crate::schema::SchemaCoordinateParseErrorKind;
+use crate::schema::SchemaElement;
+use crate::types::ParameterOwner;
+
+fn build(source: &str) -> Schema {
+    SchemaBuilder::build_from_str(source)
+        .unwrap_or_else(|errs| panic!("schema failed to build:\n{errs}"))
+}
+
+fn parse(source: &str) -> SchemaCoordinate {
+    SchemaCoordinate::parse(source)
+        .unwrap_or_else(|err| panic!("failed to parse `{source}`: {err}"))
+}
+
+fn parse_error(source: &str) -> (u32, SchemaCoordinateParseErrorKind) {
+    let err = SchemaCoordinate::parse(source)
+        .expect_err(&format!("`{source}` should not parse"));
+    (err.byte_offset(), err.kind().clone())
+}
+
+const SCHEMA: &str = "\
+type Query { user(id: ID!, true: Boolean): User, node: Node }
+interface Node { id(format: String): ID! }
+type User implements Node { id(format: String): ID!, name: String }
+union Result = User
+enum Color { RED GREEN }
+input Filter { name: String = \"x\" }
+scalar Url
+directive @tag(name: String!) on FIELD_DEFINITION
+";
+
+// Verifies that each form of schema coordinate parses into the
+// matching variant and prints back to the same string.
+//
+// See https://spec.graphql.org/draft/#sec-Schema-Coordinates
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn parses_and_prints_each_form() {
+    assert_eq!(parse("User"), SchemaCoordinate::Type {
+        type_name: TypeName::new("User"),
+    });
+    assert_eq!(parse("User.name"), SchemaCoordinate::Member {
+        member_name: "name".to_string(),
+        type_name: TypeName::new("User"),
+    });
+    assert_eq!(parse("Query.user(id:)"), SchemaCoordinate::Argument {
+        argument_name: FieldName::new("id"),
+        field_name: FieldName::new("user"),
+        type_name: TypeName::new("Query"),
+    });
+    assert_eq!(parse("@deprecated"), SchemaCoordinate::Directive {
+        directive_name: DirectiveName::new("deprecated"),
+    });
+    assert_eq!(
+        parse("@deprecated(reason:)"),
+        SchemaCoordinate::DirectiveArgument {
+            argument_name: FieldName::new("reason"),
+            directive_name: DirectiveName::new("deprecated"),
+        },
+    );
+
+    for source in [
+        "User",
+        "User.name",
+        "Query.user(id:)",
+        "@deprecated",
+        "@deprecated(reason:)",
+        "Query.user(true:)",
+        "Color.null",
+        "__Type.fields(includeDeprecated:)",
+    ] {
+        assert_eq!(parse(source).to_string(), source);
+        assert_eq!(source.parse::<SchemaCoordinate>().unwrap(), parse(source));
+    }
+}
+
+// Verifies that malformed coordinates are rejected with the offset
+// of the offending token, including coordinates containing
+// whitespace, commas, or comments (which the coordinate grammar
+// does not allow).
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn rejects_malformed_coordinates() {
+    use SchemaCoordinateParseErrorKind as Kind;
+
+    for (source, offset) in [
+        (" User", 0),
+        ("User ", 4),
+        ("User .name", 4),
+        ("User. name", 5),
+        ("User.name (id:)", 9),
+        ("Query.user(id: )", 14),
+        ("@ tag", 1),
+        ("User,", 4),
+        ("User#comment", 4),
+    ] {
+        assert_eq!(
+            parse_error(source),
+            (offset, Kind::IgnoredTokens),
+            "parsing `{source}`",
+        );
+    }
+
+    assert_eq!(parse_error(""), (0, Kind::UnexpectedEnd {
+        expected: "a name",
+    }));
+    assert_eq!(parse_error("User."), (5, Kind::UnexpectedEnd {
+        expected: "a name",
+    }));
+    assert_eq!(parse_error("Query.user(id)"), (13, Kind::UnexpectedToken {
+        expected: "`:`",
+        found: ")".to_string(),
+    }));
+    assert_eq!(parse_error("User..name"), (4, Kind::UnexpectedToken {
+        expected: "end of input",
+        found: "..".to_string(),
+    }));
+    assert_eq!(parse_error("User(id:)"), (4, Kind::UnexpectedToken {
+        expected: "end of input",
+        found: "(".to_string(),
+    }));
+    assert_eq!(parse_error("@tag.name"), (4, Kind::UnexpectedToken {
+        expected: "end of input",
+        found: ".".to_string(),
+    }));
+    assert_eq!(parse_error("User.name.id"), (9, Kind::UnexpectedToken {
+        expected: "end of input",
+        found: ".".to_string(),
+    }));
+    assert_eq!(parse_error("$user"), (0, Kind::UnexpectedToken {
+        expected: "a name",
+        found: "$".to_string(),
+    }));
+
+    assert_eq!(
+        SchemaCoordinate::parse("Query.user(id)").unwrap_err().to_string(),
+        "invalid schema coordinate `Query.user(id)`: expected `:`, found `)`",
+    );
+}
+
+// Verifies that each form of coordinate resolves to the matching
+// element, and that coordinates naming undefined elements (or
+// members of types that have none) resolve to `None`.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn resolves_coordinates() {
+    let schema = build(SCHEMA);
+    let resolve = |source: &str| schema.resolve_coordinate(&parse(source));
+
+    assert!(matches!(
+        resolve("Url"),
+        Some(SchemaElement::Type(type_)) if type_.name().as_str() == "Url",
+    ));
+    assert!(matches!(
+        resolve("User.name"),
+        Some(SchemaElement::Field(field))
+            if field.parent_type_name().as_str() == "User",
+    ));
+    assert!(matches!(
+        resolve("Node.id(format:)"),
+        Some(SchemaElement::FieldArgument(param))
+            if param.owner() == &ParameterOwner::Field {
+                field_name: FieldName::new("id"),
+                type_name: TypeName::new("Node"),
+            },
+    ));
+    assert!(matches!(
+        resolve("Color.GREEN"),
+        Some(SchemaElement::EnumValue(value)) if value.name().as_str() == "GREEN",
+    ));
+    assert!(matches!(
+        resolve("Filter.name"),
+        Some(SchemaElement::InputField(field))
+            if field.default_value().is_some(),
+    ));
+    assert!(matches!(
+        resolve("@tag"),
+        Some(SchemaElement::Directive(def)) if !def.is_builtin(),
+    ));
+    assert!(matches!(
+        resolve("@deprecated(reason:)"),
+        Some(SchemaElement::DirectiveArgument(param))
+            if param.default_value().is_some(),
+    ));
+    assert!(matches!(
+        resolve("Query.user(true:)"),
+        Some(SchemaElement::FieldArgument(_)),
+    ));
+
+    for source in [
+        "Missing",
+        "User.missing",
+        "Result.User",
+        "Url.value",
+        "Filter.name(x:)",
+        "Color.RED(x:)",
+        "Query.user(missing:)",
+        "@missing",
+        "@tag(missing:)",
+    ] {
+        assert_eq!(resolve(source), None, "resolving `{source}`");
+    }
+}
+
+// Verifies that every element of a schema exposes a coordinate
+// which resolves back to that same element.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn element_coordinates_resolve_to_themselves() {
+    let schema = build(SCHEMA);
+    let mut elements = vec![];
+    for def in schema.directive_defs().values() {
+        elements.push(SchemaElement::Directive(def));
+        elements.extend(
+            def.parameters().values().map(SchemaElement::DirectiveArgument),
+        );
+    }
+    for type_ in schema.types().values() {
+        elements.push(SchemaElement::Type(type_));
+        if let Some(obj) = type_.as_object() {
+            assert_eq!(obj.coordinate(), type_.coordinate());
+        }
+        let fields = type_.as_object()
+            .map(|obj| obj.fields())
+            .or_else(|| type_.as_interface().map(|iface| iface.fields()));
+        for field in fields.into_iter().flat_map(|fields| fields.values()) {
+            elements.push(SchemaElement::Field(field));
+            elements.extend(
+                field.parameters().values().map(SchemaElement::FieldArgument),
+            );
+        }
+        if let Some(enum_type) = type_.as_enum() {
+            elements.extend(
+                enum_type.values().values().map(SchemaElement::EnumValue),
+            );
+        }
+        if let Some(input_obj) = type_.as_input_object() {
+            elements.extend(
+                input_obj.fields().values().map(SchemaElement::InputField),
+            );
+        }
+    }
+
+    let coordinates: Vec<String> = elements
+        .iter()
+        .map(|element| element.coordinate().to_string())
+        .collect();
+    for expected in [
+        "@skip(if:)",
+        "@tag(name:)",
+        "Query.user(id:)",
+        "User.id(format:)",
+        "Color.RED",
+        "Filter.name",
+        "Boolean",
+    ] {
+        assert!(coordinates.iter().any(|c| c == expected), "missing `{expected}`");
+    }
+
+    for element in elements {
+        let coordinate = element.coordinate();
+        assert_eq!(
+            schema.resolve_coordinate(&coordinate),
+            Some(element),
+            "resolving `{coordinate}`",
+        );
+        assert_eq!(parse(&coordinate.to_string()), coordinate);
+    }
+}
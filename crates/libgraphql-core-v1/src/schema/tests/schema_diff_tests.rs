@@ -0,0 +1,266 @@
+use crate::schema::diff;
+use crate::schema::ChangeSeverity;
+use crate::schema::Schema;
+use crate::schema::SchemaBuilder;
+use crate::schema::SchemaChangeKind;
+use crate::types::GraphQLTypeKind;
+
+fn build(source: &str) -> Schema {
+    SchemaBuilder::build_from_str(source)
+        .unwrap_or_else(|errs| panic!("schema failed to build:\n{errs}"))
+}
+
+/// Diffs two schemas and renders each change as
+/// `"<severity>: <message>"`.
+fn diff_summary(old: &str, new: &str) -> Vec<String> {
+    diff(&build(old), &build(new))
+        .iter()
+        .map(|change| change.to_string())
+        .collect()
+}
+
+// Verifies that identical schemas (including ones that differ
+// only in definition order) produce no changes.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn identical_schemas_have_no_changes() {
+    let schema = "type Query { a: Int, b(x: Int = 1): [String!] }\n\
+                  enum E { A B }\n\
+                  directive @d on FIELD";
+    assert!(diff_summary(schema, schema).is_empty());
+
+    let reordered = "directive @d on FIELD\n\
+                     enum E { A B }\n\
+                     type Query { a: Int, b(x: Int = 1): [String!] }";
+    assert!(diff_summary(schema, reordered).is_empty());
+}
+
+// Verifies the typical breaking changes: a removed field, an
+// incompatible field type, a new required argument, a removed
+// enum value, a removed union member, and a dropped interface.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn reports_breaking_changes() {
+    let old = "\
+type Query { user: User, search: Result, status: Status }
+interface Node { id: ID! }
+type User implements Node { id: ID!, name: String, age: Int }
+type Post { id: ID! }
+union Result = User | Post
+enum Status { ACTIVE INACTIVE }
+";
+    let new = "\
+type Query { user(id: ID!): User, search: Result, status: Status }
+interface Node { id: ID! }
+type User { id: ID!, name: Int }
+type Post { id: ID! }
+union Result = User
+enum Status { ACTIVE }
+";
+
+    assert_eq!(diff_summary(old, new), [
+        "breaking: argument `id` was added to `Query.user` as a required \
+         argument",
+        "breaking: `User` no longer implements `Node`",
+        "breaking: type of field `User.name` changed from `String` to `Int`",
+        "breaking: field `User.age` was removed",
+        "breaking: member `Post` was removed from union `Result`",
+        "breaking: value `INACTIVE` was removed from enum `Status`",
+    ]);
+}
+
+// Verifies that field types may change covariantly (to a
+// non-null or more specific type) while argument and input field
+// types may only change contravariantly (to a nullable type).
+//
+// See https://spec.graphql.org/September2025/#IsSubType()
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn classifies_type_changes_by_variance() {
+    let old = "\
+type Query {
+  a: String
+  b: String!
+  node: Node
+  f(x: Int!, y: Int): Int
+}
+interface Node { id: ID! }
+type User implements Node { id: ID! }
+input In { p: Int!, q: Int }
+";
+    let new = "\
+type Query {
+  a: String!
+  b: String
+  node: User
+  f(x: Int, y: Int!): Int
+}
+interface Node { id: ID! }
+type User implements Node { id: ID! }
+input In { p: Int, q: Int! }
+";
+
+    assert_eq!(diff_summary(old, new), [
+        "safe: type of field `Query.a` changed from `String` to `String!`",
+        "breaking: type of field `Query.b` changed from `String!` to `String`",
+        "safe: type of field `Query.node` changed from `Node` to `User`",
+        "safe: type of argument `x` on `Query.f` changed from `Int!` to `Int`",
+        "breaking: type of argument `y` on `Query.f` changed from `Int` to \
+         `Int!`",
+        "safe: type of input field `In.p` changed from `Int!` to `Int`",
+        "breaking: type of input field `In.q` changed from `Int` to `Int!`",
+    ]);
+}
+
+// Verifies that additions which can change what existing clients
+// observe are classified as dangerous, and other additions as
+// safe.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn classifies_additions() {
+    let old = "\
+type Query { a(x: Int = 1): Int, u: U }
+type A { a: Int }
+type B { b: Int }
+union U = A
+enum E { X }
+interface I { a: Int }
+input In { p: Int }
+";
+    let new = "\
+type Query { a(x: Int = 2, y: Int, z: Int! = 0): Int, u: U, b: Int }
+type A implements I { a: Int }
+type B { b: Int }
+union U = A | B
+enum E { X Y }
+interface I { a: Int }
+input In { p: Int, q: Int, r: Int! }
+type C { c: Int }
+";
+
+    assert_eq!(diff_summary(old, new), [
+        "dangerous: default value of argument `x` on `Query.a` changed from \
+         `1` to `2`",
+        "dangerous: argument `y` was added to `Query.a`",
+        "dangerous: argument `z` was added to `Query.a`",
+        "safe: field `Query.b` was added",
+        "dangerous: `A` now implements `I`",
+        "dangerous: member `B` was added to union `U`",
+        "dangerous: value `Y` was added to enum `E`",
+        "dangerous: input field `In.q` was added",
+        "breaking: input field `In.r` was added as a required field",
+        "safe: type `C` was added",
+    ]);
+}
+
+// Verifies removed and kind-changed types, and root operation
+// type changes (adding a root is safe, removing one is not).
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn reports_type_and_root_changes() {
+    let old = "\
+type Query { a: Int }
+type Mutation { m: Int }
+type Gone { a: Int }
+enum Shape { A }
+";
+    let new = "\
+type Query { a: Int }
+type Subscription { s: Int }
+scalar Shape
+";
+
+    assert_eq!(diff_summary(old, new), [
+        "breaking: root mutation type changed from `Mutation` to none",
+        "safe: root subscription type changed from none to `Subscription`",
+        "breaking: type `Mutation` was removed",
+        "breaking: type `Gone` was removed",
+        "breaking: `Shape` changed from enum type to scalar type",
+        "safe: type `Subscription` was added",
+    ]);
+
+    let changes = diff(&build(old), &build(new));
+    assert_eq!(changes[4].kind(), &SchemaChangeKind::TypeKindChanged {
+        new_kind: GraphQLTypeKind::Scalar,
+        old_kind: GraphQLTypeKind::Enum,
+        type_name: "Shape".to_string(),
+    });
+}
+
+// Verifies directive definition changes: removal, locations,
+// repeatability, and arguments.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn reports_directive_changes() {
+    let old = "\
+type Query { a: Int }
+directive @a(x: Int, y: Int = 1) repeatable on FIELD | QUERY
+directive @gone on FIELD
+";
+    let new = "\
+type Query { a: Int }
+directive @a(y: Int = 2, z: Int, w: Int!) on FIELD | MUTATION
+directive @new on FIELD
+";
+
+    assert_eq!(diff_summary(old, new), [
+        "breaking: directive `@a` is no longer repeatable",
+        "breaking: location `QUERY` was removed from directive `@a`",
+        "safe: location `MUTATION` was added to directive `@a`",
+        "breaking: argument `x` was removed from directive `@a`",
+        "dangerous: default value of argument `y` on directive `@a` changed \
+         from `1` to `2`",
+        "safe: argument `z` was added to directive `@a`",
+        "breaking: argument `w` was added to directive `@a` as a required \
+         argument",
+        "breaking: directive `@gone` was removed",
+        "safe: directive `@new` was added",
+    ]);
+}
+
+// Verifies that each change carries spans into both schemas:
+// the changed element itself, or its closest enclosing element
+// on the side where it doesn't exist.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn changes_carry_spans_in_both_schemas() {
+    let old = build("type Query {\n  a: Int\n  b: String\n}\n");
+    let new = build("\n\ntype Query {\n  b: Int\n  c: Int\n}\n");
+
+    let changes = diff(&old, &new);
+    let lines = |change: &crate::schema::SchemaChange| {
+        (
+            change.old_span()
+                .map(|s| old.resolve_span(s).unwrap().start.line),
+            change.new_span()
+                .map(|s| new.resolve_span(s).unwrap().start.line),
+        )
+    };
+    let summary: Vec<(ChangeSeverity, String)> = changes
+        .iter()
+        .map(|c| (c.severity(), c.kind().to_string()))
+        .collect();
+    assert_eq!(summary, [
+        (ChangeSeverity::Breaking, "field `Query.a` was removed".to_string()),
+        (
+            ChangeSeverity::Breaking,
+            "type of field `Query.b` changed from `String` to `Int`"
+                .to_string(),
+        ),
+        (ChangeSeverity::Safe, "field `Query.c` was added".to_string()),
+    ]);
+
+    // Lines are 0-based.
+    assert_eq!(lines(&changes[0]), (Some(1), Some(2)));
+    assert_eq!(lines(&changes[1]), (Some(2), Some(3)));
+    assert_eq!(lines(&changes[2]), (Some(0), Some(4)));
+    assert!(changes[0].is_breaking());
+    assert!(!changes[2].is_breaking());
+}
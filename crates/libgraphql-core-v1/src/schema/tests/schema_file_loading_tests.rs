@@ -0,0 +1,187 @@
+use crate::schema::SchemaBuildErrorKind;
+use crate::schema::SchemaBuilder;
+use std::path::Path;
+use std::path::PathBuf;
+
+fn fixture_path(relative: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("src/schema/tests/fixtures")
+        .join(relative)
+}
+
+// Verifies that load_file() loads a single file and records the
+// file's path in its source map.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn load_file_records_file_path() {
+    let path = fixture_path("multi_file/types/user.graphql");
+    let mut sb = SchemaBuilder::new();
+    sb.load_file(&path).unwrap();
+
+    // Index 0 is the built-in source map.
+    assert_eq!(sb.source_maps().len(), 2);
+    assert_eq!(sb.source_maps()[1].file_path(), Some(path.as_path()));
+}
+
+// Verifies that load_files() registers one source map per file,
+// in the order given, and that spans of types from each file
+// resolve to that file's path and line.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn load_files_resolves_spans_per_file() {
+    let query_path = fixture_path("multi_file/query.graphql");
+    let user_path = fixture_path("multi_file/types/user.graphql");
+    let post_path = fixture_path("multi_file/types/post.graphqls");
+
+    let mut sb = SchemaBuilder::new();
+    sb.load_files([&query_path, &user_path, &post_path]).unwrap();
+    let schema = sb.build().unwrap();
+
+    for (type_name, path) in [
+        ("Query", &query_path),
+        ("User", &user_path),
+        ("Post", &post_path),
+    ] {
+        let span = schema.get_type(type_name).unwrap().span();
+        let resolved = schema.resolve_span(span).unwrap();
+        assert_eq!(resolved.file_path, Some(path.as_path()));
+        assert_eq!(resolved.start.line, 0);
+        assert_eq!(resolved.start.col_utf8, 0);
+    }
+
+    let name_field = schema.object_type("User").unwrap()
+        .field("name").unwrap();
+    let resolved = schema.resolve_span(name_field.span()).unwrap();
+    assert_eq!(resolved.start.line, 2);
+    assert_eq!(resolved.start.col_utf8, 2);
+}
+
+// Verifies that load_dir() recursively loads only schema files
+// (skipping other extensions) in sorted path order.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn load_dir_loads_schema_files_in_sorted_order() {
+    let mut sb = SchemaBuilder::new();
+    sb.load_dir(fixture_path("multi_file")).unwrap();
+
+    let file_paths: Vec<&Path> = sb.source_maps()
+        .iter()
+        .filter_map(|sm| sm.file_path())
+        .collect();
+    assert_eq!(file_paths, vec![
+        fixture_path("multi_file/query.graphql").as_path(),
+        fixture_path("multi_file/types/post.graphqls").as_path(),
+        fixture_path("multi_file/types/user.graphql").as_path(),
+    ]);
+
+    let schema = sb.build().unwrap();
+    assert!(schema.object_type("Post").is_some());
+}
+
+// Verifies that a cross-file duplicate type definition is
+// reported with a primary span in the second file and a "first
+// defined here" note resolving to the first file, including
+// after build() has consumed the builder.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn cross_file_error_spans_resolve_to_each_file() {
+    let a_path = fixture_path("invalid_multi_file/a.graphql");
+    let b_path = fixture_path("invalid_multi_file/b.graphql");
+
+    let mut sb = SchemaBuilder::new();
+    sb.load_dir(fixture_path("invalid_multi_file")).unwrap();
+    let errors = sb.build().unwrap_err();
+
+    let dup = errors.errors().iter()
+        .find(|e| matches!(
+            e.kind(),
+            SchemaBuildErrorKind::DuplicateTypeDefinition { .. },
+        ))
+        .expect("expected a DuplicateTypeDefinition error");
+
+    let primary = errors.resolve_span(dup.span()).unwrap();
+    assert_eq!(primary.file_path, Some(b_path.as_path()));
+    assert_eq!(primary.start.line, 2);
+
+    let first_def_span = dup.notes()[0].span.unwrap();
+    let first_def = errors.resolve_span(first_def_span).unwrap();
+    assert_eq!(first_def.file_path, Some(a_path.as_path()));
+    assert_eq!(first_def.start.line, 4);
+}
+
+// Verifies that an unreadable file produces a
+// SchemaFileReadError naming the path, without preventing the
+// other files from loading.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn load_files_reports_unreadable_file() {
+    let missing = fixture_path("multi_file/does_not_exist.graphql");
+    let mut sb = SchemaBuilder::new();
+    let errors = match sb.load_files([
+        fixture_path("multi_file/query.graphql"),
+        missing.clone(),
+        fixture_path("multi_file/types/user.graphql"),
+    ]) {
+        Err(errs) => errs,
+        Ok(_) => panic!("expected a file read error"),
+    };
+
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(
+        errors[0].kind(),
+        SchemaBuildErrorKind::SchemaFileReadError { file_path, .. }
+            if file_path == &missing,
+    ));
+    // 1 built-in + 2 readable files
+    assert_eq!(sb.source_maps().len(), 3);
+}
+
+// Verifies that load_dir() on a nonexistent directory reports a
+// SchemaFileReadError.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn load_dir_missing_directory() {
+    let mut sb = SchemaBuilder::new();
+    let errors = match sb.load_dir(fixture_path("no_such_dir")) {
+        Err(errs) => errs,
+        Ok(_) => panic!("expected a directory read error"),
+    };
+
+    assert!(matches!(
+        errors[0].kind(),
+        SchemaBuildErrorKind::SchemaFileReadError { .. },
+    ));
+}
+
+// Verifies that loading many files in parallel yields the same
+// source map order as the input order.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn load_files_preserves_input_order() {
+    let paths: Vec<PathBuf> = std::iter::repeat_n(
+        [
+            fixture_path("multi_file/types/user.graphql"),
+            fixture_path("multi_file/query.graphql"),
+        ],
+        16,
+    ).flatten().collect();
+
+    let mut sb = SchemaBuilder::new();
+    // Duplicate definitions are collected into the builder's
+    // errors; only the source map order matters here.
+    sb.load_files(&paths).unwrap();
+
+    let loaded: Vec<&Path> = sb.source_maps()
+        .iter()
+        .filter_map(|sm| sm.file_path())
+        .collect();
+    let expected: Vec<&Path> = paths.iter().map(|p| p.as_path()).collect();
+    assert_eq!(loaded, expected);
+}
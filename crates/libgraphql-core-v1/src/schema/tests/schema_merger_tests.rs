@@ -0,0 +1,182 @@
+use crate::schema::Schema;
+use crate::schema::SchemaBuildErrorKind;
+use crate::schema::SchemaBuilder;
+use crate::schema::SchemaCoordinate;
+use crate::schema::SchemaMerger;
+use crate::types::GraphQLTypeKind;
+
+fn build(source: &str) -> Schema {
+    SchemaBuilder::build_from_str(source)
+        .unwrap_or_else(|errs| panic!("schema failed to build:\n{errs}"))
+}
+
+fn coordinate(source: &str) -> SchemaCoordinate {
+    SchemaCoordinate::parse(source).unwrap()
+}
+
+// Verifies that identical types are deduped, object types with
+// disjoint fields are unioned, enum values and union members are
+// merged, and provenance records every source of each element.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn merges_types_and_records_provenance() {
+    let users = build("\
+type Query { me: User }
+\"The current user.\" type User { id: ID!, name: String }
+enum Role { MEMBER }
+union Actor = User
+scalar DateTime
+directive @cost(weight: Int) on FIELD_DEFINITION
+");
+    let reviews = build("\
+type Query { reviews: [Review] @cost(weight: 2) }
+type User { id: ID!, reviews: [Review] }
+type Review { body: String, by: Actor, at: DateTime }
+enum Role { MEMBER MODERATOR }
+union Actor = User | Bot
+type Bot { name: String }
+scalar DateTime
+directive @cost(weight: Int) on FIELD_DEFINITION
+");
+
+    let merged = SchemaMerger::new()
+        .add_schema("users", users)
+        .add_schema("reviews", reviews)
+        .merge()
+        .unwrap();
+    assert_eq!(merged.schema().to_sdl(), "\
+directive @cost(weight: Int) on FIELD_DEFINITION
+
+type Query {
+  me: User
+  reviews: [Review] @cost(weight: 2)
+}
+
+\"\"\"The current user.\"\"\"
+type User {
+  id: ID!
+  name: String
+  reviews: [Review]
+}
+
+enum Role {
+  MEMBER
+  MODERATOR
+}
+
+union Actor = User | Bot
+
+scalar DateTime
+
+type Review {
+  body: String
+  by: Actor
+  at: DateTime
+}
+
+type Bot {
+  name: String
+}
+");
+
+    assert_eq!(merged.sources_of(&coordinate("User")), ["users", "reviews"]);
+    assert_eq!(merged.sources_of(&coordinate("User.id")), ["users", "reviews"]);
+    assert_eq!(merged.sources_of(&coordinate("User.name")), ["users"]);
+    assert_eq!(merged.sources_of(&coordinate("User.reviews")), ["reviews"]);
+    assert_eq!(merged.sources_of(&coordinate("Role.MODERATOR")), ["reviews"]);
+    assert!(merged.sources_of(&coordinate("String")).is_empty());
+    assert_eq!(merged.provenance().len(), 18);
+}
+
+// Verifies that spans in the merged schema still resolve into each
+// source's own text.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn merged_spans_resolve_to_their_sources() {
+    let a = build("type Query { a: Int }");
+    let b = build("\n\ntype Query { b: Int }");
+    let merged = SchemaMerger::new()
+        .add_schema("a", a)
+        .add_schema("b", b)
+        .merge()
+        .unwrap();
+    let schema = merged.schema();
+    let query = schema.query_type();
+
+    let a_span = schema.resolve_span(query.field("a").unwrap().span()).unwrap();
+    let b_span = schema.resolve_span(query.field("b").unwrap().span()).unwrap();
+    assert_eq!(a_span.start.line, 0);
+    assert_eq!(b_span.start.line, 2);
+    assert_eq!(schema.source_maps().len(), 3);
+}
+
+// Verifies that conflicting field types, field arguments, input
+// field defaults, type kinds, directive definitions, and root
+// operation types are all reported, each pointing at the later
+// definition with a note on the first.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn reports_conflicts() {
+    let a = build("\
+schema { query: Query }
+type Query { user(id: ID!): User }
+type User { id: ID!, age: Int }
+input Page { size: Int = 10 }
+enum Status { ON }
+directive @tag(name: String!) on FIELD_DEFINITION
+type Extra { page(p: Page): Int }
+");
+    let b = build("\
+schema { query: Root }
+type Root { a: Int }
+type Query { user(id: String!): User }
+type User { id: ID!, age: String }
+input Page { size: Int = 20 }
+type Status { on: Boolean }
+directive @tag(name: String) on FIELD_DEFINITION
+");
+
+    let errors = SchemaMerger::new()
+        .add_schema("a", a)
+        .add_schema("b", b)
+        .merge()
+        .unwrap_err();
+    let kinds: Vec<_> = errors.errors()
+        .iter()
+        .map(|error| error.kind().clone())
+        .collect();
+    assert_eq!(kinds, [
+        SchemaBuildErrorKind::MergeConflictingRootOperationType {
+            first_type_name: "Query".to_string(),
+            operation: "query".to_string(),
+            second_type_name: "Root".to_string(),
+        },
+        SchemaBuildErrorKind::MergeConflictingDirectiveDefinition {
+            name: "tag".to_string(),
+        },
+        SchemaBuildErrorKind::MergeConflictingFieldArguments {
+            coordinate: "Query.user".to_string(),
+        },
+        SchemaBuildErrorKind::MergeConflictingFieldType {
+            coordinate: "User.age".to_string(),
+            first_type: "Int".to_string(),
+            second_type: "String".to_string(),
+        },
+        SchemaBuildErrorKind::MergeConflictingDefaultValue {
+            coordinate: "Page.size".to_string(),
+        },
+        SchemaBuildErrorKind::MergeConflictingTypeKind {
+            first_kind: GraphQLTypeKind::Enum,
+            second_kind: GraphQLTypeKind::Object,
+            type_name: "Status".to_string(),
+        },
+    ]);
+
+    let age_conflict = &errors.errors()[3];
+    let detailed = age_conflict.format_detailed(errors.source_maps());
+    assert!(detailed.contains("age: String"), "{detailed}");
+    assert!(detailed.contains("age: Int"), "{detailed}");
+}
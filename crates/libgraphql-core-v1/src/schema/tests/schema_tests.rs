@@ -0,0 +1,173 @@
+use crate::schema::Schema;
+use crate::schema::SchemaBuilder;
+use crate::types::GraphQLTypeKind;
+
+fn build(source: &str) -> Schema {
+    SchemaBuilder::build_from_str(source)
+        .unwrap_or_else(|errs| panic!("schema failed to build:\n{errs}"))
+}
+
+const SOCIAL_SCHEMA: &str = "
+    schema { query: Root mutation: Mutate }
+    type Root { me: User, search(term: String!): [SearchResult!]! }
+    type Mutate { like(id: ID!): Post }
+    interface Node { id: ID! }
+    interface Entity implements Node { id: ID!, createdAt: String }
+    type User implements Entity & Node {
+        id: ID!
+        createdAt: String
+        name: String
+    }
+    type Post implements Entity & Node {
+        id: ID!
+        createdAt: String
+        author: User
+        status: Status
+    }
+    type Orphan { x: Int }
+    union SearchResult = Post | User
+    enum Status { DRAFT PUBLISHED }
+    input PostFilter { status: Status }
+    scalar DateTime
+    directive @tag(name: String!) on FIELD_DEFINITION
+";
+
+// Verifies that typed lookups return the type only when the name
+// refers to a type of the requested kind.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn typed_lookups() {
+    let schema = build(SOCIAL_SCHEMA);
+
+    assert_eq!(
+        schema.object_type("User").map(|t| t.name().as_str()),
+        Some("User"),
+    );
+    assert!(schema.object_type("Node").is_none());
+    assert!(schema.interface_type("Node").is_some());
+    assert!(schema.union_type("SearchResult").is_some());
+    assert!(schema.enum_type("Status").is_some());
+    assert!(schema.input_object_type("PostFilter").is_some());
+    assert!(schema.scalar_type("DateTime").is_some());
+    assert!(schema.scalar_type("String").is_some());
+    assert!(schema.object_type("Nope").is_none());
+    assert_eq!(
+        schema.get_type("Status").map(|t| t.type_kind()),
+        Some(GraphQLTypeKind::Enum),
+    );
+}
+
+// Verifies that typed iterators yield exactly the types of their
+// kind, in definition order.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn typed_iterators() {
+    let schema = build(SOCIAL_SCHEMA);
+
+    let objects: Vec<&str> = schema.object_types()
+        .map(|t| t.name().as_str())
+        .collect();
+    assert_eq!(objects, vec!["Root", "Mutate", "User", "Post", "Orphan"]);
+
+    let interfaces: Vec<&str> = schema.interface_types()
+        .map(|t| t.name().as_str())
+        .collect();
+    assert_eq!(interfaces, vec!["Node", "Entity"]);
+
+    assert_eq!(schema.union_types().count(), 1);
+    assert_eq!(schema.enum_types().count(), 1);
+    assert_eq!(schema.input_object_types().count(), 1);
+    // 5 built-in scalars + DateTime
+    assert_eq!(schema.scalar_types().count(), 6);
+}
+
+// Verifies that root operation accessors resolve through an
+// explicit `schema { ... }` definition.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn root_operation_types() {
+    let schema = build(SOCIAL_SCHEMA);
+
+    assert_eq!(schema.query_type().name().as_str(), "Root");
+    assert_eq!(
+        schema.mutation_type().map(|t| t.name().as_str()),
+        Some("Mutate"),
+    );
+    assert!(schema.subscription_type().is_none());
+    assert!(schema.subscription_type_name().is_none());
+}
+
+// Verifies that directive() finds both built-in and custom
+// directive definitions.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn directive_lookup() {
+    let schema = build(SOCIAL_SCHEMA);
+
+    assert!(schema.directive("skip").is_some_and(|d| d.is_builtin()));
+    assert!(schema.directive("tag").is_some_and(|d| !d.is_builtin()));
+    assert!(schema.directive("nope").is_none());
+    // 5 built-ins + @tag
+    assert_eq!(schema.directive_defs().len(), 6);
+}
+
+// Verifies that types_implementing() returns both object and
+// interface implementors of an interface.
+//
+// See https://spec.graphql.org/September2025/#sec-Interfaces
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn types_implementing_includes_objects_and_interfaces() {
+    let schema = build(SOCIAL_SCHEMA);
+
+    let node_impls: Vec<&str> = schema.types_implementing("Node")
+        .map(|t| t.name().as_str())
+        .collect();
+    assert_eq!(node_impls, vec!["Entity", "User", "Post"]);
+
+    assert_eq!(schema.types_implementing("User").count(), 0);
+    assert_eq!(schema.types_implementing("Nope").count(), 0);
+}
+
+// Verifies possible_types() for unions, interfaces, objects, and
+// non-composite types.
+//
+// See https://spec.graphql.org/September2025/#GetPossibleTypes()
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn possible_types_by_kind() {
+    let schema = build(SOCIAL_SCHEMA);
+    let names = |type_name: &str| -> Vec<String> {
+        schema.possible_types(type_name)
+            .iter()
+            .map(|t| t.name().to_string())
+            .collect()
+    };
+
+    assert_eq!(names("SearchResult"), vec!["Post", "User"]);
+    assert_eq!(names("Node"), vec!["User", "Post"]);
+    assert_eq!(names("Entity"), vec!["User", "Post"]);
+    assert_eq!(names("Orphan"), vec!["Orphan"]);
+    assert!(names("Status").is_empty());
+    assert!(names("Nope").is_empty());
+}
+
+// Verifies that the source maps registered while loading are
+// carried into the built Schema (index 0 is the built-in map).
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn source_maps_carried_into_schema() {
+    let mut sb = SchemaBuilder::new();
+    sb.load_str("type Query { a: Int }").unwrap();
+    sb.load_str("type Other { b: Int }").unwrap();
+    let schema = sb.build().unwrap();
+
+    assert_eq!(schema.source_maps().len(), 3);
+}
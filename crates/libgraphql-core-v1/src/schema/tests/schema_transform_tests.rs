@@ -0,0 +1,249 @@
+use crate::schema::Schema;
+use crate::schema::SchemaBuildErrorKind;
+use crate::schema::SchemaBuilder;
+use crate::schema::SchemaCoordinate;
+
+fn build(source: &str) -> Schema {
+    SchemaBuilder::build_from_str(source)
+        .unwrap_or_else(|errs| panic!("schema failed to build:\n{errs}"))
+}
+
+/// The names of all non-built-in types in `schema`, in definition
+/// order.
+fn type_names(schema: &Schema) -> Vec<&str> {
+    schema.types()
+        .values()
+        .filter(|type_| !type_.is_builtin())
+        .map(|type_| type_.name().as_str())
+        .collect()
+}
+
+// Verifies that pruning keeps every type reachable from the root
+// operation types (through fields, arguments, input fields,
+// interfaces, implementations of reachable interfaces, and union
+// members) or used by a directive definition, and removes the rest.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn prune_unreachable_types() {
+    let schema = build("\
+directive @tag(kind: TagKind) on FIELD_DEFINITION
+enum TagKind { A B }
+type Query { node(filter: Filter): Node, search: SearchResult }
+type Mutation { ping: Boolean }
+input Filter { range: Range }
+input Range { min: Int }
+interface Node { id: ID! }
+type User implements Node { id: ID!, posts: [Post] }
+type Post { title: String }
+type Photo { url: String }
+union SearchResult = Photo
+type Orphan { a: Int }
+interface Unused { a: Int }
+type Impl implements Unused { a: Int }
+scalar Json
+");
+    let pruned = schema.prune_unreachable_types().unwrap();
+    assert_eq!(type_names(&pruned), [
+        "TagKind", "Query", "Mutation", "Filter", "Range", "Node", "User",
+        "Post", "Photo", "SearchResult",
+    ]);
+    assert_eq!(pruned.mutation_type_name().unwrap().as_str(), "Mutation");
+    assert!(pruned.scalar_type("Float").is_some());
+}
+
+// Verifies that removing the elements carrying a directive removes
+// types, fields, arguments, input fields, and enum values, along
+// with the directive's definition, fields of removed types, types
+// left empty, and types left unreachable.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn remove_elements_with_directive() {
+    let schema = build("\
+directive @internal on OBJECT | FIELD_DEFINITION | ARGUMENT_DEFINITION \
+  | INPUT_FIELD_DEFINITION | ENUM_VALUE
+directive @tag(name: String) on FIELD_DEFINITION
+type Query {
+  user(id: ID!, debug: Boolean @internal): User @tag(name: \"u\")
+  audit: AuditLog
+  admin: Admin
+}
+type Mutation { resetAll: Boolean @internal }
+type User {
+  name: String
+  role: Role
+  flags: Flags
+}
+enum Role { MEMBER STAFF @internal }
+input UserFilter { name: String, secret: String @internal }
+type AuditLog @internal { entries: [AuditEntry] }
+type AuditEntry { at: String }
+type Admin { audit: AuditLog }
+type Flags { beta: Boolean @internal }
+");
+    let public = schema.remove_elements_with_directive("internal").unwrap();
+    assert_eq!(public.to_sdl(), "\
+directive @tag(name: String) on FIELD_DEFINITION
+
+type Query {
+  user(id: ID!): User @tag(name: \"u\")
+}
+
+type User {
+  name: String
+  role: Role
+}
+
+enum Role {
+  MEMBER
+}
+");
+    assert!(public.directive("internal").is_none());
+    assert!(public.mutation_type_name().is_none());
+}
+
+// Verifies that a removal which leaves the schema invalid (here, a
+// field an interface still requires) is reported as a validation
+// error of the transformed schema.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn remove_elements_with_directive_revalidates() {
+    let schema = build("\
+directive @internal on FIELD_DEFINITION
+type Query { node: Node }
+interface Node { id: ID!, secret: String }
+type User implements Node { id: ID!, secret: String @internal }
+");
+    let errors = schema.remove_elements_with_directive("internal").unwrap_err();
+    let messages: Vec<String> = errors.errors()
+        .iter()
+        .map(|error| error.to_string())
+        .collect();
+    assert_eq!(messages.len(), 1, "{messages:?}");
+    assert!(
+        matches!(
+            errors.errors()[0].kind(),
+            SchemaBuildErrorKind::TypeValidation(_),
+        ),
+        "{messages:?}",
+    );
+    assert!(messages[0].contains("secret"), "{messages:?}");
+}
+
+// Verifies that filtering keeps only the elements whose coordinates
+// satisfy the predicate, drops applications of removed directives,
+// never removes built-ins, and does not prune unreachable types.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn filter_by_coordinate() {
+    let schema = build("\
+directive @beta on FIELD_DEFINITION
+type Query {
+  me(locale: String, debug: Boolean): User @beta
+  stats: Stats
+  version: String @deprecated
+}
+type User { name: String, email: String }
+type Stats { count: Int }
+type Extra { a: Int }
+");
+    let removed = [
+        "@beta",
+        "@deprecated",
+        "Query.me(debug:)",
+        "Stats",
+        "String",
+        "User.email",
+    ];
+    let partner = schema.filter(|coordinate| {
+        !removed.contains(&coordinate.to_string().as_str())
+    }).unwrap();
+    assert_eq!(partner.to_sdl(), "\
+type Query {
+  me(locale: String): User
+  version: String @deprecated
+}
+
+type User {
+  name: String
+}
+
+type Extra {
+  a: Int
+}
+");
+
+    let coordinate = SchemaCoordinate::parse("Query.stats").unwrap();
+    assert!(schema.resolve_coordinate(&coordinate).is_some());
+    assert!(partner.resolve_coordinate(&coordinate).is_none());
+}
+
+// Verifies that prefixing renames every non-built-in type along with
+// every reference to it, including root operation bindings, and
+// reports a prefixed name that collides with a built-in scalar.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn prefix_type_names() {
+    let schema = build("\
+directive @auth(role: Role) on FIELD_DEFINITION
+type Query { node(filter: Filter): Node @auth(role: ADMIN), search: Result }
+interface Node { id: ID! }
+type User implements Node { id: ID! }
+union Result = User
+input Filter { role: Role = ADMIN, ids: [ID!] }
+enum Role { ADMIN }
+scalar Json
+");
+    let prefixed = schema.prefix_type_names("Partner").unwrap();
+    assert_eq!(prefixed.to_sdl(), "\
+schema {
+  query: PartnerQuery
+}
+
+directive @auth(role: PartnerRole) on FIELD_DEFINITION
+
+type PartnerQuery {
+  node(filter: PartnerFilter): PartnerNode @auth(role: ADMIN)
+  search: PartnerResult
+}
+
+interface PartnerNode {
+  id: ID!
+}
+
+type PartnerUser implements PartnerNode {
+  id: ID!
+}
+
+union PartnerResult = PartnerUser
+
+input PartnerFilter {
+  role: PartnerRole = ADMIN
+  ids: [ID!]
+}
+
+enum PartnerRole {
+  ADMIN
+}
+
+scalar PartnerJson
+");
+    let user = prefixed.object_type("PartnerUser").unwrap();
+    assert_eq!(user.field("id").unwrap().parent_type_name().as_str(), "PartnerUser");
+    assert_eq!(prefixed.possible_types("PartnerNode").len(), 1);
+
+    let schema = build("type Query { a: t } type t { b: Int }");
+    let errors = schema.prefix_type_names("In").unwrap_err();
+    assert!(
+        matches!(
+            errors.errors()[0].kind(),
+            SchemaBuildErrorKind::DuplicateTypeDefinition { type_name }
+                if type_name == "Int",
+        ),
+        "{errors}",
+    );
+}
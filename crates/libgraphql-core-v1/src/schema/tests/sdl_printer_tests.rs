@@ -0,0 +1,288 @@
+use crate::schema::Schema;
+use crate::schema::SchemaBuilder;
+use crate::schema::SdlPrintOptions;
+
+fn build(source: &str) -> Schema {
+    SchemaBuilder::build_from_str(source)
+        .unwrap_or_else(|errs| panic!("schema failed to build:\n{errs}"))
+}
+
+const KITCHEN_SINK: &str = "\
+\"\"\"
+The root query.
+Second line.
+\"\"\"
+type Query implements Node @tag(name: \"root\") {
+  \"The ID.\"
+  id: ID!
+  search(
+    \"What to search for.\"
+    term: String! = \"*\"
+    limit: Int = 10 @deprecated
+  ): [SearchResult!]! @deprecated(reason: \"Use `find`.\")
+  find(filter: Filter = {tags: [\"a\", \"b\"]}, first: Float = 1.0): Post
+}
+
+interface Node {
+  id: ID!
+}
+
+type Post implements Node {
+  id: ID!
+  status: Status
+}
+
+union SearchResult @tag(name: \"u\") = Post | Query
+
+enum Status {
+  \"\"\"Not yet live.\"\"\"
+  DRAFT
+  PUBLISHED @deprecated
+}
+
+input Filter @oneOf {
+  status: Status
+  tags: [String!] = null
+}
+
+scalar Url @specifiedBy(url: \"https://url.spec.whatwg.org\")
+
+directive @tag(name: String!) repeatable on OBJECT | FIELD_DEFINITION | UNION
+";
+
+// Verifies the default printed form of a schema exercising every
+// type kind, descriptions, default values, and applied directives
+// with arguments. Directive definitions print before types.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn prints_all_definition_kinds() {
+    let schema = build(KITCHEN_SINK);
+
+    assert_eq!(schema.to_sdl(), "\
+directive @tag(name: String!) repeatable on OBJECT | FIELD_DEFINITION | UNION
+
+\"\"\"
+The root query.
+Second line.
+\"\"\"
+type Query implements Node @tag(name: \"root\") {
+  \"\"\"The ID.\"\"\"
+  id: ID!
+  search(
+    \"\"\"What to search for.\"\"\"
+    term: String! = \"*\"
+    limit: Int = 10 @deprecated
+  ): [SearchResult!]! @deprecated(reason: \"Use `find`.\")
+  find(filter: Filter = {tags: [\"a\", \"b\"]}, first: Float = 1.0): Post
+}
+
+interface Node {
+  id: ID!
+}
+
+type Post implements Node {
+  id: ID!
+  status: Status
+}
+
+union SearchResult @tag(name: \"u\") = Post | Query
+
+enum Status {
+  \"\"\"Not yet live.\"\"\"
+  DRAFT
+  PUBLISHED @deprecated
+}
+
+input Filter @oneOf {
+  status: Status
+  tags: [String!] = null
+}
+
+scalar Url @specifiedBy(url: \"https://url.spec.whatwg.org\")
+");
+}
+
+// Verifies that printed SDL rebuilds into a schema that prints
+// identically (a print/parse/print fixed point).
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn printed_sdl_round_trips() {
+    let schema = build(KITCHEN_SINK);
+    let sdl = schema.to_sdl();
+    let rebuilt = build(&sdl);
+
+    assert_eq!(rebuilt.to_sdl(), sdl);
+    assert_eq!(rebuilt.types().len(), schema.types().len());
+}
+
+// Verifies that sort_alphabetically orders types and directive
+// definitions by name while keeping field order.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn sorts_types_and_directives_alphabetically() {
+    let schema = build(
+        "type Query { z: Int, a: Int }\n\
+         type B { x: Int }\n\
+         type A { x: Int }\n\
+         directive @z on FIELD\n\
+         directive @a on FIELD",
+    );
+
+    let sdl = schema.to_sdl_with_options(&SdlPrintOptions {
+        sort_alphabetically: true,
+        ..SdlPrintOptions::default()
+    });
+    assert_eq!(sdl, "\
+directive @a on FIELD
+
+directive @z on FIELD
+
+type A {
+  x: Int
+}
+
+type B {
+  x: Int
+}
+
+type Query {
+  z: Int
+  a: Int
+}
+");
+}
+
+// Verifies that built-in scalars and directives are printed only
+// when requested.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn includes_builtins_only_when_requested() {
+    let schema = build("type Query { a: Int }");
+
+    assert!(!schema.to_sdl().contains("scalar Int"));
+    assert!(!schema.to_sdl().contains("directive @skip"));
+
+    let with_scalars = schema.to_sdl_with_options(&SdlPrintOptions {
+        include_builtin_scalars: true,
+        ..SdlPrintOptions::default()
+    });
+    assert!(with_scalars.contains("scalar Int\n"));
+    assert!(!with_scalars.contains("directive @skip"));
+
+    let full = schema.to_sdl_with_options(&SdlPrintOptions::with_builtins());
+    assert!(full.contains("scalar Boolean\n"));
+    assert!(full.contains(
+        "directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT\n",
+    ));
+    assert!(full.contains("directive @oneOf on INPUT_OBJECT\n"));
+}
+
+// Verifies that `schema { ... }` is omitted when root types use
+// the default names, and printed when they don't.
+//
+// See https://spec.graphql.org/September2025/#sec-Root-Operation-Types.Default-Root-Operation-Type-Names
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn schema_definition_only_for_non_default_root_names() {
+    let defaults = build(
+        "type Query { a: Int }\ntype Mutation { b: Int }",
+    );
+    assert!(!defaults.to_sdl().contains("schema {"));
+
+    let renamed = build(
+        "schema { query: Root, mutation: Mutate }\n\
+         type Root { a: Int }\n\
+         type Mutate { b: Int }",
+    );
+    assert!(renamed.to_sdl().starts_with(
+        "schema {\n  query: Root\n  mutation: Mutate\n}\n\n",
+    ));
+}
+
+// Verifies that a schema definition is printed when a type named
+// `Mutation` exists but is not the mutation root: omitting it
+// would bind `Mutation` as the mutation root on re-parse.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn schema_definition_printed_when_default_named_type_is_unbound() {
+    let schema = build(
+        "schema { query: Query }\n\
+         type Query { a: Int }\n\
+         type Mutation { b: Int }",
+    );
+
+    let sdl = schema.to_sdl();
+    assert!(sdl.starts_with("schema {\n  query: Query\n}\n\n"), "{sdl}");
+    assert!(build(&sdl).mutation_type().is_none());
+}
+
+// Verifies that descriptions print as quoted strings when block
+// strings are disabled, and fall back to quoted strings when the
+// description can't survive a block string round trip.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn description_string_styles() {
+    let schema = build(
+        "\"line one\\nline two\" type Query {\n\
+           \"  indented\\n  both lines\" a: Int\n\
+           \"ends with quote\\\"\" b: Int\n\
+           \"\\nleading blank line\" c: Int\n\
+         }",
+    );
+
+    let quoted = schema.to_sdl_with_options(&SdlPrintOptions {
+        descriptions_as_block_strings: false,
+        ..SdlPrintOptions::default()
+    });
+    assert!(quoted.starts_with("\"line one\\nline two\"\ntype Query {"));
+
+    let block = schema.to_sdl();
+    assert!(block.starts_with("\"\"\"\nline one\nline two\n\"\"\"\n"));
+    assert!(block.contains("  \"  indented\\n  both lines\"\n  a: Int"));
+    assert!(block.contains(
+        "  \"\"\"\n  ends with quote\"\n  \"\"\"\n  b: Int",
+    ));
+    assert!(block.contains("  \"\\nleading blank line\"\n  c: Int"));
+
+    // Every description survives the round trip exactly.
+    let rebuilt = build(&block);
+    let query = rebuilt.query_type();
+    assert_eq!(query.description(), Some("line one\nline two"));
+    assert_eq!(
+        query.field("a").unwrap().description(),
+        Some("  indented\n  both lines"),
+    );
+    assert_eq!(
+        query.field("b").unwrap().description(),
+        Some("ends with quote\""),
+    );
+    assert_eq!(
+        query.field("c").unwrap().description(),
+        Some("\nleading blank line"),
+    );
+}
+
+// Verifies that `"""` inside a description is escaped in block
+// string output.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn block_string_escapes_triple_quotes() {
+    let schema = build(
+        "type Query { \"say \\\"\\\"\\\"hi\\\"\\\"\\\" ok\" a: Int }",
+    );
+
+    let sdl = schema.to_sdl();
+    assert!(sdl.contains("  \"\"\"say \\\"\"\"hi\\\"\"\" ok\"\"\"\n"), "{sdl}");
+    assert_eq!(
+        build(&sdl).query_type().field("a").unwrap().description(),
+        Some("say \"\"\"hi\"\"\" ok"),
+    );
+}
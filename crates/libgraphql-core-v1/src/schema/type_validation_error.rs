@@ -1,4 +1,5 @@
 use crate::error_note::ErrorNote;
+use crate::schema_source_map::SchemaSourceMap;
 use crate::span::Span;
 
 /// A type-system validation error discovered during
@@ -32,6 +33,31 @@ impl TypeValidationError {
     pub fn kind(&self) -> &TypeValidationErrorKind { &self.kind }
     pub fn notes(&self) -> &[ErrorNote] { &self.notes }
     pub fn span(&self) -> Span { self.span }
+
+    /// Formats this error as a multi-line diagnostic with source
+    /// snippets. See
+    /// [`SchemaBuildError::format_detailed()`](crate::schema::SchemaBuildError::format_detailed)
+    /// for the format and for which `source_maps` to pass.
+    ///
+    /// ```text
+    /// error: union member `Int` on `SearchResult` is not an object type
+    ///   --> schema.graphql:4:22
+    ///    |
+    ///  4 | union SearchResult = Int | User
+    ///    |                      ^^^
+    ///    = note: `Int` is defined here
+    /// ```
+    pub fn format_detailed(
+        &self,
+        source_maps: &[SchemaSourceMap],
+    ) -> String {
+        crate::diagnostic_renderer::format_detailed(
+            &self.kind.to_string(),
+            self.span,
+            &self.notes,
+            source_maps,
+        )
+    }
 }
 
 impl std::fmt::Display for TypeValidationError {
@@ -65,6 +91,15 @@ pub enum TypeValidationErrorKind {
         circular_field_path: Vec<String>,
     },
 
+    #[error(
+        "directive `@{directive_name}` may not be used on \
+        {location}"
+    )]
+    DirectiveNotAllowedAtLocation {
+        directive_name: String,
+        location: String,
+    },
+
     #[error(
         "`{type_name}` declares it implements \
         `{non_interface_type_name}`, but \
@@ -85,6 +120,26 @@ pub enum TypeValidationErrorKind {
         undefined_interface_name: String,
     },
 
+    #[error(
+        "invalid default value `{default_value}` for \
+        `{coordinate}`: {reason}"
+    )]
+    InvalidDefaultValue {
+        coordinate: String,
+        default_value: String,
+        reason: String,
+    },
+
+    #[error(
+        "invalid value for argument `{argument_name}` of \
+        `@{directive_name}`: {reason}"
+    )]
+    InvalidDirectiveArgumentValue {
+        argument_name: String,
+        directive_name: String,
+        reason: String,
+    },
+
     #[error(
         "parameter `{parameter_name}` on directive `@{directive_name}` \
         has type `{invalid_type_name}` which is not an input type"
@@ -95,6 +150,16 @@ pub enum TypeValidationErrorKind {
         parameter_name: String,
     },
 
+    #[error(
+        "invalid `@{directive_name}(fields:)` on `{type_name}`: \
+        {reason}"
+    )]
+    InvalidFieldSet {
+        directive_name: String,
+        reason: String,
+        type_name: String,
+    },
+
     #[error(
         "input field `{parent_type_name}.{field_name}` has \
         type `{invalid_type_name}` which is not an input type"
@@ -176,6 +241,16 @@ pub enum TypeValidationErrorKind {
         union_type_name: String,
     },
 
+    #[error(
+        "directive `@{directive_name}` is missing required \
+        argument `{argument_name}` of type `{argument_type}`"
+    )]
+    MissingRequiredDirectiveArgument {
+        argument_name: String,
+        argument_type: String,
+        directive_name: String,
+    },
+
     #[error(
         "`{type_name}` implements `{interface_name}` but does \
         not define field `{field_name}`"
@@ -213,6 +288,28 @@ pub enum TypeValidationErrorKind {
         type_name: String,
     },
 
+    #[error(
+        "non-repeatable directive `@{directive_name}` is applied \
+        more than once to the same element"
+    )]
+    NonRepeatableDirectiveRepeated {
+        directive_name: String,
+    },
+
+    #[error("directive `@{directive_name}` is used but not defined")]
+    UndefinedDirective {
+        directive_name: String,
+    },
+
+    #[error(
+        "directive `@{directive_name}` has no argument \
+        `{argument_name}`"
+    )]
+    UndefinedDirectiveArgument {
+        argument_name: String,
+        directive_name: String,
+    },
+
     #[error(
         "type `{undefined_type_name}` is referenced but not \
         defined"
@@ -0,0 +1,238 @@
+use crate::names::TypeName;
+use crate::scalars::ScalarRegistry;
+use crate::types::GraphQLType;
+use crate::types::ScalarKind;
+use crate::types::TypeAnnotation;
+use crate::value::Value;
+use indexmap::IndexMap;
+use serde_json::Value as JsonValue;
+
+/// An error produced by
+/// [`Schema::coerce_variable_value()`](crate::schema::Schema::coerce_variable_value).
+#[derive(Clone, Debug, PartialEq, thiserror::Error)]
+#[error(
+    "{reason}{}",
+    if path.is_empty() { String::new() } else { format!(" at `{path}`") },
+)]
+pub struct VariableCoercionError {
+    path: String,
+    reason: String,
+}
+
+impl VariableCoercionError {
+    /// The path to the offending value within the coerced value
+    /// (e.g. `filters[1].key`), or an empty string if the value
+    /// itself could not be coerced.
+    pub fn path(&self) -> &str { &self.path }
+    pub fn reason(&self) -> &str { &self.reason }
+}
+
+/// Coerces a JSON variable value to `type_annotation`, following
+/// each type's input coercion rules and consulting `scalars` for
+/// custom scalars. Omitted input object fields with a default value
+/// are filled in.
+///
+/// See [Coercing Variable Values](https://spec.graphql.org/September2025/#sec-Coercing-Variable-Values).
+pub(crate) fn coerce_variable_value(
+    value: &JsonValue,
+    type_annotation: &TypeAnnotation,
+    types_map: &IndexMap<TypeName, GraphQLType>,
+    scalars: &ScalarRegistry,
+    path: &str,
+) -> Result<JsonValue, VariableCoercionError> {
+    let fail = |reason: String| Err(VariableCoercionError {
+        path: path.to_string(),
+        reason,
+    });
+
+    if value.is_null() {
+        return if type_annotation.nullable() {
+            Ok(JsonValue::Null)
+        } else {
+            fail(format!("expected non-null `{type_annotation}`, found `null`"))
+        };
+    }
+
+    let named = match type_annotation {
+        // https://spec.graphql.org/September2025/#sec-List.Input-Coercion
+        TypeAnnotation::List(list) => {
+            return match value {
+                JsonValue::Array(items) => items
+                    .iter()
+                    .enumerate()
+                    .map(|(i, item)| coerce_variable_value(
+                        item,
+                        list.inner(),
+                        types_map,
+                        scalars,
+                        &format!("{path}[{i}]"),
+                    ))
+                    .collect::<Result<_, _>>()
+                    .map(JsonValue::Array),
+                // A non-list value coerces to a list of one item.
+                _ => coerce_variable_value(
+                    value,
+                    list.inner(),
+                    types_map,
+                    scalars,
+                    path,
+                ).map(|item| JsonValue::Array(vec![item])),
+            };
+        },
+        TypeAnnotation::Named(named) => named,
+    };
+
+    let type_name = named.type_name();
+    let mismatch = || fail(format!("expected `{type_name}`, found `{value}`"));
+    let Some(type_) = types_map.get(type_name) else {
+        return fail(format!("type `{type_name}` is not defined"));
+    };
+    match type_ {
+        GraphQLType::Scalar(scalar) => {
+            match scalar.kind() {
+                ScalarKind::Boolean if value.is_boolean() => Ok(value.clone()),
+                ScalarKind::Custom => match scalars.get(scalar) {
+                    Some(custom) => custom.coerce_input(value).or_else(|reason| {
+                        fail(format!("invalid `{type_name}` value: {reason}"))
+                    }),
+                    None => Ok(value.clone()),
+                },
+                ScalarKind::Float if value.is_number() => Ok(value.clone()),
+                // Integer input is accepted for `ID` and coerced to
+                // its string form.
+                ScalarKind::ID => match value {
+                    JsonValue::String(_) => Ok(value.clone()),
+                    JsonValue::Number(n) if n.is_i64() || n.is_u64() => {
+                        Ok(JsonValue::String(n.to_string()))
+                    },
+                    _ => mismatch(),
+                },
+                ScalarKind::Int if value
+                    .as_i64()
+                    .is_some_and(|n| i32::try_from(n).is_ok()) => {
+                    Ok(value.clone())
+                },
+                ScalarKind::String if value.is_string() => Ok(value.clone()),
+                _ => mismatch(),
+            }
+        },
+
+        GraphQLType::Enum(enum_type) => {
+            let Some(value_name) = value.as_str() else {
+                return mismatch();
+            };
+            if enum_type.value(value_name).is_some() {
+                Ok(value.clone())
+            } else {
+                fail(format!("enum `{type_name}` has no value `{value_name}`"))
+            }
+        },
+
+        // https://spec.graphql.org/September2025/#sec-Input-Objects.Input-Coercion
+        GraphQLType::InputObject(input_obj) => {
+            let JsonValue::Object(fields) = value else {
+                return mismatch();
+            };
+            if let Some(unknown) = fields
+                .keys()
+                .find(|key| input_obj.field(key).is_none()) {
+                return fail(format!(
+                    "input object `{type_name}` has no field `{unknown}`",
+                ));
+            }
+
+            // https://spec.graphql.org/September2025/#sec-OneOf-Input-Objects
+            if input_obj.is_one_of() {
+                let mut fields_iter = fields.iter();
+                match (fields_iter.next(), fields_iter.next()) {
+                    (Some((field_name, JsonValue::Null)), None) => {
+                        return fail(format!(
+                            "OneOf input object `{type_name}` field \
+                            `{field_name}` must not be `null`",
+                        ));
+                    },
+                    (Some(_), None) => (),
+                    _ => {
+                        return fail(format!(
+                            "OneOf input object `{type_name}` requires \
+                            exactly one field, found {}",
+                            fields.len(),
+                        ));
+                    },
+                }
+            }
+
+            let mut coerced = serde_json::Map::new();
+            for (field_name, field) in input_obj.fields() {
+                let field_path = if path.is_empty() {
+                    field_name.to_string()
+                } else {
+                    format!("{path}.{field_name}")
+                };
+                match fields.get(field_name.as_str()) {
+                    Some(field_value) => {
+                        coerced.insert(
+                            field_name.to_string(),
+                            coerce_variable_value(
+                                field_value,
+                                field.type_annotation(),
+                                types_map,
+                                scalars,
+                                &field_path,
+                            )?,
+                        );
+                    },
+                    None => match field.default_value() {
+                        Some(default_value) => {
+                            coerced.insert(
+                                field_name.to_string(),
+                                const_value_to_json(default_value),
+                            );
+                        },
+                        None if !field.type_annotation().nullable() => {
+                            return fail(format!(
+                                "missing required field \
+                                `{type_name}.{field_name}`",
+                            ));
+                        },
+                        None => (),
+                    },
+                }
+            }
+            Ok(JsonValue::Object(coerced))
+        },
+
+        GraphQLType::Interface(_)
+            | GraphQLType::Object(_)
+            | GraphQLType::Union(_) => fail(format!(
+                "`{type_name}` is not an input type",
+            )),
+    }
+}
+
+/// Converts a const [`Value`] (e.g. a default value) to JSON.
+/// Enum values become strings.
+fn const_value_to_json(value: &Value) -> JsonValue {
+    match value {
+        Value::Boolean(b) => JsonValue::Bool(*b),
+        Value::Enum(name) => JsonValue::String(name.to_string()),
+        Value::Float(n) => serde_json::Number::from_f64(*n)
+            .map_or(JsonValue::Null, JsonValue::Number),
+        Value::Int(n) => JsonValue::from(*n),
+        Value::List(items) => {
+            JsonValue::Array(items.iter().map(const_value_to_json).collect())
+        },
+        // Default values are validated to be const when the schema
+        // is built.
+        Value::Null | Value::VarRef(_) => JsonValue::Null,
+        Value::Object(fields) => JsonValue::Object(
+            fields
+                .iter()
+                .map(|(name, value)| {
+                    (name.to_string(), const_value_to_json(value))
+                })
+                .collect(),
+        ),
+        Value::String(s) => JsonValue::String(s.clone()),
+    }
+}
@@ -1,4 +1,7 @@
+use crate::span::Span;
+use std::path::Path;
 use std::path::PathBuf;
+use std::sync::Arc;
 
 /// Owned, serializable source map data for resolving
 /// [`ByteSpan`](libgraphql_parser::ByteSpan)s to line/column
@@ -10,17 +13,34 @@ use std::path::PathBuf;
 /// borrows source text via `'src` and does not implement
 /// `serde::Serialize`. A [`Schema`] must be `'static` and
 /// serde-serializable (the `libgraphql-macros` crate embeds
-/// schemas as binary at compile time). `SchemaSourceMap` stores
-/// just the line-start byte offsets and optional file path —
-/// the minimum data needed for deferred line/column resolution.
+/// schemas as binary at compile time). `SchemaSourceMap`
+/// serializes just the line-start byte offsets and optional file
+/// path — the minimum data needed for deferred line/column
+/// resolution.
+///
+/// The source text itself is also retained (behind an [`Arc`])
+/// so that errors can be rendered with source snippets, but it
+/// is skipped during serialization: a deserialized source map
+/// resolves line/column positions but renders without snippets.
+/// For the same reason, the source text does not participate in
+/// equality.
 ///
 /// One `SchemaSourceMap` exists per source file or string loaded
 /// into a [`SchemaBuilder`](crate::schema::SchemaBuilder).
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug)]
 #[derive(serde::Deserialize, serde::Serialize)]
 pub struct SchemaSourceMap {
     pub(crate) file_path: Option<PathBuf>,
     pub(crate) line_starts: Vec<u32>,
+    #[serde(skip)]
+    pub(crate) source: Option<Arc<str>>,
+}
+
+impl PartialEq for SchemaSourceMap {
+    fn eq(&self, other: &Self) -> bool {
+        self.file_path == other.file_path
+            && self.line_starts == other.line_starts
+    }
 }
 
 impl SchemaSourceMap {
@@ -55,18 +75,40 @@ impl SchemaSourceMap {
                 _ => i += 1,
             }
         }
-        Self { file_path, line_starts }
+        Self {
+            file_path,
+            line_starts,
+            source: Some(Arc::from(source)),
+        }
     }
 
     /// Creates a synthetic source map for built-in definitions.
     pub fn builtin() -> Self {
-        Self { file_path: None, line_starts: vec![0] }
+        Self { file_path: None, line_starts: vec![0], source: None }
     }
 
     pub fn file_path(&self) -> Option<&std::path::Path> {
         self.file_path.as_deref()
     }
 
+    /// The source text this map was built from, if still
+    /// available. Always `None` for the built-in source map and
+    /// for source maps that were deserialized.
+    pub fn source(&self) -> Option<&str> {
+        self.source.as_deref()
+    }
+
+    /// Returns the content of the 0-based line `line`, without
+    /// its line terminator, if the source text is available.
+    pub fn line_content(&self, line: u32) -> Option<&str> {
+        let source = self.source.as_deref()?;
+        let start = *self.line_starts.get(line as usize)? as usize;
+        let end = self.line_starts
+            .get(line as usize + 1)
+            .map_or(source.len(), |&next| next as usize);
+        Some(source[start..end].trim_end_matches(['\n', '\r']))
+    }
+
     /// Resolves a byte offset to a 0-based line/column position.
     ///
     /// Returns both a byte-offset column and a UTF-8 character
@@ -103,6 +145,39 @@ impl SchemaSourceMap {
     }
 }
 
+/// Resolves `span` against the source map it was recorded in.
+/// Returns `None` if `span.source_map_id` does not index into
+/// `source_maps`.
+pub(crate) fn resolve_span(
+    source_maps: &[SchemaSourceMap],
+    span: Span,
+) -> Option<ResolvedSpan<'_>> {
+    let source_map = source_maps.get(span.source_map_id.0 as usize)?;
+    let source = source_map.source();
+    Some(ResolvedSpan {
+        end: source_map.resolve_offset(span.byte_span.end, source),
+        file_path: source_map.file_path(),
+        start: source_map.resolve_offset(span.byte_span.start, source),
+    })
+}
+
+/// A [`Span`] resolved to the file it came from and 0-based
+/// line/column positions within that file.
+///
+/// When the source map no longer holds its source text (e.g.
+/// after deserialization), [`LineCol::col_utf8`] is only exact
+/// for ASCII lines (see [`SchemaSourceMap::resolve_offset()`]).
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct ResolvedSpan<'a> {
+    /// Position just past the end of the span.
+    pub end: LineCol,
+    /// The file the span's source was loaded from, or `None` for
+    /// sources loaded from a string (and for built-ins).
+    pub file_path: Option<&'a Path>,
+    /// Position of the first byte of the span.
+    pub start: LineCol,
+}
+
 /// A resolved 0-based line and column position.
 ///
 /// Provides two column representations:
@@ -0,0 +1,180 @@
+use crate::schema::SchemaBuildErrorKind;
+use crate::schema::SchemaBuilder;
+use crate::schema::SchemaErrors;
+use crate::schema_source_map::SchemaSourceMap;
+use std::path::Path;
+
+// Verifies the full rendered form of an error whose "first
+// defined here" note points into a different source than the
+// primary span: each snippet is resolved through its own source
+// map and carries its own location line.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn renders_primary_and_cross_source_note_snippets() {
+    let mut sb = SchemaBuilder::new();
+    sb.load_str("type Query { a: Int }\n\ntype User {\n  id: ID!\n}")
+        .unwrap();
+    sb.load_str("# dup\ntype User { name: String }").unwrap();
+    let errors = sb.build().unwrap_err();
+
+    assert_eq!(
+        errors.errors()[0].format_detailed(errors.source_maps()),
+        "\
+error: duplicate type definition `User`
+  --> <input>:2:1
+   |
+ 2 | type User { name: String }
+   | ^^^^^^^^^^^^^^^^^^^^^^^^^^
+   = note: first defined here
+     --> <input>:3:1
+      |
+    3 | type User {
+      | -----------
+",
+    );
+}
+
+// Verifies that file-backed sources render with their file path
+// in each location line.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn renders_file_paths_for_each_snippet() {
+    let fixtures = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("src/schema/tests/fixtures/invalid_multi_file");
+    let mut sb = SchemaBuilder::new();
+    sb.load_dir(&fixtures).unwrap();
+    let errors = sb.build().unwrap_err();
+
+    let rendered = errors.format_detailed();
+    let a_loc = format!("--> {}:5:1", fixtures.join("a.graphql").display());
+    let b_loc = format!("--> {}:3:1", fixtures.join("b.graphql").display());
+    assert!(rendered.contains(&b_loc), "{rendered}");
+    assert!(rendered.contains(&a_loc), "{rendered}");
+}
+
+// Verifies that type-validation errors render their validator
+// notes (help + spec) after the snippet, and that
+// TypeValidationError::format_detailed() matches the wrapping
+// SchemaBuildError's rendering.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn renders_type_validation_error_with_help_note() {
+    let errors = SchemaBuilder::build_from_str(
+        "type Query { u: SearchResult }\n\
+         type User { id: ID! }\n\
+         union SearchResult = Usr",
+    ).unwrap_err();
+
+    let err = &errors.errors()[0];
+    let SchemaBuildErrorKind::TypeValidation(tv_err) = err.kind() else {
+        panic!("expected a TypeValidation error, got {err:?}");
+    };
+    let rendered = tv_err.format_detailed(errors.source_maps());
+    assert_eq!(rendered, err.format_detailed(errors.source_maps()));
+    assert_eq!(
+        rendered,
+        "\
+error: type `Usr` is referenced but not defined
+  --> <input>:3:22
+   |
+ 3 | union SearchResult = Usr
+   |                      ^^^
+   = help: did you mean `User`?
+   = spec: https://spec.graphql.org/September2025/#sel-HAHdfFDABABlG3ib
+",
+    );
+}
+
+// Verifies that spans covering multiple lines are underlined only
+// to the end of their first line.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn multi_line_span_underlined_to_end_of_first_line() {
+    let errors = SchemaBuilder::build_from_str(
+        "type Query { a: Int }\nenum Empty\n  @deprecated",
+    ).unwrap_err();
+
+    let rendered = errors.format_detailed();
+    assert!(
+        rendered.contains(" 2 | enum Empty\n   | ^^^^^^^^^^\n"),
+        "{rendered}",
+    );
+}
+
+// Verifies that columns (and underline offsets) count UTF-8
+// characters rather than bytes.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn utf8_columns() {
+    let errors = SchemaBuilder::build_from_str(
+        "type Query { a: Int }\n\
+         \"héllo\" union U = Missing",
+    ).unwrap_err();
+
+    let rendered = errors.format_detailed();
+    assert!(rendered.contains("--> <input>:2:19"), "{rendered}");
+    assert!(
+        rendered.contains(&format!("   | {}^^^^^^^\n", " ".repeat(18))),
+        "{rendered}",
+    );
+}
+
+// Verifies that errors without a user-authored location (here, a
+// file read error) render only the header and notes.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn builtin_span_renders_without_location() {
+    let mut sb = SchemaBuilder::new();
+    let errors = match sb.load_file("/no/such/schema.graphql") {
+        Err(errs) => errs,
+        Ok(_) => panic!("expected a file read error"),
+    };
+
+    let rendered = errors[0].format_detailed(sb.source_maps());
+    assert!(rendered.starts_with(
+        "error: failed to read schema file `/no/such/schema.graphql`",
+    ));
+    assert!(!rendered.contains("-->"), "{rendered}");
+}
+
+// Verifies that when source text is unavailable (as for
+// deserialized source maps), the location is still rendered but
+// the snippet is omitted.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn missing_source_text_renders_location_only() {
+    let errors = SchemaBuilder::build_from_str(
+        "type Query { a: Int }\nunion U = Missing",
+    ).unwrap_err();
+    let source_maps: Vec<SchemaSourceMap> = errors.source_maps()
+        .iter()
+        .map(|sm| SchemaSourceMap { source: None, ..sm.clone() })
+        .collect();
+
+    let rendered = errors.errors()[0].format_detailed(&source_maps);
+    assert!(rendered.contains("  --> <input>:2:11\n"), "{rendered}");
+    assert!(!rendered.contains(" | "), "{rendered}");
+}
+
+// Verifies that SchemaErrors::format_detailed() renders every
+// error, separated by blank lines.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn schema_errors_format_detailed_renders_all() {
+    let errors: SchemaErrors = SchemaBuilder::build_from_str(
+        "type Query { a: Int }\nunion A = X\nunion B = Y",
+    ).unwrap_err();
+
+    let rendered = errors.format_detailed();
+    assert_eq!(errors.len(), 2);
+    assert_eq!(rendered.matches("error: ").count(), 2);
+    assert!(rendered.contains("\n\nerror: "), "{rendered}");
+}
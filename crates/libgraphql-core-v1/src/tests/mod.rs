@@ -1,3 +1,4 @@
+mod diagnostic_renderer_tests;
 mod directive_annotation_tests;
 mod located_tests;
 mod schema_source_map_tests;
@@ -142,4 +142,20 @@ fn schema_source_map_serde_roundtrip() {
             bincode::config::standard(),
         ).unwrap();
     assert_eq!(sm, deserialized);
+    // Source text is not serialized.
+    assert_eq!(sm.source(), Some("abc\ndef"));
+    assert!(deserialized.source().is_none());
+}
+
+// Verifies line_content() strips line terminators and returns
+// None past the last line or without source text.
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn line_content_lookup() {
+    let sm = SchemaSourceMap::from_source("ab\r\ncd\nef", None);
+    assert_eq!(sm.line_content(0), Some("ab"));
+    assert_eq!(sm.line_content(1), Some("cd"));
+    assert_eq!(sm.line_content(2), Some("ef"));
+    assert_eq!(sm.line_content(3), None);
+    assert_eq!(SchemaSourceMap::builtin().line_content(0), None);
 }
@@ -82,3 +82,40 @@ fn value_serde_roundtrip() {
         ).unwrap();
     assert_eq!(value, deserialized);
 }
+
+// Verifies Display formats each variant as a GraphQL literal.
+// https://spec.graphql.org/September2025/#sec-Input-Values
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn value_display_as_graphql_literal() {
+    let mut obj = IndexMap::new();
+    obj.insert(FieldName::new("ids"), Value::List(vec![
+        Value::Int(1),
+        Value::Int(-2),
+    ]));
+    obj.insert(FieldName::new("kind"), Value::Enum(EnumValueName::new("A")));
+
+    assert_eq!(Value::Boolean(false).to_string(), "false");
+    assert_eq!(Value::Float(1.0).to_string(), "1.0");
+    assert_eq!(Value::Float(2.5e-3).to_string(), "0.0025");
+    assert_eq!(Value::Float(1e300).to_string(), "1e300");
+    assert_eq!(Value::Null.to_string(), "null");
+    assert_eq!(Value::Object(obj).to_string(), "{ids: [1, -2], kind: A}");
+    assert_eq!(Value::Object(IndexMap::new()).to_string(), "{}");
+    assert_eq!(
+        Value::VarRef(VariableName::new("id")).to_string(),
+        "$id",
+    );
+}
+
+// Verifies Display escapes quotes, backslashes, and control
+// characters in string values.
+// https://spec.graphql.org/September2025/#sec-String-Value
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn value_display_escapes_strings() {
+    assert_eq!(
+        Value::String("a\"b\\c\nd\te\u{1}é".to_string()).to_string(),
+        "\"a\\\"b\\\\c\\nd\\te\\u0001é\"",
+    );
+}
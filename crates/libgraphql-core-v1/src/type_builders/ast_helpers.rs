@@ -9,7 +9,9 @@ use crate::span::Span;
 use crate::types::ListTypeAnnotation;
 use crate::types::NamedTypeAnnotation;
 use crate::types::TypeAnnotation;
+use crate::value::NestedValueSpans;
 use crate::value::Value;
+use crate::value::ValueSpans;
 use libgraphql_parser::ByteSpan;
 use libgraphql_parser::ast;
 
@@ -29,7 +31,7 @@ pub(crate) fn type_annotation_from_ast(
             TypeAnnotation::Named(NamedTypeAnnotation {
                 nullable: named.nullable(),
                 span: span_from_ast(named.span, source_map_id),
-                type_name: TypeName::new(named.name.value.as_ref()),
+                type_name: TypeName::from(named.name.symbol()),
             })
         },
         ast::TypeAnnotation::List(list) => {
@@ -60,7 +62,7 @@ pub(crate) fn value_from_ast(ast_val: &ast::Value<'_>) -> Value {
         ast::Value::Object(v) => Value::Object(
             v.fields.iter().map(|f| {
                 (
-                    FieldName::new(f.name.value.as_ref()),
+                    FieldName::from(f.name.symbol()),
                     value_from_ast(&f.value),
                 )
             }).collect(),
@@ -69,11 +71,39 @@ pub(crate) fn value_from_ast(ast_val: &ast::Value<'_>) -> Value {
             Value::String(v.value.to_string())
         },
         ast::Value::Variable(v) => {
-            Value::VarRef(VariableName::new(v.name.value.as_ref()))
+            Value::VarRef(VariableName::from(v.name.symbol()))
         },
     }
 }
 
+/// Collects the spans of `ast_val` and of every value nested inside
+/// it, mirroring the structure [`value_from_ast()`] builds.
+pub(crate) fn value_spans_from_ast(
+    ast_val: &ast::Value<'_>,
+    source_map_id: SourceMapId,
+) -> ValueSpans {
+    let nested = match ast_val {
+        ast::Value::List(v) => NestedValueSpans::Items(
+            v.values.iter().map(|item| {
+                value_spans_from_ast(item, source_map_id)
+            }).collect(),
+        ),
+        ast::Value::Object(v) => NestedValueSpans::Fields(
+            v.fields.iter().map(|f| {
+                (
+                    FieldName::from(f.name.symbol()),
+                    value_spans_from_ast(&f.value, source_map_id),
+                )
+            }).collect(),
+        ),
+        _ => NestedValueSpans::None,
+    };
+    ValueSpans {
+        nested,
+        span: span_from_ast(ast_val.byte_span(), source_map_id),
+    }
+}
+
 pub(crate) fn directive_annotation_from_ast(
     ast_dir: &ast::DirectiveAnnotation<'_>,
     source_map_id: SourceMapId,
@@ -81,11 +111,11 @@ pub(crate) fn directive_annotation_from_ast(
     DirectiveAnnotation {
         arguments: ast_dir.arguments.iter().map(|arg| {
             (
-                FieldName::new(arg.name.value.as_ref()),
+                FieldName::from(arg.name.symbol()),
                 value_from_ast(&arg.value),
             )
         }).collect(),
-        name: DirectiveName::new(ast_dir.name.value.as_ref()),
+        name: DirectiveName::from(ast_dir.name.symbol()),
         span: span_from_ast(ast_dir.span, source_map_id),
     }
 }
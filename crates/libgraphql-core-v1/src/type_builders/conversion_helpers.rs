@@ -7,19 +7,24 @@ use crate::types::EnumValue;
 use crate::types::FieldDefinition;
 use crate::types::InputField;
 use crate::types::ParameterDefinition;
+use crate::types::ParameterOwner;
 
 pub(crate) fn field_def_from_builder(
     b: FieldDefBuilder,
     parent_type_name: &TypeName,
 ) -> FieldDefinition {
+    let parameters = b.parameters.into_iter().map(|p| {
+        let param = param_def_from_builder(p, ParameterOwner::Field {
+            field_name: b.name.clone(),
+            type_name: parent_type_name.clone(),
+        });
+        (param.name.clone(), param)
+    }).collect();
     FieldDefinition {
         description: b.description,
         directives: b.directives,
         name: b.name,
-        parameters: b.parameters.into_iter().map(|p| {
-            let param = param_def_from_builder(p);
-            (param.name.clone(), param)
-        }).collect(),
+        parameters,
         parent_type_name: parent_type_name.clone(),
         span: b.span,
         type_annotation: b.type_annotation,
@@ -28,12 +33,15 @@ pub(crate) fn field_def_from_builder(
 
 pub(crate) fn param_def_from_builder(
     b: ParameterDefBuilder,
+    owner: ParameterOwner,
 ) -> ParameterDefinition {
     ParameterDefinition {
         default_value: b.default_value,
+        default_value_spans: b.default_value_spans,
         description: b.description,
         directives: b.directives,
         name: b.name,
+        owner,
         span: b.span,
         type_annotation: b.type_annotation,
     }
@@ -45,6 +53,7 @@ pub(crate) fn input_field_from_builder(
 ) -> InputField {
     InputField {
         default_value: b.default_value,
+        default_value_spans: b.default_value_spans,
         description: b.description,
         directives: b.directives,
         name: b.name,
@@ -0,0 +1,27 @@
+use crate::schema::SchemaBuildError;
+use crate::type_builders::graphql_type_ref::GraphQLTypeRef;
+use crate::type_builders::into_graphql_type::IntoGraphQLType;
+
+/// A Rust type that defines a GraphQL type, for code-first schema
+/// construction.
+///
+/// Implemented by `#[derive(GraphQLObject)]`,
+/// `#[derive(GraphQLEnum)]`, and `#[derive(GraphQLInputObject)]`
+/// from `libgraphql-macros`, whose type builders are registered
+/// with
+/// [`SchemaBuilder::absorb_rust_type()`](crate::schema::SchemaBuilder::absorb_rust_type).
+pub trait DefinesGraphQLType: GraphQLTypeRef {
+    /// The type builder produced for `Self` (e.g.
+    /// [`ObjectTypeBuilder`](crate::type_builders::ObjectTypeBuilder)).
+    type Builder: IntoGraphQLType;
+
+    /// Returns a builder for the GraphQL type `Self` defines. The
+    /// builder can be further customized (e.g. to add interfaces)
+    /// before being passed to
+    /// [`SchemaBuilder::absorb_type()`](crate::schema::SchemaBuilder::absorb_type).
+    // TODO: SchemaBuildError is large due to SchemaBuildErrorKind
+    // variants + Vec<ErrorNote>. Consider boxing the error or
+    // using an error index to reduce Result size.
+    #[allow(clippy::result_large_err)]
+    fn graphql_type_builder() -> Result<Self::Builder, SchemaBuildError>;
+}
@@ -134,7 +134,7 @@ impl DirectiveBuilder {
             locations: ast_dir.locations.iter()
                 .map(|loc| loc.kind)
                 .collect(),
-            name: DirectiveName::new(ast_dir.name.value.as_ref()),
+            name: DirectiveName::from(ast_dir.name.symbol()),
             parameters: vec![],
             span,
         };
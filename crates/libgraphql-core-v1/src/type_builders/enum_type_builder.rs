@@ -126,7 +126,7 @@ impl EnumTypeBuilder {
                 &ast_enum.description,
             ),
             directives: vec![],
-            name: TypeName::new(ast_enum.name.value.as_ref()),
+            name: TypeName::from(ast_enum.name.symbol()),
             span,
             values: vec![],
         };
@@ -60,7 +60,7 @@ impl EnumValueDefBuilder {
                     d, source_map_id,
                 )
             }).collect(),
-            name: EnumValueName::new(ast_val.name.value.as_ref()),
+            name: EnumValueName::from(ast_val.name.symbol()),
             span: ast_helpers::span_from_ast(
                 ast_val.span,
                 source_map_id,
@@ -114,7 +114,7 @@ impl FieldDefBuilder {
                     d, source_map_id,
                 )
             }).collect(),
-            name: FieldName::new(ast_field.name.value.as_ref()),
+            name: FieldName::from(ast_field.name.symbol()),
             parameters: vec![],
             span,
             type_annotation: ast_helpers::type_annotation_from_ast(
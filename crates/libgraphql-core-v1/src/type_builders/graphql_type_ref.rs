@@ -0,0 +1,64 @@
+use crate::types::TypeAnnotation;
+
+/// A Rust type that can be referenced as the type of a GraphQL
+/// field, argument, or input field.
+///
+/// Implemented for Rust's primitive equivalents of the built-in
+/// scalars (`bool`, `i32`, `f64`, `String`, ...), for the wrappers
+/// `Option<T>` (nullable), `Vec<T>` (list), and `Box<T>`
+/// (transparent), and by `libgraphql-macros`'
+/// `#[derive(GraphQLObject)]`, `#[derive(GraphQLEnum)]`, and
+/// `#[derive(GraphQLInputObject)]` for the types they define.
+/// Non-`Option` types are non-null:
+///
+/// ```rust
+/// # use libgraphql_core_v1 as libgraphql_core;
+/// use libgraphql_core::type_builders::GraphQLTypeRef;
+///
+/// assert_eq!(i32::type_annotation().to_string(), "Int!");
+/// assert_eq!(
+///     <Option<Vec<Option<String>>>>::type_annotation().to_string(),
+///     "[String]",
+/// );
+/// ```
+pub trait GraphQLTypeRef {
+    /// The (non-null, unless `Self` is an `Option`) GraphQL type
+    /// annotation for `Self`.
+    fn type_annotation() -> TypeAnnotation;
+}
+
+macro_rules! impl_builtin_scalar_type_ref {
+    ($scalar_name:literal: $($rust_type:ty),+) => {$(
+        impl GraphQLTypeRef for $rust_type {
+            fn type_annotation() -> TypeAnnotation {
+                TypeAnnotation::named($scalar_name, false)
+            }
+        }
+    )+};
+}
+
+impl_builtin_scalar_type_ref!("Boolean": bool);
+impl_builtin_scalar_type_ref!("Float": f32, f64);
+impl_builtin_scalar_type_ref!("Int": i8, i16, i32, u8, u16);
+impl_builtin_scalar_type_ref!("String": String);
+
+impl<T: GraphQLTypeRef> GraphQLTypeRef for Box<T> {
+    fn type_annotation() -> TypeAnnotation { T::type_annotation() }
+}
+
+impl<T: GraphQLTypeRef> GraphQLTypeRef for Option<T> {
+    fn type_annotation() -> TypeAnnotation {
+        let mut annotation = T::type_annotation();
+        match &mut annotation {
+            TypeAnnotation::List(list) => list.nullable = true,
+            TypeAnnotation::Named(named) => named.nullable = true,
+        }
+        annotation
+    }
+}
+
+impl<T: GraphQLTypeRef> GraphQLTypeRef for Vec<T> {
+    fn type_annotation() -> TypeAnnotation {
+        TypeAnnotation::list(T::type_annotation(), false)
+    }
+}
@@ -5,12 +5,14 @@ use crate::span::Span;
 use crate::type_builders::ast_helpers;
 use crate::types::TypeAnnotation;
 use crate::value::Value;
+use crate::value::ValueSpans;
 use libgraphql_parser::ast;
 
 /// Builder-stage input field data before validation.
 #[derive(Debug)]
 pub struct InputFieldDefBuilder {
     pub(crate) default_value: Option<Value>,
+    pub(crate) default_value_spans: Option<ValueSpans>,
     pub(crate) description: Option<String>,
     pub(crate) directives: Vec<DirectiveAnnotation>,
     pub(crate) name: FieldName,
@@ -27,6 +29,7 @@ impl InputFieldDefBuilder {
     ) -> Self {
         Self {
             default_value: None,
+            default_value_spans: None,
             description: None,
             directives: vec![],
             name: name.into(),
@@ -41,6 +44,7 @@ impl InputFieldDefBuilder {
         value: Value,
     ) -> &mut Self {
         self.default_value = Some(value);
+        self.default_value_spans = None;
         self
     }
 
@@ -71,6 +75,9 @@ impl InputFieldDefBuilder {
             default_value: ast_field.default_value
                 .as_ref()
                 .map(ast_helpers::value_from_ast),
+            default_value_spans: ast_field.default_value
+                .as_ref()
+                .map(|v| ast_helpers::value_spans_from_ast(v, source_map_id)),
             description: ast_helpers::description_from_ast(
                 &ast_field.description,
             ),
@@ -79,7 +86,7 @@ impl InputFieldDefBuilder {
                     d, source_map_id,
                 )
             }).collect(),
-            name: FieldName::new(ast_field.name.value.as_ref()),
+            name: FieldName::from(ast_field.name.symbol()),
             span: ast_helpers::span_from_ast(
                 ast_field.span,
                 source_map_id,
@@ -123,7 +123,7 @@ impl InputObjectTypeBuilder {
             ),
             directives: vec![],
             fields: vec![],
-            name: TypeName::new(ast_input.name.value.as_ref()),
+            name: TypeName::from(ast_input.name.symbol()),
             span,
         };
         if builder.name.as_str().starts_with("__") {
@@ -161,7 +161,7 @@ impl InterfaceTypeBuilder {
             directives: vec![],
             fields: vec![],
             implements: vec![],
-            name: TypeName::new(ast_iface.name.value.as_ref()),
+            name: TypeName::from(ast_iface.name.symbol()),
             span,
         };
         if builder.name.as_str().starts_with("__") {
@@ -1,9 +1,11 @@
 pub(crate) mod ast_helpers;
 pub(crate) mod conversion_helpers;
+mod defines_graphql_type;
 mod directive_builder;
 mod enum_type_builder;
 mod enum_value_def_builder;
 mod field_def_builder;
+mod graphql_type_ref;
 mod input_field_def_builder;
 mod input_object_type_builder;
 mod interface_type_builder;
@@ -13,10 +15,12 @@ mod parameter_def_builder;
 mod scalar_type_builder;
 mod union_type_builder;
 
+pub use crate::type_builders::defines_graphql_type::DefinesGraphQLType;
 pub use crate::type_builders::directive_builder::DirectiveBuilder;
 pub use crate::type_builders::enum_type_builder::EnumTypeBuilder;
 pub use crate::type_builders::enum_value_def_builder::EnumValueDefBuilder;
 pub use crate::type_builders::field_def_builder::FieldDefBuilder;
+pub use crate::type_builders::graphql_type_ref::GraphQLTypeRef;
 pub use crate::type_builders::input_field_def_builder::InputFieldDefBuilder;
 pub use crate::type_builders::input_object_type_builder::InputObjectTypeBuilder;
 pub use crate::type_builders::interface_type_builder::InterfaceTypeBuilder;
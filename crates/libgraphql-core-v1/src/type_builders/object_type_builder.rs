@@ -151,7 +151,7 @@ impl ObjectTypeBuilder {
             directives: vec![],
             fields: vec![],
             implements: vec![],
-            name: TypeName::new(ast_obj.name.value.as_ref()),
+            name: TypeName::from(ast_obj.name.symbol()),
             span,
         };
         if builder.name.as_str().starts_with("__") {
@@ -87,7 +87,7 @@ impl ScalarTypeBuilder {
                 &ast_scalar.description,
             ),
             directives: vec![],
-            name: TypeName::new(ast_scalar.name.value.as_ref()),
+            name: TypeName::from(ast_scalar.name.symbol()),
             span,
         };
         if builder.name.as_str().starts_with("__") {
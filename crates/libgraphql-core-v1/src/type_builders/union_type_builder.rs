@@ -112,7 +112,7 @@ impl UnionTypeBuilder {
             ),
             directives: vec![],
             members: vec![],
-            name: TypeName::new(ast_union.name.value.as_ref()),
+            name: TypeName::from(ast_union.name.symbol()),
             span,
         };
         if builder.name.as_str().starts_with("__") {
@@ -1,3 +1,6 @@
+use crate::directive_annotation::DirectiveAnnotation;
+use crate::value::Value;
+
 /// Deprecation status of a type, field, enum value, or argument,
 /// derived from the presence of a
 /// [`@deprecated`](https://spec.graphql.org/September2025/#sec--deprecated)
@@ -8,7 +11,24 @@ pub enum DeprecationState<'a> {
     Deprecated { reason: Option<&'a str> },
 }
 
-impl DeprecationState<'_> {
+impl<'a> DeprecationState<'a> {
+    /// Derives the deprecation status from the directives applied
+    /// to an element. `reason` is `None` when `@deprecated` is
+    /// applied without a (string) `reason` argument.
+    pub fn from_directives(directives: &'a [DirectiveAnnotation]) -> Self {
+        let Some(deprecated) = directives
+            .iter()
+            .find(|d| d.name().as_str() == "deprecated")
+        else {
+            return Self::Active;
+        };
+        let reason = match deprecated.arguments().get("reason") {
+            Some(Value::String(reason)) => Some(reason.as_str()),
+            _ => None,
+        };
+        Self::Deprecated { reason }
+    }
+
     #[inline]
     pub fn is_deprecated(&self) -> bool {
         matches!(self, Self::Deprecated { .. })
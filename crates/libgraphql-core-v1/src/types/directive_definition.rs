@@ -1,5 +1,6 @@
 use crate::names::DirectiveName;
 use crate::names::FieldName;
+use crate::schema::SchemaCoordinate;
 use crate::span::Span;
 use crate::types::directive_definition_kind::DirectiveDefinitionKind;
 use crate::types::directive_location_kind::DirectiveLocationKind;
@@ -27,6 +28,10 @@ pub struct DirectiveDefinition {
 }
 
 impl DirectiveDefinition {
+    /// This directive's schema coordinate (e.g. `@deprecated`).
+    pub fn coordinate(&self) -> SchemaCoordinate {
+        SchemaCoordinate::Directive { directive_name: self.name.clone() }
+    }
     pub fn description(&self) -> Option<&str> {
         self.description.as_deref()
     }
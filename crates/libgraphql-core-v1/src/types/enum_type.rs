@@ -1,6 +1,7 @@
 use crate::directive_annotation::DirectiveAnnotation;
 use crate::names::EnumValueName;
 use crate::names::TypeName;
+use crate::schema::SchemaCoordinate;
 use crate::span::Span;
 use crate::types::enum_value::EnumValue;
 use indexmap::IndexMap;
@@ -17,6 +18,10 @@ pub struct EnumType {
 }
 
 impl EnumType {
+    /// This type's schema coordinate (e.g. `User`).
+    pub fn coordinate(&self) -> SchemaCoordinate {
+        SchemaCoordinate::Type { type_name: self.name.clone() }
+    }
     pub fn description(&self) -> Option<&str> {
         self.description.as_deref()
     }
@@ -1,6 +1,7 @@
 use crate::directive_annotation::DirectiveAnnotation;
 use crate::names::EnumValueName;
 use crate::names::TypeName;
+use crate::schema::SchemaCoordinate;
 use crate::span::Span;
 
 /// A single value within an [`EnumType`](crate::types::EnumType)
@@ -19,6 +20,13 @@ pub struct EnumValue {
 }
 
 impl EnumValue {
+    /// This value's schema coordinate (e.g. `Color.RED`).
+    pub fn coordinate(&self) -> SchemaCoordinate {
+        SchemaCoordinate::Member {
+            member_name: self.name.as_str().to_string(),
+            type_name: self.parent_type_name.clone(),
+        }
+    }
     pub fn description(&self) -> Option<&str> {
         self.description.as_deref()
     }
@@ -1,6 +1,7 @@
 use crate::directive_annotation::DirectiveAnnotation;
 use crate::names::FieldName;
 use crate::names::TypeName;
+use crate::schema::SchemaCoordinate;
 use crate::span::Span;
 use crate::types::parameter_definition::ParameterDefinition;
 use crate::types::type_annotation::TypeAnnotation;
@@ -29,6 +30,13 @@ pub struct FieldDefinition {
 }
 
 impl FieldDefinition {
+    /// This field's schema coordinate (e.g. `User.name`).
+    pub fn coordinate(&self) -> SchemaCoordinate {
+        SchemaCoordinate::Member {
+            member_name: self.name.as_str().to_string(),
+            type_name: self.parent_type_name.clone(),
+        }
+    }
     pub fn description(&self) -> Option<&str> {
         self.description.as_deref()
     }
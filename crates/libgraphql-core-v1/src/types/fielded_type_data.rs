@@ -2,6 +2,7 @@ use crate::directive_annotation::DirectiveAnnotation;
 use crate::located::Located;
 use crate::names::FieldName;
 use crate::names::TypeName;
+use crate::schema::SchemaCoordinate;
 use crate::span::Span;
 use crate::types::field_definition::FieldDefinition;
 use indexmap::IndexMap;
@@ -23,6 +24,9 @@ pub(crate) struct FieldedTypeData {
 }
 
 impl FieldedTypeData {
+    pub(crate) fn coordinate(&self) -> SchemaCoordinate {
+        SchemaCoordinate::Type { type_name: self.name.clone() }
+    }
     pub(crate) fn description(&self) -> Option<&str> {
         self.description.as_deref()
     }
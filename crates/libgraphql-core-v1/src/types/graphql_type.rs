@@ -1,4 +1,6 @@
+use crate::directive_annotation::DirectiveAnnotation;
 use crate::names::TypeName;
+use crate::schema::SchemaCoordinate;
 use crate::span::Span;
 use crate::types::enum_type::EnumType;
 use crate::types::graphql_type_kind::GraphQLTypeKind;
@@ -46,6 +48,11 @@ impl GraphQLType {
         }
     }
 
+    /// This type's schema coordinate (e.g. `User`).
+    pub fn coordinate(&self) -> SchemaCoordinate {
+        SchemaCoordinate::Type { type_name: self.name().clone() }
+    }
+
     pub fn span(&self) -> Span {
         match self {
             Self::Enum(t) => t.span(),
@@ -68,6 +75,17 @@ impl GraphQLType {
         }
     }
 
+    pub fn directives(&self) -> &[DirectiveAnnotation] {
+        match self {
+            Self::Enum(t) => t.directives(),
+            Self::InputObject(t) => t.directives(),
+            Self::Interface(t) => t.directives(),
+            Self::Object(t) => t.directives(),
+            Self::Scalar(t) => t.directives(),
+            Self::Union(t) => t.directives(),
+        }
+    }
+
     /// Input types can appear in input positions (arguments,
     /// variables, input object fields): Scalar, Enum, InputObject.
     ///
@@ -2,6 +2,7 @@ use crate::directive_annotation::DirectiveAnnotation;
 use crate::located::Located;
 use crate::names::FieldName;
 use crate::names::TypeName;
+use crate::schema::SchemaCoordinate;
 use crate::span::Span;
 use crate::types::field_definition::FieldDefinition;
 use indexmap::IndexMap;
@@ -20,6 +21,8 @@ use indexmap::IndexMap;
 /// This trait enables the validator and downstream consumers to
 /// operate generically over both types without duplication.
 pub trait HasFieldsAndInterfaces {
+    /// This type's schema coordinate (e.g. `User`).
+    fn coordinate(&self) -> SchemaCoordinate;
     fn description(&self) -> Option<&str>;
     fn directives(&self) -> &[DirectiveAnnotation];
     fn field(&self, name: &str) -> Option<&FieldDefinition>;
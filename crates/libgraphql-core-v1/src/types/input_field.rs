@@ -1,9 +1,11 @@
 use crate::directive_annotation::DirectiveAnnotation;
 use crate::names::FieldName;
 use crate::names::TypeName;
+use crate::schema::SchemaCoordinate;
 use crate::span::Span;
 use crate::types::type_annotation::TypeAnnotation;
 use crate::value::Value;
+use crate::value::ValueSpans;
 
 /// A field on an
 /// [`InputObjectType`](crate::types::InputObjectType).
@@ -18,6 +20,7 @@ use crate::value::Value;
 #[derive(serde::Deserialize, serde::Serialize)]
 pub struct InputField {
     pub(crate) default_value: Option<Value>,
+    pub(crate) default_value_spans: Option<ValueSpans>,
     pub(crate) description: Option<String>,
     pub(crate) directives: Vec<DirectiveAnnotation>,
     pub(crate) name: FieldName,
@@ -30,6 +33,13 @@ impl InputField {
     pub fn default_value(&self) -> Option<&Value> {
         self.default_value.as_ref()
     }
+    /// This input field's schema coordinate (e.g. `UserFilter.name`).
+    pub fn coordinate(&self) -> SchemaCoordinate {
+        SchemaCoordinate::Member {
+            member_name: self.name.as_str().to_string(),
+            type_name: self.parent_type_name.clone(),
+        }
+    }
     pub fn description(&self) -> Option<&str> {
         self.description.as_deref()
     }
@@ -1,6 +1,7 @@
 use crate::directive_annotation::DirectiveAnnotation;
 use crate::names::FieldName;
 use crate::names::TypeName;
+use crate::schema::SchemaCoordinate;
 use crate::span::Span;
 use crate::types::input_field::InputField;
 use indexmap::IndexMap;
@@ -21,6 +22,10 @@ pub struct InputObjectType {
 }
 
 impl InputObjectType {
+    /// This type's schema coordinate (e.g. `User`).
+    pub fn coordinate(&self) -> SchemaCoordinate {
+        SchemaCoordinate::Type { type_name: self.name.clone() }
+    }
     pub fn description(&self) -> Option<&str> {
         self.description.as_deref()
     }
@@ -33,6 +38,13 @@ impl InputObjectType {
     pub fn fields(&self) -> &IndexMap<FieldName, InputField> {
         &self.fields
     }
+    /// Whether this is a
+    /// [OneOf input object](https://spec.graphql.org/September2025/#sec-OneOf-Input-Objects)
+    /// (annotated with `@oneOf`), which requires exactly one
+    /// non-null field to be provided.
+    pub fn is_one_of(&self) -> bool {
+        self.directives.iter().any(|d| d.name().as_str() == "oneOf")
+    }
     pub fn name(&self) -> &TypeName { &self.name }
     pub fn span(&self) -> Span { self.span }
 }
@@ -2,6 +2,7 @@ use crate::directive_annotation::DirectiveAnnotation;
 use crate::located::Located;
 use crate::names::FieldName;
 use crate::names::TypeName;
+use crate::schema::SchemaCoordinate;
 use crate::span::Span;
 use crate::types::field_definition::FieldDefinition;
 use crate::types::fielded_type_data::FieldedTypeData;
@@ -27,6 +28,7 @@ pub struct InterfaceType(pub(crate) FieldedTypeData);
 
 #[inherent]
 impl HasFieldsAndInterfaces for InterfaceType {
+    pub fn coordinate(&self) -> SchemaCoordinate { self.0.coordinate() }
     pub fn description(&self) -> Option<&str> { self.0.description() }
     pub fn directives(&self) -> &[DirectiveAnnotation] { self.0.directives() }
     pub fn field(&self, name: &str) -> Option<&FieldDefinition> { self.0.field(name) }
@@ -16,6 +16,7 @@ mod list_type_annotation;
 mod named_type_annotation;
 mod object_type;
 mod parameter_definition;
+mod parameter_owner;
 mod scalar_kind;
 mod scalar_type;
 mod type_annotation;
@@ -40,6 +41,7 @@ pub use crate::types::list_type_annotation::ListTypeAnnotation;
 pub use crate::types::named_type_annotation::NamedTypeAnnotation;
 pub use crate::types::object_type::ObjectType;
 pub use crate::types::parameter_definition::ParameterDefinition;
+pub use crate::types::parameter_owner::ParameterOwner;
 pub use crate::types::scalar_kind::ScalarKind;
 pub use crate::types::scalar_type::ScalarType;
 pub use crate::types::type_annotation::TypeAnnotation;
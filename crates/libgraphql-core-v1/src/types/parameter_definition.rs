@@ -1,8 +1,11 @@
 use crate::directive_annotation::DirectiveAnnotation;
 use crate::names::FieldName;
+use crate::schema::SchemaCoordinate;
 use crate::span::Span;
+use crate::types::parameter_owner::ParameterOwner;
 use crate::types::type_annotation::TypeAnnotation;
 use crate::value::Value;
+use crate::value::ValueSpans;
 
 /// A parameter definition on a
 /// [`FieldDefinition`](crate::types::FieldDefinition) or
@@ -17,14 +20,35 @@ use crate::value::Value;
 #[derive(serde::Deserialize, serde::Serialize)]
 pub struct ParameterDefinition {
     pub(crate) default_value: Option<Value>,
+    pub(crate) default_value_spans: Option<ValueSpans>,
     pub(crate) description: Option<String>,
     pub(crate) directives: Vec<DirectiveAnnotation>,
     pub(crate) name: FieldName,
+    pub(crate) owner: ParameterOwner,
     pub(crate) span: Span,
     pub(crate) type_annotation: TypeAnnotation,
 }
 
 impl ParameterDefinition {
+    /// This parameter's schema coordinate (e.g. `Query.user(id:)` or
+    /// `@deprecated(reason:)`).
+    pub fn coordinate(&self) -> SchemaCoordinate {
+        match &self.owner {
+            ParameterOwner::Directive(directive_name) => {
+                SchemaCoordinate::DirectiveArgument {
+                    argument_name: self.name.clone(),
+                    directive_name: directive_name.clone(),
+                }
+            },
+            ParameterOwner::Field { field_name, type_name } => {
+                SchemaCoordinate::Argument {
+                    argument_name: self.name.clone(),
+                    field_name: field_name.clone(),
+                    type_name: type_name.clone(),
+                }
+            },
+        }
+    }
     pub fn default_value(&self) -> Option<&Value> {
         self.default_value.as_ref()
     }
@@ -35,6 +59,8 @@ impl ParameterDefinition {
         &self.directives
     }
     pub fn name(&self) -> &FieldName { &self.name }
+    /// The field or directive that defines this parameter.
+    pub fn owner(&self) -> &ParameterOwner { &self.owner }
     pub fn span(&self) -> Span { self.span }
     pub fn type_annotation(&self) -> &TypeAnnotation {
         &self.type_annotation
@@ -0,0 +1,22 @@
+use crate::names::DirectiveName;
+use crate::names::FieldName;
+use crate::names::TypeName;
+
+/// The schema element a
+/// [`ParameterDefinition`](crate::types::ParameterDefinition) is
+/// defined on.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[derive(serde::Deserialize, serde::Serialize)]
+pub enum ParameterOwner {
+    /// A parameter of a
+    /// [`DirectiveDefinition`](crate::types::DirectiveDefinition).
+    Directive(DirectiveName),
+
+    /// A parameter of a
+    /// [`FieldDefinition`](crate::types::FieldDefinition) on an
+    /// object or interface type.
+    Field {
+        field_name: FieldName,
+        type_name: TypeName,
+    },
+}
@@ -1,7 +1,9 @@
 use crate::directive_annotation::DirectiveAnnotation;
 use crate::names::TypeName;
+use crate::schema::SchemaCoordinate;
 use crate::span::Span;
 use crate::types::scalar_kind::ScalarKind;
+use crate::value::Value;
 
 /// A GraphQL [scalar type](https://spec.graphql.org/September2025/#sec-Scalars).
 ///
@@ -20,6 +22,10 @@ pub struct ScalarType {
 }
 
 impl ScalarType {
+    /// This type's schema coordinate (e.g. `User`).
+    pub fn coordinate(&self) -> SchemaCoordinate {
+        SchemaCoordinate::Type { type_name: self.name.clone() }
+    }
     pub fn description(&self) -> Option<&str> {
         self.description.as_deref()
     }
@@ -32,4 +38,17 @@ impl ScalarType {
     pub fn kind(&self) -> ScalarKind { self.kind }
     pub fn name(&self) -> &TypeName { &self.name }
     pub fn span(&self) -> Span { self.span }
+    /// The URL of this scalar's specification, from its
+    /// [`@specifiedBy(url:)`](https://spec.graphql.org/September2025/#sec--specifiedBy)
+    /// directive.
+    pub fn specified_by_url(&self) -> Option<&str> {
+        self.directives
+            .iter()
+            .find(|d| d.name().as_str() == "specifiedBy")
+            .and_then(|d| d.arguments().get("url"))
+            .and_then(|url| match url {
+                Value::String(url) => Some(url.as_str()),
+                _ => None,
+            })
+    }
 }
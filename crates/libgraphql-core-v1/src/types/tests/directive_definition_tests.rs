@@ -5,6 +5,7 @@ use crate::types::DirectiveDefinition;
 use crate::types::DirectiveDefinitionKind;
 use crate::types::DirectiveLocationKind;
 use crate::types::ParameterDefinition;
+use crate::types::ParameterOwner;
 use crate::types::TypeAnnotation;
 use indexmap::IndexMap;
 
@@ -28,9 +29,11 @@ fn builtin_directive_accessors() {
     let mut params = IndexMap::new();
     params.insert(FieldName::new("if"), ParameterDefinition {
         default_value: None,
+        default_value_spans: None,
         description: None,
         directives: vec![],
         name: FieldName::new("if"),
+        owner: ParameterOwner::Directive(DirectiveName::new("skip")),
         span: Span::builtin(),
         type_annotation: TypeAnnotation::named("Boolean", false),
     });
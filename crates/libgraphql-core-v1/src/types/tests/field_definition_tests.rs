@@ -3,6 +3,7 @@ use crate::names::TypeName;
 use crate::span::Span;
 use crate::types::FieldDefinition;
 use crate::types::ParameterDefinition;
+use crate::types::ParameterOwner;
 use crate::types::TypeAnnotation;
 use crate::value::Value;
 use indexmap::IndexMap;
@@ -35,9 +36,14 @@ fn field_definition_accessors() {
     let mut params = IndexMap::new();
     params.insert(FieldName::new("first"), ParameterDefinition {
         default_value: Some(Value::Int(10)),
+        default_value_spans: None,
         description: None,
         directives: vec![],
         name: FieldName::new("first"),
+        owner: ParameterOwner::Field {
+            field_name: FieldName::new("friends"),
+            type_name: TypeName::new("User"),
+        },
         span: Span::builtin(),
         type_annotation: TypeAnnotation::named("Int", true),
     });
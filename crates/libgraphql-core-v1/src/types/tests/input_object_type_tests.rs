@@ -11,6 +11,7 @@ fn sample_input_object() -> InputObjectType {
     let mut fields = IndexMap::new();
     fields.insert(FieldName::new("name"), InputField {
         default_value: None,
+        default_value_spans: None,
         description: Some("The user's name".to_string()),
         directives: vec![],
         name: FieldName::new("name"),
@@ -20,6 +21,7 @@ fn sample_input_object() -> InputObjectType {
     });
     fields.insert(FieldName::new("age"), InputField {
         default_value: Some(Value::Int(18)),
+        default_value_spans: None,
         description: None,
         directives: vec![],
         name: FieldName::new("age"),
@@ -1,6 +1,7 @@
 use crate::directive_annotation::DirectiveAnnotation;
 use crate::located::Located;
 use crate::names::TypeName;
+use crate::schema::SchemaCoordinate;
 use crate::span::Span;
 
 /// A GraphQL [union type](https://spec.graphql.org/September2025/#sec-Unions).
@@ -20,6 +21,10 @@ pub struct UnionType {
 }
 
 impl UnionType {
+    /// This type's schema coordinate (e.g. `User`).
+    pub fn coordinate(&self) -> SchemaCoordinate {
+        SchemaCoordinate::Type { type_name: self.name.clone() }
+    }
     pub fn description(&self) -> Option<&str> {
         self.description.as_deref()
     }
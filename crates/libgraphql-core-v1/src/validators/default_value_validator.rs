@@ -0,0 +1,114 @@
+use crate::error_note::ErrorNote;
+use crate::names::DirectiveName;
+use crate::names::TypeName;
+use crate::scalars::ScalarRegistry;
+use crate::schema::SchemaCoordinate;
+use crate::schema::TypeValidationError;
+use crate::schema::TypeValidationErrorKind;
+use crate::span::Span;
+use crate::types::DirectiveDefinition;
+use crate::types::GraphQLType;
+use crate::types::TypeAnnotation;
+use crate::validators::value_coercion::check_const_value_coercion;
+use crate::value::Value;
+use crate::value::ValueSpans;
+use indexmap::IndexMap;
+
+/// Validates that the default value of every input field, field
+/// parameter, and directive parameter coerces to its declared
+/// type, consulting `scalars` for custom scalar values.
+///
+/// See [Input Values](https://spec.graphql.org/September2025/#sec-Input-Values).
+pub(crate) fn validate_default_values(
+    directive_defs: &IndexMap<DirectiveName, DirectiveDefinition>,
+    types_map: &IndexMap<TypeName, GraphQLType>,
+    scalars: &ScalarRegistry,
+) -> Vec<TypeValidationError> {
+    let mut errors = Vec::new();
+    let mut check = |
+        default_value: Option<&Value>,
+        default_value_spans: Option<&ValueSpans>,
+        type_annotation: &TypeAnnotation,
+        coordinate: SchemaCoordinate,
+        span: Span,
+    | {
+        let Some(default_value) = default_value else {
+            return;
+        };
+        if let Err(error) = check_const_value_coercion(
+            default_value,
+            type_annotation,
+            types_map,
+            scalars,
+        ) {
+            // Point at the offending (possibly nested) value when its
+            // source is known, and at the definition otherwise.
+            let error_span = default_value_spans.map_or(span, |spans| {
+                spans.span_at(&error.path)
+            });
+            errors.push(TypeValidationError::new(
+                TypeValidationErrorKind::InvalidDefaultValue {
+                    coordinate: coordinate.to_string(),
+                    default_value: default_value.to_string(),
+                    reason: error.to_string(),
+                },
+                error_span,
+                vec![
+                    ErrorNote::general_with_span(
+                        format!(
+                            "`{coordinate}` is declared with type \
+                            `{type_annotation}` here",
+                        ),
+                        span,
+                    ),
+                    ErrorNote::spec(
+                        "https://spec.graphql.org/September2025/#sec-Input-Values",
+                    ),
+                ],
+            ));
+        }
+    };
+
+    for type_ in types_map.values() {
+        let fields = match type_ {
+            GraphQLType::InputObject(input_obj) => {
+                for field in input_obj.fields().values() {
+                    check(
+                        field.default_value(),
+                        field.default_value_spans.as_ref(),
+                        field.type_annotation(),
+                        field.coordinate(),
+                        field.span(),
+                    );
+                }
+                continue;
+            },
+            GraphQLType::Interface(iface) => iface.fields(),
+            GraphQLType::Object(obj) => obj.fields(),
+            GraphQLType::Enum(_)
+                | GraphQLType::Scalar(_)
+                | GraphQLType::Union(_) => continue,
+        };
+        for param in fields.values().flat_map(|f| f.parameters().values()) {
+            check(
+                param.default_value(),
+                param.default_value_spans.as_ref(),
+                param.type_annotation(),
+                param.coordinate(),
+                param.span(),
+            );
+        }
+    }
+
+    for param in directive_defs.values().flat_map(|d| d.parameters().values()) {
+        check(
+            param.default_value(),
+            param.default_value_spans.as_ref(),
+            param.type_annotation(),
+            param.coordinate(),
+            param.span(),
+        );
+    }
+
+    errors
+}
@@ -0,0 +1,304 @@
+use crate::directive_annotation::DirectiveAnnotation;
+use crate::error_note::ErrorNote;
+use crate::names::DirectiveName;
+use crate::names::TypeName;
+use crate::scalars::ScalarRegistry;
+use crate::schema::TypeValidationError;
+use crate::schema::TypeValidationErrorKind;
+use crate::types::DirectiveDefinition;
+use crate::types::DirectiveLocationKind;
+use crate::types::GraphQLType;
+use crate::types::HasFieldsAndInterfaces;
+use crate::types::ParameterDefinition;
+use crate::validators::edit_distance::find_similar_names;
+use crate::validators::value_coercion::check_const_value_coercion;
+use indexmap::IndexMap;
+
+/// Validates every directive applied to a type-system element:
+/// types, fields, arguments, enum values, input fields, and
+/// directive definition arguments.
+///
+/// Each application must name a defined directive that is allowed
+/// at the element's location, non-repeatable directives may only be
+/// applied once per element, and the arguments must be known,
+/// complete, and coerce to their parameter types.
+///
+/// See [Type System Directives](https://spec.graphql.org/September2025/#sec-Type-System.Directives).
+pub(crate) fn validate_directive_annotations(
+    directive_defs: &IndexMap<DirectiveName, DirectiveDefinition>,
+    types_map: &IndexMap<TypeName, GraphQLType>,
+    scalars: &ScalarRegistry,
+) -> Vec<TypeValidationError> {
+    let mut validator = DirectiveAnnotationValidator {
+        directive_defs,
+        errors: vec![],
+        scalars,
+        types_map,
+    };
+
+    for type_ in types_map.values() {
+        match type_ {
+            GraphQLType::Enum(enum_type) => {
+                validator.validate(
+                    enum_type.directives(),
+                    DirectiveLocationKind::Enum,
+                );
+                for value in enum_type.values().values() {
+                    validator.validate(
+                        value.directives(),
+                        DirectiveLocationKind::EnumValue,
+                    );
+                }
+            },
+            GraphQLType::InputObject(input_obj) => {
+                validator.validate(
+                    input_obj.directives(),
+                    DirectiveLocationKind::InputObject,
+                );
+                for field in input_obj.fields().values() {
+                    validator.validate(
+                        field.directives(),
+                        DirectiveLocationKind::InputFieldDefinition,
+                    );
+                }
+            },
+            GraphQLType::Interface(iface) => validator.validate_fielded(
+                iface.as_ref(),
+                DirectiveLocationKind::Interface,
+            ),
+            GraphQLType::Object(obj) => validator.validate_fielded(
+                obj.as_ref(),
+                DirectiveLocationKind::Object,
+            ),
+            GraphQLType::Scalar(scalar) => validator.validate(
+                scalar.directives(),
+                DirectiveLocationKind::Scalar,
+            ),
+            GraphQLType::Union(union_type) => validator.validate(
+                union_type.directives(),
+                DirectiveLocationKind::Union,
+            ),
+        }
+    }
+
+    for directive_def in directive_defs.values() {
+        validator.validate_parameters(directive_def.parameters().values());
+    }
+
+    validator.errors
+}
+
+struct DirectiveAnnotationValidator<'a> {
+    directive_defs: &'a IndexMap<DirectiveName, DirectiveDefinition>,
+    errors: Vec<TypeValidationError>,
+    scalars: &'a ScalarRegistry,
+    types_map: &'a IndexMap<TypeName, GraphQLType>,
+}
+
+impl DirectiveAnnotationValidator<'_> {
+    fn validate_fielded(
+        &mut self,
+        type_: &impl HasFieldsAndInterfaces,
+        location: DirectiveLocationKind,
+    ) {
+        self.validate(type_.directives(), location);
+        for field in type_.fields().values() {
+            self.validate(
+                field.directives(),
+                DirectiveLocationKind::FieldDefinition,
+            );
+            self.validate_parameters(field.parameters().values());
+        }
+    }
+
+    fn validate_parameters<'p>(
+        &mut self,
+        params: impl Iterator<Item = &'p ParameterDefinition>,
+    ) {
+        for param in params {
+            self.validate(
+                param.directives(),
+                DirectiveLocationKind::ArgumentDefinition,
+            );
+        }
+    }
+
+    /// Validates the directives applied to a single element found
+    /// at `location`.
+    fn validate(
+        &mut self,
+        annotations: &[DirectiveAnnotation],
+        location: DirectiveLocationKind,
+    ) {
+        for (i, annotation) in annotations.iter().enumerate() {
+            let directive_name = annotation.name();
+
+            // https://spec.graphql.org/September2025/#sec-Directives-Are-Defined
+            let Some(directive_def) = self.directive_defs.get(directive_name)
+            else {
+                let mut notes = Vec::new();
+                let suggestions = find_similar_names(
+                    directive_name.as_str(),
+                    self.directive_defs.keys(),
+                );
+                if let Some(best) = suggestions.first() {
+                    notes.push(ErrorNote::help(
+                        format!("did you mean `@{best}`?"),
+                    ));
+                }
+                notes.push(ErrorNote::spec(
+                    "https://spec.graphql.org/September2025/#sec-Directives-Are-Defined",
+                ));
+                self.errors.push(TypeValidationError::new(
+                    TypeValidationErrorKind::UndefinedDirective {
+                        directive_name: directive_name.to_string(),
+                    },
+                    annotation.span(),
+                    notes,
+                ));
+                continue;
+            };
+
+            // https://spec.graphql.org/September2025/#sec-Directives-Are-In-Valid-Locations
+            if !directive_def.locations().contains(&location) {
+                let allowed = directive_def.locations()
+                    .iter()
+                    .map(|loc| loc.as_str())
+                    .collect::<Vec<_>>()
+                    .join(" | ");
+                self.errors.push(TypeValidationError::new(
+                    TypeValidationErrorKind::DirectiveNotAllowedAtLocation {
+                        directive_name: directive_name.to_string(),
+                        location: location.as_str()
+                            .to_string(),
+                    },
+                    annotation.span(),
+                    vec![
+                        ErrorNote::general_with_span(
+                            format!(
+                                "`@{directive_name}` may only be used \
+                                on {allowed}",
+                            ),
+                            directive_def.span(),
+                        ),
+                        ErrorNote::spec(
+                            "https://spec.graphql.org/September2025/#sec-Directives-Are-In-Valid-Locations",
+                        ),
+                    ],
+                ));
+            }
+
+            // https://spec.graphql.org/September2025/#sec-Directives-Are-Unique-Per-Location
+            if !directive_def.is_repeatable() {
+                let first = annotations[..i]
+                    .iter()
+                    .find(|prev| prev.name() == directive_name);
+                if let Some(first) = first {
+                    self.errors.push(TypeValidationError::new(
+                        TypeValidationErrorKind::NonRepeatableDirectiveRepeated {
+                            directive_name: directive_name.to_string(),
+                        },
+                        annotation.span(),
+                        vec![
+                            ErrorNote::general_with_span(
+                                "first applied here",
+                                first.span(),
+                            ),
+                            ErrorNote::spec(
+                                "https://spec.graphql.org/September2025/#sec-Directives-Are-Unique-Per-Location",
+                            ),
+                        ],
+                    ));
+                }
+            }
+
+            self.validate_arguments(annotation, directive_def);
+        }
+    }
+
+    fn validate_arguments(
+        &mut self,
+        annotation: &DirectiveAnnotation,
+        directive_def: &DirectiveDefinition,
+    ) {
+        let directive_name = directive_def.name();
+
+        for (arg_name, arg_value) in annotation.arguments() {
+            // https://spec.graphql.org/September2025/#sec-Argument-Names
+            let Some(param) = directive_def.parameters().get(arg_name)
+            else {
+                let mut notes = Vec::new();
+                let suggestions = find_similar_names(
+                    arg_name.as_str(),
+                    directive_def.parameters().keys(),
+                );
+                if let Some(best) = suggestions.first() {
+                    notes.push(ErrorNote::help(
+                        format!("did you mean `{best}`?"),
+                    ));
+                }
+                notes.push(ErrorNote::spec(
+                    "https://spec.graphql.org/September2025/#sec-Argument-Names",
+                ));
+                self.errors.push(TypeValidationError::new(
+                    TypeValidationErrorKind::UndefinedDirectiveArgument {
+                        argument_name: arg_name.to_string(),
+                        directive_name: directive_name.to_string(),
+                    },
+                    annotation.span(),
+                    notes,
+                ));
+                continue;
+            };
+
+            // https://spec.graphql.org/September2025/#sec-Values-of-Correct-Type
+            if let Err(error) = check_const_value_coercion(
+                arg_value,
+                param.type_annotation(),
+                self.types_map,
+                self.scalars,
+            ) {
+                self.errors.push(TypeValidationError::new(
+                    TypeValidationErrorKind::InvalidDirectiveArgumentValue {
+                        argument_name: arg_name.to_string(),
+                        directive_name: directive_name.to_string(),
+                        reason: error.to_string(),
+                    },
+                    annotation.span(),
+                    vec![
+                        ErrorNote::general_with_span(
+                            format!(
+                                "`{arg_name}` is declared with type `{}` \
+                                here",
+                                param.type_annotation(),
+                            ),
+                            param.span(),
+                        ),
+                        ErrorNote::spec(
+                            "https://spec.graphql.org/September2025/#sec-Values-of-Correct-Type",
+                        ),
+                    ],
+                ));
+            }
+        }
+
+        // https://spec.graphql.org/September2025/#sec-Required-Arguments
+        for (param_name, param) in directive_def.parameters() {
+            let is_required = !param.type_annotation().nullable()
+                && param.default_value().is_none();
+            if is_required && !annotation.arguments().contains_key(param_name) {
+                self.errors.push(TypeValidationError::new(
+                    TypeValidationErrorKind::MissingRequiredDirectiveArgument {
+                        argument_name: param_name.to_string(),
+                        argument_type: param.type_annotation().to_string(),
+                        directive_name: directive_name.to_string(),
+                    },
+                    annotation.span(),
+                    vec![ErrorNote::spec(
+                        "https://spec.graphql.org/September2025/#sec-Required-Arguments",
+                    )],
+                ));
+            }
+        }
+    }
+}
@@ -1,21 +1,19 @@
-use crate::names::TypeName;
-
-/// Finds type names in `candidates` that are within a reasonable
-/// edit distance of `name`. Returns at most 3 suggestions,
-/// sorted by distance (best first).
+/// Finds names (type, directive, enum value, ...) in `candidates`
+/// that are within a reasonable edit distance of `name`. Returns at
+/// most 3 suggestions, sorted by distance (best first).
 ///
 /// The distance threshold is adaptive: `name.len() / 3 + 1`.
 /// Shorter names require closer matches to avoid nonsensical
 /// suggestions.
-pub(crate) fn find_similar_names<'a>(
+pub(crate) fn find_similar_names<'a, N: AsRef<str> + Ord + 'a>(
     name: &str,
-    candidates: impl Iterator<Item = &'a TypeName>,
-) -> Vec<&'a TypeName> {
+    candidates: impl Iterator<Item = &'a N>,
+) -> Vec<&'a N> {
     let max_distance = name.len() / 3 + 1;
-    let mut scored: Vec<(usize, &'a TypeName)> = candidates
+    let mut scored: Vec<(usize, &'a N)> = candidates
         .filter_map(|candidate| {
             let dist =
-                levenshtein_distance(name, candidate.as_str());
+                levenshtein_distance(name, candidate.as_ref());
             if dist > 0 && dist <= max_distance {
                 Some((dist, candidate))
             } else {
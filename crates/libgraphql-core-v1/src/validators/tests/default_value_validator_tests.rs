@@ -0,0 +1,185 @@
+use crate::schema::SchemaBuildErrorKind;
+use crate::schema::SchemaBuilder;
+use crate::schema::TypeValidationErrorKind;
+
+/// Builds `source` and returns the messages of its type
+/// validation errors (empty if the schema builds).
+fn validation_errors(source: &str) -> Vec<String> {
+    match SchemaBuilder::build_from_str(source) {
+        Ok(_) => vec![],
+        Err(errors) => errors
+            .errors()
+            .iter()
+            .map(|e| match e.kind() {
+                SchemaBuildErrorKind::TypeValidation(tv) => tv.to_string(),
+                other => panic!("unexpected error kind: {other:?}"),
+            })
+            .collect(),
+    }
+}
+
+/// Builds `source` and returns the source text each of its type
+/// validation errors points at.
+fn validation_error_snippets(source: &str) -> Vec<&str> {
+    let errors = SchemaBuilder::build_from_str(source).unwrap_err();
+    errors
+        .errors()
+        .iter()
+        .map(|e| match e.kind() {
+            SchemaBuildErrorKind::TypeValidation(tv) => {
+                let byte_span = tv.span().byte_span;
+                &source[byte_span.start as usize..byte_span.end as usize]
+            },
+            other => panic!("unexpected error kind: {other:?}"),
+        })
+        .collect()
+}
+
+// Verifies that default values which coerce to their declared
+// types are accepted: built-in scalar coercions (Int to Float,
+// Int to ID), single values for list types, nested input objects
+// with defaulted fields omitted, null for nullable types, OneOf
+// input objects, and custom scalars.
+// https://spec.graphql.org/September2025/#sec-Input-Values
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn valid_default_values() {
+    let errors = validation_errors("\
+scalar Json
+enum Color { RED GREEN }
+input Page { size: Int! = 10, after: ID }
+input Filter {
+  colors: [Color!] = RED
+  page: Page = {after: 1}
+  ratio: Float = 1
+  extra: Json = {anything: [1, \"two\"]}
+}
+input By @oneOf { id: ID, name: String }
+type Query {
+  find(filter: Filter = {colors: [RED, GREEN], page: {size: 5}}): Int
+  one(by: By = {name: \"x\"}, limit: Int = null): Int
+}
+directive @d(color: Color = GREEN, page: Page = {}) on FIELD_DEFINITION
+");
+    assert!(errors.is_empty(), "{errors:#?}");
+}
+
+// Verifies that invalid default values on input fields, field
+// parameters, and directive parameters are reported with the
+// element's schema coordinate.
+// https://spec.graphql.org/September2025/#sec-Input-Values
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn invalid_default_values() {
+    assert_eq!(
+        validation_errors("\
+enum Color { RED GREEN }
+input Filter {
+  limit: Int = \"ten\"
+  color: Color = PURPLE
+}
+type Query {
+  a(x: Boolean! = null): Int
+}
+directive @d(color: Color = \"RED\") on FIELD_DEFINITION
+"),
+        [
+            "invalid default value `\"ten\"` for `Filter.limit`: expected \
+             `Int`, found `\"ten\"`",
+            "invalid default value `PURPLE` for `Filter.color`: enum `Color` \
+             has no value `PURPLE`",
+            "invalid default value `null` for `Query.a(x:)`: expected \
+             non-null `Boolean!`, found `null`",
+            "invalid default value `\"RED\"` for `@d(color:)`: expected \
+             `Color`, found `\"RED\"`",
+        ],
+    );
+}
+
+// Verifies that errors inside lists and nested input objects
+// report the path to the offending value.
+// https://spec.graphql.org/September2025/#sec-Input-Objects.Input-Coercion
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn nested_invalid_default_values_report_paths() {
+    assert_eq!(
+        validation_errors("\
+input Page { size: Int!, tags: [String!] }
+input Filter { pages: [Page!] }
+type Query {
+  a(f: Filter = {pages: [{size: 1}, {size: 2, tags: [\"x\", 3]}]}): Int
+  b(f: Filter = {pages: [{size: 1}, {tags: []}]}): Int
+  c(f: Filter = {pages: {size: 1, extra: 2}}): Int
+}
+"),
+        [
+            "invalid default value `{pages: [{size: 1}, {size: 2, tags: \
+             [\"x\", 3]}]}` for `Query.a(f:)`: expected `String`, found `3` \
+             at `pages[1].tags[1]`",
+            "invalid default value `{pages: [{size: 1}, {tags: []}]}` for \
+             `Query.b(f:)`: missing required field `Page.size` at \
+             `pages[1]`",
+            "invalid default value `{pages: {size: 1, extra: 2}}` for \
+             `Query.c(f:)`: input object `Page` has no field `extra` at \
+             `pages`",
+        ],
+    );
+}
+
+// Verifies that errors point at the offending value itself, even
+// when it is nested inside a list or input object, rather than at
+// the whole field or parameter definition.
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn invalid_default_values_point_at_offending_value() {
+    assert_eq!(
+        validation_error_snippets("\
+input Page { size: Int!, tags: [String!] }
+input Filter { pages: [Page!], limit: Int = \"ten\" }
+type Query {
+  a(f: Filter = {pages: [{size: 1}, {size: 2, tags: [\"x\", 3]}]}): Int
+  b(f: Filter = {pages: [{size: 1}, {tags: []}]}): Int
+}
+"),
+        ["\"ten\"", "3", "{tags: []}"],
+    );
+}
+
+// Verifies that a OneOf input object default must provide exactly
+// one field, and that field must not be null.
+// https://spec.graphql.org/September2025/#sec-OneOf-Input-Objects
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn one_of_default_values() {
+    let errors = SchemaBuilder::build_from_str("\
+input By @oneOf { id: ID, name: String }
+type Query {
+  a(by: By = {id: 1, name: \"x\"}): Int
+  b(by: By = {name: null}): Int
+  c(by: By = {}): Int
+}
+").unwrap_err();
+    let reasons: Vec<&str> = errors
+        .errors()
+        .iter()
+        .map(|e| match e.kind() {
+            SchemaBuildErrorKind::TypeValidation(tv) => match tv.kind() {
+                TypeValidationErrorKind::InvalidDefaultValue {
+                    reason, ..
+                } => reason.as_str(),
+                other => panic!("unexpected error kind: {other:?}"),
+            },
+            other => panic!("unexpected error kind: {other:?}"),
+        })
+        .collect();
+    assert_eq!(reasons, [
+        "OneOf input object `By` requires exactly one field, found 2",
+        "OneOf input object `By` field `name` must not be `null`",
+        "OneOf input object `By` requires exactly one field, found 0",
+    ]);
+}
@@ -0,0 +1,177 @@
+use crate::schema::SchemaBuildErrorKind;
+use crate::schema::SchemaBuilder;
+use crate::schema::TypeValidationErrorKind;
+
+/// Builds `source` and returns the messages of its type
+/// validation errors (empty if the schema builds).
+fn validation_errors(source: &str) -> Vec<String> {
+    match SchemaBuilder::build_from_str(source) {
+        Ok(_) => vec![],
+        Err(errors) => errors
+            .errors()
+            .iter()
+            .map(|e| match e.kind() {
+                SchemaBuildErrorKind::TypeValidation(tv) => tv.to_string(),
+                other => panic!("unexpected error kind: {other:?}"),
+            })
+            .collect(),
+    }
+}
+
+// Verifies that well-formed directive applications on every kind
+// of type-system element are accepted, including built-in
+// directives, repeatable directives, defaulted and nullable
+// arguments, and coercible argument values.
+// https://spec.graphql.org/September2025/#sec-Type-System.Directives
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn valid_directive_applications() {
+    let errors = validation_errors("\
+directive @tag(name: String!) repeatable on OBJECT | FIELD_DEFINITION \
+  | ARGUMENT_DEFINITION | ENUM_VALUE | INPUT_FIELD_DEFINITION | SCALAR
+directive @limit(max: Float = 1.5, ids: [ID!], by: Order) on FIELD_DEFINITION
+directive @cfg(filter: Filter) on ENUM | UNION | INTERFACE | INPUT_OBJECT
+enum Order @cfg(filter: {key: \"a\", tags: \"single\"}) {
+  ASC @tag(name: \"asc\")
+  DESC @deprecated
+}
+input Filter @cfg {
+  key: String!
+  tags: [String] @tag(name: \"t\")
+  limit: Int! = 10
+}
+interface Node @cfg(filter: null) { id: ID! }
+union U @cfg(filter: {key: \"u\"}) = Query
+scalar Url @specifiedBy(url: \"https://example.com\") @tag(name: \"u\")
+type Query @tag(name: \"a\") @tag(name: \"b\") {
+  a(x: Int @tag(name: \"x\")): Int
+    @limit(max: 3, ids: 7, by: ASC)
+    @deprecated(reason: \"use b\")
+}
+");
+    assert!(errors.is_empty(), "{errors:#?}");
+}
+
+// Verifies that applying an undefined directive is an error, with
+// a suggestion for a similarly named defined directive.
+// https://spec.graphql.org/September2025/#sec-Directives-Are-Defined
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn undefined_directive() {
+    let errors = SchemaBuilder::build_from_str(
+        "type Query { a: Int @deprecatd }",
+    ).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    let error = &errors.errors()[0];
+    assert!(matches!(
+        error.kind(),
+        SchemaBuildErrorKind::TypeValidation(tv)
+            if tv.kind() == &TypeValidationErrorKind::UndefinedDirective {
+                directive_name: "deprecatd".to_string(),
+            },
+    ));
+    assert!(error.notes().iter().any(|note| {
+        note.message == "did you mean `@deprecated`?"
+    }));
+}
+
+// Verifies that a directive applied at a location it does not
+// declare is an error, including for built-in directives.
+// https://spec.graphql.org/September2025/#sec-Directives-Are-In-Valid-Locations
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn directive_not_allowed_at_location() {
+    assert_eq!(
+        validation_errors("\
+directive @onField on FIELD_DEFINITION
+type Query @onField { a: Int }
+enum E { A @specifiedBy(url: \"x\") }
+"),
+        [
+            "directive `@onField` may not be used on OBJECT",
+            "directive `@specifiedBy` may not be used on ENUM_VALUE",
+        ],
+    );
+}
+
+// Verifies that a non-repeatable directive may only be applied
+// once per element, while a repeatable one may be applied many
+// times.
+// https://spec.graphql.org/September2025/#sec-Directives-Are-Unique-Per-Location
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn non_repeatable_directive_repeated() {
+    assert_eq!(
+        validation_errors("\
+directive @once on FIELD_DEFINITION
+directive @many repeatable on FIELD_DEFINITION
+type Query {
+  a: Int @once @many @many
+  b: Int @once @deprecated @once
+  c: Int @once
+}
+"),
+        [
+            "non-repeatable directive `@once` is applied more than once \
+             to the same element",
+        ],
+    );
+}
+
+// Verifies argument checks: unknown arguments, missing required
+// arguments (non-null without a default), and argument values
+// that do not coerce to the parameter type.
+// https://spec.graphql.org/September2025/#sec-Argument-Names
+// https://spec.graphql.org/September2025/#sec-Required-Arguments
+// https://spec.graphql.org/September2025/#sec-Values-of-Correct-Type
+//
+// Written by Claude Code, reviewed by a human.
+#[test]
+fn invalid_directive_arguments() {
+    assert_eq!(
+        validation_errors("\
+directive @d(
+  req: Int!
+  opt: Int! = 1
+  order: Order
+  filter: Filter
+  list: [Int!]
+) repeatable on FIELD_DEFINITION
+enum Order { ASC DESC }
+input Filter { key: String!, limit: Int }
+type Query {
+  a: Int @d(req: 1, bogus: 2)
+  b: Int @d(opt: 2)
+  c: Int @d(req: \"1\")
+  d: Int @d(req: null)
+  e: Int @d(req: 1, order: UP) @d(req: 1, order: \"ASC\")
+  f: Int @d(req: 1, filter: {limit: 1}) @d(req: 1, filter: {key: \"k\", x: 1})
+  g: Int @d(req: 1, list: [1, null]) @d(req: 1, list: 1.5)
+}
+"),
+        [
+            "directive `@d` has no argument `bogus`",
+            "directive `@d` is missing required argument `req` of type `Int!`",
+            "invalid value for argument `req` of `@d`: expected `Int`, found \
+             `\"1\"`",
+            "invalid value for argument `req` of `@d`: expected non-null \
+             `Int!`, found `null`",
+            "invalid value for argument `order` of `@d`: enum `Order` has no \
+             value `UP`",
+            "invalid value for argument `order` of `@d`: expected `Order`, \
+             found `\"ASC\"`",
+            "invalid value for argument `filter` of `@d`: missing required \
+             field `Filter.key`",
+            "invalid value for argument `filter` of `@d`: input object \
+             `Filter` has no field `x`",
+            "invalid value for argument `list` of `@d`: expected non-null \
+             `Int!`, found `null` at `[1]`",
+            "invalid value for argument `list` of `@d`: expected `Int`, found \
+             `1.5`",
+        ],
+    );
+}
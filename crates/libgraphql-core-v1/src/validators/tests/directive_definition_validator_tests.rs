@@ -10,6 +10,7 @@ use crate::types::FieldedTypeData;
 use crate::types::GraphQLType;
 use crate::types::ObjectType;
 use crate::types::ParameterDefinition;
+use crate::types::ParameterOwner;
 use crate::types::ScalarKind;
 use crate::types::ScalarType;
 use crate::types::TypeAnnotation;
@@ -32,9 +33,12 @@ fn make_param(
 ) -> ParameterDefinition {
     ParameterDefinition {
         default_value: None,
+        default_value_spans: None,
         description: None,
         directives: vec![],
         name: FieldName::new(name),
+        // Validators don't inspect a parameter's owner.
+        owner: ParameterOwner::Directive(DirectiveName::new("test")),
         span: Span::dummy(),
         type_annotation: type_annot,
     }
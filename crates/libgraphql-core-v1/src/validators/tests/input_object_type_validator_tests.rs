@@ -43,6 +43,7 @@ fn make_input_field(
 ) -> InputField {
     InputField {
         default_value: None,
+        default_value_spans: None,
         description: None,
         directives: vec![],
         name: FieldName::new(name),
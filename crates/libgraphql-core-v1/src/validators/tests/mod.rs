@@ -1,3 +1,5 @@
+mod default_value_validator_tests;
+mod directive_annotation_validator_tests;
 mod directive_definition_validator_tests;
 mod input_object_type_validator_tests;
 mod object_or_interface_type_validator_tests;
@@ -16,9 +16,6 @@ use indexmap::IndexMap;
 /// Note: the empty-union check (`EmptyUnionType`) is a build-level
 /// error handled by `SchemaBuildErrorKind`; this validator only
 /// covers member-exists and member-is-object checks.
-// TODO(Task 16): Wire this validator into SchemaBuilder::build().
-// Also add EmptyUnionType check in build() since it's a
-// SchemaBuildErrorKind (not a TypeValidationErrorKind).
 pub(crate) struct UnionTypeValidator<'a> {
     errors: Vec<TypeValidationError>,
     type_: &'a UnionType,
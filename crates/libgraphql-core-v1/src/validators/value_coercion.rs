@@ -0,0 +1,226 @@
+use crate::names::TypeName;
+use crate::scalars::ScalarRegistry;
+use crate::types::GraphQLType;
+use crate::types::ScalarKind;
+use crate::types::TypeAnnotation;
+use crate::value::Value;
+use crate::value::ValuePathSegment;
+use indexmap::IndexMap;
+
+/// Why a const value failed to coerce to its declared type.
+///
+/// Displays as the reason, followed by the path of the offending
+/// value when it is nested inside a list or input object (e.g.
+/// ``expected `Int`, found `"x"` at `filters[1].key` ``).
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct ValueCoercionError {
+    /// The location of the offending value within the checked
+    /// value. Empty if the checked value itself is at fault.
+    pub(crate) path: Vec<ValuePathSegment>,
+
+    /// A short human-readable reason describing the offending
+    /// value (e.g. ``expected `Int`, found `"x"` ``).
+    pub(crate) reason: String,
+}
+
+impl std::fmt::Display for ValueCoercionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.reason)?;
+        if self.path.is_empty() {
+            return Ok(());
+        }
+        f.write_str(" at `")?;
+        for (i, segment) in self.path.iter().enumerate() {
+            match segment {
+                ValuePathSegment::Field(name) if i == 0 => {
+                    write!(f, "{name}")?
+                },
+                ValuePathSegment::Field(name) => write!(f, ".{name}")?,
+                ValuePathSegment::Index(index) => write!(f, "[{index}]")?,
+            }
+        }
+        f.write_str("`")
+    }
+}
+
+/// Checks that a const `value` (a directive argument or a default
+/// value written in SDL) coerces to `type_annotation`, following
+/// each type's input coercion rules.
+///
+/// On failure, the returned [`ValueCoercionError`] describes the
+/// innermost offending value and its path within `value`.
+///
+/// Named types that are undefined or are not input types are
+/// accepted here: they are reported by the validators that check
+/// type references. Custom scalars are validated by their
+/// implementation in `scalars`, and accept any value if none is
+/// registered.
+///
+/// See [Input Values](https://spec.graphql.org/September2025/#sec-Input-Values)
+/// and [List Input Coercion](https://spec.graphql.org/September2025/#sec-List.Input-Coercion).
+pub(crate) fn check_const_value_coercion(
+    value: &Value,
+    type_annotation: &TypeAnnotation,
+    types_map: &IndexMap<TypeName, GraphQLType>,
+    scalars: &ScalarRegistry,
+) -> Result<(), ValueCoercionError> {
+    check_at_path(value, type_annotation, types_map, scalars, &[])
+}
+
+/// Recursive worker for [`check_const_value_coercion()`]. `path`
+/// is the location of `value` within the root value.
+fn check_at_path(
+    value: &Value,
+    type_annotation: &TypeAnnotation,
+    types_map: &IndexMap<TypeName, GraphQLType>,
+    scalars: &ScalarRegistry,
+    path: &[ValuePathSegment],
+) -> Result<(), ValueCoercionError> {
+    let fail = |reason: String| Err(ValueCoercionError {
+        path: path.to_vec(),
+        reason,
+    });
+    match value {
+        Value::Null if type_annotation.nullable() => return Ok(()),
+        Value::Null => {
+            return fail(format!(
+                "expected non-null `{type_annotation}`, found `null`",
+            ));
+        },
+        Value::VarRef(_) => {
+            return fail(format!(
+                "variables are not allowed in const values, found \
+                `{value}`",
+            ));
+        },
+        _ => (),
+    }
+
+    let named = match type_annotation {
+        TypeAnnotation::List(list) => {
+            // A non-list value coerces to a list of one item.
+            return match value {
+                Value::List(items) => {
+                    items.iter().enumerate().try_for_each(|(i, item)| {
+                        check_at_path(
+                            item,
+                            list.inner(),
+                            types_map,
+                            scalars,
+                            &[path, &[ValuePathSegment::Index(i)]].concat(),
+                        )
+                    })
+                },
+                _ => check_at_path(
+                    value,
+                    list.inner(),
+                    types_map,
+                    scalars,
+                    path,
+                ),
+            };
+        },
+        TypeAnnotation::Named(named) => named,
+    };
+
+    let type_name = named.type_name();
+    let mismatch = || fail(format!("expected `{type_name}`, found `{value}`"));
+    match types_map.get(type_name) {
+        Some(GraphQLType::Scalar(scalar)) => {
+            let coerces = match scalar.kind() {
+                ScalarKind::Boolean => matches!(value, Value::Boolean(_)),
+                ScalarKind::Custom => {
+                    return match scalars.get(scalar) {
+                        Some(custom) => custom.validate_literal(value)
+                            .or_else(|reason| fail(format!(
+                                "invalid `{type_name}` value `{value}`: \
+                                {reason}",
+                            ))),
+                        None => Ok(()),
+                    };
+                },
+                ScalarKind::Float => {
+                    matches!(value, Value::Float(_) | Value::Int(_))
+                },
+                ScalarKind::ID => {
+                    matches!(value, Value::Int(_) | Value::String(_))
+                },
+                ScalarKind::Int => matches!(value, Value::Int(_)),
+                ScalarKind::String => matches!(value, Value::String(_)),
+            };
+            if coerces { Ok(()) } else { mismatch() }
+        },
+
+        Some(GraphQLType::Enum(enum_type)) => {
+            let Value::Enum(value_name) = value else {
+                return mismatch();
+            };
+            if enum_type.values().contains_key(value_name) {
+                Ok(())
+            } else {
+                fail(format!(
+                    "enum `{type_name}` has no value `{value_name}`",
+                ))
+            }
+        },
+
+        // https://spec.graphql.org/September2025/#sec-Input-Objects.Input-Coercion
+        Some(GraphQLType::InputObject(input_obj)) => {
+            let Value::Object(fields) = value else {
+                return mismatch();
+            };
+            for (field_name, field_value) in fields {
+                let Some(field) = input_obj.fields().get(field_name) else {
+                    return fail(format!(
+                        "input object `{type_name}` has no field \
+                        `{field_name}`",
+                    ));
+                };
+                let field_path = [
+                    path,
+                    &[ValuePathSegment::Field(field_name.clone())],
+                ].concat();
+                check_at_path(
+                    field_value,
+                    field.type_annotation(),
+                    types_map,
+                    scalars,
+                    &field_path,
+                )?;
+            }
+
+            // https://spec.graphql.org/September2025/#sec-OneOf-Input-Objects
+            if input_obj.is_one_of() {
+                let mut fields_iter = fields.iter();
+                return match (fields_iter.next(), fields_iter.next()) {
+                    (Some((field_name, Value::Null)), None) => fail(format!(
+                        "OneOf input object `{type_name}` field \
+                        `{field_name}` must not be `null`",
+                    )),
+                    (Some(_), None) => Ok(()),
+                    _ => fail(format!(
+                        "OneOf input object `{type_name}` requires exactly \
+                        one field, found {}",
+                        fields.len(),
+                    )),
+                };
+            }
+
+            for (field_name, field) in input_obj.fields() {
+                let is_required = !field.type_annotation().nullable()
+                    && field.default_value().is_none();
+                if is_required && !fields.contains_key(field_name) {
+                    return fail(format!(
+                        "missing required field `{type_name}.{field_name}`",
+                    ));
+                }
+            }
+            Ok(())
+        },
+
+        Some(GraphQLType::Interface(_))
+            | Some(GraphQLType::Object(_))
+            | Some(GraphQLType::Union(_))
+            | None => Ok(()),
+    }
+}
@@ -1,7 +1,9 @@
 use crate::names::EnumValueName;
 use crate::names::FieldName;
 use crate::names::VariableName;
+use crate::span::Span;
 use indexmap::IndexMap;
+use libgraphql_parser::ast::StringValue;
 
 /// A GraphQL input value.
 ///
@@ -26,3 +28,107 @@ pub enum Value {
     String(String),
     VarRef(VariableName),
 }
+
+/// Formats the value as a GraphQL literal, exactly as it would be
+/// written in a GraphQL document (e.g. `{ids: [1, 2], name: "x"}`
+/// or `$var`).
+///
+/// See [Input Values](https://spec.graphql.org/September2025/#sec-Input-Values).
+impl std::fmt::Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Boolean(b) => write!(f, "{b}"),
+            Self::Enum(name) => write!(f, "{name}"),
+            // `Debug` always includes a fractional part or an
+            // exponent, so integral floats stay FloatValues
+            // (`1.0`, not `1`).
+            Self::Float(n) => write!(f, "{n:?}"),
+            Self::Int(n) => write!(f, "{n}"),
+            Self::List(items) => {
+                f.write_str("[")?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{item}")?;
+                }
+                f.write_str("]")
+            },
+            Self::Null => f.write_str("null"),
+            Self::Object(fields) => {
+                f.write_str("{")?;
+                for (i, (name, value)) in fields.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{name}: {value}")?;
+                }
+                f.write_str("}")
+            },
+            Self::String(s) => {
+                let mut literal = String::new();
+                StringValue::append_quoted_string(&mut literal, s);
+                f.write_str(&literal)
+            },
+            Self::VarRef(name) => write!(f, "${name}"),
+        }
+    }
+}
+
+/// One step from a [`Value`] into a value nested inside it: an
+/// item of a list, or a field of an input object.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum ValuePathSegment {
+    Field(FieldName),
+    Index(usize),
+}
+
+/// The source [`Span`] of a [`Value`] parsed from a literal, along
+/// with the spans of the values nested inside it.
+///
+/// Kept alongside values whose errors are reported after the AST is
+/// gone (e.g. default values, which can only be checked once every
+/// type is known), so those errors can point at the offending
+/// nested value rather than at the whole definition.
+#[derive(Clone, Debug, PartialEq)]
+#[derive(serde::Deserialize, serde::Serialize)]
+pub(crate) struct ValueSpans {
+    pub(crate) nested: NestedValueSpans,
+    pub(crate) span: Span,
+}
+
+/// The spans of the values nested inside a list or input object
+/// literal.
+#[derive(Clone, Debug, PartialEq)]
+#[derive(serde::Deserialize, serde::Serialize)]
+pub(crate) enum NestedValueSpans {
+    Fields(IndexMap<FieldName, ValueSpans>),
+    Items(Vec<ValueSpans>),
+    None,
+}
+
+impl ValueSpans {
+    /// The span of the value at `path` within this value. Falls back
+    /// to the span of the deepest value along `path` that has one.
+    pub(crate) fn span_at(&self, path: &[ValuePathSegment]) -> Span {
+        let mut spans = self;
+        for segment in path {
+            let nested = match (segment, &spans.nested) {
+                (
+                    ValuePathSegment::Field(name),
+                    NestedValueSpans::Fields(fields),
+                ) => fields.get(name),
+                (
+                    ValuePathSegment::Index(i),
+                    NestedValueSpans::Items(items),
+                ) => items.get(*i),
+                _ => None,
+            };
+            let Some(nested) = nested else {
+                break;
+            };
+            spans = nested;
+        }
+        spans.span
+    }
+}
@@ -0,0 +1,578 @@
+use crate::arbitrary::values::arb_input_value;
+use crate::operation::OperationKind;
+use crate::schema::Schema;
+use crate::types::Field;
+use crate::types::GraphQLType;
+use crate::types::TypeAnnotation;
+use indexmap::IndexMap;
+use proptest::prelude::*;
+use proptest::strategy::BoxedStrategy;
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+/// Generates an executable document that is valid against `schema`.
+///
+/// Each document contains 1-3 named operations (mutations and
+/// subscriptions only when `schema` defines the corresponding root
+/// type) and any fragment definitions those operations spread.
+/// `depth` bounds how many selection sets may nest below an
+/// operation's root selection set.
+///
+/// Generated documents respect:
+///
+/// - Field selection: every selected field exists on its parent type,
+///   and composite-typed fields (and only those) have a selection set.
+/// - Arguments: every required argument is provided, every provided
+///   argument exists, and each value (or variable) matches the
+///   argument's type.
+/// - Fragments: inline fragment and fragment spread type conditions
+///   are possible types of their parent type, fragments are
+///   acyclic, and every defined fragment is used.
+/// - Variables: every variable is defined with the type of the
+///   argument it is passed to, and every defined variable is used.
+/// - Field merging: fields selected within fragments are aliased so
+///   that response names never collide, and subscriptions select a
+///   single root field.
+///
+/// The schema is cloned into the returned strategy.
+pub fn arb_executable_document(schema: &Schema, depth: usize) -> BoxedStrategy<String> {
+    let schema = Arc::new(schema.clone());
+    let fragment_types = composite_type_names(&schema);
+    let fragment_ctx = Arc::new(Context {
+        allow_variables: false,
+        fragments: vec![],
+        schema: schema.clone(),
+    });
+    prop::collection::vec(prop::sample::select(fragment_types), 0..3)
+        .prop_flat_map(move |fragment_types| {
+            fragment_types
+                .into_iter()
+                .enumerate()
+                .map(|(idx, type_condition)| {
+                    let name = format!("{type_condition}Fragment{idx}");
+                    arb_selection_set(
+                        fragment_ctx.clone(),
+                        type_condition.clone(),
+                        depth.saturating_sub(1),
+                        Some(alias_prefix_for(&name)),
+                    )
+                    .prop_map(move |selection_set| FragmentDef {
+                        name: name.clone(),
+                        selection_set,
+                        type_condition: type_condition.clone(),
+                    })
+                })
+                .collect::<Vec<_>>()
+        })
+        .prop_flat_map(move |fragments| {
+            let ctx = Arc::new(Context {
+                allow_variables: true,
+                fragments: fragments
+                    .iter()
+                    .map(|frag| (frag.name.clone(), frag.type_condition.clone()))
+                    .collect(),
+                schema: schema.clone(),
+            });
+            (
+                prop::collection::vec(arb_operation(ctx, depth), 1..4),
+                Just(fragments),
+            )
+        })
+        .prop_map(|(operations, fragments)| render_document(&operations, &fragments))
+        .boxed()
+}
+
+struct Context {
+    /// Whether arguments may be passed as variables. Fragment
+    /// definitions never use variables, since every operation that
+    /// spreads them would have to define them.
+    allow_variables: bool,
+
+    /// `(name, type condition)` of each fragment that may be spread.
+    fragments: Vec<(String, String)>,
+
+    schema: Arc<Schema>,
+}
+
+#[derive(Clone, Debug)]
+enum Argument {
+    Literal(String),
+    /// A variable of the given type.
+    Variable(String),
+}
+
+#[derive(Clone, Debug)]
+struct FragmentDef {
+    name: String,
+    selection_set: Vec<Selection>,
+    type_condition: String,
+}
+
+#[derive(Clone, Debug)]
+struct OperationDef {
+    kind: OperationKind,
+    selection_set: Vec<Selection>,
+}
+
+#[derive(Clone, Debug)]
+enum Selection {
+    Field {
+        alias: Option<String>,
+        arguments: Vec<(String, Argument)>,
+        directive: Option<&'static str>,
+        name: String,
+        selection_set: Vec<Selection>,
+    },
+    FragmentSpread(String),
+    InlineFragment {
+        selection_set: Vec<Selection>,
+        type_condition: String,
+    },
+}
+
+impl Selection {
+    fn typename() -> Self {
+        Selection::Field {
+            alias: None,
+            arguments: vec![],
+            directive: None,
+            name: "__typename".to_string(),
+            selection_set: vec![],
+        }
+    }
+}
+
+fn arb_operation(ctx: Arc<Context>, depth: usize) -> BoxedStrategy<OperationDef> {
+    let schema = &ctx.schema;
+    let mut kinds = vec![OperationKind::Query];
+    if schema.mutation_type().is_some() {
+        kinds.push(OperationKind::Mutation);
+    }
+    if let Some(subscription_type) = schema.subscription_type()
+        && !selectable_fields(subscription_type).is_empty()
+    {
+        kinds.push(OperationKind::Subscription);
+    }
+    prop::sample::select(kinds)
+        .prop_flat_map(move |kind| {
+            let schema = &ctx.schema;
+            let selection_set = match kind {
+                OperationKind::Query => arb_selection_set(
+                    ctx.clone(),
+                    schema.query_type().name().to_string(),
+                    depth,
+                    None,
+                ),
+                OperationKind::Mutation => arb_selection_set(
+                    ctx.clone(),
+                    schema.mutation_type().unwrap().name().to_string(),
+                    depth,
+                    None,
+                ),
+                // Subscriptions must select exactly one root field, and
+                // may not apply `@skip`/`@include` to it.
+                OperationKind::Subscription => {
+                    let root_type = schema.subscription_type().unwrap();
+                    let root_name = root_type.name().to_string();
+                    let ctx = ctx.clone();
+                    prop::sample::select(selectable_fields(root_type))
+                        .prop_flat_map(move |field_name| {
+                            arb_field(ctx.clone(), &root_name, field_name, depth, None)
+                        })
+                        .prop_map(|mut field| {
+                            if let Selection::Field { directive, .. } = &mut field {
+                                *directive = None;
+                            }
+                            vec![field]
+                        })
+                        .boxed()
+                },
+            };
+            selection_set.prop_map(move |selection_set| OperationDef {
+                kind: kind.clone(),
+                selection_set,
+            })
+        })
+        .boxed()
+}
+
+/// Generates a non-empty selection set on `parent_type`.
+///
+/// At `depth` 0 only leaf fields and `__typename` are selected (plus,
+/// for unions, inline fragments on member types).
+///
+/// Fields selected directly in this selection set are aliased as
+/// `{alias_prefix}_{field}` when `alias_prefix` is set. Fragment
+/// bodies pass a prefix unique to the fragment, so their fields can
+/// never conflict with differently-argumented selections of the same
+/// field elsewhere in the merged selection set.
+fn arb_selection_set(
+    ctx: Arc<Context>,
+    parent_type: String,
+    depth: usize,
+    alias_prefix: Option<String>,
+) -> BoxedStrategy<Vec<Selection>> {
+    let schema = &ctx.schema;
+    let graphql_type = schema.all_types().get(&parent_type).unwrap();
+    let is_union = matches!(graphql_type, GraphQLType::Union(_));
+
+    let field_names = selectable_fields(graphql_type)
+        .into_iter()
+        .filter(|name| {
+            depth > 0 || !fields_of(graphql_type).unwrap()[name].requires_selection_set(schema)
+        })
+        .collect::<Vec<_>>();
+    let fields = {
+        let ctx = ctx.clone();
+        let parent_type = parent_type.clone();
+        let alias_prefix = alias_prefix.clone();
+        let max = field_names.len().min(4);
+        prop::sample::subsequence(field_names, 0..=max)
+            .prop_flat_map(move |names| {
+                names
+                    .into_iter()
+                    .map(|name| {
+                        arb_field(ctx.clone(), &parent_type, name, depth, alias_prefix.clone())
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .boxed()
+    };
+
+    // Inline fragments on an object type's own type are only generated
+    // outside of other fragments, to avoid long chains of redundant
+    // `... on T` nesting.
+    let is_object = matches!(graphql_type, GraphQLType::Object(_));
+    let type_conditions = if is_union || (depth > 0 && !(is_object && alias_prefix.is_some())) {
+        possible_types(schema, graphql_type)
+    } else {
+        vec![]
+    };
+    let inline_fragments = {
+        let ctx = ctx.clone();
+        let min = if is_union { 1 } else { 0 };
+        let max = type_conditions.len().min(2);
+        let alias_prefix = alias_prefix.clone();
+        prop::sample::subsequence(type_conditions, min..=max)
+            .prop_flat_map(move |type_conditions| {
+                type_conditions
+                    .into_iter()
+                    .map(|type_condition| {
+                        let prefix = match &alias_prefix {
+                            Some(prefix) => format!("{prefix}On{type_condition}"),
+                            None => format!("on{type_condition}"),
+                        };
+                        arb_selection_set(
+                            ctx.clone(),
+                            type_condition.clone(),
+                            depth.saturating_sub(1),
+                            Some(prefix),
+                        )
+                        .prop_map(move |selection_set| Selection::InlineFragment {
+                            selection_set,
+                            type_condition: type_condition.clone(),
+                        })
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .boxed()
+    };
+
+    // A fragment may be spread where its type condition is the parent
+    // type itself or one of the parent type's possible types.
+    let possible = possible_types(schema, graphql_type);
+    let spreadable = ctx
+        .fragments
+        .iter()
+        .filter(|(_, type_condition)| {
+            *type_condition == parent_type || possible.contains(type_condition)
+        })
+        .map(|(name, _)| name.clone())
+        .collect::<Vec<_>>();
+    let max_spreads = spreadable.len().min(2);
+    let spreads = prop::sample::subsequence(spreadable, 0..=max_spreads);
+
+    let typename = if is_union {
+        Just(false).boxed()
+    } else {
+        prop::bool::weighted(0.2).boxed()
+    };
+
+    (fields, inline_fragments, spreads, typename)
+        .prop_map(|(fields, inline_fragments, spreads, typename)| {
+            let mut selections = fields;
+            if typename {
+                selections.push(Selection::typename());
+            }
+            selections.extend(spreads.into_iter().map(Selection::FragmentSpread));
+            selections.extend(inline_fragments);
+            if selections.is_empty() {
+                selections.push(Selection::typename());
+            }
+            selections
+        })
+        .boxed()
+}
+
+/// Generates a selection of `field_name` on `parent_type`, including
+/// its arguments and (for composite-typed fields) a selection set one
+/// level deeper.
+fn arb_field(
+    ctx: Arc<Context>,
+    parent_type: &str,
+    field_name: String,
+    depth: usize,
+    alias_prefix: Option<String>,
+) -> BoxedStrategy<Selection> {
+    let schema = &ctx.schema;
+    let graphql_type = schema.all_types().get(parent_type).unwrap();
+    let field = &fields_of(graphql_type).unwrap()[&field_name];
+
+    let arguments = field
+        .parameters()
+        .iter()
+        .map(|(name, param)| {
+            let type_annot = param.type_annotation();
+            let literal = arb_input_value(schema, type_annot, 2).prop_map(Argument::Literal);
+            let value = if ctx.allow_variables {
+                let type_str = type_annotation_string(type_annot);
+                prop_oneof![
+                    4 => literal,
+                    1 => Just(Argument::Variable(type_str)),
+                ]
+                .boxed()
+            } else {
+                literal.boxed()
+            };
+            let name = name.clone();
+            let arg = value.prop_map(move |value| (name.clone(), value));
+            if type_annot.nullable() || param.default_value().is_some() {
+                prop::option::of(arg).boxed()
+            } else {
+                arg.prop_map(Some).boxed()
+            }
+        })
+        .collect::<Vec<_>>()
+        .prop_map(|args| args.into_iter().flatten().collect::<Vec<_>>());
+
+    let selection_set = if field.requires_selection_set(schema) {
+        let inner_type = field.type_annotation()
+            .innermost_named_type_annotation()
+            .graphql_type_name()
+            .to_string();
+        arb_selection_set(ctx.clone(), inner_type, depth.saturating_sub(1), None)
+    } else {
+        Just(vec![]).boxed()
+    };
+
+    let directive = prop::option::weighted(
+        0.1,
+        prop::sample::select(vec!["@include(if: true)", "@skip(if: false)"]),
+    );
+
+    let alias = alias_prefix.map(|prefix| format!("{prefix}_{field_name}"));
+    (arguments, directive, selection_set)
+        .prop_map(move |(arguments, directive, selection_set)| Selection::Field {
+            alias: alias.clone(),
+            arguments,
+            directive,
+            name: field_name.clone(),
+            selection_set,
+        })
+        .boxed()
+}
+
+/// Renders `type_annot` in GraphQL syntax (e.g. `[Int!]`).
+fn type_annotation_string(type_annot: &TypeAnnotation) -> String {
+    let non_null = if type_annot.nullable() { "" } else { "!" };
+    match type_annot {
+        TypeAnnotation::List(list_annot) => format!(
+            "[{}]{non_null}",
+            type_annotation_string(list_annot.inner_type_annotation()),
+        ),
+        TypeAnnotation::Named(named_annot) => {
+            format!("{}{non_null}", named_annot.graphql_type_name())
+        },
+    }
+}
+
+/// Returns a lowercase-initial alias prefix derived from a fragment
+/// name.
+fn alias_prefix_for(fragment_name: &str) -> String {
+    let mut chars = fragment_name.chars();
+    match chars.next() {
+        Some(first) => first.to_lowercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Names of the object, interface, and union types in `schema`,
+/// sorted for deterministic generation.
+fn composite_type_names(schema: &Schema) -> Vec<String> {
+    let mut names = schema
+        .all_types()
+        .iter()
+        .filter(|(_, graphql_type)| graphql_type.requires_selection_set())
+        .map(|(name, _)| name.clone())
+        .collect::<Vec<_>>();
+    names.sort();
+    names
+}
+
+fn fields_of(graphql_type: &GraphQLType) -> Option<&IndexMap<String, Field>> {
+    match graphql_type {
+        GraphQLType::Interface(iface) => Some(iface.fields()),
+        GraphQLType::Object(obj) => Some(obj.fields()),
+        _ => None,
+    }
+}
+
+/// The non-introspection fields of `graphql_type`, in definition
+/// order.
+fn selectable_fields(graphql_type: &GraphQLType) -> Vec<String> {
+    fields_of(graphql_type)
+        .map(|fields| {
+            fields
+                .keys()
+                .filter(|name| !name.starts_with("__"))
+                .cloned()
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// The object types that `graphql_type` may resolve to, sorted.
+fn possible_types(schema: &Schema, graphql_type: &GraphQLType) -> Vec<String> {
+    let mut names = match graphql_type {
+        GraphQLType::Interface(iface) => schema
+            .all_types()
+            .values()
+            .filter_map(|graphql_type| graphql_type.as_object())
+            .filter(|obj| obj.implements_interface(schema, iface))
+            .map(|obj| obj.name().to_string())
+            .collect(),
+        GraphQLType::Object(obj) => vec![obj.name().to_string()],
+        GraphQLType::Union(union_type) => union_type
+            .member_type_names()
+            .into_iter()
+            .map(str::to_string)
+            .collect(),
+        _ => vec![],
+    };
+    names.sort();
+    names
+}
+
+// =============================================================================
+// Rendering
+// =============================================================================
+
+fn render_document(operations: &[OperationDef], fragments: &[FragmentDef]) -> String {
+    let mut out = String::new();
+    let mut used_fragments = BTreeSet::new();
+    for (idx, operation) in operations.iter().enumerate() {
+        collect_spreads(&operation.selection_set, &mut used_fragments);
+
+        let mut variables = vec![];
+        let mut body = String::new();
+        render_selection_set(&mut body, &operation.selection_set, 0, &mut variables);
+
+        let (keyword, name) = match operation.kind {
+            OperationKind::Mutation => ("mutation", "Mutation"),
+            OperationKind::Query => ("query", "Query"),
+            OperationKind::Subscription => ("subscription", "Subscription"),
+        };
+        out.push_str(&format!("{keyword} {name}{idx}"));
+        if !variables.is_empty() {
+            let defs = variables
+                .iter()
+                .enumerate()
+                .map(|(var_idx, type_str)| format!("$var{var_idx}: {type_str}"))
+                .collect::<Vec<_>>();
+            out.push_str(&format!("({})", defs.join(", ")));
+        }
+        out.push(' ');
+        out.push_str(&body);
+        out.push_str("\n\n");
+    }
+    for fragment in fragments {
+        if !used_fragments.contains(&fragment.name) {
+            continue;
+        }
+        let mut body = String::new();
+        render_selection_set(&mut body, &fragment.selection_set, 0, &mut vec![]);
+        out.push_str(&format!(
+            "fragment {} on {} {body}\n\n",
+            fragment.name,
+            fragment.type_condition,
+        ));
+    }
+    out
+}
+
+fn collect_spreads(selections: &[Selection], used: &mut BTreeSet<String>) {
+    for selection in selections {
+        match selection {
+            Selection::Field { selection_set, .. }
+            | Selection::InlineFragment { selection_set, .. } => {
+                collect_spreads(selection_set, used)
+            },
+            Selection::FragmentSpread(name) => {
+                used.insert(name.clone());
+            },
+        }
+    }
+}
+
+/// Renders `selections` as an indented selection set. Each variable
+/// argument is assigned the next `$varN` name, and its type is
+/// appended to `variables`.
+fn render_selection_set(
+    out: &mut String,
+    selections: &[Selection],
+    indent: usize,
+    variables: &mut Vec<String>,
+) {
+    let pad = "  ".repeat(indent + 1);
+    out.push_str("{\n");
+    for selection in selections {
+        out.push_str(&pad);
+        match selection {
+            Selection::Field { alias, arguments, directive, name, selection_set } => {
+                if let Some(alias) = alias {
+                    out.push_str(&format!("{alias}: "));
+                }
+                out.push_str(name);
+                if !arguments.is_empty() {
+                    let arguments = arguments
+                        .iter()
+                        .map(|(arg_name, value)| match value {
+                            Argument::Literal(literal) => format!("{arg_name}: {literal}"),
+                            Argument::Variable(type_str) => {
+                                let var_idx = variables.len();
+                                variables.push(type_str.clone());
+                                format!("{arg_name}: $var{var_idx}")
+                            },
+                        })
+                        .collect::<Vec<_>>();
+                    out.push_str(&format!("({})", arguments.join(", ")));
+                }
+                if let Some(directive) = directive {
+                    out.push_str(&format!(" {directive}"));
+                }
+                if !selection_set.is_empty() {
+                    out.push(' ');
+                    render_selection_set(out, selection_set, indent + 1, variables);
+                }
+            },
+            Selection::FragmentSpread(name) => out.push_str(&format!("...{name}")),
+            Selection::InlineFragment { selection_set, type_condition } => {
+                out.push_str(&format!("... on {type_condition} "));
+                render_selection_set(out, selection_set, indent + 1, variables);
+            },
+        }
+        out.push('\n');
+    }
+    out.push_str(&"  ".repeat(indent));
+    out.push('}');
+}
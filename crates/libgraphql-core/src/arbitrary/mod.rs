@@ -0,0 +1,55 @@
+//! [`proptest`] strategies that generate *semantically* valid GraphQL
+//! schemas, operations, and values.
+//!
+//! Available with the `arbitrary` cargo feature.
+//!
+//! - [`arb_schema()`] / [`arb_schema_source()`] generate small,
+//!   self-consistent schemas.
+//! - [`arb_executable_document()`] generates executable documents that
+//!   are valid against a given [`Schema`](crate::schema::Schema) —
+//!   whether generated or hand-written.
+//! - [`arb_input_value()`] generates value literals for a given input
+//!   type.
+//!
+//! For generators that only need to produce *syntactically* valid
+//! GraphQL, see `libgraphql_parser::arbitrary`.
+//!
+//! # Example
+//!
+//! ```rust
+//! use libgraphql_core::arbitrary::arb_executable_document;
+//! use libgraphql_core::arbitrary::arb_schema;
+//! use libgraphql_core::operation::ExecutableDocumentBuilder;
+//! use libgraphql_core::operation::FragmentRegistryBuilder;
+//! use proptest::prelude::*;
+//!
+//! let schema_and_document = arb_schema().prop_flat_map(|schema| {
+//!     let document = arb_executable_document(&schema, 3);
+//!     (Just(schema), document)
+//! });
+//!
+//! proptest!(|((schema, document) in schema_and_document)| {
+//!     let mut fragments = FragmentRegistryBuilder::new();
+//!     fragments.add_from_document_str(&schema, &document, None).unwrap();
+//!     let fragments = fragments.build().unwrap();
+//!     let result = ExecutableDocumentBuilder::from_str(
+//!         &schema,
+//!         &fragments,
+//!         &document,
+//!         None,
+//!     );
+//!     prop_assert!(result.is_ok());
+//! });
+//! ```
+
+mod executable;
+mod schema;
+mod values;
+
+pub use executable::arb_executable_document;
+pub use schema::arb_schema;
+pub use schema::arb_schema_source;
+pub use values::arb_input_value;
+
+#[cfg(test)]
+mod tests;
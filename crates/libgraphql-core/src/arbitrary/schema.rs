@@ -0,0 +1,335 @@
+use crate::schema::Schema;
+use crate::schema::SchemaBuilder;
+use proptest::prelude::*;
+use proptest::strategy::BoxedStrategy;
+
+const BUILTIN_SCALARS: &[&str] = &["Boolean", "Float", "ID", "Int", "String"];
+const SCALAR_NAMES: &[&str] = &["DateTime", "JSON", "URL"];
+const ENUM_NAMES: &[&str] = &["Color", "Role", "Status"];
+const INPUT_OBJECT_NAMES: &[&str] = &["Filter", "OrderBy", "Pagination"];
+const INTERFACE_NAMES: &[&str] = &["Entity", "Named", "Node"];
+const OBJECT_NAMES: &[&str] = &["Comment", "Post", "Product", "Review", "User"];
+const UNION_NAMES: &[&str] = &["FeedItem", "SearchResult"];
+
+// None of these contain `_`, so callers can derive collision-free aliases
+// by adding one (see `arb_executable_document()`).
+const ARGUMENT_NAMES: &[&str] = &[
+    "after", "before", "filter", "first", "id", "includeArchived", "last",
+    "limit", "locale", "offset", "orderBy", "query",
+];
+const ENUM_VALUES: &[&str] = &[
+    "ACTIVE", "ADMIN", "ARCHIVED", "ASC", "DESC", "DRAFT", "EDITOR",
+    "PENDING", "PUBLISHED", "VIEWER",
+];
+const FIELD_NAMES: &[&str] = &[
+    "author", "body", "children", "count", "createdAt", "description",
+    "email", "enabled", "id", "items", "name", "owner", "parent", "price",
+    "rating", "related", "status", "tags", "title", "total", "updatedAt",
+    "url",
+];
+
+/// Generates the SDL for a small, self-consistent schema that
+/// [`SchemaBuilder`] accepts.
+///
+/// Each schema defines a `Query` type and, optionally, `Mutation` and
+/// `Subscription` types, plus a random selection of custom scalars,
+/// enums, input objects, interfaces, object types, and unions. Every
+/// type reference resolves, every object implements its interfaces'
+/// fields exactly, input objects never form cycles, and every union
+/// member is an object type.
+pub fn arb_schema_source() -> BoxedStrategy<String> {
+    (
+        prop::sample::subsequence(SCALAR_NAMES, 0..=1),
+        prop::sample::subsequence(ENUM_NAMES, 0..=2),
+        prop::sample::subsequence(INPUT_OBJECT_NAMES, 0..=2),
+        prop::sample::subsequence(INTERFACE_NAMES, 0..=2),
+        prop::sample::subsequence(OBJECT_NAMES, 1..=4),
+        prop::sample::subsequence(UNION_NAMES, 0..=1),
+        any::<bool>(),
+        any::<bool>(),
+    )
+        .prop_flat_map(|(
+            scalars,
+            enums,
+            input_objects,
+            interfaces,
+            objects,
+            unions,
+            has_mutation,
+            has_subscription,
+        )| {
+            let names = TypeNames {
+                scalars,
+                enums,
+                input_objects,
+                interfaces,
+                objects,
+                unions,
+            };
+            let root_names = std::iter::once("Query")
+                .chain(has_mutation.then_some("Mutation"))
+                .chain(has_subscription.then_some("Subscription"))
+                .collect::<Vec<_>>();
+            (
+                names
+                    .enums
+                    .iter()
+                    .map(|_| arb_enum_values())
+                    .collect::<Vec<_>>(),
+                (0..names.input_objects.len())
+                    .map(|idx| arb_input_fields(FIELD_NAMES, names.input_types(idx)))
+                    .collect::<Vec<_>>(),
+                names
+                    .interfaces
+                    .iter()
+                    .map(|_| arb_fields(&names))
+                    .collect::<Vec<_>>(),
+                names
+                    .objects
+                    .iter()
+                    .map(|_| {
+                        (
+                            prop::sample::subsequence(
+                                names.interfaces.clone(),
+                                0..=names.interfaces.len(),
+                            ),
+                            arb_fields(&names),
+                        )
+                    })
+                    .collect::<Vec<_>>(),
+                names
+                    .unions
+                    .iter()
+                    .map(|_| {
+                        prop::sample::subsequence(
+                            names.objects.clone(),
+                            1..=names.objects.len(),
+                        )
+                    })
+                    .collect::<Vec<_>>(),
+                root_names
+                    .iter()
+                    .map(|_| arb_fields(&names))
+                    .collect::<Vec<_>>(),
+                Just(names),
+                Just(root_names),
+            )
+        })
+        .prop_map(|(
+            enum_values,
+            input_fields,
+            interface_fields,
+            objects,
+            union_members,
+            root_fields,
+            names,
+            root_names,
+        )| {
+            let mut sdl = String::new();
+            for scalar in &names.scalars {
+                sdl.push_str(&format!("scalar {scalar}\n\n"));
+            }
+            for (name, values) in names.enums.iter().zip(&enum_values) {
+                sdl.push_str(&format!("enum {name} {{\n  {}\n}}\n\n", values.join("\n  ")));
+            }
+            for (name, fields) in names.input_objects.iter().zip(&input_fields) {
+                sdl.push_str(&format!("input {name} {{\n"));
+                for (field_name, field_type) in fields {
+                    sdl.push_str(&format!("  {field_name}: {field_type}\n"));
+                }
+                sdl.push_str("}\n\n");
+            }
+            for (name, fields) in names.interfaces.iter().zip(&interface_fields) {
+                sdl.push_str(&format!("interface {name} {{\n"));
+                push_fields(&mut sdl, fields);
+                sdl.push_str("}\n\n");
+            }
+            for (name, (candidate_interfaces, own_fields)) in names.objects.iter().zip(&objects) {
+                // Implement each candidate interface whose fields don't
+                // conflict with those of an interface already implemented,
+                // then add the object's own fields on top.
+                let mut implemented = vec![];
+                let mut fields: Vec<FieldDef> = vec![];
+                for iface in candidate_interfaces {
+                    let iface_idx = names.interfaces.iter().position(|i| i == iface).unwrap();
+                    let iface_fields = &interface_fields[iface_idx];
+                    let conflicts = iface_fields.iter().any(|iface_field| {
+                        fields.iter().any(|field| {
+                            field.name == iface_field.name && field != iface_field
+                        })
+                    });
+                    if conflicts {
+                        continue;
+                    }
+                    implemented.push(*iface);
+                    for iface_field in iface_fields {
+                        if !fields.iter().any(|field| field.name == iface_field.name) {
+                            fields.push(iface_field.clone());
+                        }
+                    }
+                }
+                for own_field in own_fields {
+                    if !fields.iter().any(|field| field.name == own_field.name) {
+                        fields.push(own_field.clone());
+                    }
+                }
+                let implements = if implemented.is_empty() {
+                    String::new()
+                } else {
+                    format!(" implements {}", implemented.join(" & "))
+                };
+                sdl.push_str(&format!("type {name}{implements} {{\n"));
+                push_fields(&mut sdl, &fields);
+                sdl.push_str("}\n\n");
+            }
+            for (name, members) in names.unions.iter().zip(&union_members) {
+                sdl.push_str(&format!("union {name} = {}\n\n", members.join(" | ")));
+            }
+            for (name, fields) in root_names.iter().zip(&root_fields) {
+                sdl.push_str(&format!("type {name} {{\n"));
+                push_fields(&mut sdl, fields);
+                sdl.push_str("}\n\n");
+            }
+            sdl
+        })
+        .boxed()
+}
+
+/// Generates a small, self-consistent [`Schema`].
+///
+/// See [`arb_schema_source()`] for the shape of the generated schemas.
+pub fn arb_schema() -> BoxedStrategy<Schema> {
+    arb_schema_source()
+        .prop_map(|sdl| {
+            SchemaBuilder::build_from_str(None, &sdl).unwrap_or_else(|err| {
+                panic!("Generated schema failed to build: {err:?}\n\n{sdl}")
+            })
+        })
+        .boxed()
+}
+
+/// The names of the non-root types defined by a generated schema.
+#[derive(Clone, Debug)]
+struct TypeNames {
+    enums: Vec<&'static str>,
+    input_objects: Vec<&'static str>,
+    interfaces: Vec<&'static str>,
+    objects: Vec<&'static str>,
+    scalars: Vec<&'static str>,
+    unions: Vec<&'static str>,
+}
+
+impl TypeNames {
+    /// Named types usable as field argument types.
+    ///
+    /// Passing `input_object_idx` restricts input objects to those
+    /// defined before it, which keeps input objects acyclic.
+    fn input_types(&self, input_object_idx: usize) -> Vec<&'static str> {
+        BUILTIN_SCALARS
+            .iter()
+            .chain(&self.scalars)
+            .chain(&self.enums)
+            .chain(&self.input_objects[..input_object_idx])
+            .copied()
+            .collect()
+    }
+
+    /// Named types usable as field types.
+    fn output_types(&self) -> Vec<&'static str> {
+        BUILTIN_SCALARS
+            .iter()
+            .chain(&self.scalars)
+            .chain(&self.enums)
+            .chain(&self.interfaces)
+            .chain(&self.objects)
+            .chain(&self.unions)
+            .copied()
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct FieldDef {
+    arguments: Vec<(String, String)>,
+    name: String,
+    type_annotation: String,
+}
+
+fn push_fields(sdl: &mut String, fields: &[FieldDef]) {
+    for field in fields {
+        let arguments = if field.arguments.is_empty() {
+            String::new()
+        } else {
+            let arguments = field.arguments
+                .iter()
+                .map(|(name, type_annotation)| format!("{name}: {type_annotation}"))
+                .collect::<Vec<_>>();
+            format!("({})", arguments.join(", "))
+        };
+        sdl.push_str(&format!("  {}{arguments}: {}\n", field.name, field.type_annotation));
+    }
+}
+
+fn arb_enum_values() -> BoxedStrategy<Vec<&'static str>> {
+    prop::sample::subsequence(ENUM_VALUES, 1..5).boxed()
+}
+
+/// Wraps `named_type` in random list and non-null modifiers.
+fn arb_type_annotation(named_type: BoxedStrategy<String>) -> BoxedStrategy<String> {
+    (named_type, any::<bool>(), prop::option::weighted(0.3, any::<bool>()))
+        .prop_map(|(name, non_null, list)| {
+            let named = if non_null { format!("{name}!") } else { name };
+            match list {
+                Some(true) => format!("[{named}]!"),
+                Some(false) => format!("[{named}]"),
+                None => named,
+            }
+        })
+        .boxed()
+}
+
+/// Generates input object fields (or, with `ARGUMENT_NAMES`,
+/// arguments) whose types are drawn from `input_types`.
+fn arb_input_fields(
+    names: &'static [&'static str],
+    input_types: Vec<&'static str>,
+) -> BoxedStrategy<Vec<(String, String)>> {
+    let field_type = arb_type_annotation(
+        prop::sample::select(input_types).prop_map(str::to_string).boxed(),
+    );
+    (
+        prop::sample::subsequence(names, 1..5),
+        prop::collection::vec(field_type, 4),
+    )
+        .prop_map(|(names, types)| {
+            names.into_iter().map(str::to_string).zip(types).collect()
+        })
+        .boxed()
+}
+
+fn arb_fields(names: &TypeNames) -> BoxedStrategy<Vec<FieldDef>> {
+    let input_types = names.input_types(names.input_objects.len());
+    let output_type = arb_type_annotation(
+        prop::sample::select(names.output_types()).prop_map(str::to_string).boxed(),
+    );
+    let field = (
+        prop::option::weighted(0.3, arb_input_fields(ARGUMENT_NAMES, input_types)),
+        output_type,
+    );
+    (
+        prop::sample::subsequence(FIELD_NAMES, 1..5),
+        prop::collection::vec(field, 4),
+    )
+        .prop_map(|(names, fields)| {
+            names
+                .into_iter()
+                .zip(fields)
+                .map(|(name, (arguments, type_annotation))| FieldDef {
+                    arguments: arguments.unwrap_or_default(),
+                    name: name.to_string(),
+                    type_annotation,
+                })
+                .collect()
+        })
+        .boxed()
+}
@@ -0,0 +1,109 @@
+use crate::arbitrary::arb_executable_document;
+use crate::arbitrary::arb_schema;
+use crate::arbitrary::arb_schema_source;
+use crate::operation::ExecutableDocumentBuilder;
+use crate::operation::FragmentRegistryBuilder;
+use crate::schema::Schema;
+use crate::schema::SchemaBuilder;
+use proptest::prelude::*;
+
+const HAND_WRITTEN_SCHEMA: &str = r#"
+    scalar DateTime
+
+    enum Role { ADMIN EDITOR VIEWER }
+
+    input Pagination { first: Int!, after: String }
+    input UserFilter { role: Role, createdAfter: DateTime, page: Pagination }
+
+    interface Node { id: ID! }
+
+    type User implements Node {
+        id: ID!
+        name: String!
+        role: Role
+        friends(page: Pagination!, filter: UserFilter): [User!]!
+        posts(first: Int = 10): [Post!]!
+    }
+
+    type Post implements Node {
+        id: ID!
+        title: String!
+        author: User!
+        tags: [String!]
+    }
+
+    union SearchResult = User | Post
+
+    type Query {
+        node(id: ID!): Node
+        search(text: String!, roles: [Role!]): [SearchResult!]!
+        me: User
+    }
+
+    type Mutation {
+        createPost(title: String!, tags: [String!]): Post
+    }
+
+    type Subscription {
+        postCreated(authorId: ID): Post!
+    }
+"#;
+
+fn config() -> ProptestConfig {
+    ProptestConfig {
+        cases: 64,
+        ..Default::default()
+    }
+}
+
+/// Builds `document` against `schema`, registering the fragments it
+/// defines, and fails the test case on any error.
+fn assert_builds(schema: &Schema, document: &str) -> Result<(), TestCaseError> {
+    let mut fragments = FragmentRegistryBuilder::new();
+    let added = fragments.add_from_document_str(schema, document, None);
+    prop_assert!(added.is_ok(), "{added:?}\n\n{document}");
+    let fragments = fragments.build();
+    prop_assert!(fragments.is_ok(), "{fragments:?}\n\n{document}");
+    let fragments = fragments.unwrap();
+    let built = ExecutableDocumentBuilder::from_str(schema, &fragments, document, None)
+        .and_then(|builder| builder.build());
+    prop_assert!(built.is_ok(), "{:?}\n\n{document}", built.err());
+    Ok(())
+}
+
+proptest! {
+    #![proptest_config(config())]
+
+    /// Every generated schema builds.
+    #[test]
+    fn generated_schemas_build(sdl in arb_schema_source()) {
+        let result = SchemaBuilder::build_from_str(None, &sdl);
+        prop_assert!(result.is_ok(), "{:?}\n\n{sdl}", result.err());
+    }
+
+    /// Documents generated against a generated schema build against
+    /// that schema.
+    #[test]
+    fn documents_build_against_generated_schemas(
+        (schema, document) in arb_schema().prop_flat_map(|schema| {
+            let document = arb_executable_document(&schema, 3);
+            (Just(schema), document)
+        }),
+    ) {
+        assert_builds(&schema, &document)?;
+    }
+}
+
+/// Documents generated against a hand-written schema (exercising
+/// unions, interfaces, required and list arguments, input objects,
+/// and all three operation kinds) build against that schema.
+#[test]
+fn documents_build_against_hand_written_schema() {
+    let schema = SchemaBuilder::build_from_str(None, HAND_WRITTEN_SCHEMA).unwrap();
+    let mut runner = proptest::test_runner::TestRunner::new(config());
+    runner
+        .run(&arb_executable_document(&schema, 4), |document| {
+            assert_builds(&schema, &document)
+        })
+        .unwrap();
+}
@@ -0,0 +1,121 @@
+use crate::schema::Schema;
+use crate::types::GraphQLType;
+use crate::types::TypeAnnotation;
+use proptest::prelude::*;
+use proptest::strategy::BoxedStrategy;
+
+/// Generates a constant GraphQL value literal that is valid for
+/// `type_annotation` in `schema`.
+///
+/// `depth` bounds how deeply lists and input objects nest. Once it
+/// reaches 0, nullable positions are filled with `null`, lists are
+/// empty, and input objects only set their required (non-null) fields.
+///
+/// Custom scalars accept any literal, so they are given string values.
+pub fn arb_input_value(
+    schema: &Schema,
+    type_annotation: &TypeAnnotation,
+    depth: usize,
+) -> BoxedStrategy<String> {
+    arb_value(schema, type_annotation, type_annotation.nullable(), depth)
+}
+
+/// Like [`arb_input_value()`], but only generates `null` if `nullable`,
+/// regardless of whether `type_annotation` is nullable.
+fn arb_value(
+    schema: &Schema,
+    type_annotation: &TypeAnnotation,
+    nullable: bool,
+    depth: usize,
+) -> BoxedStrategy<String> {
+    if nullable && depth == 0 {
+        return Just("null".to_string()).boxed();
+    }
+    let non_null_value = match type_annotation {
+        TypeAnnotation::List(_) if depth == 0 => Just("[]".to_string()).boxed(),
+        TypeAnnotation::List(list_annot) => {
+            let inner = list_annot.inner_type_annotation();
+            let item = arb_input_value(schema, inner, depth - 1);
+            prop_oneof![
+                // Input coercion wraps a lone item in a list, but a lone
+                // `null` is a null list rather than a list of one `null`.
+                1 => arb_value(schema, inner, false, depth - 1),
+                3 => prop::collection::vec(item, 0..3)
+                    .prop_map(|items| format!("[{}]", items.join(", "))),
+            ]
+            .boxed()
+        },
+        TypeAnnotation::Named(named_annot) => arb_named_input_value(
+            schema,
+            named_annot.graphql_type(schema),
+            depth,
+        ),
+    };
+    if nullable {
+        prop_oneof![
+            1 => Just("null".to_string()),
+            9 => non_null_value,
+        ]
+        .boxed()
+    } else {
+        non_null_value
+    }
+}
+
+fn arb_named_input_value(
+    schema: &Schema,
+    graphql_type: &GraphQLType,
+    depth: usize,
+) -> BoxedStrategy<String> {
+    match graphql_type {
+        GraphQLType::Bool => any::<bool>().prop_map(|b| b.to_string()).boxed(),
+        GraphQLType::Float => (-1.0e6..1.0e6f64).prop_map(|f| format!("{f:?}")).boxed(),
+        GraphQLType::ID => prop_oneof![
+            arb_string_value(),
+            (0..100_000i32).prop_map(|i| i.to_string()),
+        ]
+        .boxed(),
+        GraphQLType::Int => any::<i32>().prop_map(|i| i.to_string()).boxed(),
+        GraphQLType::Scalar(_) | GraphQLType::String => arb_string_value(),
+        GraphQLType::Enum(enum_type) => {
+            let values = enum_type.values().keys().cloned().collect::<Vec<_>>();
+            prop::sample::select(values).boxed()
+        },
+        GraphQLType::InputObject(input_type) => {
+            let fields = input_type
+                .fields()
+                .iter()
+                .map(|(name, field)| {
+                    let annot = field.type_annotation();
+                    let value = arb_input_value(schema, annot, depth.saturating_sub(1))
+                        .prop_map({
+                            let name = name.clone();
+                            move |value| format!("{name}: {value}")
+                        });
+                    if !annot.nullable() {
+                        value.prop_map(Some).boxed()
+                    } else if depth == 0 {
+                        Just(None).boxed()
+                    } else {
+                        prop::option::of(value).boxed()
+                    }
+                })
+                .collect::<Vec<_>>();
+            fields
+                .prop_map(|fields| {
+                    let fields = fields.into_iter().flatten().collect::<Vec<_>>();
+                    format!("{{{}}}", fields.join(", "))
+                })
+                .boxed()
+        },
+        GraphQLType::Interface(_) | GraphQLType::Object(_) | GraphQLType::Union(_) => {
+            panic!("`{}` is not an input type", graphql_type.name())
+        },
+    }
+}
+
+fn arb_string_value() -> BoxedStrategy<String> {
+    "[a-zA-Z0-9 _-]{0,16}"
+        .prop_map(|s| format!("\"{s}\""))
+        .boxed()
+}
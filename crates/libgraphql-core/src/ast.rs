@@ -79,4 +79,36 @@ pub mod serde_adapters {
             value.0.into()
         }
     }
+
+    /// Serializes a list of
+    /// [`DirectiveLocation`](crate::ast::schema::DirectiveLocation)s as their
+    /// GraphQL names (e.g. `"FIELD_DEFINITION"`).
+    pub(crate) struct SerdeDirectiveLocations;
+
+    impl SerdeDirectiveLocations {
+        pub(crate) fn serialize<S: serde::Serializer>(
+            locations: &[crate::ast::schema::DirectiveLocation],
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            serializer.collect_seq(locations.iter().map(|location| location.as_str()))
+        }
+
+        pub(crate) fn deserialize<'de, D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Vec<crate::ast::schema::DirectiveLocation>, D::Error> {
+            use serde::Deserialize;
+            use serde::de::Error;
+            use std::str::FromStr;
+
+            Vec::<String>::deserialize(deserializer)?
+                .iter()
+                .map(|name| {
+                    crate::ast::schema::DirectiveLocation::from_str(name)
+                        .map_err(|_| D::Error::custom(format!(
+                            "unknown directive location `{name}`",
+                        )))
+                })
+                .collect()
+        }
+    }
 }
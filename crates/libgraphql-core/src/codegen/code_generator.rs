@@ -0,0 +1,994 @@
+use crate::ast;
+use crate::codegen::rust_names::rust_identifier;
+use crate::codegen::rust_names::to_pascal_case;
+use crate::codegen::rust_names::to_snake_case;
+use crate::codegen::rust_names::unique_name;
+use crate::codegen::rust_source_writer::RustSourceWriter;
+use crate::file_reader;
+use crate::loc;
+use crate::operation::ExecutableDocument;
+use crate::operation::ExecutableDocumentBuildError;
+use crate::operation::ExecutableDocumentBuilder;
+use crate::operation::FragmentRegistry;
+use crate::operation::FragmentRegistryBuildError;
+use crate::operation::FragmentRegistryBuilder;
+use crate::operation::Operation;
+use crate::operation::Selection;
+use crate::operation::SelectionSet;
+use crate::operation::Variable;
+use crate::schema::Schema;
+use crate::types::EnumType;
+use crate::types::Field;
+use crate::types::GraphQLType;
+use crate::types::InputObjectType;
+use crate::types::ObjectType;
+use crate::types::TypeAnnotation;
+use crate::DirectiveAnnotation;
+use indexmap::IndexMap;
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+use thiserror::Error;
+
+type Result<T> = std::result::Result<T, Vec<CodegenError>>;
+
+const ENUM_DERIVES: &str =
+    "#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, ::serde::Deserialize, ::serde::Serialize)]";
+const STRUCT_DERIVES: &str =
+    "#[derive(Clone, Debug, ::serde::Deserialize, ::serde::Serialize)]";
+
+/// Generates typed Rust code for a set of operations, in the style of
+/// [`graphql_client`](https://docs.rs/graphql_client), after validating them
+/// against a [`Schema`].
+///
+/// Each named operation gets a module (named after the operation, in
+/// `snake_case`) containing:
+///
+///   * `OPERATION_NAME` and `DOCUMENT` constants, where `DOCUMENT` is the
+///     operation's source along with every fragment it (transitively)
+///     spreads, ready to be sent to a server.
+///   * A `Variables` struct with a field for each of the operation's
+///     variables.
+///   * A `ResponseData` struct (and a struct for each nested selection set)
+///     that mirrors the operation's selections. Fields are named after their
+///     alias (if any) and fragments are merged into the structs of the
+///     selection sets they are spread into. Nullable types are mapped to
+///     `Option`, and fields that may be skipped by `@skip`/`@include` are
+///     always `Option`s.
+///
+/// A selection set on an interface or union type becomes an enum with a
+/// variant for each possible object type, distinguished by `__typename`.
+/// Such selection sets must therefore select `__typename` for every possible
+/// type (see [`CodegenError::MissingTypenameSelection`]).
+///
+/// The enum and input object types used by any of the operations are
+/// generated once, at the top of the file, with GraphQL names. Built-in
+/// scalars map to `bool`, `f64`, `i32`, and `String`; custom scalars must be
+/// mapped to a Rust type with [`CodeGenerator::set_scalar_type()`].
+///
+/// All generated types derive `serde`'s `Deserialize` and `Serialize`, so the
+/// crate that includes the generated code must depend on `serde` (with its
+/// `derive` feature).
+///
+/// # Example
+///
+/// ```
+/// use libgraphql_core::codegen::CodeGenerator;
+/// use libgraphql_core::schema::SchemaBuilder;
+///
+/// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let schema = SchemaBuilder::from_str(
+///     None,
+///     "type Query { me: User } type User { id: ID!, displayName: String }",
+/// )?.build()?;
+///
+/// let mut codegen = CodeGenerator::new(&schema);
+/// codegen.add_document_str("query Me { me { id name: displayName } }", None).unwrap();
+///
+/// let source = codegen.generate().unwrap();
+/// assert!(source.contains("pub mod me {"));
+/// assert!(source.contains("pub me: Option<MeMe>,"));
+/// assert!(source.contains("pub name: Option<String>,"));
+/// # Ok(())
+/// # }
+/// # example().unwrap();
+/// ```
+///
+/// In a `build.rs`, the generated file would typically be written to
+/// `OUT_DIR`:
+///
+/// ```no_run
+/// use libgraphql_core::codegen::CodeGenerator;
+/// use libgraphql_core::schema::SchemaBuilder;
+/// use std::path::Path;
+///
+/// let schema = SchemaBuilder::from_file("schema.graphql").unwrap().build().unwrap();
+/// let mut codegen = CodeGenerator::new(&schema);
+/// codegen.add_document_file("src/queries.graphql").unwrap();
+/// codegen.set_scalar_type("DateTime", "::std::string::String");
+///
+/// let out_dir = std::env::var("OUT_DIR").unwrap();
+/// codegen.write_to_file(Path::new(&out_dir).join("graphql.rs")).unwrap();
+/// println!("cargo::rerun-if-changed=schema.graphql");
+/// println!("cargo::rerun-if-changed=src/queries.graphql");
+/// ```
+///
+/// ...and then included into the crate with
+/// `include!(concat!(env!("OUT_DIR"), "/graphql.rs"));`.
+#[derive(Debug)]
+pub struct CodeGenerator<'schema> {
+    documents: Vec<CodegenDocument>,
+    scalar_types: HashMap<String, String>,
+    schema: &'schema Schema,
+}
+
+impl<'schema> CodeGenerator<'schema> {
+    pub fn new(schema: &'schema Schema) -> Self {
+        Self {
+            documents: vec![],
+            scalar_types: HashMap::new(),
+            schema,
+        }
+    }
+
+    /// Add an executable document (operations and/or fragments) to generate
+    /// code for.
+    ///
+    /// Fragments are shared across all added documents, so an operation in
+    /// one document may spread a fragment defined in another.
+    pub fn add_document_ast(
+        &mut self,
+        ast: ast::operation::Document,
+        file_path: Option<&Path>,
+    ) {
+        self.documents.push(CodegenDocument {
+            ast,
+            file_path: file_path.map(Path::to_path_buf),
+        });
+    }
+
+    pub fn add_document_file(&mut self, file_path: impl AsRef<Path>) -> Result<()> {
+        let file_path = file_path.as_ref();
+        let content = file_reader::read_content(file_path)
+            .map_err(|err| vec![CodegenError::DocumentFileReadError(Box::new(err))])?;
+        self.add_document_str(content, Some(file_path))
+    }
+
+    pub fn add_document_str(
+        &mut self,
+        content: impl AsRef<str>,
+        file_path: Option<&Path>,
+    ) -> Result<()> {
+        let ast = ast::operation::parse(content.as_ref())
+            .map_err(|err| vec![CodegenError::ParseError(Arc::new(err))])?;
+        self.add_document_ast(ast, file_path);
+        Ok(())
+    }
+
+    /// Validate every added document against the schema and generate the
+    /// Rust source for all of their operations.
+    pub fn generate(&self) -> Result<String> {
+        let mut fragment_registry_builder = FragmentRegistryBuilder::new();
+        let mut fragment_errors = vec![];
+        for document in &self.documents {
+            let added = fragment_registry_builder.add_from_document_ast(
+                self.schema,
+                &document.ast,
+                document.file_path.as_deref(),
+            );
+            if let Err(mut errors) = added {
+                fragment_errors.append(&mut errors);
+            }
+        }
+        if !fragment_errors.is_empty() {
+            return Err(vec![CodegenError::ExecutableDocumentBuildErrors(vec![
+                ExecutableDocumentBuildError::FragmentValidationErrors(fragment_errors),
+            ])]);
+        }
+        let fragment_registry = fragment_registry_builder
+            .build()
+            .map_err(|errors| vec![CodegenError::FragmentRegistryBuildErrors(errors)])?;
+
+        let mut errors = vec![];
+        let mut executable_documents = vec![];
+        for document in &self.documents {
+            let executable_document = ExecutableDocumentBuilder::from_ast(
+                self.schema,
+                &fragment_registry,
+                &document.ast,
+                document.file_path.as_deref(),
+            ).and_then(|builder| builder.build());
+            match executable_document {
+                Ok(executable_document) => {
+                    executable_documents.push((document, executable_document));
+                },
+                Err(document_errors) => errors.push(
+                    CodegenError::ExecutableDocumentBuildErrors(document_errors),
+                ),
+            }
+        }
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        let fragment_defs: HashMap<&str, &ast::operation::FragmentDefinition> =
+            self.documents
+                .iter()
+                .flat_map(|document| &document.ast.definitions)
+                .filter_map(|def| match def {
+                    ast::operation::Definition::Fragment(frag_def) => {
+                        Some((frag_def.name.as_str(), frag_def))
+                    },
+                    ast::operation::Definition::Operation(_) => None,
+                })
+                .collect();
+
+        let operations = self.named_operations(&executable_documents)?;
+        let mut generation = Generation {
+            enum_names: BTreeSet::new(),
+            errors: vec![],
+            fragment_registry: &fragment_registry,
+            input_object_names: BTreeSet::new(),
+            scalar_types: &self.scalar_types,
+            schema: self.schema,
+            struct_names: HashSet::new(),
+            unmapped_scalar_names: BTreeSet::new(),
+        };
+
+        let mut modules_writer = RustSourceWriter::default();
+        for (module_name, operation_name, operation, op_def) in &operations {
+            let document = operation_document(
+                op_def,
+                &spread_fragment_names(operation.selection_set(), &fragment_registry),
+                &fragment_defs,
+            );
+            generation.write_operation_module(
+                &mut modules_writer,
+                module_name,
+                operation_name,
+                operation,
+                &document,
+            );
+        }
+
+        let mut writer = RustSourceWriter::default();
+        writer.line(
+            "// @generated by libgraphql's `codegen::CodeGenerator`. Do not edit by hand.",
+        );
+        for enum_name in &generation.enum_names {
+            let enum_type = generation.schema.types[*enum_name]
+                .as_enum()
+                .expect("only enum type names are recorded");
+            write_enum(&mut writer, enum_type);
+        }
+        for input_object_name in &generation.input_object_names.clone() {
+            let input_object = generation.schema.types[*input_object_name]
+                .as_input_object()
+                .expect("only input object type names are recorded");
+            generation.write_input_object_struct(&mut writer, input_object);
+        }
+
+        let mut errors = generation.errors;
+        errors.extend(generation.unmapped_scalar_names.iter().map(|scalar_name| {
+            CodegenError::UnmappedCustomScalar {
+                scalar_name: scalar_name.to_string(),
+            }
+        }));
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        let mut source = writer.into_source();
+        let modules_source = modules_writer.into_source();
+        if !modules_source.is_empty() {
+            source.push('\n');
+            source.push_str(&modules_source);
+        }
+        Ok(source)
+    }
+
+    /// Map a custom scalar to the Rust type its values should be
+    /// (de)serialized as (e.g. `"::chrono::DateTime<::chrono::Utc>"`).
+    ///
+    /// Since operation types are generated in nested modules, paths should be
+    /// absolute (starting with `::` or `crate::`). Built-in scalars may be
+    /// remapped as well (e.g. `ID` to a newtype).
+    pub fn set_scalar_type(
+        &mut self,
+        scalar_name: impl AsRef<str>,
+        rust_type: impl AsRef<str>,
+    ) -> &mut Self {
+        self.scalar_types.insert(
+            scalar_name.as_ref().to_string(),
+            rust_type.as_ref().to_string(),
+        );
+        self
+    }
+
+    /// [Generate](Self::generate) the Rust source and write it to `file_path`.
+    pub fn write_to_file(&self, file_path: impl AsRef<Path>) -> Result<()> {
+        let file_path = file_path.as_ref();
+        let source = self.generate()?;
+        std::fs::write(file_path, source).map_err(|err| {
+            vec![CodegenError::OutputFileWriteError {
+                file_path: file_path.to_path_buf(),
+                err: Arc::new(err),
+            }]
+        })
+    }
+
+    /// Pairs each operation with its module name, its name, and its AST,
+    /// returning an error for each anonymous operation and each operation
+    /// whose module name is already taken.
+    fn named_operations<'a>(
+        &self,
+        executable_documents: &'a [(&'a CodegenDocument, ExecutableDocument<'a, 'a>)],
+    ) -> Result<Vec<NamedOperation<'a>>> {
+        let mut errors = vec![];
+        let mut module_locations: HashMap<String, &loc::SourceLocation> = HashMap::new();
+        let mut operations = vec![];
+        for (document, executable_document) in executable_documents {
+            let op_defs = document.ast.definitions.iter().filter_map(|def| match def {
+                ast::operation::Definition::Operation(op_def) => Some(op_def),
+                ast::operation::Definition::Fragment(_) => None,
+            });
+            for (op_def, operation) in op_defs.zip(executable_document.operations()) {
+                let Some(operation_name) = operation.name() else {
+                    errors.push(CodegenError::AnonymousOperation {
+                        location: operation.def_location().clone(),
+                    });
+                    continue;
+                };
+                let module_name = rust_identifier(&to_snake_case(operation_name));
+                if let Some(first_def_location) = module_locations.get(&module_name) {
+                    errors.push(CodegenError::DuplicateOperationModule {
+                        module_name,
+                        first_def_location: (*first_def_location).clone(),
+                        second_def_location: operation.def_location().clone(),
+                    });
+                    continue;
+                }
+                module_locations.insert(module_name.clone(), operation.def_location());
+                operations.push((module_name, operation_name, operation, op_def));
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+        Ok(operations)
+    }
+}
+
+#[derive(Clone, Debug, Error)]
+pub enum CodegenError {
+    #[error("Code can only be generated for named operations")]
+    AnonymousOperation {
+        location: loc::SourceLocation,
+    },
+
+    #[error(
+        "Failure while trying to read an executable document file from disk: {0:?}"
+    )]
+    DocumentFileReadError(Box<file_reader::ReadContentError>),
+
+    #[error(
+        "Multiple operations would generate a module named `{module_name}`"
+    )]
+    DuplicateOperationModule {
+        module_name: String,
+        first_def_location: loc::SourceLocation,
+        second_def_location: loc::SourceLocation,
+    },
+
+    #[error(
+        "Encountered errors while building the executable documents to \
+        generate code for: {0:?}"
+    )]
+    ExecutableDocumentBuildErrors(Vec<ExecutableDocumentBuildError>),
+
+    #[error(
+        "Encountered errors while building the fragment registry: {0:?}"
+    )]
+    FragmentRegistryBuildErrors(Vec<FragmentRegistryBuildError>),
+
+    #[error(
+        "The selection set of `{type_name}` must select `__typename` (without \
+        an alias) when its type is `{possible_type_name}`"
+    )]
+    MissingTypenameSelection {
+        location: loc::SourceLocation,
+        possible_type_name: String,
+        type_name: String,
+    },
+
+    #[error(
+        "Failure while trying to write generated code to `{}`: {err}",
+        file_path.display(),
+    )]
+    OutputFileWriteError {
+        file_path: PathBuf,
+        err: Arc<std::io::Error>,
+    },
+
+    #[error("Error parsing executable document: {0}")]
+    ParseError(Arc<ast::operation::ParseError>),
+
+    #[error(
+        "No Rust type was set for the custom scalar `{scalar_name}` (see \
+        `CodeGenerator::set_scalar_type()`)"
+    )]
+    UnmappedCustomScalar {
+        scalar_name: String,
+    },
+}
+
+#[derive(Debug)]
+struct CodegenDocument {
+    ast: ast::operation::Document,
+    file_path: Option<PathBuf>,
+}
+
+/// An operation's module name, name, validated operation, and AST.
+type NamedOperation<'a> = (
+    String,
+    &'a str,
+    &'a Operation<'a, 'a>,
+    &'a ast::operation::OperationDefinition,
+);
+
+/// A field of a response struct, merging every selection of the same
+/// response key (across fragments) within one selection set.
+struct CollectedField<'a> {
+    /// Whether some selection of this field is not subject to `@skip` or
+    /// `@include`.
+    always_selected: bool,
+    field: &'a Field,
+    /// The location of the first selection of this field.
+    location: &'a loc::SourceLocation,
+    selection_sets: Vec<&'a SelectionSet<'a>>,
+}
+
+type CollectedFields<'a> = IndexMap<&'a str, CollectedField<'a>>;
+
+/// The state of a single [`CodeGenerator::generate()`] call.
+struct Generation<'a> {
+    enum_names: BTreeSet<&'a str>,
+    errors: Vec<CodegenError>,
+    fragment_registry: &'a FragmentRegistry<'a>,
+    input_object_names: BTreeSet<&'a str>,
+    scalar_types: &'a HashMap<String, String>,
+    schema: &'a Schema,
+    /// The struct names taken in the current operation's module.
+    struct_names: HashSet<String>,
+    unmapped_scalar_names: BTreeSet<&'a str>,
+}
+
+impl<'a> Generation<'a> {
+    /// Collects the fields selected by `selection_sets` when the selected
+    /// object has type `object_type`, following the spec's
+    /// [CollectFields()](https://spec.graphql.org/October2021/#CollectFields())
+    /// algorithm.
+    fn collect_fields(
+        &self,
+        object_type: &ObjectType,
+        selection_sets: &[&'a SelectionSet<'a>],
+        always_selected: bool,
+        fields: &mut CollectedFields<'a>,
+    ) {
+        for selection in selection_sets.iter().flat_map(|set| set.selections()) {
+            match selection {
+                Selection::Field(field_selection) => {
+                    let field = fields
+                        .entry(field_selection.selected_name())
+                        .or_insert_with(|| CollectedField {
+                            always_selected: false,
+                            field: field_selection.field(),
+                            location: field_selection.def_location(),
+                            selection_sets: vec![],
+                        });
+                    field.always_selected |=
+                        always_selected && !is_conditional(field_selection.directives());
+                    if let Some(selection_set) = field_selection.selection_set() {
+                        field.selection_sets.push(selection_set);
+                    }
+                },
+                Selection::FragmentSpread(fragment_spread) => {
+                    let fragment = self.fragment_registry
+                        .fragments()
+                        .get(fragment_spread.fragment_name())
+                        .expect("fragment is present in the fragment registry");
+                    if self.type_condition_applies(fragment.type_condition_name(), object_type) {
+                        self.collect_fields(
+                            object_type,
+                            &[fragment.selection_set()],
+                            always_selected && !is_conditional(fragment_spread.directives()),
+                            fields,
+                        );
+                    }
+                },
+                Selection::InlineFragment(inline_fragment) => {
+                    let applies = inline_fragment
+                        .type_condition_name()
+                        .is_none_or(|type_name| self.type_condition_applies(type_name, object_type));
+                    if applies {
+                        self.collect_fields(
+                            object_type,
+                            &[inline_fragment.selection_set()],
+                            always_selected && !is_conditional(inline_fragment.directives()),
+                            fields,
+                        );
+                    }
+                },
+            }
+        }
+    }
+
+    /// Returns the (non-builtin) Rust type for a leaf or input type,
+    /// recording the enum and input object types (and custom scalars) that
+    /// need to be generated (or mapped).
+    fn leaf_type_name(&mut self, graphql_type: &'a GraphQLType, path_prefix: &str) -> String {
+        if let Some(rust_type) = self.scalar_types.get(graphql_type.name()) {
+            return rust_type.clone();
+        }
+        match graphql_type {
+            GraphQLType::Bool => "bool".to_string(),
+            GraphQLType::Float => "f64".to_string(),
+            GraphQLType::ID | GraphQLType::String => "String".to_string(),
+            GraphQLType::Int => "i32".to_string(),
+            GraphQLType::Enum(enum_type) => {
+                self.enum_names.insert(enum_type.name());
+                format!("{path_prefix}{}", rust_identifier(enum_type.name()))
+            },
+            GraphQLType::InputObject(input_object) => {
+                self.record_input_object(input_object);
+                format!("{path_prefix}{}", rust_identifier(input_object.name()))
+            },
+            GraphQLType::Scalar(scalar_type) => {
+                self.unmapped_scalar_names.insert(scalar_type.name());
+                scalar_type.name().to_string()
+            },
+            GraphQLType::Interface(_) | GraphQLType::Object(_) | GraphQLType::Union(_) => {
+                unreachable!("composite types have generated struct names")
+            },
+        }
+    }
+
+    fn named_type(&self, type_annotation: &TypeAnnotation) -> &'a GraphQLType {
+        let type_name = type_annotation.innermost_named_type_annotation().graphql_type_name();
+        self.schema.types
+            .get(type_name)
+            .expect("type is present in schema")
+    }
+
+    /// Returns the object types a value of the (interface or union) type
+    /// `abstract_type` may have, sorted by name.
+    fn possible_types(&self, abstract_type: &GraphQLType) -> Vec<&'a ObjectType> {
+        let mut possible_types: Vec<&'a ObjectType> = match abstract_type {
+            GraphQLType::Interface(interface_type) => self.schema.types
+                .values()
+                .filter_map(GraphQLType::as_object)
+                .filter(|object_type| {
+                    object_type.interface_names().contains(&interface_type.name())
+                })
+                .collect(),
+            GraphQLType::Union(union_type) => union_type
+                .member_type_names()
+                .into_iter()
+                .filter_map(|type_name| self.schema.types.get(type_name)?.as_object())
+                .collect(),
+            _ => unreachable!("only interface and union types have possible types"),
+        };
+        possible_types.sort_by_key(|object_type| object_type.name());
+        possible_types
+    }
+
+    fn record_input_object(&mut self, input_object: &'a InputObjectType) {
+        if !self.input_object_names.insert(input_object.name()) {
+            return;
+        }
+        for input_field in input_object.fields().values() {
+            let named_type = self.named_type(input_field.type_annotation());
+            self.leaf_type_name(named_type, "");
+        }
+    }
+
+    /// Whether a fragment with the type condition `type_name` applies to an
+    /// object of type `object_type`.
+    fn type_condition_applies(&self, type_name: &str, object_type: &ObjectType) -> bool {
+        type_name == object_type.name()
+            || object_type.interface_names().contains(&type_name)
+            || self.schema.types
+                .get(type_name)
+                .and_then(GraphQLType::as_union)
+                .is_some_and(|union_type| {
+                    union_type.member_type_names().contains(&object_type.name())
+                })
+    }
+
+    /// Takes `name` as a struct name in the current module, suffixing it
+    /// with a number if it is already taken.
+    fn unique_struct_name(&mut self, name: String) -> String {
+        unique_name(&mut self.struct_names, name)
+    }
+
+    /// Writes the enum for a selection set on an interface or union type,
+    /// followed by the struct for each of its variants.
+    fn write_abstract_type_enum(
+        &mut self,
+        writer: &mut RustSourceWriter,
+        enum_name: &str,
+        abstract_type: &GraphQLType,
+        field: &CollectedField<'a>,
+    ) {
+        let mut variants = vec![];
+        for object_type in self.possible_types(abstract_type) {
+            let mut fields = IndexMap::new();
+            self.collect_fields(object_type, &field.selection_sets, true, &mut fields);
+            let typename = fields.shift_remove("__typename");
+            if typename.is_none_or(|typename| typename.field.name() != "__typename") {
+                self.errors.push(CodegenError::MissingTypenameSelection {
+                    location: field.location.clone(),
+                    possible_type_name: object_type.name().to_string(),
+                    type_name: abstract_type.name().to_string(),
+                });
+                continue;
+            }
+            let struct_name = self.unique_struct_name(format!(
+                "{enum_name}On{}",
+                to_pascal_case(object_type.name()),
+            ));
+            variants.push((object_type.name(), struct_name, fields));
+        }
+
+        writer.separate_item();
+        writer.line(STRUCT_DERIVES);
+        writer.line("#[serde(tag = \"__typename\")]");
+        writer.open_block(format!("pub enum {enum_name}"));
+        for (type_name, struct_name, _) in &variants {
+            let variant_name = rust_identifier(type_name);
+            if variant_name != *type_name {
+                writer.line(format!("#[serde(rename = \"{type_name}\")]"));
+            }
+            writer.line(format!("{variant_name}({struct_name}),"));
+        }
+        writer.close_block();
+
+        for (_, struct_name, fields) in variants {
+            self.write_object_struct(writer, &struct_name, &struct_name, fields);
+        }
+    }
+
+    fn write_input_object_struct(
+        &mut self,
+        writer: &mut RustSourceWriter,
+        input_object: &'a InputObjectType,
+    ) {
+        writer.separate_item();
+        writer.doc_comment(input_object.description());
+        writer.line(STRUCT_DERIVES);
+        writer.open_block(format!("pub struct {}", rust_identifier(input_object.name())));
+        let mut field_names = HashSet::new();
+        for input_field in input_object.fields().values() {
+            let type_annotation = input_field.type_annotation();
+            let named_type = self.named_type(type_annotation);
+            let mut leaf_type = self.leaf_type_name(named_type, "");
+            // A (non-list) field whose type leads back to this input object
+            // would make it infinitely sized.
+            if let (TypeAnnotation::Named(_), GraphQLType::InputObject(field_input_object)) =
+                (type_annotation, named_type)
+                && input_object_reaches(
+                    self.schema,
+                    field_input_object,
+                    input_object.name(),
+                    &mut HashSet::new(),
+                )
+            {
+                leaf_type = format!("Box<{leaf_type}>");
+            }
+            write_field(
+                writer,
+                &mut field_names,
+                input_field.name(),
+                input_field.description(),
+                &rust_type(type_annotation, &leaf_type),
+                false,
+            );
+        }
+        writer.close_block();
+    }
+
+    /// Writes a response struct with the given (collected) fields, followed
+    /// by the types for each of their selection sets (named by prefixing
+    /// each field's response key with `child_name_prefix`).
+    fn write_object_struct(
+        &mut self,
+        writer: &mut RustSourceWriter,
+        struct_name: &str,
+        child_name_prefix: &str,
+        fields: CollectedFields<'a>,
+    ) {
+        writer.separate_item();
+        writer.line(STRUCT_DERIVES);
+        if fields.is_empty() {
+            writer.line(format!("pub struct {struct_name} {{}}"));
+            return;
+        }
+
+        let mut children = vec![];
+        let mut field_names = HashSet::new();
+        writer.open_block(format!("pub struct {struct_name}"));
+        for (response_key, field) in fields {
+            let type_annotation = field.field.type_annotation();
+            let named_type = self.named_type(type_annotation);
+            let leaf_type = if named_type.requires_selection_set() {
+                self.unique_struct_name(format!(
+                    "{child_name_prefix}{}",
+                    to_pascal_case(response_key),
+                ))
+            } else {
+                self.leaf_type_name(named_type, "super::")
+            };
+            let mut field_type = rust_type(type_annotation, &leaf_type);
+            // A skipped field is missing from the response entirely.
+            if !field.always_selected && !type_annotation.nullable() {
+                field_type = format!("Option<{field_type}>");
+            }
+            write_field(
+                writer,
+                &mut field_names,
+                response_key,
+                field.field.description(),
+                &field_type,
+                false,
+            );
+            if named_type.requires_selection_set() {
+                children.push((leaf_type, named_type, field));
+            }
+        }
+        writer.close_block();
+
+        for (child_name, named_type, field) in children {
+            match named_type {
+                GraphQLType::Object(object_type) => {
+                    let mut child_fields = IndexMap::new();
+                    self.collect_fields(object_type, &field.selection_sets, true, &mut child_fields);
+                    self.write_object_struct(writer, &child_name, &child_name, child_fields);
+                },
+                _ => self.write_abstract_type_enum(writer, &child_name, named_type, &field),
+            }
+        }
+    }
+
+    fn write_operation_module(
+        &mut self,
+        writer: &mut RustSourceWriter,
+        module_name: &str,
+        operation_name: &str,
+        operation: &'a Operation<'a, 'a>,
+        document: &str,
+    ) {
+        self.struct_names = HashSet::from([
+            "ResponseData".to_string(),
+            "Variables".to_string(),
+        ]);
+
+        writer.separate_item();
+        writer.open_block(format!("pub mod {module_name}"));
+        writer.line(format!("pub const OPERATION_NAME: &str = \"{operation_name}\";"));
+        writer.line(format!("pub const DOCUMENT: &str = {};", raw_string_literal(document)));
+
+        self.write_variables_struct(writer, operation.variables());
+
+        let root_type = operation
+            .root_graphql_type(self.schema)
+            .as_object()
+            .expect("root operation types are object types");
+        let mut fields = IndexMap::new();
+        self.collect_fields(root_type, &[operation.selection_set()], true, &mut fields);
+        self.write_object_struct(
+            writer,
+            "ResponseData",
+            &to_pascal_case(operation_name),
+            fields,
+        );
+        writer.close_block();
+    }
+
+    fn write_variables_struct(
+        &mut self,
+        writer: &mut RustSourceWriter,
+        variables: &'a IndexMap<String, Variable>,
+    ) {
+        writer.separate_item();
+        writer.line(STRUCT_DERIVES);
+        if variables.is_empty() {
+            writer.line("pub struct Variables;");
+            return;
+        }
+
+        let mut field_names = HashSet::new();
+        writer.open_block("pub struct Variables");
+        for variable in variables.values() {
+            let type_annotation = variable.type_annotation();
+            let leaf_type = self.leaf_type_name(self.named_type(type_annotation), "super::");
+            // Sending `null` for a variable would override its default value.
+            let skip_if_none = type_annotation.nullable() && variable.default_value().is_some();
+            write_field(
+                writer,
+                &mut field_names,
+                variable.name(),
+                None,
+                &rust_type(type_annotation, &leaf_type),
+                skip_if_none,
+            );
+        }
+        writer.close_block();
+    }
+}
+
+/// Whether an input object of type `input_object` (transitively) contains a
+/// non-list field of the input object type named `target_name`.
+fn input_object_reaches<'a>(
+    schema: &'a Schema,
+    input_object: &'a InputObjectType,
+    target_name: &str,
+    visited: &mut HashSet<&'a str>,
+) -> bool {
+    if input_object.name() == target_name {
+        return true;
+    }
+    if !visited.insert(input_object.name()) {
+        return false;
+    }
+    input_object.fields().values().any(|input_field| {
+        let TypeAnnotation::Named(named_annotation) = input_field.type_annotation() else {
+            return false;
+        };
+        schema.types
+            .get(named_annotation.graphql_type_name())
+            .and_then(GraphQLType::as_input_object)
+            .is_some_and(|field_input_object| {
+                input_object_reaches(schema, field_input_object, target_name, visited)
+            })
+    })
+}
+
+/// Whether a selection is subject to `@skip` or `@include`.
+fn is_conditional(directives: &[DirectiveAnnotation]) -> bool {
+    directives.iter().any(|directive| {
+        matches!(directive.directive_type_name(), "include" | "skip")
+    })
+}
+
+/// Returns the source of an operation followed by the source of each of the
+/// named fragments.
+fn operation_document(
+    op_def: &ast::operation::OperationDefinition,
+    fragment_names: &[&str],
+    fragment_defs: &HashMap<&str, &ast::operation::FragmentDefinition>,
+) -> String {
+    let mut document = op_def.to_string();
+    for fragment_name in fragment_names {
+        document.push('\n');
+        document.push_str(&fragment_defs[fragment_name].to_string());
+    }
+    document
+}
+
+/// Returns `text` as a raw string literal, with as many `#`s as it takes to
+/// delimit it.
+fn raw_string_literal(text: &str) -> String {
+    let hashes = (0..)
+        .map(|num_hashes| "#".repeat(num_hashes))
+        .find(|hashes| !text.contains(&format!("\"{hashes}")))
+        .expect("some number of hashes delimits the text");
+    format!("r{hashes}\"{text}\"{hashes}")
+}
+
+/// Returns the Rust type for a value with the given type annotation, where
+/// `leaf_type` is the Rust type of its innermost named type.
+fn rust_type(type_annotation: &TypeAnnotation, leaf_type: &str) -> String {
+    let rust_type = match type_annotation {
+        TypeAnnotation::List(list_annotation) => format!(
+            "Vec<{}>",
+            rust_type(list_annotation.inner_type_annotation(), leaf_type),
+        ),
+        TypeAnnotation::Named(_) => leaf_type.to_string(),
+    };
+    if type_annotation.nullable() {
+        format!("Option<{rust_type}>")
+    } else {
+        rust_type
+    }
+}
+
+/// Returns the names of all fragments spread (directly or through other
+/// fragments) within a selection set, in the order they are first
+/// encountered.
+fn spread_fragment_names<'a>(
+    selection_set: &'a SelectionSet<'a>,
+    fragment_registry: &'a FragmentRegistry<'a>,
+) -> Vec<&'a str> {
+    fn collect<'a>(
+        selection_set: &'a SelectionSet<'a>,
+        fragment_registry: &'a FragmentRegistry<'a>,
+        fragment_names: &mut Vec<&'a str>,
+    ) {
+        for selection in selection_set.selections() {
+            match selection {
+                Selection::Field(field_selection) => {
+                    if let Some(selection_set) = field_selection.selection_set() {
+                        collect(selection_set, fragment_registry, fragment_names);
+                    }
+                },
+                Selection::FragmentSpread(fragment_spread) => {
+                    let fragment = fragment_registry
+                        .fragments()
+                        .get(fragment_spread.fragment_name())
+                        .expect("fragment is present in the fragment registry");
+                    if !fragment_names.contains(&fragment.name()) {
+                        fragment_names.push(fragment.name());
+                        collect(fragment.selection_set(), fragment_registry, fragment_names);
+                    }
+                },
+                Selection::InlineFragment(inline_fragment) => {
+                    collect(inline_fragment.selection_set(), fragment_registry, fragment_names);
+                },
+            }
+        }
+    }
+
+    let mut fragment_names = vec![];
+    collect(selection_set, fragment_registry, &mut fragment_names);
+    fragment_names
+}
+
+fn write_enum(writer: &mut RustSourceWriter, enum_type: &EnumType) {
+    writer.separate_item();
+    writer.doc_comment(enum_type.description());
+    writer.line(ENUM_DERIVES);
+    writer.open_block(format!("pub enum {}", rust_identifier(enum_type.name())));
+    let mut variant_names = HashSet::new();
+    for enum_value in enum_type.values().values() {
+        let variant_name = unique_name(
+            &mut variant_names,
+            rust_identifier(&to_pascal_case(enum_value.name())),
+        );
+        writer.doc_comment(enum_value.description());
+        if variant_name != enum_value.name() {
+            writer.line(format!("#[serde(rename = \"{}\")]", enum_value.name()));
+        }
+        writer.line(format!("{variant_name},"));
+    }
+    writer.close_block();
+}
+
+/// Writes a `pub` struct field for the GraphQL field (or variable) named
+/// `graphql_name`, renaming it for `serde` if its Rust name differs.
+fn write_field(
+    writer: &mut RustSourceWriter,
+    field_names: &mut HashSet<String>,
+    graphql_name: &str,
+    description: Option<&str>,
+    rust_type: &str,
+    skip_if_none: bool,
+) {
+    let field_name = unique_name(field_names, rust_identifier(&to_snake_case(graphql_name)));
+    let mut serde_attrs = vec![];
+    if field_name.trim_start_matches("r#") != graphql_name {
+        serde_attrs.push(format!("rename = \"{graphql_name}\""));
+    }
+    if skip_if_none {
+        serde_attrs.push("skip_serializing_if = \"Option::is_none\"".to_string());
+    }
+
+    writer.doc_comment(description);
+    if !serde_attrs.is_empty() {
+        writer.line(format!("#[serde({})]", serde_attrs.join(", ")));
+    }
+    writer.line(format!("pub {field_name}: {rust_type},"));
+}
@@ -0,0 +1,9 @@
+mod code_generator;
+mod rust_names;
+mod rust_source_writer;
+
+#[cfg(test)]
+mod tests;
+
+pub use code_generator::CodeGenerator;
+pub use code_generator::CodegenError;
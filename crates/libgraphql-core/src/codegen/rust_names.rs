@@ -0,0 +1,91 @@
+use std::collections::HashSet;
+
+/// Rust keywords (including reserved ones) that can be used as raw
+/// identifiers (e.g. `r#type`).
+const RAW_IDENTIFIER_KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const",
+    "continue", "do", "dyn", "else", "enum", "extern", "false", "final", "fn",
+    "for", "gen", "if", "impl", "in", "let", "loop", "macro", "match", "mod",
+    "move", "mut", "override", "priv", "pub", "ref", "return", "static",
+    "struct", "trait", "true", "try", "type", "typeof", "unsafe", "unsized",
+    "use", "virtual", "where", "while", "yield",
+];
+
+/// Identifiers that cannot be written as raw identifiers, and so get a `_`
+/// suffix instead.
+const UNRAWABLE_IDENTIFIERS: &[&str] = &["_", "crate", "self", "Self", "super"];
+
+/// Converts a name into a valid Rust identifier, escaping keywords.
+pub(super) fn rust_identifier(name: &str) -> String {
+    if RAW_IDENTIFIER_KEYWORDS.contains(&name) {
+        format!("r#{name}")
+    } else if UNRAWABLE_IDENTIFIERS.contains(&name) {
+        format!("{name}_")
+    } else {
+        name.to_string()
+    }
+}
+
+/// Converts a GraphQL `camelCase` (or `PascalCase`) name to a Rust
+/// `snake_case` name, keeping acronyms together (`userURL` becomes
+/// `user_url`) and dropping leading underscores (`__typename` becomes
+/// `typename`).
+pub(super) fn to_snake_case(name: &str) -> String {
+    let trimmed = name.trim_start_matches('_');
+    if trimmed.is_empty() {
+        return "_".to_string();
+    }
+
+    let chars: Vec<char> = trimmed.chars().collect();
+    let mut snake_case = String::with_capacity(trimmed.len() + 4);
+    for (i, &c) in chars.iter().enumerate() {
+        if i > 0 && c.is_uppercase() {
+            let prev = chars[i - 1];
+            let next_is_lower = chars.get(i + 1).is_some_and(|next| next.is_lowercase());
+            if prev.is_lowercase() || prev.is_ascii_digit() || (prev.is_uppercase() && next_is_lower) {
+                snake_case.push('_');
+            }
+        }
+        snake_case.extend(c.to_lowercase());
+    }
+    snake_case
+}
+
+/// Converts a GraphQL name to a Rust `PascalCase` name. Each
+/// `_`-separated word is capitalized, and all-caps words are otherwise
+/// lowercased (`READ_ONLY` becomes `ReadOnly`, `friendsConnection`
+/// becomes `FriendsConnection`).
+pub(super) fn to_pascal_case(name: &str) -> String {
+    let pascal_case: String = name
+        .split('_')
+        .filter(|word| !word.is_empty())
+        .flat_map(|word| {
+            let mut chars = word.chars();
+            let first = chars.next().expect("empty words are filtered out");
+            let rest = chars.as_str();
+            let rest = if word.chars().any(char::is_lowercase) {
+                rest.to_string()
+            } else {
+                rest.to_lowercase()
+            };
+            first.to_uppercase().chain(rest.chars().collect::<Vec<_>>())
+        })
+        .collect();
+    if pascal_case.is_empty() {
+        "_".to_string()
+    } else {
+        pascal_case
+    }
+}
+
+/// Returns `name`, or `name` with the smallest numeric suffix (starting at
+/// 2) that has not been taken yet, and marks the result as taken.
+pub(super) fn unique_name(taken_names: &mut HashSet<String>, name: String) -> String {
+    if taken_names.insert(name.clone()) {
+        return name;
+    }
+    (2..)
+        .map(|suffix| format!("{name}{suffix}"))
+        .find(|candidate| taken_names.insert(candidate.clone()))
+        .expect("some numeric suffix is not taken")
+}
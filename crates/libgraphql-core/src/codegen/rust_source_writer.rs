@@ -0,0 +1,60 @@
+/// Accumulates Rust source one line at a time, indenting each line
+/// (rustfmt-style, by 4 spaces) according to how many blocks are open.
+#[derive(Debug, Default)]
+pub(super) struct RustSourceWriter {
+    at_block_start: bool,
+    depth: usize,
+    source: String,
+}
+
+impl RustSourceWriter {
+    /// Writes `header {` and indents the lines that follow.
+    pub(super) fn open_block(&mut self, header: impl AsRef<str>) {
+        self.line(format!("{} {{", header.as_ref()));
+        self.at_block_start = true;
+        self.depth += 1;
+    }
+
+    /// Closes the most recently opened block.
+    pub(super) fn close_block(&mut self) {
+        self.depth -= 1;
+        self.line("}");
+    }
+
+    /// Writes `doc` (if any) as `///` doc-comment lines.
+    pub(super) fn doc_comment(&mut self, doc: Option<&str>) {
+        let Some(doc) = doc else { return };
+        for doc_line in doc.trim().split('\n') {
+            let doc_line = doc_line.trim_end();
+            if doc_line.is_empty() {
+                self.line("///");
+            } else {
+                self.line(format!("/// {doc_line}"));
+            }
+        }
+    }
+
+    pub(super) fn into_source(self) -> String {
+        self.source
+    }
+
+    /// Writes a single line at the current indentation. Only the first line
+    /// of a multi-line `line` (e.g. one that ends in a raw string literal) is
+    /// indented.
+    pub(super) fn line(&mut self, line: impl AsRef<str>) {
+        for _ in 0..self.depth {
+            self.source.push_str("    ");
+        }
+        self.source.push_str(line.as_ref());
+        self.source.push('\n');
+        self.at_block_start = false;
+    }
+
+    /// Separates the item about to be written from the one before it with a
+    /// blank line (unless it is the first item in the file or its block).
+    pub(super) fn separate_item(&mut self) {
+        if !self.at_block_start && !self.source.is_empty() {
+            self.source.push('\n');
+        }
+    }
+}
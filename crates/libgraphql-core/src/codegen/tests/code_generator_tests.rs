@@ -0,0 +1,307 @@
+use crate::codegen::CodeGenerator;
+use crate::codegen::CodegenError;
+use crate::schema::Schema;
+use crate::schema::SchemaBuilder;
+
+const SCHEMA: &str = r#"
+    type Query {
+        me: User
+        node(id: ID!): Node
+        search(text: String!, filter: SearchFilter): [SearchResult!]!
+    }
+
+    type Mutation {
+        setRole(userId: ID!, role: Role!): User
+    }
+
+    interface Node {
+        id: ID!
+    }
+
+    "A registered user."
+    type User implements Node {
+        id: ID!
+        displayName: String
+        "The user's role."
+        role: Role!
+        joinedAt: DateTime!
+        friends(first: Int = 10): [User!]
+    }
+
+    type Post implements Node {
+        id: ID!
+        title: String!
+        author: User!
+    }
+
+    union SearchResult = Post | User
+
+    enum Role {
+        ADMIN
+        "Can only read."
+        READ_ONLY
+    }
+
+    input SearchFilter {
+        roles: [Role!]
+        "Narrows down the search."
+        and: SearchFilter
+        createdAfter: DateTime
+    }
+
+    scalar DateTime
+"#;
+
+fn build_schema() -> Schema {
+    SchemaBuilder::from_str(None, SCHEMA)
+        .unwrap()
+        .build()
+        .unwrap()
+}
+
+fn generate(schema: &Schema, document: &str) -> Result<String, Vec<CodegenError>> {
+    let mut codegen = CodeGenerator::new(schema);
+    codegen.set_scalar_type("DateTime", "::std::string::String");
+    codegen.add_document_str(document, None).unwrap();
+    codegen.generate()
+}
+
+/// Validates the full output for an operation with variables, aliases, a
+/// nested selection set, and a fragment spread that is merged into the
+/// struct it is spread into.
+///
+/// Written by Claude Code. Reviewed/iterated on by @jeffmo.
+#[test]
+fn generates_operation_module() {
+    let schema = build_schema();
+    let source = generate(&schema, r#"
+        query GetMe($first: Int = 5) {
+            me {
+                name: displayName
+                ...UserFields
+                friends(first: $first) { id }
+            }
+        }
+
+        fragment UserFields on User { id displayName role }
+    "#).unwrap();
+
+    assert_eq!(source, r##"// @generated by libgraphql's `codegen::CodeGenerator`. Do not edit by hand.
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, ::serde::Deserialize, ::serde::Serialize)]
+pub enum Role {
+    #[serde(rename = "ADMIN")]
+    Admin,
+    /// Can only read.
+    #[serde(rename = "READ_ONLY")]
+    ReadOnly,
+}
+
+pub mod get_me {
+    pub const OPERATION_NAME: &str = "GetMe";
+    pub const DOCUMENT: &str = r"query GetMe($first: Int = 5) {
+  me {
+    name: displayName
+    ...UserFields
+    friends(first: $first) {
+      id
+    }
+  }
+}
+
+fragment UserFields on User {
+  id
+  displayName
+  role
+}
+";
+
+    #[derive(Clone, Debug, ::serde::Deserialize, ::serde::Serialize)]
+    pub struct Variables {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub first: Option<i32>,
+    }
+
+    #[derive(Clone, Debug, ::serde::Deserialize, ::serde::Serialize)]
+    pub struct ResponseData {
+        pub me: Option<GetMeMe>,
+    }
+
+    #[derive(Clone, Debug, ::serde::Deserialize, ::serde::Serialize)]
+    pub struct GetMeMe {
+        pub name: Option<String>,
+        pub id: String,
+        #[serde(rename = "displayName")]
+        pub display_name: Option<String>,
+        /// The user's role.
+        pub role: super::Role,
+        pub friends: Option<Vec<GetMeMeFriends>>,
+    }
+
+    #[derive(Clone, Debug, ::serde::Deserialize, ::serde::Serialize)]
+    pub struct GetMeMeFriends {
+        pub id: String,
+    }
+}
+"##);
+}
+
+/// Validates that selection sets on interface and union types become enums
+/// tagged by `__typename`, with fields from type-conditioned fragments only
+/// on the variants they apply to.
+///
+/// Written by Claude Code. Reviewed/iterated on by @jeffmo.
+#[test]
+fn generates_typename_enums_for_abstract_types() {
+    let schema = build_schema();
+    let source = generate(&schema, r#"
+        query Lookup {
+            node(id: "1") {
+                __typename
+                id
+                ... on User { joinedAt }
+            }
+            search(text: "a") {
+                ... on User { __typename displayName }
+                ... on Post { __typename title author { id } }
+            }
+        }
+    "#).unwrap();
+
+    let module = &source[source.find("    #[derive").unwrap()..];
+    assert!(module.contains(r#"    #[derive(Clone, Debug, ::serde::Deserialize, ::serde::Serialize)]
+    pub struct ResponseData {
+        pub node: Option<LookupNode>,
+        pub search: Vec<LookupSearch>,
+    }
+
+    #[derive(Clone, Debug, ::serde::Deserialize, ::serde::Serialize)]
+    #[serde(tag = "__typename")]
+    pub enum LookupNode {
+        Post(LookupNodeOnPost),
+        User(LookupNodeOnUser),
+    }
+
+    #[derive(Clone, Debug, ::serde::Deserialize, ::serde::Serialize)]
+    pub struct LookupNodeOnPost {
+        pub id: String,
+    }
+
+    #[derive(Clone, Debug, ::serde::Deserialize, ::serde::Serialize)]
+    pub struct LookupNodeOnUser {
+        pub id: String,
+        #[serde(rename = "joinedAt")]
+        pub joined_at: ::std::string::String,
+    }
+
+    #[derive(Clone, Debug, ::serde::Deserialize, ::serde::Serialize)]
+    #[serde(tag = "__typename")]
+    pub enum LookupSearch {
+        Post(LookupSearchOnPost),
+        User(LookupSearchOnUser),
+    }
+
+    #[derive(Clone, Debug, ::serde::Deserialize, ::serde::Serialize)]
+    pub struct LookupSearchOnPost {
+        pub title: String,
+        pub author: LookupSearchOnPostAuthor,
+    }
+
+    #[derive(Clone, Debug, ::serde::Deserialize, ::serde::Serialize)]
+    pub struct LookupSearchOnPostAuthor {
+        pub id: String,
+    }
+
+    #[derive(Clone, Debug, ::serde::Deserialize, ::serde::Serialize)]
+    pub struct LookupSearchOnUser {
+        #[serde(rename = "displayName")]
+        pub display_name: Option<String>,
+    }
+}
+"#), "{source}");
+}
+
+/// Validates that input objects (boxing self-referencing fields) and the enums
+/// they use are generated for variables, and that fields subject to `@skip`
+/// or `@include` are optional.
+///
+/// Written by Claude Code. Reviewed/iterated on by @jeffmo.
+#[test]
+fn generates_input_objects_and_optional_skipped_fields() {
+    let schema = build_schema();
+    let source = generate(&schema, r#"
+        query Search($text: String!, $filter: SearchFilter, $withTitle: Boolean!) {
+            search(text: $text, filter: $filter) {
+                ... on Post { __typename id title @include(if: $withTitle) }
+                ... on User { __typename id }
+            }
+        }
+    "#).unwrap();
+
+    assert!(source.contains("pub enum Role {"), "{source}");
+    assert!(source.contains(r#"
+#[derive(Clone, Debug, ::serde::Deserialize, ::serde::Serialize)]
+pub struct SearchFilter {
+    pub roles: Option<Vec<Role>>,
+    /// Narrows down the search.
+    pub and: Option<Box<SearchFilter>>,
+    #[serde(rename = "createdAfter")]
+    pub created_after: Option<::std::string::String>,
+}
+"#), "{source}");
+    assert!(source.contains(r#"
+    pub struct Variables {
+        pub text: String,
+        pub filter: Option<super::SearchFilter>,
+        #[serde(rename = "withTitle")]
+        pub with_title: bool,
+    }
+"#), "{source}");
+    assert!(source.contains(r#"
+    pub struct SearchSearchOnPost {
+        pub id: String,
+        pub title: Option<String>,
+    }
+"#), "{source}");
+}
+
+/// Validates that operations which cannot be generated are reported: anonymous
+/// operations, abstract selection sets missing `__typename`, and custom
+/// scalars with no Rust type.
+///
+/// Written by Claude Code. Reviewed/iterated on by @jeffmo.
+#[test]
+fn reports_ungeneratable_operations() {
+    let schema = build_schema();
+    let errors = generate(&schema, "{ me { id } }").unwrap_err();
+    assert!(
+        matches!(errors.as_slice(), [CodegenError::AnonymousOperation { .. }]),
+        "{errors:?}",
+    );
+
+    let errors = generate(&schema, r#"
+        query Search { search(text: "a") { ... on User { __typename id } } }
+    "#).unwrap_err();
+    let messages: Vec<String> = errors.iter().map(|err| err.to_string()).collect();
+    assert_eq!(messages, [
+        "The selection set of `SearchResult` must select `__typename` (without \
+        an alias) when its type is `Post`",
+    ]);
+
+    let mut codegen = CodeGenerator::new(&schema);
+    codegen.add_document_str("query Me { me { joinedAt } }", None).unwrap();
+    let errors = codegen.generate().unwrap_err();
+    assert!(
+        matches!(
+            errors.as_slice(),
+            [CodegenError::UnmappedCustomScalar { scalar_name }] if scalar_name == "DateTime",
+        ),
+        "{errors:?}",
+    );
+
+    let errors = generate(&schema, "query Me { me { email } }").unwrap_err();
+    assert!(
+        matches!(errors.as_slice(), [CodegenError::ExecutableDocumentBuildErrors(_)]),
+        "{errors:?}",
+    );
+}
@@ -0,0 +1 @@
+mod code_generator_tests;
@@ -1,6 +1,13 @@
+#[cfg(any(test, feature = "arbitrary"))]
+pub mod arbitrary;
 /// Things related to GraphQL syntax trees. Currently this is mostly re-exports
 /// of/wrappers around [`graphql_parser`].
 pub mod ast;
+/// Generation of typed Rust code (response, variables, enum, and input object
+/// types) for [operations](operation) validated against a
+/// [`Schema`](schema::Schema), for use in `build.rs` scripts (e.g.
+/// [`CodeGenerator`](codegen::CodeGenerator)).
+pub mod codegen;
 mod directive_annotation;
 mod directive_annotation_builder;
 mod file_reader;
@@ -15,6 +22,10 @@ mod named_ref;
 /// [`Mutation`](operation::Mutation), etc...).
 pub mod operation;
 mod readonly_map;
+/// Pluggable Rust implementations of
+/// [custom scalars](https://spec.graphql.org/October2021/#sec-Scalars.Custom-Scalars)
+/// (e.g. [`ScalarRegistry`](scalars::ScalarRegistry)).
+pub mod scalars;
 /// Things related to
 /// [GraphQL schemas](https://spec.graphql.org/October2021/#sec-Schema)
 /// (e.g. [`Schema`](schema::Schema), [`SchemaBuilder`](schema::SchemaBuilder),
@@ -25,6 +36,7 @@ pub mod schema;
 /// been defined within some [`Schema`](schema::Schema).
 pub mod types;
 mod value;
+mod value_coercion;
 
 pub use directive_annotation::DirectiveAnnotation;
 pub use directive_annotation_builder::DirectiveAnnotationBuilder;
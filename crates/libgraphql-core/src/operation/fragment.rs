@@ -20,9 +20,25 @@ pub struct Fragment<'schema> {
 }
 
 impl<'schema> Fragment<'schema> {
+    pub fn def_location(&self) -> &loc::SourceLocation {
+        &self.def_location
+    }
+
+    pub fn directives(&self) -> &Vec<DirectiveAnnotation> {
+        &self.directives
+    }
+
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
     pub fn selection_set(&self) -> &SelectionSet<'schema> {
         &self.selection_set
     }
+
+    pub fn type_condition_name(&self) -> &str {
+        self.type_condition_ref.name()
+    }
 }
 
 impl<'schema> DerefByName for Fragment<'schema> {
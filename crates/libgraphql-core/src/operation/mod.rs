@@ -14,6 +14,7 @@ mod mutation_builder;
 mod operation;
 mod operation_builder;
 mod operation_builder_trait;
+mod operation_compatibility_checker;
 mod operation_kind;
 mod operation_data;
 mod operation_trait;
@@ -50,6 +51,9 @@ pub use operation::Operation;
 pub use operation_builder::OperationBuilder;
 pub use operation_builder::OperationBuildError;
 use operation_builder_trait::OperationBuilderTrait;
+pub use operation_compatibility_checker::OperationCompatibilityChecker;
+pub use operation_compatibility_checker::OperationCompatibilityReport;
+pub use operation_compatibility_checker::OperationFailure;
 use operation_data::OperationData;
 pub use operation_kind::OperationKind;
 use operation_trait::OperationTrait;
@@ -21,6 +21,7 @@ use crate::schema::Schema;
 use crate::types::GraphQLType;
 use crate::types::TypeAnnotation;
 use crate::Value;
+use crate::value_coercion::check_const_value_coercion;
 use indexmap::IndexMap;
 use inherent::inherent;
 use thiserror::Error;
@@ -264,9 +265,9 @@ impl<'schema: 'fragreg, 'fragreg> OperationBuilderTrait<
                 type_ref.inner_named_type_ref()
                     .deref(schema)
                     .map_err(|err| match err {
-                        DerefByNameError::DanglingReference(var_name)
+                        DerefByNameError::DanglingReference(_)
                             => OperationBuildError::UndefinedVariableType {
-                                variable_name: var_name,
+                                variable_name: var_name.to_owned(),
                                 location: vardef_srcloc.to_owned(),
                             },
                     });
@@ -275,8 +276,40 @@ impl<'schema: 'fragreg, 'fragreg> OperationBuilderTrait<
                 continue
             }
 
+            let type_annotation = TypeAnnotation::from_ast_type(
+                &vardef_srcloc,
+                &ast_var_def.var_type,
+            );
+
+            // Default values must coerce to the variable's type. An invalid
+            // default is dropped, but the variable is kept so that its uses
+            // don't produce cascading errors.
+            //
+            // https://spec.graphql.org/October2021/#sec-Values-of-Correct-Type
+            let ast_default_value = ast_var_def.default_value.as_ref().filter(
+                |ast_default_value| match check_const_value_coercion(
+                    ast_default_value,
+                    &type_annotation,
+                    schema,
+                    &vardef_srcloc,
+                ) {
+                    Ok(()) => true,
+                    Err(error) => {
+                        errors.push(
+                            OperationBuildError::InvalidVariableDefaultValue {
+                                location: vardef_srcloc.to_owned(),
+                                reason: error.reason,
+                                value_path: error.path,
+                                variable_name: var_name.to_owned(),
+                            },
+                        );
+                        false
+                    },
+                },
+            );
+
             let default_value =
-                ast_var_def.default_value.as_ref().map(|val| {
+                ast_default_value.map(|val| {
                     Value::from_ast(val, &loc::SourceLocation::from_execdoc_ast_position(
                         file_path,
                         &ast_var_def.position,
@@ -286,10 +319,7 @@ impl<'schema: 'fragreg, 'fragreg> OperationBuilderTrait<
             variables.insert(ast_var_def.name.to_string(), Variable {
                 default_value,
                 name: ast_var_def.name.to_string(),
-                type_annotation: TypeAnnotation::from_ast_type(
-                    &vardef_srcloc,
-                    &ast_var_def.var_type,
-                ),
+                type_annotation,
                 def_location: vardef_srcloc,
             });
         }
@@ -498,6 +528,23 @@ pub enum OperationBuildError {
         location2: loc::FilePosition,
     },
 
+    #[error(
+        "Invalid default value for variable `${variable_name}`: {reason}{}",
+        value_path.as_ref().map(|path| format!(" at `{path}`")).unwrap_or_default(),
+    )]
+    InvalidVariableDefaultValue {
+        /// The location of the variable definition. Value literals carry
+        /// no source positions of their own, so `value_path` identifies
+        /// the offending value within the default.
+        location: loc::SourceLocation,
+        reason: String,
+        /// The path of the offending value within the default value (e.g.
+        /// `pages[1].tags[0]`), or `None` if the default value as a whole
+        /// does not coerce.
+        value_path: Option<String>,
+        variable_name: String,
+    },
+
     #[error(
         "Found multiple operations in document. If this was expected, consider \
         using ExecutableDocumentBuilder instead.",
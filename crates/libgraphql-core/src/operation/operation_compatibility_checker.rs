@@ -0,0 +1,431 @@
+use crate::ast;
+use crate::file_reader;
+use crate::loc;
+use crate::operation::ExecutableDocumentBuildError;
+use crate::operation::FragmentBuilder;
+use crate::operation::FragmentBuildError;
+use crate::operation::FragmentRegistry;
+use crate::operation::OperationBuilder;
+use crate::operation::OperationBuildError;
+use crate::schema::Schema;
+use indexmap::IndexMap;
+use std::collections::HashMap;
+use std::path::Path;
+use std::path::PathBuf;
+
+type Result<T> = std::result::Result<T, Vec<ExecutableDocumentBuildError>>;
+
+/// Determines which operations in a corpus of executable documents would stop
+/// validating if an old [`Schema`] were replaced by a new one.
+///
+/// Unlike a purely structural schema comparison, this only reports changes
+/// that actually affect the given operations: removing a field that no
+/// operation in the corpus selects is not a problem.
+///
+/// Each operation (and every fragment it transitively spreads) is built with
+/// [`OperationBuilder`] and [`FragmentBuilder`] against both schemas.
+/// Operations that already fail to build against the old schema are reported
+/// separately (see [`OperationCompatibilityReport::invalid_operations()`]) so
+/// that pre-existing problems are not mistaken for breakages.
+///
+/// Fragments are shared across all documents in the corpus (as they would be
+/// in a [`FragmentRegistry`]), so an operation in one document may spread a
+/// fragment defined in another.
+///
+/// # Example
+///
+/// ```
+/// use libgraphql_core::operation::OperationCompatibilityChecker;
+/// use libgraphql_core::schema::SchemaBuilder;
+///
+/// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let old_schema = SchemaBuilder::from_str(
+///     None,
+///     "type Query { me: String, unused: String }",
+/// )?.build()?;
+/// let new_schema = SchemaBuilder::from_str(
+///     None,
+///     "type Query { me: String }",
+/// )?.build()?;
+///
+/// let mut checker = OperationCompatibilityChecker::new(
+///     &old_schema,
+///     &new_schema,
+/// );
+/// checker.add_document_str("query Me { me }", None).unwrap();
+///
+/// let report = checker.check();
+/// assert!(report.is_compatible());
+/// # Ok(())
+/// # }
+/// # example().unwrap();
+/// ```
+#[derive(Debug)]
+pub struct OperationCompatibilityChecker<'schema> {
+    documents: Vec<CorpusDocument>,
+    /// The location of the first definition of each fragment in the corpus.
+    fragment_locations: HashMap<String, loc::SourceLocation>,
+    new_schema: &'schema Schema,
+    old_schema: &'schema Schema,
+}
+
+impl<'schema> OperationCompatibilityChecker<'schema> {
+    pub fn new(old_schema: &'schema Schema, new_schema: &'schema Schema) -> Self {
+        Self {
+            documents: vec![],
+            fragment_locations: HashMap::new(),
+            new_schema,
+            old_schema,
+        }
+    }
+
+    /// Add an executable document (operations and/or fragments) to the
+    /// corpus.
+    ///
+    /// Returns an error if the document defines a fragment whose name is
+    /// already defined elsewhere in the corpus. In that case, nothing from the
+    /// document is added.
+    pub fn add_document_ast(
+        &mut self,
+        ast: &ast::operation::Document,
+        file_path: Option<&Path>,
+    ) -> Result<()> {
+        let mut new_fragment_locations = HashMap::new();
+        let mut dupe_errors = vec![];
+        for def in &ast.definitions {
+            if let ast::operation::Definition::Fragment(frag_def) = def {
+                let fragment_name = frag_def.name.as_str();
+                let def_location = loc::SourceLocation::from_execdoc_ast_position(
+                    file_path,
+                    &frag_def.position,
+                );
+                let first_def_location =
+                    self.fragment_locations
+                        .get(fragment_name)
+                        .or_else(|| new_fragment_locations.get(fragment_name));
+                if let Some(first_def_location) = first_def_location {
+                    dupe_errors.push(FragmentBuildError::DuplicateFragmentDefinition {
+                        fragment_name: fragment_name.to_string(),
+                        first_def_location: first_def_location.clone(),
+                        second_def_location: def_location,
+                    });
+                    continue;
+                }
+                new_fragment_locations.insert(fragment_name.to_string(), def_location);
+            }
+        }
+
+        if !dupe_errors.is_empty() {
+            return Err(vec![
+                ExecutableDocumentBuildError::FragmentValidationErrors(dupe_errors),
+            ]);
+        }
+
+        self.fragment_locations.extend(new_fragment_locations);
+        self.documents.push(CorpusDocument {
+            ast: ast.clone(),
+            file_path: file_path.map(Path::to_path_buf),
+        });
+        Ok(())
+    }
+
+    /// Read an executable document from disk and add it to the corpus.
+    ///
+    /// See [`OperationCompatibilityChecker::add_document_ast()`].
+    pub fn add_document_file(&mut self, file_path: impl AsRef<Path>) -> Result<()> {
+        let file_path = file_path.as_ref();
+        let file_content = file_reader::read_content(file_path)
+            .map_err(|e| ExecutableDocumentBuildError::ExecutableDocumentFileReadError(
+                Box::new(e),
+            ))?;
+        self.add_document_str(file_content, Some(file_path))
+    }
+
+    /// Parse an executable document from a string and add it to the corpus.
+    ///
+    /// See [`OperationCompatibilityChecker::add_document_ast()`].
+    pub fn add_document_str(
+        &mut self,
+        content: impl AsRef<str>,
+        file_path: Option<&Path>,
+    ) -> Result<()> {
+        let ast_doc =
+            ast::operation::parse(content.as_ref())
+                .map_err(|e| vec![e.into()])?;
+        self.add_document_ast(&ast_doc, file_path)
+    }
+
+    /// Validate every operation in the corpus against both schemas.
+    pub fn check(&self) -> OperationCompatibilityReport {
+        let fragment_defs: HashMap<&str, (&ast::operation::FragmentDefinition, Option<&Path>)> =
+            self.documents
+                .iter()
+                .flat_map(|doc| doc.ast.definitions.iter().filter_map(|def| match def {
+                    ast::operation::Definition::Fragment(frag_def) =>
+                        Some((frag_def.name.as_str(), (frag_def, doc.file_path.as_deref()))),
+                    ast::operation::Definition::Operation(_) => None,
+                }))
+                .collect();
+        let old_fragment_errors = build_fragments(self.old_schema, &fragment_defs);
+        let new_fragment_errors = build_fragments(self.new_schema, &fragment_defs);
+
+        let mut report = OperationCompatibilityReport {
+            broken_operations: vec![],
+            invalid_operations: vec![],
+            num_operations_checked: 0,
+        };
+        for (document_index, doc) in self.documents.iter().enumerate() {
+            let file_path = doc.file_path.as_deref();
+            for def in &doc.ast.definitions {
+                let op_def = match def {
+                    ast::operation::Definition::Operation(op_def) => op_def,
+                    ast::operation::Definition::Fragment(_) => continue,
+                };
+                report.num_operations_checked += 1;
+
+                let fragment_names = spread_fragment_names(op_def, &fragment_defs);
+                let (operation_name, position) = operation_name_and_position(op_def);
+                let mut failure = OperationFailure {
+                    def_location: loc::SourceLocation::from_execdoc_ast_position(
+                        file_path,
+                        position,
+                    ),
+                    document_index,
+                    errors: vec![],
+                    fragment_errors: IndexMap::new(),
+                    operation_name: operation_name.map(str::to_string),
+                };
+
+                failure.collect_errors(
+                    self.old_schema,
+                    op_def,
+                    file_path,
+                    &fragment_names,
+                    &old_fragment_errors,
+                );
+                if failure.has_errors() {
+                    report.invalid_operations.push(failure);
+                    continue;
+                }
+
+                failure.collect_errors(
+                    self.new_schema,
+                    op_def,
+                    file_path,
+                    &fragment_names,
+                    &new_fragment_errors,
+                );
+                if failure.has_errors() {
+                    report.broken_operations.push(failure);
+                }
+            }
+        }
+
+        report
+    }
+}
+
+/// The result of [`OperationCompatibilityChecker::check()`].
+#[derive(Clone, Debug)]
+pub struct OperationCompatibilityReport {
+    broken_operations: Vec<OperationFailure>,
+    invalid_operations: Vec<OperationFailure>,
+    num_operations_checked: usize,
+}
+
+impl OperationCompatibilityReport {
+    /// Operations that validate against the old schema but fail to validate
+    /// against the new schema, in corpus order.
+    pub fn broken_operations(&self) -> &Vec<OperationFailure> {
+        &self.broken_operations
+    }
+
+    /// Operations that already fail to validate against the old schema, in
+    /// corpus order. These are not checked against the new schema.
+    pub fn invalid_operations(&self) -> &Vec<OperationFailure> {
+        &self.invalid_operations
+    }
+
+    /// Whether every operation that validates against the old schema also
+    /// validates against the new schema.
+    pub fn is_compatible(&self) -> bool {
+        self.broken_operations.is_empty()
+    }
+
+    /// The total number of operations in the corpus.
+    pub fn num_operations_checked(&self) -> usize {
+        self.num_operations_checked
+    }
+}
+
+/// An operation in an [`OperationCompatibilityChecker`] corpus that failed to
+/// validate against a schema, along with the reasons why.
+#[derive(Clone, Debug)]
+pub struct OperationFailure {
+    def_location: loc::SourceLocation,
+    document_index: usize,
+    errors: Vec<OperationBuildError>,
+    fragment_errors: IndexMap<String, FragmentBuildError>,
+    operation_name: Option<String>,
+}
+
+impl OperationFailure {
+    pub fn def_location(&self) -> &loc::SourceLocation {
+        &self.def_location
+    }
+
+    /// The index (in the order documents were added to the corpus) of the
+    /// document that defines this operation.
+    pub fn document_index(&self) -> usize {
+        self.document_index
+    }
+
+    /// Errors produced by [`OperationBuilder`] for the operation itself.
+    pub fn errors(&self) -> &Vec<OperationBuildError> {
+        &self.errors
+    }
+
+    /// Errors produced by [`FragmentBuilder`] for fragments (transitively)
+    /// spread by this operation, keyed by fragment name.
+    pub fn fragment_errors(&self) -> &IndexMap<String, FragmentBuildError> {
+        &self.fragment_errors
+    }
+
+    pub fn operation_name(&self) -> Option<&str> {
+        self.operation_name.as_deref()
+    }
+
+    fn collect_errors(
+        &mut self,
+        schema: &Schema,
+        op_def: &ast::operation::OperationDefinition,
+        file_path: Option<&Path>,
+        fragment_names: &[&str],
+        fragment_errors: &HashMap<&str, FragmentBuildError>,
+    ) {
+        // Fragment spreads are resolved separately below, so the operation
+        // itself can be built without a registry.
+        let maybe_op = OperationBuilder::from_ast(
+            schema,
+            FragmentRegistry::empty(),
+            op_def,
+            file_path,
+        ).and_then(|op_builder| op_builder.build());
+        if let Err(errors) = maybe_op {
+            self.errors = errors;
+        }
+
+        self.fragment_errors = fragment_names
+            .iter()
+            .filter_map(|name| {
+                fragment_errors
+                    .get(name)
+                    .map(|err| (name.to_string(), err.clone()))
+            })
+            .collect();
+    }
+
+    fn has_errors(&self) -> bool {
+        !self.errors.is_empty() || !self.fragment_errors.is_empty()
+    }
+}
+
+#[derive(Debug)]
+struct CorpusDocument {
+    ast: ast::operation::Document,
+    file_path: Option<PathBuf>,
+}
+
+/// Builds each fragment against `schema`, returning the errors for those that
+/// fail to build.
+fn build_fragments<'a>(
+    schema: &Schema,
+    fragment_defs: &HashMap<&'a str, (&ast::operation::FragmentDefinition, Option<&Path>)>,
+) -> HashMap<&'a str, FragmentBuildError> {
+    fragment_defs
+        .iter()
+        .filter_map(|(name, (frag_def, file_path))| {
+            FragmentBuilder::from_ast(
+                schema,
+                FragmentRegistry::empty(),
+                frag_def,
+                *file_path,
+            )
+            .and_then(|builder| builder.build())
+            .err()
+            .map(|err| (*name, err))
+        })
+        .collect()
+}
+
+fn operation_name_and_position(
+    op_def: &ast::operation::OperationDefinition,
+) -> (Option<&str>, &ast::AstPos) {
+    use ast::operation::OperationDefinition as OpDef;
+    match op_def {
+        OpDef::Mutation(mutation) => (mutation.name.as_deref(), &mutation.position),
+        OpDef::Query(query) => (query.name.as_deref(), &query.position),
+        OpDef::SelectionSet(selection_set) => (None, &selection_set.span.0),
+        OpDef::Subscription(subscription) =>
+            (subscription.name.as_deref(), &subscription.position),
+    }
+}
+
+/// Returns the names of all fragments spread (directly or through other
+/// fragments) by an operation, in the order they are first encountered.
+///
+/// Spreads of fragments that are not defined anywhere in the corpus are not
+/// included; these fail identically against both schemas.
+fn spread_fragment_names<'a>(
+    op_def: &ast::operation::OperationDefinition,
+    fragment_defs: &HashMap<&'a str, (&'a ast::operation::FragmentDefinition, Option<&Path>)>,
+) -> Vec<&'a str> {
+    use ast::operation::OperationDefinition as OpDef;
+    let selection_set = match op_def {
+        OpDef::Mutation(mutation) => &mutation.selection_set,
+        OpDef::Query(query) => &query.selection_set,
+        OpDef::SelectionSet(selection_set) => selection_set,
+        OpDef::Subscription(subscription) => &subscription.selection_set,
+    };
+
+    let mut fragment_names = vec![];
+    collect_spread_fragment_names(selection_set, fragment_defs, &mut fragment_names);
+    fragment_names
+}
+
+fn collect_spread_fragment_names<'a>(
+    selection_set: &ast::operation::SelectionSet,
+    fragment_defs: &HashMap<&'a str, (&'a ast::operation::FragmentDefinition, Option<&Path>)>,
+    fragment_names: &mut Vec<&'a str>,
+) {
+    for selection in &selection_set.items {
+        match selection {
+            ast::operation::Selection::Field(field) => collect_spread_fragment_names(
+                &field.selection_set,
+                fragment_defs,
+                fragment_names,
+            ),
+            ast::operation::Selection::FragmentSpread(spread) => {
+                let Some((name, (frag_def, _))) =
+                    fragment_defs.get_key_value(spread.fragment_name.as_str())
+                else {
+                    continue;
+                };
+                if !fragment_names.contains(name) {
+                    fragment_names.push(*name);
+                    collect_spread_fragment_names(
+                        &frag_def.selection_set,
+                        fragment_defs,
+                        fragment_names,
+                    );
+                }
+            },
+            ast::operation::Selection::InlineFragment(inline_frag) =>
+                collect_spread_fragment_names(
+                    &inline_frag.selection_set,
+                    fragment_defs,
+                    fragment_names,
+                ),
+        }
+    }
+}
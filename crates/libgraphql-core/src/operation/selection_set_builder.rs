@@ -14,6 +14,7 @@ use crate::operation::InlineFragment;
 use crate::operation::Selection;
 use crate::schema::Schema;
 use crate::types::GraphQLType;
+use crate::value_coercion::check_value_coercion;
 use crate::Value;
 use indexmap::IndexMap;
 use std::path::Path;
@@ -54,6 +55,10 @@ impl<'schema: 'fragreg, 'fragreg> SelectionSetBuilder<'schema, 'fragreg> {
         let parent_fields = match parent_type {
             GraphQLType::Interface(iface_t) => iface_t.fields(),
             GraphQLType::Object(obj_t) => obj_t.fields(),
+            // Unions define no fields of their own; apart from `__typename`,
+            // their selection sets select fields through inline fragments on
+            // member types.
+            GraphQLType::Union(union_t) => union_t.meta_fields(),
             _ => return Err(vec![
                 SelectionSetBuildError::UnselectableFieldType {
                     location: loc::SourceLocation::from_execdoc_ast_position(
@@ -105,6 +110,26 @@ impl<'schema: 'fragreg, 'fragreg> SelectionSetBuilder<'schema, 'fragreg> {
 
                     let mut arguments = IndexMap::new();
                     for (arg_name, ast_arg_value) in ast_arguments {
+                        // Argument literals must coerce to the parameter's
+                        // type.
+                        //
+                        // https://spec.graphql.org/October2021/#sec-Values-of-Correct-Type
+                        if let Some(param) = selected_field.parameters().get(arg_name)
+                            && let Err(error) = check_value_coercion(
+                                ast_arg_value,
+                                param.type_annotation(),
+                                schema,
+                                &selected_field_srcloc,
+                            ) {
+                            errors.push(SelectionSetBuildError::InvalidArgumentValue {
+                                argument_name: arg_name.to_string(),
+                                field_name: field_name.to_string(),
+                                location: selected_field_srcloc.to_owned(),
+                                reason: error.reason,
+                                value_path: error.path,
+                            });
+                        }
+
                         if arguments.insert(
                             arg_name.to_string(),
                             Value::from_ast(
@@ -302,6 +327,10 @@ impl<'schema: 'fragreg, 'fragreg> SelectionSetBuilder<'schema, 'fragreg> {
             })
         }
 
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
         Ok(SelectionSetBuilder {
             fragment_registry,
             schema,
@@ -366,6 +395,25 @@ pub enum SelectionSetBuildError {
         location2: loc::SourceLocation,
     },
 
+    #[error(
+        "Invalid value for argument `{argument_name}` of field \
+        `{field_name}`: {reason}{}",
+        value_path.as_ref().map(|path| format!(" at `{path}`")).unwrap_or_default(),
+    )]
+    InvalidArgumentValue {
+        argument_name: String,
+        field_name: String,
+        /// The location of the selected field. Value literals carry no source
+        /// positions of their own, so `value_path` identifies the offending
+        /// value within the argument.
+        location: loc::SourceLocation,
+        reason: String,
+        /// The path of the offending value within the argument value (e.g.
+        /// `pages[1].tags[0]`), or `None` if the argument value as a whole
+        /// does not coerce.
+        value_path: Option<String>,
+    },
+
     #[error(
         "Attempted to selected a type-qualified set of fields using an \
         invalid type. `{invalid_qualifying_type_name}` is not a subtype \
@@ -2,6 +2,9 @@ use crate::operation::ExecutableDocumentBuildError;
 use crate::operation::ExecutableDocumentBuilder;
 use crate::operation::FragmentRegistry;
 use crate::operation::FragmentRegistryBuilder;
+use crate::operation::OperationBuildError;
+use crate::operation::Selection;
+use crate::operation::SelectionSetBuildError;
 use crate::schema::SchemaBuilder;
 
 fn setup_schema() -> crate::schema::Schema {
@@ -789,7 +792,6 @@ fn incomplete_query_syntax() {
 // =============================================================================
 
 #[test]
-#[ignore] // TODO: OperationBuilder needs to implement field validation
 fn operation_with_nonexistent_field() {
     let schema = setup_schema();
     let registry = FragmentRegistry::empty();
@@ -820,7 +822,6 @@ fn operation_with_nonexistent_field() {
 }
 
 #[test]
-#[ignore] // TODO: OperationBuilder needs to implement root field validation
 fn operation_with_nonexistent_type() {
     let schema = setup_schema();
     let registry = FragmentRegistry::empty();
@@ -867,7 +868,7 @@ fn operation_with_wrong_argument_type() {
         None,
     );
 
-    // This should parse correctly - argument type validation happens at execution time
+    // Int literals coerce to ID.
     assert!(result.is_ok());
 }
 
@@ -1252,6 +1253,170 @@ fn document_with_fragment_spread_in_inline_fragment() {
     assert_eq!(doc.operations().len(), 1);
 }
 
+#[test]
+fn document_with_inline_fragments_on_union_members() {
+    let schema = SchemaBuilder::from_str(
+        None,
+        r#"
+        type Query { search(text: String!): [SearchResult!]! }
+        type User { id: ID! name: String! }
+        type Post { id: ID! title: String! }
+        union SearchResult = User | Post
+        "#,
+    )
+    .unwrap()
+    .build()
+    .unwrap();
+
+    let result = ExecutableDocumentBuilder::from_str(
+        &schema,
+        FragmentRegistry::empty(),
+        r#"
+        query Search {
+            search(text: "graphql") {
+                ... on User { id name }
+                ... on Post { id title }
+            }
+        }
+        "#,
+        None,
+    );
+
+    let doc = result.unwrap().build().unwrap();
+    assert_eq!(doc.operations().len(), 1);
+
+    let selections = doc.operations()[0].selection_set().selections();
+    assert_eq!(selections.len(), 1);
+    let search_selection_set = match &selections[0] {
+        Selection::Field(field) => field.selection_set().unwrap(),
+        other => panic!("Expected a field selection, found {other:?}"),
+    };
+    assert_eq!(search_selection_set.selections().len(), 2);
+}
+
+#[test]
+fn document_with_typename_selected_directly_on_union() {
+    let schema = SchemaBuilder::from_str(
+        None,
+        r#"
+        type Query { search(text: String!): [SearchResult!]! }
+        type User { id: ID! name: String! }
+        type Post { id: ID! title: String! }
+        union SearchResult = User | Post
+        "#,
+    )
+    .unwrap()
+    .build()
+    .unwrap();
+
+    let result = ExecutableDocumentBuilder::from_str(
+        &schema,
+        FragmentRegistry::empty(),
+        r#"
+        query Search {
+            search(text: "graphql") {
+                __typename
+                ... on User { id }
+            }
+        }
+        "#,
+        None,
+    );
+
+    let doc = result.unwrap().build().unwrap();
+    let selections = doc.operations()[0].selection_set().selections();
+    let search_selection_set = match &selections[0] {
+        Selection::Field(field) => field.selection_set().unwrap(),
+        other => panic!("Expected a field selection, found {other:?}"),
+    };
+    assert_eq!(search_selection_set.selections().len(), 2);
+
+    let typename_field = match &search_selection_set.selections()[0] {
+        Selection::Field(field) => field.field(),
+        other => panic!("Expected a field selection, found {other:?}"),
+    };
+    assert_eq!(typename_field.name(), "__typename");
+    assert_eq!(typename_field.parent_type_name(), "SearchResult");
+}
+
+#[test]
+fn document_with_non_meta_field_selected_directly_on_union() {
+    let schema = SchemaBuilder::from_str(
+        None,
+        r#"
+        type Query { search(text: String!): [SearchResult!]! }
+        type User { id: ID! name: String! }
+        type Post { id: ID! title: String! }
+        union SearchResult = User | Post
+        "#,
+    )
+    .unwrap()
+    .build()
+    .unwrap();
+
+    let result = ExecutableDocumentBuilder::from_str(
+        &schema,
+        FragmentRegistry::empty(),
+        r#"
+        query Search {
+            search(text: "graphql") {
+                id
+            }
+        }
+        "#,
+        None,
+    );
+
+    let Err(errors) = result else {
+        panic!("Expected selecting `id` directly on a union to fail");
+    };
+    let [ExecutableDocumentBuildError::OperationBuildErrors(op_errors)] =
+        errors.as_slice() else {
+        panic!("Expected a single OperationBuildErrors, found {errors:?}");
+    };
+    let [OperationBuildError::SelectionSetBuildErrors(ss_errors)] =
+        op_errors.as_slice() else {
+        panic!("Expected a single SelectionSetBuildErrors, found {op_errors:?}");
+    };
+    assert!(matches!(
+        ss_errors.as_slice(),
+        [SelectionSetBuildError::UndefinedFieldName {
+            parent_type_name,
+            undefined_field_name,
+            ..
+        }] if parent_type_name == "SearchResult" && undefined_field_name == "id",
+    ));
+}
+
+#[test]
+fn undefined_variable_type_reports_variable_name() {
+    let schema = setup_schema();
+
+    let result = ExecutableDocumentBuilder::from_str(
+        &schema,
+        FragmentRegistry::empty(),
+        r#"
+        query GetUser($userId: UserId!) {
+            user(id: $userId) { id }
+        }
+        "#,
+        None,
+    );
+
+    let Err(errors) = result else {
+        panic!("Expected an undefined variable type to fail");
+    };
+    let [ExecutableDocumentBuildError::OperationBuildErrors(op_errors)] =
+        errors.as_slice() else {
+        panic!("Expected a single OperationBuildErrors, found {errors:?}");
+    };
+    assert!(matches!(
+        op_errors.as_slice(),
+        [OperationBuildError::UndefinedVariableType { variable_name, .. }]
+            if variable_name == "userId",
+    ), "{op_errors:?}");
+}
+
 // =============================================================================
 // Tests for ExecutableDocument Access Methods
 // =============================================================================
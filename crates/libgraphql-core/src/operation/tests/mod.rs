@@ -1,2 +1,4 @@
 mod executable_document_builder_tests;
 mod fragment_registry_builder_tests;
+mod operation_builder_tests;
+mod operation_compatibility_checker_tests;
@@ -0,0 +1,143 @@
+use crate::operation::FragmentRegistry;
+use crate::operation::OperationBuildError;
+use crate::operation::OperationBuilder;
+use crate::schema::Schema;
+use crate::schema::SchemaBuilder;
+
+const SCHEMA: &str = r#"
+    type Query {
+        users(filter: UserFilter, limit: Int, ids: [ID!]): [String]
+    }
+
+    enum Color { RED GREEN }
+
+    input Page {
+        size: Int!
+        tags: [String!]
+    }
+
+    input UserFilter {
+        color: Color
+        pages: [Page!]
+        ratio: Float
+    }
+"#;
+
+fn build_schema(src: &str) -> Schema {
+    SchemaBuilder::from_str(None, src)
+        .unwrap()
+        .build()
+        .unwrap()
+}
+
+/// Builds `operation` against `schema` and returns the reasons and value
+/// paths of its `InvalidVariableDefaultValue` errors, keyed by variable name.
+fn invalid_default_values(
+    schema: &Schema,
+    operation: &str,
+) -> Vec<(String, String, Option<String>)> {
+    let result = OperationBuilder::from_str(
+        schema,
+        FragmentRegistry::empty(),
+        operation,
+        None,
+    );
+    let Err(errors) = result else {
+        return vec![];
+    };
+    errors.into_iter().map(|err| match err {
+        OperationBuildError::InvalidVariableDefaultValue {
+            reason,
+            value_path,
+            variable_name,
+            ..
+        } => (variable_name, reason, value_path),
+        other => panic!("unexpected error: {other:?}"),
+    }).collect()
+}
+
+/// Validates that variable default values which coerce to the variable's type
+/// are accepted, including Int-to-Float coercion, single values for list
+/// types, nested input objects, and `null` for nullable types.
+///
+/// Written by Claude Code. Reviewed/iterated on by @jeffmo.
+#[test]
+fn valid_variable_default_values_are_accepted() {
+    let schema = build_schema(SCHEMA);
+    let errors = invalid_default_values(&schema, r#"
+        query Users(
+            $filter: UserFilter = {
+                color: RED,
+                pages: [{size: 1, tags: "a"}],
+                ratio: 1,
+            },
+            $limit: Int = null,
+            $ids: [ID!] = 7,
+        ) {
+            users(filter: $filter, limit: $limit, ids: $ids)
+        }
+    "#);
+    assert!(errors.is_empty(), "{errors:#?}");
+}
+
+/// Validates that variable default values which do not coerce to the
+/// variable's type are rejected, with the path to the offending value when it
+/// is nested in a list or input object.
+///
+/// Written by Claude Code. Reviewed/iterated on by @jeffmo.
+#[test]
+fn invalid_variable_default_values_are_rejected() {
+    let schema = build_schema(SCHEMA);
+    let errors = invalid_default_values(&schema, r#"
+        query Users(
+            $limit: Int = "ten",
+            $color: Color = PURPLE,
+            $required: Int! = null,
+            $filter: UserFilter = {pages: [{size: 1}, {tags: ["a", 2]}]},
+            $missing: UserFilter = {pages: [{tags: []}]},
+            $big: Int = 3000000000,
+        ) {
+            users(limit: $limit)
+        }
+    "#);
+    let errors: Vec<(&str, &str, Option<&str>)> = errors
+        .iter()
+        .map(|(name, reason, value_path)| {
+            (name.as_str(), reason.as_str(), value_path.as_deref())
+        })
+        .collect();
+    assert_eq!(errors, [
+        ("limit", "expected `Int`, found `\"ten\"`", None),
+        ("color", "enum `Color` has no value `PURPLE`", None),
+        ("required", "expected a non-null value, found `null`", None),
+        ("filter", "expected `String`, found `2`", Some("pages[1].tags[1]")),
+        ("missing", "missing required field `Page.size`", Some("pages[0]")),
+        ("big", "expected `Int`, found `3000000000`", None),
+    ]);
+}
+
+/// Validates that the error message for a nested invalid variable default
+/// value includes the path to the offending value.
+#[test]
+fn invalid_variable_default_value_message_includes_value_path() {
+    let schema = build_schema(SCHEMA);
+    let result = OperationBuilder::from_str(
+        &schema,
+        FragmentRegistry::empty(),
+        r#"
+            query Users($filter: UserFilter = {pages: [{size: "one"}]}) {
+                users(filter: $filter)
+            }
+        "#,
+        None,
+    );
+    let Err(errors) = result else {
+        panic!("Expected an invalid default value to fail");
+    };
+    let messages: Vec<String> =
+        errors.iter().map(|err| err.to_string()).collect();
+    assert_eq!(messages, [
+        "Invalid default value for variable `$filter`: expected `Int`, found \
+        `\"one\"` at `pages[0].size`",
+    ]);
+}
@@ -0,0 +1,208 @@
+use crate::operation::ExecutableDocumentBuildError;
+use crate::operation::FragmentBuildError;
+use crate::operation::OperationBuildError;
+use crate::operation::OperationCompatibilityChecker;
+use crate::operation::SelectionSetBuildError;
+use crate::schema::Schema;
+use crate::schema::SchemaBuilder;
+
+const OLD_SCHEMA: &str = r#"
+    type Query {
+        me: User
+        user(id: ID!): User
+    }
+
+    type User {
+        id: ID!
+        name: String
+        email: String
+        legacyId: Int
+    }
+
+    input UserFilter {
+        name: String
+    }
+"#;
+
+fn build_schema(src: &str) -> Schema {
+    SchemaBuilder::from_str(None, src)
+        .unwrap()
+        .build()
+        .unwrap()
+}
+
+/// Returns the names of the undefined fields reported in a list of selection
+/// set build errors.
+fn undefined_field_names(errors: &[SelectionSetBuildError]) -> Vec<&str> {
+    errors
+        .iter()
+        .map(|err| match err {
+            SelectionSetBuildError::UndefinedFieldName {
+                undefined_field_name,
+                ..
+            } => undefined_field_name.as_str(),
+            err => panic!("Unexpected error: {err:?}"),
+        })
+        .collect()
+}
+
+/// Validates that removing a field no operation selects is not reported, while
+/// removing a selected field reports exactly the operations that select it.
+///
+/// Written by Claude Code. Reviewed/iterated on by @jeffmo.
+#[test]
+fn reports_only_operations_affected_by_schema_change() {
+    let old_schema = build_schema(OLD_SCHEMA);
+    let new_schema = build_schema(&OLD_SCHEMA.replace("legacyId: Int", ""));
+    let newer_schema = build_schema(&OLD_SCHEMA.replace("email: String", ""));
+
+    let mut checker = OperationCompatibilityChecker::new(&old_schema, &new_schema);
+    checker.add_document_str(
+        "query Me { me { id name } }\n\
+         query Emails { me { email } user(id: \"1\") { email } }",
+        None,
+    ).unwrap();
+    checker.add_document_str("{ me { id } }", None).unwrap();
+
+    let report = checker.check();
+    assert_eq!(report.num_operations_checked(), 3);
+    assert!(report.is_compatible());
+    assert!(report.invalid_operations().is_empty());
+
+    let mut checker = OperationCompatibilityChecker::new(&old_schema, &newer_schema);
+    checker.add_document_str(
+        "query Me { me { id name } }\n\
+         query Emails { me { email } user(id: \"1\") { email } }",
+        None,
+    ).unwrap();
+    checker.add_document_str("{ me { id } }", None).unwrap();
+
+    let report = checker.check();
+    assert!(!report.is_compatible());
+    assert_eq!(report.broken_operations().len(), 1);
+
+    let broken = &report.broken_operations()[0];
+    assert_eq!(broken.operation_name(), Some("Emails"));
+    assert_eq!(broken.document_index(), 0);
+    assert!(broken.fragment_errors().is_empty());
+    assert_eq!(broken.errors().len(), 1);
+    let OperationBuildError::SelectionSetBuildErrors(ss_errors) = &broken.errors()[0] else {
+        panic!("Unexpected error: {:?}", broken.errors()[0]);
+    };
+    assert_eq!(undefined_field_names(ss_errors), ["email", "email"]);
+}
+
+/// Validates that an operation is reported as broken when a fragment it
+/// spreads (directly or through another fragment, possibly defined in another
+/// document) no longer validates.
+///
+/// Written by Claude Code. Reviewed/iterated on by @jeffmo.
+#[test]
+fn reports_operations_broken_through_fragments() {
+    let old_schema = build_schema(OLD_SCHEMA);
+    let new_schema = build_schema(&OLD_SCHEMA.replace("legacyId: Int", ""));
+
+    let mut checker = OperationCompatibilityChecker::new(&old_schema, &new_schema);
+    checker.add_document_str(
+        "fragment UserFields on User { id ...LegacyFields }\n\
+         fragment LegacyFields on User { legacyId }\n\
+         fragment NameFields on User { name }",
+        None,
+    ).unwrap();
+    checker.add_document_str(
+        "query Me { me { ...UserFields } }\n\
+         query Name { me { ... on User { ...NameFields } } }",
+        None,
+    ).unwrap();
+
+    let report = checker.check();
+    assert_eq!(report.num_operations_checked(), 2);
+    assert_eq!(report.broken_operations().len(), 1);
+
+    let broken = &report.broken_operations()[0];
+    assert_eq!(broken.operation_name(), Some("Me"));
+    assert_eq!(broken.document_index(), 1);
+    assert!(broken.errors().is_empty());
+    assert_eq!(
+        broken.fragment_errors().keys().collect::<Vec<_>>(),
+        ["LegacyFields"],
+    );
+    let FragmentBuildError::SelectionSetBuildErrors(ss_errors) =
+        &broken.fragment_errors()["LegacyFields"]
+    else {
+        panic!("Unexpected error: {:?}", broken.fragment_errors());
+    };
+    assert_eq!(undefined_field_names(ss_errors), ["legacyId"]);
+}
+
+/// Validates that operations which already fail against the old schema are
+/// reported as invalid rather than broken, and that changes which break
+/// operation-level validation (e.g. a removed variable type or root type) are
+/// reported.
+///
+/// Written by Claude Code. Reviewed/iterated on by @jeffmo.
+#[test]
+fn separates_invalid_operations_from_broken_ones() {
+    let old_schema = build_schema(OLD_SCHEMA);
+    let new_schema = build_schema(
+        "type Query { me: User }\n\
+         type User { id: ID! }",
+    );
+
+    let mut checker = OperationCompatibilityChecker::new(&old_schema, &new_schema);
+    checker.add_document_str(
+        "query Typo { me { nmae } }\n\
+         query Filter($filter: UserFilter) { me { id } }\n\
+         mutation Update { update }",
+        None,
+    ).unwrap();
+
+    let report = checker.check();
+    assert_eq!(report.num_operations_checked(), 3);
+
+    let invalid_names: Vec<_> = report.invalid_operations()
+        .iter()
+        .map(|op| op.operation_name())
+        .collect();
+    assert_eq!(invalid_names, [Some("Typo"), Some("Update")]);
+    assert!(matches!(
+        report.invalid_operations()[1].errors()[..],
+        [OperationBuildError::NoMutationTypeDefinedInSchema],
+    ));
+
+    assert_eq!(report.broken_operations().len(), 1);
+    let broken = &report.broken_operations()[0];
+    assert_eq!(broken.operation_name(), Some("Filter"));
+    assert!(matches!(
+        &broken.errors()[..],
+        [OperationBuildError::UndefinedVariableType { variable_name, .. }]
+            if variable_name == "filter",
+    ));
+}
+
+/// Validates that a fragment name may only be defined once across the corpus,
+/// and that a rejected document is not added.
+///
+/// Written by Claude Code. Reviewed/iterated on by @jeffmo.
+#[test]
+fn rejects_duplicate_fragments_across_documents() {
+    let old_schema = build_schema(OLD_SCHEMA);
+    let mut checker = OperationCompatibilityChecker::new(&old_schema, &old_schema);
+    checker.add_document_str("fragment F on User { id }", None).unwrap();
+
+    let errors = checker.add_document_str(
+        "query Q { me { ...F } }\n\
+         fragment F on User { name }",
+        None,
+    ).unwrap_err();
+    assert!(matches!(
+        &errors[..],
+        [ExecutableDocumentBuildError::FragmentValidationErrors(frag_errors)]
+            if matches!(
+                &frag_errors[..],
+                [FragmentBuildError::DuplicateFragmentDefinition { fragment_name, .. }]
+                    if fragment_name == "F",
+            ),
+    ));
+    assert_eq!(checker.check().num_operations_checked(), 0);
+}
@@ -0,0 +1,40 @@
+use crate::Value;
+
+/// A Rust implementation of a custom scalar type (e.g. `DateTime`, `URL`,
+/// `JSON`, `BigInt`).
+///
+/// Custom scalars are opaque to the GraphQL type system: the spec leaves
+/// their input and result coercion rules to the service. Registering an
+/// implementation in a [`ScalarRegistry`](crate::scalars::ScalarRegistry)
+/// lets operation validation enforce those rules, so that an argument like
+/// `createdAt: "not-a-date"` is rejected when the operation is built, and
+/// gives executors one place to look up how to coerce variable values and
+/// serialize results.
+///
+/// Errors are returned as short human-readable reasons (e.g.
+/// `"expected an RFC 3339 timestamp"`); callers attach the location of the
+/// offending value.
+///
+/// See [Custom Scalars](https://spec.graphql.org/October2021/#sec-Scalars.Custom-Scalars).
+pub trait CustomScalar: Send + Sync {
+    /// Validates a literal written in an executable document: a field
+    /// argument or a variable's default value.
+    ///
+    /// Literals that contain variable references are not passed, since
+    /// their values are not known until execution.
+    fn validate_literal(&self, value: &Value) -> Result<(), String>;
+
+    /// Coerces a JSON input value (e.g. a variable value provided with a
+    /// request) to this scalar's canonical JSON representation.
+    fn coerce_input(
+        &self,
+        value: &serde_json::Value,
+    ) -> Result<serde_json::Value, String>;
+
+    /// Serializes a resolved result value to the JSON representation sent
+    /// to clients.
+    fn serialize(
+        &self,
+        value: &serde_json::Value,
+    ) -> Result<serde_json::Value, String>;
+}
@@ -0,0 +1,8 @@
+mod custom_scalar;
+mod scalar_registry;
+
+#[cfg(test)]
+mod tests;
+
+pub use custom_scalar::CustomScalar;
+pub use scalar_registry::ScalarRegistry;
@@ -0,0 +1,241 @@
+use crate::operation::ExecutableDocumentBuildError;
+use crate::operation::ExecutableDocumentBuilder;
+use crate::operation::FragmentRegistry;
+use crate::operation::OperationBuildError;
+use crate::operation::SelectionSetBuildError;
+use crate::scalars::CustomScalar;
+use crate::scalars::ScalarRegistry;
+use crate::schema::Schema;
+use crate::schema::SchemaBuilder;
+use crate::Value;
+use serde_json::json;
+use serde_json::Value as JsonValue;
+
+/// A minimal `Date` scalar accepting `YYYY-MM-DD` strings. Input values may
+/// also use `/` separators, which are normalized to `-`.
+struct Date;
+
+impl Date {
+    fn check(s: Option<&str>) -> Result<(), String> {
+        let is_date = s.is_some_and(|s| {
+            s.len() == 10 && s.char_indices().all(|(i, c)| match i {
+                4 | 7 => c == '-',
+                _ => c.is_ascii_digit(),
+            })
+        });
+        if is_date {
+            Ok(())
+        } else {
+            Err("expected a `YYYY-MM-DD` date".to_string())
+        }
+    }
+}
+
+impl CustomScalar for Date {
+    fn validate_literal(&self, value: &Value) -> Result<(), String> {
+        Self::check(value.as_str())
+    }
+
+    fn coerce_input(&self, value: &JsonValue) -> Result<JsonValue, String> {
+        let normalized = value.as_str().map(|s| s.replace('/', "-"));
+        Self::check(normalized.as_deref())?;
+        Ok(JsonValue::from(normalized))
+    }
+
+    fn serialize(&self, value: &JsonValue) -> Result<JsonValue, String> {
+        Self::check(value.as_str())?;
+        Ok(value.clone())
+    }
+}
+
+const DATE_URL: &str = "https://example.com/date";
+
+const SCHEMA: &str = r#"
+    scalar Date
+    scalar SpecifiedDate @specifiedBy(url: "https://example.com/date")
+    scalar Json
+
+    input Range {
+        after: Date
+        before: Date
+    }
+
+    type Query {
+        events(
+            after: Date,
+            on: SpecifiedDate,
+            range: Range,
+            meta: Json,
+            limit: Int,
+        ): [String]
+    }
+"#;
+
+fn build_schema(registry: ScalarRegistry) -> Schema {
+    SchemaBuilder::from_str(None, SCHEMA)
+        .unwrap()
+        .set_scalar_registry(registry)
+        .build()
+        .unwrap()
+}
+
+fn date_registry() -> ScalarRegistry {
+    let mut registry = ScalarRegistry::new();
+    registry.register("Date", Date);
+    registry.register_specified_by(DATE_URL, Date);
+    registry
+}
+
+/// Builds `operation` against `schema` and returns the messages of its
+/// operation build errors.
+fn operation_errors(schema: &Schema, operation: &str) -> Vec<String> {
+    let result = ExecutableDocumentBuilder::from_str(
+        schema,
+        FragmentRegistry::empty(),
+        operation,
+        None,
+    );
+    let Err(errors) = result else {
+        return vec![];
+    };
+    errors.into_iter().flat_map(|err| match err {
+        ExecutableDocumentBuildError::OperationBuildErrors(op_errors) => {
+            op_errors
+        },
+        other => panic!("unexpected error: {other:?}"),
+    }).flat_map(|err| match err {
+        OperationBuildError::SelectionSetBuildErrors(ss_errors) => {
+            ss_errors.iter().map(|err| err.to_string()).collect()
+        },
+        other => vec![other.to_string()],
+    }).collect()
+}
+
+/// Validates that the registry finds implementations by scalar name and by
+/// `@specifiedBy` URL, and that scalars without an implementation are not
+/// found.
+#[test]
+fn registry_looks_up_by_name_and_specified_by_url() {
+    let schema = build_schema(date_registry());
+    let scalar = |name: &str| match schema.all_types().get(name) {
+        Some(crate::types::GraphQLType::Scalar(scalar)) => scalar.clone(),
+        other => panic!("expected scalar `{name}`, found {other:?}"),
+    };
+    let registry = schema.scalar_registry();
+
+    assert!(registry.get(&scalar("Date")).is_some());
+    assert_eq!(scalar("SpecifiedDate").specified_by_url(), Some(DATE_URL));
+    assert!(registry.get(&scalar("SpecifiedDate")).is_some());
+    assert!(registry.get(&scalar("Json")).is_none());
+}
+
+/// Validates that a registered implementation's input coercion and result
+/// serialization hooks are reachable through the schema's registry.
+#[test]
+fn registry_coerces_inputs_and_serializes_results() {
+    let schema = build_schema(date_registry());
+    let Some(crate::types::GraphQLType::Scalar(date)) =
+        schema.all_types().get("Date").cloned() else {
+        panic!("expected scalar `Date`");
+    };
+    let date = schema.scalar_registry().get(&date).unwrap();
+
+    assert_eq!(date.coerce_input(&json!("2024/01/02")), Ok(json!("2024-01-02")));
+    assert_eq!(
+        date.coerce_input(&json!(20240102)),
+        Err("expected a `YYYY-MM-DD` date".to_string()),
+    );
+    assert_eq!(date.serialize(&json!("2024-01-02")), Ok(json!("2024-01-02")));
+    assert!(date.serialize(&json!("2024/01/02")).is_err());
+}
+
+/// Validates that custom scalar literals in field arguments are checked by
+/// the registered implementation, including when nested in an input object,
+/// while literals for unregistered scalars are accepted.
+#[test]
+fn argument_literals_are_validated_by_custom_scalars() {
+    let schema = build_schema(date_registry());
+    assert_eq!(
+        operation_errors(&schema, r#"
+            query {
+                valid: events(
+                    after: "2024-01-01",
+                    on: "2024-01-02",
+                    range: {before: "2024-02-01"},
+                    meta: {anything: [1, "two"]},
+                )
+                invalid: events(
+                    after: "not-a-date",
+                    on: 20240102,
+                    range: {after: "2024-01-01", before: "soon"},
+                )
+            }
+        "#),
+        [
+            "Invalid value for argument `after` of field `events`: invalid \
+             `Date` value `\"not-a-date\"`: expected a `YYYY-MM-DD` date",
+            "Invalid value for argument `on` of field `events`: invalid \
+             `SpecifiedDate` value `20240102`: expected a `YYYY-MM-DD` date",
+            "Invalid value for argument `range` of field `events`: invalid \
+             `Date` value `\"soon\"`: expected a `YYYY-MM-DD` date at `before`",
+        ],
+    );
+}
+
+/// Validates that custom scalar implementations also check variable default
+/// values, and are not consulted for argument values that are (or contain)
+/// variable references.
+#[test]
+fn variable_defaults_are_validated_by_custom_scalars() {
+    let schema = build_schema(date_registry());
+    assert_eq!(
+        operation_errors(&schema, r#"
+            query Events($after: Date = "yesterday", $before: Date) {
+                events(after: $after, range: {before: $before})
+            }
+        "#),
+        [
+            "Invalid default value for variable `$after`: invalid `Date` \
+             value `\"yesterday\"`: expected a `YYYY-MM-DD` date",
+        ],
+    );
+}
+
+/// Validates that without a registry, custom scalars accept any literal while
+/// built-in scalar arguments are still checked.
+#[test]
+fn argument_literals_without_registry() {
+    let schema = build_schema(ScalarRegistry::new());
+    let errors = operation_errors(&schema, r#"
+        query {
+            events(after: "not-a-date", limit: "ten")
+        }
+    "#);
+    assert_eq!(errors, [
+        "Invalid value for argument `limit` of field `events`: expected \
+         `Int`, found `\"ten\"`",
+    ]);
+    assert!(matches!(
+        ExecutableDocumentBuilder::from_str(
+            &schema,
+            FragmentRegistry::empty(),
+            "query { events(limit: 1.5) }",
+            None,
+        ),
+        Err(errors) if matches!(
+            errors.as_slice(),
+            [ExecutableDocumentBuildError::OperationBuildErrors(op_errors)]
+                if matches!(
+                    op_errors.as_slice(),
+                    [OperationBuildError::SelectionSetBuildErrors(ss_errors)]
+                        if matches!(
+                            ss_errors.as_slice(),
+                            [SelectionSetBuildError::InvalidArgumentValue {
+                                value_path: None,
+                                ..
+                            }],
+                        ),
+                ),
+        ),
+    ));
+}
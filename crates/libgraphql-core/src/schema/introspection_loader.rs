@@ -0,0 +1,613 @@
+//! Converts a JSON-serialized
+//! [introspection](https://spec.graphql.org/October2021/#sec-Introspection)
+//! result into a schema AST [`Document`](ast::schema::Document) that
+//! [`SchemaBuilder::load_ast()`](crate::schema::SchemaBuilder::load_ast)
+//! can load.
+//!
+//! Malformed input is reported as
+//! [`SchemaBuildError::MalformedIntrospection`] with a JSON path to
+//! the offending value (e.g. `$.data.__schema.types[3].fields[0].type`).
+
+use crate::ast;
+use crate::schema::schema_builder::builtin_directive_names;
+use crate::schema::SchemaBuildError;
+use serde_json::Map;
+use serde_json::Value as JsonValue;
+use std::str::FromStr;
+
+type JsonObject = Map<String, JsonValue>;
+type Result<T> = std::result::Result<T, SchemaBuildError>;
+
+/// Scalars the [`TypesMapBuilder`](crate::types::TypesMapBuilder)
+/// seeds itself.
+const BUILTIN_SCALAR_NAMES: [&str; 5] = [
+    "Boolean",
+    "Float",
+    "ID",
+    "Int",
+    "String",
+];
+
+/// Converts `json`, which may be either a full GraphQL response
+/// (`{"data": {"__schema": ...}}`) or just its `data`
+/// (`{"__schema": ...}`), into a schema document.
+///
+/// The document starts with a `schema { ... }` definition binding
+/// the root operation types, followed by every type and directive
+/// other than the introspection types (`__Type`, ...) and the
+/// built-in scalars and directives.
+pub(crate) fn introspection_json_to_ast(
+    json: &str,
+) -> Result<ast::schema::Document> {
+    let root: JsonValue = serde_json::from_str(json).map_err(|err| {
+        malformed("$", format!("invalid JSON: {err}"))
+    })?;
+    let root_obj = as_object(&root, "$")?;
+
+    let (data, data_path) = if root_obj.contains_key("data") {
+        (required_object(root_obj, "$", "data")?, "$.data")
+    } else if root_obj.contains_key("__schema") {
+        (root_obj, "$")
+    } else {
+        return Err(malformed(
+            "$",
+            "expected a `data` or `__schema` field".to_string(),
+        ));
+    };
+    let schema_path = format!("{data_path}.__schema");
+    let schema = required_object(data, data_path, "__schema")?;
+    schema_to_ast(schema, &schema_path)
+}
+
+fn schema_to_ast(
+    schema: &JsonObject,
+    path: &str,
+) -> Result<ast::schema::Document> {
+    use ast::schema::Definition;
+
+    let schema_def = ast::schema::SchemaDefinition {
+        query: Some(root_type_name(
+            required_object(schema, path, "queryType")?,
+            &format!("{path}.queryType"),
+        )?),
+        mutation: optional_object(schema, path, "mutationType")?
+            .map(|type_ref| {
+                root_type_name(type_ref, &format!("{path}.mutationType"))
+            })
+            .transpose()?,
+        subscription: optional_object(schema, path, "subscriptionType")?
+            .map(|type_ref| {
+                root_type_name(type_ref, &format!("{path}.subscriptionType"))
+            })
+            .transpose()?,
+        ..Default::default()
+    };
+    let mut definitions = vec![Definition::SchemaDefinition(schema_def)];
+
+    for (i, type_) in required_array(schema, path, "types")?.iter().enumerate() {
+        let type_path = format!("{path}.types[{i}]");
+        if let Some(type_def) = type_to_ast(type_, &type_path)? {
+            definitions.push(Definition::TypeDefinition(type_def));
+        }
+    }
+
+    let directives = required_array(schema, path, "directives")?;
+    for (i, directive) in directives.iter().enumerate() {
+        let directive_path = format!("{path}.directives[{i}]");
+        if let Some(directive_def) =
+            directive_to_ast(directive, &directive_path)?
+        {
+            definitions.push(Definition::DirectiveDefinition(directive_def));
+        }
+    }
+
+    Ok(ast::schema::Document { definitions })
+}
+
+/// Reads a root operation type reference (`{"name": "Query"}`).
+fn root_type_name(type_ref: &JsonObject, path: &str) -> Result<String> {
+    required_str(type_ref, path, "name").map(str::to_string)
+}
+
+// ---------------------------------------------------------
+// __Type
+// ---------------------------------------------------------
+
+/// Converts a `__Type` record, returning `None` for introspection
+/// types and built-in scalars.
+fn type_to_ast(
+    value: &JsonValue,
+    path: &str,
+) -> Result<Option<ast::schema::TypeDefinition>> {
+    use ast::schema::TypeDefinition;
+
+    let obj = as_object(value, path)?;
+    let kind = required_str(obj, path, "kind")?;
+    let name = required_str(obj, path, "name")?;
+    let description = optional_str(obj, path, "description")?
+        .map(str::to_string);
+
+    // The introspection types themselves (`__Schema`, `__Type`,
+    // ...) are implicit in every schema.
+    if name.starts_with("__") {
+        return Ok(None);
+    }
+
+    let type_def = match kind {
+        "ENUM" => {
+            let mut enum_def = ast::schema::EnumType::new(name.to_string());
+            enum_def.description = description;
+            let values_path = format!("{path}.enumValues");
+            let values = required_array(obj, path, "enumValues")?;
+            for (i, value) in values.iter().enumerate() {
+                let value_path = format!("{values_path}[{i}]");
+                let value_obj = as_object(value, &value_path)?;
+                let mut enum_value = ast::schema::EnumValue::new(
+                    required_str(value_obj, &value_path, "name")?.to_string(),
+                );
+                enum_value.description =
+                    optional_str(value_obj, &value_path, "description")?
+                        .map(str::to_string);
+                enum_value.directives =
+                    deprecation(value_obj, &value_path)?.into_iter().collect();
+                enum_def.values.push(enum_value);
+            }
+            TypeDefinition::Enum(enum_def)
+        },
+
+        "INPUT_OBJECT" => {
+            let mut inputobj_def =
+                ast::schema::InputObjectType::new(name.to_string());
+            inputobj_def.description = description;
+            if optional_bool(obj, path, "isOneOf")? == Some(true) {
+                inputobj_def.directives.push(directive("oneOf", vec![]));
+            }
+            inputobj_def.fields =
+                input_values(obj, path, "inputFields")?;
+            TypeDefinition::InputObject(inputobj_def)
+        },
+
+        "INTERFACE" => {
+            let mut iface_def =
+                ast::schema::InterfaceType::new(name.to_string());
+            iface_def.description = description;
+            iface_def.fields = fields(obj, path)?;
+            // `interfaces` on interface types was only added in the
+            // October 2021 spec, so older servers may omit it.
+            iface_def.implements_interfaces =
+                match optional_array(obj, path, "interfaces")? {
+                    Some(ifaces) => named_type_refs(
+                        ifaces,
+                        &format!("{path}.interfaces"),
+                    )?,
+                    None => vec![],
+                };
+            TypeDefinition::Interface(iface_def)
+        },
+
+        "OBJECT" => {
+            let mut obj_def = ast::schema::ObjectType::new(name.to_string());
+            obj_def.description = description;
+            obj_def.fields = fields(obj, path)?;
+            obj_def.implements_interfaces = named_type_refs(
+                required_array(obj, path, "interfaces")?,
+                &format!("{path}.interfaces"),
+            )?;
+            TypeDefinition::Object(obj_def)
+        },
+
+        "SCALAR" => {
+            if BUILTIN_SCALAR_NAMES.contains(&name) {
+                return Ok(None);
+            }
+            let mut scalar_def = ast::schema::ScalarType::new(name.to_string());
+            scalar_def.description = description;
+            if let Some(url) = optional_str(obj, path, "specifiedByURL")? {
+                scalar_def.directives.push(directive(
+                    "specifiedBy",
+                    vec![("url", ast::Value::String(url.to_string()))],
+                ));
+            }
+            TypeDefinition::Scalar(scalar_def)
+        },
+
+        "UNION" => {
+            let mut union_def = ast::schema::UnionType::new(name.to_string());
+            union_def.description = description;
+            union_def.types = named_type_refs(
+                required_array(obj, path, "possibleTypes")?,
+                &format!("{path}.possibleTypes"),
+            )?;
+            TypeDefinition::Union(union_def)
+        },
+
+        "LIST" | "NON_NULL" => return Err(malformed(
+            &format!("{path}.kind"),
+            format!("wrapping type kind `{kind}` cannot be a named type"),
+        )),
+
+        _ => return Err(malformed(
+            &format!("{path}.kind"),
+            format!("unknown type kind `{kind}`"),
+        )),
+    };
+
+    Ok(Some(type_def))
+}
+
+// ---------------------------------------------------------
+// __Field and __InputValue
+// ---------------------------------------------------------
+
+fn fields(
+    obj: &JsonObject,
+    path: &str,
+) -> Result<Vec<ast::schema::Field>> {
+    let fields_path = format!("{path}.fields");
+    required_array(obj, path, "fields")?
+        .iter()
+        .enumerate()
+        .map(|(i, field)| {
+            let field_path = format!("{fields_path}[{i}]");
+            let field_obj = as_object(field, &field_path)?;
+            Ok(ast::schema::Field {
+                position: ast::AstPos::default(),
+                description: optional_str(field_obj, &field_path, "description")?
+                    .map(str::to_string),
+                name: required_str(field_obj, &field_path, "name")?
+                    .to_string(),
+                arguments: input_values(field_obj, &field_path, "args")?,
+                field_type: required_type_ref(field_obj, &field_path, "type")?,
+                directives: deprecation(field_obj, &field_path)?
+                    .into_iter()
+                    .collect(),
+            })
+        })
+        .collect()
+}
+
+/// Converts the `__InputValue` records at `obj[key]` (field and
+/// directive `args`, or input object `inputFields`).
+fn input_values(
+    obj: &JsonObject,
+    path: &str,
+    key: &str,
+) -> Result<Vec<ast::schema::InputValue>> {
+    let values_path = format!("{path}.{key}");
+    required_array(obj, path, key)?
+        .iter()
+        .enumerate()
+        .map(|(i, value)| {
+            let value_path = format!("{values_path}[{i}]");
+            let value_obj = as_object(value, &value_path)?;
+            let default_value =
+                match optional_str(value_obj, &value_path, "defaultValue")? {
+                    Some(literal) => Some(
+                        parse_default_value(literal).ok_or_else(|| malformed(
+                            &format!("{value_path}.defaultValue"),
+                            format!(
+                                "`{literal}` is not a valid GraphQL input \
+                                value literal",
+                            ),
+                        ))?,
+                    ),
+                    None => None,
+                };
+            Ok(ast::schema::InputValue {
+                position: ast::AstPos::default(),
+                description: optional_str(value_obj, &value_path, "description")?
+                    .map(str::to_string),
+                name: required_str(value_obj, &value_path, "name")?
+                    .to_string(),
+                value_type: required_type_ref(value_obj, &value_path, "type")?,
+                default_value,
+                directives: deprecation(value_obj, &value_path)?
+                    .into_iter()
+                    .collect(),
+            })
+        })
+        .collect()
+}
+
+/// Converts `isDeprecated` / `deprecationReason` into a
+/// `@deprecated` annotation.
+fn deprecation(
+    obj: &JsonObject,
+    path: &str,
+) -> Result<Option<ast::operation::Directive>> {
+    if optional_bool(obj, path, "isDeprecated")? != Some(true) {
+        return Ok(None);
+    }
+    let arguments = optional_str(obj, path, "deprecationReason")?
+        .map(|reason| ("reason", ast::Value::String(reason.to_string())))
+        .into_iter()
+        .collect();
+    Ok(Some(directive("deprecated", arguments)))
+}
+
+// ---------------------------------------------------------
+// __Type references
+// ---------------------------------------------------------
+
+fn required_type_ref(
+    obj: &JsonObject,
+    path: &str,
+    key: &str,
+) -> Result<ast::schema::Type> {
+    type_ref(required(obj, path, key)?, &format!("{path}.{key}"))
+}
+
+/// Converts a (possibly wrapped) type reference such as
+/// `{"kind": "NON_NULL", "ofType": {"kind": "SCALAR", ...}}`.
+fn type_ref(value: &JsonValue, path: &str) -> Result<ast::schema::Type> {
+    use ast::schema::Type;
+
+    let obj = as_object(value, path)?;
+    let kind = required_str(obj, path, "kind")?;
+    match kind {
+        "LIST" => Ok(Type::ListType(Box::new(
+            required_type_ref(obj, path, "ofType")?,
+        ))),
+        "NON_NULL" => match required_type_ref(obj, path, "ofType")? {
+            Type::NonNullType(_) => Err(malformed(
+                &format!("{path}.ofType"),
+                "`NON_NULL` must not wrap another `NON_NULL`".to_string(),
+            )),
+            inner => Ok(Type::NonNullType(Box::new(inner))),
+        },
+        "ENUM" | "INPUT_OBJECT" | "INTERFACE" | "OBJECT" | "SCALAR"
+        | "UNION" => Ok(Type::NamedType(
+            required_str(obj, path, "name")?.to_string(),
+        )),
+        _ => Err(malformed(
+            &format!("{path}.kind"),
+            format!("unknown type kind `{kind}`"),
+        )),
+    }
+}
+
+/// Reads the names of the unwrapped type references listed in
+/// `interfaces` or `possibleTypes`.
+fn named_type_refs(values: &[JsonValue], path: &str) -> Result<Vec<String>> {
+    values
+        .iter()
+        .enumerate()
+        .map(|(i, value)| {
+            let ref_path = format!("{path}[{i}]");
+            let obj = as_object(value, &ref_path)?;
+            Ok(required_str(obj, &ref_path, "name")?.to_string())
+        })
+        .collect()
+}
+
+// ---------------------------------------------------------
+// __Directive
+// ---------------------------------------------------------
+
+/// Converts a `__Directive` record, returning `None` for built-in
+/// directives.
+fn directive_to_ast(
+    value: &JsonValue,
+    path: &str,
+) -> Result<Option<ast::schema::DirectiveDefinition>> {
+    let obj = as_object(value, path)?;
+    let name = required_str(obj, path, "name")?;
+    if builtin_directive_names().contains(name) {
+        return Ok(None);
+    }
+
+    let mut directive_def =
+        ast::schema::DirectiveDefinition::new(name.to_string());
+    directive_def.description = optional_str(obj, path, "description")?
+        .map(str::to_string);
+    // `isRepeatable` was only added in the October 2021 spec.
+    directive_def.repeatable =
+        optional_bool(obj, path, "isRepeatable")?.unwrap_or(false);
+    directive_def.arguments = input_values(obj, path, "args")?;
+
+    let locations_path = format!("{path}.locations");
+    let locations = required_array(obj, path, "locations")?;
+    for (i, location) in locations.iter().enumerate() {
+        let location_path = format!("{locations_path}[{i}]");
+        let location = location.as_str().ok_or_else(|| malformed(
+            &location_path,
+            format!("expected a string, found {}", json_kind(location)),
+        ))?;
+        directive_def.locations.push(
+            ast::schema::DirectiveLocation::from_str(location).map_err(|_| {
+                malformed(
+                    &location_path,
+                    format!("unknown directive location `{location}`"),
+                )
+            })?,
+        );
+    }
+
+    Ok(Some(directive_def))
+}
+
+// ---------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------
+
+fn directive(
+    name: &str,
+    arguments: Vec<(&str, ast::Value)>,
+) -> ast::operation::Directive {
+    ast::operation::Directive {
+        position: ast::AstPos::default(),
+        name: name.to_string(),
+        arguments: arguments
+            .into_iter()
+            .map(|(arg_name, value)| (arg_name.to_string(), value))
+            .collect(),
+    }
+}
+
+/// Parses an introspection `defaultValue` (a GraphQL literal
+/// printed as a string, e.g. `"{limit: 10}"`).
+///
+/// The literal is parsed as the default value of a one-field input
+/// object. Anything other than a single clean field (e.g. a literal
+/// that closes the braces itself) or a literal containing a
+/// variable is rejected.
+fn parse_default_value(literal: &str) -> Option<ast::Value> {
+    let source = format!(
+        "input IntrospectionDefaultValue {{ value: Int = {literal} }}",
+    );
+    let doc = ast::schema::parse(&source).ok()?;
+    let [ast::schema::Definition::TypeDefinition(
+        ast::schema::TypeDefinition::InputObject(inputobj_def),
+    )] = doc.definitions.as_slice() else {
+        return None;
+    };
+    let [field] = inputobj_def.fields.as_slice() else {
+        return None;
+    };
+    field.default_value
+        .clone()
+        .filter(|value| !contains_variable(value))
+}
+
+fn contains_variable(value: &ast::Value) -> bool {
+    match value {
+        ast::Value::Variable(_) => true,
+        ast::Value::List(items) => items.iter().any(contains_variable),
+        ast::Value::Object(fields) => fields.values().any(contains_variable),
+        _ => false,
+    }
+}
+
+fn malformed(json_path: &str, message: String) -> SchemaBuildError {
+    SchemaBuildError::MalformedIntrospection {
+        json_path: json_path.to_string(),
+        message,
+    }
+}
+
+fn json_kind(value: &JsonValue) -> &'static str {
+    match value {
+        JsonValue::Array(_) => "an array",
+        JsonValue::Bool(_) => "a boolean",
+        JsonValue::Null => "null",
+        JsonValue::Number(_) => "a number",
+        JsonValue::Object(_) => "an object",
+        JsonValue::String(_) => "a string",
+    }
+}
+
+fn as_object<'j>(value: &'j JsonValue, path: &str) -> Result<&'j JsonObject> {
+    value.as_object().ok_or_else(|| malformed(
+        path,
+        format!("expected an object, found {}", json_kind(value)),
+    ))
+}
+
+/// Returns `obj[key]`, or an error if it is missing or `null`.
+fn required<'j>(
+    obj: &'j JsonObject,
+    path: &str,
+    key: &str,
+) -> Result<&'j JsonValue> {
+    match obj.get(key) {
+        None | Some(JsonValue::Null) => Err(malformed(
+            &format!("{path}.{key}"),
+            "missing required field".to_string(),
+        )),
+        Some(value) => Ok(value),
+    }
+}
+
+/// Returns `obj[key]`, or `None` if it is missing or `null`.
+fn optional<'j>(obj: &'j JsonObject, key: &str) -> Option<&'j JsonValue> {
+    obj.get(key).filter(|value| !value.is_null())
+}
+
+fn required_array<'j>(
+    obj: &'j JsonObject,
+    path: &str,
+    key: &str,
+) -> Result<&'j Vec<JsonValue>> {
+    expect_array(required(obj, path, key)?, path, key)
+}
+
+fn optional_array<'j>(
+    obj: &'j JsonObject,
+    path: &str,
+    key: &str,
+) -> Result<Option<&'j Vec<JsonValue>>> {
+    optional(obj, key)
+        .map(|value| expect_array(value, path, key))
+        .transpose()
+}
+
+fn expect_array<'j>(
+    value: &'j JsonValue,
+    path: &str,
+    key: &str,
+) -> Result<&'j Vec<JsonValue>> {
+    value.as_array().ok_or_else(|| malformed(
+        &format!("{path}.{key}"),
+        format!("expected an array, found {}", json_kind(value)),
+    ))
+}
+
+fn required_object<'j>(
+    obj: &'j JsonObject,
+    path: &str,
+    key: &str,
+) -> Result<&'j JsonObject> {
+    as_object(required(obj, path, key)?, &format!("{path}.{key}"))
+}
+
+fn optional_object<'j>(
+    obj: &'j JsonObject,
+    path: &str,
+    key: &str,
+) -> Result<Option<&'j JsonObject>> {
+    optional(obj, key)
+        .map(|value| as_object(value, &format!("{path}.{key}")))
+        .transpose()
+}
+
+fn required_str<'j>(
+    obj: &'j JsonObject,
+    path: &str,
+    key: &str,
+) -> Result<&'j str> {
+    expect_str(required(obj, path, key)?, path, key)
+}
+
+fn optional_str<'j>(
+    obj: &'j JsonObject,
+    path: &str,
+    key: &str,
+) -> Result<Option<&'j str>> {
+    optional(obj, key)
+        .map(|value| expect_str(value, path, key))
+        .transpose()
+}
+
+fn expect_str<'j>(
+    value: &'j JsonValue,
+    path: &str,
+    key: &str,
+) -> Result<&'j str> {
+    value.as_str().ok_or_else(|| malformed(
+        &format!("{path}.{key}"),
+        format!("expected a string, found {}", json_kind(value)),
+    ))
+}
+
+fn optional_bool(
+    obj: &JsonObject,
+    path: &str,
+    key: &str,
+) -> Result<Option<bool>> {
+    optional(obj, key)
+        .map(|value| value.as_bool().ok_or_else(|| malformed(
+            &format!("{path}.{key}"),
+            format!("expected a boolean, found {}", json_kind(value)),
+        )))
+        .transpose()
+}
@@ -0,0 +1,346 @@
+use crate::DirectiveAnnotation;
+use crate::schema::IntrospectionOptions;
+use crate::schema::Schema;
+use crate::types::DeprecationState;
+use crate::types::Directive;
+use crate::types::EnumValue;
+use crate::types::Field;
+use crate::types::GraphQLType;
+use crate::types::InputField;
+use crate::types::Parameter;
+use crate::types::TypeAnnotation;
+use crate::types::introspection_types;
+use crate::Value;
+use indexmap::IndexMap;
+use serde_json::json;
+use serde_json::Value as JsonValue;
+
+/// Generates the result of the standard `IntrospectionQuery` for a
+/// [`Schema`].
+///
+/// Every record carries exactly the fields that query selects (including
+/// `isDeprecated`/`deprecationReason` on input values, `specifiedByURL`, and
+/// `isOneOf`), and fields that do not apply to a type's kind (e.g.
+/// `enumValues` on an object type) are `null`, as a server would resolve
+/// them. Types and directives are listed sorted by name (a [`Schema`] does
+/// not retain the order they were defined in), followed by the introspection
+/// types.
+pub(crate) struct IntrospectionPrinter<'a> {
+    options: &'a IntrospectionOptions,
+    schema: &'a Schema,
+}
+
+impl<'a> IntrospectionPrinter<'a> {
+    pub fn new(
+        schema: &'a Schema,
+        options: &'a IntrospectionOptions,
+    ) -> Self {
+        Self { options, schema }
+    }
+
+    pub fn print(&self) -> JsonValue {
+        let root_type = |graphql_type: Option<&GraphQLType>| match graphql_type {
+            Some(graphql_type) => json!({ "name": graphql_type.name() }),
+            None => JsonValue::Null,
+        };
+
+        let all_types = self.schema.all_types();
+        let mut schema_types: Vec<&GraphQLType> = all_types.values().collect();
+        schema_types.sort_by(|a, b| a.name().cmp(b.name()));
+        let types: Vec<JsonValue> = schema_types
+            .into_iter()
+            .chain(introspection_types().values())
+            .map(|graphql_type| self.full_type(graphql_type))
+            .collect();
+
+        let all_directives = self.schema.all_directives();
+        let mut directives: Vec<&Directive> =
+            all_directives.values().collect();
+        directives.sort_by(|a, b| a.name().cmp(b.name()));
+        let directives: Vec<JsonValue> = directives
+            .into_iter()
+            .map(|directive| self.directive(directive))
+            .collect();
+
+        json!({
+            "__schema": {
+                // Schema descriptions are not retained by the builder.
+                "description": null,
+                "queryType": root_type(Some(self.schema.query_type())),
+                "mutationType": root_type(self.schema.mutation_type()),
+                "subscriptionType": root_type(self.schema.subscription_type()),
+                "types": types,
+                "directives": directives,
+            },
+        })
+    }
+
+    // ---------------------------------------------------------
+    // __Type
+    // ---------------------------------------------------------
+
+    fn full_type(&self, graphql_type: &GraphQLType) -> JsonValue {
+        let mut fields = JsonValue::Null;
+        let mut input_fields = JsonValue::Null;
+        let mut interfaces = JsonValue::Null;
+        let mut enum_values = JsonValue::Null;
+        let mut possible_types = JsonValue::Null;
+        let mut specified_by_url = JsonValue::Null;
+        let mut is_one_of = JsonValue::Null;
+
+        match graphql_type {
+            GraphQLType::Enum(enum_type) => {
+                enum_values = self.enum_values(enum_type.values().values());
+            },
+            GraphQLType::InputObject(input_obj) => {
+                input_fields = self.input_fields(input_obj.fields().values());
+                is_one_of = input_obj.directives()
+                    .iter()
+                    .any(|annot| annot.directive_type_name() == "oneOf")
+                    .into();
+            },
+            GraphQLType::Interface(iface) => {
+                fields = self.fields(iface.fields());
+                interfaces = self.named_type_refs(iface.interface_names());
+                possible_types = self.named_type_refs(
+                    self.possible_type_names(iface.name()),
+                );
+            },
+            GraphQLType::Object(obj) => {
+                fields = self.fields(obj.fields());
+                interfaces = self.named_type_refs(obj.interface_names());
+            },
+            GraphQLType::Scalar(scalar) => {
+                specified_by_url = scalar.specified_by_url()
+                    .map_or(JsonValue::Null, JsonValue::from);
+            },
+            GraphQLType::Union(union_type) => {
+                possible_types =
+                    self.named_type_refs(union_type.member_type_names());
+            },
+            GraphQLType::Bool
+                | GraphQLType::Float
+                | GraphQLType::ID
+                | GraphQLType::Int
+                | GraphQLType::String => (),
+        }
+
+        json!({
+            "kind": type_kind_str(graphql_type),
+            "name": graphql_type.name(),
+            "description": graphql_type.description(),
+            "specifiedByURL": specified_by_url,
+            "isOneOf": is_one_of,
+            "fields": fields,
+            "inputFields": input_fields,
+            "interfaces": interfaces,
+            "enumValues": enum_values,
+            "possibleTypes": possible_types,
+        })
+    }
+
+    fn fields(&self, fields: &IndexMap<String, Field>) -> JsonValue {
+        fields
+            .values()
+            // `__typename` is implicit on every object and interface type.
+            .filter(|field| !field.name().starts_with("__"))
+            .map(|field| (field, DeprecationState::from(field.directives())))
+            .filter(|(_, state)| self.is_included(state))
+            .map(|(field, state)| {
+                let (is_deprecated, reason) = deprecation_fields(&state);
+                json!({
+                    "name": field.name(),
+                    "description": field.description(),
+                    "args": self.args(field.parameters()),
+                    "type": self.type_ref(field.type_annotation()),
+                    "isDeprecated": is_deprecated,
+                    "deprecationReason": reason,
+                })
+            })
+            .collect()
+    }
+
+    fn enum_values<'v>(
+        &self,
+        values: impl Iterator<Item = &'v EnumValue>,
+    ) -> JsonValue {
+        values
+            .map(|value| (value, DeprecationState::from(value.directives())))
+            .filter(|(_, state)| self.is_included(state))
+            .map(|(value, state)| {
+                let (is_deprecated, reason) = deprecation_fields(&state);
+                json!({
+                    "name": value.name(),
+                    "description": value.description(),
+                    "isDeprecated": is_deprecated,
+                    "deprecationReason": reason,
+                })
+            })
+            .collect()
+    }
+
+    /// The names of the object types that implement the interface named
+    /// `iface_name`, sorted by name.
+    fn possible_type_names(&self, iface_name: &str) -> Vec<&'a str> {
+        let mut names: Vec<&str> = self.schema
+            .types
+            .values()
+            .filter_map(GraphQLType::as_object)
+            .filter(|obj| obj.interface_names().contains(&iface_name))
+            .map(|obj| obj.name())
+            .collect();
+        names.sort();
+        names
+    }
+
+    // ---------------------------------------------------------
+    // __InputValue
+    // ---------------------------------------------------------
+
+    fn args(&self, params: &IndexMap<String, Parameter>) -> JsonValue {
+        params
+            .values()
+            .filter_map(|param| self.input_value(
+                param.name(),
+                param.description(),
+                param.type_annotation(),
+                param.default_value().as_ref(),
+                param.directives(),
+            ))
+            .collect()
+    }
+
+    fn input_fields<'f>(
+        &self,
+        fields: impl Iterator<Item = &'f InputField>,
+    ) -> JsonValue {
+        fields
+            .filter_map(|field| self.input_value(
+                field.name(),
+                field.description(),
+                field.type_annotation(),
+                field.default_value().as_ref(),
+                field.directives(),
+            ))
+            .collect()
+    }
+
+    /// Returns `None` if the input value is deprecated and deprecated
+    /// elements are excluded.
+    fn input_value(
+        &self,
+        name: &str,
+        description: Option<&str>,
+        type_annotation: &TypeAnnotation,
+        default_value: Option<&Value>,
+        directives: &Vec<DirectiveAnnotation>,
+    ) -> Option<JsonValue> {
+        let state = DeprecationState::from(directives);
+        if !self.is_included(&state) {
+            return None;
+        }
+        let (is_deprecated, reason) = deprecation_fields(&state);
+        Some(json!({
+            "name": name,
+            "description": description,
+            "type": self.type_ref(type_annotation),
+            "defaultValue": default_value.map(Value::to_graphql_string),
+            "isDeprecated": is_deprecated,
+            "deprecationReason": reason,
+        }))
+    }
+
+    // ---------------------------------------------------------
+    // __Directive
+    // ---------------------------------------------------------
+
+    fn directive(&self, directive: &Directive) -> JsonValue {
+        let locations: Vec<&str> = directive.locations()
+            .iter()
+            .map(|location| location.as_str())
+            .collect();
+        json!({
+            "name": directive.name(),
+            "description": directive.description(),
+            "isRepeatable": directive.is_repeatable(),
+            "locations": locations,
+            "args": self.args(directive.parameters()),
+        })
+    }
+
+    // ---------------------------------------------------------
+    // Type references
+    // ---------------------------------------------------------
+
+    /// Converts a type annotation into a (possibly wrapped) type reference
+    /// such as `{"kind": "NON_NULL", "name": null, "ofType": {...}}`.
+    fn type_ref(&self, annotation: &TypeAnnotation) -> JsonValue {
+        let unwrapped = match annotation {
+            TypeAnnotation::List(list) => json!({
+                "kind": "LIST",
+                "name": null,
+                "ofType": self.type_ref(list.inner_type_annotation()),
+            }),
+            TypeAnnotation::Named(named) => {
+                self.named_type_ref(named.graphql_type_name())
+            },
+        };
+        if annotation.nullable() {
+            unwrapped
+        } else {
+            json!({
+                "kind": "NON_NULL",
+                "name": null,
+                "ofType": unwrapped,
+            })
+        }
+    }
+
+    fn named_type_ref(&self, name: &str) -> JsonValue {
+        let graphql_type = self.schema
+            .types
+            .get(name)
+            .or_else(|| introspection_types().get(name))
+            .expect("type references are validated at build time");
+        json!({
+            "kind": type_kind_str(graphql_type),
+            "name": name,
+            "ofType": null,
+        })
+    }
+
+    fn named_type_refs(&self, names: Vec<&str>) -> JsonValue {
+        names.into_iter().map(|name| self.named_type_ref(name)).collect()
+    }
+
+    fn is_included(&self, state: &DeprecationState<'_>) -> bool {
+        self.options.include_deprecated || !state.is_deprecated()
+    }
+}
+
+/// The `isDeprecated` and `deprecationReason` values for an element.
+fn deprecation_fields<'s>(
+    state: &DeprecationState<'s>,
+) -> (bool, Option<&'s str>) {
+    match state {
+        DeprecationState::Deprecated(reason) => (true, Some(reason)),
+        DeprecationState::NotDeprecated => (false, None),
+    }
+}
+
+/// The `__TypeKind` of a named type.
+fn type_kind_str(graphql_type: &GraphQLType) -> &'static str {
+    match graphql_type {
+        GraphQLType::Bool
+            | GraphQLType::Float
+            | GraphQLType::ID
+            | GraphQLType::Int
+            | GraphQLType::Scalar(_)
+            | GraphQLType::String => "SCALAR",
+        GraphQLType::Enum(_) => "ENUM",
+        GraphQLType::InputObject(_) => "INPUT_OBJECT",
+        GraphQLType::Interface(_) => "INTERFACE",
+        GraphQLType::Object(_) => "OBJECT",
+        GraphQLType::Union(_) => "UNION",
+    }
+}
@@ -1,9 +1,14 @@
 pub mod _macro_runtime;
+mod introspection_loader;
+mod introspection_options;
+mod introspection_printer;
 #[allow(clippy::module_inception)]
 mod schema;
 pub(crate) mod schema_builder;
+mod sdl_printer;
 mod type_validation_error;
 
+pub use introspection_options::IntrospectionOptions;
 pub use schema::Schema;
 pub use schema_builder::SchemaBuilder;
 pub use schema_builder::SchemaBuildError;
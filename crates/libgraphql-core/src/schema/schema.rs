@@ -1,5 +1,9 @@
 use crate::ReadOnlyMap;
+use crate::scalars::ScalarRegistry;
+use crate::schema::IntrospectionOptions;
 use crate::schema::SchemaBuilder;
+use crate::schema::introspection_printer::IntrospectionPrinter;
+use crate::schema::sdl_printer::SdlPrinter;
 use crate::types::Directive;
 use crate::types::GraphQLType;
 use crate::types::NamedGraphQLTypeRef;
@@ -11,6 +15,8 @@ pub struct Schema {
     pub(crate) directive_defs: HashMap<String, Directive>,
     pub(crate) query_type: NamedGraphQLTypeRef,
     pub(crate) mutation_type: Option<NamedGraphQLTypeRef>,
+    #[serde(skip)]
+    pub(crate) scalar_registry: ScalarRegistry,
     pub(crate) subscription_type: Option<NamedGraphQLTypeRef>,
     pub(crate) types: HashMap<String, GraphQLType>,
 }
@@ -110,6 +116,96 @@ impl Schema {
             .expect("type is present in schema")
     }
 
+    /// Returns the [`ScalarRegistry`] that operations built against this
+    /// [`Schema`] use to validate custom scalar literals (see
+    /// [`SchemaBuilder::set_scalar_registry()`]).
+    pub fn scalar_registry(&self) -> &ScalarRegistry {
+        &self.scalar_registry
+    }
+
+    /// Generates the `data` the standard `IntrospectionQuery` would return for
+    /// this [`Schema`], using the default [`IntrospectionOptions`] (deprecated
+    /// elements included).
+    ///
+    /// The result can be loaded back with
+    /// [`SchemaBuilder::build_from_introspection_json()`] to rebuild an
+    /// equivalent [`Schema`].
+    pub fn to_introspection_json(&self) -> serde_json::Value {
+        self.to_introspection_json_with_options(
+            &IntrospectionOptions::default(),
+        )
+    }
+
+    /// Generates the `data` the standard `IntrospectionQuery` would return for
+    /// this [`Schema`]: a `{"__schema": ...}` object listing every type
+    /// (including the built-in scalars and the introspection types), every
+    /// directive, and the root operation types.
+    ///
+    /// `options.include_deprecated` is passed as the `includeDeprecated`
+    /// argument of every field that accepts one.
+    ///
+    /// ```rust
+    /// use libgraphql_core::schema::IntrospectionOptions;
+    /// use libgraphql_core::schema::SchemaBuilder;
+    ///
+    /// let schema = SchemaBuilder::build_from_str(
+    ///     None,
+    ///     "type Query { old: Int @deprecated, new: Int }",
+    /// ).unwrap();
+    ///
+    /// let json = schema.to_introspection_json_with_options(
+    ///     &IntrospectionOptions { include_deprecated: false },
+    /// );
+    /// let query = json["__schema"]["types"]
+    ///     .as_array()
+    ///     .unwrap()
+    ///     .iter()
+    ///     .find(|t| t["name"] == "Query")
+    ///     .unwrap();
+    /// assert_eq!(query["fields"].as_array().unwrap().len(), 1);
+    /// assert_eq!(query["fields"][0]["name"], "new");
+    /// ```
+    pub fn to_introspection_json_with_options(
+        &self,
+        options: &IntrospectionOptions,
+    ) -> serde_json::Value {
+        IntrospectionPrinter::new(self, options).print()
+    }
+
+    /// Prints this [`Schema`] as GraphQL SDL.
+    ///
+    /// Built-in directives and scalars are omitted, and directive and type
+    /// definitions are sorted by name. A `schema { ... }` definition is
+    /// printed only when the root operation types differ from the defaults
+    /// (`Query`, `Mutation`, `Subscription`). The output can be loaded back
+    /// with [`SchemaBuilder::build_from_str()`] to rebuild an equivalent
+    /// [`Schema`].
+    ///
+    /// ```rust
+    /// use libgraphql_core::schema::SchemaBuilder;
+    ///
+    /// let schema = SchemaBuilder::build_from_str(
+    ///     None,
+    ///     "type Query { b: Int, a: Color }
+    ///      enum Color { RED }",
+    /// ).unwrap();
+    ///
+    /// // Types are sorted by name; fields keep their definition order.
+    /// assert_eq!(schema.to_sdl(), "\
+    /// enum Color {
+    ///   RED
+    /// }
+    ///
+    /// type Query {
+    ///   b: Int
+    ///   a: Color
+    /// }
+    /// ");
+    /// ```
+    pub fn to_sdl(&self) -> String {
+        SdlPrinter::new(self).print()
+    }
+
     /// Returns this [`Schema`]'s Subscription root operation type.
     //
     /// > **⚠️ NOTE**: It is ***strongly*** recommended that you use
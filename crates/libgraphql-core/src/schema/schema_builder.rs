@@ -2,6 +2,8 @@ use crate::ast;
 use crate::file_reader;
 use crate::loc;
 use crate::operation::OperationKind;
+use crate::scalars::ScalarRegistry;
+use crate::schema::introspection_loader::introspection_json_to_ast;
 use crate::schema::Schema;
 use crate::schema::TypeValidationError;
 use crate::types::Directive;
@@ -24,7 +26,7 @@ use thiserror::Error;
 
 type Result<T> = std::result::Result<T, SchemaBuildError>;
 
-fn builtin_directive_names() -> &'static HashSet<&'static str> {
+pub(crate) fn builtin_directive_names() -> &'static HashSet<&'static str> {
     static NAMES: OnceLock<HashSet<&'static str>> = OnceLock::new();
     NAMES.get_or_init(|| {
         HashSet::from([
@@ -54,6 +56,7 @@ pub struct SchemaBuilder {
     mutation_type: Option<NamedTypeDefLocation>,
     object_builder: ObjectTypeBuilder,
     scalar_builder: ScalarTypeBuilder,
+    scalar_registry: ScalarRegistry,
     subscription_type: Option<NamedTypeDefLocation>,
     types_map_builder: TypesMapBuilder,
     union_builder: UnionTypeBuilder,
@@ -123,6 +126,7 @@ impl SchemaBuilder {
                 t.type_name,
                 t.def_location,
             )),
+            scalar_registry: self.scalar_registry,
             subscription_type: subscription_type.map(|t| NamedGraphQLTypeRef::new(
                 t.type_name,
                 t.def_location,
@@ -142,6 +146,10 @@ impl SchemaBuilder {
         Self::from_ast(file_path, ast_doc).and_then(|builder| builder.build())
     }
 
+    pub fn build_from_introspection_json(json: &str) -> Result<Schema> {
+        Self::from_introspection_json(json).and_then(|builder| builder.build())
+    }
+
     pub fn build_from_str(
         file_path: Option<&Path>,
         content: impl AsRef<str>,
@@ -161,6 +169,10 @@ impl SchemaBuilder {
             .load_file(file_path)
     }
 
+    pub fn from_introspection_json(json: &str) -> Result<Self> {
+        Self::new().load_introspection_json(json)
+    }
+
     pub fn from_str(
         file_path: Option<&Path>,
         content: impl AsRef<str>,
@@ -204,6 +216,27 @@ impl SchemaBuilder {
         Ok(self)
     }
 
+    /// Loads every type and directive described by a JSON-serialized
+    /// [introspection](https://spec.graphql.org/October2021/#sec-Introspection)
+    /// result, such as the response to the canonical `IntrospectionQuery`.
+    ///
+    /// `json` may be either the full response (`{"data": {"__schema": ...}}`)
+    /// or just its `data` (`{"__schema": ...}`). The root operation types are
+    /// bound from `queryType`, `mutationType`, and `subscriptionType` (as if
+    /// by a `schema { ... }` definition); deprecations become
+    /// `@deprecated(reason:)` annotations, `specifiedByURL` becomes
+    /// `@specifiedBy(url:)`, and `isOneOf` becomes `@oneOf`. Introspection
+    /// types (`__Type`, ...) and the built-in scalars and directives are
+    /// skipped.
+    ///
+    /// Malformed input is reported as
+    /// [`SchemaBuildError::MalformedIntrospection`] with the JSON path of the
+    /// offending value (e.g. `$.data.__schema.types[3].fields[0].type`).
+    pub fn load_introspection_json(self, json: &str) -> Result<Self> {
+        let ast_doc = introspection_json_to_ast(json)?;
+        self.load_ast(None, ast_doc)
+    }
+
     pub fn load_str(
         self,
         file_path: Option<&Path>,
@@ -219,6 +252,14 @@ impl SchemaBuilder {
         self.load_ast(file_path, ast_doc)
     }
 
+    /// Sets the [`ScalarRegistry`] that operations built against the
+    /// resulting [`Schema`] use to validate custom scalar literals in field
+    /// arguments and variable default values.
+    pub fn set_scalar_registry(mut self, registry: ScalarRegistry) -> Self {
+        self.scalar_registry = registry;
+        self
+    }
+
     pub fn new() -> Self {
         let types_map_builder = TypesMapBuilder::new();
 
@@ -231,6 +272,7 @@ impl SchemaBuilder {
             mutation_type: None,
             object_builder: ObjectTypeBuilder::new(),
             scalar_builder: ScalarTypeBuilder::new(),
+            scalar_registry: ScalarRegistry::new(),
             subscription_type: None,
             types_map_builder,
             union_builder: UnionTypeBuilder::new(),
@@ -311,6 +353,7 @@ impl SchemaBuilder {
         self.directive_defs.insert(def.name.to_string(), Directive::Custom {
             def_location: directivedef_srcloc,
             description: def.description.to_owned(),
+            locations: def.locations.to_owned(),
             name: def.name.to_string(),
             params: def.arguments.iter().map(|input_val| (
                 input_val.name.to_string(),
@@ -318,7 +361,8 @@ impl SchemaBuilder {
                     file_path,
                     input_val,
                 ),
-            )).collect()
+            )).collect(),
+            repeatable: def.repeatable,
         });
 
         Ok(())
@@ -645,6 +689,12 @@ pub enum SchemaBuildError {
         interface_name: String,
     },
 
+    #[error("Malformed introspection result at `{json_path}`: {message}")]
+    MalformedIntrospection {
+        json_path: String,
+        message: String,
+    },
+
     #[error("Attempted to build a schema that has no Query operation type defined")]
     NoQueryOperationTypeDefined,
 
@@ -0,0 +1,331 @@
+use crate::DirectiveAnnotation;
+use crate::schema::Schema;
+use crate::types::Directive;
+use crate::types::EnumType;
+use crate::types::Field;
+use crate::types::GraphQLType;
+use crate::types::InputObjectType;
+use crate::types::InterfaceType;
+use crate::types::ObjectType;
+use crate::types::Parameter;
+use crate::types::ScalarType;
+use crate::types::UnionType;
+use indexmap::IndexMap;
+use libgraphql_parser::ast::StringValue;
+use std::fmt::Write;
+
+const INDENT: &str = "  ";
+
+/// Prints a [`Schema`] as GraphQL SDL.
+///
+/// Output follows the conventional layout used by most GraphQL tooling: a
+/// `schema { ... }` definition (only when needed), then directive
+/// definitions, then type definitions, separated by blank lines. Built-in
+/// directives and scalars are omitted, and directives and types are sorted by
+/// name (a [`Schema`] does not retain the order they were defined in).
+pub(crate) struct SdlPrinter<'a> {
+    output: String,
+    schema: &'a Schema,
+}
+
+impl<'a> SdlPrinter<'a> {
+    pub fn new(schema: &'a Schema) -> Self {
+        Self {
+            output: String::new(),
+            schema,
+        }
+    }
+
+    pub fn print(mut self) -> String {
+        let mut definitions = vec![];
+
+        if let Some(schema_def) = self.schema_definition() {
+            definitions.push(schema_def);
+        }
+
+        let defined_directives = self.schema.defined_directives();
+        let mut directives: Vec<&Directive> =
+            defined_directives.values().collect();
+        directives.sort_by(|a, b| a.name().cmp(b.name()));
+        let defined_types = self.schema.defined_types();
+        let mut types: Vec<&GraphQLType> = defined_types.values().collect();
+        types.sort_by(|a, b| a.name().cmp(b.name()));
+
+        for directive in directives {
+            self.print_directive_definition(directive);
+            definitions.push(std::mem::take(&mut self.output));
+        }
+        for graphql_type in types {
+            self.print_type(graphql_type);
+            definitions.push(std::mem::take(&mut self.output));
+        }
+
+        definitions.join("\n")
+    }
+
+    /// Returns a `schema { ... }` definition if the root operation types
+    /// differ from the ones a reader would infer from the default root type
+    /// names (`Query`, `Mutation`, `Subscription`) in the absence of a schema
+    /// definition.
+    ///
+    /// See [Default Root Operation Type Names](https://spec.graphql.org/October2021/#sec-Root-Operation-Types.Default-Root-Operation-Type-Names).
+    fn schema_definition(&self) -> Option<String> {
+        let types = self.schema.all_types();
+        let bindings = [
+            ("query", Some(self.schema.query_type()), "Query"),
+            ("mutation", self.schema.mutation_type(), "Mutation"),
+            ("subscription", self.schema.subscription_type(), "Subscription"),
+        ];
+        let is_default = bindings.iter().all(|(_, bound, default)| {
+            bound.map(|t| t.name()) == types.get(*default).map(|t| t.name())
+        });
+        if is_default {
+            return None;
+        }
+
+        let mut output = String::from("schema {\n");
+        for (operation, bound, _) in bindings {
+            if let Some(graphql_type) = bound {
+                let _ = writeln!(
+                    output,
+                    "{INDENT}{operation}: {}",
+                    graphql_type.name(),
+                );
+            }
+        }
+        output.push_str("}\n");
+        Some(output)
+    }
+
+    // ---------------------------------------------------------
+    // Definitions
+    // ---------------------------------------------------------
+
+    fn print_directive_definition(&mut self, directive: &Directive) {
+        self.print_description(directive.description(), "");
+        let _ = write!(self.output, "directive @{}", directive.name());
+        self.print_parameters(directive.parameters(), "");
+        if directive.is_repeatable() {
+            self.output.push_str(" repeatable");
+        }
+        let locations: Vec<&str> = directive.locations()
+            .iter()
+            .map(|location| location.as_str())
+            .collect();
+        let _ = writeln!(self.output, " on {}", locations.join(" | "));
+    }
+
+    fn print_type(&mut self, graphql_type: &GraphQLType) {
+        self.print_description(graphql_type.description(), "");
+        match graphql_type {
+            GraphQLType::Enum(enum_type) => self.print_enum(enum_type),
+            GraphQLType::InputObject(input_obj) => {
+                self.print_input_object(input_obj)
+            },
+            GraphQLType::Interface(iface) => self.print_interface(iface),
+            GraphQLType::Object(obj) => self.print_object(obj),
+            GraphQLType::Scalar(scalar) => self.print_scalar(scalar),
+            GraphQLType::Union(union_type) => self.print_union(union_type),
+            GraphQLType::Bool
+                | GraphQLType::Float
+                | GraphQLType::ID
+                | GraphQLType::Int
+                | GraphQLType::String => {
+                let _ = writeln!(self.output, "scalar {}", graphql_type.name());
+            },
+        }
+    }
+
+    fn print_enum(&mut self, enum_type: &EnumType) {
+        let _ = write!(self.output, "enum {}", enum_type.name());
+        self.print_directives(enum_type.directives());
+        self.output.push_str(" {\n");
+        for value in enum_type.values().values() {
+            self.print_description(value.description(), INDENT);
+            let _ = write!(self.output, "{INDENT}{}", value.name());
+            self.print_directives(value.directives());
+            self.output.push('\n');
+        }
+        self.output.push_str("}\n");
+    }
+
+    fn print_input_object(&mut self, input_obj: &InputObjectType) {
+        let _ = write!(self.output, "input {}", input_obj.name());
+        self.print_directives(input_obj.directives());
+        self.output.push_str(" {\n");
+        for field in input_obj.fields().values() {
+            self.print_description(field.description(), INDENT);
+            let _ = write!(
+                self.output,
+                "{INDENT}{}: {}",
+                field.name(),
+                field.type_annotation(),
+            );
+            if let Some(default_value) = field.default_value() {
+                let _ = write!(
+                    self.output,
+                    " = {}",
+                    default_value.to_graphql_string(),
+                );
+            }
+            self.print_directives(field.directives());
+            self.output.push('\n');
+        }
+        self.output.push_str("}\n");
+    }
+
+    fn print_interface(&mut self, iface: &InterfaceType) {
+        self.print_fielded_type(
+            "interface",
+            iface.name(),
+            &iface.interface_names(),
+            iface.directives(),
+            iface.fields(),
+        );
+    }
+
+    fn print_object(&mut self, obj: &ObjectType) {
+        self.print_fielded_type(
+            "type",
+            obj.name(),
+            &obj.interface_names(),
+            obj.directives(),
+            obj.fields(),
+        );
+    }
+
+    fn print_fielded_type(
+        &mut self,
+        keyword: &str,
+        name: &str,
+        interface_names: &[&str],
+        directives: &[DirectiveAnnotation],
+        fields: &IndexMap<String, Field>,
+    ) {
+        let _ = write!(self.output, "{keyword} {name}");
+        if !interface_names.is_empty() {
+            let _ = write!(
+                self.output,
+                " implements {}",
+                interface_names.join(" & "),
+            );
+        }
+        self.print_directives(directives);
+        self.output.push_str(" {\n");
+        // `__typename` is implicit on every object and interface type.
+        for field in fields.values().filter(|f| !f.name().starts_with("__")) {
+            self.print_description(field.description(), INDENT);
+            let _ = write!(self.output, "{INDENT}{}", field.name());
+            self.print_parameters(field.parameters(), INDENT);
+            let _ = write!(self.output, ": {}", field.type_annotation());
+            self.print_directives(field.directives());
+            self.output.push('\n');
+        }
+        self.output.push_str("}\n");
+    }
+
+    fn print_scalar(&mut self, scalar: &ScalarType) {
+        let _ = write!(self.output, "scalar {}", scalar.name());
+        self.print_directives(scalar.directives());
+        self.output.push('\n');
+    }
+
+    fn print_union(&mut self, union_type: &UnionType) {
+        let _ = write!(self.output, "union {}", union_type.name());
+        self.print_directives(union_type.directives());
+        let _ = writeln!(
+            self.output,
+            " = {}",
+            union_type.member_type_names().join(" | "),
+        );
+    }
+
+    // ---------------------------------------------------------
+    // Shared pieces
+    // ---------------------------------------------------------
+
+    /// Prints an argument definition list. Arguments are printed inline
+    /// unless any of them has a description, in which case each goes on its
+    /// own line (descriptions must precede the definition they describe).
+    fn print_parameters(
+        &mut self,
+        params: &IndexMap<String, Parameter>,
+        indent: &str,
+    ) {
+        if params.is_empty() {
+            return;
+        }
+
+        let multiline = params.values().any(|p| p.description().is_some());
+        let param_indent = format!("{indent}{INDENT}");
+        self.output.push('(');
+        for (i, param) in params.values().enumerate() {
+            if multiline {
+                self.output.push('\n');
+                self.print_description(param.description(), &param_indent);
+                self.output.push_str(&param_indent);
+            } else if i > 0 {
+                self.output.push_str(", ");
+            }
+            let _ = write!(
+                self.output,
+                "{}: {}",
+                param.name(),
+                param.type_annotation(),
+            );
+            if let Some(default_value) = param.default_value() {
+                let _ = write!(
+                    self.output,
+                    " = {}",
+                    default_value.to_graphql_string(),
+                );
+            }
+            self.print_directives(param.directives());
+        }
+        if multiline {
+            let _ = write!(self.output, "\n{indent}");
+        }
+        self.output.push(')');
+    }
+
+    /// Prints applied directives, each preceded by a space.
+    fn print_directives(&mut self, directives: &[DirectiveAnnotation]) {
+        for directive in directives {
+            let _ = write!(self.output, " @{}", directive.directive_type_name());
+            let args = directive.arguments();
+            if args.is_empty() {
+                continue;
+            }
+            let args: Vec<String> = args
+                .iter()
+                .map(|(name, value)| {
+                    format!("{name}: {}", value.to_graphql_string())
+                })
+                .collect();
+            let _ = write!(self.output, "({})", args.join(", "));
+        }
+    }
+
+    /// Prints a description on its own line(s) at `indent`, as a block
+    /// string when it can be represented exactly as one.
+    fn print_description(&mut self, description: Option<&str>, indent: &str) {
+        let Some(description) = description else {
+            return;
+        };
+        let mut literal = String::new();
+        if StringValue::is_printable_as_block_string(description) {
+            StringValue::append_block_string(&mut literal, description);
+        } else {
+            StringValue::append_quoted_string(&mut literal, description);
+        }
+        // Block string values strip the indentation common to their lines,
+        // so indenting every line keeps the value unchanged.
+        for line in literal.split('\n') {
+            if !line.is_empty() {
+                self.output.push_str(indent);
+            }
+            self.output.push_str(line);
+            self.output.push('\n');
+        }
+    }
+}
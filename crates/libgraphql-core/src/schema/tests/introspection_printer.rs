@@ -0,0 +1,185 @@
+use crate::schema::IntrospectionOptions;
+use crate::schema::SchemaBuilder;
+use crate::schema::SchemaBuildError;
+use serde_json::Value as JsonValue;
+use serde_json::json;
+
+type Result<T> = std::result::Result<T, SchemaBuildError>;
+
+const KITCHEN_SINK_JSON: &str =
+    include_str!("fixtures/introspection/kitchen_sink.json");
+
+const SCHEMA_SDL: &str = r#"
+directive @tag(name: String!) repeatable on FIELD_DEFINITION | OBJECT
+
+type Query implements Node {
+  id: ID!
+  search(term: String!, exact: Boolean @deprecated(reason: "Always exact.")): [Result!] @deprecated
+  node(id: ID! = "1"): Node
+}
+
+interface Node {
+  id: ID!
+}
+
+type User implements Node @tag(name: "user") {
+  id: ID!
+  role: Role
+}
+
+enum Role {
+  ADMIN
+  GUEST @deprecated(reason: "Guests were removed.")
+}
+
+input Filter {
+  name: String = "anyone"
+  legacy: Boolean @deprecated
+}
+
+union Result = User | Query
+
+scalar Url @specifiedBy(url: "https://url.spec.whatwg.org")
+"#;
+
+fn find_type<'a>(json: &'a JsonValue, name: &str) -> &'a JsonValue {
+    json["__schema"]["types"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|t| t["name"] == name)
+        .unwrap_or_else(|| panic!("no type named `{name}`"))
+}
+
+fn names(list: &JsonValue) -> Vec<&str> {
+    list.as_array()
+        .unwrap()
+        .iter()
+        .map(|item| item["name"].as_str().unwrap())
+        .collect()
+}
+
+#[test]
+fn round_trips_through_introspection_loader() -> Result<()> {
+    let schema = SchemaBuilder::build_from_str(None, SCHEMA_SDL)?;
+    let json = schema.to_introspection_json();
+
+    // Applied directives other than `@deprecated`, `@specifiedBy` and
+    // `@oneOf` are not part of introspection, so compare the JSON of the
+    // rebuilt schema rather than the schemas themselves.
+    let rebuilt =
+        SchemaBuilder::build_from_introspection_json(&json.to_string())?;
+    assert_eq!(rebuilt.to_introspection_json(), json);
+
+    Ok(())
+}
+
+#[test]
+fn round_trips_kitchen_sink_fixture() -> Result<()> {
+    let schema = SchemaBuilder::build_from_introspection_json(KITCHEN_SINK_JSON)?;
+    let json = schema.to_introspection_json();
+
+    let rebuilt =
+        SchemaBuilder::build_from_introspection_json(&json.to_string())?;
+    assert_eq!(rebuilt.to_introspection_json(), json);
+    assert_eq!(find_type(&json, "UserKey")["isOneOf"], true);
+    assert_eq!(find_type(&json, "UserFilter")["isOneOf"], false);
+    assert_eq!(find_type(&json, "Query")["isOneOf"], JsonValue::Null);
+
+    Ok(())
+}
+
+#[test]
+fn describes_types_and_directives() -> Result<()> {
+    let schema = SchemaBuilder::build_from_str(None, SCHEMA_SDL)?;
+    let json = schema.to_introspection_json();
+    let schema_json = &json["__schema"];
+
+    assert_eq!(schema_json["queryType"], json!({"name": "Query"}));
+    assert_eq!(schema_json["mutationType"], JsonValue::Null);
+
+    // Schema types sorted by name (including the built-in scalars), then the
+    // introspection types.
+    let type_names = names(&schema_json["types"]);
+    assert_eq!(&type_names[..4], ["Boolean", "Filter", "Float", "ID"]);
+    assert_eq!(type_names.last(), Some(&"__DirectiveLocation"));
+
+    let query = find_type(&json, "Query");
+    assert_eq!(query["kind"], "OBJECT");
+    assert_eq!(names(&query["fields"]), vec!["id", "search", "node"]);
+    assert_eq!(names(&query["interfaces"]), vec!["Node"]);
+    assert_eq!(query["enumValues"], JsonValue::Null);
+    let search = &query["fields"][1];
+    assert_eq!(search["isDeprecated"], true);
+    assert_eq!(search["deprecationReason"], "No longer supported");
+    assert_eq!(search["type"], json!({
+        "kind": "LIST",
+        "name": null,
+        "ofType": {
+            "kind": "NON_NULL",
+            "name": null,
+            "ofType": {"kind": "UNION", "name": "Result", "ofType": null},
+        },
+    }));
+    assert_eq!(search["args"][1]["deprecationReason"], "Always exact.");
+    assert_eq!(query["fields"][2]["args"][0]["defaultValue"], "\"1\"");
+
+    assert_eq!(names(&find_type(&json, "Node")["possibleTypes"]), vec!["Query", "User"]);
+    assert_eq!(names(&find_type(&json, "Result")["possibleTypes"]), vec!["User", "Query"]);
+    assert_eq!(
+        find_type(&json, "Url")["specifiedByURL"],
+        "https://url.spec.whatwg.org",
+    );
+    assert_eq!(find_type(&json, "String")["kind"], "SCALAR");
+    assert_eq!(
+        names(&find_type(&json, "__Type")["fields"])[..3],
+        ["kind", "name", "description"],
+    );
+
+    let directives = schema_json["directives"].as_array().unwrap();
+    assert_eq!(
+        names(&schema_json["directives"]),
+        vec!["deprecated", "include", "skip", "specifiedBy", "tag"],
+    );
+    assert_eq!(directives[0]["args"][0]["defaultValue"], "\"No longer supported\"");
+    assert_eq!(directives[0]["args"][0]["type"]["name"], "String");
+    assert_eq!(directives[4], json!({
+        "name": "tag",
+        "description": null,
+        "isRepeatable": true,
+        "locations": ["FIELD_DEFINITION", "OBJECT"],
+        "args": [{
+            "name": "name",
+            "description": null,
+            "type": {
+                "kind": "NON_NULL",
+                "name": null,
+                "ofType": {"kind": "SCALAR", "name": "String", "ofType": null},
+            },
+            "defaultValue": null,
+            "isDeprecated": false,
+            "deprecationReason": null,
+        }],
+    }));
+
+    Ok(())
+}
+
+#[test]
+fn omits_deprecated_elements_when_excluded() -> Result<()> {
+    let schema = SchemaBuilder::build_from_str(None, SCHEMA_SDL)?;
+    let json = schema.to_introspection_json_with_options(
+        &IntrospectionOptions { include_deprecated: false },
+    );
+
+    let query = find_type(&json, "Query");
+    assert_eq!(names(&query["fields"]), vec!["id", "node"]);
+    assert_eq!(names(&find_type(&json, "Role")["enumValues"]), vec!["ADMIN"]);
+    assert_eq!(names(&find_type(&json, "Filter")["inputFields"]), vec!["name"]);
+
+    let with_deprecated = schema.to_introspection_json();
+    let search = &find_type(&with_deprecated, "Query")["fields"][1];
+    assert_eq!(names(&search["args"]), vec!["term", "exact"]);
+
+    Ok(())
+}
@@ -1 +1,3 @@
 mod schema_builder;
+mod introspection_printer;
+mod sdl_printer;
@@ -854,3 +854,154 @@ mod object_types {
         }
     }
 }
+
+mod introspection {
+    use super::*;
+    use crate::Value;
+
+    const KITCHEN_SINK_JSON: &str =
+        include_str!("fixtures/introspection/kitchen_sink.json");
+
+    fn bare_introspection_json(types: &str) -> String {
+        format!(
+            r#"{{"__schema": {{
+                "queryType": {{"name": "Query"}},
+                "types": {types},
+                "directives": []
+            }}}}"#,
+        )
+    }
+
+    fn malformed(json_path: &str, message: &str) -> SchemaBuildError {
+        SchemaBuildError::MalformedIntrospection {
+            json_path: json_path.to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    #[test]
+    fn kitchen_sink() -> Result<()> {
+        let schema =
+            SchemaBuilder::build_from_introspection_json(KITCHEN_SINK_JSON)?;
+
+        assert_eq!(schema.query_type.name(), "Query");
+        assert_eq!(schema.mutation_type.as_ref().unwrap().name(), "Mutation");
+        assert!(schema.subscription_type.is_none());
+        assert!(!schema.types.contains_key("__Schema"));
+        assert!(schema.directive_defs.contains_key("auth"));
+
+        let query_type = schema.types.get("Query").unwrap().as_object().unwrap();
+        assert_eq!(query_type.description(), Some("The query root."));
+        let users_field = query_type.fields().get("users").unwrap();
+        // [User!]!
+        let users_annot = users_field.type_annotation();
+        assert!(!users_annot.nullable());
+        let users_inner = users_annot.as_list_annotation().unwrap().inner_type_annotation();
+        assert!(!users_inner.nullable());
+        assert_eq!(users_inner.as_named_annotation().unwrap().graphql_type_name(), "User");
+        let first_param = users_field.parameters().get("first").unwrap();
+        assert!(matches!(
+            first_param.default_value(),
+            Some(Value::Int(n)) if n.as_i64() == Some(20),
+        ));
+        let search_field = query_type.fields().get("search").unwrap();
+        assert_eq!(search_field.directives().len(), 1);
+        assert_eq!(search_field.directives()[0].directive_type_name(), "deprecated");
+        assert!(search_field.directives()[0].arguments().is_empty());
+
+        let user_type = schema.types.get("User").unwrap().as_object().unwrap();
+        assert_eq!(user_type.interface_names(), vec!["Node"]);
+
+        let role_type = schema.types.get("Role").unwrap().as_enum().unwrap();
+        let guest_directives = role_type.values().get("GUEST").unwrap().directives();
+        assert_eq!(guest_directives[0].directive_type_name(), "deprecated");
+        assert_eq!(
+            guest_directives[0].arguments().get("reason"),
+            Some(&Value::String("Guests were removed.".to_string())),
+        );
+
+        let url_type = schema.types.get("Url").unwrap().as_scalar().unwrap();
+        assert_eq!(url_type.directives()[0].directive_type_name(), "specifiedBy");
+        assert_eq!(
+            url_type.directives()[0].arguments().get("url"),
+            Some(&Value::String("https://url.spec.whatwg.org".to_string())),
+        );
+
+        let search_result = schema.types.get("SearchResult").unwrap().as_union().unwrap();
+        assert_eq!(search_result.member_type_names(), vec!["User", "Query"]);
+
+        let user_key = schema.types.get("UserKey").unwrap().as_input_object().unwrap();
+        assert_eq!(user_key.directives()[0].directive_type_name(), "oneOf");
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_json() -> Result<()> {
+        let err = SchemaBuilder::from_introspection_json("{\"data\": ").unwrap_err();
+        assert!(matches!(
+            err,
+            SchemaBuildError::MalformedIntrospection { ref json_path, .. }
+                if json_path == "$",
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn missing_schema() -> Result<()> {
+        assert_eq!(
+            SchemaBuilder::from_introspection_json(r#"{"data": {}}"#).unwrap_err(),
+            malformed("$.data.__schema", "missing required field"),
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn malformed_nested_type_ref() -> Result<()> {
+        let json = bare_introspection_json(r#"[{
+            "kind": "OBJECT",
+            "name": "Query",
+            "fields": [{
+                "name": "tags",
+                "args": [],
+                "type": {"kind": "LIST", "ofType": {"kind": "ARRAY"}}
+            }],
+            "interfaces": []
+        }]"#);
+
+        assert_eq!(
+            SchemaBuilder::from_introspection_json(&json).unwrap_err(),
+            malformed(
+                "$.__schema.types[0].fields[0].type.ofType.kind",
+                "unknown type kind `ARRAY`",
+            ),
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_default_value() -> Result<()> {
+        let json = bare_introspection_json(r#"[{
+            "kind": "INPUT_OBJECT",
+            "name": "Filter",
+            "inputFields": [{
+                "name": "limit",
+                "type": {"kind": "SCALAR", "name": "Int"},
+                "defaultValue": "$limit"
+            }]
+        }]"#);
+
+        assert_eq!(
+            SchemaBuilder::from_introspection_json(&json).unwrap_err(),
+            malformed(
+                "$.__schema.types[0].inputFields[0].defaultValue",
+                "`$limit` is not a valid GraphQL input value literal",
+            ),
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,183 @@
+use crate::schema::SchemaBuilder;
+use crate::schema::SchemaBuildError;
+
+type Result<T> = std::result::Result<T, SchemaBuildError>;
+
+const KITCHEN_SINK_SDL: &str = r#"
+"""
+Marks an element as visible only to the given roles.
+"""
+directive @auth(
+  "Roles allowed to see the element."
+  roles: [Role!] = [ADMIN]
+) repeatable on FIELD_DEFINITION | OBJECT
+
+"The query root."
+type Query implements Node {
+  id: ID!
+  users(first: Int = 20, filter: UserFilter = {name: "a\"b", tags: []}): [User!]! @auth(roles: [ADMIN, USER])
+  search(term: String!, exact: Boolean @deprecated): [SearchResult!] @deprecated
+}
+
+interface Node {
+  id: ID!
+}
+
+type User implements Node @auth {
+  id: ID!
+  role: Role
+  score(scale: Float = 1.0): Float
+}
+
+enum Role {
+  ADMIN
+  USER
+  GUEST @deprecated(reason: "Guests were removed.")
+}
+
+input UserFilter {
+  name: String = "anyone"
+  tags: [String!]
+}
+
+union SearchResult = User | Query
+
+scalar Url @specifiedBy(url: "https://url.spec.whatwg.org")
+"#;
+
+#[test]
+fn round_trips_kitchen_sink() -> Result<()> {
+    let schema = SchemaBuilder::build_from_str(None, KITCHEN_SINK_SDL)?;
+    let sdl = schema.to_sdl();
+
+    let rebuilt = SchemaBuilder::build_from_str(None, &sdl)?;
+    assert_eq!(rebuilt.to_sdl(), sdl);
+    assert_eq!(
+        rebuilt.defined_types().keys().count(),
+        schema.defined_types().keys().count(),
+    );
+
+    assert!(sdl.starts_with(concat!(
+        "\"\"\"Marks an element as visible only to the given roles.\"\"\"\n",
+        "directive @auth(\n",
+        "  \"\"\"Roles allowed to see the element.\"\"\"\n",
+        "  roles: [Role!] = [ADMIN]\n",
+        ") repeatable on FIELD_DEFINITION | OBJECT\n",
+    )), "{sdl}");
+    assert!(sdl.contains(concat!(
+        "  users(first: Int = 20, filter: UserFilter = ",
+        "{name: \"a\\\"b\", tags: []}): [User!]! @auth(roles: [ADMIN, USER])\n",
+    )), "{sdl}");
+    assert!(sdl.contains("  score(scale: Float = 1.0): Float\n"), "{sdl}");
+    assert!(sdl.contains(
+        "  search(term: String!, exact: Boolean @deprecated): [SearchResult!] @deprecated\n",
+    ), "{sdl}");
+    assert!(sdl.contains("  GUEST @deprecated(reason: \"Guests were removed.\")\n"));
+    assert!(sdl.contains("  name: String = \"anyone\"\n"), "{sdl}");
+    assert!(sdl.contains("union SearchResult = User | Query\n"), "{sdl}");
+    assert!(sdl.contains(
+        "scalar Url @specifiedBy(url: \"https://url.spec.whatwg.org\")\n",
+    ));
+    // Built-ins and the implicit `__typename` field are never printed.
+    assert!(!sdl.contains("__typename"), "{sdl}");
+    assert!(!sdl.contains("directive @deprecated"), "{sdl}");
+    assert!(!sdl.contains("scalar String"), "{sdl}");
+    // Default root type names need no schema definition.
+    assert!(!sdl.contains("schema {"), "{sdl}");
+
+    Ok(())
+}
+
+#[test]
+fn records_directive_locations_and_repeatability() -> Result<()> {
+    let schema = SchemaBuilder::build_from_str(None, KITCHEN_SINK_SDL)?;
+    let directives = schema.all_directives();
+
+    let auth = directives.get("auth").unwrap();
+    assert!(auth.is_repeatable());
+    let locations: Vec<&str> =
+        auth.locations().iter().map(|loc| loc.as_str()).collect();
+    assert_eq!(locations, vec!["FIELD_DEFINITION", "OBJECT"]);
+    assert_eq!(
+        auth.parameters()["roles"].description(),
+        Some("Roles allowed to see the element."),
+    );
+
+    let deprecated = directives.get("deprecated").unwrap();
+    assert!(!deprecated.is_repeatable());
+    assert_eq!(deprecated.locations().len(), 4);
+    assert_eq!(
+        deprecated.parameters()["reason"].type_annotation().to_string(),
+        "String",
+    );
+
+    Ok(())
+}
+
+#[test]
+fn prints_types_sorted_by_name() -> Result<()> {
+    let schema = SchemaBuilder::build_from_str(
+        None,
+        "type Query { b: Int, a: Color } enum Color { RED } scalar Aardvark",
+    )?;
+
+    assert_eq!(schema.to_sdl(), concat!(
+        "scalar Aardvark\n",
+        "\n",
+        "enum Color {\n",
+        "  RED\n",
+        "}\n",
+        "\n",
+        "type Query {\n",
+        "  b: Int\n",
+        "  a: Color\n",
+        "}\n",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn prints_schema_definition_for_custom_root_names() -> Result<()> {
+    let schema = SchemaBuilder::build_from_str(
+        None,
+        "schema { query: Root, mutation: Mutation }
+         type Root { a: Int }
+         type Mutation { b: Int }
+         type Query { c: Int }",
+    )?;
+    let sdl = schema.to_sdl();
+
+    assert!(sdl.starts_with(
+        "schema {\n  query: Root\n  mutation: Mutation\n}\n\n",
+    ), "{sdl}");
+    let rebuilt = SchemaBuilder::build_from_str(None, &sdl)?;
+    assert_eq!(rebuilt.query_type().name(), "Root");
+
+    Ok(())
+}
+
+#[test]
+fn falls_back_to_quoted_descriptions() -> Result<()> {
+    let schema = SchemaBuilder::build_from_str(
+        None,
+        "type Query {\n\
+           \"  indented\\n  both lines\" a: Int\n\
+           \"\\nleading blank line\" b: Int\n\
+           \"\"\"\n  one\n    two\n\"\"\" c: Int\n\
+         }",
+    )?;
+    let sdl = schema.to_sdl();
+
+    assert!(sdl.contains("  \"  indented\\n  both lines\"\n  a: Int\n"), "{sdl}");
+    assert!(sdl.contains("  \"\\nleading blank line\"\n  b: Int\n"), "{sdl}");
+    assert!(sdl.contains("  \"\"\"\n  one\n    two\n  \"\"\"\n  c: Int\n"), "{sdl}");
+
+    let rebuilt = SchemaBuilder::build_from_str(None, &sdl)?;
+    let fields = rebuilt.query_type().as_object().unwrap().fields();
+    assert_eq!(fields["a"].description(), Some("  indented\n  both lines"));
+    assert_eq!(fields["b"].description(), Some("\nleading blank line"));
+    assert_eq!(fields["c"].description(), Some("one\n  two"));
+
+    Ok(())
+}
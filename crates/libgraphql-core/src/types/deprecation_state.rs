@@ -1,4 +1,7 @@
 use crate::DirectiveAnnotation;
+use crate::Value;
+
+const DEFAULT_DEPRECATION_REASON: &str = "No longer supported";
 
 pub enum DeprecationState<'a> {
     Deprecated(&'a str),
@@ -16,12 +19,13 @@ impl<'a> std::convert::From<&'a Vec<DirectiveAnnotation>> for DeprecationState<'
             directive_annot.directive_type_name() == "deprecated"
         });
         if let Some(directive_annot) = directive_annot {
+            // `reason` is optional and falls back to its default value:
+            // https://spec.graphql.org/October2021/#sec--deprecated
             let reason =
                 directive_annot.arguments()
                     .get("reason")
-                    .expect("no `reason` argument found")
-                    .as_str()
-                    .expect("`reason` argument found as non-string type");
+                    .and_then(Value::as_str)
+                    .unwrap_or(DEFAULT_DEPRECATION_REASON);
             DeprecationState::Deprecated(reason)
         } else {
             DeprecationState::NotDeprecated
@@ -1,5 +1,6 @@
 use std::sync::OnceLock;
 
+use crate::ast::schema::DirectiveLocation;
 use crate::loc;
 use crate::named_ref::DerefByName;
 use crate::named_ref::DerefByNameError;
@@ -13,18 +14,37 @@ use indexmap::IndexMap;
 
 type DirectiveParamsMap = IndexMap<String, Parameter>;
 
+const DEPRECATED_DIRECTIVE_LOCATIONS: [DirectiveLocation; 4] = [
+    DirectiveLocation::FieldDefinition,
+    DirectiveLocation::ArgumentDefinition,
+    DirectiveLocation::InputFieldDefinition,
+    DirectiveLocation::EnumValue,
+];
+
+const INCLUDE_SKIP_DIRECTIVE_LOCATIONS: [DirectiveLocation; 3] = [
+    DirectiveLocation::Field,
+    DirectiveLocation::FragmentSpread,
+    DirectiveLocation::InlineFragment,
+];
+
+const SPECIFIED_BY_DIRECTIVE_LOCATIONS: [DirectiveLocation; 1] = [
+    DirectiveLocation::Scalar,
+];
+
 fn deprecated_directive_params() -> &'static DirectiveParamsMap {
     static PARAMS: OnceLock<DirectiveParamsMap> = OnceLock::new();
     PARAMS.get_or_init(|| {
         IndexMap::from([
             ("reason".to_string(), Parameter {
                 def_location: loc::SourceLocation::GraphQLBuiltIn,
+                description: None,
+                directives: vec![],
                 default_value: Some(Value::String("No longer supported".to_string())),
                 name: "reason".to_string(),
                 type_annotation: NamedTypeAnnotation {
                     nullable: true,
                     type_ref: NamedGraphQLTypeRef::new(
-                        "deprecated",
+                        "String",
                         loc::SourceLocation::GraphQLBuiltIn,
                     ),
                 }.into(),
@@ -39,6 +59,8 @@ fn include_directive_params() -> &'static DirectiveParamsMap {
         IndexMap::from([
             ("if".to_string(), Parameter {
                 def_location: loc::SourceLocation::GraphQLBuiltIn,
+                description: None,
+                directives: vec![],
                 default_value: None,
                 name: "if".to_string(),
                 type_annotation: NamedTypeAnnotation {
@@ -59,6 +81,8 @@ fn skip_directive_params() -> &'static DirectiveParamsMap {
         IndexMap::from([
             ("if".to_string(), Parameter {
                 def_location: loc::SourceLocation::GraphQLBuiltIn,
+                description: None,
+                directives: vec![],
                 default_value: None,
                 name: "if".to_string(),
                 type_annotation: NamedTypeAnnotation {
@@ -79,6 +103,8 @@ fn specified_by_directive_params() -> &'static DirectiveParamsMap {
         IndexMap::from([
             ("url".to_string(), Parameter {
                 def_location: loc::SourceLocation::GraphQLBuiltIn,
+                description: None,
+                directives: vec![],
                 default_value: None,
                 name: "url".to_string(),
                 type_annotation: NamedTypeAnnotation {
@@ -99,8 +125,11 @@ pub enum Directive {
     Custom {
         def_location: loc::SourceLocation,
         description: Option<String>,
+        #[serde(with = "crate::ast::serde_adapters::SerdeDirectiveLocations")]
+        locations: Vec<DirectiveLocation>,
         name: String,
         params: IndexMap<String, Parameter>,
+        repeatable: bool,
     },
     Deprecated,
     Include,
@@ -135,6 +164,30 @@ impl Directive {
         matches!(self.def_location(), loc::SourceLocation::GraphQLBuiltIn)
     }
 
+    /// Whether this [`Directive`] may be applied more than once at the same
+    /// location (i.e. it was defined with the `repeatable` keyword).
+    pub fn is_repeatable(&self) -> bool {
+        match self {
+            Directive::Custom { repeatable, .. } => *repeatable,
+            Directive::Deprecated
+                | Directive::Include
+                | Directive::Skip
+                | Directive::SpecifiedBy => false,
+        }
+    }
+
+    /// The locations this [`Directive`] may be applied at, in the order they
+    /// were listed in its definition.
+    pub fn locations(&self) -> &[DirectiveLocation] {
+        match self {
+            Directive::Custom { locations, .. } => locations,
+            Directive::Deprecated => &DEPRECATED_DIRECTIVE_LOCATIONS,
+            Directive::Include
+                | Directive::Skip => &INCLUDE_SKIP_DIRECTIVE_LOCATIONS,
+            Directive::SpecifiedBy => &SPECIFIED_BY_DIRECTIVE_LOCATIONS,
+        }
+    }
+
     pub fn name(&self) -> &str {
         match self {
             Directive::Custom { name, .. } => name.as_str(),
@@ -4,6 +4,7 @@ use crate::types::NamedGraphQLTypeRef;
 use crate::DirectiveAnnotation;
 use crate::loc;
 use crate::types::TypeAnnotation;
+use crate::Value;
 
 /// Represents an
 /// [input field](https://spec.graphql.org/October2021/#InputFieldsDefinition)
@@ -11,6 +12,7 @@ use crate::types::TypeAnnotation;
 #[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct InputField {
     pub(super) def_location: loc::SourceLocation,
+    pub(super) default_value: Option<Value>,
     pub(super) description: Option<String>,
     pub(super) directives: Vec<DirectiveAnnotation>,
     pub(super) name: String,
@@ -24,6 +26,12 @@ impl InputField {
         &self.def_location
     }
 
+    /// The value used for this [`InputField`] when an input object omits it,
+    /// if its definition specified one.
+    pub fn default_value(&self) -> &Option<Value> {
+        &self.default_value
+    }
+
     /// The description of this [`InputField`] as defined in the schema
     /// (e.g. in a `"""`-string immediately before the input field definition).
     pub fn description(&self) -> Option<&str> {
@@ -10,6 +10,7 @@ use crate::types::TypeAnnotation;
 use crate::types::TypeBuilder;
 use crate::types::TypeBuilderHelpers;
 use crate::types::TypesMapBuilder;
+use crate::Value;
 use inherent::inherent;
 use std::path::Path;
 use std::path::PathBuf;
@@ -57,6 +58,9 @@ impl InputObjectTypeBuilder {
                 })?;
             }
             inputobj_type.fields.insert(ext_field.name.to_string(), InputField {
+                default_value: ext_field.default_value.as_ref().map(
+                    |val| Value::from_ast(val, &fielddef_srcloc)
+                ),
                 description: ext_field.description.to_owned(),
                 directives: DirectiveAnnotationBuilder::from_ast(
                     &fielddef_srcloc,
@@ -0,0 +1,223 @@
+//! The introspection meta-types (`__Schema`, `__Type`, ...) that every schema
+//! implicitly contains.
+//!
+//! [`SchemaBuilder`](crate::schema::SchemaBuilder) never stores these (it
+//! rejects `__`-prefixed names), but introspection results list them
+//! alongside the schema's own types, so they are constructed here directly.
+//!
+//! See [Schema Introspection](https://spec.graphql.org/October2021/#sec-Schema-Introspection).
+
+use crate::loc;
+use crate::types::EnumType;
+use crate::types::EnumValue;
+use crate::types::Field;
+use crate::types::GraphQLType;
+use crate::types::ListTypeAnnotation;
+use crate::types::NamedGraphQLTypeRef;
+use crate::types::NamedTypeAnnotation;
+use crate::types::ObjectType;
+use crate::types::Parameter;
+use crate::types::TypeAnnotation;
+use crate::types::object_or_interface_type_data::ObjectOrInterfaceTypeData;
+use crate::Value;
+use indexmap::IndexMap;
+use std::sync::OnceLock;
+
+/// A meta-type field: `(name, type, takes_include_deprecated)`.
+type FieldSpec = (&'static str, &'static str, bool);
+
+const SCHEMA_FIELDS: &[FieldSpec] = &[
+    ("description", "String", false),
+    ("types", "[__Type!]!", false),
+    ("queryType", "__Type!", false),
+    ("mutationType", "__Type", false),
+    ("subscriptionType", "__Type", false),
+    ("directives", "[__Directive!]!", false),
+];
+
+const TYPE_FIELDS: &[FieldSpec] = &[
+    ("kind", "__TypeKind!", false),
+    ("name", "String", false),
+    ("description", "String", false),
+    ("fields", "[__Field!]", true),
+    ("interfaces", "[__Type!]", false),
+    ("possibleTypes", "[__Type!]", false),
+    ("enumValues", "[__EnumValue!]", true),
+    ("inputFields", "[__InputValue!]", true),
+    ("ofType", "__Type", false),
+    ("specifiedByURL", "String", false),
+    ("isOneOf", "Boolean", false),
+];
+
+const FIELD_FIELDS: &[FieldSpec] = &[
+    ("name", "String!", false),
+    ("description", "String", false),
+    ("args", "[__InputValue!]!", true),
+    ("type", "__Type!", false),
+    ("isDeprecated", "Boolean!", false),
+    ("deprecationReason", "String", false),
+];
+
+const INPUT_VALUE_FIELDS: &[FieldSpec] = &[
+    ("name", "String!", false),
+    ("description", "String", false),
+    ("type", "__Type!", false),
+    ("defaultValue", "String", false),
+    ("isDeprecated", "Boolean!", false),
+    ("deprecationReason", "String", false),
+];
+
+const ENUM_VALUE_FIELDS: &[FieldSpec] = &[
+    ("name", "String!", false),
+    ("description", "String", false),
+    ("isDeprecated", "Boolean!", false),
+    ("deprecationReason", "String", false),
+];
+
+const DIRECTIVE_FIELDS: &[FieldSpec] = &[
+    ("name", "String!", false),
+    ("description", "String", false),
+    ("isRepeatable", "Boolean!", false),
+    ("locations", "[__DirectiveLocation!]!", false),
+    ("args", "[__InputValue!]!", true),
+];
+
+const TYPE_KIND_VALUES: &[&str] = &[
+    "SCALAR",
+    "OBJECT",
+    "INTERFACE",
+    "UNION",
+    "ENUM",
+    "INPUT_OBJECT",
+    "LIST",
+    "NON_NULL",
+];
+
+const DIRECTIVE_LOCATION_VALUES: &[&str] = &[
+    "QUERY",
+    "MUTATION",
+    "SUBSCRIPTION",
+    "FIELD",
+    "FRAGMENT_DEFINITION",
+    "FRAGMENT_SPREAD",
+    "INLINE_FRAGMENT",
+    "VARIABLE_DEFINITION",
+    "SCHEMA",
+    "SCALAR",
+    "OBJECT",
+    "FIELD_DEFINITION",
+    "ARGUMENT_DEFINITION",
+    "INTERFACE",
+    "UNION",
+    "ENUM",
+    "ENUM_VALUE",
+    "INPUT_OBJECT",
+    "INPUT_FIELD_DEFINITION",
+];
+
+/// The introspection meta-types, in the order the spec defines them.
+pub(crate) fn introspection_types() -> &'static IndexMap<String, GraphQLType> {
+    static TYPES: OnceLock<IndexMap<String, GraphQLType>> = OnceLock::new();
+    TYPES.get_or_init(|| {
+        [
+            object_type("__Schema", SCHEMA_FIELDS),
+            object_type("__Type", TYPE_FIELDS),
+            enum_type("__TypeKind", TYPE_KIND_VALUES),
+            object_type("__Field", FIELD_FIELDS),
+            object_type("__InputValue", INPUT_VALUE_FIELDS),
+            object_type("__EnumValue", ENUM_VALUE_FIELDS),
+            object_type("__Directive", DIRECTIVE_FIELDS),
+            enum_type("__DirectiveLocation", DIRECTIVE_LOCATION_VALUES),
+        ]
+        .into_iter()
+        .map(|graphql_type| (graphql_type.name().to_string(), graphql_type))
+        .collect()
+    })
+}
+
+fn object_type(name: &str, fields: &[FieldSpec]) -> GraphQLType {
+    let fields = fields
+        .iter()
+        .map(|&(field_name, type_str, takes_include_deprecated)| {
+            let mut parameters = IndexMap::new();
+            if takes_include_deprecated {
+                // includeDeprecated: Boolean = false
+                parameters.insert("includeDeprecated".to_string(), Parameter {
+                    def_location: loc::SourceLocation::GraphQLBuiltIn,
+                    default_value: Some(Value::Bool(false)),
+                    description: None,
+                    directives: vec![],
+                    name: "includeDeprecated".to_string(),
+                    type_annotation: parse_type_annotation("Boolean"),
+                });
+            }
+            (field_name.to_string(), Field {
+                def_location: loc::SourceLocation::GraphQLBuiltIn,
+                description: None,
+                directives: vec![],
+                name: field_name.to_string(),
+                parameters,
+                parent_type: NamedGraphQLTypeRef::new(
+                    name,
+                    loc::SourceLocation::GraphQLBuiltIn,
+                ),
+                type_annotation: parse_type_annotation(type_str),
+            })
+        })
+        .collect();
+
+    GraphQLType::Object(Box::new(ObjectType(ObjectOrInterfaceTypeData {
+        def_location: loc::SourceLocation::GraphQLBuiltIn,
+        description: None,
+        directives: vec![],
+        fields,
+        interfaces: vec![],
+        name: name.to_string(),
+    })))
+}
+
+fn enum_type(name: &str, values: &[&str]) -> GraphQLType {
+    let values = values
+        .iter()
+        .map(|&value_name| (value_name.to_string(), EnumValue {
+            def_location: loc::SourceLocation::GraphQLBuiltIn,
+            description: None,
+            directives: vec![],
+            name: value_name.to_string(),
+            type_ref: NamedGraphQLTypeRef::new(
+                name,
+                loc::SourceLocation::GraphQLBuiltIn,
+            ),
+        }))
+        .collect();
+
+    GraphQLType::Enum(Box::new(EnumType {
+        def_location: loc::SourceLocation::GraphQLBuiltIn,
+        description: None,
+        directives: vec![],
+        name: name.to_string(),
+        values,
+    }))
+}
+
+/// Parses the type references used in the tables above (e.g. `[__Type!]!`).
+fn parse_type_annotation(s: &str) -> TypeAnnotation {
+    let (inner, nullable) = match s.strip_suffix('!') {
+        Some(inner) => (inner, false),
+        None => (s, true),
+    };
+    match inner.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+        Some(item) => TypeAnnotation::List(ListTypeAnnotation {
+            inner_type_ref: Box::new(parse_type_annotation(item)),
+            nullable,
+            ref_location: loc::SourceLocation::GraphQLBuiltIn,
+        }),
+        None => TypeAnnotation::Named(NamedTypeAnnotation {
+            nullable,
+            type_ref: NamedGraphQLTypeRef::new(
+                inner,
+                loc::SourceLocation::GraphQLBuiltIn,
+            ),
+        }),
+    }
+}
@@ -13,6 +13,7 @@ mod input_object_type_validator;
 mod object_or_interface_type_validator;
 mod interface_type;
 mod interface_type_builder;
+mod introspection_types;
 mod list_type_annotation;
 mod named_graphql_type_ref;
 mod named_type_annotation;
@@ -47,6 +48,7 @@ pub(crate) use input_object_type_builder::InputObjectTypeBuilder;
 use object_or_interface_type_validator::ObjectOrInterfaceTypeValidator;
 pub use interface_type::InterfaceType;
 pub(crate) use interface_type_builder::InterfaceTypeBuilder;
+pub(crate) use introspection_types::introspection_types;
 pub use list_type_annotation::ListTypeAnnotation;
 pub use named_type_annotation::NamedTypeAnnotation;
 pub(crate) use named_graphql_type_ref::NamedGraphQLTypeRef;
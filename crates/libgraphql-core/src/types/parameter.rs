@@ -1,4 +1,6 @@
 use crate::ast;
+use crate::DirectiveAnnotation;
+use crate::DirectiveAnnotationBuilder;
 use crate::loc;
 use crate::types::TypeAnnotation;
 use crate::Value;
@@ -8,6 +10,8 @@ use std::path::Path;
 pub struct Parameter {
     pub(super) def_location: loc::SourceLocation,
     pub(super) default_value: Option<Value>,
+    pub(super) description: Option<String>,
+    pub(super) directives: Vec<DirectiveAnnotation>,
     pub(super) name: String,
     pub(super) type_annotation: TypeAnnotation,
 }
@@ -20,6 +24,18 @@ impl Parameter {
         &self.default_value
     }
 
+    /// The description of this [`Parameter`] as defined in the schema (e.g. in
+    /// a `"""`-string immediately before the parameter definition).
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// The list of [`DirectiveAnnotation`]s applied to this [`Parameter`], in
+    /// the order they were specified on its definition.
+    pub fn directives(&self) -> &Vec<DirectiveAnnotation> {
+        &self.directives
+    }
+
     pub(crate) fn from_ast(
         file_path: Option<&Path>,
         param: &ast::schema::InputValue,
@@ -33,6 +49,11 @@ impl Parameter {
             default_value: param.default_value.as_ref().map(
                 |val| Value::from_ast(val, &paramdef_srcloc)
             ),
+            description: param.description.to_owned(),
+            directives: DirectiveAnnotationBuilder::from_ast(
+                &paramdef_srcloc,
+                &param.directives,
+            ),
             name: param.name.to_owned(),
             type_annotation: TypeAnnotation::from_ast_type(
                 &paramdef_srcloc,
@@ -48,4 +48,15 @@ impl ScalarType {
     pub fn name(&self) -> &str {
         self.name.as_str()
     }
+
+    /// The URL passed to this [`ScalarType`]'s
+    /// [`@specifiedBy`](https://spec.graphql.org/October2021/#sec--specifiedBy)
+    /// annotation, if it has one.
+    pub fn specified_by_url(&self) -> Option<&str> {
+        self.directives
+            .iter()
+            .find(|annot| annot.directive_type_name() == "specifiedBy")
+            .and_then(|annot| annot.arguments().get("url"))
+            .and_then(|url| url.as_str())
+    }
 }
@@ -61,6 +61,9 @@ impl TypeBuilderHelpers {
             }
 
             field_map.insert(field.name.to_string(), InputField {
+                default_value: field.default_value.as_ref().map(
+                    |val| Value::from_ast(val, &fielddef_srcloc)
+                ),
                 description: field.description.to_owned(),
                 directives: DirectiveAnnotationBuilder::from_ast(
                     &fielddef_srcloc,
@@ -83,33 +86,44 @@ impl TypeBuilderHelpers {
         Ok(field_map)
     }
 
+    /// Builds the implicit
+    /// [`__typename`](https://spec.graphql.org/October2021/#sec-Type-Name-Introspection)
+    /// meta-field that every object, interface, and union type exposes.
+    pub fn typename_field(
+        type_def_location: &loc::SourceLocation,
+        type_name: &str,
+    ) -> Field {
+        Field {
+            def_location: loc::SourceLocation::GraphQLBuiltIn,
+            description: None,
+            directives: vec![],
+            name: "__typename".to_string(),
+            parameters: IndexMap::new(),
+            parent_type: NamedGraphQLTypeRef::new(
+                type_name,
+                type_def_location.to_owned(),
+            ),
+            type_annotation: TypeAnnotation::Named(
+                NamedTypeAnnotation {
+                    nullable: false,
+                    type_ref: NamedGraphQLTypeRef::new(
+                        "String",
+                        type_def_location.to_owned(),
+                    ),
+                },
+            ),
+        }
+    }
+
     pub fn object_fielddefs_from_ast(
         obj_def_location: &loc::SourceLocation,
         type_name: &str,
         fields: &[ast::schema::Field],
     ) -> Result<IndexMap<String, Field>> {
-        let mut field_map = IndexMap::from([
-            ("__typename".to_string(), Field {
-                def_location: loc::SourceLocation::GraphQLBuiltIn,
-                description: None,
-                directives: vec![],
-                name: "__typename".to_string(),
-                parameters: IndexMap::new(),
-                parent_type: NamedGraphQLTypeRef::new(
-                    type_name,
-                    obj_def_location.to_owned(),
-                ),
-                type_annotation: TypeAnnotation::Named(
-                    NamedTypeAnnotation {
-                        nullable: false,
-                        type_ref: NamedGraphQLTypeRef::new(
-                            "String",
-                            obj_def_location.to_owned(),
-                        ),
-                    },
-                ),
-            }),
-        ]);
+        let mut field_map = IndexMap::from([(
+            "__typename".to_string(),
+            Self::typename_field(obj_def_location, type_name),
+        )]);
 
         for field in fields {
             let fielddef_srcloc = obj_def_location.with_ast_position(&field.position);
@@ -143,6 +157,11 @@ impl TypeBuilderHelpers {
                     default_value: param.default_value.as_ref().map(
                         |val| Value::from_ast(val, &param_srcloc)
                     ),
+                    description: param.description.to_owned(),
+                    directives: DirectiveAnnotationBuilder::from_ast(
+                        &param_srcloc,
+                        &param.directives,
+                    ),
                     name: param.name.to_owned(),
                     type_annotation: TypeAnnotation::from_ast_type(
                         &param_srcloc,
@@ -3,6 +3,7 @@ use crate::DirectiveAnnotation;
 use crate::loc;
 use crate::schema::Schema;
 use crate::types::DeprecationState;
+use crate::types::Field;
 use crate::types::NamedGraphQLTypeRef;
 use crate::types::GraphQLType;
 use indexmap::IndexMap;
@@ -17,6 +18,7 @@ pub struct UnionType {
     pub(crate) directives: Vec<DirectiveAnnotation>,
     pub(crate) name: String,
     pub(crate) members: IndexMap<String, NamedGraphQLTypeRef>,
+    pub(crate) meta_fields: IndexMap<String, Field>,
 }
 impl UnionType {
     pub fn contains_member(&self, member: &GraphQLType) -> bool {
@@ -118,6 +120,15 @@ impl UnionType {
             .collect()
     }
 
+    /// The meta-fields that can be selected directly on this [`UnionType`].
+    ///
+    /// Unions define no fields of their own, so this only contains the
+    /// implicit `__typename` field. All other fields must be selected through
+    /// fragments on the union's member types.
+    pub fn meta_fields(&self) -> &IndexMap<String, Field> {
+        &self.meta_fields
+    }
+
     /// The name of this [`UnionType`].
     pub fn name(&self) -> &str {
         self.name.as_str()
@@ -3,6 +3,7 @@ use crate::DirectiveAnnotationBuilder;
 use crate::loc;
 use crate::schema::SchemaBuildError;
 use crate::types::TypeBuilder;
+use crate::types::TypeBuilderHelpers;
 use crate::types::TypesMapBuilder;
 use crate::types::UnionType;
 use crate::types::GraphQLType;
@@ -146,6 +147,13 @@ impl TypeBuilder for UnionTypeBuilder {
             def.name.as_str(),
             &uniondef_srcloc.to_owned(),
             GraphQLType::Union(UnionType {
+                meta_fields: IndexMap::from([(
+                    "__typename".to_string(),
+                    TypeBuilderHelpers::typename_field(
+                        &uniondef_srcloc,
+                        def.name.as_str(),
+                    ),
+                )]),
                 def_location: uniondef_srcloc,
                 description: def.description.to_owned(),
                 directives,
@@ -6,6 +6,7 @@ use crate::operation::Variable;
 use crate::types::EnumValue;
 use crate::types::NamedEnumValueRef;
 use indexmap::IndexMap;
+use libgraphql_parser::ast::StringValue;
 
 #[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
 pub enum Value {
@@ -29,6 +30,45 @@ impl Value {
         }
     }
 
+    /// Prints this [`Value`] as a GraphQL value literal (e.g. `{a: [1, "b"]}`).
+    pub(crate) fn to_graphql_string(&self) -> String {
+        match self {
+            Value::VarRef(var_ref) => format!("${}", var_ref.name()),
+            Value::Int(value) => value.as_i64()
+                .expect("graphql_parser numbers are always i64s")
+                .to_string(),
+            // `{:?}` keeps a fractional part or exponent, so integral floats
+            // stay FloatValues.
+            Value::Float(value) => format!("{value:?}"),
+            Value::String(value) => {
+                let mut literal = String::new();
+                StringValue::append_quoted_string(&mut literal, value);
+                literal
+            },
+            Value::Bool(value) => value.to_string(),
+            Value::Null => "null".to_string(),
+            Value::EnumValue(enum_value_ref) => {
+                enum_value_ref.name().to_string()
+            },
+            Value::List(values) => format!(
+                "[{}]",
+                values.iter()
+                    .map(Value::to_graphql_string)
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            ),
+            Value::Object(entries) => format!(
+                "{{{}}}",
+                entries.iter()
+                    .map(|(key, value)| {
+                        format!("{key}: {}", value.to_graphql_string())
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            ),
+        }
+    }
+
     // TODO: Move this to a private function on OperationsBuilder
     pub(crate) fn from_ast(
         ast_value: &ast::Value,
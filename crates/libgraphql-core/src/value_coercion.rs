@@ -0,0 +1,251 @@
+use crate::ast;
+use crate::loc;
+use crate::schema::Schema;
+use crate::types::GraphQLType;
+use crate::types::TypeAnnotation;
+use crate::Value;
+
+/// Why a const literal failed to coerce to its expected type.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct ValueCoercionError {
+    /// The path of the offending value within the checked literal
+    /// (e.g. `filters[1].key`), or `None` if the literal itself is at
+    /// fault.
+    pub(crate) path: Option<String>,
+
+    /// A short human-readable reason describing the offending value.
+    pub(crate) reason: String,
+}
+
+/// Checks that a const literal `value` (e.g. a variable's default
+/// value) coerces to `type_annotation` under the
+/// [input coercion](https://spec.graphql.org/October2021/#sec-Input-Values)
+/// rules of each type.
+///
+/// On failure, the returned [`ValueCoercionError`] describes the
+/// innermost offending value and its path within `value`.
+///
+/// Custom scalars are validated by their implementation in the
+/// schema's [`ScalarRegistry`](crate::scalars::ScalarRegistry), and
+/// accept any value if none is registered. `location` is the location
+/// reported for values passed to those implementations.
+pub(crate) fn check_const_value_coercion(
+    value: &ast::Value,
+    type_annotation: &TypeAnnotation,
+    schema: &Schema,
+    location: &loc::SourceLocation,
+) -> Result<(), ValueCoercionError> {
+    check_value(value, type_annotation, schema, location, false)
+}
+
+/// Like [`check_const_value_coercion()`], but for a literal that may
+/// reference variables (e.g. a field argument). Variable references
+/// are accepted wherever they appear, as their values are only known
+/// at execution time.
+pub(crate) fn check_value_coercion(
+    value: &ast::Value,
+    type_annotation: &TypeAnnotation,
+    schema: &Schema,
+    location: &loc::SourceLocation,
+) -> Result<(), ValueCoercionError> {
+    check_value(value, type_annotation, schema, location, true)
+}
+
+fn check_value(
+    value: &ast::Value,
+    type_annotation: &TypeAnnotation,
+    schema: &Schema,
+    location: &loc::SourceLocation,
+    allow_variables: bool,
+) -> Result<(), ValueCoercionError> {
+    let checker = Checker { allow_variables, location, schema };
+    checker.check_at_path(value, type_annotation, "").map_err(
+        |(reason, path)| ValueCoercionError {
+            path: (!path.is_empty()).then_some(path),
+            reason,
+        },
+    )
+}
+
+struct Checker<'a> {
+    allow_variables: bool,
+    location: &'a loc::SourceLocation,
+    schema: &'a Schema,
+}
+
+impl Checker<'_> {
+    fn check_at_path(
+        &self,
+        value: &ast::Value,
+        type_annotation: &TypeAnnotation,
+        path: &str,
+    ) -> Result<(), (String, String)> {
+        let fail = |reason: String| Err((reason, path.to_string()));
+        match value {
+            ast::Value::Null if type_annotation.nullable() => return Ok(()),
+            ast::Value::Null => {
+                return fail("expected a non-null value, found `null`".to_string());
+            },
+            ast::Value::Variable(_) if self.allow_variables => return Ok(()),
+            ast::Value::Variable(_) => {
+                return fail(format!(
+                    "variables are not allowed in const values, found `{value}`",
+                ));
+            },
+            _ => (),
+        }
+
+        let named = match type_annotation {
+            // https://spec.graphql.org/October2021/#sec-List.Input-Coercion
+            TypeAnnotation::List(list) => {
+                let inner = list.inner_type_annotation();
+                return match value {
+                    ast::Value::List(items) => {
+                        items.iter().enumerate().try_for_each(|(i, item)| {
+                            self.check_at_path(
+                                item,
+                                inner,
+                                &format!("{path}[{i}]"),
+                            )
+                        })
+                    },
+                    // A non-list value coerces to a list of one item.
+                    _ => self.check_at_path(value, inner, path),
+                };
+            },
+            TypeAnnotation::Named(named) => named,
+        };
+
+        let type_name = named.graphql_type_name();
+        let mismatch = || fail(format!("expected `{type_name}`, found `{value}`"));
+        let Some(graphql_type) = self.schema.all_types().get(type_name) else {
+            // Undefined variable types are reported separately.
+            return Ok(());
+        };
+        match graphql_type {
+            GraphQLType::Bool => match value {
+                ast::Value::Boolean(_) => Ok(()),
+                _ => mismatch(),
+            },
+
+            // https://spec.graphql.org/October2021/#sec-Int.Input-Coercion
+            GraphQLType::Int => match value {
+                ast::Value::Int(num) if num.as_i64()
+                    .is_some_and(|n| i32::try_from(n).is_ok()) => Ok(()),
+                _ => mismatch(),
+            },
+
+            GraphQLType::Float => match value {
+                ast::Value::Float(_) | ast::Value::Int(_) => Ok(()),
+                _ => mismatch(),
+            },
+
+            GraphQLType::ID => match value {
+                ast::Value::Int(_) | ast::Value::String(_) => Ok(()),
+                _ => mismatch(),
+            },
+
+            GraphQLType::String => match value {
+                ast::Value::String(_) => Ok(()),
+                _ => mismatch(),
+            },
+
+            GraphQLType::Scalar(scalar) => {
+                let Some(custom) = self.schema.scalar_registry().get(scalar) else {
+                    return Ok(());
+                };
+                // A literal that references variables can only be checked
+                // once their values are known.
+                if contains_variable(value) {
+                    return Ok(());
+                }
+                custom.validate_literal(&Value::from_ast(value, self.location))
+                    .or_else(|reason| fail(format!(
+                        "invalid `{type_name}` value `{value}`: {reason}",
+                    )))
+            },
+
+            GraphQLType::Enum(enum_type) => {
+                let ast::Value::Enum(value_name) = value else {
+                    return mismatch();
+                };
+                if enum_type.values().contains_key(value_name) {
+                    Ok(())
+                } else {
+                    fail(format!(
+                        "enum `{type_name}` has no value `{value_name}`",
+                    ))
+                }
+            },
+
+            // https://spec.graphql.org/October2021/#sec-Input-Objects.Input-Coercion
+            GraphQLType::InputObject(input_obj) => {
+                let ast::Value::Object(fields) = value else {
+                    return mismatch();
+                };
+                for (field_name, field_value) in fields {
+                    let Some(field) = input_obj.fields().get(field_name) else {
+                        return fail(format!(
+                            "input object `{type_name}` has no field \
+                            `{field_name}`",
+                        ));
+                    };
+                    let field_path = if path.is_empty() {
+                        field_name.to_string()
+                    } else {
+                        format!("{path}.{field_name}")
+                    };
+                    self.check_at_path(
+                        field_value,
+                        field.type_annotation(),
+                        &field_path,
+                    )?;
+                }
+
+                let is_one_of = input_obj.directives()
+                    .iter()
+                    .any(|directive| directive.directive_type_name() == "oneOf");
+                if is_one_of {
+                    let mut fields_iter = fields.iter();
+                    return match (fields_iter.next(), fields_iter.next()) {
+                        (Some((field_name, ast::Value::Null)), None) => fail(format!(
+                            "OneOf input object `{type_name}` field \
+                            `{field_name}` must not be `null`",
+                        )),
+                        (Some(_), None) => Ok(()),
+                        _ => fail(format!(
+                            "OneOf input object `{type_name}` requires exactly \
+                            one field, found {}",
+                            fields.len(),
+                        )),
+                    };
+                }
+
+                for (field_name, field) in input_obj.fields() {
+                    if !field.type_annotation().nullable()
+                        && !fields.contains_key(field_name) {
+                        return fail(format!(
+                            "missing required field `{type_name}.{field_name}`",
+                        ));
+                    }
+                }
+                Ok(())
+            },
+
+            // Output types are not input types, so there is nothing to
+            // coerce to.
+            GraphQLType::Interface(_)
+                | GraphQLType::Object(_)
+                | GraphQLType::Union(_) => Ok(()),
+        }
+    }
+}
+
+fn contains_variable(value: &ast::Value) -> bool {
+    match value {
+        ast::Value::Variable(_) => true,
+        ast::Value::List(items) => items.iter().any(contains_variable),
+        ast::Value::Object(fields) => fields.values().any(contains_variable),
+        _ => false,
+    }
+}
@@ -0,0 +1,384 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Attribute;
+use syn::Data;
+use syn::DeriveInput;
+use syn::Expr;
+use syn::ExprLit;
+use syn::Fields;
+use syn::Lit;
+use syn::LitStr;
+use syn::Meta;
+use syn::spanned::Spanned;
+
+/// The GraphQL type kind a `#[derive(GraphQL*)]` macro defines.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum DeriveKind {
+    Enum,
+    InputObject,
+    Object,
+}
+
+impl DeriveKind {
+    fn derive_name(self) -> &'static str {
+        match self {
+            Self::Enum => "GraphQLEnum",
+            Self::InputObject => "GraphQLInputObject",
+            Self::Object => "GraphQLObject",
+        }
+    }
+}
+
+/// Options given through `#[graphql(...)]` attributes.
+#[derive(Debug, Default, PartialEq)]
+pub(crate) struct GraphQLAttrs {
+    /// The path to `libgraphql_core_v1` the generated code uses, for
+    /// crates that depend on it under another name or re-export it.
+    pub crate_path: Option<String>,
+    pub name: Option<String>,
+    pub skip: bool,
+}
+
+/// Expands a `#[derive(GraphQLObject)]`, `#[derive(GraphQLEnum)]`,
+/// or `#[derive(GraphQLInputObject)]` on `input` into
+/// `GraphQLTypeRef` and `DefinesGraphQLType` impls whose builder
+/// defines the corresponding GraphQL type.
+pub(crate) fn expand_derive(
+    kind: DeriveKind,
+    input: &DeriveInput,
+) -> syn::Result<TokenStream> {
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new(
+            input.generics.span(),
+            format!("`#[derive({})]` does not support generic types", kind.derive_name()),
+        ));
+    }
+    if let Some(attr) = input.attrs.iter().find(|attr| attr.path().is_ident("deprecated")) {
+        return Err(syn::Error::new(
+            attr.span(),
+            "GraphQL types cannot be deprecated; deprecate their fields or \
+            values instead",
+        ));
+    }
+    let attrs = graphql_attrs(&input.attrs)?;
+    if attrs.skip {
+        return Err(syn::Error::new(
+            input.ident.span(),
+            "`#[graphql(skip)]` is only supported on fields",
+        ));
+    }
+
+    let core = match &attrs.crate_path {
+        Some(path) => {
+            let path = syn::parse_str::<syn::Path>(path)?;
+            quote!(#path)
+        },
+        None => quote!(::libgraphql_core_v1),
+    };
+    let ident = &input.ident;
+    let type_name = attrs.name.unwrap_or_else(|| ident.to_string());
+    let set_description = doc_description(&input.attrs).map(|description| {
+        quote!(builder.set_description(#description);)
+    });
+    let (builder_type, members) = match (kind, &input.data) {
+        (DeriveKind::Enum, Data::Enum(data)) => {
+            let values = data.variants.iter()
+                .map(|variant| expand_enum_value(variant, &core))
+                .collect::<syn::Result<Vec<_>>>()?;
+            (quote!(EnumTypeBuilder), values)
+        },
+        (DeriveKind::InputObject | DeriveKind::Object, Data::Struct(data)) => {
+            let Fields::Named(fields) = &data.fields else {
+                return Err(syn::Error::new(
+                    data.fields.span(),
+                    format!(
+                        "`#[derive({})]` requires a struct with named fields",
+                        kind.derive_name(),
+                    ),
+                ));
+            };
+            let fields = fields.named.iter()
+                .map(|field| expand_field(kind, field, &core))
+                .collect::<syn::Result<Vec<_>>>()?;
+            let builder_type = if kind == DeriveKind::Object {
+                quote!(ObjectTypeBuilder)
+            } else {
+                quote!(InputObjectTypeBuilder)
+            };
+            (builder_type, fields)
+        },
+        (DeriveKind::Enum, _) => {
+            return Err(syn::Error::new(
+                ident.span(),
+                "`#[derive(GraphQLEnum)]` requires an enum",
+            ));
+        },
+        (DeriveKind::InputObject | DeriveKind::Object, _) => {
+            return Err(syn::Error::new(
+                ident.span(),
+                format!("`#[derive({})]` requires a struct", kind.derive_name()),
+            ));
+        },
+    };
+
+    Ok(quote! {
+        impl #core::type_builders::GraphQLTypeRef for #ident {
+            fn type_annotation() -> #core::types::TypeAnnotation {
+                #core::types::TypeAnnotation::named(#type_name, false)
+            }
+        }
+
+        impl #core::type_builders::DefinesGraphQLType for #ident {
+            type Builder = #core::type_builders::#builder_type;
+
+            fn graphql_type_builder() -> ::std::result::Result<
+                Self::Builder,
+                #core::schema::SchemaBuildError,
+            > {
+                #[allow(unused_mut)]
+                let mut builder = #core::type_builders::#builder_type::new(
+                    #type_name,
+                    #core::Span::dummy(),
+                )?;
+                #set_description
+                #(#members)*
+                ::std::result::Result::Ok(builder)
+            }
+        }
+    })
+}
+
+fn expand_enum_value(
+    variant: &syn::Variant,
+    core: &TokenStream,
+) -> syn::Result<TokenStream> {
+    if !matches!(variant.fields, Fields::Unit) {
+        return Err(syn::Error::new(
+            variant.fields.span(),
+            "`#[derive(GraphQLEnum)]` requires unit variants",
+        ));
+    }
+    let attrs = graphql_attrs(&variant.attrs)?;
+    if attrs.skip {
+        return Err(syn::Error::new(
+            variant.ident.span(),
+            "`#[graphql(skip)]` is only supported on fields",
+        ));
+    }
+    if attrs.crate_path.is_some() {
+        return Err(syn::Error::new(
+            variant.ident.span(),
+            "`#[graphql(crate = \"...\")]` is only supported on types",
+        ));
+    }
+    let name = attrs.name.unwrap_or_else(|| {
+        to_screaming_snake_case(&variant.ident.to_string())
+    });
+    let annotate = expand_description_and_deprecation(&variant.attrs, core)?;
+    Ok(quote! {
+        {
+            #[allow(unused_mut)]
+            let mut member = #core::type_builders::EnumValueDefBuilder::new(
+                #name,
+                #core::Span::dummy(),
+            );
+            #annotate
+            builder.add_value(member)?;
+        }
+    })
+}
+
+fn expand_field(
+    kind: DeriveKind,
+    field: &syn::Field,
+    core: &TokenStream,
+) -> syn::Result<TokenStream> {
+    let attrs = graphql_attrs(&field.attrs)?;
+    if attrs.crate_path.is_some() {
+        return Err(syn::Error::new(
+            field.span(),
+            "`#[graphql(crate = \"...\")]` is only supported on types",
+        ));
+    }
+    if attrs.skip {
+        if kind == DeriveKind::InputObject {
+            return Err(syn::Error::new(
+                field.span(),
+                "`#[graphql(skip)]` is not supported on input object fields",
+            ));
+        }
+        return Ok(TokenStream::new());
+    }
+    let ident = field.ident.as_ref().expect("named fields have identifiers");
+    let name = attrs.name.unwrap_or_else(|| {
+        to_camel_case(ident.to_string().trim_start_matches("r#"))
+    });
+    let ty = &field.ty;
+    let builder_type = if kind == DeriveKind::Object {
+        quote!(FieldDefBuilder)
+    } else {
+        quote!(InputFieldDefBuilder)
+    };
+    let annotate = expand_description_and_deprecation(&field.attrs, core)?;
+    Ok(quote! {
+        {
+            #[allow(unused_mut)]
+            let mut member = #core::type_builders::#builder_type::new(
+                #name,
+                <#ty as #core::type_builders::GraphQLTypeRef>::type_annotation(),
+                #core::Span::dummy(),
+            );
+            #annotate
+            builder.add_field(member)?;
+        }
+    })
+}
+
+/// Expands the `set_description()` and `@deprecated`
+/// `add_directive()` calls on the member builder `member` for a
+/// field's or variant's doc comment and `#[deprecated]` attribute.
+fn expand_description_and_deprecation(
+    attrs: &[Attribute],
+    core: &TokenStream,
+) -> syn::Result<TokenStream> {
+    let set_description = doc_description(attrs).map(|description| {
+        quote!(member.set_description(#description);)
+    });
+    let add_deprecated = deprecation(attrs)?.map(|reason| {
+        let arguments = match reason {
+            Some(reason) => quote! {
+                [(
+                    #core::names::FieldName::new("reason"),
+                    #core::value::Value::String(#reason.to_string()),
+                )]
+            },
+            None => quote!(::std::iter::empty()),
+        };
+        quote! {
+            member.add_directive(#core::directive_annotation::DirectiveAnnotation::new(
+                "deprecated",
+                #arguments,
+                #core::Span::dummy(),
+            ));
+        }
+    });
+    Ok(quote!(#set_description #add_deprecated))
+}
+
+/// Parses the `#[graphql(name = "...", skip, crate = "...")]`
+/// attributes in `attrs`.
+pub(crate) fn graphql_attrs(attrs: &[Attribute]) -> syn::Result<GraphQLAttrs> {
+    let mut graphql_attrs = GraphQLAttrs::default();
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("graphql")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("name") {
+                graphql_attrs.name = Some(meta.value()?.parse::<LitStr>()?.value());
+                Ok(())
+            } else if meta.path.is_ident("skip") {
+                graphql_attrs.skip = true;
+                Ok(())
+            } else if meta.path.is_ident("crate") {
+                let path = meta.value()?.parse::<LitStr>()?;
+                path.parse::<syn::Path>()?;
+                graphql_attrs.crate_path = Some(path.value());
+                Ok(())
+            } else {
+                Err(meta.error(
+                    "expected `name = \"...\"`, `skip`, or `crate = \"...\"`",
+                ))
+            }
+        })?;
+    }
+    Ok(graphql_attrs)
+}
+
+/// Joins the `///` doc comments in `attrs` into a GraphQL
+/// description, stripping the single leading space rustdoc
+/// conventionally follows `///` with.
+pub(crate) fn doc_description(attrs: &[Attribute]) -> Option<String> {
+    let lines: Vec<String> = attrs.iter()
+        .filter_map(|attr| match &attr.meta {
+            Meta::NameValue(meta) if meta.path.is_ident("doc") => {
+                match &meta.value {
+                    Expr::Lit(ExprLit { lit: Lit::Str(doc), .. }) => Some(doc.value()),
+                    _ => None,
+                }
+            },
+            _ => None,
+        })
+        .flat_map(|doc| {
+            // Not `lines()`, which yields nothing for an empty `///`
+            doc.split('\n')
+                .map(|line| line.strip_prefix(' ').unwrap_or(line).trim_end().to_string())
+                .collect::<Vec<_>>()
+        })
+        .collect();
+    let description = lines.join("\n").trim().to_string();
+    (!description.is_empty()).then_some(description)
+}
+
+/// Returns `Some(reason)` if `attrs` includes `#[deprecated]`,
+/// `#[deprecated = "reason"]`, or
+/// `#[deprecated(note = "reason")]`.
+pub(crate) fn deprecation(attrs: &[Attribute]) -> syn::Result<Option<Option<String>>> {
+    let Some(attr) = attrs.iter().find(|attr| attr.path().is_ident("deprecated")) else {
+        return Ok(None);
+    };
+    match &attr.meta {
+        Meta::Path(_) => Ok(Some(None)),
+        Meta::NameValue(meta) => match &meta.value {
+            Expr::Lit(ExprLit { lit: Lit::Str(reason), .. }) => {
+                Ok(Some(Some(reason.value())))
+            },
+            other => Err(syn::Error::new(other.span(), "expected a string literal")),
+        },
+        Meta::List(_) => {
+            let mut reason = None;
+            attr.parse_nested_meta(|meta| {
+                let value = meta.value()?.parse::<LitStr>()?;
+                if meta.path.is_ident("note") {
+                    reason = Some(value.value());
+                }
+                Ok(())
+            })?;
+            Ok(Some(reason))
+        },
+    }
+}
+
+/// Converts a Rust `snake_case` field name to a GraphQL
+/// `camelCase` field name.
+pub(crate) fn to_camel_case(snake_case: &str) -> String {
+    let mut camel_case = String::with_capacity(snake_case.len());
+    let mut capitalize_next = false;
+    for c in snake_case.chars() {
+        if c == '_' && !camel_case.is_empty() {
+            capitalize_next = true;
+        } else if capitalize_next {
+            camel_case.extend(c.to_uppercase());
+            capitalize_next = false;
+        } else {
+            camel_case.push(c);
+        }
+    }
+    camel_case
+}
+
+/// Converts a Rust `PascalCase` variant name to a GraphQL
+/// `SCREAMING_SNAKE_CASE` enum value name, keeping acronyms
+/// together (`HTTPError` becomes `HTTP_ERROR`).
+pub(crate) fn to_screaming_snake_case(pascal_case: &str) -> String {
+    let chars: Vec<char> = pascal_case.chars().collect();
+    let mut screaming = String::with_capacity(pascal_case.len() + 4);
+    for (i, &c) in chars.iter().enumerate() {
+        if i > 0 && c.is_uppercase() {
+            let prev = chars[i - 1];
+            let next_is_lower = chars.get(i + 1).is_some_and(|next| next.is_lowercase());
+            if prev.is_lowercase() || prev.is_ascii_digit() || (prev.is_uppercase() && next_is_lower) {
+                screaming.push('_');
+            }
+        }
+        screaming.extend(c.to_uppercase());
+    }
+    screaming
+}
@@ -1,13 +1,13 @@
+mod derive_graphql_type;
 mod emittable_schema;
 mod graphql_schema_from_str_token_consumer;
 mod graphql_schema_token_consumer;
 mod parse_error_converter;
-mod rust_macro_graphql_token_source;
-mod span_map;
 
 #[cfg(test)]
 mod tests;
 
+use crate::derive_graphql_type::DeriveKind;
 use crate::graphql_schema_token_consumer::GraphQLSchemaTokenConsumer;
 use crate::graphql_schema_from_str_token_consumer::GraphQLSchemaFromStrTokenConsumer;
 
@@ -180,3 +180,152 @@ pub fn graphql_schema_from_str(
 ) -> proc_macro::TokenStream {
     GraphQLSchemaFromStrTokenConsumer::new(input).into()
 }
+
+fn expand_derive(
+    kind: DeriveKind,
+    input: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+    derive_graphql_type::expand_derive(kind, &input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives a GraphQL object type from a struct with named fields,
+/// for code-first schema construction with `libgraphql-core-v1`.
+///
+/// Implements `GraphQLTypeRef` (so the struct can be used as a field
+/// type) and `DefinesGraphQLType`, whose `ObjectTypeBuilder` is
+/// registered with `SchemaBuilder::absorb_rust_type()`:
+///
+/// - The type is named after the struct and each field is named
+///   after its Rust field in `camelCase`. Either can be renamed with
+///   `#[graphql(name = "...")]`, and fields can be omitted with
+///   `#[graphql(skip)]`.
+/// - Field types are mapped through `GraphQLTypeRef`: `Option<T>`
+///   is nullable, `Vec<T>` is a list, and everything else is
+///   non-null.
+/// - `///` doc comments become descriptions, and `#[deprecated]`
+///   fields become `@deprecated` (with the `note`, if any, as the
+///   `reason`).
+/// - The generated code refers to `::libgraphql_core_v1`. Crates
+///   that depend on it under another name, or through a re-export,
+///   can point it elsewhere with `#[graphql(crate = "...")]` on the
+///   type (e.g. `#[graphql(crate = "my_crate::graphql")]`).
+///
+/// ```rust
+/// use libgraphql_core_v1::schema::SchemaBuilder;
+/// use libgraphql_macros::GraphQLEnum;
+/// use libgraphql_macros::GraphQLObject;
+///
+/// #[derive(GraphQLObject)]
+/// #[graphql(name = "Query")]
+/// struct QueryRoot {
+///     /// The currently-authenticated user.
+///     me: Option<User>,
+/// }
+///
+/// /// A registered user.
+/// #[derive(GraphQLObject)]
+/// struct User {
+///     display_name: String,
+///     #[deprecated(note = "Use `displayName`.")]
+///     name: Option<String>,
+///     roles: Vec<Role>,
+///     #[graphql(skip)]
+///     password_hash: String,
+/// }
+///
+/// #[derive(GraphQLEnum)]
+/// enum Role {
+///     Admin,
+///     /// May read, but not write.
+///     ReadOnly,
+/// }
+///
+/// let mut builder = SchemaBuilder::new();
+/// builder.absorb_rust_type::<QueryRoot>().unwrap();
+/// builder.absorb_rust_type::<User>().unwrap();
+/// builder.absorb_rust_type::<Role>().unwrap();
+/// let schema = builder.build().unwrap();
+///
+/// assert_eq!(schema.to_sdl(), r#"type Query {
+///   """The currently-authenticated user."""
+///   me: User
+/// }
+///
+/// """A registered user."""
+/// type User {
+///   displayName: String!
+///   name: String @deprecated(reason: "Use `displayName`.")
+///   roles: [Role!]!
+/// }
+///
+/// enum Role {
+///   ADMIN
+///   """May read, but not write."""
+///   READ_ONLY
+/// }
+/// "#);
+/// ```
+#[proc_macro_derive(GraphQLObject, attributes(graphql))]
+pub fn derive_graphql_object(
+    input: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    expand_derive(DeriveKind::Object, input)
+}
+
+/// Derives a GraphQL enum type from a Rust enum of unit variants,
+/// for code-first schema construction with `libgraphql-core-v1`.
+///
+/// Each variant is named after its Rust name in
+/// `SCREAMING_SNAKE_CASE` (`ReadOnly` becomes `READ_ONLY`) unless
+/// renamed with `#[graphql(name = "...")]`. Doc comments,
+/// `#[deprecated]`, and `#[graphql(crate = "...")]` are handled as
+/// for [`GraphQLObject`](derive@GraphQLObject).
+#[proc_macro_derive(GraphQLEnum, attributes(graphql))]
+pub fn derive_graphql_enum(
+    input: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    expand_derive(DeriveKind::Enum, input)
+}
+
+/// Derives a GraphQL input object type from a struct with named
+/// fields, for code-first schema construction with
+/// `libgraphql-core-v1`.
+///
+/// Fields, names, doc comments, `#[deprecated]`, and
+/// `#[graphql(crate = "...")]` are handled as for
+/// [`GraphQLObject`](derive@GraphQLObject), except that
+/// `#[graphql(skip)]` is not supported (an input object's fields are
+/// all provided by the client).
+///
+/// ```rust
+/// use libgraphql_core_v1::type_builders::DefinesGraphQLType;
+/// use libgraphql_core_v1::type_builders::IntoGraphQLType;
+/// use libgraphql_macros::GraphQLInputObject;
+///
+/// /// Filters a user search.
+/// #[derive(GraphQLInputObject)]
+/// struct UserFilter {
+///     name_prefix: Option<String>,
+///     #[graphql(name = "limit")]
+///     max_results: i32,
+/// }
+///
+/// let input_type = UserFilter::graphql_type_builder()
+///     .unwrap()
+///     .into_graphql_type();
+/// let input_type = input_type.as_input_object().unwrap();
+/// assert_eq!(input_type.description(), Some("Filters a user search."));
+/// assert_eq!(
+///     input_type.fields().keys().map(|name| name.as_str()).collect::<Vec<_>>(),
+///     ["namePrefix", "limit"],
+/// );
+/// ```
+#[proc_macro_derive(GraphQLInputObject, attributes(graphql))]
+pub fn derive_graphql_input_object(
+    input: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    expand_derive(DeriveKind::InputObject, input)
+}
@@ -2,7 +2,7 @@
 //! `compile_error!` token streams using a [`SpanMap`] to recover
 //! `proc_macro2::Span` locations.
 
-use crate::span_map::SpanMap;
+use libgraphql_parser::token::SpanMap;
 use libgraphql_parser::GraphQLErrorNoteKind;
 use libgraphql_parser::GraphQLParseError;
 use proc_macro2::Span;
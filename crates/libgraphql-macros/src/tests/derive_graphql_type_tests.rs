@@ -0,0 +1,233 @@
+//! Unit tests for `crate::derive_graphql_type`.
+//!
+//! The derives' generated code is exercised end-to-end by the
+//! doc-tests on `GraphQLObject` and `GraphQLInputObject`; these
+//! tests cover the attribute parsing and naming helpers, and the
+//! inputs each derive rejects with a compile error.
+
+use crate::derive_graphql_type::DeriveKind;
+use crate::derive_graphql_type::GraphQLAttrs;
+use crate::derive_graphql_type::deprecation;
+use crate::derive_graphql_type::doc_description;
+use crate::derive_graphql_type::expand_derive;
+use crate::derive_graphql_type::graphql_attrs;
+use crate::derive_graphql_type::to_camel_case;
+use crate::derive_graphql_type::to_screaming_snake_case;
+use syn::DeriveInput;
+use syn::parse_quote;
+
+// ── Helpers ──────────────────────────────────────────────────────
+
+/// Returns the compile error message `expand_derive()` produces for
+/// `input`, panicking if it expands successfully.
+fn expansion_error(kind: DeriveKind, input: DeriveInput) -> String {
+    match expand_derive(kind, &input) {
+        Ok(tokens) => panic!("expected an error, got: {tokens}"),
+        Err(err) => err.to_string(),
+    }
+}
+
+// ── Naming ───────────────────────────────────────────────────────
+
+/// Verifies that Rust field names become `camelCase` GraphQL field
+/// names, leaving a leading underscore and already-camel names
+/// alone.
+///
+/// Written by Claude Code, reviewed by a human.
+#[test]
+fn camel_cases_field_names() {
+    assert_eq!(to_camel_case("name"), "name");
+    assert_eq!(to_camel_case("display_name"), "displayName");
+    assert_eq!(to_camel_case("created_at_2"), "createdAt2");
+    assert_eq!(to_camel_case("_internal_id"), "_internalId");
+    assert_eq!(to_camel_case("alreadyCamel"), "alreadyCamel");
+}
+
+/// Verifies that Rust variant names become `SCREAMING_SNAKE_CASE`
+/// GraphQL enum value names, keeping acronyms together.
+///
+/// Written by Claude Code, reviewed by a human.
+#[test]
+fn screaming_snake_cases_variant_names() {
+    assert_eq!(to_screaming_snake_case("Admin"), "ADMIN");
+    assert_eq!(to_screaming_snake_case("ReadOnly"), "READ_ONLY");
+    assert_eq!(to_screaming_snake_case("HTTPError"), "HTTP_ERROR");
+    assert_eq!(to_screaming_snake_case("Utf8Text"), "UTF8_TEXT");
+    assert_eq!(to_screaming_snake_case("V2"), "V2");
+}
+
+// ── Attributes ───────────────────────────────────────────────────
+
+/// Verifies that `///` doc comments are joined line by line, with
+/// rustdoc's single leading space stripped (preserving any further
+/// indentation) and surrounding blank lines trimmed.
+///
+/// Written by Claude Code, reviewed by a human.
+#[test]
+fn joins_doc_comments_into_descriptions() {
+    let input: DeriveInput = parse_quote! {
+        ///
+        /// A registered user.
+        ///
+        ///     indented
+        #[derive(Debug)]
+        struct User;
+    };
+    assert_eq!(
+        doc_description(&input.attrs).as_deref(),
+        Some("A registered user.\n\n    indented"),
+    );
+
+    let input: DeriveInput = parse_quote! { struct User; };
+    assert_eq!(doc_description(&input.attrs), None);
+}
+
+/// Verifies each form of `#[deprecated]`, and that only its `note`
+/// becomes the `@deprecated` reason.
+///
+/// Written by Claude Code, reviewed by a human.
+#[test]
+fn parses_deprecation_reasons() {
+    let field: syn::Field = parse_quote! { a: i32 };
+    assert_eq!(deprecation(&field.attrs).unwrap(), None);
+
+    let field: syn::Field = parse_quote! { #[deprecated] a: i32 };
+    assert_eq!(deprecation(&field.attrs).unwrap(), Some(None));
+
+    let field: syn::Field = parse_quote! { #[deprecated = "Use b."] a: i32 };
+    assert_eq!(deprecation(&field.attrs).unwrap(), Some(Some("Use b.".to_string())));
+
+    let field: syn::Field = parse_quote! {
+        #[deprecated(since = "1.2.0", note = "Use b.")]
+        a: i32
+    };
+    assert_eq!(deprecation(&field.attrs).unwrap(), Some(Some("Use b.".to_string())));
+
+    let field: syn::Field = parse_quote! { #[deprecated(since = "1.2.0")] a: i32 };
+    assert_eq!(deprecation(&field.attrs).unwrap(), Some(None));
+}
+
+/// Verifies that `#[graphql(...)]` options are collected across
+/// attributes, and that unknown options are rejected.
+///
+/// Written by Claude Code, reviewed by a human.
+#[test]
+fn parses_graphql_attrs() {
+    let field: syn::Field = parse_quote! {
+        #[graphql(name = "id")]
+        #[graphql(skip, crate = "::my_crate::core")]
+        a: i32
+    };
+    assert_eq!(graphql_attrs(&field.attrs).unwrap(), GraphQLAttrs {
+        crate_path: Some("::my_crate::core".to_string()),
+        name: Some("id".to_string()),
+        skip: true,
+    });
+
+    let field: syn::Field = parse_quote! { #[graphql(rename = "id")] a: i32 };
+    assert_eq!(
+        graphql_attrs(&field.attrs).unwrap_err().to_string(),
+        "expected `name = \"...\"`, `skip`, or `crate = \"...\"`",
+    );
+
+    let field: syn::Field = parse_quote! { #[graphql(crate = "not a path")] a: i32 };
+    assert!(graphql_attrs(&field.attrs).is_err());
+}
+
+/// Verifies that `#[graphql(crate = "...")]` replaces the
+/// `::libgraphql_core_v1` path in the generated code, as serde's
+/// `#[serde(crate = "...")]` does.
+///
+/// Written by Claude Code, reviewed by a human.
+#[test]
+fn overrides_core_crate_path() {
+    let expand = |input: DeriveInput| {
+        expand_derive(DeriveKind::Object, &input).unwrap().to_string()
+    };
+
+    let default = expand(parse_quote! { struct User { name: String } });
+    assert!(default.contains(":: libgraphql_core_v1 :: type_builders"), "{default}");
+
+    let overridden = expand(parse_quote! {
+        #[graphql(crate = "my_crate::graphql")]
+        struct User { name: String }
+    });
+    assert!(!overridden.contains("libgraphql_core_v1"), "{overridden}");
+    assert!(
+        overridden.contains(
+            "impl my_crate :: graphql :: type_builders :: GraphQLTypeRef for User",
+        ),
+        "{overridden}",
+    );
+}
+
+// ── Rejected inputs ──────────────────────────────────────────────
+
+/// Verifies that each derive rejects Rust items it cannot map to its
+/// GraphQL type kind.
+///
+/// Written by Claude Code, reviewed by a human.
+#[test]
+fn rejects_unsupported_items() {
+    assert_eq!(
+        expansion_error(DeriveKind::Object, parse_quote! { enum Role { Admin } }),
+        "`#[derive(GraphQLObject)]` requires a struct",
+    );
+    assert_eq!(
+        expansion_error(DeriveKind::Object, parse_quote! { struct Id(String); }),
+        "`#[derive(GraphQLObject)]` requires a struct with named fields",
+    );
+    assert_eq!(
+        expansion_error(DeriveKind::Object, parse_quote! { struct Page<T> { items: Vec<T> } }),
+        "`#[derive(GraphQLObject)]` does not support generic types",
+    );
+    assert_eq!(
+        expansion_error(DeriveKind::Enum, parse_quote! { struct Role { a: i32 } }),
+        "`#[derive(GraphQLEnum)]` requires an enum",
+    );
+    assert_eq!(
+        expansion_error(DeriveKind::Enum, parse_quote! { enum Role { Admin(u8) } }),
+        "`#[derive(GraphQLEnum)]` requires unit variants",
+    );
+}
+
+/// Verifies that `#[deprecated]` types and `#[graphql(skip)]` outside
+/// of object fields are rejected, since neither has a GraphQL
+/// equivalent.
+///
+/// Written by Claude Code, reviewed by a human.
+#[test]
+fn rejects_unsupported_attributes() {
+    assert_eq!(
+        expansion_error(DeriveKind::Object, parse_quote! {
+            #[deprecated]
+            struct User { name: String }
+        }),
+        "GraphQL types cannot be deprecated; deprecate their fields or \
+        values instead",
+    );
+    assert_eq!(
+        expansion_error(DeriveKind::InputObject, parse_quote! {
+            struct UserFilter { #[graphql(skip)] name: String }
+        }),
+        "`#[graphql(skip)]` is not supported on input object fields",
+    );
+    assert_eq!(
+        expansion_error(DeriveKind::Enum, parse_quote! {
+            enum Role { #[graphql(skip)] Admin }
+        }),
+        "`#[graphql(skip)]` is only supported on fields",
+    );
+    assert_eq!(
+        expansion_error(DeriveKind::Object, parse_quote! {
+            struct User { #[graphql(crate = "core")] name: String }
+        }),
+        "`#[graphql(crate = \"...\")]` is only supported on types",
+    );
+    assert_eq!(
+        expansion_error(DeriveKind::Enum, parse_quote! {
+            enum Role { #[graphql(crate = "core")] Admin }
+        }),
+        "`#[graphql(crate = \"...\")]` is only supported on types",
+    );
+}
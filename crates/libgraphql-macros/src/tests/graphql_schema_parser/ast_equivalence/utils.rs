@@ -1,9 +1,6 @@
-use crate::rust_macro_graphql_token_source::RustMacroGraphQLTokenSource;
+use libgraphql_parser::token::RustMacroGraphQLTokenSource;
 use libgraphql_core::ast;
 use libgraphql_parser::GraphQLParser;
-use std::cell::RefCell;
-use std::collections::HashMap;
-use std::rc::Rc;
 use std::str::FromStr;
 
 /// Helper to parse schema using the new libgraphql_parser pipeline
@@ -15,9 +12,7 @@ pub fn parse_with_graphqlschemaparser(
     let token_stream =
         proc_macro2::TokenStream::from_str(schema)
             .expect("Should parse as valid TokenStream");
-    let span_map = Rc::new(RefCell::new(HashMap::new()));
-    let token_source =
-        RustMacroGraphQLTokenSource::new(token_stream, span_map);
+    let token_source = RustMacroGraphQLTokenSource::new(token_stream);
     let parser =
         GraphQLParser::from_token_source(token_source);
     let result = parser.parse_schema_document();
@@ -12,14 +12,11 @@
 //! string adjacency detection in
 //! `RustMacroGraphQLTokenSource::try_combine_block_string`.
 
-use crate::rust_macro_graphql_token_source::RustMacroGraphQLTokenSource;
+use libgraphql_parser::token::RustMacroGraphQLTokenSource;
 use libgraphql_core::ast;
 use libgraphql_parser::GraphQLParser;
 use libgraphql_parser::ParseResult;
 use quote::quote;
-use std::cell::RefCell;
-use std::collections::HashMap;
-use std::rc::Rc;
 use std::str::FromStr;
 
 fn parse_schema_from_str(
@@ -33,9 +30,7 @@ fn parse_schema_from_str(
 fn parse_schema_from_quote(
     input: proc_macro2::TokenStream,
 ) -> ParseResult<'static, ast::schema::Document> {
-    let span_map = Rc::new(RefCell::new(HashMap::new()));
-    let token_source =
-        RustMacroGraphQLTokenSource::new(input, span_map);
+    let token_source = RustMacroGraphQLTokenSource::new(input);
     let parser =
         GraphQLParser::from_token_source(token_source);
     let result = parser.parse_schema_document();
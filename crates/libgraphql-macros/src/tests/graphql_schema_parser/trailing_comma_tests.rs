@@ -7,20 +7,15 @@
 /// - Input object values
 /// - Argument lists
 /// - And by extension, field lists, enum value lists, etc.
-use crate::rust_macro_graphql_token_source::RustMacroGraphQLTokenSource;
+use libgraphql_parser::token::RustMacroGraphQLTokenSource;
 use libgraphql_parser::GraphQLParser;
 use libgraphql_parser::ParseResult;
 use quote::quote;
-use std::cell::RefCell;
-use std::collections::HashMap;
-use std::rc::Rc;
 
 fn parse_schema(
     input: proc_macro2::TokenStream,
 ) -> ParseResult<'static, libgraphql_core::ast::schema::Document> {
-    let span_map = Rc::new(RefCell::new(HashMap::new()));
-    let token_source =
-        RustMacroGraphQLTokenSource::new(input, span_map);
+    let token_source = RustMacroGraphQLTokenSource::new(input);
     let parser =
         GraphQLParser::from_token_source(token_source);
     let result = parser.parse_schema_document();
@@ -12,7 +12,7 @@
 use crate::parse_error_converter::convert_parse_errors_to_tokenstream;
 use crate::parse_error_converter::format_parse_error_message;
 use crate::parse_error_converter::format_parse_error_note;
-use crate::span_map::SpanMap;
+use libgraphql_parser::token::SpanMap;
 use libgraphql_parser::GraphQLErrorNote;
 use libgraphql_parser::GraphQLParseError;
 use libgraphql_parser::GraphQLParseErrorKind;
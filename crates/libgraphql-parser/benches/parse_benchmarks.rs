@@ -1,11 +1,13 @@
 mod fixtures;
 
+use bumpalo::Bump;
 use criterion::BenchmarkId;
 use criterion::Criterion;
 use criterion::Throughput;
 use criterion::black_box;
 use criterion::criterion_group;
 use criterion::criterion_main;
+use libgraphql_parser::ArenaGraphQLParser;
 use libgraphql_parser::GraphQLParser;
 use libgraphql_parser::GraphQLParserConfig;
 use libgraphql_parser::token::StrGraphQLTokenSource;
@@ -256,7 +258,145 @@ fn executable_parse_lean(c: &mut Criterion) {
     group.finish();
 }
 
-// ─── Group 5: Lexer (Tokenization Only) ──────────────────
+// ─── Group 5: Schema Parsing (Arena Mode) ────────────────
+//
+// The arena is reused (and reset) across iterations, which is
+// how a long-running service would typically drive it.
+
+fn schema_parse_arena(c: &mut Criterion) {
+    let mut group = c.benchmark_group("schema_parse_arena");
+    let shopify_admin =
+        fixtures::load_shopify_admin_schema();
+
+    group.bench_function("small (synthetic)", |b| {
+        let mut bump = Bump::new();
+        b.iter(|| {
+            bump.reset();
+            let parser =
+                ArenaGraphQLParser::new(fixtures::SMALL_SCHEMA, &bump);
+            black_box(parser.parse_schema_document().is_ok());
+        })
+    });
+
+    group.bench_function("medium (synthetic)", |b| {
+        let mut bump = Bump::new();
+        b.iter(|| {
+            bump.reset();
+            let parser =
+                ArenaGraphQLParser::new(fixtures::MEDIUM_SCHEMA, &bump);
+            black_box(parser.parse_schema_document().is_ok());
+        })
+    });
+
+    group.bench_function("large (synthetic)", |b| {
+        let mut bump = Bump::new();
+        b.iter(|| {
+            bump.reset();
+            let parser =
+                ArenaGraphQLParser::new(fixtures::LARGE_SCHEMA, &bump);
+            black_box(parser.parse_schema_document().is_ok());
+        })
+    });
+
+    group.bench_function("starwars", |b| {
+        let mut bump = Bump::new();
+        b.iter(|| {
+            bump.reset();
+            let parser =
+                ArenaGraphQLParser::new(fixtures::STARWARS_SCHEMA, &bump);
+            black_box(parser.parse_schema_document().is_ok());
+        })
+    });
+
+    group.bench_function("github", |b| {
+        let mut bump = Bump::new();
+        b.iter(|| {
+            bump.reset();
+            let parser =
+                ArenaGraphQLParser::new(fixtures::GITHUB_SCHEMA, &bump);
+            black_box(parser.parse_schema_document().is_ok());
+        })
+    });
+
+    group.bench_function("shopify_admin", |b| {
+        let mut bump = Bump::new();
+        b.iter(|| {
+            bump.reset();
+            let parser =
+                ArenaGraphQLParser::new(&shopify_admin, &bump);
+            black_box(parser.parse_schema_document().is_ok());
+        })
+    });
+
+    group.finish();
+}
+
+// ─── Group 6: Executable Document Parsing (Arena Mode) ───
+
+fn executable_parse_arena(c: &mut Criterion) {
+    let mut group =
+        c.benchmark_group("executable_parse_arena");
+
+    group.bench_function("simple_query", |b| {
+        let mut bump = Bump::new();
+        b.iter(|| {
+            bump.reset();
+            let parser =
+                ArenaGraphQLParser::new(fixtures::SIMPLE_QUERY, &bump);
+            black_box(parser.parse_executable_document().is_ok());
+        })
+    });
+
+    group.bench_function("complex_query", |b| {
+        let mut bump = Bump::new();
+        b.iter(|| {
+            bump.reset();
+            let parser =
+                ArenaGraphQLParser::new(fixtures::COMPLEX_QUERY, &bump);
+            black_box(parser.parse_executable_document().is_ok());
+        })
+    });
+
+    let nested_10 =
+        fixtures::operations::deeply_nested_query(10);
+    group.bench_function("nested_depth_10", |b| {
+        let mut bump = Bump::new();
+        b.iter(|| {
+            bump.reset();
+            let parser =
+                ArenaGraphQLParser::new(&nested_10, &bump);
+            black_box(parser.parse_executable_document().is_ok());
+        })
+    });
+
+    let nested_30 =
+        fixtures::operations::deeply_nested_query(30);
+    group.bench_function("nested_depth_30", |b| {
+        let mut bump = Bump::new();
+        b.iter(|| {
+            bump.reset();
+            let parser =
+                ArenaGraphQLParser::new(&nested_30, &bump);
+            black_box(parser.parse_executable_document().is_ok());
+        })
+    });
+
+    let many_ops =
+        fixtures::operations::many_operations(50);
+    group.bench_function("many_operations_50", |b| {
+        let mut bump = Bump::new();
+        b.iter(|| {
+            bump.reset();
+            let parser =
+                ArenaGraphQLParser::new(&many_ops, &bump);
+            black_box(parser.parse_executable_document().is_ok());
+        })
+    });
+
+    group.finish();
+}
+
+// ─── Group 7: Lexer (Tokenization Only) ──────────────────
 
 fn lexer(c: &mut Criterion) {
     let mut group = c.benchmark_group("lexer");
@@ -350,7 +490,7 @@ fn lexer(c: &mut Criterion) {
     group.finish();
 }
 
-// ─── Group 6: Cross-Parser Comparisons ───────────────────
+// ─── Group 8: Cross-Parser Comparisons ───────────────────
 
 fn compare_schema_parse(c: &mut Criterion) {
     let mut group =
@@ -472,6 +612,8 @@ criterion_group!(
     executable_parse,
     schema_parse_lean,
     executable_parse_lean,
+    schema_parse_arena,
+    executable_parse_arena,
     lexer,
     compare_schema_parse,
     compare_executable_parse,
@@ -0,0 +1,13 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use libgraphql_parser_fuzz::check_against_apollo_parser;
+use libgraphql_parser_fuzz::check_schema_against_graphql_parser;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(s) = std::str::from_utf8(data) else {
+        return;
+    };
+    check_schema_against_graphql_parser(s);
+    check_against_apollo_parser(s);
+});
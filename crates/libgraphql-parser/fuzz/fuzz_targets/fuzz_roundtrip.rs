@@ -0,0 +1,13 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use libgraphql_parser_fuzz::check_executable_roundtrip;
+use libgraphql_parser_fuzz::check_schema_roundtrip;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(s) = std::str::from_utf8(data) else {
+        return;
+    };
+    check_schema_roundtrip(s);
+    check_executable_roundtrip(s);
+});
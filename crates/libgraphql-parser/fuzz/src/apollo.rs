@@ -0,0 +1,765 @@
+use crate::disagreement::DisagreementKind;
+use crate::disagreement::Oracle;
+use crate::disagreement::report;
+use apollo_parser::cst;
+use apollo_parser::cst::CstChildren;
+use apollo_parser::cst::CstNode;
+use libgraphql_parser::GraphQLParser;
+use libgraphql_parser::ast;
+use libgraphql_parser::ast::AstNode;
+
+/// Parses `source` as a mixed document with both `libgraphql-parser`
+/// and `apollo-parser`, and panics if they disagree on accept/reject
+/// or (when both accept) on the shape of the document.
+///
+/// `apollo-parser` produces a lossless CST rather than an AST, so
+/// both trees are rendered to a common [`Outline`] (one line per
+/// node, values normalized to their parsed form) and the outlines
+/// are compared. Descriptions on executable definitions are not
+/// rendered: `apollo-parser` rejects them (the
+/// `executable_description` known divergence), so they never reach
+/// the comparison.
+pub fn check_against_apollo_parser(source: &str) {
+    let ours = GraphQLParser::new(source).parse_mixed_document();
+    let tree = apollo_parser::Parser::new(source).parse();
+    let theirs_accepts = tree.errors().len() == 0;
+    let kind = match (ours.valid(), theirs_accepts) {
+        (Some((doc, _)), true) => {
+            let mut ours = Outline::default();
+            for def in &doc.definitions {
+                ours.definition(def);
+            }
+            let mut theirs = Outline::default();
+            for def in tree.document().definitions() {
+                theirs.cst_definition(def);
+            }
+            if ours.out == theirs.out {
+                return;
+            }
+            DisagreementKind::AstMismatch {
+                ours: ours.out,
+                theirs: theirs.out,
+            }
+        },
+        (None, true) => DisagreementKind::OnlyOracleAccepts {
+            our_errors: ours.errors(),
+        },
+        (Some(_), false) => DisagreementKind::OnlyOursAccepts {
+            oracle_error: tree
+                .errors()
+                .map(|e| format!("{e:?}"))
+                .collect::<Vec<_>>()
+                .join("\n"),
+        },
+        (None, false) => return,
+    };
+    report(Oracle::ApolloParser, source, kind);
+}
+
+/// An indented, one-node-per-line rendering of a document that both
+/// our AST and `apollo-parser`'s CST can be reduced to.
+///
+/// Type annotations are written in SDL form (`[Int!]!`), values in a
+/// compact GraphQL-like form with strings unescaped and numbers
+/// parsed, so that the two trees compare equal exactly when they
+/// describe the same document.
+#[derive(Default)]
+struct Outline {
+    depth: usize,
+    out: String,
+}
+
+impl Outline {
+    fn line(&mut self, text: impl std::fmt::Display) {
+        for _ in 0..self.depth {
+            self.out.push_str("  ");
+        }
+        self.out.push_str(&text.to_string());
+        self.out.push('\n');
+    }
+
+    fn nested(&mut self, text: impl std::fmt::Display, body: impl FnOnce(&mut Self)) {
+        self.line(text);
+        self.depth += 1;
+        body(self);
+        self.depth -= 1;
+    }
+
+    fn description(&mut self, description: Option<String>) {
+        if let Some(description) = description {
+            self.line(format!("description {description:?}"));
+        }
+    }
+
+    // =========================================================================
+    // libgraphql-parser AST
+    // =========================================================================
+
+    fn definition(&mut self, def: &ast::Definition<'_>) {
+        match def {
+            ast::Definition::DirectiveDefinition(d) => {
+                self.nested(format!("DirectiveDefinition @{}", d.name.value), |o| {
+                    o.description(d.description.as_ref().map(string_value));
+                    o.input_values("argument", &d.arguments);
+                    if d.repeatable {
+                        o.line("repeatable");
+                    }
+                    for location in &d.locations {
+                        o.line(format!("location {}", location.to_source(None)));
+                    }
+                });
+            },
+            ast::Definition::FragmentDefinition(d) => {
+                let header = format!(
+                    "FragmentDefinition {} on {}",
+                    d.name.value,
+                    d.type_condition.named_type.value,
+                );
+                self.nested(header, |o| {
+                    o.directives(&d.directives);
+                    o.selection_set(&d.selection_set);
+                });
+            },
+            ast::Definition::OperationDefinition(d) => {
+                let kind = match d.operation_kind {
+                    ast::OperationKind::Mutation => "mutation",
+                    ast::OperationKind::Query => "query",
+                    ast::OperationKind::Subscription => "subscription",
+                };
+                let name = d.name.as_ref().map_or("<anonymous>", |n| &n.value);
+                self.nested(format!("OperationDefinition {kind} {name}"), |o| {
+                    for var in &d.variable_definitions {
+                        let header = format!(
+                            "variable ${}: {}{}",
+                            var.variable.value,
+                            var.var_type.to_source(None),
+                            default_value(var.default_value.as_ref()),
+                        );
+                        o.nested(header, |o| o.directives(&var.directives));
+                    }
+                    o.directives(&d.directives);
+                    o.selection_set(&d.selection_set);
+                });
+            },
+            ast::Definition::SchemaDefinition(d) => {
+                self.nested("SchemaDefinition", |o| {
+                    o.description(d.description.as_ref().map(string_value));
+                    o.directives(&d.directives);
+                    o.root_operations(&d.root_operations);
+                });
+            },
+            ast::Definition::SchemaExtension(d) => {
+                self.nested("SchemaExtension", |o| {
+                    o.directives(&d.directives);
+                    o.root_operations(&d.root_operations);
+                });
+            },
+            ast::Definition::TypeDefinition(td) => self.type_definition(td),
+            ast::Definition::TypeExtension(te) => self.type_extension(te),
+        }
+    }
+
+    fn type_definition(&mut self, td: &ast::TypeDefinition<'_>) {
+        let header = |kind: &str, name: &ast::Name<'_>| format!("{kind} {}", name.value);
+        match td {
+            ast::TypeDefinition::Enum(d) => {
+                self.nested(header("EnumTypeDefinition", &d.name), |o| {
+                    o.description(d.description.as_ref().map(string_value));
+                    o.directives(&d.directives);
+                    o.enum_values(&d.values);
+                });
+            },
+            ast::TypeDefinition::InputObject(d) => {
+                self.nested(header("InputObjectTypeDefinition", &d.name), |o| {
+                    o.description(d.description.as_ref().map(string_value));
+                    o.directives(&d.directives);
+                    o.input_values("input field", &d.fields);
+                });
+            },
+            ast::TypeDefinition::Interface(d) => {
+                self.nested(header("InterfaceTypeDefinition", &d.name), |o| {
+                    o.description(d.description.as_ref().map(string_value));
+                    o.names("implements", &d.implements);
+                    o.directives(&d.directives);
+                    o.field_definitions(&d.fields);
+                });
+            },
+            ast::TypeDefinition::Object(d) => {
+                self.nested(header("ObjectTypeDefinition", &d.name), |o| {
+                    o.description(d.description.as_ref().map(string_value));
+                    o.names("implements", &d.implements);
+                    o.directives(&d.directives);
+                    o.field_definitions(&d.fields);
+                });
+            },
+            ast::TypeDefinition::Scalar(d) => {
+                self.nested(header("ScalarTypeDefinition", &d.name), |o| {
+                    o.description(d.description.as_ref().map(string_value));
+                    o.directives(&d.directives);
+                });
+            },
+            ast::TypeDefinition::Union(d) => {
+                self.nested(header("UnionTypeDefinition", &d.name), |o| {
+                    o.description(d.description.as_ref().map(string_value));
+                    o.directives(&d.directives);
+                    o.names("member", &d.members);
+                });
+            },
+        }
+    }
+
+    fn type_extension(&mut self, te: &ast::TypeExtension<'_>) {
+        let header = |kind: &str, name: &ast::Name<'_>| format!("{kind} {}", name.value);
+        match te {
+            ast::TypeExtension::Enum(d) => {
+                self.nested(header("EnumTypeExtension", &d.name), |o| {
+                    o.directives(&d.directives);
+                    o.enum_values(&d.values);
+                });
+            },
+            ast::TypeExtension::InputObject(d) => {
+                self.nested(header("InputObjectTypeExtension", &d.name), |o| {
+                    o.directives(&d.directives);
+                    o.input_values("input field", &d.fields);
+                });
+            },
+            ast::TypeExtension::Interface(d) => {
+                self.nested(header("InterfaceTypeExtension", &d.name), |o| {
+                    o.names("implements", &d.implements);
+                    o.directives(&d.directives);
+                    o.field_definitions(&d.fields);
+                });
+            },
+            ast::TypeExtension::Object(d) => {
+                self.nested(header("ObjectTypeExtension", &d.name), |o| {
+                    o.names("implements", &d.implements);
+                    o.directives(&d.directives);
+                    o.field_definitions(&d.fields);
+                });
+            },
+            ast::TypeExtension::Scalar(d) => {
+                self.nested(header("ScalarTypeExtension", &d.name), |o| {
+                    o.directives(&d.directives);
+                });
+            },
+            ast::TypeExtension::Union(d) => {
+                self.nested(header("UnionTypeExtension", &d.name), |o| {
+                    o.directives(&d.directives);
+                    o.names("member", &d.members);
+                });
+            },
+        }
+    }
+
+    fn names(&mut self, label: &str, names: &[ast::Name<'_>]) {
+        for name in names {
+            self.line(format!("{label} {}", name.value));
+        }
+    }
+
+    fn root_operations(&mut self, root_operations: &[ast::RootOperationTypeDefinition<'_>]) {
+        for root in root_operations {
+            let kind = match root.operation_kind {
+                ast::OperationKind::Mutation => "mutation",
+                ast::OperationKind::Query => "query",
+                ast::OperationKind::Subscription => "subscription",
+            };
+            self.line(format!("root {kind}: {}", root.named_type.value));
+        }
+    }
+
+    fn field_definitions(&mut self, fields: &[ast::FieldDefinition<'_>]) {
+        for field in fields {
+            let header = format!(
+                "field {}: {}",
+                field.name.value,
+                field.field_type.to_source(None),
+            );
+            self.nested(header, |o| {
+                o.description(field.description.as_ref().map(string_value));
+                o.input_values("argument", &field.parameters);
+                o.directives(&field.directives);
+            });
+        }
+    }
+
+    fn input_values(&mut self, label: &str, values: &[ast::InputValueDefinition<'_>]) {
+        for value in values {
+            let header = format!(
+                "{label} {}: {}{}",
+                value.name.value,
+                value.value_type.to_source(None),
+                default_value(value.default_value.as_ref()),
+            );
+            self.nested(header, |o| {
+                o.description(value.description.as_ref().map(string_value));
+                o.directives(&value.directives);
+            });
+        }
+    }
+
+    fn enum_values(&mut self, values: &[ast::EnumValueDefinition<'_>]) {
+        for value in values {
+            self.nested(format!("enum value {}", value.name.value), |o| {
+                o.description(value.description.as_ref().map(string_value));
+                o.directives(&value.directives);
+            });
+        }
+    }
+
+    fn directives(&mut self, directives: &[ast::DirectiveAnnotation<'_>]) {
+        for directive in directives {
+            self.nested(format!("directive @{}", directive.name.value), |o| {
+                o.arguments(&directive.arguments);
+            });
+        }
+    }
+
+    fn arguments(&mut self, arguments: &[ast::Argument<'_>]) {
+        for argument in arguments {
+            self.line(format!(
+                "argument {}: {}",
+                argument.name.value,
+                value(&argument.value),
+            ));
+        }
+    }
+
+    fn selection_set(&mut self, selection_set: &ast::SelectionSet<'_>) {
+        self.nested("selection set", |o| {
+            for selection in &selection_set.selections {
+                o.selection(selection);
+            }
+        });
+    }
+
+    fn selection(&mut self, selection: &ast::Selection<'_>) {
+        match selection {
+            ast::Selection::Field(field) => {
+                let header = match &field.alias {
+                    Some(alias) => format!("field {}: {}", alias.value, field.name.value),
+                    None => format!("field {}", field.name.value),
+                };
+                self.nested(header, |o| {
+                    o.arguments(&field.arguments);
+                    o.directives(&field.directives);
+                    if let Some(selection_set) = &field.selection_set {
+                        o.selection_set(selection_set);
+                    }
+                });
+            },
+            ast::Selection::FragmentSpread(spread) => {
+                self.nested(format!("fragment spread {}", spread.name.value), |o| {
+                    o.directives(&spread.directives);
+                });
+            },
+            ast::Selection::InlineFragment(inline) => {
+                let header = match &inline.type_condition {
+                    Some(tc) => format!("inline fragment on {}", tc.named_type.value),
+                    None => "inline fragment".to_string(),
+                };
+                self.nested(header, |o| {
+                    o.directives(&inline.directives);
+                    o.selection_set(&inline.selection_set);
+                });
+            },
+        }
+    }
+
+    // =========================================================================
+    // apollo-parser CST
+    // =========================================================================
+
+    fn cst_definition(&mut self, def: cst::Definition) {
+        use cst::Definition as D;
+        match def {
+            D::DirectiveDefinition(d) => {
+                self.nested(format!("DirectiveDefinition @{}", cst_name(d.name())), |o| {
+                    o.description(cst_description(d.description()));
+                    if let Some(args) = d.arguments_definition() {
+                        o.cst_input_values("argument", args.input_value_definitions());
+                    }
+                    if d.repeatable_token().is_some() {
+                        o.line("repeatable");
+                    }
+                    let locations = d.directive_locations().into_iter().flat_map(|locations| {
+                        locations.directive_locations()
+                    });
+                    for location in locations {
+                        let location = location.text();
+                        o.line(format!(
+                            "location {}",
+                            location.as_deref().unwrap_or("<missing>"),
+                        ));
+                    }
+                });
+            },
+            D::FragmentDefinition(d) => {
+                let header = format!(
+                    "FragmentDefinition {} on {}",
+                    cst_name(d.fragment_name().and_then(|f| f.name())),
+                    cst_name(d.type_condition().and_then(|tc| tc.named_type()?.name())),
+                );
+                self.nested(header, |o| {
+                    o.cst_directives(d.directives());
+                    o.cst_selection_set(d.selection_set());
+                });
+            },
+            D::OperationDefinition(d) => {
+                // A shorthand operation (`{ ... }`) has no operation type.
+                let kind = d.operation_type().map_or("query", cst_operation_kind);
+                let name = d.name().map_or("<anonymous>".to_string(), |n| n.text().to_string());
+                self.nested(format!("OperationDefinition {kind} {name}"), |o| {
+                    let vars = d.variable_definitions().into_iter().flat_map(|vars| {
+                        vars.variable_definitions()
+                    });
+                    for var in vars {
+                        let header = format!(
+                            "variable ${}: {}{}",
+                            cst_name(var.variable().and_then(|v| v.name())),
+                            cst_type(var.ty()),
+                            cst_default_value(var.default_value()),
+                        );
+                        o.nested(header, |o| o.cst_directives(var.directives()));
+                    }
+                    o.cst_directives(d.directives());
+                    o.cst_selection_set(d.selection_set());
+                });
+            },
+            D::SchemaDefinition(d) => {
+                self.nested("SchemaDefinition", |o| {
+                    o.description(cst_description(d.description()));
+                    o.cst_directives(d.directives());
+                    o.cst_root_operations(d.root_operation_type_definitions());
+                });
+            },
+            D::SchemaExtension(d) => {
+                self.nested("SchemaExtension", |o| {
+                    o.cst_directives(d.directives());
+                    o.cst_root_operations(d.root_operation_type_definitions());
+                });
+            },
+            D::EnumTypeDefinition(d) => {
+                self.nested(format!("EnumTypeDefinition {}", cst_name(d.name())), |o| {
+                    o.description(cst_description(d.description()));
+                    o.cst_directives(d.directives());
+                    o.cst_enum_values(d.enum_values_definition());
+                });
+            },
+            D::InputObjectTypeDefinition(d) => {
+                let header = format!("InputObjectTypeDefinition {}", cst_name(d.name()));
+                self.nested(header, |o| {
+                    o.description(cst_description(d.description()));
+                    o.cst_directives(d.directives());
+                    if let Some(fields) = d.input_fields_definition() {
+                        o.cst_input_values("input field", fields.input_value_definitions());
+                    }
+                });
+            },
+            D::InterfaceTypeDefinition(d) => {
+                let header = format!("InterfaceTypeDefinition {}", cst_name(d.name()));
+                self.nested(header, |o| {
+                    o.description(cst_description(d.description()));
+                    o.cst_implements(d.implements_interfaces());
+                    o.cst_directives(d.directives());
+                    o.cst_field_definitions(d.fields_definition());
+                });
+            },
+            D::ObjectTypeDefinition(d) => {
+                self.nested(format!("ObjectTypeDefinition {}", cst_name(d.name())), |o| {
+                    o.description(cst_description(d.description()));
+                    o.cst_implements(d.implements_interfaces());
+                    o.cst_directives(d.directives());
+                    o.cst_field_definitions(d.fields_definition());
+                });
+            },
+            D::ScalarTypeDefinition(d) => {
+                self.nested(format!("ScalarTypeDefinition {}", cst_name(d.name())), |o| {
+                    o.description(cst_description(d.description()));
+                    o.cst_directives(d.directives());
+                });
+            },
+            D::UnionTypeDefinition(d) => {
+                self.nested(format!("UnionTypeDefinition {}", cst_name(d.name())), |o| {
+                    o.description(cst_description(d.description()));
+                    o.cst_directives(d.directives());
+                    o.cst_union_members(d.union_member_types());
+                });
+            },
+            D::EnumTypeExtension(d) => {
+                self.nested(format!("EnumTypeExtension {}", cst_name(d.name())), |o| {
+                    o.cst_directives(d.directives());
+                    o.cst_enum_values(d.enum_values_definition());
+                });
+            },
+            D::InputObjectTypeExtension(d) => {
+                let header = format!("InputObjectTypeExtension {}", cst_name(d.name()));
+                self.nested(header, |o| {
+                    o.cst_directives(d.directives());
+                    if let Some(fields) = d.input_fields_definition() {
+                        o.cst_input_values("input field", fields.input_value_definitions());
+                    }
+                });
+            },
+            D::InterfaceTypeExtension(d) => {
+                let header = format!("InterfaceTypeExtension {}", cst_name(d.name()));
+                self.nested(header, |o| {
+                    o.cst_implements(d.implements_interfaces());
+                    o.cst_directives(d.directives());
+                    o.cst_field_definitions(d.fields_definition());
+                });
+            },
+            D::ObjectTypeExtension(d) => {
+                self.nested(format!("ObjectTypeExtension {}", cst_name(d.name())), |o| {
+                    o.cst_implements(d.implements_interfaces());
+                    o.cst_directives(d.directives());
+                    o.cst_field_definitions(d.fields_definition());
+                });
+            },
+            D::ScalarTypeExtension(d) => {
+                self.nested(format!("ScalarTypeExtension {}", cst_name(d.name())), |o| {
+                    o.cst_directives(d.directives());
+                });
+            },
+            D::UnionTypeExtension(d) => {
+                self.nested(format!("UnionTypeExtension {}", cst_name(d.name())), |o| {
+                    o.cst_directives(d.directives());
+                    o.cst_union_members(d.union_member_types());
+                });
+            },
+        }
+    }
+
+    fn cst_implements(&mut self, implements: Option<cst::ImplementsInterfaces>) {
+        for named in implements.into_iter().flat_map(|i| i.named_types()) {
+            self.line(format!("implements {}", cst_name(named.name())));
+        }
+    }
+
+    fn cst_union_members(&mut self, members: Option<cst::UnionMemberTypes>) {
+        for named in members.into_iter().flat_map(|m| m.named_types()) {
+            self.line(format!("member {}", cst_name(named.name())));
+        }
+    }
+
+    fn cst_root_operations(&mut self, roots: CstChildren<cst::RootOperationTypeDefinition>) {
+        for root in roots {
+            let kind = root.operation_type().map_or("<missing>", cst_operation_kind);
+            let named_type = cst_name(root.named_type().and_then(|n| n.name()));
+            self.line(format!("root {kind}: {named_type}"));
+        }
+    }
+
+    fn cst_field_definitions(&mut self, fields: Option<cst::FieldsDefinition>) {
+        for field in fields.into_iter().flat_map(|f| f.field_definitions()) {
+            let header = format!("field {}: {}", cst_name(field.name()), cst_type(field.ty()));
+            self.nested(header, |o| {
+                o.description(cst_description(field.description()));
+                if let Some(args) = field.arguments_definition() {
+                    o.cst_input_values("argument", args.input_value_definitions());
+                }
+                o.cst_directives(field.directives());
+            });
+        }
+    }
+
+    fn cst_input_values(
+        &mut self,
+        label: &str,
+        values: CstChildren<cst::InputValueDefinition>,
+    ) {
+        for value in values {
+            let header = format!(
+                "{label} {}: {}{}",
+                cst_name(value.name()),
+                cst_type(value.ty()),
+                cst_default_value(value.default_value()),
+            );
+            self.nested(header, |o| {
+                o.description(cst_description(value.description()));
+                o.cst_directives(value.directives());
+            });
+        }
+    }
+
+    fn cst_enum_values(&mut self, values: Option<cst::EnumValuesDefinition>) {
+        for value in values.into_iter().flat_map(|v| v.enum_value_definitions()) {
+            let name = cst_name(value.enum_value().and_then(|v| v.name()));
+            self.nested(format!("enum value {name}"), |o| {
+                o.description(cst_description(value.description()));
+                o.cst_directives(value.directives());
+            });
+        }
+    }
+
+    fn cst_directives(&mut self, directives: Option<cst::Directives>) {
+        for directive in directives.into_iter().flat_map(|d| d.directives()) {
+            self.nested(format!("directive @{}", cst_name(directive.name())), |o| {
+                o.cst_arguments(directive.arguments());
+            });
+        }
+    }
+
+    fn cst_arguments(&mut self, arguments: Option<cst::Arguments>) {
+        for argument in arguments.into_iter().flat_map(|a| a.arguments()) {
+            self.line(format!(
+                "argument {}: {}",
+                cst_name(argument.name()),
+                cst_value(argument.value()),
+            ));
+        }
+    }
+
+    fn cst_selection_set(&mut self, selection_set: Option<cst::SelectionSet>) {
+        self.nested("selection set", |o| {
+            for selection in selection_set.into_iter().flat_map(|s| s.selections()) {
+                o.cst_selection(selection);
+            }
+        });
+    }
+
+    fn cst_selection(&mut self, selection: cst::Selection) {
+        match selection {
+            cst::Selection::Field(field) => {
+                let header = match field.alias() {
+                    Some(alias) => format!(
+                        "field {}: {}",
+                        cst_name(alias.name()),
+                        cst_name(field.name()),
+                    ),
+                    None => format!("field {}", cst_name(field.name())),
+                };
+                self.nested(header, |o| {
+                    o.cst_arguments(field.arguments());
+                    o.cst_directives(field.directives());
+                    if let Some(selection_set) = field.selection_set() {
+                        o.cst_selection_set(Some(selection_set));
+                    }
+                });
+            },
+            cst::Selection::FragmentSpread(spread) => {
+                let name = cst_name(spread.fragment_name().and_then(|f| f.name()));
+                self.nested(format!("fragment spread {name}"), |o| {
+                    o.cst_directives(spread.directives());
+                });
+            },
+            cst::Selection::InlineFragment(inline) => {
+                let header = match inline.type_condition() {
+                    Some(tc) => format!(
+                        "inline fragment on {}",
+                        cst_name(tc.named_type().and_then(|n| n.name())),
+                    ),
+                    None => "inline fragment".to_string(),
+                };
+                self.nested(header, |o| {
+                    o.cst_directives(inline.directives());
+                    o.cst_selection_set(inline.selection_set());
+                });
+            },
+        }
+    }
+}
+
+// =============================================================================
+// Leaf rendering
+// =============================================================================
+
+fn string_value(value: &ast::StringValue<'_>) -> String {
+    value.value.to_string()
+}
+
+fn default_value(value: Option<&ast::Value<'_>>) -> String {
+    value.map_or(String::new(), |value| format!(" = {}", self::value(value)))
+}
+
+fn value(value: &ast::Value<'_>) -> String {
+    match value {
+        ast::Value::Boolean(v) => v.value.to_string(),
+        ast::Value::Enum(v) => v.value.to_string(),
+        ast::Value::Float(v) => format!("{:?}", v.value),
+        ast::Value::Int(v) => v.value.to_string(),
+        ast::Value::List(v) => {
+            let items: Vec<_> = v.values.iter().map(self::value).collect();
+            format!("[{}]", items.join(", "))
+        },
+        ast::Value::Null(_) => "null".to_string(),
+        ast::Value::Object(v) => {
+            let fields: Vec<_> = v
+                .fields
+                .iter()
+                .map(|f| format!("{}: {}", f.name.value, self::value(&f.value)))
+                .collect();
+            format!("{{{}}}", fields.join(", "))
+        },
+        ast::Value::String(v) => format!("{:?}", v.value),
+        ast::Value::Variable(v) => format!("${}", v.name.value),
+    }
+}
+
+fn cst_name(name: Option<cst::Name>) -> String {
+    name.map_or("<missing>".to_string(), |n| n.text().to_string())
+}
+
+fn cst_description(description: Option<cst::Description>) -> Option<String> {
+    description?.string_value().map(String::from)
+}
+
+fn cst_operation_kind(operation_type: cst::OperationType) -> &'static str {
+    if operation_type.mutation_token().is_some() {
+        "mutation"
+    } else if operation_type.subscription_token().is_some() {
+        "subscription"
+    } else {
+        "query"
+    }
+}
+
+fn cst_type(ty: Option<cst::Type>) -> String {
+    match ty {
+        Some(cst::Type::NamedType(named)) => cst_name(named.name()),
+        Some(cst::Type::ListType(list)) => format!("[{}]", cst_type(list.ty())),
+        Some(cst::Type::NonNullType(non_null)) => {
+            let inner = match (non_null.named_type(), non_null.list_type()) {
+                (Some(named), _) => cst_name(named.name()),
+                (None, Some(list)) => format!("[{}]", cst_type(list.ty())),
+                (None, None) => "<missing>".to_string(),
+            };
+            format!("{inner}!")
+        },
+        None => "<missing>".to_string(),
+    }
+}
+
+fn cst_default_value(value: Option<cst::DefaultValue>) -> String {
+    value.map_or(String::new(), |value| format!(" = {}", cst_value(value.value())))
+}
+
+fn cst_value(value: Option<cst::Value>) -> String {
+    let Some(value) = value else {
+        return "<missing>".to_string();
+    };
+    // Fall back to the literal's source text for numbers we cannot
+    // represent; our parser rejects those, so they never compare
+    // equal by accident.
+    let text = || value.syntax().text().to_string();
+    match &value {
+        cst::Value::BooleanValue(v) => (v.true_token().is_some()).to_string(),
+        cst::Value::EnumValue(v) => cst_name(v.name()),
+        cst::Value::FloatValue(v) => f64::try_from(v).map_or_else(|_| text(), |f| format!("{f:?}")),
+        cst::Value::IntValue(v) => i32::try_from(v).map_or_else(|_| text(), |i| i.to_string()),
+        cst::Value::ListValue(v) => {
+            let items: Vec<_> = v.values().map(|item| cst_value(Some(item))).collect();
+            format!("[{}]", items.join(", "))
+        },
+        cst::Value::NullValue(_) => "null".to_string(),
+        cst::Value::ObjectValue(v) => {
+            let fields: Vec<_> = v
+                .object_fields()
+                .map(|f| format!("{}: {}", cst_name(f.name()), cst_value(f.value())))
+                .collect();
+            format!("{{{}}}", fields.join(", "))
+        },
+        cst::Value::StringValue(v) => format!("{:?}", String::from(v)),
+        cst::Value::Variable(v) => format!("${}", cst_name(v.name())),
+    }
+}
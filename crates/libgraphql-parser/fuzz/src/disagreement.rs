@@ -0,0 +1,134 @@
+use crate::known_divergences::KNOWN_DIVERGENCES;
+use libgraphql_parser::GraphQLParseError;
+use libgraphql_parser::GraphQLParser;
+use libgraphql_parser::GraphQLParserConfig;
+use libgraphql_parser::ast;
+
+/// The reference a fuzz input was checked against.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Oracle {
+    /// `apollo-parser` v0.8 (always parses mixed documents).
+    ApolloParser,
+
+    /// `graphql_parser` v0.4, compared through
+    /// `compat::graphql_parser_v0_4`.
+    GraphQLParserV04,
+
+    /// `libgraphql-parser` itself: the original parse acts as the
+    /// oracle, and "ours" is the re-parse of its printed output.
+    RoundTrip,
+}
+
+/// How `libgraphql-parser` and an [`Oracle`] disagreed.
+#[derive(Debug)]
+pub enum DisagreementKind<'a> {
+    /// Both accepted the input but produced different trees. Each
+    /// side is rendered to a string in the oracle's vocabulary.
+    AstMismatch {
+        ours: String,
+        theirs: String,
+    },
+
+    /// The oracle accepted the input; we rejected it.
+    OnlyOracleAccepts {
+        our_errors: &'a [GraphQLParseError],
+    },
+
+    /// We accepted the input; the oracle rejected it.
+    OnlyOursAccepts {
+        oracle_error: String,
+    },
+}
+
+/// A single disagreement between `libgraphql-parser` and an
+/// [`Oracle`] on one fuzz input.
+#[derive(Debug)]
+pub struct Disagreement<'a, 'src> {
+    pub kind: DisagreementKind<'a>,
+    pub oracle: Oracle,
+
+    /// Our (lean, mixed-document) parse of `source`, when it
+    /// succeeded. Used by allowlist predicates to inspect which
+    /// language features the input uses.
+    pub our_document: Option<&'a ast::Document<'src>>,
+
+    pub source: &'src str,
+}
+
+impl Disagreement<'_, '_> {
+    /// Returns `true` if some entry in [`KNOWN_DIVERGENCES`] explains
+    /// this disagreement.
+    ///
+    /// When only the oracle accepted the input and no entry explains
+    /// all of our errors together, each error is matched on its own,
+    /// so an input that trips several unrelated known divergences is
+    /// still recognized.
+    fn is_known(&self) -> bool {
+        if KNOWN_DIVERGENCES
+            .iter()
+            .any(|known| known.oracle == self.oracle && (known.matches)(self))
+        {
+            return true;
+        }
+        let DisagreementKind::OnlyOracleAccepts { our_errors } = self.kind else {
+            return false;
+        };
+        our_errors.len() > 1
+            && our_errors.iter().all(|error| {
+                Disagreement {
+                    kind: DisagreementKind::OnlyOracleAccepts {
+                        our_errors: std::slice::from_ref(error),
+                    },
+                    oracle: self.oracle,
+                    our_document: self.our_document,
+                    source: self.source,
+                }
+                .is_known()
+            })
+    }
+
+    /// Panics unless this disagreement matches an entry in
+    /// [`KNOWN_DIVERGENCES`].
+    pub(crate) fn assert_known(&self) {
+        if self.is_known() {
+            return;
+        }
+        let details = match &self.kind {
+            DisagreementKind::AstMismatch { ours, theirs } => format!(
+                "ASTs differ.\n\n--- ours ---\n{ours}\n\n--- theirs ---\n{theirs}",
+            ),
+            DisagreementKind::OnlyOracleAccepts { our_errors } => format!(
+                "Only the oracle accepted the input. Our errors:\n{}",
+                our_errors
+                    .iter()
+                    .map(|e| e.format_oneline())
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            ),
+            DisagreementKind::OnlyOursAccepts { oracle_error } => format!(
+                "Only libgraphql-parser accepted the input. Oracle error:\n\
+                 {oracle_error}",
+            ),
+        };
+        panic!(
+            "Unexpected divergence from {:?}.\n\nSource:\n{}\n\n{details}",
+            self.oracle,
+            self.source,
+        );
+    }
+}
+
+/// Builds a [`Disagreement`] for `source` and panics unless it is a
+/// known divergence.
+pub(crate) fn report(oracle: Oracle, source: &str, kind: DisagreementKind<'_>) {
+    let mixed = GraphQLParser::with_config(source, GraphQLParserConfig::lean())
+        .parse_mixed_document();
+    Disagreement {
+        kind,
+        oracle,
+        our_document: mixed.valid().map(|(doc, _)| doc),
+        source,
+    }
+    .assert_known();
+}
+
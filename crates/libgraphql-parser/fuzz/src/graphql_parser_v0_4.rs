@@ -0,0 +1,66 @@
+use crate::disagreement::DisagreementKind;
+use crate::disagreement::Oracle;
+use crate::disagreement::report;
+use libgraphql_parser::GraphQLParser;
+use libgraphql_parser::compat::graphql_parser_v0_4::to_graphql_parser_query_ast;
+use libgraphql_parser::compat::graphql_parser_v0_4::to_graphql_parser_schema_ast;
+
+/// Parses `source` as a schema document with both
+/// `libgraphql-parser` and `graphql_parser` v0.4, and panics if they
+/// disagree on accept/reject or (when both accept) on the converted
+/// AST.
+pub fn check_schema_against_graphql_parser(source: &str) {
+    let ours = GraphQLParser::new(source).parse_schema_document();
+    let theirs = graphql_parser::schema::parse_schema::<String>(source);
+    let kind = match (ours.valid(), theirs) {
+        (Some((doc, source_map)), Ok(their_doc)) => {
+            let ours = format!(
+                "{:#?}",
+                to_graphql_parser_schema_ast(doc, source_map).into_ast(),
+            );
+            let theirs = format!("{:#?}", their_doc.into_static());
+            if ours == theirs {
+                return;
+            }
+            DisagreementKind::AstMismatch { ours, theirs }
+        },
+        (None, Ok(_)) => DisagreementKind::OnlyOracleAccepts {
+            our_errors: ours.errors(),
+        },
+        (Some(_), Err(err)) => DisagreementKind::OnlyOursAccepts {
+            oracle_error: err.to_string(),
+        },
+        (None, Err(_)) => return,
+    };
+    report(Oracle::GraphQLParserV04, source, kind);
+}
+
+/// Parses `source` as an executable document with both
+/// `libgraphql-parser` and `graphql_parser` v0.4, and panics if they
+/// disagree on accept/reject or (when both accept) on the converted
+/// AST.
+pub fn check_executable_against_graphql_parser(source: &str) {
+    let ours = GraphQLParser::new(source).parse_executable_document();
+    let theirs = graphql_parser::parse_query::<String>(source);
+    let kind = match (ours.valid(), theirs) {
+        (Some((doc, source_map)), Ok(their_doc)) => {
+            let ours = format!(
+                "{:#?}",
+                to_graphql_parser_query_ast(doc, source_map).into_ast(),
+            );
+            let theirs = format!("{:#?}", their_doc.into_static());
+            if ours == theirs {
+                return;
+            }
+            DisagreementKind::AstMismatch { ours, theirs }
+        },
+        (None, Ok(_)) => DisagreementKind::OnlyOracleAccepts {
+            our_errors: ours.errors(),
+        },
+        (Some(_), Err(err)) => DisagreementKind::OnlyOursAccepts {
+            oracle_error: err.to_string(),
+        },
+        (None, Err(_)) => return,
+    };
+    report(Oracle::GraphQLParserV04, source, kind);
+}
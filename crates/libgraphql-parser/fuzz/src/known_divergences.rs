@@ -0,0 +1,312 @@
+use crate::disagreement::Disagreement;
+use crate::disagreement::DisagreementKind;
+use crate::disagreement::Oracle;
+use libgraphql_parser::GraphQLParseErrorKind;
+use libgraphql_parser::ValueParsingError;
+use libgraphql_parser::ast;
+use libgraphql_parser::token::GraphQLTokenKind;
+use libgraphql_parser::token::StrGraphQLTokenSource;
+use libgraphql_parser::token::StrGraphQLTokenSourceConfig;
+
+/// A documented, intentional difference between `libgraphql-parser`
+/// and an [`Oracle`].
+///
+/// Divergences are matched structurally (by inspecting the
+/// [`Disagreement`]) rather than by input, so each entry covers one
+/// spec-level difference wherever it shows up. Anything that does not
+/// match an entry is treated as a bug.
+///
+/// A [`DisagreementKind::OnlyOracleAccepts`] disagreement that no
+/// entry explains as a whole is also matched one error at a time, in
+/// which case each of our errors must be explained by some entry.
+pub struct KnownDivergence {
+    /// Short identifier used in reports.
+    pub name: &'static str,
+
+    /// The oracle this divergence applies to.
+    pub oracle: Oracle,
+
+    /// Why the parsers disagree, and which side follows the spec.
+    pub reason: &'static str,
+
+    /// Returns `true` if the disagreement is explained by this
+    /// divergence.
+    pub matches: fn(&Disagreement<'_, '_>) -> bool,
+}
+
+/// All known divergences between `libgraphql-parser` and the fuzz
+/// oracles.
+pub static KNOWN_DIVERGENCES: &[KnownDivergence] = &[
+    // -------------------------------------------------------------------------
+    // Both parser oracles
+    // -------------------------------------------------------------------------
+    KnownDivergence {
+        name: "empty_document",
+        oracle: Oracle::ApolloParser,
+        reason: EMPTY_DOCUMENT_REASON,
+        matches: accepted_empty_document,
+    },
+    KnownDivergence {
+        name: "empty_document",
+        oracle: Oracle::GraphQLParserV04,
+        reason: EMPTY_DOCUMENT_REASON,
+        matches: accepted_empty_document,
+    },
+    KnownDivergence {
+        name: "empty_braced_body",
+        oracle: Oracle::ApolloParser,
+        reason: EMPTY_BRACED_BODY_REASON,
+        matches: accepted_empty_braced_body,
+    },
+    KnownDivergence {
+        name: "empty_braced_body",
+        oracle: Oracle::GraphQLParserV04,
+        reason: EMPTY_BRACED_BODY_REASON,
+        matches: accepted_empty_braced_body,
+    },
+    KnownDivergence {
+        name: "braced_unicode_escape",
+        oracle: Oracle::ApolloParser,
+        reason: BRACED_UNICODE_ESCAPE_REASON,
+        matches: accepted_braced_unicode_escape,
+    },
+    KnownDivergence {
+        name: "braced_unicode_escape",
+        oracle: Oracle::GraphQLParserV04,
+        reason: BRACED_UNICODE_ESCAPE_REASON,
+        matches: accepted_braced_unicode_escape,
+    },
+    KnownDivergence {
+        name: "int_out_of_range",
+        oracle: Oracle::ApolloParser,
+        reason: INT_OUT_OF_RANGE_REASON,
+        matches: rejected_int_out_of_range,
+    },
+    KnownDivergence {
+        name: "int_out_of_range",
+        oracle: Oracle::GraphQLParserV04,
+        reason: INT_OUT_OF_RANGE_REASON,
+        matches: rejected_int_out_of_range,
+    },
+    KnownDivergence {
+        name: "non_finite_float",
+        oracle: Oracle::ApolloParser,
+        reason: NON_FINITE_FLOAT_REASON,
+        matches: rejected_non_finite_float,
+    },
+    KnownDivergence {
+        name: "non_finite_float",
+        oracle: Oracle::GraphQLParserV04,
+        reason: NON_FINITE_FLOAT_REASON,
+        matches: rejected_non_finite_float,
+    },
+    KnownDivergence {
+        name: "executable_description",
+        oracle: Oracle::ApolloParser,
+        reason: EXECUTABLE_DESCRIPTION_REASON,
+        matches: accepted_executable_description,
+    },
+    KnownDivergence {
+        name: "executable_description",
+        oracle: Oracle::GraphQLParserV04,
+        reason: EXECUTABLE_DESCRIPTION_REASON,
+        matches: accepted_executable_description,
+    },
+
+    // -------------------------------------------------------------------------
+    // apollo-parser only
+    // -------------------------------------------------------------------------
+    KnownDivergence {
+        name: "missing_colon",
+        oracle: Oracle::ApolloParser,
+        reason: "apollo-parser silently tolerates a missing `:` in some \
+                 positions (e.g. `a(b)` or `a(b c: 1)`) without reporting \
+                 an error. The spec requires the colon. Any errors we \
+                 report after the first are recovery fallout.",
+        matches: rejected_missing_colon,
+    },
+
+    // -------------------------------------------------------------------------
+    // graphql_parser v0.4 only
+    // -------------------------------------------------------------------------
+    KnownDivergence {
+        name: "reserved_name",
+        oracle: Oracle::GraphQLParserV04,
+        reason: "We reject `on` as a fragment name and `true`/`false`/`null` \
+                 as enum values, as the spec requires. graphql_parser \
+                 v0.4 accepts them.",
+        matches: rejected_reserved_name,
+    },
+    KnownDivergence {
+        name: "variable_directives",
+        oracle: Oracle::GraphQLParserV04,
+        reason: "Directives on variable definitions are valid since the \
+                 October 2021 spec. graphql_parser v0.4 predates them.",
+        matches: accepted_variable_directives,
+    },
+    KnownDivergence {
+        name: "schema_extension",
+        oracle: Oracle::GraphQLParserV04,
+        reason: "graphql_parser v0.4 does not support `extend schema`.",
+        matches: accepted_schema_extension,
+    },
+];
+
+const EMPTY_DOCUMENT_REASON: &str =
+    "We deliberately accept documents with no definitions (e.g. an empty \
+     file or one containing only comments). The spec grammar requires at \
+     least one definition.";
+
+const EMPTY_BRACED_BODY_REASON: &str =
+    "The September 2025 spec allows empty field, enum value, and input \
+     field lists (`type Query {}`). Older parsers require at least one \
+     entry.";
+
+const BRACED_UNICODE_ESCAPE_REASON: &str =
+    "Braced unicode escapes (`\\u{1F600}`) were added in the October 2021 \
+     spec.";
+
+const INT_OUT_OF_RANGE_REASON: &str =
+    "We reject integer literals outside the 32-bit signed range, which \
+     the spec's `Int` type cannot represent.";
+
+const NON_FINITE_FLOAT_REASON: &str =
+    "We reject float literals that overflow to infinity, which the \
+     spec's `Float` type cannot represent.";
+
+const EXECUTABLE_DESCRIPTION_REASON: &str =
+    "The September 2025 spec allows descriptions on operations, \
+     fragments, and variable definitions.";
+
+// =============================================================================
+// Matchers
+// =============================================================================
+
+fn accepted_empty_document(d: &Disagreement<'_, '_>) -> bool {
+    matches!(d.kind, DisagreementKind::OnlyOursAccepts { .. })
+        && d.our_document.is_some_and(|doc| doc.definitions.is_empty())
+}
+
+fn accepted_empty_braced_body(d: &Disagreement<'_, '_>) -> bool {
+    matches!(d.kind, DisagreementKind::OnlyOursAccepts { .. })
+        && tokens(d.source).windows(2).any(|pair| {
+            matches!(
+                pair,
+                [GraphQLTokenKind::CurlyBraceOpen, GraphQLTokenKind::CurlyBraceClose],
+            )
+        })
+}
+
+fn accepted_braced_unicode_escape(d: &Disagreement<'_, '_>) -> bool {
+    matches!(d.kind, DisagreementKind::OnlyOursAccepts { .. })
+        && tokens(d.source).iter().any(|token| match token {
+            GraphQLTokenKind::StringValue(raw) => {
+                !raw.starts_with("\"\"\"") && raw.contains("\\u{")
+            },
+            _ => false,
+        })
+}
+
+fn accepted_executable_description(d: &Disagreement<'_, '_>) -> bool {
+    if !matches!(d.kind, DisagreementKind::OnlyOursAccepts { .. }) {
+        return false;
+    }
+    // Our parser accepts but does not retain executable descriptions,
+    // so look for a top-level string that introduces an operation or
+    // fragment.
+    let tokens = tokens(d.source);
+    let mut depth = 0usize;
+    tokens.windows(2).any(|pair| {
+        match &pair[0] {
+            GraphQLTokenKind::CurlyBraceOpen | GraphQLTokenKind::ParenOpen => depth += 1,
+            GraphQLTokenKind::CurlyBraceClose | GraphQLTokenKind::ParenClose => {
+                depth = depth.saturating_sub(1)
+            },
+            _ => (),
+        }
+        depth == 0
+            && matches!(pair[0], GraphQLTokenKind::StringValue(_))
+            && match &pair[1] {
+                GraphQLTokenKind::CurlyBraceOpen => true,
+                GraphQLTokenKind::Name(name) => matches!(
+                    name.as_ref(),
+                    "query" | "mutation" | "subscription" | "fragment",
+                ),
+                _ => false,
+            }
+    })
+}
+
+fn accepted_variable_directives(d: &Disagreement<'_, '_>) -> bool {
+    if !matches!(d.kind, DisagreementKind::OnlyOursAccepts { .. }) {
+        return false;
+    }
+    d.our_document.is_some_and(|doc| {
+        doc.definitions.iter().any(|def| match def {
+            ast::Definition::OperationDefinition(op) => op
+                .variable_definitions
+                .iter()
+                .any(|var| !var.directives.is_empty()),
+            _ => false,
+        })
+    })
+}
+
+fn accepted_schema_extension(d: &Disagreement<'_, '_>) -> bool {
+    matches!(d.kind, DisagreementKind::OnlyOursAccepts { .. })
+        && d.our_document.is_some_and(|doc| {
+            doc.definitions
+                .iter()
+                .any(|def| matches!(def, ast::Definition::SchemaExtension(_)))
+        })
+}
+
+fn rejected_int_out_of_range(d: &Disagreement<'_, '_>) -> bool {
+    only_our_errors(d, |kind| {
+        matches!(kind, GraphQLParseErrorKind::InvalidValue(ValueParsingError::Int(_)))
+    })
+}
+
+fn rejected_non_finite_float(d: &Disagreement<'_, '_>) -> bool {
+    only_our_errors(d, |kind| {
+        matches!(kind, GraphQLParseErrorKind::InvalidValue(ValueParsingError::Float(_)))
+    })
+}
+
+fn rejected_reserved_name(d: &Disagreement<'_, '_>) -> bool {
+    only_our_errors(d, |kind| matches!(kind, GraphQLParseErrorKind::ReservedName { .. }))
+}
+
+fn rejected_missing_colon(d: &Disagreement<'_, '_>) -> bool {
+    let DisagreementKind::OnlyOracleAccepts { our_errors } = d.kind else {
+        return false;
+    };
+    our_errors
+        .first()
+        .is_some_and(|error| error.message().starts_with("expected `:`"))
+}
+
+// =============================================================================
+// Helpers
+// =============================================================================
+
+/// Returns `true` if `d` is a [`DisagreementKind::OnlyOracleAccepts`]
+/// whose errors all satisfy `pred`.
+fn only_our_errors(
+    d: &Disagreement<'_, '_>,
+    pred: fn(&GraphQLParseErrorKind) -> bool,
+) -> bool {
+    let DisagreementKind::OnlyOracleAccepts { our_errors } = d.kind else {
+        return false;
+    };
+    our_errors.iter().all(|error| pred(error.kind()))
+}
+
+/// Lexes `source` (without trivia) into its token kinds, excluding the
+/// trailing EOF.
+fn tokens(source: &str) -> Vec<GraphQLTokenKind<'_>> {
+    StrGraphQLTokenSource::with_config(source, StrGraphQLTokenSourceConfig::no_trivia())
+        .map(|token| token.kind)
+        .filter(|kind| !matches!(kind, GraphQLTokenKind::Eof))
+        .collect()
+}
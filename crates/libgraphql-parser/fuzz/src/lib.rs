@@ -0,0 +1,26 @@
+//! Oracles shared by the differential and round-trip fuzz targets.
+//!
+//! Each `check_*` function parses its input with `libgraphql-parser`
+//! and compares the outcome against a reference: another parser
+//! (`graphql_parser` v0.4 via
+//! [`compat::graphql_parser_v0_4`](libgraphql_parser::compat::graphql_parser_v0_4),
+//! or `apollo-parser`), or a re-parse of our own printed output. Any
+//! disagreement panics — which libFuzzer reports as a crash — unless
+//! it matches an entry in [`KNOWN_DIVERGENCES`].
+
+mod apollo;
+mod disagreement;
+mod graphql_parser_v0_4;
+mod known_divergences;
+mod roundtrip;
+
+pub use apollo::check_against_apollo_parser;
+pub use disagreement::Disagreement;
+pub use disagreement::DisagreementKind;
+pub use disagreement::Oracle;
+pub use graphql_parser_v0_4::check_executable_against_graphql_parser;
+pub use graphql_parser_v0_4::check_schema_against_graphql_parser;
+pub use known_divergences::KNOWN_DIVERGENCES;
+pub use known_divergences::KnownDivergence;
+pub use roundtrip::check_executable_roundtrip;
+pub use roundtrip::check_schema_roundtrip;
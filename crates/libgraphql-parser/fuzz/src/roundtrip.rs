@@ -0,0 +1,86 @@
+use crate::disagreement::DisagreementKind;
+use crate::disagreement::Oracle;
+use crate::disagreement::report;
+use libgraphql_parser::GraphQLParser;
+use libgraphql_parser::GraphQLParserConfig;
+use libgraphql_parser::ParseResult;
+use libgraphql_parser::ast;
+use libgraphql_parser::ast::AstNode;
+
+/// Parses `source` as a schema document, prints it with
+/// [`AstNode::to_source`], re-parses the printed text, and panics if
+/// the re-parse fails or yields a different AST (ignoring spans).
+pub fn check_schema_roundtrip(source: &str) {
+    check_roundtrip(source, parse_schema);
+}
+
+/// Parses `source` as an executable document, prints it with
+/// [`AstNode::to_source`], re-parses the printed text, and panics if
+/// the re-parse fails or yields a different AST (ignoring spans).
+pub fn check_executable_roundtrip(source: &str) {
+    check_roundtrip(source, parse_executable);
+}
+
+type ParseFn = for<'src> fn(&'src str) -> ParseResult<'src, ast::Document<'src>>;
+
+fn parse_schema(source: &str) -> ParseResult<'_, ast::Document<'_>> {
+    GraphQLParser::with_config(source, GraphQLParserConfig::lean())
+        .parse_schema_document()
+}
+
+fn parse_executable(source: &str) -> ParseResult<'_, ast::Document<'_>> {
+    GraphQLParser::with_config(source, GraphQLParserConfig::lean())
+        .parse_executable_document()
+}
+
+fn check_roundtrip(source: &str, parse: ParseFn) {
+    let original = parse(source);
+    let Some((original_doc, _)) = original.valid() else {
+        return;
+    };
+    // Printing without the source text exercises the synthetic
+    // printer rather than slicing the input back out.
+    let printed = original_doc.to_source(None);
+    let reparsed = parse(&printed);
+    let kind = match reparsed.valid() {
+        Some((reparsed_doc, _)) => {
+            let ours = without_spans(&format!("{reparsed_doc:#?}"));
+            let theirs = without_spans(&format!("{original_doc:#?}"));
+            if ours == theirs {
+                return;
+            }
+            DisagreementKind::AstMismatch {
+                ours: format!("{printed}\n\n{ours}"),
+                theirs,
+            }
+        },
+        None => DisagreementKind::OnlyOracleAccepts {
+            our_errors: reparsed.errors(),
+        },
+    };
+    report(Oracle::RoundTrip, source, kind);
+}
+
+/// Removes every `span: ByteSpan { .. }` field from a pretty-printed
+/// `Debug` rendering of an AST, so documents that differ only in
+/// byte offsets render identically.
+fn without_spans(debug: &str) -> String {
+    let mut out = String::with_capacity(debug.len());
+    let mut lines = debug.lines();
+    while let Some(line) = lines.next() {
+        if line.trim_start().starts_with("span: ByteSpan {") {
+            // Skip the field's nested lines through its closing brace.
+            if !line.trim_end().ends_with("},") {
+                for nested in lines.by_ref() {
+                    if nested.trim() == "}," {
+                        break;
+                    }
+                }
+            }
+            continue;
+        }
+        out.push_str(line);
+        out.push('\n');
+    }
+    out
+}
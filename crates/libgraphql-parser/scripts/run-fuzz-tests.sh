@@ -27,6 +27,9 @@ ALL_TARGETS=(
 	fuzz_parse_schema
 	fuzz_parse_executable
 	fuzz_parse_mixed
+	fuzz_differential_schema
+	fuzz_differential_executable
+	fuzz_roundtrip
 )
 
 assert_cargo_installed "cargo-fuzz" || exit 1
@@ -13,13 +13,13 @@
 use proptest::prelude::*;
 use proptest::strategy::BoxedStrategy;
 
-use crate::tests::property_tests::generators::names::arb_directive_name;
-use crate::tests::property_tests::generators::names::arb_field_name;
-use crate::tests::property_tests::generators::type_annotations::arb_type_annotation;
-use crate::tests::property_tests::generators::values::arb_const_value;
-use crate::tests::property_tests::generators::values::arb_value;
-use crate::tests::property_tests::generators::whitespace::arb_separator;
-use crate::tests::property_tests::generators::whitespace::join_items;
+use crate::arbitrary::names::arb_directive_name;
+use crate::arbitrary::names::arb_field_name;
+use crate::arbitrary::type_annotations::arb_type_annotation;
+use crate::arbitrary::values::arb_const_value;
+use crate::arbitrary::values::arb_value;
+use crate::arbitrary::whitespace::arb_separator;
+use crate::arbitrary::whitespace::join_items;
 
 /// Generates a directive annotation with runtime values:
 /// `@name` or `@name(arg: value, ...)`.
@@ -12,12 +12,12 @@
 use proptest::prelude::*;
 use proptest::strategy::BoxedStrategy;
 
-use crate::tests::property_tests::generators::extensions::arb_type_system_extension;
-use crate::tests::property_tests::generators::operations::arb_executable_definition;
-use crate::tests::property_tests::generators::operations::arb_named_executable_definition;
-use crate::tests::property_tests::generators::schema_types::arb_type_system_definition;
-use crate::tests::property_tests::generators::whitespace::arb_separator;
-use crate::tests::property_tests::generators::whitespace::join_items;
+use crate::arbitrary::extensions::arb_type_system_extension;
+use crate::arbitrary::operations::arb_executable_definition;
+use crate::arbitrary::operations::arb_named_executable_definition;
+use crate::arbitrary::schema_types::arb_type_system_definition;
+use crate::arbitrary::whitespace::arb_separator;
+use crate::arbitrary::whitespace::join_items;
 
 /// Generates a schema document (type-system definitions only).
 ///
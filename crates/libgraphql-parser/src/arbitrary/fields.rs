@@ -9,13 +9,13 @@
 use proptest::prelude::*;
 use proptest::strategy::BoxedStrategy;
 
-use crate::tests::property_tests::generators::directives::arb_const_directives;
-use crate::tests::property_tests::generators::names::arb_enum_value_name;
-use crate::tests::property_tests::generators::names::arb_field_name;
-use crate::tests::property_tests::generators::type_annotations::arb_type_annotation;
-use crate::tests::property_tests::generators::values::arb_const_value;
-use crate::tests::property_tests::generators::whitespace::arb_separator;
-use crate::tests::property_tests::generators::whitespace::join_items;
+use crate::arbitrary::directives::arb_const_directives;
+use crate::arbitrary::names::arb_enum_value_name;
+use crate::arbitrary::names::arb_field_name;
+use crate::arbitrary::type_annotations::arb_type_annotation;
+use crate::arbitrary::values::arb_const_value;
+use crate::arbitrary::whitespace::arb_separator;
+use crate::arbitrary::whitespace::join_items;
 
 /// Generates a field definition for object types and interfaces:
 /// `name(args): Type @directives`.
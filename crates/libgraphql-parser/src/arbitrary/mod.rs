@@ -0,0 +1,49 @@
+//! [`proptest`] strategies that generate syntactically valid GraphQL
+//! source text.
+//!
+//! Available with the `arbitrary` cargo feature.
+//!
+//! The generators mirror the GraphQL grammar hierarchically: leaf
+//! strategies (names, values, type annotations) compose into
+//! definitions (types, directives, operations, fragments), which
+//! compose into full [schema](documents::arb_schema_document),
+//! [executable](documents::arb_executable_document), and
+//! [mixed](documents::arb_mixed_document) documents. Each produces a
+//! `BoxedStrategy<String>`, and most take a `depth` parameter that
+//! bounds recursion (nested selection sets, list/object values,
+//! etc).
+//!
+//! Output is *syntactically* valid — every generated document parses
+//! without error — but not semantically validated: field types,
+//! fragment spreads, and similar references are arbitrary names.
+//! Schema-aware generators that produce documents valid against a
+//! particular schema live in `libgraphql-core`.
+//!
+//! # Example
+//!
+//! ```rust
+//! use libgraphql_parser::arbitrary::documents::arb_schema_document;
+//! use proptest::prelude::*;
+//!
+//! proptest! {
+//!     fn generated_schemas_parse(source in arb_schema_document(2)) {
+//!         let result = libgraphql_parser::parse_schema(&source);
+//!         prop_assert!(!result.has_errors());
+//!     }
+//! }
+//! # generated_schemas_parse();
+//! ```
+//!
+//! Written by Claude Code, reviewed by a human.
+
+pub mod directives;
+pub mod documents;
+pub mod extensions;
+pub mod fields;
+pub mod names;
+pub mod operations;
+pub mod schema_types;
+pub mod selections;
+pub mod type_annotations;
+pub mod values;
+pub mod whitespace;
@@ -12,12 +12,12 @@
 use proptest::prelude::*;
 use proptest::strategy::BoxedStrategy;
 
-use crate::tests::property_tests::generators::directives::arb_const_directives;
-use crate::tests::property_tests::generators::directives::arb_directive_definition;
-use crate::tests::property_tests::generators::fields::arb_enum_value_definitions;
-use crate::tests::property_tests::generators::fields::arb_field_definitions;
-use crate::tests::property_tests::generators::fields::arb_input_value_definitions;
-use crate::tests::property_tests::generators::names::arb_type_name;
+use crate::arbitrary::directives::arb_const_directives;
+use crate::arbitrary::directives::arb_directive_definition;
+use crate::arbitrary::fields::arb_enum_value_definitions;
+use crate::arbitrary::fields::arb_field_definitions;
+use crate::arbitrary::fields::arb_input_value_definitions;
+use crate::arbitrary::names::arb_type_name;
 
 /// Generates a ScalarTypeDefinition:
 /// `scalar Name @directives`
@@ -13,13 +13,13 @@
 use proptest::prelude::*;
 use proptest::strategy::BoxedStrategy;
 
-use crate::tests::property_tests::generators::directives::arb_directives;
-use crate::tests::property_tests::generators::names::arb_field_name;
-use crate::tests::property_tests::generators::names::arb_fragment_name;
-use crate::tests::property_tests::generators::names::arb_type_name;
-use crate::tests::property_tests::generators::values::arb_value;
-use crate::tests::property_tests::generators::whitespace::arb_separator;
-use crate::tests::property_tests::generators::whitespace::join_items;
+use crate::arbitrary::directives::arb_directives;
+use crate::arbitrary::names::arb_field_name;
+use crate::arbitrary::names::arb_fragment_name;
+use crate::arbitrary::names::arb_type_name;
+use crate::arbitrary::values::arb_value;
+use crate::arbitrary::whitespace::arb_separator;
+use crate::arbitrary::whitespace::join_items;
 
 /// Generates a selection set: `{ selection+ }`.
 ///
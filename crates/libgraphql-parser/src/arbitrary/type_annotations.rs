@@ -10,7 +10,7 @@
 use proptest::prelude::*;
 use proptest::strategy::BoxedStrategy;
 
-use crate::tests::property_tests::generators::names::arb_type_name;
+use crate::arbitrary::names::arb_type_name;
 
 /// Generates a valid GraphQL type annotation at the given nesting depth.
 ///
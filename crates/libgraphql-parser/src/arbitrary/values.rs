@@ -8,11 +8,11 @@
 use proptest::prelude::*;
 use proptest::strategy::BoxedStrategy;
 
-use crate::tests::property_tests::generators::names::arb_enum_value_name;
-use crate::tests::property_tests::generators::names::arb_field_name;
-use crate::tests::property_tests::generators::names::arb_name;
-use crate::tests::property_tests::generators::whitespace::arb_separator;
-use crate::tests::property_tests::generators::whitespace::join_items;
+use crate::arbitrary::names::arb_enum_value_name;
+use crate::arbitrary::names::arb_field_name;
+use crate::arbitrary::names::arb_name;
+use crate::arbitrary::whitespace::arb_separator;
+use crate::arbitrary::whitespace::join_items;
 
 /// Generates a valid GraphQL IntValue.
 ///
@@ -0,0 +1,1297 @@
+//! Recursive descent parser that builds an
+//! [arena-allocated AST](crate::ast::arena).
+//!
+//! [`ArenaGraphQLParser`] follows the same grammar as
+//! [`GraphQLParser`] but allocates every node, list, and owned
+//! string from a caller-supplied [`Bump`] arena instead of
+//! individual `Vec`/`Box`/`String` heap allocations. It always
+//! produces a lean tree (no syntax layer) and lexes without
+//! trivia.
+//!
+//! # Error Handling
+//!
+//! The arena parser is a fast path for documents that are
+//! expected to be valid, so it does not perform error recovery.
+//! When it encounters the first error it stops and re-parses the
+//! source with [`GraphQLParser`] to produce the complete list of
+//! [`GraphQLParseError`]s. Diagnostics are therefore identical to
+//! those of a standard parse; only the error path pays for the
+//! second pass.
+
+use crate::ast;
+use crate::ast::arena;
+use crate::ByteSpan;
+use crate::GraphQLParseError;
+use crate::GraphQLParseErrorKind;
+use crate::GraphQLParser;
+use crate::GraphQLParserConfig;
+use crate::GraphQLTokenStream;
+use crate::SourceMap;
+use crate::SourceSpan;
+use crate::ast::DocumentKind;
+use crate::token::GraphQLToken;
+use crate::token::GraphQLTokenKind;
+use crate::token::StrGraphQLTokenSource;
+use crate::token::StrGraphQLTokenSourceConfig;
+use bumpalo::Bump;
+use bumpalo::collections::Vec as BumpVec;
+use std::borrow::Cow;
+
+/// A parser that builds an [arena-allocated AST](crate::ast::arena)
+/// from a string source.
+///
+/// The returned [`arena::Document`] borrows from both the source
+/// text and the arena, so it lives for the shorter of the two
+/// (`'src: 'arena`).
+///
+/// # Usage
+///
+/// ```rust
+/// use bumpalo::Bump;
+/// use libgraphql_parser::ArenaGraphQLParser;
+///
+/// let bump = Bump::new();
+/// let parser = ArenaGraphQLParser::new("{ me { name } }", &bump);
+/// let (doc, _source_map) = parser.parse_executable_document().unwrap();
+/// assert_eq!(doc.definitions.len(), 1);
+///
+/// // Parse errors are reported exactly as `GraphQLParser` reports
+/// // them.
+/// let parser = ArenaGraphQLParser::new("{ me { name }", &bump);
+/// let errors = parser.parse_executable_document().unwrap_err();
+/// assert!(!errors.is_empty());
+/// ```
+pub struct ArenaGraphQLParser<'src, 'arena> {
+    /// The arena that all AST nodes are allocated from.
+    arena: &'arena Bump,
+
+    /// The source text, retained so that the error path can
+    /// re-parse it with [`GraphQLParser`].
+    source: &'src str,
+
+    /// The underlying token stream with lookahead support.
+    token_stream:
+        GraphQLTokenStream<'src, StrGraphQLTokenSource<'src>>,
+
+    /// Current nesting depth for recursive parsing (values,
+    /// selection sets, and type annotations).
+    recursion_depth: usize,
+
+    /// End byte offset of the most recently consumed token.
+    last_end_position: Option<u32>,
+}
+
+impl<'src: 'arena, 'arena> ArenaGraphQLParser<'src, 'arena> {
+    /// Maximum nesting depth for recursive parsing. Matches
+    /// [`GraphQLParser`]'s limit so that both parsers accept the
+    /// same documents.
+    const MAX_RECURSION_DEPTH: usize = 32;
+
+    /// Creates a new arena parser from a string-like source.
+    pub fn new<S: AsRef<str> + ?Sized>(
+        source: &'src S,
+        arena: &'arena Bump,
+    ) -> Self {
+        let source = source.as_ref();
+        let token_source = StrGraphQLTokenSource::with_config(
+            source,
+            StrGraphQLTokenSourceConfig::no_trivia(),
+        );
+        Self {
+            arena,
+            source,
+            token_stream: GraphQLTokenStream::new(token_source),
+            recursion_depth: 0,
+            last_end_position: None,
+        }
+    }
+
+    // =========================================================================
+    // Document parsing (public API)
+    // =========================================================================
+
+    /// Parses a schema document (type system definitions only).
+    pub fn parse_schema_document(
+        mut self,
+    ) -> Result<(arena::Document<'arena>, SourceMap<'src>), Vec<GraphQLParseError>> {
+        let mut definitions = BumpVec::new_in(self.arena);
+        while !self.token_stream.is_at_end() {
+            match self.parse_schema_definition_item() {
+                Ok(def) => definitions.push(def),
+                Err(()) => return Err(self.reparse_for_errors(DocumentKind::Schema)),
+            }
+        }
+        Ok(self.finish_document(definitions))
+    }
+
+    /// Parses an executable document (operations and fragments only).
+    pub fn parse_executable_document(
+        mut self,
+    ) -> Result<(arena::Document<'arena>, SourceMap<'src>), Vec<GraphQLParseError>> {
+        let mut definitions = BumpVec::new_in(self.arena);
+        while !self.token_stream.is_at_end() {
+            match self.parse_executable_definition_item() {
+                Ok(def) => definitions.push(def),
+                Err(()) => {
+                    return Err(self.reparse_for_errors(DocumentKind::Executable));
+                },
+            }
+        }
+        Ok(self.finish_document(definitions))
+    }
+
+    /// Parses a mixed document (both type system and executable
+    /// definitions).
+    pub fn parse_mixed_document(
+        mut self,
+    ) -> Result<(arena::Document<'arena>, SourceMap<'src>), Vec<GraphQLParseError>> {
+        let mut definitions = BumpVec::new_in(self.arena);
+        while !self.token_stream.is_at_end() {
+            match self.parse_mixed_definition_item() {
+                Ok(def) => definitions.push(def),
+                Err(()) => return Err(self.reparse_for_errors(DocumentKind::Mixed)),
+            }
+        }
+        Ok(self.finish_document(definitions))
+    }
+
+    /// Builds the final [`arena::Document`] and hands back the
+    /// token source's [`SourceMap`].
+    fn finish_document(
+        self,
+        definitions: BumpVec<'arena, arena::Definition<'arena>>,
+    ) -> (arena::Document<'arena>, SourceMap<'src>) {
+        let span = ByteSpan::new(0, self.last_end_position.unwrap_or(0));
+        let document = arena::Document {
+            definitions: definitions.into_bump_slice(),
+            span,
+        };
+        (document, self.token_stream.into_source_map())
+    }
+
+    /// Re-parses the source with [`GraphQLParser`] to collect the
+    /// full, recovered set of parse errors.
+    ///
+    /// Should the two parsers ever disagree and the re-parse succeed,
+    /// a single error pointing at the token the arena parser stopped
+    /// on is returned instead, so that callers never see an `Err`
+    /// without diagnostics.
+    fn reparse_for_errors(
+        &mut self,
+        document_kind: DocumentKind,
+    ) -> Vec<GraphQLParseError> {
+        let parser = GraphQLParser::with_config(
+            self.source,
+            GraphQLParserConfig::lean(),
+        );
+        let result = match document_kind {
+            DocumentKind::Executable => parser.parse_executable_document(),
+            DocumentKind::Mixed => parser.parse_mixed_document(),
+            DocumentKind::Schema => parser.parse_schema_document(),
+        };
+        let errors = result.errors().to_vec();
+        if errors.is_empty() {
+            return vec![self.divergence_error()];
+        }
+        errors
+    }
+
+    /// Builds the error reported when the arena parser rejects a
+    /// document that [`GraphQLParser`] accepts.
+    pub(crate) fn divergence_error(&mut self) -> GraphQLParseError {
+        let span = match self.token_stream.peek() {
+            Some(token) => token.span,
+            None => {
+                let end = self.last_end_position.unwrap_or(0);
+                ByteSpan::new(end, end)
+            },
+        };
+        let source_span = self
+            .token_stream
+            .source_map()
+            .resolve_span(span)
+            .unwrap_or_else(SourceSpan::zero);
+        GraphQLParseError::new(
+            "the arena parser rejected this document but the standard \
+             parser accepts it; parse it with `GraphQLParser` instead",
+            GraphQLParseErrorKind::InvalidSyntax,
+            source_span,
+        )
+    }
+
+    // =========================================================================
+    // Token helpers
+    // =========================================================================
+
+    /// Consumes the next token from the stream and tracks its end
+    /// position.
+    fn consume_token(&mut self) -> Option<GraphQLToken<'src>> {
+        let token = self.token_stream.consume();
+        if let Some(ref t) = token {
+            self.last_end_position = Some(t.span.end);
+        }
+        token
+    }
+
+    /// Expects a punctuator of the given kind and returns its span.
+    fn expect(&mut self, expected_kind: &GraphQLTokenKind) -> Result<ByteSpan, ()> {
+        if self.peek_is(expected_kind) {
+            Ok(self.consume_token().unwrap().span)
+        } else {
+            Err(())
+        }
+    }
+
+    /// Expects a structural keyword and returns its span.
+    fn expect_keyword(&mut self, keyword: &str) -> Result<ByteSpan, ()> {
+        if self.peek_is_keyword(keyword) {
+            Ok(self.consume_token().unwrap().span)
+        } else {
+            Err(())
+        }
+    }
+
+    /// Checks if the current token is a specific structural keyword.
+    fn peek_is_keyword(&mut self, keyword: &str) -> bool {
+        matches!(
+            self.token_stream.peek(),
+            Some(GraphQLToken { kind: GraphQLTokenKind::Name(name), .. })
+                if name.as_ref() == keyword
+        )
+    }
+
+    /// Checks if the current token is the given punctuator.
+    ///
+    /// Only used with payload-free token kinds.
+    fn peek_is(&mut self, kind: &GraphQLTokenKind) -> bool {
+        matches!(self.token_stream.peek(), Some(token) if &token.kind == kind)
+    }
+
+    /// Moves a `Cow` out of a token into the arena: borrowed
+    /// strings are reused as-is, owned strings are copied.
+    fn arena_str(&self, value: Cow<'src, str>) -> &'arena str {
+        match value {
+            Cow::Borrowed(s) => s,
+            Cow::Owned(s) => self.arena.alloc_str(&s),
+        }
+    }
+
+    /// Expects a name token (including `true`, `false`, `null`).
+    fn expect_name(&mut self) -> Result<arena::Name<'arena>, ()> {
+        let is_name = matches!(
+            self.token_stream.peek().map(|t| &t.kind),
+            Some(
+                GraphQLTokenKind::Name(_)
+                    | GraphQLTokenKind::True
+                    | GraphQLTokenKind::False
+                    | GraphQLTokenKind::Null
+            )
+        );
+        if !is_name {
+            return Err(());
+        }
+        let token = self.consume_token().unwrap();
+        let value = match token.kind {
+            GraphQLTokenKind::Name(s) => self.arena_str(s),
+            GraphQLTokenKind::True => "true",
+            GraphQLTokenKind::False => "false",
+            GraphQLTokenKind::Null => "null",
+            _ => unreachable!(),
+        };
+        Ok(arena::Name { span: token.span, value })
+    }
+
+    /// Builds a span from `start` to the end of the last consumed
+    /// token.
+    fn make_span(&self, start: ByteSpan) -> ByteSpan {
+        ByteSpan::new(start.start, self.last_end_position.unwrap_or(start.start))
+    }
+
+    /// Increments the recursion depth, failing if the limit is
+    /// exceeded.
+    fn enter_recursion(&mut self) -> Result<(), ()> {
+        if self.recursion_depth >= Self::MAX_RECURSION_DEPTH {
+            return Err(());
+        }
+        self.recursion_depth += 1;
+        Ok(())
+    }
+
+    /// Decrements the recursion depth counter.
+    fn exit_recursion(&mut self) {
+        self.recursion_depth -= 1;
+    }
+
+    // =========================================================================
+    // Value parsing
+    // =========================================================================
+
+    /// Parses a value; variables are only accepted when
+    /// `allow_variables` is `true`.
+    fn parse_value(
+        &mut self,
+        allow_variables: bool,
+    ) -> Result<arena::Value<'arena>, ()> {
+        self.enter_recursion()?;
+        let result = self.parse_value_impl(allow_variables);
+        self.exit_recursion();
+        result
+    }
+
+    fn parse_value_impl(
+        &mut self,
+        allow_variables: bool,
+    ) -> Result<arena::Value<'arena>, ()> {
+        let token = self.token_stream.peek().ok_or(())?;
+        match &token.kind {
+            GraphQLTokenKind::Dollar => {
+                if !allow_variables {
+                    return Err(());
+                }
+                let dollar_span = self.consume_token().unwrap().span;
+                let name = self.expect_name()?;
+                Ok(arena::Value::Variable(arena::VariableReference {
+                    name,
+                    span: self.make_span(dollar_span),
+                }))
+            },
+            GraphQLTokenKind::IntValue(_) => {
+                let value = match token.kind.parse_int_value() {
+                    Some(Ok(val)) => i32::try_from(val).map_err(|_| ())?,
+                    _ => return Err(()),
+                };
+                let span = self.consume_token().unwrap().span;
+                Ok(arena::Value::Int(arena::IntValue { span, value }))
+            },
+            GraphQLTokenKind::FloatValue(_) => {
+                let value = match token.kind.parse_float_value() {
+                    Some(Ok(val)) if val.is_finite() => val,
+                    _ => return Err(()),
+                };
+                let span = self.consume_token().unwrap().span;
+                Ok(arena::Value::Float(arena::FloatValue { span, value }))
+            },
+            GraphQLTokenKind::StringValue(_) => {
+                let string = self.parse_string_value()?;
+                Ok(arena::Value::String(string))
+            },
+            GraphQLTokenKind::True | GraphQLTokenKind::False => {
+                let value = matches!(token.kind, GraphQLTokenKind::True);
+                let span = self.consume_token().unwrap().span;
+                Ok(arena::Value::Boolean(arena::BooleanValue { span, value }))
+            },
+            GraphQLTokenKind::Null => {
+                let span = self.consume_token().unwrap().span;
+                Ok(arena::Value::Null(arena::NullValue { span }))
+            },
+            GraphQLTokenKind::SquareBracketOpen => {
+                self.parse_list_value(allow_variables)
+            },
+            GraphQLTokenKind::CurlyBraceOpen => {
+                self.parse_object_value(allow_variables)
+            },
+            GraphQLTokenKind::Name(_) => {
+                let token = self.consume_token().unwrap();
+                let value = match token.kind {
+                    GraphQLTokenKind::Name(s) => self.arena_str(s),
+                    _ => unreachable!(),
+                };
+                Ok(arena::Value::Enum(arena::EnumValue { span: token.span, value }))
+            },
+            _ => Err(()),
+        }
+    }
+
+    /// Parses a string token (the current token must be a
+    /// `StringValue`) into an arena-allocated [`arena::StringValue`].
+    fn parse_string_value(&mut self) -> Result<arena::StringValue<'arena>, ()> {
+        let token = self.consume_token().unwrap();
+        let is_block = matches!(
+            &token.kind,
+            GraphQLTokenKind::StringValue(raw) if raw.starts_with("\"\"\""),
+        );
+        match token.kind.parse_string_value() {
+            Some(Ok(parsed)) => Ok(arena::StringValue {
+                is_block,
+                span: token.span,
+                value: self.arena.alloc_str(&parsed),
+            }),
+            _ => Err(()),
+        }
+    }
+
+    /// Parses a list value: `[value, value, ...]`
+    fn parse_list_value(
+        &mut self,
+        allow_variables: bool,
+    ) -> Result<arena::Value<'arena>, ()> {
+        let open_span = self.expect(&GraphQLTokenKind::SquareBracketOpen)?;
+        let mut values = BumpVec::new_in(self.arena);
+        while !self.peek_is(&GraphQLTokenKind::SquareBracketClose) {
+            values.push(self.parse_value(allow_variables)?);
+        }
+        self.expect(&GraphQLTokenKind::SquareBracketClose)?;
+        Ok(arena::Value::List(arena::ListValue {
+            span: self.make_span(open_span),
+            values: values.into_bump_slice(),
+        }))
+    }
+
+    /// Parses an object value: `{ field: value, ... }`
+    fn parse_object_value(
+        &mut self,
+        allow_variables: bool,
+    ) -> Result<arena::Value<'arena>, ()> {
+        let open_span = self.expect(&GraphQLTokenKind::CurlyBraceOpen)?;
+        let mut fields = BumpVec::new_in(self.arena);
+        while !self.peek_is(&GraphQLTokenKind::CurlyBraceClose) {
+            let name = self.expect_name()?;
+            self.expect(&GraphQLTokenKind::Colon)?;
+            let value = self.parse_value(allow_variables)?;
+            fields.push(arena::ObjectField {
+                name,
+                span: self.make_span(name.span),
+                value,
+            });
+        }
+        self.expect(&GraphQLTokenKind::CurlyBraceClose)?;
+        Ok(arena::Value::Object(arena::ObjectValue {
+            fields: fields.into_bump_slice(),
+            span: self.make_span(open_span),
+        }))
+    }
+
+    // =========================================================================
+    // Type annotation parsing
+    // =========================================================================
+
+    /// Parses a type annotation: `TypeName`, `[Type]`, `Type!`, etc.
+    fn parse_type_annotation(&mut self) -> Result<arena::TypeAnnotation<'arena>, ()> {
+        self.enter_recursion()?;
+        let result = self.parse_type_annotation_impl();
+        self.exit_recursion();
+        result
+    }
+
+    fn parse_type_annotation_impl(
+        &mut self,
+    ) -> Result<arena::TypeAnnotation<'arena>, ()> {
+        if self.peek_is(&GraphQLTokenKind::SquareBracketOpen) {
+            let open_span = self.consume_token().unwrap().span;
+            let element_type = self.parse_type_annotation()?;
+            self.expect(&GraphQLTokenKind::SquareBracketClose)?;
+            let nullability = self.parse_nullability();
+            Ok(arena::TypeAnnotation::List(arena::ListTypeAnnotation {
+                element_type: self.arena.alloc(element_type),
+                nullability,
+                span: self.make_span(open_span),
+            }))
+        } else {
+            let name = self.expect_name()?;
+            let nullability = self.parse_nullability();
+            Ok(arena::TypeAnnotation::Named(arena::NamedTypeAnnotation {
+                name,
+                nullability,
+                span: self.make_span(name.span),
+            }))
+        }
+    }
+
+    /// Consumes an optional trailing `!`.
+    fn parse_nullability(&mut self) -> arena::Nullability {
+        if self.peek_is(&GraphQLTokenKind::Bang) {
+            self.consume_token();
+            arena::Nullability::NonNull
+        } else {
+            arena::Nullability::Nullable
+        }
+    }
+
+    // =========================================================================
+    // Directive annotation and argument parsing
+    // =========================================================================
+
+    /// Parses zero or more directive annotations: `@directive(args)...`
+    fn parse_directive_annotations(
+        &mut self,
+        allow_variables: bool,
+    ) -> Result<&'arena [arena::DirectiveAnnotation<'arena>], ()> {
+        if !self.peek_is(&GraphQLTokenKind::At) {
+            return Ok(&[]);
+        }
+        let mut directives = BumpVec::new_in(self.arena);
+        while self.peek_is(&GraphQLTokenKind::At) {
+            let at_span = self.consume_token().unwrap().span;
+            let name = self.expect_name()?;
+            let arguments = self.parse_optional_arguments(allow_variables)?;
+            directives.push(arena::DirectiveAnnotation {
+                arguments,
+                name,
+                span: self.make_span(at_span),
+            });
+        }
+        Ok(directives.into_bump_slice())
+    }
+
+    /// Parses `(name: value, ...)` if present.
+    fn parse_optional_arguments(
+        &mut self,
+        allow_variables: bool,
+    ) -> Result<&'arena [arena::Argument<'arena>], ()> {
+        if !self.peek_is(&GraphQLTokenKind::ParenOpen) {
+            return Ok(&[]);
+        }
+        self.consume_token();
+        // An empty argument list is a parse error.
+        if self.peek_is(&GraphQLTokenKind::ParenClose) {
+            return Err(());
+        }
+        let mut arguments = BumpVec::new_in(self.arena);
+        while !self.peek_is(&GraphQLTokenKind::ParenClose) {
+            let name = self.expect_name()?;
+            self.expect(&GraphQLTokenKind::Colon)?;
+            let value = self.parse_value(allow_variables)?;
+            arguments.push(arena::Argument {
+                name,
+                span: self.make_span(name.span),
+                value,
+            });
+        }
+        self.expect(&GraphQLTokenKind::ParenClose)?;
+        Ok(arguments.into_bump_slice())
+    }
+
+    // =========================================================================
+    // Selection set parsing
+    // =========================================================================
+
+    /// Parses a selection set: `{ selection... }`
+    fn parse_selection_set(&mut self) -> Result<arena::SelectionSet<'arena>, ()> {
+        self.enter_recursion()?;
+        let result = self.parse_selection_set_impl();
+        self.exit_recursion();
+        result
+    }
+
+    fn parse_selection_set_impl(&mut self) -> Result<arena::SelectionSet<'arena>, ()> {
+        let open_span = self.expect(&GraphQLTokenKind::CurlyBraceOpen)?;
+        // An empty selection set is a parse error.
+        if self.peek_is(&GraphQLTokenKind::CurlyBraceClose) {
+            return Err(());
+        }
+        let mut selections = BumpVec::new_in(self.arena);
+        while !self.peek_is(&GraphQLTokenKind::CurlyBraceClose) {
+            selections.push(self.parse_selection()?);
+        }
+        self.expect(&GraphQLTokenKind::CurlyBraceClose)?;
+        Ok(arena::SelectionSet {
+            selections: selections.into_bump_slice(),
+            span: self.make_span(open_span),
+        })
+    }
+
+    /// Parses a single selection (field, fragment spread, or
+    /// inline fragment).
+    fn parse_selection(&mut self) -> Result<arena::Selection<'arena>, ()> {
+        if !self.peek_is(&GraphQLTokenKind::Ellipsis) {
+            return self.parse_field().map(arena::Selection::Field);
+        }
+        let ellipsis_span = self.consume_token().unwrap().span;
+        if self.peek_is_keyword("on")
+            || self.peek_is(&GraphQLTokenKind::At)
+            || self.peek_is(&GraphQLTokenKind::CurlyBraceOpen)
+        {
+            let type_condition = if self.peek_is_keyword("on") {
+                Some(self.parse_type_condition()?)
+            } else {
+                None
+            };
+            let directives = self.parse_directive_annotations(true)?;
+            let selection_set = self.parse_selection_set()?;
+            Ok(arena::Selection::InlineFragment(arena::InlineFragment {
+                directives,
+                selection_set,
+                span: self.make_span(ellipsis_span),
+                type_condition,
+            }))
+        } else {
+            let name = self.expect_name()?;
+            let directives = self.parse_directive_annotations(true)?;
+            Ok(arena::Selection::FragmentSpread(arena::FragmentSpread {
+                directives,
+                name,
+                span: self.make_span(ellipsis_span),
+            }))
+        }
+    }
+
+    /// Parses a field: `alias: name(args) @directives { selections }`
+    fn parse_field(&mut self) -> Result<arena::FieldSelection<'arena>, ()> {
+        let first_name = self.expect_name()?;
+        let (alias, name) = if self.peek_is(&GraphQLTokenKind::Colon) {
+            self.consume_token();
+            (Some(first_name), self.expect_name()?)
+        } else {
+            (None, first_name)
+        };
+        let arguments = self.parse_optional_arguments(true)?;
+        let directives = self.parse_directive_annotations(true)?;
+        let selection_set = if self.peek_is(&GraphQLTokenKind::CurlyBraceOpen) {
+            Some(self.parse_selection_set()?)
+        } else {
+            None
+        };
+        Ok(arena::FieldSelection {
+            alias,
+            arguments,
+            directives,
+            name,
+            selection_set,
+            span: self.make_span(first_name.span),
+        })
+    }
+
+    /// Parses a type condition: `on TypeName`
+    fn parse_type_condition(&mut self) -> Result<arena::TypeCondition<'arena>, ()> {
+        let on_span = self.expect_keyword("on")?;
+        let named_type = self.expect_name()?;
+        Ok(arena::TypeCondition {
+            named_type,
+            span: ByteSpan::new(on_span.start, named_type.span.end),
+        })
+    }
+
+    // =========================================================================
+    // Executable definition parsing
+    // =========================================================================
+
+    /// Parses an operation definition.
+    fn parse_operation_definition(
+        &mut self,
+    ) -> Result<arena::OperationDefinition<'arena>, ()> {
+        // Shorthand query: just a selection set with no keyword
+        if self.peek_is(&GraphQLTokenKind::CurlyBraceOpen) {
+            let selection_set = self.parse_selection_set()?;
+            return Ok(arena::OperationDefinition {
+                description: None,
+                directives: &[],
+                name: None,
+                operation_kind: ast::OperationKind::Query,
+                selection_set,
+                shorthand: true,
+                span: selection_set.span,
+                variable_definitions: &[],
+            });
+        }
+
+        let operation_kind = if self.peek_is_keyword("query") {
+            ast::OperationKind::Query
+        } else if self.peek_is_keyword("mutation") {
+            ast::OperationKind::Mutation
+        } else if self.peek_is_keyword("subscription") {
+            ast::OperationKind::Subscription
+        } else {
+            return Err(());
+        };
+        let keyword_span = self.consume_token().unwrap().span;
+
+        let name = match self.token_stream.peek().map(|t| &t.kind) {
+            Some(
+                GraphQLTokenKind::Name(_)
+                | GraphQLTokenKind::True
+                | GraphQLTokenKind::False
+                | GraphQLTokenKind::Null,
+            ) => Some(self.expect_name()?),
+            _ => None,
+        };
+
+        let variable_definitions = if self.peek_is(&GraphQLTokenKind::ParenOpen) {
+            self.parse_variable_definitions()?
+        } else {
+            &[]
+        };
+        let directives = self.parse_directive_annotations(true)?;
+        let selection_set = self.parse_selection_set()?;
+        Ok(arena::OperationDefinition {
+            description: None,
+            directives,
+            name,
+            operation_kind,
+            selection_set,
+            shorthand: false,
+            span: self.make_span(keyword_span),
+            variable_definitions,
+        })
+    }
+
+    /// Parses variable definitions: `($var: Type = default, ...)`
+    fn parse_variable_definitions(
+        &mut self,
+    ) -> Result<&'arena [arena::VariableDefinition<'arena>], ()> {
+        self.expect(&GraphQLTokenKind::ParenOpen)?;
+        // Empty variable definitions are a parse error.
+        if self.peek_is(&GraphQLTokenKind::ParenClose) {
+            return Err(());
+        }
+        let mut definitions = BumpVec::new_in(self.arena);
+        while !self.peek_is(&GraphQLTokenKind::ParenClose) {
+            let dollar_span = self.expect(&GraphQLTokenKind::Dollar)?;
+            let variable = self.expect_name()?;
+            self.expect(&GraphQLTokenKind::Colon)?;
+            let var_type = self.parse_type_annotation()?;
+            let default_value = if self.peek_is(&GraphQLTokenKind::Equals) {
+                self.consume_token();
+                Some(self.parse_value(false)?)
+            } else {
+                None
+            };
+            let directives = self.parse_directive_annotations(false)?;
+            definitions.push(arena::VariableDefinition {
+                default_value,
+                description: None,
+                directives,
+                span: self.make_span(dollar_span),
+                var_type,
+                variable,
+            });
+        }
+        self.expect(&GraphQLTokenKind::ParenClose)?;
+        Ok(definitions.into_bump_slice())
+    }
+
+    /// Parses a fragment definition: `fragment Name on Type @directives { ... }`
+    fn parse_fragment_definition(
+        &mut self,
+    ) -> Result<arena::FragmentDefinition<'arena>, ()> {
+        let keyword_span = self.expect_keyword("fragment")?;
+        let name = self.expect_name()?;
+        if name.value == "on" {
+            return Err(());
+        }
+        let type_condition = self.parse_type_condition()?;
+        let directives = self.parse_directive_annotations(true)?;
+        let selection_set = self.parse_selection_set()?;
+        Ok(arena::FragmentDefinition {
+            description: None,
+            directives,
+            name,
+            selection_set,
+            span: self.make_span(keyword_span),
+            type_condition,
+        })
+    }
+
+    // =========================================================================
+    // Type system definition parsing
+    // =========================================================================
+
+    /// Parses an optional description string.
+    fn parse_description(&mut self) -> Result<Option<arena::StringValue<'arena>>, ()> {
+        if matches!(
+            self.token_stream.peek().map(|t| &t.kind),
+            Some(GraphQLTokenKind::StringValue(_)),
+        ) {
+            Ok(Some(self.parse_string_value()?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Parses the `{ query: Query ... }` body of a schema
+    /// definition or extension.
+    fn parse_root_operations(
+        &mut self,
+    ) -> Result<&'arena [arena::RootOperationTypeDefinition<'arena>], ()> {
+        self.expect(&GraphQLTokenKind::CurlyBraceOpen)?;
+        let mut root_operations = BumpVec::new_in(self.arena);
+        while !self.peek_is(&GraphQLTokenKind::CurlyBraceClose) {
+            let op_name = self.expect_name()?;
+            let operation_kind = match op_name.value {
+                "query" => ast::OperationKind::Query,
+                "mutation" => ast::OperationKind::Mutation,
+                "subscription" => ast::OperationKind::Subscription,
+                _ => return Err(()),
+            };
+            self.expect(&GraphQLTokenKind::Colon)?;
+            let named_type = self.expect_name()?;
+            root_operations.push(arena::RootOperationTypeDefinition {
+                named_type,
+                operation_kind,
+                span: ByteSpan::new(op_name.span.start, named_type.span.end),
+            });
+        }
+        self.expect(&GraphQLTokenKind::CurlyBraceClose)?;
+        Ok(root_operations.into_bump_slice())
+    }
+
+    /// Parses `implements A & B & C` if present.
+    fn parse_implements_interfaces(
+        &mut self,
+    ) -> Result<&'arena [arena::Name<'arena>], ()> {
+        if !self.peek_is_keyword("implements") {
+            return Ok(&[]);
+        }
+        self.consume_token();
+        if self.peek_is(&GraphQLTokenKind::Ampersand) {
+            self.consume_token();
+        }
+        let mut interfaces = BumpVec::new_in(self.arena);
+        interfaces.push(self.expect_name()?);
+        while self.peek_is(&GraphQLTokenKind::Ampersand) {
+            self.consume_token();
+            interfaces.push(self.expect_name()?);
+        }
+        Ok(interfaces.into_bump_slice())
+    }
+
+    /// Parses `= A | B | C` if present.
+    fn parse_union_members(&mut self) -> Result<&'arena [arena::Name<'arena>], ()> {
+        if !self.peek_is(&GraphQLTokenKind::Equals) {
+            return Ok(&[]);
+        }
+        self.consume_token();
+        if self.peek_is(&GraphQLTokenKind::Pipe) {
+            self.consume_token();
+        }
+        let mut members = BumpVec::new_in(self.arena);
+        members.push(self.expect_name()?);
+        while self.peek_is(&GraphQLTokenKind::Pipe) {
+            self.consume_token();
+            members.push(self.expect_name()?);
+        }
+        Ok(members.into_bump_slice())
+    }
+
+    /// Parses `{ field: Type, ... }` if present.
+    fn parse_fields_definition(
+        &mut self,
+    ) -> Result<&'arena [arena::FieldDefinition<'arena>], ()> {
+        if !self.peek_is(&GraphQLTokenKind::CurlyBraceOpen) {
+            return Ok(&[]);
+        }
+        self.consume_token();
+        let mut fields = BumpVec::new_in(self.arena);
+        while !self.peek_is(&GraphQLTokenKind::CurlyBraceClose) {
+            let description = self.parse_description()?;
+            let name = self.expect_name()?;
+            let parameters = if self.peek_is(&GraphQLTokenKind::ParenOpen) {
+                self.parse_input_values(
+                    &GraphQLTokenKind::ParenOpen,
+                    &GraphQLTokenKind::ParenClose,
+                )?
+            } else {
+                &[]
+            };
+            self.expect(&GraphQLTokenKind::Colon)?;
+            let field_type = self.parse_type_annotation()?;
+            let directives = self.parse_directive_annotations(false)?;
+            fields.push(arena::FieldDefinition {
+                parameters,
+                description,
+                directives,
+                field_type,
+                name,
+                span: self.make_span(name.span),
+            });
+        }
+        self.expect(&GraphQLTokenKind::CurlyBraceClose)?;
+        Ok(fields.into_bump_slice())
+    }
+
+    /// Parses a delimited list of input value definitions, used
+    /// for both argument definitions (`(...)`) and input object
+    /// fields (`{...}`).
+    fn parse_input_values(
+        &mut self,
+        open: &GraphQLTokenKind,
+        close: &GraphQLTokenKind,
+    ) -> Result<&'arena [arena::InputValueDefinition<'arena>], ()> {
+        self.expect(open)?;
+        let mut values = BumpVec::new_in(self.arena);
+        while !self.peek_is(close) {
+            let description = self.parse_description()?;
+            let name = self.expect_name()?;
+            self.expect(&GraphQLTokenKind::Colon)?;
+            let value_type = self.parse_type_annotation()?;
+            let default_value = if self.peek_is(&GraphQLTokenKind::Equals) {
+                self.consume_token();
+                Some(self.parse_value(false)?)
+            } else {
+                None
+            };
+            let directives = self.parse_directive_annotations(false)?;
+            values.push(arena::InputValueDefinition {
+                default_value,
+                description,
+                directives,
+                name,
+                span: self.make_span(name.span),
+                value_type,
+            });
+        }
+        self.expect(close)?;
+        Ok(values.into_bump_slice())
+    }
+
+    /// Parses `{ input: Type, ... }` if present.
+    fn parse_input_fields_definition(
+        &mut self,
+    ) -> Result<&'arena [arena::InputValueDefinition<'arena>], ()> {
+        if !self.peek_is(&GraphQLTokenKind::CurlyBraceOpen) {
+            return Ok(&[]);
+        }
+        self.parse_input_values(
+            &GraphQLTokenKind::CurlyBraceOpen,
+            &GraphQLTokenKind::CurlyBraceClose,
+        )
+    }
+
+    /// Parses `{ VALUE, ... }` if present.
+    fn parse_enum_values_definition(
+        &mut self,
+    ) -> Result<&'arena [arena::EnumValueDefinition<'arena>], ()> {
+        if !self.peek_is(&GraphQLTokenKind::CurlyBraceOpen) {
+            return Ok(&[]);
+        }
+        self.consume_token();
+        let mut values = BumpVec::new_in(self.arena);
+        while !self.peek_is(&GraphQLTokenKind::CurlyBraceClose) {
+            let description = self.parse_description()?;
+            let name = self.expect_name()?;
+            if matches!(name.value, "true" | "false" | "null") {
+                return Err(());
+            }
+            let directives = self.parse_directive_annotations(false)?;
+            values.push(arena::EnumValueDefinition {
+                description,
+                directives,
+                name,
+                span: self.make_span(name.span),
+            });
+        }
+        self.expect(&GraphQLTokenKind::CurlyBraceClose)?;
+        Ok(values.into_bump_slice())
+    }
+
+    /// Parses a directive definition.
+    fn parse_directive_definition(
+        &mut self,
+        description: Option<arena::StringValue<'arena>>,
+    ) -> Result<arena::DirectiveDefinition<'arena>, ()> {
+        let keyword_span = self.expect_keyword("directive")?;
+        self.expect(&GraphQLTokenKind::At)?;
+        let name = self.expect_name()?;
+        let arguments = if self.peek_is(&GraphQLTokenKind::ParenOpen) {
+            self.parse_input_values(
+                &GraphQLTokenKind::ParenOpen,
+                &GraphQLTokenKind::ParenClose,
+            )?
+        } else {
+            &[]
+        };
+        let repeatable = self.peek_is_keyword("repeatable");
+        if repeatable {
+            self.consume_token();
+        }
+        self.expect_keyword("on")?;
+        if self.peek_is(&GraphQLTokenKind::Pipe) {
+            self.consume_token();
+        }
+        let mut locations = BumpVec::new_in(self.arena);
+        locations.push(self.parse_directive_location()?);
+        while self.peek_is(&GraphQLTokenKind::Pipe) {
+            self.consume_token();
+            locations.push(self.parse_directive_location()?);
+        }
+        Ok(arena::DirectiveDefinition {
+            arguments,
+            description,
+            locations: locations.into_bump_slice(),
+            name,
+            repeatable,
+            span: self.make_span(keyword_span),
+        })
+    }
+
+    /// Parses a single directive location.
+    fn parse_directive_location(&mut self) -> Result<arena::DirectiveLocation, ()> {
+        let name = self.expect_name()?;
+        let kind = match name.value {
+            "QUERY" => ast::DirectiveLocationKind::Query,
+            "MUTATION" => ast::DirectiveLocationKind::Mutation,
+            "SUBSCRIPTION" => ast::DirectiveLocationKind::Subscription,
+            "FIELD" => ast::DirectiveLocationKind::Field,
+            "FRAGMENT_DEFINITION" => ast::DirectiveLocationKind::FragmentDefinition,
+            "FRAGMENT_SPREAD" => ast::DirectiveLocationKind::FragmentSpread,
+            "INLINE_FRAGMENT" => ast::DirectiveLocationKind::InlineFragment,
+            "VARIABLE_DEFINITION" => ast::DirectiveLocationKind::VariableDefinition,
+            "SCHEMA" => ast::DirectiveLocationKind::Schema,
+            "SCALAR" => ast::DirectiveLocationKind::Scalar,
+            "OBJECT" => ast::DirectiveLocationKind::Object,
+            "FIELD_DEFINITION" => ast::DirectiveLocationKind::FieldDefinition,
+            "ARGUMENT_DEFINITION" => ast::DirectiveLocationKind::ArgumentDefinition,
+            "INTERFACE" => ast::DirectiveLocationKind::Interface,
+            "UNION" => ast::DirectiveLocationKind::Union,
+            "ENUM" => ast::DirectiveLocationKind::Enum,
+            "ENUM_VALUE" => ast::DirectiveLocationKind::EnumValue,
+            "INPUT_OBJECT" => ast::DirectiveLocationKind::InputObject,
+            "INPUT_FIELD_DEFINITION" => ast::DirectiveLocationKind::InputFieldDefinition,
+            _ => return Err(()),
+        };
+        Ok(arena::DirectiveLocation { kind, span: name.span })
+    }
+
+    /// Parses a type definition (the current token must be one of
+    /// the type keywords).
+    fn parse_type_definition(
+        &mut self,
+        description: Option<arena::StringValue<'arena>>,
+    ) -> Result<arena::TypeDefinition<'arena>, ()> {
+        let keyword = match self.token_stream.peek().map(|t| &t.kind) {
+            Some(GraphQLTokenKind::Name(name)) => name.clone(),
+            _ => return Err(()),
+        };
+        let keyword_span = self.consume_token().unwrap().span;
+        let name = self.expect_name()?;
+        let type_def = match keyword.as_ref() {
+            "scalar" => {
+                let directives = self.parse_directive_annotations(false)?;
+                arena::TypeDefinition::Scalar(arena::ScalarTypeDefinition {
+                    description,
+                    directives,
+                    name,
+                    span: self.make_span(keyword_span),
+                })
+            },
+            "type" => {
+                let implements = self.parse_implements_interfaces()?;
+                let directives = self.parse_directive_annotations(false)?;
+                let fields = self.parse_fields_definition()?;
+                arena::TypeDefinition::Object(arena::ObjectTypeDefinition {
+                    description,
+                    directives,
+                    fields,
+                    implements,
+                    name,
+                    span: self.make_span(keyword_span),
+                })
+            },
+            "interface" => {
+                let implements = self.parse_implements_interfaces()?;
+                let directives = self.parse_directive_annotations(false)?;
+                let fields = self.parse_fields_definition()?;
+                arena::TypeDefinition::Interface(arena::InterfaceTypeDefinition {
+                    description,
+                    directives,
+                    fields,
+                    implements,
+                    name,
+                    span: self.make_span(keyword_span),
+                })
+            },
+            "union" => {
+                let directives = self.parse_directive_annotations(false)?;
+                let members = self.parse_union_members()?;
+                arena::TypeDefinition::Union(arena::UnionTypeDefinition {
+                    description,
+                    directives,
+                    members,
+                    name,
+                    span: self.make_span(keyword_span),
+                })
+            },
+            "enum" => {
+                let directives = self.parse_directive_annotations(false)?;
+                let values = self.parse_enum_values_definition()?;
+                arena::TypeDefinition::Enum(arena::EnumTypeDefinition {
+                    description,
+                    directives,
+                    name,
+                    span: self.make_span(keyword_span),
+                    values,
+                })
+            },
+            "input" => {
+                let directives = self.parse_directive_annotations(false)?;
+                let fields = self.parse_input_fields_definition()?;
+                arena::TypeDefinition::InputObject(arena::InputObjectTypeDefinition {
+                    description,
+                    directives,
+                    fields,
+                    name,
+                    span: self.make_span(keyword_span),
+                })
+            },
+            _ => return Err(()),
+        };
+        Ok(type_def)
+    }
+
+    /// Parses a type or schema extension: `extend <keyword> ...`
+    fn parse_extension(&mut self) -> Result<arena::Definition<'arena>, ()> {
+        let extend_span = self.expect_keyword("extend")?;
+        if self.peek_is_keyword("schema") {
+            self.consume_token();
+            let directives = self.parse_directive_annotations(false)?;
+            let root_operations = if self.peek_is(&GraphQLTokenKind::CurlyBraceOpen) {
+                self.parse_root_operations()?
+            } else {
+                &[]
+            };
+            return Ok(arena::Definition::SchemaExtension(arena::SchemaExtension {
+                directives,
+                root_operations,
+                span: self.make_span(extend_span),
+            }));
+        }
+
+        let keyword = match self.token_stream.peek().map(|t| &t.kind) {
+            Some(GraphQLTokenKind::Name(name)) => name.clone(),
+            _ => return Err(()),
+        };
+        if !matches!(
+            keyword.as_ref(),
+            "scalar" | "type" | "interface" | "union" | "enum" | "input",
+        ) {
+            return Err(());
+        }
+        self.consume_token();
+        let name = self.expect_name()?;
+        let type_ext = match keyword.as_ref() {
+            "scalar" => {
+                let directives = self.parse_directive_annotations(false)?;
+                arena::TypeExtension::Scalar(arena::ScalarTypeExtension {
+                    directives,
+                    name,
+                    span: self.make_span(extend_span),
+                })
+            },
+            "type" => {
+                let implements = self.parse_implements_interfaces()?;
+                let directives = self.parse_directive_annotations(false)?;
+                let fields = self.parse_fields_definition()?;
+                arena::TypeExtension::Object(arena::ObjectTypeExtension {
+                    directives,
+                    fields,
+                    implements,
+                    name,
+                    span: self.make_span(extend_span),
+                })
+            },
+            "interface" => {
+                let implements = self.parse_implements_interfaces()?;
+                let directives = self.parse_directive_annotations(false)?;
+                let fields = self.parse_fields_definition()?;
+                arena::TypeExtension::Interface(arena::InterfaceTypeExtension {
+                    directives,
+                    fields,
+                    implements,
+                    name,
+                    span: self.make_span(extend_span),
+                })
+            },
+            "union" => {
+                let directives = self.parse_directive_annotations(false)?;
+                let members = self.parse_union_members()?;
+                arena::TypeExtension::Union(arena::UnionTypeExtension {
+                    directives,
+                    members,
+                    name,
+                    span: self.make_span(extend_span),
+                })
+            },
+            "enum" => {
+                let directives = self.parse_directive_annotations(false)?;
+                let values = self.parse_enum_values_definition()?;
+                arena::TypeExtension::Enum(arena::EnumTypeExtension {
+                    directives,
+                    name,
+                    span: self.make_span(extend_span),
+                    values,
+                })
+            },
+            _ => {
+                let directives = self.parse_directive_annotations(false)?;
+                let fields = self.parse_input_fields_definition()?;
+                arena::TypeExtension::InputObject(arena::InputObjectTypeExtension {
+                    directives,
+                    fields,
+                    name,
+                    span: self.make_span(extend_span),
+                })
+            },
+        };
+        Ok(arena::Definition::TypeExtension(type_ext))
+    }
+
+    /// Parses the type-system definitions shared by schema and
+    /// mixed documents, after an optional description.
+    fn parse_type_system_definition(
+        &mut self,
+        description: Option<arena::StringValue<'arena>>,
+    ) -> Result<arena::Definition<'arena>, ()> {
+        if self.peek_is_keyword("schema") {
+            let keyword_span = self.consume_token().unwrap().span;
+            let directives = self.parse_directive_annotations(false)?;
+            let root_operations = self.parse_root_operations()?;
+            Ok(arena::Definition::SchemaDefinition(arena::SchemaDefinition {
+                description,
+                directives,
+                root_operations,
+                span: self.make_span(keyword_span),
+            }))
+        } else if self.peek_is_keyword("directive") {
+            Ok(arena::Definition::DirectiveDefinition(
+                self.parse_directive_definition(description)?,
+            ))
+        } else if self.peek_is_keyword("extend") {
+            self.parse_extension()
+        } else {
+            Ok(arena::Definition::TypeDefinition(
+                self.parse_type_definition(description)?,
+            ))
+        }
+    }
+
+    /// Parses a single schema definition item.
+    fn parse_schema_definition_item(
+        &mut self,
+    ) -> Result<arena::Definition<'arena>, ()> {
+        let description = self.parse_description()?;
+        self.parse_type_system_definition(description)
+    }
+
+    /// Parses a single executable definition item.
+    fn parse_executable_definition_item(
+        &mut self,
+    ) -> Result<arena::Definition<'arena>, ()> {
+        if self.peek_is_keyword("fragment") {
+            Ok(arena::Definition::FragmentDefinition(
+                self.parse_fragment_definition()?,
+            ))
+        } else {
+            Ok(arena::Definition::OperationDefinition(
+                self.parse_operation_definition()?,
+            ))
+        }
+    }
+
+    /// Parses a definition for mixed documents.
+    fn parse_mixed_definition_item(
+        &mut self,
+    ) -> Result<arena::Definition<'arena>, ()> {
+        let description = self.parse_description()?;
+        if self.peek_is_keyword("query")
+            || self.peek_is_keyword("mutation")
+            || self.peek_is_keyword("subscription")
+            || self.peek_is_keyword("fragment")
+            || self.peek_is(&GraphQLTokenKind::CurlyBraceOpen)
+        {
+            // Matches `GraphQLParser`: a description preceding an
+            // executable definition is parsed but not retained.
+            self.parse_executable_definition_item()
+        } else {
+            self.parse_type_system_definition(description)
+        }
+    }
+}
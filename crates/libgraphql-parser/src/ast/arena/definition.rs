@@ -0,0 +1,119 @@
+use crate::ast::arena::DirectiveDefinition;
+use crate::ast::arena::EnumTypeDefinition;
+use crate::ast::arena::EnumTypeExtension;
+use crate::ast::arena::FragmentDefinition;
+use crate::ast::arena::InputObjectTypeDefinition;
+use crate::ast::arena::InputObjectTypeExtension;
+use crate::ast::arena::InterfaceTypeDefinition;
+use crate::ast::arena::InterfaceTypeExtension;
+use crate::ast::arena::Name;
+use crate::ast::arena::ObjectTypeDefinition;
+use crate::ast::arena::ObjectTypeExtension;
+use crate::ast::arena::OperationDefinition;
+use crate::ast::arena::ScalarTypeDefinition;
+use crate::ast::arena::ScalarTypeExtension;
+use crate::ast::arena::SchemaDefinition;
+use crate::ast::arena::SchemaExtension;
+use crate::ast::arena::UnionTypeDefinition;
+use crate::ast::arena::UnionTypeExtension;
+
+/// Arena counterpart of [`ast::Definition`](crate::ast::Definition).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Definition<'arena> {
+    DirectiveDefinition(DirectiveDefinition<'arena>),
+    FragmentDefinition(FragmentDefinition<'arena>),
+    OperationDefinition(OperationDefinition<'arena>),
+    SchemaDefinition(SchemaDefinition<'arena>),
+    SchemaExtension(SchemaExtension<'arena>),
+    TypeDefinition(TypeDefinition<'arena>),
+    TypeExtension(TypeExtension<'arena>),
+}
+
+impl<'arena> Definition<'arena> {
+    /// Returns the [`Name`] of this definition, or [`None`]
+    /// for schema definitions/extensions and anonymous
+    /// operations.
+    pub fn name(&self) -> Option<&Name<'arena>> {
+        match self {
+            Self::DirectiveDefinition(def) => Some(&def.name),
+            Self::FragmentDefinition(def) => Some(&def.name),
+            Self::OperationDefinition(def) => def.name.as_ref(),
+            Self::SchemaDefinition(_) => None,
+            Self::SchemaExtension(_) => None,
+            Self::TypeDefinition(def) => Some(def.name()),
+            Self::TypeExtension(def) => Some(def.name()),
+        }
+    }
+
+    /// Returns the name of this definition as a string slice,
+    /// or [`None`] for unnamed definitions.
+    pub fn name_value(&self) -> Option<&str> {
+        self.name().map(|n| n.value)
+    }
+}
+
+/// Arena counterpart of
+/// [`ast::TypeDefinition`](crate::ast::TypeDefinition).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TypeDefinition<'arena> {
+    Enum(EnumTypeDefinition<'arena>),
+    InputObject(InputObjectTypeDefinition<'arena>),
+    Interface(InterfaceTypeDefinition<'arena>),
+    Object(ObjectTypeDefinition<'arena>),
+    Scalar(ScalarTypeDefinition<'arena>),
+    Union(UnionTypeDefinition<'arena>),
+}
+
+impl<'arena> TypeDefinition<'arena> {
+    /// Returns the [`Name`] of this type definition.
+    pub fn name(&self) -> &Name<'arena> {
+        match self {
+            Self::Enum(def) => &def.name,
+            Self::InputObject(def) => &def.name,
+            Self::Interface(def) => &def.name,
+            Self::Object(def) => &def.name,
+            Self::Scalar(def) => &def.name,
+            Self::Union(def) => &def.name,
+        }
+    }
+
+    /// Returns the name of this type definition as a string
+    /// slice.
+    #[inline]
+    pub fn name_value(&self) -> &str {
+        self.name().value
+    }
+}
+
+/// Arena counterpart of
+/// [`ast::TypeExtension`](crate::ast::TypeExtension).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TypeExtension<'arena> {
+    Enum(EnumTypeExtension<'arena>),
+    InputObject(InputObjectTypeExtension<'arena>),
+    Interface(InterfaceTypeExtension<'arena>),
+    Object(ObjectTypeExtension<'arena>),
+    Scalar(ScalarTypeExtension<'arena>),
+    Union(UnionTypeExtension<'arena>),
+}
+
+impl<'arena> TypeExtension<'arena> {
+    /// Returns the [`Name`] of the type being extended.
+    pub fn name(&self) -> &Name<'arena> {
+        match self {
+            Self::Enum(ext) => &ext.name,
+            Self::InputObject(ext) => &ext.name,
+            Self::Interface(ext) => &ext.name,
+            Self::Object(ext) => &ext.name,
+            Self::Scalar(ext) => &ext.name,
+            Self::Union(ext) => &ext.name,
+        }
+    }
+
+    /// Returns the name of the type being extended as a string
+    /// slice.
+    #[inline]
+    pub fn name_value(&self) -> &str {
+        self.name().value
+    }
+}
@@ -0,0 +1,54 @@
+use crate::ast::arena::Definition;
+use crate::ByteSpan;
+
+/// Arena counterpart of [`ast::Document`](crate::ast::Document).
+///
+/// Produced by
+/// [`ArenaGraphQLParser`](crate::ArenaGraphQLParser). All
+/// definitions (and everything they reference) live in the
+/// arena passed to the parser.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Document<'arena> {
+    pub definitions: &'arena [Definition<'arena>],
+    pub span: ByteSpan,
+}
+
+impl<'arena> Document<'arena> {
+    /// Iterate over only the executable definitions
+    /// (operations and fragments) in this document.
+    pub fn executable_definitions(
+        &self,
+    ) -> impl Iterator<Item = &Definition<'arena>> {
+        self.definitions.iter().filter(|d| {
+            matches!(
+                d,
+                Definition::FragmentDefinition(_)
+                    | Definition::OperationDefinition(_)
+            )
+        })
+    }
+
+    /// Iterate over only the type-system definitions
+    /// and extensions in this document.
+    pub fn schema_definitions(
+        &self,
+    ) -> impl Iterator<Item = &Definition<'arena>> {
+        self.definitions.iter().filter(|d| {
+            matches!(
+                d,
+                Definition::DirectiveDefinition(_)
+                    | Definition::SchemaDefinition(_)
+                    | Definition::SchemaExtension(_)
+                    | Definition::TypeDefinition(_)
+                    | Definition::TypeExtension(_)
+            )
+        })
+    }
+
+    /// Returns this document's byte-offset span within the
+    /// source text.
+    #[inline]
+    pub fn byte_span(&self) -> ByteSpan {
+        self.span
+    }
+}
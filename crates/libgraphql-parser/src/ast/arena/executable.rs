@@ -0,0 +1,127 @@
+use crate::ast::arena::Argument;
+use crate::ast::arena::DirectiveAnnotation;
+use crate::ast::arena::Name;
+use crate::ast::arena::StringValue;
+use crate::ast::arena::TypeAnnotation;
+use crate::ast::arena::Value;
+use crate::ast::OperationKind;
+use crate::ByteSpan;
+
+/// Arena counterpart of
+/// [`ast::OperationDefinition`](crate::ast::OperationDefinition).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct OperationDefinition<'arena> {
+    pub description: Option<StringValue<'arena>>,
+    pub directives: &'arena [DirectiveAnnotation<'arena>],
+    pub name: Option<Name<'arena>>,
+    pub operation_kind: OperationKind,
+    pub selection_set: SelectionSet<'arena>,
+    pub shorthand: bool,
+    pub span: ByteSpan,
+    pub variable_definitions: &'arena [VariableDefinition<'arena>],
+}
+
+impl OperationDefinition<'_> {
+    /// Returns the name of this operation as a string slice,
+    /// or [`None`] for anonymous operations.
+    #[inline]
+    pub fn name_value(&self) -> Option<&str> {
+        self.name.as_ref().map(|n| n.value)
+    }
+}
+
+/// Arena counterpart of
+/// [`ast::VariableDefinition`](crate::ast::VariableDefinition).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct VariableDefinition<'arena> {
+    pub default_value: Option<Value<'arena>>,
+    pub description: Option<StringValue<'arena>>,
+    pub directives: &'arena [DirectiveAnnotation<'arena>],
+    pub span: ByteSpan,
+    pub var_type: TypeAnnotation<'arena>,
+    pub variable: Name<'arena>,
+}
+
+/// Arena counterpart of
+/// [`ast::FragmentDefinition`](crate::ast::FragmentDefinition).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FragmentDefinition<'arena> {
+    pub description: Option<StringValue<'arena>>,
+    pub directives: &'arena [DirectiveAnnotation<'arena>],
+    pub name: Name<'arena>,
+    pub selection_set: SelectionSet<'arena>,
+    pub span: ByteSpan,
+    pub type_condition: TypeCondition<'arena>,
+}
+
+impl FragmentDefinition<'_> {
+    /// Returns the name of this fragment as a string slice.
+    #[inline]
+    pub fn name_value(&self) -> &str {
+        self.name.value
+    }
+}
+
+/// Arena counterpart of
+/// [`ast::TypeCondition`](crate::ast::TypeCondition).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TypeCondition<'arena> {
+    pub named_type: Name<'arena>,
+    pub span: ByteSpan,
+}
+
+/// Arena counterpart of
+/// [`ast::SelectionSet`](crate::ast::SelectionSet).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SelectionSet<'arena> {
+    pub selections: &'arena [Selection<'arena>],
+    pub span: ByteSpan,
+}
+
+/// Arena counterpart of [`ast::Selection`](crate::ast::Selection).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Selection<'arena> {
+    Field(FieldSelection<'arena>),
+    FragmentSpread(FragmentSpread<'arena>),
+    InlineFragment(InlineFragment<'arena>),
+}
+
+/// Arena counterpart of
+/// [`ast::FieldSelection`](crate::ast::FieldSelection).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FieldSelection<'arena> {
+    pub alias: Option<Name<'arena>>,
+    pub arguments: &'arena [Argument<'arena>],
+    pub directives: &'arena [DirectiveAnnotation<'arena>],
+    pub name: Name<'arena>,
+    pub selection_set: Option<SelectionSet<'arena>>,
+    pub span: ByteSpan,
+}
+
+impl FieldSelection<'_> {
+    /// Returns the name of this field selection as a string
+    /// slice.
+    #[inline]
+    pub fn name_value(&self) -> &str {
+        self.name.value
+    }
+}
+
+/// Arena counterpart of
+/// [`ast::FragmentSpread`](crate::ast::FragmentSpread).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FragmentSpread<'arena> {
+    pub directives: &'arena [DirectiveAnnotation<'arena>],
+    pub name: Name<'arena>,
+    pub span: ByteSpan,
+}
+
+/// Arena counterpart of
+/// [`ast::InlineFragment`](crate::ast::InlineFragment).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct InlineFragment<'arena> {
+    pub directives: &'arena [DirectiveAnnotation<'arena>],
+    pub selection_set: SelectionSet<'arena>,
+    pub span: ByteSpan,
+    pub type_condition: Option<TypeCondition<'arena>>,
+}
@@ -0,0 +1,106 @@
+//! Arena-allocated AST types for lean, allocation-light parsing.
+//!
+//! The node types in this module mirror the semantic layer of
+//! [`crate::ast`] field-for-field, but every list is an
+//! `&'arena [T]` slice, every boxed child is an `&'arena T`
+//! reference, and every string is an `&'arena str` — all
+//! allocated from a single [`bumpalo::Bump`] arena supplied by
+//! the caller. Names and enum values borrow straight from the
+//! source text; only strings that must be unescaped (string
+//! literals and descriptions) are copied into the arena.
+//!
+//! Because nothing in an arena AST owns heap memory, all node
+//! types are `Copy`, and dropping the whole tree is a single
+//! arena reset.
+//!
+//! Arena ASTs never carry a syntax layer (there are no `syntax`
+//! fields): they are always equivalent to a
+//! [`GraphQLParserConfig::lean()`](crate::GraphQLParserConfig::lean)
+//! parse. Use [`Document::to_ast()`] to convert an arena AST into
+//! a standard [`ast::Document`](crate::ast::Document) when a
+//! heap-owned tree is needed.
+//!
+//! # Example
+//!
+//! ```rust
+//! use bumpalo::Bump;
+//! use libgraphql_parser::ArenaGraphQLParser;
+//! use libgraphql_parser::ast::arena;
+//!
+//! let bump = Bump::new();
+//! let parser = ArenaGraphQLParser::new(
+//!     "type Query { hello: String }",
+//!     &bump,
+//! );
+//! let (doc, _source_map) = parser.parse_schema_document().unwrap();
+//!
+//! let query_def = match &doc.definitions[0] {
+//!     arena::Definition::TypeDefinition(
+//!         arena::TypeDefinition::Object(obj),
+//!     ) => obj,
+//!     _ => panic!("expected an object type definition"),
+//! };
+//! assert_eq!(query_def.fields.len(), 1);
+//! assert_eq!(query_def.fields[0].name_value(), "hello");
+//! ```
+
+mod definition;
+mod document;
+mod executable;
+mod name;
+mod to_ast;
+mod type_annotation;
+mod type_system;
+mod value;
+
+pub use definition::Definition;
+pub use definition::TypeDefinition;
+pub use definition::TypeExtension;
+pub use document::Document;
+pub use executable::FieldSelection;
+pub use executable::FragmentDefinition;
+pub use executable::FragmentSpread;
+pub use executable::InlineFragment;
+pub use executable::OperationDefinition;
+pub use executable::Selection;
+pub use executable::SelectionSet;
+pub use executable::TypeCondition;
+pub use executable::VariableDefinition;
+pub use name::Name;
+pub use name::StringValue;
+pub use type_annotation::ListTypeAnnotation;
+pub use type_annotation::NamedTypeAnnotation;
+pub use type_annotation::Nullability;
+pub use type_annotation::TypeAnnotation;
+pub use type_system::DirectiveDefinition;
+pub use type_system::DirectiveLocation;
+pub use type_system::EnumTypeDefinition;
+pub use type_system::EnumTypeExtension;
+pub use type_system::EnumValueDefinition;
+pub use type_system::FieldDefinition;
+pub use type_system::InputObjectTypeDefinition;
+pub use type_system::InputObjectTypeExtension;
+pub use type_system::InputValueDefinition;
+pub use type_system::InterfaceTypeDefinition;
+pub use type_system::InterfaceTypeExtension;
+pub use type_system::ObjectTypeDefinition;
+pub use type_system::ObjectTypeExtension;
+pub use type_system::RootOperationTypeDefinition;
+pub use type_system::ScalarTypeDefinition;
+pub use type_system::ScalarTypeExtension;
+pub use type_system::SchemaDefinition;
+pub use type_system::SchemaExtension;
+pub use type_system::UnionTypeDefinition;
+pub use type_system::UnionTypeExtension;
+pub use value::Argument;
+pub use value::BooleanValue;
+pub use value::DirectiveAnnotation;
+pub use value::EnumValue;
+pub use value::FloatValue;
+pub use value::IntValue;
+pub use value::ListValue;
+pub use value::NullValue;
+pub use value::ObjectField;
+pub use value::ObjectValue;
+pub use value::Value;
+pub use value::VariableReference;
@@ -0,0 +1,31 @@
+use crate::ByteSpan;
+use crate::Symbol;
+
+/// Arena counterpart of [`ast::Name`](crate::ast::Name).
+///
+/// `value` borrows directly from the source text.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Name<'arena> {
+    pub span: ByteSpan,
+    pub value: &'arena str,
+}
+
+impl Name<'_> {
+    /// Interns this name's value, returning its [`Symbol`].
+    #[inline]
+    pub fn symbol(&self) -> Symbol {
+        Symbol::intern(self.value)
+    }
+}
+
+/// Arena counterpart of
+/// [`ast::StringValue`](crate::ast::StringValue).
+///
+/// `value` holds the processed (unescaped, block-string
+/// dedented) contents, copied into the arena.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StringValue<'arena> {
+    pub is_block: bool,
+    pub span: ByteSpan,
+    pub value: &'arena str,
+}
@@ -0,0 +1,487 @@
+//! Conversion from arena ASTs to standard (heap-owned) ASTs.
+
+use crate::ast;
+use crate::ast::arena;
+use std::borrow::Cow;
+
+impl<'arena> arena::Document<'arena> {
+    /// Converts this arena document into a standard
+    /// [`ast::Document`].
+    ///
+    /// Strings in the returned document borrow from the arena
+    /// (`Cow::Borrowed`), so the result cannot outlive the arena.
+    /// The result is identical to what a
+    /// [`GraphQLParserConfig::lean()`](crate::GraphQLParserConfig::lean)
+    /// parse of the same source produces.
+    pub fn to_ast(&self) -> ast::Document<'arena> {
+        ast::Document {
+            definitions: self.definitions.iter().map(definition).collect(),
+            span: self.span,
+            syntax: None,
+        }
+    }
+}
+
+fn definition<'a>(def: &arena::Definition<'a>) -> ast::Definition<'a> {
+    match def {
+        arena::Definition::DirectiveDefinition(d) => {
+            ast::Definition::DirectiveDefinition(directive_definition(d))
+        },
+        arena::Definition::FragmentDefinition(d) => {
+            ast::Definition::FragmentDefinition(ast::FragmentDefinition {
+                description: d.description.as_ref().map(string_value),
+                directives: directives(d.directives),
+                name: name(&d.name),
+                selection_set: selection_set(&d.selection_set),
+                span: d.span,
+                syntax: None,
+                type_condition: type_condition(&d.type_condition),
+            })
+        },
+        arena::Definition::OperationDefinition(d) => {
+            ast::Definition::OperationDefinition(ast::OperationDefinition {
+                description: d.description.as_ref().map(string_value),
+                directives: directives(d.directives),
+                name: d.name.as_ref().map(name),
+                operation_kind: d.operation_kind,
+                selection_set: selection_set(&d.selection_set),
+                shorthand: d.shorthand,
+                span: d.span,
+                syntax: None,
+                variable_definitions: d
+                    .variable_definitions
+                    .iter()
+                    .map(variable_definition)
+                    .collect(),
+            })
+        },
+        arena::Definition::SchemaDefinition(d) => {
+            ast::Definition::SchemaDefinition(ast::SchemaDefinition {
+                description: d.description.as_ref().map(string_value),
+                directives: directives(d.directives),
+                root_operations: d
+                    .root_operations
+                    .iter()
+                    .map(root_operation)
+                    .collect(),
+                span: d.span,
+                syntax: None,
+            })
+        },
+        arena::Definition::SchemaExtension(d) => {
+            ast::Definition::SchemaExtension(ast::SchemaExtension {
+                directives: directives(d.directives),
+                root_operations: d
+                    .root_operations
+                    .iter()
+                    .map(root_operation)
+                    .collect(),
+                span: d.span,
+                syntax: None,
+            })
+        },
+        arena::Definition::TypeDefinition(d) => {
+            ast::Definition::TypeDefinition(type_definition(d))
+        },
+        arena::Definition::TypeExtension(d) => {
+            ast::Definition::TypeExtension(type_extension(d))
+        },
+    }
+}
+
+fn type_definition<'a>(
+    def: &arena::TypeDefinition<'a>,
+) -> ast::TypeDefinition<'a> {
+    match def {
+        arena::TypeDefinition::Enum(d) => {
+            ast::TypeDefinition::Enum(ast::EnumTypeDefinition {
+                description: d.description.as_ref().map(string_value),
+                directives: directives(d.directives),
+                name: name(&d.name),
+                span: d.span,
+                syntax: None,
+                values: d.values.iter().map(enum_value_definition).collect(),
+            })
+        },
+        arena::TypeDefinition::InputObject(d) => {
+            ast::TypeDefinition::InputObject(ast::InputObjectTypeDefinition {
+                description: d.description.as_ref().map(string_value),
+                directives: directives(d.directives),
+                fields: d.fields.iter().map(input_value_definition).collect(),
+                name: name(&d.name),
+                span: d.span,
+                syntax: None,
+            })
+        },
+        arena::TypeDefinition::Interface(d) => {
+            ast::TypeDefinition::Interface(ast::InterfaceTypeDefinition {
+                description: d.description.as_ref().map(string_value),
+                directives: directives(d.directives),
+                fields: d.fields.iter().map(field_definition).collect(),
+                implements: d.implements.iter().map(name).collect(),
+                name: name(&d.name),
+                span: d.span,
+                syntax: None,
+            })
+        },
+        arena::TypeDefinition::Object(d) => {
+            ast::TypeDefinition::Object(ast::ObjectTypeDefinition {
+                description: d.description.as_ref().map(string_value),
+                directives: directives(d.directives),
+                fields: d.fields.iter().map(field_definition).collect(),
+                implements: d.implements.iter().map(name).collect(),
+                name: name(&d.name),
+                span: d.span,
+                syntax: None,
+            })
+        },
+        arena::TypeDefinition::Scalar(d) => {
+            ast::TypeDefinition::Scalar(ast::ScalarTypeDefinition {
+                description: d.description.as_ref().map(string_value),
+                directives: directives(d.directives),
+                name: name(&d.name),
+                span: d.span,
+                syntax: None,
+            })
+        },
+        arena::TypeDefinition::Union(d) => {
+            ast::TypeDefinition::Union(ast::UnionTypeDefinition {
+                description: d.description.as_ref().map(string_value),
+                directives: directives(d.directives),
+                members: d.members.iter().map(name).collect(),
+                name: name(&d.name),
+                span: d.span,
+                syntax: None,
+            })
+        },
+    }
+}
+
+fn type_extension<'a>(
+    ext: &arena::TypeExtension<'a>,
+) -> ast::TypeExtension<'a> {
+    match ext {
+        arena::TypeExtension::Enum(e) => {
+            ast::TypeExtension::Enum(ast::EnumTypeExtension {
+                directives: directives(e.directives),
+                name: name(&e.name),
+                span: e.span,
+                syntax: None,
+                values: e.values.iter().map(enum_value_definition).collect(),
+            })
+        },
+        arena::TypeExtension::InputObject(e) => {
+            ast::TypeExtension::InputObject(ast::InputObjectTypeExtension {
+                directives: directives(e.directives),
+                fields: e.fields.iter().map(input_value_definition).collect(),
+                name: name(&e.name),
+                span: e.span,
+                syntax: None,
+            })
+        },
+        arena::TypeExtension::Interface(e) => {
+            ast::TypeExtension::Interface(ast::InterfaceTypeExtension {
+                directives: directives(e.directives),
+                fields: e.fields.iter().map(field_definition).collect(),
+                implements: e.implements.iter().map(name).collect(),
+                name: name(&e.name),
+                span: e.span,
+                syntax: None,
+            })
+        },
+        arena::TypeExtension::Object(e) => {
+            ast::TypeExtension::Object(ast::ObjectTypeExtension {
+                directives: directives(e.directives),
+                fields: e.fields.iter().map(field_definition).collect(),
+                implements: e.implements.iter().map(name).collect(),
+                name: name(&e.name),
+                span: e.span,
+                syntax: None,
+            })
+        },
+        arena::TypeExtension::Scalar(e) => {
+            ast::TypeExtension::Scalar(ast::ScalarTypeExtension {
+                directives: directives(e.directives),
+                name: name(&e.name),
+                span: e.span,
+                syntax: None,
+            })
+        },
+        arena::TypeExtension::Union(e) => {
+            ast::TypeExtension::Union(ast::UnionTypeExtension {
+                directives: directives(e.directives),
+                members: e.members.iter().map(name).collect(),
+                name: name(&e.name),
+                span: e.span,
+                syntax: None,
+            })
+        },
+    }
+}
+
+fn root_operation<'a>(
+    root: &arena::RootOperationTypeDefinition<'a>,
+) -> ast::RootOperationTypeDefinition<'a> {
+    ast::RootOperationTypeDefinition {
+        named_type: name(&root.named_type),
+        operation_kind: root.operation_kind,
+        span: root.span,
+        syntax: None,
+    }
+}
+
+fn directive_definition<'a>(
+    def: &arena::DirectiveDefinition<'a>,
+) -> ast::DirectiveDefinition<'a> {
+    ast::DirectiveDefinition {
+        arguments: def.arguments.iter().map(input_value_definition).collect(),
+        description: def.description.as_ref().map(string_value),
+        locations: def
+            .locations
+            .iter()
+            .map(|loc| ast::DirectiveLocation {
+                kind: loc.kind,
+                span: loc.span,
+                syntax: None,
+            })
+            .collect(),
+        name: name(&def.name),
+        repeatable: def.repeatable,
+        span: def.span,
+        syntax: None,
+    }
+}
+
+fn field_definition<'a>(
+    def: &arena::FieldDefinition<'a>,
+) -> ast::FieldDefinition<'a> {
+    ast::FieldDefinition {
+        parameters: def.parameters.iter().map(input_value_definition).collect(),
+        description: def.description.as_ref().map(string_value),
+        directives: directives(def.directives),
+        field_type: type_annotation(&def.field_type),
+        name: name(&def.name),
+        span: def.span,
+        syntax: None,
+    }
+}
+
+fn input_value_definition<'a>(
+    def: &arena::InputValueDefinition<'a>,
+) -> ast::InputValueDefinition<'a> {
+    ast::InputValueDefinition {
+        default_value: def.default_value.as_ref().map(value),
+        description: def.description.as_ref().map(string_value),
+        directives: directives(def.directives),
+        name: name(&def.name),
+        span: def.span,
+        syntax: None,
+        value_type: type_annotation(&def.value_type),
+    }
+}
+
+fn enum_value_definition<'a>(
+    def: &arena::EnumValueDefinition<'a>,
+) -> ast::EnumValueDefinition<'a> {
+    ast::EnumValueDefinition {
+        description: def.description.as_ref().map(string_value),
+        directives: directives(def.directives),
+        name: name(&def.name),
+        span: def.span,
+    }
+}
+
+fn variable_definition<'a>(
+    def: &arena::VariableDefinition<'a>,
+) -> ast::VariableDefinition<'a> {
+    ast::VariableDefinition {
+        default_value: def.default_value.as_ref().map(value),
+        description: def.description.as_ref().map(string_value),
+        directives: directives(def.directives),
+        span: def.span,
+        syntax: None,
+        var_type: type_annotation(&def.var_type),
+        variable: name(&def.variable),
+    }
+}
+
+fn selection_set<'a>(
+    set: &arena::SelectionSet<'a>,
+) -> ast::SelectionSet<'a> {
+    ast::SelectionSet {
+        selections: set.selections.iter().map(selection).collect(),
+        span: set.span,
+        syntax: None,
+    }
+}
+
+fn selection<'a>(sel: &arena::Selection<'a>) -> ast::Selection<'a> {
+    match sel {
+        arena::Selection::Field(f) => {
+            ast::Selection::Field(ast::FieldSelection {
+                alias: f.alias.as_ref().map(name),
+                arguments: arguments(f.arguments),
+                directives: directives(f.directives),
+                name: name(&f.name),
+                selection_set: f.selection_set.as_ref().map(selection_set),
+                span: f.span,
+                syntax: None,
+            })
+        },
+        arena::Selection::FragmentSpread(s) => {
+            ast::Selection::FragmentSpread(ast::FragmentSpread {
+                directives: directives(s.directives),
+                name: name(&s.name),
+                span: s.span,
+                syntax: None,
+            })
+        },
+        arena::Selection::InlineFragment(f) => {
+            ast::Selection::InlineFragment(ast::InlineFragment {
+                directives: directives(f.directives),
+                selection_set: selection_set(&f.selection_set),
+                span: f.span,
+                syntax: None,
+                type_condition: f.type_condition.as_ref().map(type_condition),
+            })
+        },
+    }
+}
+
+fn type_condition<'a>(
+    cond: &arena::TypeCondition<'a>,
+) -> ast::TypeCondition<'a> {
+    ast::TypeCondition {
+        named_type: name(&cond.named_type),
+        span: cond.span,
+        syntax: None,
+    }
+}
+
+fn type_annotation<'a>(
+    annot: &arena::TypeAnnotation<'a>,
+) -> ast::TypeAnnotation<'a> {
+    match annot {
+        arena::TypeAnnotation::List(list) => {
+            ast::TypeAnnotation::List(ast::ListTypeAnnotation {
+                element_type: Box::new(type_annotation(list.element_type)),
+                nullability: nullability(list.nullability),
+                span: list.span,
+                syntax: None,
+            })
+        },
+        arena::TypeAnnotation::Named(named) => {
+            ast::TypeAnnotation::Named(ast::NamedTypeAnnotation {
+                name: name(&named.name),
+                nullability: nullability(named.nullability),
+                span: named.span,
+            })
+        },
+    }
+}
+
+fn nullability<'a>(n: arena::Nullability) -> ast::Nullability<'a> {
+    match n {
+        arena::Nullability::NonNull => ast::Nullability::NonNull { syntax: None },
+        arena::Nullability::Nullable => ast::Nullability::Nullable,
+    }
+}
+
+fn directives<'a>(
+    annots: &[arena::DirectiveAnnotation<'a>],
+) -> Vec<ast::DirectiveAnnotation<'a>> {
+    annots
+        .iter()
+        .map(|d| ast::DirectiveAnnotation {
+            arguments: arguments(d.arguments),
+            name: name(&d.name),
+            span: d.span,
+            syntax: None,
+        })
+        .collect()
+}
+
+fn arguments<'a>(args: &[arena::Argument<'a>]) -> Vec<ast::Argument<'a>> {
+    args.iter()
+        .map(|arg| ast::Argument {
+            name: name(&arg.name),
+            span: arg.span,
+            syntax: None,
+            value: value(&arg.value),
+        })
+        .collect()
+}
+
+fn value<'a>(val: &arena::Value<'a>) -> ast::Value<'a> {
+    match val {
+        arena::Value::Boolean(v) => ast::Value::Boolean(ast::BooleanValue {
+            span: v.span,
+            syntax: None,
+            value: v.value,
+        }),
+        arena::Value::Enum(v) => ast::Value::Enum(ast::EnumValue {
+            span: v.span,
+            syntax: None,
+            value: Cow::Borrowed(v.value),
+        }),
+        arena::Value::Float(v) => ast::Value::Float(ast::FloatValue {
+            span: v.span,
+            syntax: None,
+            value: v.value,
+        }),
+        arena::Value::Int(v) => ast::Value::Int(ast::IntValue {
+            span: v.span,
+            syntax: None,
+            value: v.value,
+        }),
+        arena::Value::List(v) => ast::Value::List(ast::ListValue {
+            span: v.span,
+            syntax: None,
+            values: v.values.iter().map(value).collect(),
+        }),
+        arena::Value::Null(v) => ast::Value::Null(ast::NullValue {
+            span: v.span,
+            syntax: None,
+        }),
+        arena::Value::Object(v) => ast::Value::Object(ast::ObjectValue {
+            fields: v
+                .fields
+                .iter()
+                .map(|f| ast::ObjectField {
+                    name: name(&f.name),
+                    span: f.span,
+                    syntax: None,
+                    value: value(&f.value),
+                })
+                .collect(),
+            span: v.span,
+            syntax: None,
+        }),
+        arena::Value::String(v) => ast::Value::String(string_value(v)),
+        arena::Value::Variable(v) => {
+            ast::Value::Variable(ast::VariableReference {
+                name: name(&v.name),
+                span: v.span,
+                syntax: None,
+            })
+        },
+    }
+}
+
+fn name<'a>(n: &arena::Name<'a>) -> ast::Name<'a> {
+    ast::Name {
+        span: n.span,
+        symbol: None,
+        syntax: None,
+        value: Cow::Borrowed(n.value),
+    }
+}
+
+fn string_value<'a>(s: &arena::StringValue<'a>) -> ast::StringValue<'a> {
+    ast::StringValue {
+        is_block: s.is_block,
+        span: s.span,
+        syntax: None,
+        value: Cow::Borrowed(s.value),
+    }
+}
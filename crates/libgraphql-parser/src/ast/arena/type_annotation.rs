@@ -0,0 +1,70 @@
+use crate::ast::arena::Name;
+use crate::ByteSpan;
+
+/// Arena counterpart of
+/// [`ast::TypeAnnotation`](crate::ast::TypeAnnotation).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TypeAnnotation<'arena> {
+    List(ListTypeAnnotation<'arena>),
+    Named(NamedTypeAnnotation<'arena>),
+}
+
+impl TypeAnnotation<'_> {
+    /// Returns `true` if this type annotation is nullable
+    /// (i.e. not wrapped with `!`).
+    pub fn nullable(&self) -> bool {
+        match self {
+            Self::List(list) => list.nullable(),
+            Self::Named(named) => named.nullable(),
+        }
+    }
+}
+
+/// Arena counterpart of
+/// [`ast::ListTypeAnnotation`](crate::ast::ListTypeAnnotation).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ListTypeAnnotation<'arena> {
+    pub element_type: &'arena TypeAnnotation<'arena>,
+    pub nullability: Nullability,
+    pub span: ByteSpan,
+}
+
+impl ListTypeAnnotation<'_> {
+    /// Returns `true` if this list type is nullable.
+    pub fn nullable(&self) -> bool {
+        matches!(self.nullability, Nullability::Nullable)
+    }
+}
+
+/// Arena counterpart of
+/// [`ast::NamedTypeAnnotation`](crate::ast::NamedTypeAnnotation).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NamedTypeAnnotation<'arena> {
+    pub name: Name<'arena>,
+    pub nullability: Nullability,
+    pub span: ByteSpan,
+}
+
+impl NamedTypeAnnotation<'_> {
+    /// Returns `true` if this named type is nullable.
+    pub fn nullable(&self) -> bool {
+        matches!(self.nullability, Nullability::Nullable)
+    }
+
+    /// Returns the referenced type name as a string slice.
+    #[inline]
+    pub fn name_value(&self) -> &str {
+        self.name.value
+    }
+}
+
+/// Arena counterpart of
+/// [`ast::Nullability`](crate::ast::Nullability).
+///
+/// Arena ASTs carry no syntax layer, so the `!` token is not
+/// retained.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Nullability {
+    NonNull,
+    Nullable,
+}
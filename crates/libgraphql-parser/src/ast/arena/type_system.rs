@@ -0,0 +1,236 @@
+use crate::ast::arena::DirectiveAnnotation;
+use crate::ast::arena::Name;
+use crate::ast::arena::StringValue;
+use crate::ast::arena::TypeAnnotation;
+use crate::ast::arena::Value;
+use crate::ast::DirectiveLocationKind;
+use crate::ast::OperationKind;
+use crate::ByteSpan;
+
+/// Arena counterpart of
+/// [`ast::SchemaDefinition`](crate::ast::SchemaDefinition).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SchemaDefinition<'arena> {
+    pub description: Option<StringValue<'arena>>,
+    pub directives: &'arena [DirectiveAnnotation<'arena>],
+    pub root_operations: &'arena [RootOperationTypeDefinition<'arena>],
+    pub span: ByteSpan,
+}
+
+/// Arena counterpart of
+/// [`ast::SchemaExtension`](crate::ast::SchemaExtension).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SchemaExtension<'arena> {
+    pub directives: &'arena [DirectiveAnnotation<'arena>],
+    pub root_operations: &'arena [RootOperationTypeDefinition<'arena>],
+    pub span: ByteSpan,
+}
+
+/// Arena counterpart of
+/// [`ast::RootOperationTypeDefinition`](crate::ast::RootOperationTypeDefinition).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RootOperationTypeDefinition<'arena> {
+    pub named_type: Name<'arena>,
+    pub operation_kind: OperationKind,
+    pub span: ByteSpan,
+}
+
+/// Arena counterpart of
+/// [`ast::ScalarTypeDefinition`](crate::ast::ScalarTypeDefinition).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ScalarTypeDefinition<'arena> {
+    pub description: Option<StringValue<'arena>>,
+    pub directives: &'arena [DirectiveAnnotation<'arena>],
+    pub name: Name<'arena>,
+    pub span: ByteSpan,
+}
+
+/// Arena counterpart of
+/// [`ast::ObjectTypeDefinition`](crate::ast::ObjectTypeDefinition).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ObjectTypeDefinition<'arena> {
+    pub description: Option<StringValue<'arena>>,
+    pub directives: &'arena [DirectiveAnnotation<'arena>],
+    pub fields: &'arena [FieldDefinition<'arena>],
+    pub implements: &'arena [Name<'arena>],
+    pub name: Name<'arena>,
+    pub span: ByteSpan,
+}
+
+/// Arena counterpart of
+/// [`ast::InterfaceTypeDefinition`](crate::ast::InterfaceTypeDefinition).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct InterfaceTypeDefinition<'arena> {
+    pub description: Option<StringValue<'arena>>,
+    pub directives: &'arena [DirectiveAnnotation<'arena>],
+    pub fields: &'arena [FieldDefinition<'arena>],
+    pub implements: &'arena [Name<'arena>],
+    pub name: Name<'arena>,
+    pub span: ByteSpan,
+}
+
+/// Arena counterpart of
+/// [`ast::UnionTypeDefinition`](crate::ast::UnionTypeDefinition).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UnionTypeDefinition<'arena> {
+    pub description: Option<StringValue<'arena>>,
+    pub directives: &'arena [DirectiveAnnotation<'arena>],
+    pub members: &'arena [Name<'arena>],
+    pub name: Name<'arena>,
+    pub span: ByteSpan,
+}
+
+/// Arena counterpart of
+/// [`ast::EnumTypeDefinition`](crate::ast::EnumTypeDefinition).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EnumTypeDefinition<'arena> {
+    pub description: Option<StringValue<'arena>>,
+    pub directives: &'arena [DirectiveAnnotation<'arena>],
+    pub name: Name<'arena>,
+    pub span: ByteSpan,
+    pub values: &'arena [EnumValueDefinition<'arena>],
+}
+
+/// Arena counterpart of
+/// [`ast::InputObjectTypeDefinition`](crate::ast::InputObjectTypeDefinition).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct InputObjectTypeDefinition<'arena> {
+    pub description: Option<StringValue<'arena>>,
+    pub directives: &'arena [DirectiveAnnotation<'arena>],
+    pub fields: &'arena [InputValueDefinition<'arena>],
+    pub name: Name<'arena>,
+    pub span: ByteSpan,
+}
+
+/// Arena counterpart of
+/// [`ast::ScalarTypeExtension`](crate::ast::ScalarTypeExtension).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ScalarTypeExtension<'arena> {
+    pub directives: &'arena [DirectiveAnnotation<'arena>],
+    pub name: Name<'arena>,
+    pub span: ByteSpan,
+}
+
+/// Arena counterpart of
+/// [`ast::ObjectTypeExtension`](crate::ast::ObjectTypeExtension).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ObjectTypeExtension<'arena> {
+    pub directives: &'arena [DirectiveAnnotation<'arena>],
+    pub fields: &'arena [FieldDefinition<'arena>],
+    pub implements: &'arena [Name<'arena>],
+    pub name: Name<'arena>,
+    pub span: ByteSpan,
+}
+
+/// Arena counterpart of
+/// [`ast::InterfaceTypeExtension`](crate::ast::InterfaceTypeExtension).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct InterfaceTypeExtension<'arena> {
+    pub directives: &'arena [DirectiveAnnotation<'arena>],
+    pub fields: &'arena [FieldDefinition<'arena>],
+    pub implements: &'arena [Name<'arena>],
+    pub name: Name<'arena>,
+    pub span: ByteSpan,
+}
+
+/// Arena counterpart of
+/// [`ast::UnionTypeExtension`](crate::ast::UnionTypeExtension).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UnionTypeExtension<'arena> {
+    pub directives: &'arena [DirectiveAnnotation<'arena>],
+    pub members: &'arena [Name<'arena>],
+    pub name: Name<'arena>,
+    pub span: ByteSpan,
+}
+
+/// Arena counterpart of
+/// [`ast::EnumTypeExtension`](crate::ast::EnumTypeExtension).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EnumTypeExtension<'arena> {
+    pub directives: &'arena [DirectiveAnnotation<'arena>],
+    pub name: Name<'arena>,
+    pub span: ByteSpan,
+    pub values: &'arena [EnumValueDefinition<'arena>],
+}
+
+/// Arena counterpart of
+/// [`ast::InputObjectTypeExtension`](crate::ast::InputObjectTypeExtension).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct InputObjectTypeExtension<'arena> {
+    pub directives: &'arena [DirectiveAnnotation<'arena>],
+    pub fields: &'arena [InputValueDefinition<'arena>],
+    pub name: Name<'arena>,
+    pub span: ByteSpan,
+}
+
+/// Arena counterpart of
+/// [`ast::FieldDefinition`](crate::ast::FieldDefinition).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FieldDefinition<'arena> {
+    pub parameters: &'arena [InputValueDefinition<'arena>],
+    pub description: Option<StringValue<'arena>>,
+    pub directives: &'arena [DirectiveAnnotation<'arena>],
+    pub field_type: TypeAnnotation<'arena>,
+    pub name: Name<'arena>,
+    pub span: ByteSpan,
+}
+
+impl FieldDefinition<'_> {
+    /// Returns the name of this field definition as a string
+    /// slice.
+    #[inline]
+    pub fn name_value(&self) -> &str {
+        self.name.value
+    }
+}
+
+/// Arena counterpart of
+/// [`ast::InputValueDefinition`](crate::ast::InputValueDefinition).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct InputValueDefinition<'arena> {
+    pub default_value: Option<Value<'arena>>,
+    pub description: Option<StringValue<'arena>>,
+    pub directives: &'arena [DirectiveAnnotation<'arena>],
+    pub name: Name<'arena>,
+    pub span: ByteSpan,
+    pub value_type: TypeAnnotation<'arena>,
+}
+
+impl InputValueDefinition<'_> {
+    /// Returns the name of this input value definition as a
+    /// string slice.
+    #[inline]
+    pub fn name_value(&self) -> &str {
+        self.name.value
+    }
+}
+
+/// Arena counterpart of
+/// [`ast::EnumValueDefinition`](crate::ast::EnumValueDefinition).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EnumValueDefinition<'arena> {
+    pub description: Option<StringValue<'arena>>,
+    pub directives: &'arena [DirectiveAnnotation<'arena>],
+    pub name: Name<'arena>,
+    pub span: ByteSpan,
+}
+
+/// Arena counterpart of
+/// [`ast::DirectiveDefinition`](crate::ast::DirectiveDefinition).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DirectiveDefinition<'arena> {
+    pub arguments: &'arena [InputValueDefinition<'arena>],
+    pub description: Option<StringValue<'arena>>,
+    pub locations: &'arena [DirectiveLocation],
+    pub name: Name<'arena>,
+    pub repeatable: bool,
+    pub span: ByteSpan,
+}
+
+/// Arena counterpart of
+/// [`ast::DirectiveLocation`](crate::ast::DirectiveLocation).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DirectiveLocation {
+    pub kind: DirectiveLocationKind,
+    pub span: ByteSpan,
+}
@@ -0,0 +1,137 @@
+use crate::ast::arena::Name;
+use crate::ast::arena::StringValue;
+use crate::ByteSpan;
+
+/// Arena counterpart of [`ast::Value`](crate::ast::Value).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Value<'arena> {
+    Boolean(BooleanValue),
+    Enum(EnumValue<'arena>),
+    Float(FloatValue),
+    Int(IntValue),
+    List(ListValue<'arena>),
+    Null(NullValue),
+    Object(ObjectValue<'arena>),
+    String(StringValue<'arena>),
+    Variable(VariableReference<'arena>),
+}
+
+impl Value<'_> {
+    /// Returns this value's byte-offset span within the source
+    /// text.
+    pub fn byte_span(&self) -> ByteSpan {
+        match self {
+            Self::Boolean(v) => v.span,
+            Self::Enum(v) => v.span,
+            Self::Float(v) => v.span,
+            Self::Int(v) => v.span,
+            Self::List(v) => v.span,
+            Self::Null(v) => v.span,
+            Self::Object(v) => v.span,
+            Self::String(v) => v.span,
+            Self::Variable(v) => v.span,
+        }
+    }
+}
+
+/// Arena counterpart of
+/// [`ast::BooleanValue`](crate::ast::BooleanValue).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BooleanValue {
+    pub span: ByteSpan,
+    pub value: bool,
+}
+
+/// Arena counterpart of [`ast::EnumValue`](crate::ast::EnumValue).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EnumValue<'arena> {
+    pub span: ByteSpan,
+    pub value: &'arena str,
+}
+
+/// Arena counterpart of
+/// [`ast::FloatValue`](crate::ast::FloatValue).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FloatValue {
+    pub span: ByteSpan,
+    pub value: f64,
+}
+
+/// Arena counterpart of [`ast::IntValue`](crate::ast::IntValue).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct IntValue {
+    pub span: ByteSpan,
+    pub value: i32,
+}
+
+/// Arena counterpart of [`ast::ListValue`](crate::ast::ListValue).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ListValue<'arena> {
+    pub span: ByteSpan,
+    pub values: &'arena [Value<'arena>],
+}
+
+/// Arena counterpart of [`ast::NullValue`](crate::ast::NullValue).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NullValue {
+    pub span: ByteSpan,
+}
+
+/// Arena counterpart of
+/// [`ast::ObjectValue`](crate::ast::ObjectValue).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ObjectValue<'arena> {
+    pub fields: &'arena [ObjectField<'arena>],
+    pub span: ByteSpan,
+}
+
+/// Arena counterpart of
+/// [`ast::ObjectField`](crate::ast::ObjectField).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ObjectField<'arena> {
+    pub name: Name<'arena>,
+    pub span: ByteSpan,
+    pub value: Value<'arena>,
+}
+
+/// Arena counterpart of
+/// [`ast::VariableReference`](crate::ast::VariableReference).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct VariableReference<'arena> {
+    pub name: Name<'arena>,
+    pub span: ByteSpan,
+}
+
+/// Arena counterpart of [`ast::Argument`](crate::ast::Argument).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Argument<'arena> {
+    pub name: Name<'arena>,
+    pub span: ByteSpan,
+    pub value: Value<'arena>,
+}
+
+impl Argument<'_> {
+    /// Returns the name of this argument as a string slice.
+    #[inline]
+    pub fn name_value(&self) -> &str {
+        self.name.value
+    }
+}
+
+/// Arena counterpart of
+/// [`ast::DirectiveAnnotation`](crate::ast::DirectiveAnnotation).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DirectiveAnnotation<'arena> {
+    pub arguments: &'arena [Argument<'arena>],
+    pub name: Name<'arena>,
+    pub span: ByteSpan,
+}
+
+impl DirectiveAnnotation<'_> {
+    /// Returns the name of this directive annotation as a
+    /// string slice.
+    #[inline]
+    pub fn name_value(&self) -> &str {
+        self.name.value
+    }
+}
@@ -51,6 +51,10 @@ impl AstNode for Argument<'_> {
             append_span_source_slice(
                 self.span, sink, src,
             );
+        } else {
+            self.name.append_source(sink, None);
+            sink.push_str(": ");
+            self.value.append_source(sink, None);
         }
     }
 
@@ -36,6 +36,8 @@ impl AstNode for BooleanValue<'_> {
             append_span_source_slice(
                 self.span, sink, src,
             );
+        } else {
+            sink.push_str(if self.value { "true" } else { "false" });
         }
     }
 
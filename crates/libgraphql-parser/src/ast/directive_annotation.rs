@@ -3,6 +3,7 @@ use crate::ast::ast_node::append_span_source_slice;
 use crate::ast::AstNode;
 use crate::ast::DelimiterPair;
 use crate::ast::Name;
+use crate::ast::synthetic_source::append_arguments;
 use crate::ByteSpan;
 use crate::SourceMap;
 use crate::SourceSpan;
@@ -54,6 +55,10 @@ impl AstNode for DirectiveAnnotation<'_> {
             append_span_source_slice(
                 self.span, sink, src,
             );
+        } else {
+            sink.push('@');
+            self.name.append_source(sink, None);
+            append_arguments(sink, &self.arguments);
         }
     }
 
@@ -5,6 +5,9 @@ use crate::ast::DirectiveLocation;
 use crate::ast::InputValueDefinition;
 use crate::ast::Name;
 use crate::ast::StringValue;
+use crate::ast::synthetic_source::append_description;
+use crate::ast::synthetic_source::append_joined;
+use crate::ast::synthetic_source::append_parameter_definitions;
 use crate::ByteSpan;
 use crate::SourceMap;
 use crate::SourceSpan;
@@ -66,6 +69,16 @@ impl AstNode for DirectiveDefinition<'_> {
             append_span_source_slice(
                 self.span, sink, src,
             );
+        } else {
+            append_description(sink, self.description.as_ref());
+            sink.push_str("directive @");
+            self.name.append_source(sink, None);
+            append_parameter_definitions(sink, &self.arguments);
+            if self.repeatable {
+                sink.push_str(" repeatable");
+            }
+            sink.push_str(" on ");
+            append_joined(sink, &self.locations, " | ");
         }
     }
 
@@ -48,6 +48,34 @@ pub enum DirectiveLocationKind {
     VariableDefinition,
 }
 
+impl DirectiveLocationKind {
+    /// Returns the name of this location as written in SDL (e.g.
+    /// `FIELD_DEFINITION`).
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::ArgumentDefinition => "ARGUMENT_DEFINITION",
+            Self::Enum => "ENUM",
+            Self::EnumValue => "ENUM_VALUE",
+            Self::Field => "FIELD",
+            Self::FieldDefinition => "FIELD_DEFINITION",
+            Self::FragmentDefinition => "FRAGMENT_DEFINITION",
+            Self::FragmentSpread => "FRAGMENT_SPREAD",
+            Self::InlineFragment => "INLINE_FRAGMENT",
+            Self::InputFieldDefinition => "INPUT_FIELD_DEFINITION",
+            Self::InputObject => "INPUT_OBJECT",
+            Self::Interface => "INTERFACE",
+            Self::Mutation => "MUTATION",
+            Self::Object => "OBJECT",
+            Self::Query => "QUERY",
+            Self::Scalar => "SCALAR",
+            Self::Schema => "SCHEMA",
+            Self::Subscription => "SUBSCRIPTION",
+            Self::Union => "UNION",
+            Self::VariableDefinition => "VARIABLE_DEFINITION",
+        }
+    }
+}
+
 /// Syntax detail for a [`DirectiveLocation`].
 #[derive(Clone, Debug, PartialEq)]
 pub struct DirectiveLocationSyntax<'src> {
@@ -70,6 +98,8 @@ impl AstNode for DirectiveLocation<'_> {
             append_span_source_slice(
                 self.span, sink, src,
             );
+        } else {
+            sink.push_str(self.kind.as_str());
         }
     }
 
@@ -146,6 +146,16 @@ impl AstNode for Document<'_> {
                     );
                 }
             }
+        } else {
+            for (i, definition) in self.definitions.iter().enumerate() {
+                if i > 0 {
+                    sink.push_str("\n\n");
+                }
+                definition.append_source(sink, None);
+            }
+            if !self.definitions.is_empty() {
+                sink.push('\n');
+            }
         }
     }
 
@@ -4,6 +4,8 @@ use crate::ast::DelimiterPair;
 use crate::ast::DirectiveAnnotation;
 use crate::ast::EnumValueDefinition;
 use crate::ast::Name;
+use crate::ast::synthetic_source::append_block;
+use crate::ast::synthetic_source::append_directives;
 use crate::ByteSpan;
 use crate::SourceMap;
 use crate::SourceSpan;
@@ -64,6 +66,11 @@ impl AstNode for EnumTypeExtension<'_> {
             append_span_source_slice(
                 self.span, sink, src,
             );
+        } else {
+            sink.push_str("extend enum ");
+            self.name.append_source(sink, None);
+            append_directives(sink, &self.directives);
+            append_block(sink, &self.values);
         }
     }
 
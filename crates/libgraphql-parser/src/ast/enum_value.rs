@@ -39,6 +39,8 @@ impl AstNode for EnumValue<'_> {
             append_span_source_slice(
                 self.span, sink, src,
             );
+        } else {
+            sink.push_str(&self.value);
         }
     }
 
@@ -3,6 +3,8 @@ use crate::ast::AstNode;
 use crate::ast::DirectiveAnnotation;
 use crate::ast::Name;
 use crate::ast::StringValue;
+use crate::ast::synthetic_source::append_description;
+use crate::ast::synthetic_source::append_directives;
 use crate::ByteSpan;
 use crate::SourceMap;
 use crate::SourceSpan;
@@ -52,6 +54,10 @@ impl AstNode for EnumValueDefinition<'_> {
             append_span_source_slice(
                 self.span, sink, src,
             );
+        } else {
+            append_description(sink, self.description.as_ref());
+            self.name.append_source(sink, None);
+            append_directives(sink, &self.directives);
         }
     }
 
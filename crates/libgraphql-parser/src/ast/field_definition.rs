@@ -6,6 +6,9 @@ use crate::ast::InputValueDefinition;
 use crate::ast::Name;
 use crate::ast::StringValue;
 use crate::ast::TypeAnnotation;
+use crate::ast::synthetic_source::append_description;
+use crate::ast::synthetic_source::append_directives;
+use crate::ast::synthetic_source::append_parameter_definitions;
 use crate::ByteSpan;
 use crate::SourceMap;
 use crate::SourceSpan;
@@ -59,6 +62,13 @@ impl AstNode for FieldDefinition<'_> {
             append_span_source_slice(
                 self.span, sink, src,
             );
+        } else {
+            append_description(sink, self.description.as_ref());
+            self.name.append_source(sink, None);
+            append_parameter_definitions(sink, &self.parameters);
+            sink.push_str(": ");
+            self.field_type.append_source(sink, None);
+            append_directives(sink, &self.directives);
         }
     }
 
@@ -5,6 +5,8 @@ use crate::ast::DelimiterPair;
 use crate::ast::DirectiveAnnotation;
 use crate::ast::Name;
 use crate::ast::SelectionSet;
+use crate::ast::synthetic_source::append_arguments;
+use crate::ast::synthetic_source::append_directives;
 use crate::ByteSpan;
 use crate::SourceMap;
 use crate::SourceSpan;
@@ -61,6 +63,18 @@ impl AstNode for FieldSelection<'_> {
             append_span_source_slice(
                 self.span, sink, src,
             );
+        } else {
+            if let Some(alias) = &self.alias {
+                alias.append_source(sink, None);
+                sink.push_str(": ");
+            }
+            self.name.append_source(sink, None);
+            append_arguments(sink, &self.arguments);
+            append_directives(sink, &self.directives);
+            if let Some(selection_set) = &self.selection_set {
+                sink.push(' ');
+                selection_set.append_source(sink, None);
+            }
         }
     }
 
@@ -50,6 +50,10 @@ impl AstNode for FloatValue<'_> {
             append_span_source_slice(
                 self.span, sink, src,
             );
+        } else {
+            // `Debug` prints the shortest representation that parses
+            // back to the same `f64`, always with a `.` or exponent.
+            sink.push_str(&format!("{:?}", self.value));
         }
     }
 
@@ -5,6 +5,8 @@ use crate::ast::Name;
 use crate::ast::SelectionSet;
 use crate::ast::StringValue;
 use crate::ast::TypeCondition;
+use crate::ast::synthetic_source::append_description;
+use crate::ast::synthetic_source::append_directives;
 use crate::ByteSpan;
 use crate::SourceMap;
 use crate::SourceSpan;
@@ -61,6 +63,15 @@ impl AstNode for FragmentDefinition<'_> {
             append_span_source_slice(
                 self.span, sink, src,
             );
+        } else {
+            append_description(sink, self.description.as_ref());
+            sink.push_str("fragment ");
+            self.name.append_source(sink, None);
+            sink.push(' ');
+            self.type_condition.append_source(sink, None);
+            append_directives(sink, &self.directives);
+            sink.push(' ');
+            self.selection_set.append_source(sink, None);
         }
     }
 
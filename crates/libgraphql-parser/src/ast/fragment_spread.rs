@@ -2,6 +2,7 @@ use crate::ast::ast_node::append_span_source_slice;
 use crate::ast::AstNode;
 use crate::ast::DirectiveAnnotation;
 use crate::ast::Name;
+use crate::ast::synthetic_source::append_directives;
 use crate::ByteSpan;
 use crate::SourceMap;
 use crate::SourceSpan;
@@ -50,6 +51,10 @@ impl AstNode for FragmentSpread<'_> {
             append_span_source_slice(
                 self.span, sink, src,
             );
+        } else {
+            sink.push_str("...");
+            self.name.append_source(sink, None);
+            append_directives(sink, &self.directives);
         }
     }
 
@@ -3,6 +3,7 @@ use crate::ast::AstNode;
 use crate::ast::DirectiveAnnotation;
 use crate::ast::SelectionSet;
 use crate::ast::TypeCondition;
+use crate::ast::synthetic_source::append_directives;
 use crate::ByteSpan;
 use crate::SourceMap;
 use crate::SourceSpan;
@@ -42,6 +43,15 @@ impl AstNode for InlineFragment<'_> {
             append_span_source_slice(
                 self.span, sink, src,
             );
+        } else {
+            sink.push_str("...");
+            if let Some(type_condition) = &self.type_condition {
+                sink.push(' ');
+                type_condition.append_source(sink, None);
+            }
+            append_directives(sink, &self.directives);
+            sink.push(' ');
+            self.selection_set.append_source(sink, None);
         }
     }
 
@@ -5,6 +5,9 @@ use crate::ast::DirectiveAnnotation;
 use crate::ast::InputValueDefinition;
 use crate::ast::Name;
 use crate::ast::StringValue;
+use crate::ast::synthetic_source::append_block;
+use crate::ast::synthetic_source::append_description;
+use crate::ast::synthetic_source::append_directives;
 use crate::ByteSpan;
 use crate::SourceMap;
 use crate::SourceSpan;
@@ -57,6 +60,12 @@ impl AstNode for InputObjectTypeDefinition<'_> {
             append_span_source_slice(
                 self.span, sink, src,
             );
+        } else {
+            append_description(sink, self.description.as_ref());
+            sink.push_str("input ");
+            self.name.append_source(sink, None);
+            append_directives(sink, &self.directives);
+            append_block(sink, &self.fields);
         }
     }
 
@@ -4,6 +4,8 @@ use crate::ast::DelimiterPair;
 use crate::ast::DirectiveAnnotation;
 use crate::ast::InputValueDefinition;
 use crate::ast::Name;
+use crate::ast::synthetic_source::append_block;
+use crate::ast::synthetic_source::append_directives;
 use crate::ByteSpan;
 use crate::SourceMap;
 use crate::SourceSpan;
@@ -65,6 +67,11 @@ impl AstNode for InputObjectTypeExtension<'_> {
             append_span_source_slice(
                 self.span, sink, src,
             );
+        } else {
+            sink.push_str("extend input ");
+            self.name.append_source(sink, None);
+            append_directives(sink, &self.directives);
+            append_block(sink, &self.fields);
         }
     }
 
@@ -5,6 +5,8 @@ use crate::ast::Name;
 use crate::ast::StringValue;
 use crate::ast::TypeAnnotation;
 use crate::ast::Value;
+use crate::ast::synthetic_source::append_description;
+use crate::ast::synthetic_source::append_directives;
 use crate::ByteSpan;
 use crate::SourceMap;
 use crate::SourceSpan;
@@ -58,6 +60,16 @@ impl AstNode for InputValueDefinition<'_> {
             append_span_source_slice(
                 self.span, sink, src,
             );
+        } else {
+            append_description(sink, self.description.as_ref());
+            self.name.append_source(sink, None);
+            sink.push_str(": ");
+            self.value_type.append_source(sink, None);
+            if let Some(default_value) = &self.default_value {
+                sink.push_str(" = ");
+                default_value.append_source(sink, None);
+            }
+            append_directives(sink, &self.directives);
         }
     }
 
@@ -49,6 +49,8 @@ impl AstNode for IntValue<'_> {
             append_span_source_slice(
                 self.span, sink, src,
             );
+        } else {
+            sink.push_str(&self.value.to_string());
         }
     }
 
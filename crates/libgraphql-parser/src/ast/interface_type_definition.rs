@@ -5,6 +5,10 @@ use crate::ast::DirectiveAnnotation;
 use crate::ast::FieldDefinition;
 use crate::ast::Name;
 use crate::ast::StringValue;
+use crate::ast::synthetic_source::append_block;
+use crate::ast::synthetic_source::append_description;
+use crate::ast::synthetic_source::append_directives;
+use crate::ast::synthetic_source::append_implements;
 use crate::ByteSpan;
 use crate::SourceMap;
 use crate::SourceSpan;
@@ -61,6 +65,13 @@ impl AstNode for InterfaceTypeDefinition<'_> {
             append_span_source_slice(
                 self.span, sink, src,
             );
+        } else {
+            append_description(sink, self.description.as_ref());
+            sink.push_str("interface ");
+            self.name.append_source(sink, None);
+            append_implements(sink, &self.implements);
+            append_directives(sink, &self.directives);
+            append_block(sink, &self.fields);
         }
     }
 
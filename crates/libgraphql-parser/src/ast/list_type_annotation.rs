@@ -3,6 +3,7 @@ use crate::ast::AstNode;
 use crate::ast::DelimiterPair;
 use crate::ast::Nullability;
 use crate::ast::TypeAnnotation;
+use crate::ast::synthetic_source::append_nullability;
 use crate::ByteSpan;
 use crate::SourceMap;
 use crate::SourceSpan;
@@ -48,6 +49,11 @@ impl AstNode for ListTypeAnnotation<'_> {
             append_span_source_slice(
                 self.span, sink, src,
             );
+        } else {
+            sink.push('[');
+            self.element_type.append_source(sink, None);
+            sink.push(']');
+            append_nullability(sink, &self.nullability);
         }
     }
 
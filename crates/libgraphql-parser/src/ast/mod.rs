@@ -43,6 +43,7 @@
 //! [`Cow<'src, str>`]: std::borrow::Cow
 //! [`ByteSpan`]: crate::ByteSpan
 
+pub mod arena;
 mod argument;
 mod ast_node;
 mod boolean_value;
@@ -90,6 +91,7 @@ mod schema_extension;
 mod selection;
 mod selection_set;
 mod string_value;
+mod synthetic_source;
 mod type_annotation;
 mod type_condition;
 mod type_definition;
@@ -3,6 +3,7 @@ use crate::ast::AstNode;
 use crate::ByteSpan;
 use crate::SourceMap;
 use crate::SourceSpan;
+use crate::Symbol;
 use crate::token::GraphQLToken;
 use inherent::inherent;
 use std::borrow::Cow;
@@ -16,6 +17,13 @@ use std::borrow::Cow;
 /// (`Cow::Borrowed`) or owns the string when the source is not
 /// available (`Cow::Owned`).
 ///
+/// # Interning
+///
+/// When the parser is configured with
+/// [`GraphQLParserConfig::intern_names`](crate::GraphQLParserConfig::intern_names),
+/// `symbol` holds the interned [`Symbol`] for `value`. Otherwise it
+/// is `None` and [`symbol()`](Self::symbol) interns on demand.
+///
 /// # Syntax Layer
 ///
 /// When the parser retains syntax detail, `syntax` contains the
@@ -24,6 +32,7 @@ use std::borrow::Cow;
 #[derive(Clone, Debug, PartialEq)]
 pub struct Name<'src> {
     pub span: ByteSpan,
+    pub symbol: Option<Symbol>,
     pub syntax: Option<Box<NameSyntax<'src>>>,
     pub value: Cow<'src, str>,
 }
@@ -34,6 +43,24 @@ pub struct NameSyntax<'src> {
     pub token: GraphQLToken<'src>,
 }
 
+impl Name<'_> {
+    /// Returns this name's [`Symbol`].
+    ///
+    /// This is the symbol recorded by the parser when
+    /// [`GraphQLParserConfig::intern_names`](crate::GraphQLParserConfig::intern_names)
+    /// is enabled. Otherwise the value is interned on demand, which
+    /// is a lookup only if a live [`Symbol`] for the same name
+    /// already exists (e.g. one held by a schema built from an
+    /// earlier definition).
+    #[inline]
+    pub fn symbol(&self) -> Symbol {
+        match &self.symbol {
+            Some(symbol) => symbol.clone(),
+            None => Symbol::intern(&self.value),
+        }
+    }
+}
+
 #[inherent]
 impl AstNode for Name<'_> {
     /// See [`AstNode::append_source()`](crate::ast::AstNode::append_source).
@@ -46,6 +73,8 @@ impl AstNode for Name<'_> {
             append_span_source_slice(
                 self.span, sink, src,
             );
+        } else {
+            sink.push_str(&self.value);
         }
     }
 
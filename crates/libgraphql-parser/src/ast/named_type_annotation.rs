@@ -2,6 +2,7 @@ use crate::ast::ast_node::append_span_source_slice;
 use crate::ast::AstNode;
 use crate::ast::Name;
 use crate::ast::Nullability;
+use crate::ast::synthetic_source::append_nullability;
 use crate::ByteSpan;
 use crate::SourceMap;
 use crate::SourceSpan;
@@ -56,6 +57,9 @@ impl AstNode for NamedTypeAnnotation<'_> {
             append_span_source_slice(
                 self.span, sink, src,
             );
+        } else {
+            self.name.append_source(sink, None);
+            append_nullability(sink, &self.nullability);
         }
     }
 
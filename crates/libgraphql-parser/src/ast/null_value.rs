@@ -35,6 +35,8 @@ impl AstNode for NullValue<'_> {
             append_span_source_slice(
                 self.span, sink, src,
             );
+        } else {
+            sink.push_str("null");
         }
     }
 
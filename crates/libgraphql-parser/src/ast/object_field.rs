@@ -47,6 +47,10 @@ impl AstNode for ObjectField<'_> {
             append_span_source_slice(
                 self.span, sink, src,
             );
+        } else {
+            self.name.append_source(sink, None);
+            sink.push_str(": ");
+            self.value.append_source(sink, None);
         }
     }
 
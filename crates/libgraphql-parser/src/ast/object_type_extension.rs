@@ -4,6 +4,9 @@ use crate::ast::DelimiterPair;
 use crate::ast::DirectiveAnnotation;
 use crate::ast::FieldDefinition;
 use crate::ast::Name;
+use crate::ast::synthetic_source::append_block;
+use crate::ast::synthetic_source::append_directives;
+use crate::ast::synthetic_source::append_implements;
 use crate::ByteSpan;
 use crate::SourceMap;
 use crate::SourceSpan;
@@ -62,6 +65,12 @@ impl AstNode for ObjectTypeExtension<'_> {
             append_span_source_slice(
                 self.span, sink, src,
             );
+        } else {
+            sink.push_str("extend type ");
+            self.name.append_source(sink, None);
+            append_implements(sink, &self.implements);
+            append_directives(sink, &self.directives);
+            append_block(sink, &self.fields);
         }
     }
 
@@ -2,6 +2,7 @@ use crate::ast::ast_node::append_span_source_slice;
 use crate::ast::AstNode;
 use crate::ast::DelimiterPair;
 use crate::ast::ObjectField;
+use crate::ast::synthetic_source::append_joined;
 use crate::ByteSpan;
 use crate::SourceMap;
 use crate::SourceSpan;
@@ -37,6 +38,10 @@ impl AstNode for ObjectValue<'_> {
             append_span_source_slice(
                 self.span, sink, src,
             );
+        } else {
+            sink.push('{');
+            append_joined(sink, &self.fields, ", ");
+            sink.push('}');
         }
     }
 
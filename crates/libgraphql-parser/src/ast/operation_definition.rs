@@ -7,6 +7,10 @@ use crate::ast::OperationKind;
 use crate::ast::SelectionSet;
 use crate::ast::StringValue;
 use crate::ast::VariableDefinition;
+use crate::ast::synthetic_source::append_description;
+use crate::ast::synthetic_source::append_directives;
+use crate::ast::synthetic_source::append_parenthesized;
+use crate::ast::synthetic_source::operation_keyword;
 use crate::ByteSpan;
 use crate::SourceMap;
 use crate::SourceSpan;
@@ -69,6 +73,25 @@ impl AstNode for OperationDefinition<'_> {
             append_span_source_slice(
                 self.span, sink, src,
             );
+        } else {
+            append_description(sink, self.description.as_ref());
+            if !self.shorthand {
+                sink.push_str(operation_keyword(self.operation_kind));
+                if let Some(name) = &self.name {
+                    sink.push(' ');
+                    name.append_source(sink, None);
+                }
+                append_parenthesized(
+                    sink,
+                    &self.variable_definitions,
+                    self.variable_definitions
+                        .iter()
+                        .any(|var_def| var_def.description.is_some()),
+                );
+                append_directives(sink, &self.directives);
+                sink.push(' ');
+            }
+            self.selection_set.append_source(sink, None);
         }
     }
 
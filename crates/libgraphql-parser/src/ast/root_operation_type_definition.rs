@@ -2,6 +2,7 @@ use crate::ast::ast_node::append_span_source_slice;
 use crate::ast::AstNode;
 use crate::ast::Name;
 use crate::ast::OperationKind;
+use crate::ast::synthetic_source::operation_keyword;
 use crate::ByteSpan;
 use crate::SourceMap;
 use crate::SourceSpan;
@@ -42,6 +43,10 @@ impl AstNode for RootOperationTypeDefinition<'_> {
             append_span_source_slice(
                 self.span, sink, src,
             );
+        } else {
+            sink.push_str(operation_keyword(self.operation_kind));
+            sink.push_str(": ");
+            self.named_type.append_source(sink, None);
         }
     }
 
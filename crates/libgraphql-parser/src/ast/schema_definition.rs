@@ -4,6 +4,9 @@ use crate::ast::DelimiterPair;
 use crate::ast::DirectiveAnnotation;
 use crate::ast::RootOperationTypeDefinition;
 use crate::ast::StringValue;
+use crate::ast::synthetic_source::append_block;
+use crate::ast::synthetic_source::append_description;
+use crate::ast::synthetic_source::append_directives;
 use crate::ByteSpan;
 use crate::SourceMap;
 use crate::SourceSpan;
@@ -51,6 +54,15 @@ impl AstNode for SchemaDefinition<'_> {
             append_span_source_slice(
                 self.span, sink, src,
             );
+        } else {
+            append_description(sink, self.description.as_ref());
+            sink.push_str("schema");
+            append_directives(sink, &self.directives);
+            if self.root_operations.is_empty() {
+                sink.push_str(" {}");
+            } else {
+                append_block(sink, &self.root_operations);
+            }
         }
     }
 
@@ -3,6 +3,8 @@ use crate::ast::AstNode;
 use crate::ast::DelimiterPair;
 use crate::ast::DirectiveAnnotation;
 use crate::ast::RootOperationTypeDefinition;
+use crate::ast::synthetic_source::append_block;
+use crate::ast::synthetic_source::append_directives;
 use crate::ByteSpan;
 use crate::SourceMap;
 use crate::SourceSpan;
@@ -43,6 +45,10 @@ impl AstNode for SchemaExtension<'_> {
             append_span_source_slice(
                 self.span, sink, src,
             );
+        } else {
+            sink.push_str("extend schema");
+            append_directives(sink, &self.directives);
+            append_block(sink, &self.root_operations);
         }
     }
 
@@ -2,6 +2,7 @@ use crate::ast::ast_node::append_span_source_slice;
 use crate::ast::AstNode;
 use crate::ast::DelimiterPair;
 use crate::ast::Selection;
+use crate::ast::synthetic_source::append_indented;
 use crate::ByteSpan;
 use crate::SourceMap;
 use crate::SourceSpan;
@@ -38,6 +39,13 @@ impl AstNode for SelectionSet<'_> {
             append_span_source_slice(
                 self.span, sink, src,
             );
+        } else {
+            sink.push('{');
+            for selection in &self.selections {
+                sink.push('\n');
+                append_indented(sink, &selection.to_source(None));
+            }
+            sink.push_str("\n}");
         }
     }
 
@@ -1,5 +1,8 @@
 use crate::ast::ast_node::append_span_source_slice;
 use crate::ast::AstNode;
+use crate::ast::synthetic_source::append_block_string;
+use crate::ast::synthetic_source::append_quoted_string;
+use crate::ast::synthetic_source::is_printable_as_block_string;
 use crate::ByteSpan;
 use crate::SourceMap;
 use crate::SourceSpan;
@@ -38,6 +41,36 @@ pub struct StringValueSyntax<'src> {
     pub token: GraphQLToken<'src>,
 }
 
+impl StringValue<'_> {
+    /// Appends `value` to `sink` as a quoted string literal (`"..."`),
+    /// escaping it as needed so the literal's
+    /// [string value](https://spec.graphql.org/September2025/#sec-String-Value.Static-Semantics)
+    /// is exactly `value`.
+    pub fn append_quoted_string(sink: &mut String, value: &str) {
+        append_quoted_string(sink, value);
+    }
+
+    /// Returns `true` if `value` can be written as a block string
+    /// (`"""..."""`) whose block string value is exactly `value`.
+    ///
+    /// Values with control characters, carriage returns, leading or
+    /// trailing blank lines, or indentation common to every line
+    /// after the first cannot round-trip through a block string.
+    pub fn is_printable_as_block_string(value: &str) -> bool {
+        is_printable_as_block_string(value)
+    }
+
+    /// Appends `value` to `sink` as an unindented block string.
+    ///
+    /// `value` must satisfy
+    /// [`is_printable_as_block_string()`](Self::is_printable_as_block_string);
+    /// callers should fall back to
+    /// [`append_quoted_string()`](Self::append_quoted_string) otherwise.
+    pub fn append_block_string(sink: &mut String, value: &str) {
+        append_block_string(sink, value);
+    }
+}
+
 #[inherent]
 impl AstNode for StringValue<'_> {
     /// See [`AstNode::append_source()`](crate::ast::AstNode::append_source).
@@ -50,6 +83,12 @@ impl AstNode for StringValue<'_> {
             append_span_source_slice(
                 self.span, sink, src,
             );
+        } else {
+            if self.is_block && is_printable_as_block_string(&self.value) {
+                append_block_string(sink, &self.value);
+            } else {
+                append_quoted_string(sink, &self.value);
+            }
         }
     }
 
@@ -0,0 +1,272 @@
+//! Helpers for the synthetic-formatting mode of
+//! [`AstNode::append_source()`](crate::ast::AstNode::append_source),
+//! used when no source text is available.
+//!
+//! The output uses conventional GraphQL formatting (two-space
+//! indentation, one field or selection per line) and always re-parses
+//! to an AST that is equal to the original apart from spans and
+//! syntax detail.
+
+use crate::ast::Argument;
+use crate::ast::AstNode;
+use crate::ast::DirectiveAnnotation;
+use crate::ast::InputValueDefinition;
+use crate::ast::Name;
+use crate::ast::Nullability;
+use crate::ast::OperationKind;
+use crate::ast::StringValue;
+
+/// Appends `text` to `sink` with every non-empty line indented by
+/// two spaces.
+///
+/// Block strings stay intact because every line of a multi-line
+/// block string is indented by the same amount, which the
+/// block-string indentation stripping removes again.
+pub(crate) fn append_indented(sink: &mut String, text: &str) {
+    for (i, line) in text.split('\n').enumerate() {
+        if i > 0 {
+            sink.push('\n');
+        }
+        if !line.is_empty() {
+            sink.push_str("  ");
+            sink.push_str(line);
+        }
+    }
+}
+
+/// Appends `items` as a braced block with one indented item per
+/// line, preceded by a space. Appends nothing when `items` is empty.
+pub(crate) fn append_block<T: AstNode>(sink: &mut String, items: &[T]) {
+    if items.is_empty() {
+        return;
+    }
+    sink.push_str(" {");
+    for item in items {
+        sink.push('\n');
+        append_indented(sink, &item.to_source(None));
+    }
+    sink.push_str("\n}");
+}
+
+/// Appends `items` separated by `separator`.
+pub(crate) fn append_joined<T: AstNode>(
+    sink: &mut String,
+    items: &[T],
+    separator: &str,
+) {
+    for (i, item) in items.iter().enumerate() {
+        if i > 0 {
+            sink.push_str(separator);
+        }
+        item.append_source(sink, None);
+    }
+}
+
+/// Appends a description followed by a newline, if present.
+pub(crate) fn append_description(
+    sink: &mut String,
+    description: Option<&StringValue<'_>>,
+) {
+    if let Some(description) = description {
+        description.append_source(sink, None);
+        sink.push('\n');
+    }
+}
+
+/// Appends each directive annotation preceded by a space.
+pub(crate) fn append_directives(
+    sink: &mut String,
+    directives: &[DirectiveAnnotation<'_>],
+) {
+    for directive in directives {
+        sink.push(' ');
+        directive.append_source(sink, None);
+    }
+}
+
+/// Appends a parenthesized argument list, if non-empty.
+pub(crate) fn append_arguments(sink: &mut String, arguments: &[Argument<'_>]) {
+    if arguments.is_empty() {
+        return;
+    }
+    sink.push('(');
+    append_joined(sink, arguments, ", ");
+    sink.push(')');
+}
+
+/// Appends a parenthesized list of parameter definitions, if
+/// non-empty. The list is written one definition per line when any
+/// of them has a description, and inline otherwise.
+pub(crate) fn append_parameter_definitions(
+    sink: &mut String,
+    parameters: &[InputValueDefinition<'_>],
+) {
+    append_parenthesized(
+        sink,
+        parameters,
+        parameters.iter().any(|param| param.description.is_some()),
+    );
+}
+
+/// Appends `items` in parentheses, one per line when `multiline` is
+/// set, and comma-separated inline otherwise. Appends nothing when
+/// `items` is empty.
+pub(crate) fn append_parenthesized<T: AstNode>(
+    sink: &mut String,
+    items: &[T],
+    multiline: bool,
+) {
+    if items.is_empty() {
+        return;
+    }
+    if !multiline {
+        sink.push('(');
+        append_joined(sink, items, ", ");
+        sink.push(')');
+        return;
+    }
+    sink.push('(');
+    for item in items {
+        sink.push('\n');
+        append_indented(sink, &item.to_source(None));
+    }
+    sink.push_str("\n)");
+}
+
+/// Appends an `implements A & B` clause preceded by a space, if
+/// `interfaces` is non-empty.
+pub(crate) fn append_implements(sink: &mut String, interfaces: &[Name<'_>]) {
+    if interfaces.is_empty() {
+        return;
+    }
+    sink.push_str(" implements ");
+    append_joined(sink, interfaces, " & ");
+}
+
+/// Appends a `= A | B` union member list preceded by a space, if
+/// `members` is non-empty.
+pub(crate) fn append_union_members(sink: &mut String, members: &[Name<'_>]) {
+    if members.is_empty() {
+        return;
+    }
+    sink.push_str(" = ");
+    append_joined(sink, members, " | ");
+}
+
+/// Appends `!` for a non-null type annotation.
+pub(crate) fn append_nullability(
+    sink: &mut String,
+    nullability: &Nullability<'_>,
+) {
+    if let Nullability::NonNull { .. } = nullability {
+        sink.push('!');
+    }
+}
+
+/// Returns the keyword that introduces an operation of `kind`.
+pub(crate) fn operation_keyword(kind: OperationKind) -> &'static str {
+    match kind {
+        OperationKind::Mutation => "mutation",
+        OperationKind::Query => "query",
+        OperationKind::Subscription => "subscription",
+    }
+}
+
+/// Appends `value` as a quoted string literal, escaping quotes,
+/// backslashes, and control characters.
+pub(crate) fn append_quoted_string(sink: &mut String, value: &str) {
+    sink.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => sink.push_str("\\\""),
+            '\\' => sink.push_str("\\\\"),
+            '\u{8}' => sink.push_str("\\b"),
+            '\u{c}' => sink.push_str("\\f"),
+            '\n' => sink.push_str("\\n"),
+            '\r' => sink.push_str("\\r"),
+            '\t' => sink.push_str("\\t"),
+            c if c < '\u{20}' => {
+                sink.push_str(&format!("\\u{:04X}", c as u32));
+            },
+            c => sink.push(c),
+        }
+    }
+    sink.push('"');
+}
+
+/// Returns `true` if `value` can be written as a block string whose
+/// [block string value](https://spec.graphql.org/September2025/#BlockStringValue())
+/// is exactly `value`.
+///
+/// Block strings cannot represent control characters (other than
+/// tab and line feed), carriage returns, leading or trailing blank
+/// lines, or indentation common to every line after the first, since
+/// block-string processing would alter or reject them.
+pub(crate) fn is_printable_as_block_string(value: &str) -> bool {
+    let mut is_empty_line = true;
+    let mut has_indent = false;
+    let mut has_common_indent = true;
+    let mut seen_non_empty_line = false;
+    for c in value.chars() {
+        match c {
+            '\n' => {
+                if is_empty_line && !seen_non_empty_line {
+                    // Leading blank line.
+                    return false;
+                }
+                seen_non_empty_line = true;
+                is_empty_line = true;
+                has_indent = false;
+            },
+            '\t' | ' ' => has_indent |= is_empty_line,
+            c if c < '\u{20}' => return false,
+            _ => {
+                has_common_indent &= has_indent;
+                is_empty_line = false;
+            },
+        }
+    }
+    if value.is_empty() {
+        return true;
+    }
+    if is_empty_line {
+        // Trailing blank line.
+        return false;
+    }
+    !(has_common_indent && seen_non_empty_line)
+}
+
+/// Appends `value` as a block string. `value` must satisfy
+/// [`is_printable_as_block_string()`].
+pub(crate) fn append_block_string(sink: &mut String, value: &str) {
+    let escaped = value.replace("\"\"\"", "\\\"\"\"");
+    let mut lines = escaped.split('\n');
+    let first_line = lines.next().unwrap_or_default();
+    let is_single_line = !escaped.contains('\n');
+    // Without a leading line break, indentation on the lines after
+    // the first would be stripped as common indentation.
+    let force_leading_newline = !is_single_line
+        && lines.all(|line| line.is_empty() || line.starts_with([' ', '\t']));
+    let has_trailing_triple_quotes = escaped.ends_with("\\\"\"\"");
+    let has_trailing_quote = value.ends_with('"') && !has_trailing_triple_quotes;
+    let has_trailing_backslash = value.ends_with('\\');
+    let force_trailing_newline = has_trailing_quote || has_trailing_backslash;
+    let print_as_multiple_lines = !is_single_line
+        || force_trailing_newline
+        || force_leading_newline
+        || has_trailing_triple_quotes;
+    // A single indented line must stay on the opening line, or its
+    // indentation would be stripped.
+    let skip_leading_newline = is_single_line
+        && first_line.starts_with([' ', '\t']);
+
+    sink.push_str("\"\"\"");
+    if (print_as_multiple_lines && !skip_leading_newline) || force_leading_newline {
+        sink.push('\n');
+    }
+    sink.push_str(&escaped);
+    if print_as_multiple_lines || force_trailing_newline {
+        sink.push('\n');
+    }
+    sink.push_str("\"\"\"");
+}
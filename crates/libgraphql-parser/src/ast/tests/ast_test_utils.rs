@@ -37,6 +37,7 @@ pub fn make_name<'a>(
     Name {
         value: Cow::Borrowed(value),
         span: make_byte_span(start, end),
+        symbol: None,
         syntax: None,
     }
 }
@@ -51,3 +51,23 @@ fn directive_location_executable_kind() {
     dl.append_source(&mut sink, Some(source));
     assert_eq!(sink, "QUERY");
 }
+
+/// Verify `DirectiveLocationKind::as_str()` returns the SDL
+/// spelling of each location.
+///
+/// Relevant spec section:
+/// https://spec.graphql.org/September2025/#DirectiveLocations
+///
+/// Written by Claude Code, reviewed by a human.
+#[test]
+fn directive_location_kind_as_str() {
+    assert_eq!(DirectiveLocationKind::Query.as_str(), "QUERY");
+    assert_eq!(
+        DirectiveLocationKind::InputFieldDefinition.as_str(),
+        "INPUT_FIELD_DEFINITION",
+    );
+    assert_eq!(
+        DirectiveLocationKind::VariableDefinition.as_str(),
+        "VARIABLE_DEFINITION",
+    );
+}
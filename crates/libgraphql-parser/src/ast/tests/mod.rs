@@ -40,6 +40,7 @@ mod schema_extension_tests;
 mod selection_set_tests;
 mod selection_tests;
 mod string_value_tests;
+mod synthetic_source_tests;
 mod type_annotation_tests;
 mod type_condition_tests;
 mod type_definition_tests;
@@ -27,3 +27,25 @@ fn string_value_construct_and_source_slice() {
     sv.append_source(&mut sink, Some(source));
     assert_eq!(sink, r#""hello""#);
 }
+
+/// Verify the public string-printing helpers pick a block string
+/// only when it round-trips, and escape quoted strings otherwise.
+///
+/// Relevant spec section:
+/// https://spec.graphql.org/September2025/#sec-String-Value
+///
+/// Written by Claude Code, reviewed by a human.
+#[test]
+fn string_value_public_printing_helpers() {
+    assert!(StringValue::is_printable_as_block_string("a\nb"));
+    assert!(!StringValue::is_printable_as_block_string("a\r\nb"));
+    assert!(!StringValue::is_printable_as_block_string("\nleading"));
+
+    let mut sink = String::new();
+    StringValue::append_block_string(&mut sink, "a\nb");
+    assert_eq!(sink, "\"\"\"\na\nb\n\"\"\"");
+
+    let mut sink = String::new();
+    StringValue::append_quoted_string(&mut sink, "say \"hi\"\n");
+    assert_eq!(sink, r#""say \"hi\"\n""#);
+}
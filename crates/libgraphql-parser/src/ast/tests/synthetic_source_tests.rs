@@ -0,0 +1,224 @@
+//! Tests for the synthetic-formatting mode of
+//! [`crate::ast::AstNode::append_source`] (i.e. `source: None`).
+
+use crate::ast::AstNode;
+use crate::ast::Definition;
+use crate::ast::Document;
+use crate::ast::TypeDefinition;
+use crate::GraphQLParser;
+
+fn print_schema(source: &str) -> String {
+    let result = GraphQLParser::new(source).parse_schema_document();
+    let (doc, _) = result.valid().expect("source should parse");
+    doc.to_source(None)
+}
+
+fn print_executable(source: &str) -> String {
+    let result = GraphQLParser::new(source).parse_executable_document();
+    let (doc, _) = result.valid().expect("source should parse");
+    doc.to_source(None)
+}
+
+fn first_description(doc: &Document<'_>) -> String {
+    let Definition::TypeDefinition(TypeDefinition::Scalar(scalar)) = &doc.definitions[0] else {
+        panic!("Expected a scalar type definition");
+    };
+    scalar.description.as_ref().unwrap().value.to_string()
+}
+
+/// Verify that type-system definitions print with conventional
+/// formatting, independent of the original whitespace and commas.
+///
+/// Written by Claude Code, reviewed by a human.
+#[test]
+fn synthetic_source_schema_document() {
+    let source = "
+        schema @a { query: Q, mutation: M }
+        extend schema @b
+        \"Q doc\" type Q implements & A & B @c(x: 1) {
+            f(a: Int = 1 @d, b: [String!]!): [Q]!  g: Q
+        }
+        extend type Q { h: Int }
+        interface A implements B { f: Int }
+        union U = | Q | M
+        enum E @e { \"one\" ONE TWO @deprecated }
+        input I @oneOf { a: Int b: I = {a: 1, b: null} }
+        scalar S
+        extend scalar S @f
+        directive @d(\"arg doc\" a: Int) repeatable on FIELD | QUERY
+    ";
+    assert_eq!(
+        print_schema(source),
+        r#"schema @a {
+  query: Q
+  mutation: M
+}
+
+extend schema @b
+
+"Q doc"
+type Q implements A & B @c(x: 1) {
+  f(a: Int = 1 @d, b: [String!]!): [Q]!
+  g: Q
+}
+
+extend type Q {
+  h: Int
+}
+
+interface A implements B {
+  f: Int
+}
+
+union U = Q | M
+
+enum E @e {
+  "one"
+  ONE
+  TWO @deprecated
+}
+
+input I @oneOf {
+  a: Int
+  b: I = {a: 1, b: null}
+}
+
+scalar S
+
+extend scalar S @f
+
+directive @d(
+  "arg doc"
+  a: Int
+) repeatable on FIELD | QUERY
+"#,
+    );
+}
+
+/// Verify that executable definitions print with nested selection
+/// sets indented, and that shorthand and anonymous `query`
+/// operations stay distinct.
+///
+/// Written by Claude Code, reviewed by a human.
+#[test]
+fn synthetic_source_executable_document() {
+    let source = "
+        { a }
+        query { b }
+        query Q($v: [Int!] = [1, 2] @x, $w: E = RED) @y {
+            alias: f(a: $v, b: \"s\", c: 1.5, d: true) @z {
+                ... on T { g }
+                ... @skip(if: false) { h }
+                ...F
+            }
+        }
+        fragment F on T { i }
+    ";
+    assert_eq!(
+        print_executable(source),
+        r#"{
+  a
+}
+
+query {
+  b
+}
+
+query Q($v: [Int!] = [1, 2] @x, $w: E = RED) @y {
+  alias: f(a: $v, b: "s", c: 1.5, d: true) @z {
+    ... on T {
+      g
+    }
+    ... @skip(if: false) {
+      h
+    }
+    ...F
+  }
+}
+
+fragment F on T {
+  i
+}
+"#,
+    );
+}
+
+/// Verify that quoted strings escape quotes, backslashes and control
+/// characters so that re-parsing yields the same value.
+///
+/// Relevant spec section:
+/// https://spec.graphql.org/September2025/#sec-String-Value
+///
+/// Written by Claude Code, reviewed by a human.
+#[test]
+fn synthetic_source_quoted_string_escapes() {
+    let printed = print_schema(r#""a\"b\\c\nd\te\u0001\u{1F600}" scalar S"#);
+    assert_eq!(printed, "\"a\\\"b\\\\c\\nd\\te\\u0001\u{1F600}\"\nscalar S\n");
+
+    let reparsed = GraphQLParser::new(&printed).parse_schema_document();
+    let (doc, _) = reparsed.valid().unwrap();
+    assert_eq!(first_description(doc), "a\"b\\c\nd\te\u{1}\u{1F600}");
+}
+
+/// Verify that block strings keep their value through a print and
+/// re-parse, including values whose indentation, trailing quotes or
+/// embedded `"""` need special handling.
+///
+/// Relevant spec section:
+/// https://spec.graphql.org/September2025/#sec-String-Value
+///
+/// Written by Claude Code, reviewed by a human.
+#[test]
+fn synthetic_source_block_strings_round_trip() {
+    let sources = [
+        "\"\"\"simple\"\"\" scalar S",
+        "\"\"\"  indented first line\"\"\" scalar S",
+        "\"\"\"\n  first\n    nested\n  last\n\"\"\" scalar S",
+        "\"\"\"  a\n  b\"\"\" scalar S",
+        "\"\"\"\nends with quote\"\n\"\"\" scalar S",
+        "\"\"\"\nends with backslash\\\n\"\"\" scalar S",
+        "\"\"\"has \\\"\"\" inside\"\"\" scalar S",
+        "\"\"\"\"\"\" scalar S",
+    ];
+    for source in sources {
+        let original = GraphQLParser::new(source).parse_schema_document();
+        let (original_doc, _) = original.valid().unwrap();
+        let printed = original_doc.to_source(None);
+        let reparsed = GraphQLParser::new(&printed).parse_schema_document();
+        let (reparsed_doc, _) = reparsed.valid().unwrap_or_else(|| {
+            panic!("printed source should parse:\n{printed}")
+        });
+        assert_eq!(
+            first_description(reparsed_doc),
+            first_description(original_doc),
+            "printed source:\n{printed}",
+        );
+        assert!(printed.starts_with("\"\"\""), "printed source:\n{printed}");
+    }
+}
+
+/// Verify that block strings nested inside indented selection sets
+/// keep their value, since indentation added by the printer is
+/// stripped again as common indentation.
+///
+/// Written by Claude Code, reviewed by a human.
+#[test]
+fn synthetic_source_block_string_in_nested_selection() {
+    let source = "{ a { b(arg: \"\"\"\n  line one\n    line two\n\"\"\") } }";
+    let printed = print_executable(source);
+    assert_eq!(printed, print_executable(&printed));
+    assert_eq!(
+        printed,
+        "{\n  a {\n    b(arg: \"\"\"\n    line one\n      line two\n    \"\"\")\n  }\n}\n",
+    );
+}
+
+/// Verify that floats print in a form that lexes as a float and
+/// parses back to the same value.
+///
+/// Written by Claude Code, reviewed by a human.
+#[test]
+fn synthetic_source_float_values() {
+    let printed = print_executable("{ f(a: 1.0, b: 1e21, c: -2.5e-8, d: 0.1) }");
+    assert!(printed.contains("f(a: 1.0, b: 1e21, c: -2.5e-8, d: 0.1)"), "{printed}");
+}
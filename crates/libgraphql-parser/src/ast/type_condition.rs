@@ -38,6 +38,9 @@ impl AstNode for TypeCondition<'_> {
             append_span_source_slice(
                 self.span, sink, src,
             );
+        } else {
+            sink.push_str("on ");
+            self.named_type.append_source(sink, None);
         }
     }
 
@@ -3,6 +3,9 @@ use crate::ast::AstNode;
 use crate::ast::DirectiveAnnotation;
 use crate::ast::Name;
 use crate::ast::StringValue;
+use crate::ast::synthetic_source::append_description;
+use crate::ast::synthetic_source::append_directives;
+use crate::ast::synthetic_source::append_union_members;
 use crate::ByteSpan;
 use crate::SourceMap;
 use crate::SourceSpan;
@@ -56,6 +59,12 @@ impl AstNode for UnionTypeDefinition<'_> {
             append_span_source_slice(
                 self.span, sink, src,
             );
+        } else {
+            append_description(sink, self.description.as_ref());
+            sink.push_str("union ");
+            self.name.append_source(sink, None);
+            append_directives(sink, &self.directives);
+            append_union_members(sink, &self.members);
         }
     }
 
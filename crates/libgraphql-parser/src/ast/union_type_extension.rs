@@ -2,6 +2,8 @@ use crate::ast::ast_node::append_span_source_slice;
 use crate::ast::AstNode;
 use crate::ast::DirectiveAnnotation;
 use crate::ast::Name;
+use crate::ast::synthetic_source::append_directives;
+use crate::ast::synthetic_source::append_union_members;
 use crate::ByteSpan;
 use crate::SourceMap;
 use crate::SourceSpan;
@@ -64,6 +66,11 @@ impl AstNode for UnionTypeExtension<'_> {
             append_span_source_slice(
                 self.span, sink, src,
             );
+        } else {
+            sink.push_str("extend union ");
+            self.name.append_source(sink, None);
+            append_directives(sink, &self.directives);
+            append_union_members(sink, &self.members);
         }
     }
 
@@ -49,6 +49,9 @@ impl AstNode for VariableReference<'_> {
             append_span_source_slice(
                 self.span, sink, src,
             );
+        } else {
+            sink.push('$');
+            self.name.append_source(sink, None);
         }
     }
 
@@ -533,6 +533,7 @@ impl<'src> FromGpContext<'src> {
     ) -> ast::Name<'static> {
         ast::Name {
             span: self.zero_span(),
+            symbol: None,
             syntax: None,
             value: Cow::Owned(value.to_owned()),
         }
@@ -547,6 +548,7 @@ impl<'src> FromGpContext<'src> {
     ) -> ast::Name<'static> {
         ast::Name {
             span: self.span_from_pos(pos),
+            symbol: None,
             syntax: None,
             value: Cow::Owned(value.to_owned()),
         }
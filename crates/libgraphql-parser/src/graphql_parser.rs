@@ -29,6 +29,7 @@ use crate::GraphQLTokenStream;
 use crate::ParseResult;
 use crate::ReservedNameContext;
 use crate::SourceSpan;
+use crate::Symbol;
 use crate::ValueParsingError;
 use crate::token::GraphQLToken;
 use crate::token::GraphQLTokenKind;
@@ -640,7 +641,7 @@ impl<'src, TTokenSource: GraphQLTokenSource<'src>> GraphQLParser<'src, TTokenSou
             return Err(());
         }
         let token = self.consume_token().unwrap();
-        if self.config.retain_syntax {
+        let name = if self.config.retain_syntax {
             let value = match &token.kind {
                 GraphQLTokenKind::Name(s) => s.clone(),
                 GraphQLTokenKind::True => Cow::Borrowed("true"),
@@ -649,11 +650,12 @@ impl<'src, TTokenSource: GraphQLTokenSource<'src>> GraphQLParser<'src, TTokenSou
                 _ => unreachable!(),
             };
             let span = token.span;
-            Ok(ast::Name {
+            ast::Name {
                 span,
+                symbol: None,
                 syntax: Some(Box::new(ast::NameSyntax { token })),
                 value,
-            })
+            }
         } else {
             let value = match token.kind {
                 GraphQLTokenKind::Name(s) => s,
@@ -662,11 +664,18 @@ impl<'src, TTokenSource: GraphQLTokenSource<'src>> GraphQLParser<'src, TTokenSou
                 GraphQLTokenKind::Null => Cow::Borrowed("null"),
                 _ => unreachable!(),
             };
-            Ok(ast::Name {
+            ast::Name {
                 span: token.span,
+                symbol: None,
                 syntax: None,
                 value,
-            })
+            }
+        };
+        if self.config.intern_names {
+            let symbol = Symbol::intern(&name.value);
+            Ok(ast::Name { symbol: Some(symbol), ..name })
+        } else {
+            Ok(name)
         }
     }
 
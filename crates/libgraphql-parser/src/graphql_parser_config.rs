@@ -16,9 +16,26 @@
 /// // Lean mode: skip populating syntax structs
 /// let lean = GraphQLParserConfig::lean();
 /// assert!(!lean.retain_syntax);
+///
+/// // Record an interned `Symbol` on every name
+/// let interning = GraphQLParserConfig {
+///     intern_names: true,
+///     ..GraphQLParserConfig::lean()
+/// };
+/// # assert!(interning.intern_names);
 /// ```
 #[derive(Clone, Debug, PartialEq)]
 pub struct GraphQLParserConfig {
+    /// Whether the parser should intern every [`ast::Name`](crate::ast::Name)
+    /// it produces and record the resulting [`Symbol`](crate::Symbol)
+    /// in the name's `symbol` field.
+    ///
+    /// All occurrences of a name within a document (and across
+    /// documents, while any of their symbols are alive) then share one
+    /// interned string, and [`ast::Name::symbol()`](crate::ast::Name::symbol)
+    /// no longer needs to consult the interner. Defaults to `false`.
+    pub intern_names: bool,
+
     /// Whether the parser should populate `*Syntax` structs on AST
     /// nodes with the concrete tokens that make up each construct
     /// (punctuation, keywords, etc.).
@@ -33,6 +50,7 @@ impl GraphQLParserConfig {
     /// Useful when only semantic AST data is needed.
     pub fn lean() -> Self {
         Self {
+            intern_names: false,
             retain_syntax: false,
         }
     }
@@ -41,6 +59,7 @@ impl GraphQLParserConfig {
 impl Default for GraphQLParserConfig {
     fn default() -> Self {
         Self {
+            intern_names: false,
             retain_syntax: true,
         }
     }
@@ -68,6 +68,9 @@
 //! This crate provides a unified token-based parser infrastructure with support for multiple token
 //! sources (string input, proc-macro input, etc.).
 
+mod arena_graphql_parser;
+#[cfg(any(test, feature = "arbitrary"))]
+pub mod arbitrary;
 pub mod ast;
 mod byte_span;
 pub mod compat;
@@ -85,9 +88,11 @@ mod reserved_name_context;
 pub mod smallvec;
 mod source_map;
 mod source_position;
+mod symbol;
 pub mod token;
 mod value_parsing_error;
 
+pub use arena_graphql_parser::ArenaGraphQLParser;
 pub use byte_span::ByteSpan;
 pub use graphql_error_note::GraphQLErrorNote;
 pub use graphql_error_note_kind::GraphQLErrorNoteKind;
@@ -102,6 +107,7 @@ pub use parse_result::ParseResult;
 pub use reserved_name_context::ReservedNameContext;
 pub use source_map::SourceMap;
 pub use source_position::SourcePosition;
+pub use symbol::Symbol;
 pub use value_parsing_error::ValueParsingError;
 
 /// Parses a schema document from a string.
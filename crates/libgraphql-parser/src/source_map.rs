@@ -169,8 +169,8 @@ impl<'src> SourceMapData<'src> {
 ///
 /// Some token sources do not have access to the underlying source text at
 /// resolution time. For example,
-/// [`libgraphql_macros::RustMacroGraphQLTokenSource`](https://docs.rs/libgraphql-macros)
-/// produces tokens from a `proc_macro2::TokenStream`. Each `proc_macro2::Span`
+/// `token::RustMacroGraphQLTokenSource` (available with the `proc-macro2`
+/// feature) produces tokens from a `proc_macro2::TokenStream`. Each `proc_macro2::Span`
 /// carries line/column information at the time the token is produced, but there
 /// is no contiguous source `&str` to scan after the fact. In this mode, the
 /// token source collects `(byte_offset, SourcePosition)` entries during lexing
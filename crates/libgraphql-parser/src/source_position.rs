@@ -25,9 +25,9 @@
 /// - **`col_utf16`** (optional): UTF-16 code unit offset within the line. This
 ///   aligns with LSP (Language Server Protocol) and many editors. It is `Some`
 ///   when the token source can provide it (e.g. `StrToGraphQLTokenSource`),
-///   and `None` when it cannot (e.g. `RustMacroGraphQLTokenSource` in
-///   `libgraphql-macros` which uses `proc_macro2::Span` that only provides
-///   UTF-8 char-based positions).
+///   and `None` when it cannot (e.g. `RustMacroGraphQLTokenSource`, which
+///   uses `proc_macro2::Span` that only provides UTF-8 char-based
+///   positions).
 ///
 /// For ASCII text, both columns are equal. For text containing characters
 /// outside the Basic Multilingual Plane (e.g., emoji), they differ:
@@ -115,7 +115,7 @@ impl SourcePosition {
     ///
     /// This is `Some` when the token source can provide UTF-16 column
     /// information (e.g., `StrToGraphQLTokenSource`), and `None` when it
-    /// cannot (e.g., `RustMacroGraphQLTokenSource` in `libgraphql-macros`).
+    /// cannot (e.g., `RustMacroGraphQLTokenSource`).
     ///
     /// For example, 'a' (1 UTF-16 code unit) adds 1 to this count, while '🎉'
     /// (a surrogate pair requiring 2 UTF-16 code units) adds 2 to this count.
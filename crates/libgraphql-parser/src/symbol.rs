@@ -0,0 +1,266 @@
+use smallvec::SmallVec;
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::hash::BuildHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::hash::RandomState;
+use std::sync::Arc;
+use std::sync::LazyLock;
+use std::sync::PoisonError;
+use std::sync::RwLock;
+use std::sync::Weak;
+
+/// Minimum number of table entries before the interner sweeps out
+/// entries whose strings have been freed.
+const MIN_SWEEP_THRESHOLD: usize = 1024;
+
+/// Process-wide table of live interned strings.
+static INTERNER: LazyLock<Interner> = LazyLock::new(|| Interner {
+    hasher: RandomState::new(),
+    table: RwLock::new(InternerTable {
+        buckets: HashMap::new(),
+        len: 0,
+        sweep_threshold: MIN_SWEEP_THRESHOLD,
+    }),
+});
+
+struct Interner {
+    hasher: RandomState,
+    table: RwLock<InternerTable>,
+}
+
+/// Weak references to interned strings, bucketed by content hash.
+///
+/// The table never keeps a string alive on its own: once the last
+/// [`Symbol`] for a string is dropped, its entry becomes dead and
+/// is removed either when its bucket is next written to or by the
+/// next full sweep. Sweeps run whenever the number of entries
+/// doubles since the previous sweep, so the table stays
+/// proportional to the number of live symbols.
+struct InternerTable {
+    buckets: HashMap<u64, SmallVec<[Weak<str>; 1]>>,
+    len: usize,
+    sweep_threshold: usize,
+}
+
+impl InternerTable {
+    fn get(&self, hash: u64, s: &str) -> Option<Arc<str>> {
+        self.buckets.get(&hash)?
+            .iter()
+            .filter_map(Weak::upgrade)
+            .find(|interned| &**interned == s)
+    }
+
+    fn sweep(&mut self) {
+        self.buckets.retain(|_, bucket| {
+            bucket.retain(|entry| entry.strong_count() > 0);
+            !bucket.is_empty()
+        });
+        self.len = self.buckets.values().map(SmallVec::len).sum();
+        self.sweep_threshold = (self.len * 2).max(MIN_SWEEP_THRESHOLD);
+    }
+}
+
+/// A handle to an interned string, typically a GraphQL
+/// [name](https://spec.graphql.org/September2025/#sec-Names).
+///
+/// Each distinct string is stored once in a process-wide interner
+/// for as long as any `Symbol` refers to it, so a `Symbol` is just
+/// a reference-counted pointer into that storage:
+///
+/// - Equality is a pointer comparison rather than a string
+///   comparison.
+/// - Many occurrences of the same name (e.g. `String`, `id`,
+///   `node`) share a single allocation.
+/// - Cloning a `Symbol` bumps a reference count rather than copying
+///   the string.
+///
+/// [`Hash`] and [`Ord`] are computed from the string contents, so a
+/// `Symbol` hashes like its `str` (making [`Borrow<str>`] lookups in
+/// hash maps work) and sorts lexicographically.
+///
+/// # Retention
+///
+/// The interner only holds weak references. A string is freed as
+/// soon as the last `Symbol` for it is dropped, and interning the
+/// same contents afterwards allocates it afresh. Memory used by the
+/// interner is therefore proportional to the names that are still
+/// in use (e.g. by a live schema), not to every name a process has
+/// ever seen, so interning names from untrusted documents does not
+/// grow memory without bound.
+///
+/// # Example
+///
+/// ```rust
+/// use libgraphql_parser::Symbol;
+///
+/// let a = Symbol::intern("User");
+/// let b = Symbol::intern(&String::from("User"));
+/// assert_eq!(a, b);
+/// assert_eq!(a.as_str(), "User");
+/// assert!(std::ptr::eq(a.as_str(), b.as_str()));
+/// ```
+#[derive(Clone)]
+pub struct Symbol(Arc<str>);
+
+impl Symbol {
+    /// Interns `s`, returning the [`Symbol`] that identifies it.
+    ///
+    /// Interning a string that is already live only performs a
+    /// hash-table lookup under a shared read lock.
+    pub fn intern(s: &str) -> Self {
+        let hash = INTERNER.hasher.hash_one(s);
+        if let Some(interned) = INTERNER.table
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(hash, s)
+        {
+            return Self(interned);
+        }
+        let mut table = INTERNER.table
+            .write()
+            .unwrap_or_else(PoisonError::into_inner);
+        // Another thread may have interned `s` between releasing the
+        // read lock and acquiring the write lock.
+        if let Some(interned) = table.get(hash, s) {
+            return Self(interned);
+        }
+        let interned: Arc<str> = Arc::from(s);
+        let bucket = table.buckets.entry(hash).or_default();
+        let len_before = bucket.len();
+        bucket.retain(|entry| entry.strong_count() > 0);
+        bucket.push(Arc::downgrade(&interned));
+        let len_after = bucket.len();
+        table.len = table.len + len_after - len_before;
+        if table.len >= table.sweep_threshold {
+            table.sweep();
+        }
+        Self(interned)
+    }
+
+    /// Returns the interned string.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns whether `s` is currently interned, i.e. whether any
+    /// live `Symbol` refers to it.
+    #[cfg(test)]
+    pub(crate) fn is_interned(s: &str) -> bool {
+        let hash = INTERNER.hasher.hash_one(s);
+        INTERNER.table
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(hash, s)
+            .is_some()
+    }
+
+    /// Returns the number of entries (live or not yet swept) in the
+    /// interner's table.
+    #[cfg(test)]
+    pub(crate) fn table_len() -> usize {
+        INTERNER.table
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .len
+    }
+}
+
+impl PartialEq for Symbol {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for Symbol {}
+
+impl Hash for Symbol {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state)
+    }
+}
+
+impl PartialOrd for Symbol {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Symbol {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self == other {
+            Ordering::Equal
+        } else {
+            self.0.cmp(&other.0)
+        }
+    }
+}
+
+impl AsRef<str> for Symbol {
+    fn as_ref(&self) -> &str { &self.0 }
+}
+
+impl Borrow<str> for Symbol {
+    fn borrow(&self) -> &str { &self.0 }
+}
+
+impl std::fmt::Debug for Symbol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(&*self.0, f)
+    }
+}
+
+impl std::fmt::Display for Symbol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<&str> for Symbol {
+    fn from(s: &str) -> Self { Self::intern(s) }
+}
+
+impl From<String> for Symbol {
+    fn from(s: String) -> Self { Self::intern(&s) }
+}
+
+impl serde::Serialize for Symbol {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Symbol {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        struct SymbolVisitor;
+
+        impl serde::de::Visitor<'_> for SymbolVisitor {
+            type Value = Symbol;
+
+            fn expecting(
+                &self,
+                f: &mut std::fmt::Formatter<'_>,
+            ) -> std::fmt::Result {
+                f.write_str("a string")
+            }
+
+            fn visit_str<E: serde::de::Error>(
+                self,
+                s: &str,
+            ) -> Result<Symbol, E> {
+                Ok(Symbol::intern(s))
+            }
+        }
+
+        deserializer.deserialize_str(SymbolVisitor)
+    }
+}
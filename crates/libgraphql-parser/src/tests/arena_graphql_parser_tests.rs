@@ -0,0 +1,384 @@
+//! Tests for `ArenaGraphQLParser`.
+//!
+//! The arena parser must accept exactly the documents that the
+//! standard `GraphQLParser` accepts, and must produce a tree that
+//! is shape-equivalent to the lean `ast::Document`. Most tests
+//! here therefore parse the same source both ways and compare
+//! `arena_doc.to_ast()` against the lean parse result.
+
+use crate::ArenaGraphQLParser;
+use crate::GraphQLParser;
+use crate::GraphQLParserConfig;
+use crate::ast;
+use crate::ast::arena;
+use bumpalo::Bump;
+use std::path::Path;
+use std::path::PathBuf;
+
+// =============================================================================
+// Helpers
+// =============================================================================
+
+#[derive(Clone, Copy)]
+enum Kind {
+    Executable,
+    Mixed,
+    Schema,
+}
+
+fn parse_lean(source: &str, kind: Kind) -> crate::ParseResult<'_, ast::Document<'_>> {
+    let parser = GraphQLParser::with_config(source, GraphQLParserConfig::lean());
+    match kind {
+        Kind::Executable => parser.parse_executable_document(),
+        Kind::Mixed => parser.parse_mixed_document(),
+        Kind::Schema => parser.parse_schema_document(),
+    }
+}
+
+/// Parses `source` with both parsers and asserts that the arena
+/// document converts to exactly the lean AST.
+fn assert_matches_lean(source: &str, kind: Kind) {
+    let bump = Bump::new();
+    let parser = ArenaGraphQLParser::new(source, &bump);
+    let arena_result = match kind {
+        Kind::Executable => parser.parse_executable_document(),
+        Kind::Mixed => parser.parse_mixed_document(),
+        Kind::Schema => parser.parse_schema_document(),
+    };
+    let lean_result = parse_lean(source, kind);
+    assert!(
+        !lean_result.has_errors(),
+        "Lean parse failed:\n{}",
+        lean_result.formatted_errors(),
+    );
+    let (arena_doc, _) = arena_result.unwrap_or_else(|errors| {
+        panic!("Arena parse failed: {errors:?}")
+    });
+    let (lean_doc, _) = lean_result.into_valid().unwrap();
+    assert_eq!(arena_doc.to_ast(), lean_doc);
+}
+
+/// Parses `source` with both parsers and asserts that both reject
+/// it with identical errors.
+fn assert_errors_match_lean(source: &str, kind: Kind) {
+    let bump = Bump::new();
+    let parser = ArenaGraphQLParser::new(source, &bump);
+    let arena_result = match kind {
+        Kind::Executable => parser.parse_executable_document(),
+        Kind::Mixed => parser.parse_mixed_document(),
+        Kind::Schema => parser.parse_schema_document(),
+    };
+    let lean_result = parse_lean(source, kind);
+    let Err(arena_errors) = arena_result else {
+        panic!("Expected arena parse of {source:?} to fail");
+    };
+    assert!(!arena_errors.is_empty());
+    assert_eq!(
+        format!("{arena_errors:?}"),
+        format!("{:?}", lean_result.errors()),
+    );
+}
+
+/// Parses `source` with both parsers and asserts that they agree:
+/// either both accept it with identical ASTs, or both reject it
+/// with identical errors.
+fn assert_parsers_agree(source: &str, kind: Kind) {
+    if parse_lean(source, kind).has_errors() {
+        assert_errors_match_lean(source, kind);
+    } else {
+        assert_matches_lean(source, kind);
+    }
+}
+
+/// Recursively collects every `.graphql` file under `dir`,
+/// skipping build output.
+fn collect_graphql_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            if path.file_name().is_some_and(|name| name != "target") {
+                collect_graphql_files(&path, files);
+            }
+        } else if path.extension().is_some_and(|ext| ext == "graphql") {
+            files.push(path);
+        }
+    }
+}
+
+const SCHEMA_SOURCE: &str = r#"
+"""
+The schema
+"""
+schema @link(url: "https://example.com") {
+  query: Query
+  mutation: Mutation
+}
+
+"A custom scalar"
+scalar DateTime @specifiedBy(url: "https://example.com/datetime")
+
+interface Node { id: ID! }
+
+interface Entity implements Node & Named {
+  id: ID!
+  name(locale: String = "en_US"): String
+}
+
+type User implements & Node & Entity @key(fields: "id") {
+  "The ID"
+  id: ID!
+  name(locale: String = "en_US" @deprecated): String
+  friends(first: Int = 10, after: [String!] = ["a", "b"]): [User!]!
+  settings(filter: Filter = { enabled: true, tags: [RED], limit: null }): Json
+}
+
+union SearchResult @tag(name: "search") = | User | Post
+
+enum Color { RED GREEN "blue" BLUE @deprecated(reason: "no") }
+
+input Filter {
+  enabled: Boolean = false
+  tags: [Color!]
+  score: Float = -1.5e3
+}
+
+directive @key(fields: String!) repeatable on OBJECT | INTERFACE
+directive @tag(name: String!) on | FIELD_DEFINITION | UNION | ENUM_VALUE
+
+extend schema @extra { subscription: Subscription }
+extend scalar DateTime @extra
+extend type User implements Named @extra { age: Int }
+extend interface Node @extra { createdAt: DateTime }
+extend union SearchResult = Comment
+extend enum Color { YELLOW }
+extend input Filter { limit: Int }
+"#;
+
+const EXECUTABLE_SOURCE: &str = r#"
+query GetUser($id: ID!, $withFriends: Boolean = false @var, $f: Filter = { tags: [RED] }) @op {
+  alias: user(id: $id) {
+    id
+    ... on User @include(if: $withFriends) {
+      friends(first: 10) { ...UserFields }
+    }
+    ... @skip(if: true) { name }
+    ... { id }
+    ...UserFields @defer
+    score(min: -2, max: 3.25, label: "x\ny\u00e9", block: """
+      block
+        string
+    """, nothing: null, on: on)
+  }
+}
+
+mutation { like(id: "1") }
+subscription OnEvent { event { id } }
+{ shorthand }
+
+fragment UserFields on User @frag {
+  id
+  name
+}
+"#;
+
+// =============================================================================
+// Equivalence with the lean AST
+// =============================================================================
+
+/// A schema document exercising every type-system construct
+/// converts to exactly the lean AST.
+#[test]
+fn schema_document_matches_lean_ast() {
+    assert_matches_lean(SCHEMA_SOURCE, Kind::Schema);
+}
+
+/// An executable document exercising operations, variables,
+/// fragments, and every value kind converts to exactly the lean
+/// AST.
+#[test]
+fn executable_document_matches_lean_ast() {
+    assert_matches_lean(EXECUTABLE_SOURCE, Kind::Executable);
+}
+
+/// Mixed documents (including a description preceding an
+/// executable definition) convert to exactly the lean AST.
+#[test]
+fn mixed_document_matches_lean_ast() {
+    let source = format!(
+        "{SCHEMA_SOURCE}\n\"ignored description\" query Q {{ a }}\n{EXECUTABLE_SOURCE}",
+    );
+    assert_matches_lean(&source, Kind::Mixed);
+}
+
+/// Empty and whitespace/comment-only documents produce an empty
+/// definitions list, as with the standard parser.
+#[test]
+fn empty_documents_match_lean_ast() {
+    assert_matches_lean("", Kind::Schema);
+    assert_matches_lean("  # just a comment\n", Kind::Executable);
+    assert_matches_lean("\n", Kind::Mixed);
+}
+
+/// `true`/`false`/`null` are valid names outside value position.
+#[test]
+fn keyword_like_names_match_lean_ast() {
+    assert_matches_lean(
+        "type true { null: false, false(true: Int): Int }",
+        Kind::Schema,
+    );
+    assert_matches_lean("query null { true false: null }", Kind::Executable);
+}
+
+/// Every GraphQL fixture in the workspace (benchmark fixtures, fuzz
+/// seeds, and the schema/operation fixtures of the core crates)
+/// parses identically with both parsers, under every document kind.
+/// The arena parser is a second implementation of the grammar, so
+/// this keeps the two from drifting apart.
+#[test]
+fn workspace_fixtures_match_lean_parser() {
+    let crates_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    let mut files = vec![];
+    collect_graphql_files(&crates_dir, &mut files);
+    assert!(
+        files.len() >= 40,
+        "Expected to find the workspace fixtures, found {files:?}",
+    );
+    for path in files {
+        let source = std::fs::read_to_string(&path).unwrap();
+        for kind in [Kind::Executable, Kind::Mixed, Kind::Schema] {
+            let result = std::panic::catch_unwind(|| {
+                assert_parsers_agree(&source, kind);
+            });
+            assert!(
+                result.is_ok(),
+                "Parsers disagree on {}",
+                path.display(),
+            );
+        }
+    }
+}
+
+// =============================================================================
+// Arena document API
+// =============================================================================
+
+/// Nodes are reachable through arena slices and expose the same
+/// convenience accessors as the standard AST.
+#[test]
+fn arena_document_accessors() {
+    let bump = Bump::new();
+    let source = "type Query { hello(name: String): [String!]! }\nquery Q { hello }";
+    let (doc, _) = ArenaGraphQLParser::new(source, &bump)
+        .parse_mixed_document()
+        .unwrap();
+
+    assert_eq!(doc.definitions.len(), 2);
+    assert_eq!(doc.schema_definitions().count(), 1);
+    assert_eq!(doc.executable_definitions().count(), 1);
+    assert_eq!(doc.definitions[0].name_value(), Some("Query"));
+    assert_eq!(doc.definitions[1].name_value(), Some("Q"));
+
+    let arena::Definition::TypeDefinition(arena::TypeDefinition::Object(obj)) =
+        &doc.definitions[0]
+    else {
+        panic!("Expected an object type definition");
+    };
+    let field = &obj.fields[0];
+    assert_eq!(field.name_value(), "hello");
+    assert_eq!(field.parameters[0].name_value(), "name");
+    let arena::TypeAnnotation::List(list) = field.field_type else {
+        panic!("Expected a list type annotation");
+    };
+    assert!(!field.field_type.nullable());
+    assert!(!list.element_type.nullable());
+}
+
+/// Strings that contain escape sequences are unescaped into the
+/// arena; simple names borrow directly from the source.
+#[test]
+fn arena_strings_are_unescaped() {
+    let bump = Bump::new();
+    let source = r#"{ f(a: "a\tb") }"#;
+    let (doc, _) = ArenaGraphQLParser::new(source, &bump)
+        .parse_executable_document()
+        .unwrap();
+    let arena::Definition::OperationDefinition(op) = &doc.definitions[0] else {
+        panic!("Expected an operation definition");
+    };
+    let arena::Selection::Field(field) = &op.selection_set.selections[0] else {
+        panic!("Expected a field selection");
+    };
+    let arena::Value::String(value) = field.arguments[0].value else {
+        panic!("Expected a string value");
+    };
+    assert_eq!(value.value, "a\tb");
+    assert!(!value.is_block);
+
+    // Names are borrowed straight from the source text.
+    let name = field.name.value;
+    assert!(source.as_bytes().as_ptr_range().contains(&name.as_ptr()));
+}
+
+// =============================================================================
+// Error fallback
+// =============================================================================
+
+/// Syntax errors are reported exactly as the standard parser
+/// reports them (including errors it would recover from).
+#[test]
+fn syntax_errors_match_lean_parser() {
+    assert_errors_match_lean("type Query { a String }", Kind::Schema);
+    assert_errors_match_lean("type Query { a: String } type B {", Kind::Schema);
+    assert_errors_match_lean("{ a { } }", Kind::Executable);
+    assert_errors_match_lean("query Q() { a }", Kind::Executable);
+    assert_errors_match_lean("{ a() }", Kind::Executable);
+    assert_errors_match_lean("fragment on on User { a }", Kind::Executable);
+    assert_errors_match_lean("query Q($a: Int = $b) { a }", Kind::Executable);
+}
+
+/// Documents that tokenize fine but violate semantic rules
+/// enforced by the parser are rejected with the standard errors.
+#[test]
+fn semantic_parse_errors_match_lean_parser() {
+    assert_errors_match_lean("enum E { true }", Kind::Schema);
+    assert_errors_match_lean("schema { foo: Query }", Kind::Schema);
+    assert_errors_match_lean("directive @d on NOWHERE", Kind::Schema);
+    assert_errors_match_lean("{ a(x: 99999999999) }", Kind::Executable);
+    assert_errors_match_lean("{ a(x: \"\\q\") }", Kind::Executable);
+}
+
+/// Definitions of the wrong kind for the requested document kind
+/// are rejected with the standard errors.
+#[test]
+fn wrong_document_kind_errors_match_lean_parser() {
+    assert_errors_match_lean("type Query { a: Int }", Kind::Executable);
+    assert_errors_match_lean("{ a }", Kind::Schema);
+}
+
+/// Nesting beyond the recursion limit is rejected rather than
+/// overflowing the stack.
+#[test]
+fn deep_nesting_errors_match_lean_parser() {
+    let depth = 100;
+    let source = format!("{}a{}", "{ a ".repeat(depth), " }".repeat(depth));
+    assert_errors_match_lean(&source, Kind::Executable);
+
+    let source = format!("{{ a(x: {}1{}) }}", "[".repeat(depth), "]".repeat(depth));
+    assert_errors_match_lean(&source, Kind::Executable);
+}
+
+/// If the arena parser ever rejects a document that the standard
+/// parser accepts, it reports an error at the token it stopped on
+/// rather than an empty error list.
+#[test]
+fn divergence_error_points_at_current_token() {
+    let bump = Bump::new();
+    let mut parser = ArenaGraphQLParser::new("  type Query { a: Int }", &bump);
+    let error = parser.divergence_error();
+    assert_eq!(error.kind(), &crate::GraphQLParseErrorKind::InvalidSyntax);
+    assert_eq!(error.source_span().start_inclusive.byte_offset(), 2);
+    assert_eq!(error.source_span().end_exclusive.byte_offset(), 6);
+}
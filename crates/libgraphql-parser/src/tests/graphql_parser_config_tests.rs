@@ -1,5 +1,6 @@
-//! Tests for `GraphQLParserConfig` behavior, specifically the `retain_syntax`
-//! flag that controls whether `*Syntax` structs are populated on AST nodes.
+//! Tests for `GraphQLParserConfig` behavior: the `retain_syntax` flag that
+//! controls whether `*Syntax` structs are populated on AST nodes, and the
+//! `intern_names` flag that records a `Symbol` on every name.
 //!
 //! Written by Claude Code, reviewed by a human.
 
@@ -257,3 +258,62 @@ fn default_config_syntax_has_tokens_with_trivia() {
         panic!("Expected an OperationDefinition");
     }
 }
+
+/// Verifies that `intern_names` records a `Symbol` on every name, and
+/// that every occurrence of a name within one parse shares a single
+/// interned string.
+///
+/// Written by Claude Code, reviewed by a human.
+#[test]
+fn intern_names_config_records_shared_symbols() {
+    let source = "\
+        type config_tests_User { friend: config_tests_User } \
+        type Query { me: config_tests_User }";
+    let config = GraphQLParserConfig {
+        intern_names: true,
+        ..GraphQLParserConfig::lean()
+    };
+    let (doc, _) = GraphQLParser::with_config(source, config)
+        .parse_schema_document()
+        .into_valid()
+        .unwrap();
+
+    let mut user_names = vec![];
+    for def in &doc.definitions {
+        let ast::Definition::TypeDefinition(ast::TypeDefinition::Object(obj)) = def else {
+            panic!("Expected an object type definition");
+        };
+        assert_eq!(obj.name.symbol.as_ref().unwrap().as_str(), obj.name.value);
+        if obj.name.value == "config_tests_User" {
+            user_names.push(&obj.name);
+        }
+        for field in &obj.fields {
+            assert!(field.name.symbol.is_some());
+            if let ast::TypeAnnotation::Named(named) = &field.field_type {
+                user_names.push(&named.name);
+            }
+        }
+    }
+    assert_eq!(user_names.len(), 3);
+    let symbols: Vec<_> = user_names
+        .iter()
+        .map(|name| name.symbol.clone().unwrap())
+        .collect();
+    for symbol in &symbols {
+        assert_eq!(symbol, &symbols[0]);
+        assert!(std::ptr::eq(symbol.as_str(), symbols[0].as_str()));
+    }
+    // `Name::symbol()` hands back the recorded symbol.
+    assert!(std::ptr::eq(user_names[0].symbol().as_str(), symbols[0].as_str()));
+
+    // Without `intern_names`, no symbol is recorded and names borrow
+    // from the source text.
+    let (doc, _) = GraphQLParser::new(source)
+        .parse_schema_document()
+        .into_valid()
+        .unwrap();
+    let name = doc.definitions[0].name().unwrap();
+    assert!(name.symbol.is_none());
+    assert!(source.as_bytes().as_ptr_range().contains(&name.value.as_ptr()));
+    assert_eq!(name.symbol(), symbols[0]);
+}
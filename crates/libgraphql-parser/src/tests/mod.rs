@@ -1,3 +1,4 @@
+mod arena_graphql_parser_tests;
 mod ast_utils;
 mod byte_span_tests;
 mod graphql_parse_error_tests;
@@ -21,4 +22,5 @@ mod parse_result_tests;
 mod property_tests;
 mod source_map_tests;
 mod source_position_tests;
+mod symbol_tests;
 mod utils;
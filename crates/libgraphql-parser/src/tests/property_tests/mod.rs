@@ -7,7 +7,7 @@
 //!
 //! Written by Claude Code, reviewed by a human.
 
-mod generators;
+mod mutations;
 mod properties;
 
 use proptest::prelude::ProptestConfig;
@@ -10,8 +10,8 @@
 use proptest::prelude::*;
 
 use crate::ast;
-use crate::tests::property_tests::generators::documents::arb_executable_document;
-use crate::tests::property_tests::generators::documents::arb_schema_document;
+use crate::arbitrary::documents::arb_executable_document;
+use crate::arbitrary::documents::arb_schema_document;
 use crate::tests::property_tests::proptest_config;
 use crate::GraphQLParser;
 use crate::GraphQLParserConfig;
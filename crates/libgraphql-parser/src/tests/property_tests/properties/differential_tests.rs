@@ -17,10 +17,13 @@ use proptest::prelude::*;
 
 use crate::compat::graphql_parser_v0_4::to_graphql_parser_query_ast;
 use crate::compat::graphql_parser_v0_4::to_graphql_parser_schema_ast;
-use crate::tests::property_tests::generators::documents::arb_executable_document;
-use crate::tests::property_tests::generators::documents::arb_schema_document;
+use crate::arbitrary::documents::arb_executable_document;
+use crate::arbitrary::documents::arb_schema_document;
 use crate::tests::property_tests::proptest_config;
+use crate::ArenaGraphQLParser;
 use crate::GraphQLParser;
+use crate::GraphQLParserConfig;
+use bumpalo::Bump;
 
 proptest! {
     #![proptest_config(proptest_config())]
@@ -140,3 +143,49 @@ proptest! {
         }
     }
 }
+
+proptest! {
+    #![proptest_config(proptest_config())]
+
+    /// The arena parser must produce the same tree as the lean
+    /// standard parse for every generated schema document.
+    #[test]
+    fn schema_arena_parser_matches_lean_parser(
+        source in arb_schema_document(3)
+    ) {
+        let bump = Bump::new();
+        let arena_result =
+            ArenaGraphQLParser::new(&source, &bump).parse_schema_document();
+        let lean_result = GraphQLParser::with_config(
+            &source,
+            GraphQLParserConfig::lean(),
+        ).parse_schema_document();
+        prop_assert_eq!(arena_result.is_err(), lean_result.has_errors());
+        if let (Ok((arena_doc, _)), Some((lean_doc, _))) =
+            (arena_result, lean_result.valid())
+        {
+            prop_assert_eq!(&arena_doc.to_ast(), lean_doc, "Source:\n{}", source);
+        }
+    }
+
+    /// The arena parser must produce the same tree as the lean
+    /// standard parse for every generated executable document.
+    #[test]
+    fn executable_arena_parser_matches_lean_parser(
+        source in arb_executable_document(3)
+    ) {
+        let bump = Bump::new();
+        let arena_result =
+            ArenaGraphQLParser::new(&source, &bump).parse_executable_document();
+        let lean_result = GraphQLParser::with_config(
+            &source,
+            GraphQLParserConfig::lean(),
+        ).parse_executable_document();
+        prop_assert_eq!(arena_result.is_err(), lean_result.has_errors());
+        if let (Ok((arena_doc, _)), Some((lean_doc, _))) =
+            (arena_result, lean_result.valid())
+        {
+            prop_assert_eq!(&arena_doc.to_ast(), lean_doc, "Source:\n{}", source);
+        }
+    }
+}
@@ -9,9 +9,9 @@
 
 use proptest::prelude::*;
 
-use crate::tests::property_tests::generators::documents::arb_executable_document;
-use crate::tests::property_tests::generators::documents::arb_schema_document;
-use crate::tests::property_tests::generators::mutations::arb_reserved_fragment_name;
+use crate::arbitrary::documents::arb_executable_document;
+use crate::arbitrary::documents::arb_schema_document;
+use crate::tests::property_tests::mutations::arb_reserved_fragment_name;
 use crate::tests::property_tests::proptest_config;
 use crate::GraphQLParser;
 
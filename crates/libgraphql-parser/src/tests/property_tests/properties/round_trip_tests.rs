@@ -5,14 +5,17 @@
 //!    reproduces the original source exactly (validates span tracking)
 //! 2. Re-parse stability: the reconstructed source parses without
 //!    errors (validates `AstNode::to_source` correctness)
+//! 3. Synthetic round trip: `parse(src).to_source(None)` re-parses
+//!    to a document that prints identically (validates the
+//!    synthetic-formatting printer)
 //!
 //! Written by Claude Code, reviewed by a human.
 
 use proptest::prelude::*;
 
 use crate::ast::AstNode;
-use crate::tests::property_tests::generators::documents::arb_executable_document;
-use crate::tests::property_tests::generators::documents::arb_schema_document;
+use crate::arbitrary::documents::arb_executable_document;
+use crate::arbitrary::documents::arb_schema_document;
 use crate::tests::property_tests::proptest_config;
 use crate::GraphQLParser;
 
@@ -132,4 +135,73 @@ proptest! {
         );
     }
 
+    /// Verifies synthetic round trip for schema documents: the
+    /// output of `to_source(None)` should re-parse, and printing the
+    /// re-parsed document should reproduce it exactly.
+    ///
+    /// Written by Claude Code, reviewed by a human.
+    #[test]
+    fn schema_synthetic_round_trip(source in arb_schema_document(4)) {
+        let result = GraphQLParser::new(&source).parse_schema_document();
+        prop_assert!(
+            !result.has_errors(),
+            "Generated schema document should parse without errors.\n\
+             Source:\n{}",
+            source,
+        );
+        let printed = result.into_ast().to_source(None);
+        let reparse_result = GraphQLParser::new(&printed)
+            .parse_schema_document();
+        prop_assert!(
+            !reparse_result.has_errors(),
+            "Re-parse of printed schema document failed.\n\
+             Original:\n{}\n\n\
+             Printed:\n{}\n\n\
+             Errors:\n{}",
+            source,
+            printed,
+            reparse_result.formatted_errors(),
+        );
+        let reprinted = reparse_result.into_ast().to_source(None);
+        prop_assert_eq!(
+            &reprinted,
+            &printed,
+            "Synthetic round trip failed for schema document.",
+        );
+    }
+
+    /// Verifies synthetic round trip for executable documents.
+    ///
+    /// Written by Claude Code, reviewed by a human.
+    #[test]
+    fn executable_synthetic_round_trip(
+        source in arb_executable_document(4)
+    ) {
+        let result = GraphQLParser::new(&source).parse_executable_document();
+        prop_assert!(
+            !result.has_errors(),
+            "Generated executable document should parse without errors.\n\
+             Source:\n{}",
+            source,
+        );
+        let printed = result.into_ast().to_source(None);
+        let reparse_result = GraphQLParser::new(&printed)
+            .parse_executable_document();
+        prop_assert!(
+            !reparse_result.has_errors(),
+            "Re-parse of printed executable document failed.\n\
+             Original:\n{}\n\n\
+             Printed:\n{}\n\n\
+             Errors:\n{}",
+            source,
+            printed,
+            reparse_result.formatted_errors(),
+        );
+        let reprinted = reparse_result.into_ast().to_source(None);
+        prop_assert_eq!(
+            &reprinted,
+            &printed,
+            "Synthetic round trip failed for executable document.",
+        );
+    }
 }
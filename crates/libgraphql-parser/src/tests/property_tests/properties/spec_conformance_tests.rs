@@ -10,9 +10,9 @@
 
 use proptest::prelude::*;
 
-use crate::tests::property_tests::generators::documents::arb_executable_document;
-use crate::tests::property_tests::generators::documents::arb_mixed_document;
-use crate::tests::property_tests::generators::documents::arb_schema_document;
+use crate::arbitrary::documents::arb_executable_document;
+use crate::arbitrary::documents::arb_mixed_document;
+use crate::arbitrary::documents::arb_schema_document;
 use crate::tests::property_tests::proptest_config;
 use crate::GraphQLParser;
 use crate::GraphQLParserConfig;
@@ -0,0 +1,94 @@
+//! Tests for `Symbol` interning.
+
+use crate::Symbol;
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+
+/// Interning the same contents twice yields the same symbol backed
+/// by the same storage, regardless of where the input came from.
+#[test]
+fn intern_deduplicates() {
+    let a = Symbol::intern("symbol_tests_dedup");
+    let b = Symbol::intern(&String::from("symbol_tests_dedup"));
+    assert_eq!(a, b);
+    assert!(std::ptr::eq(a.as_str(), b.as_str()));
+    assert_ne!(a, Symbol::intern("symbol_tests_other"));
+}
+
+/// The empty string is a valid symbol distinct from all others.
+#[test]
+fn intern_empty_string() {
+    let empty = Symbol::intern("");
+    assert_eq!(empty, Symbol::intern(""));
+    assert_eq!(empty.as_str(), "");
+    assert_ne!(empty, Symbol::intern("a"));
+}
+
+/// Symbols hash like their string contents, so `&str` lookups work.
+#[test]
+fn borrow_str_lookup() {
+    let mut map = HashMap::new();
+    map.insert(Symbol::intern("Query"), 1);
+    assert_eq!(map.get("Query"), Some(&1));
+    assert_eq!(map.get("Mutation"), None);
+}
+
+/// Ordering is lexicographic rather than by interning order.
+#[test]
+fn ord_is_lexicographic() {
+    let z = Symbol::intern("symbol_tests_zzz");
+    let a = Symbol::intern("symbol_tests_aaa");
+    let sorted: Vec<_> = BTreeSet::from([z.clone(), a.clone()]).into_iter().collect();
+    assert_eq!(sorted, vec![a, z]);
+}
+
+/// `Debug` and `Display` match the underlying string.
+#[test]
+fn formatting() {
+    let symbol = Symbol::intern("User");
+    assert_eq!(format!("{symbol}"), "User");
+    assert_eq!(format!("{symbol:?}"), "\"User\"");
+}
+
+/// Interning concurrently from many threads converges on one symbol.
+#[test]
+fn concurrent_intern() {
+    let handles: Vec<_> = (0..8)
+        .map(|_| std::thread::spawn(|| Symbol::intern("symbol_tests_concurrent")))
+        .collect();
+    let symbols: Vec<_> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+    let addrs: BTreeSet<_> = symbols
+        .iter()
+        .map(|symbol| symbol.as_str().as_ptr() as usize)
+        .collect();
+    assert_eq!(addrs.len(), 1);
+}
+
+/// A string stays interned only while some symbol refers to it, so
+/// the interner does not retain names that are no longer in use.
+#[test]
+fn dropped_symbols_are_released() {
+    let a = Symbol::intern("symbol_tests_released");
+    let b = a.clone();
+    assert!(Symbol::is_interned("symbol_tests_released"));
+    drop(a);
+    assert!(Symbol::is_interned("symbol_tests_released"));
+    drop(b);
+    assert!(!Symbol::is_interned("symbol_tests_released"));
+
+    // Re-interning after release yields an equal, usable symbol.
+    let c = Symbol::intern("symbol_tests_released");
+    assert_eq!(c.as_str(), "symbol_tests_released");
+    assert!(Symbol::is_interned("symbol_tests_released"));
+}
+
+/// Interning many short-lived names keeps the table bounded by the
+/// live symbols rather than by every name ever interned.
+#[test]
+fn short_lived_symbols_do_not_accumulate() {
+    for i in 0..10_000 {
+        Symbol::intern(&format!("symbol_tests_transient_{i}"));
+    }
+    assert!(!Symbol::is_interned("symbol_tests_transient_0"));
+    assert!(Symbol::table_len() < 2 * 1024);
+}
@@ -8,9 +8,9 @@ use crate::token::GraphQLToken;
 /// be parsed. For example:
 /// [`StrGraphQLTokenSource`](crate::token::StrGraphQLTokenSource) is a
 /// lexer over `&str` types,
-/// [`libgraphql_macros::RustMacroGraphQLTokenSource`](https://github.com/jeffmo/libgraphql/blob/59aa00fe928249c9d7abcd2576e2e37e45345955/crates/libgraphql-macros/src/rust_macro_graphql_token_source.rs#L101)
+/// `RustMacroGraphQLTokenSource` (available with the `proc-macro2` feature)
 /// is a lexer over
-/// [`proc_macro2::Span`](https://docs.rs/proc-macro2/latest/proc_macro2/struct.Span.html)
+/// [`proc_macro2::TokenStream`](https://docs.rs/proc-macro2/latest/proc_macro2/struct.TokenStream.html)
 /// (for lexing Rust procedural macro input), etc.
 ///
 /// Implementors define an [`Iterator`] that produces tokens one at a time.
@@ -5,6 +5,10 @@ mod graphql_token;
 mod graphql_token_source;
 mod graphql_token_kind;
 mod graphql_trivia_token;
+#[cfg(feature = "proc-macro2")]
+mod rust_macro_graphql_token_source;
+#[cfg(feature = "proc-macro2")]
+mod span_map;
 mod str_graphql_token_source_config;
 mod str_to_graphql_token_source;
 
@@ -14,6 +18,10 @@ pub use graphql_token_kind::GraphQLTokenError;
 pub use graphql_token_kind::GraphQLTokenKind;
 pub use graphql_token_source::GraphQLTokenSource;
 pub use graphql_trivia_token::GraphQLTriviaToken;
+#[cfg(feature = "proc-macro2")]
+pub use rust_macro_graphql_token_source::RustMacroGraphQLTokenSource;
+#[cfg(feature = "proc-macro2")]
+pub use span_map::SpanMap;
 pub use str_graphql_token_source_config::StrGraphQLTokenSourceConfig;
 pub use str_to_graphql_token_source::StrGraphQLTokenSource;
 
@@ -1,5 +1,15 @@
-//! A GraphQL token source that reads and translates from Rust proc-macro token
-//! streams into a [`GraphQLTokenSource`](libgraphql_parser::GraphQLTokenSource).
+//! A GraphQL token source that reads and translates from Rust
+//! [`proc_macro2::TokenStream`]s into a [`GraphQLTokenSource`].
+//!
+//! Available with the `proc-macro2` feature.
+//!
+//! Positions are read via `proc_macro2`'s `span-locations` feature, so this
+//! token source works both inside a running proc macro and in any other
+//! context that holds a `proc_macro2::TokenStream` (build scripts, `syn`-based
+//! code generators, tests, etc.). Outside of a proc macro, line/column info is
+//! only meaningful for streams that `proc_macro2` parsed itself (e.g. via
+//! `TokenStream::from_str()`); tokens built with `quote!` all report line 1,
+//! column 0.
 //!
 //! # Limitations
 //!
@@ -23,28 +33,31 @@
 //! Rust macros only report `byte_offset`s properly when built with Rust nightly
 //! toolchains. At the time of this writing stable rustc toolchains do not
 //! provide accurate or meaningful output for `proc_macro::Span::byte_range()`.
+//! This token source therefore assigns synthetic byte offsets and records them
+//! in a [`SpanMap`] (see [`RustMacroGraphQLTokenSource::span_map()`]).
 //!
 //! See: <https://github.com/rust-lang/rust/issues/54725>
 //!
 //! TODO: It would be good to add something that emits a warning with a clear
-//! description of caveats when using `libgraphql-macros` with a non-nightly (or
-//! otherwise incompatible) Rust toolchain.
+//! description of caveats when using this token source from a proc macro built
+//! with a non-nightly (or otherwise incompatible) Rust toolchain.
 //!
 //! e.g. build_dependency on `rustc_version` -> build.rs file that uses
 //! `rustc_version::version_meta()` to emit
 //! `"cargo:rustc-cfg=libgraphql_rustc_nightly"` when on nightly.
 
-use libgraphql_parser::smallvec::smallvec;
-use libgraphql_parser::token::GraphQLToken;
-use libgraphql_parser::token::GraphQLTokenKind;
-use libgraphql_parser::token::GraphQLTriviaToken;
-use libgraphql_parser::token::GraphQLTriviaTokenVec;
-use libgraphql_parser::token::GraphQLTokenSource;
-use libgraphql_parser::ByteSpan;
-use libgraphql_parser::GraphQLErrorNote;
-use libgraphql_parser::SourceMap;
-use libgraphql_parser::SourcePosition;
-use libgraphql_parser::SourceSpan;
+use crate::smallvec::smallvec;
+use crate::token::GraphQLToken;
+use crate::token::GraphQLTokenKind;
+use crate::token::GraphQLTriviaToken;
+use crate::token::GraphQLTriviaTokenVec;
+use crate::token::GraphQLTokenSource;
+use crate::token::SpanMap;
+use crate::ByteSpan;
+use crate::GraphQLErrorNote;
+use crate::SourceMap;
+use crate::SourcePosition;
+use crate::SourceSpan;
 use proc_macro2::Delimiter;
 use proc_macro2::Group;
 use proc_macro2::Ident;
@@ -53,11 +66,9 @@ use proc_macro2::Punct;
 use proc_macro2::Span;
 use proc_macro2::TokenStream;
 use proc_macro2::TokenTree;
-use std::cell::RefCell;
 use std::collections::HashMap;
 use std::collections::VecDeque;
 use std::iter::Peekable;
-use std::rc::Rc;
 
 /// Sentinel error message for a single `.` token.
 ///
@@ -94,7 +105,7 @@ const PENDING_MINUS_ERROR_MSG: &str = "Unexpected `-`";
 /// streams into a [`GraphQLTokenSource`].
 ///
 /// This implements `Iterator<Item = GraphQLToken<'static>>`, making it compatible
-/// with [`libgraphql_parser::GraphQLTokenStream`].
+/// with [`GraphQLTokenStream`](crate::GraphQLTokenStream).
 ///
 /// The `'static` lifetime is used because `proc_macro2` doesn't expose the
 /// original source text as a contiguous string - all string values must be
@@ -108,8 +119,33 @@ const PENDING_MINUS_ERROR_MSG: &str = "Unexpected `-`";
 /// offset the parser may encounter. The `Iterator` impl simply drains the
 /// pre-built buffer.
 ///
+/// # Example
+///
+/// ```rust
+/// use libgraphql_parser::GraphQLParser;
+/// use libgraphql_parser::token::RustMacroGraphQLTokenSource;
+/// use proc_macro2::TokenStream;
+/// use std::str::FromStr;
+///
+/// let tokens = TokenStream::from_str("type Query { me: User }").unwrap();
+/// let token_source = RustMacroGraphQLTokenSource::new(tokens);
+///
+/// // Keep the span map around to map error offsets back to `Span`s after the
+/// // parser has consumed the token source.
+/// let span_map = token_source.span_map().clone();
+///
+/// let parse_result =
+///     GraphQLParser::from_token_source(token_source).parse_schema_document();
+/// for error in parse_result.errors() {
+///     let offset = error.source_span().start_inclusive.byte_offset() as u32;
+///     let span = span_map.lookup_byte_offset(offset);
+///     eprintln!("{} at {:?}", error.message(), span.map(|s| s.start()));
+/// }
+/// # assert!(!parse_result.has_errors());
+/// ```
+///
 /// See module documentation for limitations.
-pub(crate) struct RustMacroGraphQLTokenSource {
+pub struct RustMacroGraphQLTokenSource {
     /// Pre-built buffer of all tokens, produced eagerly during
     /// construction. Tokens are pushed at the back during
     /// tokenization and consumed from the front via `pop_front()`
@@ -122,24 +158,18 @@ pub(crate) struct RustMacroGraphQLTokenSource {
     /// `resolve_span()` returns `SourceSpan`s whose `byte_offset`
     /// fields faithfully match the synthetic `ByteSpan` offsets.
     source_map: SourceMap<'static>,
+    /// Maps each emitted token's synthetic start offset back to its
+    /// `proc_macro2::Span`.
+    span_map: SpanMap,
 }
 
 impl RustMacroGraphQLTokenSource {
-    /// Creates a new token source from a proc-macro token stream.
-    ///
-    /// Eagerly tokenizes the entire input, populating the shared
-    /// `span_map` and building an immutable `SourceMap` from
-    /// pre-computed `(synthetic_offset, SourcePosition)` entries.
+    /// Creates a new token source from a `proc_macro2` token stream.
     ///
-    /// The `span_map` is a shared map that records each emitted
-    /// token's synthetic byte offset alongside its
-    /// `proc_macro2::Span`, allowing the caller to later map
-    /// `ByteSpan` offsets from parse errors back to `Span`s for
-    /// accurate `compile_error!` reporting.
-    pub fn new(
-        input: TokenStream,
-        span_map: Rc<RefCell<HashMap<u32, Span>>>,
-    ) -> Self {
+    /// Eagerly tokenizes the entire input, building both the
+    /// [`SpanMap`] and an immutable `SourceMap` from pre-computed
+    /// `(synthetic_offset, SourcePosition)` entries.
+    pub fn new(input: TokenStream) -> Self {
         let mut tokenizer = Tokenizer {
             tokens: input.into_iter().peekable(),
             pending: VecDeque::new(),
@@ -147,7 +177,7 @@ impl RustMacroGraphQLTokenSource {
             finished: false,
             last_span: None,
             next_synthetic_offset: 0,
-            span_map: Rc::clone(&span_map),
+            span_map: HashMap::new(),
             source_map_entries: Vec::new(),
         };
 
@@ -160,8 +190,21 @@ impl RustMacroGraphQLTokenSource {
         Self {
             buffered_tokens,
             source_map,
+            span_map: SpanMap::new(tokenizer.span_map),
         }
     }
+
+    /// Returns the map from each emitted token's synthetic byte
+    /// offset to its `proc_macro2::Span`.
+    ///
+    /// The map is complete as soon as the token source is
+    /// constructed, so callers typically clone it before handing
+    /// the token source to a parser. It can then be used to map
+    /// `ByteSpan` offsets from parse errors back to `Span`s (e.g.
+    /// for accurate `compile_error!` reporting).
+    pub fn span_map(&self) -> &SpanMap {
+        &self.span_map
+    }
 }
 
 impl Iterator for RustMacroGraphQLTokenSource {
@@ -221,8 +264,8 @@ struct Tokenizer {
     /// correspond to real file positions — they are unique keys for
     /// `SpanMap` lookup.
     next_synthetic_offset: u32,
-    /// Shared map from synthetic byte offset to `proc_macro2::Span`.
-    span_map: Rc<RefCell<HashMap<u32, Span>>>,
+    /// Map from synthetic byte offset to `proc_macro2::Span`.
+    span_map: HashMap<u32, Span>,
     /// Pre-computed `(byte_offset, SourcePosition)` entries collected
     /// during tokenization. Used to build the immutable `SourceMap`
     /// after all tokens have been produced.
@@ -371,13 +414,13 @@ impl Tokenizer {
     }
 
     /// Creates a `ByteSpan` with synthetic offsets and records the
-    /// start offset → `proc_macro2::Span` mapping in the shared
+    /// start offset → `proc_macro2::Span` mapping in
     /// `span_map` for later error-reporting lookup. Also records
     /// `(offset, SourcePosition)` entries for the `SourceMap`.
     fn make_byte_span(&mut self, span: &Span) -> ByteSpan {
         let start = self.next_offset();
         let end = self.next_offset();
-        self.span_map.borrow_mut().insert(start, *span);
+        self.span_map.insert(start, *span);
         self.source_map_entries.push((
             start,
             Self::span_start_position(span, start),
@@ -399,9 +442,7 @@ impl Tokenizer {
     ) -> ByteSpan {
         let start = self.next_offset();
         let end = self.next_offset();
-        self.span_map
-            .borrow_mut()
-            .insert(start, pending.span);
+        self.span_map.insert(start, pending.span);
 
         self.source_map_entries.push((
             start,
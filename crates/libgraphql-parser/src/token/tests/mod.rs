@@ -1,6 +1,8 @@
 mod graphql_token_kind_block_string_parsing_tests;
 mod graphql_token_kind_tests;
 mod graphql_token_tests;
+#[cfg(feature = "proc-macro2")]
+mod rust_macro_graphql_token_source_tests;
 mod str_graphql_token_source_lex_block_string_byte_scanning_tests;
 mod str_graphql_token_source_lex_comment_byte_scanning_tests;
 mod str_graphql_token_source_lex_name_byte_scanning_tests;
@@ -8,3 +10,11 @@ mod str_graphql_token_source_peek_consume_ascii_fast_path_tests;
 mod str_graphql_token_source_skip_whitespace_byte_scanning_tests;
 mod str_graphql_token_source_trivia_config_tests;
 mod str_to_graphql_token_source_tests;
+#[cfg(feature = "proc-macro2")]
+mod token_source_divergence_tests;
+#[cfg(feature = "proc-macro2")]
+mod token_source_parity_error_tests;
+#[cfg(feature = "proc-macro2")]
+mod token_source_parity_utils;
+#[cfg(feature = "proc-macro2")]
+mod token_source_parity_valid_tests;
@@ -13,15 +13,14 @@
 //! require accurate position tracking (block strings, spaced dots, etc.),
 //! we use `TokenStream::from_str()` which preserves real positions.
 
-use crate::rust_macro_graphql_token_source::RustMacroGraphQLTokenSource;
-use libgraphql_parser::token::GraphQLToken;
-use libgraphql_parser::token::GraphQLTokenKind;
-use libgraphql_parser::token::GraphQLTriviaToken;
+use crate::GraphQLParser;
+use crate::token::GraphQLToken;
+use crate::token::GraphQLTokenKind;
+use crate::token::GraphQLTriviaToken;
+use crate::token::RustMacroGraphQLTokenSource;
+use crate::token::SpanMap;
 use proc_macro2::TokenStream;
 use quote::quote;
-use std::cell::RefCell;
-use std::collections::HashMap;
-use std::rc::Rc;
 use std::str::FromStr;
 
 /// Helper function to tokenize a GraphQL-like token stream and return the token
@@ -30,9 +29,7 @@ use std::str::FromStr;
 /// Uses `'static` lifetime since `RustMacroGraphQLTokenSource` produces owned
 /// strings (not borrowed from source).
 fn tokenize(input: TokenStream) -> Vec<GraphQLTokenKind<'static>> {
-    let span_map = Rc::new(RefCell::new(HashMap::new()));
-    let source =
-        RustMacroGraphQLTokenSource::new(input, span_map);
+    let source = RustMacroGraphQLTokenSource::new(input);
     source.map(|t| t.kind).collect()
 }
 
@@ -42,9 +39,7 @@ fn tokenize(input: TokenStream) -> Vec<GraphQLTokenKind<'static>> {
 /// Uses `'static` lifetime since `RustMacroGraphQLTokenSource` produces owned
 /// strings.
 fn tokenize_full(input: TokenStream) -> Vec<GraphQLToken<'static>> {
-    let span_map = Rc::new(RefCell::new(HashMap::new()));
-    let source =
-        RustMacroGraphQLTokenSource::new(input, span_map);
+    let source = RustMacroGraphQLTokenSource::new(input);
     source.collect()
 }
 
@@ -55,9 +50,7 @@ fn tokenize_full(input: TokenStream) -> Vec<GraphQLToken<'static>> {
 fn tokenize_str(input: &str) -> Vec<GraphQLTokenKind<'static>> {
     let stream = TokenStream::from_str(input)
         .expect("Failed to parse as Rust tokens");
-    let span_map = Rc::new(RefCell::new(HashMap::new()));
-    let source =
-        RustMacroGraphQLTokenSource::new(stream, span_map);
+    let source = RustMacroGraphQLTokenSource::new(stream);
     source.map(|t| t.kind).collect()
 }
 
@@ -69,17 +62,12 @@ fn tokenize_str(input: &str) -> Vec<GraphQLTokenKind<'static>> {
 /// produces owned strings.
 fn tokenize_str_full_with_span_map(
     input: &str,
-) -> (Vec<GraphQLToken<'static>>, HashMap<u32, proc_macro2::Span>) {
+) -> (Vec<GraphQLToken<'static>>, SpanMap) {
     let stream = TokenStream::from_str(input)
         .expect("Failed to parse as Rust tokens");
-    let span_map = Rc::new(RefCell::new(HashMap::new()));
-    let source =
-        RustMacroGraphQLTokenSource::new(stream, span_map.clone());
-    let tokens: Vec<_> = source.collect();
-    let map = Rc::try_unwrap(span_map)
-        .expect("span_map Rc should have one ref")
-        .into_inner();
-    (tokens, map)
+    let source = RustMacroGraphQLTokenSource::new(stream);
+    let span_map = source.span_map().clone();
+    (source.collect(), span_map)
 }
 
 /// Tests that a simple GraphQL type definition produces the expected token
@@ -657,7 +645,7 @@ fn test_comma_trivia_span_positions() {
         &tokens[0].kind,
         GraphQLTokenKind::Name(n) if n == "a",
     ));
-    let a_span = span_map.get(&tokens[0].span.start)
+    let a_span = span_map.lookup_byte_offset(tokens[0].span.start)
         .expect("span_map should have entry for `a`");
     assert_eq!(a_span.start().column, 0);
 
@@ -666,7 +654,7 @@ fn test_comma_trivia_span_positions() {
         &tokens[1].kind,
         GraphQLTokenKind::Name(n) if n == "b",
     ));
-    let b_span = span_map.get(&tokens[1].span.start)
+    let b_span = span_map.lookup_byte_offset(tokens[1].span.start)
         .expect("span_map should have entry for `b`");
     assert_eq!(b_span.start().column, 3);
     assert_eq!(tokens[1].preceding_trivia.len(), 1);
@@ -675,7 +663,7 @@ fn test_comma_trivia_span_positions() {
         &tokens[1].preceding_trivia[0]
     {
         // Comma is at column 1
-        let comma_span = span_map.get(&span.start)
+        let comma_span = span_map.lookup_byte_offset(span.start)
             .expect("span_map should have entry for comma");
         assert_eq!(
             comma_span.start().column,
@@ -782,9 +770,9 @@ fn test_position_tracking() {
     let type_token = &tokens[0];
     let query_token = &tokens[1];
 
-    let type_span = span_map.get(&type_token.span.start)
+    let type_span = span_map.lookup_byte_offset(type_token.span.start)
         .expect("span_map should have entry for `type`");
-    let query_span = span_map.get(&query_token.span.start)
+    let query_span = span_map.lookup_byte_offset(query_token.span.start)
         .expect("span_map should have entry for `Query`");
 
     // proc_macro2 uses 1-based lines
@@ -796,6 +784,36 @@ fn test_position_tracking() {
     assert_eq!(query_span.start().column, 5);
 }
 
+/// Tests parsing a `proc_macro2::TokenStream` outside of a proc-macro
+/// context, mapping the resulting parse error back to a position via
+/// both the `SourceMap` and the `SpanMap`.
+///
+/// Written by Claude Code, reviewed by a human.
+#[test]
+fn test_parse_errors_resolve_outside_proc_macro() {
+    let stream = TokenStream::from_str(
+        "type Query {\n  me: User\n  name String\n}",
+    ).expect("Failed to parse as Rust tokens");
+    let source = RustMacroGraphQLTokenSource::new(stream);
+    let span_map = source.span_map().clone();
+
+    let parse_result =
+        GraphQLParser::from_token_source(source).parse_schema_document();
+    let errors = parse_result.errors();
+    assert_eq!(errors.len(), 1, "Expected 1 error, got: {errors:?}");
+
+    // `SourcePosition` lines are 0-based.
+    let start = &errors[0].source_span().start_inclusive;
+    assert_eq!(start.line(), 2);
+
+    // `proc_macro2` lines are 1-based.
+    let span = span_map
+        .lookup_byte_offset(start.byte_offset() as u32)
+        .expect("span_map should have entry for the error token");
+    assert_eq!(span.start().line, 3);
+    assert_eq!(span.start().column, start.col_utf8());
+}
+
 /// Tests that directive usage with arguments tokenizes correctly.
 ///
 /// Written by Claude Code, reviewed by a human.
@@ -8,15 +8,12 @@
 //! Each test explains **why** the divergence exists and asserts
 //! the actual divergent behavior from both sides.
 
-use crate::rust_macro_graphql_token_source::RustMacroGraphQLTokenSource;
-use crate::tests::token_source_parity_utils::tokenize_via_str;
-use crate::tests::token_source_parity_utils::tokenize_via_rust;
-use libgraphql_parser::token::GraphQLTokenKind;
-use libgraphql_parser::token::GraphQLTriviaToken;
+use crate::token::GraphQLTokenKind;
+use crate::token::GraphQLTriviaToken;
+use crate::token::RustMacroGraphQLTokenSource;
+use crate::token::tests::token_source_parity_utils::tokenize_via_rust;
+use crate::token::tests::token_source_parity_utils::tokenize_via_str;
 use proc_macro2::TokenStream;
-use std::cell::RefCell;
-use std::collections::HashMap;
-use std::rc::Rc;
 use std::str::FromStr;
 
 /// Documents that `#` is treated as a comment by
@@ -91,9 +88,7 @@ fn divergence_raw_string_rust_only() {
 
     // Rust source: raw string literal → error token
     let stream = quote! { r"raw content" };
-    let span_map = Rc::new(RefCell::new(HashMap::new()));
-    let source =
-        RustMacroGraphQLTokenSource::new(stream, span_map);
+    let source = RustMacroGraphQLTokenSource::new(stream);
     let rust_tokens: Vec<_> = source.collect();
 
     assert!(
@@ -7,7 +7,7 @@
 //!
 //! See: https://spec.graphql.org/September2025/#sec-Lexical-Tokens
 
-use crate::tests::token_source_parity_utils::assert_parity;
+use crate::token::tests::token_source_parity_utils::assert_parity;
 
 /// Tests that a standalone minus sign (`-` not followed by a
 /// number) produces identical error tokens from both sources.
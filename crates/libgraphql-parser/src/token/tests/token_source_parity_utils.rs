@@ -4,14 +4,11 @@
 //! helpers, and assertion functions used by the parity test
 //! modules.
 
-use crate::rust_macro_graphql_token_source::RustMacroGraphQLTokenSource;
-use libgraphql_parser::token::GraphQLToken;
-use libgraphql_parser::token::GraphQLTokenKind;
-use libgraphql_parser::token::GraphQLTriviaToken;
+use crate::token::GraphQLToken;
+use crate::token::GraphQLTokenKind;
+use crate::token::GraphQLTriviaToken;
+use crate::token::RustMacroGraphQLTokenSource;
 use proc_macro2::TokenStream;
-use std::cell::RefCell;
-use std::collections::HashMap;
-use std::rc::Rc;
 use std::str::FromStr;
 
 // =========================================================================
@@ -117,9 +114,7 @@ pub(crate) fn tokenize_via_rust(
 ) -> Vec<GraphQLToken<'static>> {
     let stream = TokenStream::from_str(input)
         .expect("Failed to parse as Rust tokens");
-    let span_map = Rc::new(RefCell::new(HashMap::new()));
-    let source =
-        RustMacroGraphQLTokenSource::new(stream, span_map);
+    let source = RustMacroGraphQLTokenSource::new(stream);
     source.collect()
 }
 
@@ -133,12 +128,12 @@ pub(crate) fn tokenize_via_str(
     input: &str,
 ) -> Vec<GraphQLToken<'_>> {
     let config =
-        libgraphql_parser::token::StrGraphQLTokenSourceConfig {
+        crate::token::StrGraphQLTokenSourceConfig {
             retain_whitespace: false,
             ..Default::default()
         };
     let source =
-        libgraphql_parser::token::StrGraphQLTokenSource::with_config(
+        crate::token::StrGraphQLTokenSource::with_config(
             input,
             config,
         );